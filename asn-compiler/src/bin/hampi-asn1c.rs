@@ -11,6 +11,30 @@ fn main() -> io::Result<()> {
     let mut compiler = Asn1Compiler::new();
 
     for arg in &args[1..] {
+        if arg == "--lazy-open-types" {
+            compiler.set_lazy_open_types(true);
+            continue;
+        }
+        if arg == "--big-integers" {
+            compiler.set_big_integers(true);
+            continue;
+        }
+        if arg == "--extended-values" {
+            compiler.set_extended_values(true);
+            continue;
+        }
+        if arg == "--visitors" {
+            compiler.set_visitors(true);
+            continue;
+        }
+        if arg == "--reflect" {
+            compiler.set_reflect(true);
+            continue;
+        }
+        if arg == "--value-notation" {
+            compiler.set_value_notation(true);
+            continue;
+        }
         eprintln!("File: {}", arg);
         let file = File::open(arg)?;
         let mut tokens = asn1_compiler::tokenizer::tokenize(file)?;
@@ -49,6 +49,69 @@ impl Asn1Compiler {
         }
     }
 
+    /// Generate Open Types that are decoded only on access.
+    ///
+    /// When set, the Open Type fields of the generated `SEQUENCE`s are wrapped in
+    /// `asn1_codecs::aper::LazyOpenType`, which keeps the encoded bytes of the value and decodes
+    /// the value only when requested.
+    pub fn set_lazy_open_types(&mut self, lazy: bool) {
+        self.generator.lazy_open_types = lazy;
+    }
+
+    /// Generate arbitrary precision integers for the INTEGER Types whose values are not bounded.
+    ///
+    /// When set, the INTEGER Types without a lower or an upper bound (and the extensible INTEGER
+    /// Types) are generated as `asn1_codecs::BigInt` (requires the `bigint` feature of
+    /// `asn1-codecs`), instead of `i64` or `i128`.
+    pub fn set_big_integers(&mut self, big: bool) {
+        self.generator.big_integers = big;
+    }
+
+    /// Retain the extension bit of the values of the Types with an extensible constraint.
+    ///
+    /// When set, the INTEGER, BIT STRING, OCTET STRING and character string Types with an
+    /// extensible value or `SIZE` constraint are generated with a second `bool` field, that is set
+    /// if the value was encoded as outside the Extension Root, so that a decoded value is encoded
+    /// back as it was decoded. The extensible INTEGER Types are generated with a signed type at
+    /// least 64 bits wide (instead of the smallest type for the values in the Extension Root), to
+    /// hold the values outside the Extension Root.
+    ///
+    /// Without this, the value of an extensible Type is encoded as outside the Extension Root only
+    /// if it is not within the root, and decoding an INTEGER value that does not fit the generated
+    /// type returns an error.
+    pub fn set_extended_values(&mut self, extended: bool) {
+        self.generator.extended_values = extended;
+    }
+
+    /// Generate the `Visitor` and `VisitorMut` traits for the generated Types.
+    ///
+    /// When set, `visit` and `visit_mut` modules are generated with a `visit_*` method (and a
+    /// `walk_*` function) for every generated Type. The default implementation of a method visits
+    /// all the fields, variants or entries of the Type, so that an implementation needs to override
+    /// only the methods for the Types it is interested in.
+    pub fn set_visitors(&mut self, visitors: bool) {
+        self.generator.visitors = visitors;
+    }
+
+    /// Derive `Asn1Reflect` for the generated Types.
+    ///
+    /// When set, the generated Types implement `asn1_codecs::reflect::Asn1Reflect`, which provides
+    /// the type name, the kind, the constraints, the value and the children of a value at run
+    /// time, along with the path based access to the values using `Asn1Reflect::get`.
+    pub fn set_reflect(&mut self, reflect: bool) {
+        self.generator.reflect = reflect;
+    }
+
+    /// Derive `Asn1ValueNotation` for the generated Types.
+    ///
+    /// When set, the generated Types derive `asn1_codecs::notation::Asn1ValueNotation`, which
+    /// formats the values in the ASN.1 value notation and parses the values from the ASN.1 value
+    /// notation. The identifiers from the ASN.1 definitions are generated as the `name` (and the
+    /// `names`) attributes.
+    pub fn set_value_notation(&mut self, value_notation: bool) {
+        self.generator.value_notation = value_notation;
+    }
+
     /// Add a module to the list of known modules.
     ///
     /// If the module alredy exists, returns `false` else returns `true`.
@@ -162,10 +225,10 @@ impl Asn1Compiler {
     fn resolve_definitions(&mut self) -> Result<(), Error> {
         let module_names = self.sorted_modules();
         for name in module_names {
-            let mut module = self.modules.get_mut(&name).unwrap();
+            let module = self.modules.get_mut(&name).unwrap();
 
             //let module_definitions = module.definitions_sorted();
-            self.resolver.resolve_definitions(&mut module)?;
+            self.resolver.resolve_definitions(module)?;
         }
         eprintln!(
             "Resolved Definitions: {:#?}",
@@ -1 +1,3 @@
 mod types;
+
+mod values;
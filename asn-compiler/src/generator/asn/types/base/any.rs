@@ -0,0 +1,43 @@
+//! Generator code for Base Type Asn1ResolvedAny
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::error::Error;
+
+use crate::generator::Generator;
+use crate::resolver::asn::structs::types::base::Asn1ResolvedAny;
+
+impl Asn1ResolvedAny {
+    // `ANY`, `EXTERNAL` and `EMBEDDED PDV` are all generated as the raw encoded bytes of the
+    // value, which are encoded as an Open Type.
+    pub(crate) fn generate(
+        &self,
+        name: &str,
+        generator: &mut Generator,
+    ) -> Result<TokenStream, Error> {
+        let struct_name = generator.to_type_ident(name);
+
+        let derive_tokens = generator.derive_tokens();
+        let tag_tokens = generator.tag_tokens(name);
+        let struct_tokens = quote! {
+            #derive_tokens
+            #[asn(type = "ANY" #tag_tokens)]
+            pub struct #struct_name(pub Vec<u8>);
+        };
+
+        Ok(struct_tokens)
+    }
+
+    pub(crate) fn generate_ident_and_aux_type(
+        &self,
+        generator: &mut Generator,
+    ) -> Result<Ident, Error> {
+        let unique_name = generator.get_unique_name(&self.any_type);
+
+        let item = self.generate(&unique_name, generator)?;
+        generator.aux_items.push(item);
+
+        Ok(generator.to_type_ident(&unique_name))
+    }
+}
@@ -18,15 +18,29 @@ impl Asn1ResolvedBitString {
 
         let mut ty_attributes = quote! { type = "BITSTRING" };
 
-        if self.size.is_some() {
-            let sz_attributes = self.size.as_ref().unwrap().get_ty_size_constraints_attrs();
+        let extended_field =
+            generator.extended_values && self.size.as_ref().is_some_and(|s| s.has_extension());
+        if let Some(ref size) = self.size {
+            let sz_attributes = size.get_ty_size_constraints_attrs();
             ty_attributes.extend(sz_attributes);
         }
 
+        let contents_tokens = match self.contents {
+            Some(ref contents) => {
+                contents.generate_contents_impl(&struct_name, true, extended_field, generator)
+            }
+            None => TokenStream::new(),
+        };
+
+        let extended_field_tokens = generator.extended_field_tokens(extended_field);
+        let derive_tokens = generator.derive_tokens();
+        let tag_tokens = generator.tag_tokens(name);
         let struct_tokens = quote! {
-            #[derive(Debug, AperCodec)]
-            #[asn(#ty_attributes)]
-            pub struct #struct_name(pub BitVec<u8, Msb0>);
+            #derive_tokens
+            #[asn(#ty_attributes #tag_tokens)]
+            pub struct #struct_name(pub BitVec<u8, Msb0> #extended_field_tokens);
+
+            #contents_tokens
         };
 
         Ok(struct_tokens)
@@ -20,20 +20,30 @@ impl Asn1ResolvedCharacterString {
 
         let mut ty_attributes = quote! { type = #char_str_type };
 
-        if self.size.is_some() {
-            let sz_attributes = self.size.as_ref().unwrap().get_ty_size_constraints_attrs();
+        let extended_field = generator.extended_values && self.is_extensible();
+        if let Some(ref size) = self.size {
+            let sz_attributes = size.get_ty_size_constraints_attrs();
             ty_attributes.extend(sz_attributes);
         }
 
+        let extended_field_tokens = generator.extended_field_tokens(extended_field);
+        let derive_tokens = generator.derive_tokens();
+        let tag_tokens = generator.tag_tokens(name);
         let struct_tokens = quote! {
-            #[derive(Debug, AperCodec)]
-            #[asn(#ty_attributes)]
-            pub struct #struct_name(pub String);
+            #derive_tokens
+            #[asn(#ty_attributes #tag_tokens)]
+            pub struct #struct_name(pub String #extended_field_tokens);
         };
 
         Ok(struct_tokens)
     }
 
+    // The `SIZE` constraint of a UTF8String is not PER-visible, so the value of a UTF8String is
+    // never encoded as outside the Extension Root.
+    pub(crate) fn is_extensible(&self) -> bool {
+        self.str_type != "UTF8String" && self.size.as_ref().is_some_and(|s| s.has_extension())
+    }
+
     pub(crate) fn generate_ident_and_aux_type(
         &self,
         generator: &mut Generator,
@@ -27,9 +27,23 @@ impl Asn1ResolvedEnumerated {
         ty_attributes.extend(quote! { , lb = "0" });
         ty_attributes.extend(quote! { , ub =  #ub  });
 
+        // Identifiers in the order of the values, for the `Asn1ValueNotation` derive.
+        if generator.value_notation {
+            let names = self
+                .named_root_values
+                .iter()
+                .chain(self.named_ext_values.iter())
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join(" ");
+            ty_attributes.extend(quote! { , names = #names });
+        }
+
+        let derive_tokens = generator.derive_tokens();
+        let tag_tokens = generator.tag_tokens(name);
         let struct_tokens = quote! {
-            #[derive(Debug, AperCodec)]
-            #[asn(#ty_attributes)]
+            #derive_tokens
+            #[asn(#ty_attributes #tag_tokens)]
             pub struct #struct_name(pub #inner_type);
 
             impl #struct_name {
@@ -43,7 +57,7 @@ impl Asn1ResolvedEnumerated {
     fn generate_named_values(&self, generator: &Generator) -> Result<TokenStream, Error> {
         let mut tokens = TokenStream::new();
         for (name, value) in &self.named_root_values {
-            let const_name = generator.to_const_ident(&name);
+            let const_name = generator.to_const_ident(name);
             let value_literal = generator.to_suffixed_literal(self.bits, self.signed, *value);
             let ty = generator.to_inner_type(self.bits, self.signed);
             let const_tokens = quote! {
@@ -52,6 +66,19 @@ impl Asn1ResolvedEnumerated {
             tokens.extend(const_tokens);
         }
 
+        // Values of the Extension Additions follow the values of the Extension Root.
+        let ext_value_base = self.named_root_values.len() as i128;
+        for (name, value) in &self.named_ext_values {
+            let const_name = generator.to_const_ident(name);
+            let value_literal =
+                generator.to_suffixed_literal(self.bits, self.signed, ext_value_base + *value);
+            let ty = generator.to_inner_type(self.bits, self.signed);
+            let const_tokens = quote! {
+                pub const #const_name: #ty =  #value_literal ;
+            };
+            tokens.extend(const_tokens);
+        }
+
         Ok(tokens)
     }
 
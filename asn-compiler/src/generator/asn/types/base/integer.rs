@@ -15,24 +15,26 @@ impl Asn1ResolvedInteger {
         generator: &mut Generator,
     ) -> Result<TokenStream, Error> {
         let struct_name = generator.to_type_ident(name);
-        let inner_type = generator.to_inner_type(self.bits, self.signed);
         let (min, max) = self.get_min_max_constraints();
-        let extensible = self.resolved_constraints.is_some()
-            && self.resolved_constraints.as_ref().unwrap().has_extension();
+        let extensible = self.is_extensible();
+        let extended_field = extensible && generator.extended_values;
 
-        let lb = if min.is_some() {
-            let min = format!("{}", min.unwrap());
-            Some(quote! { #min })
+        let inner_type = if self.is_big_integer(generator) {
+            quote! { asn1_codecs::BigInt }
         } else {
-            None
+            let (bits, signed) = self.get_inner_bits_signed(generator);
+            generator.to_inner_type(bits, signed)
         };
 
-        let ub = if max.is_some() {
-            let max = format!("{}", max.unwrap());
-            Some(quote! { #max })
-        } else {
-            None
-        };
+        let lb = min.map(|min| {
+            let min = format!("{}", min);
+            quote! { #min }
+        });
+
+        let ub = max.map(|max| {
+            let max = format!("{}", max);
+            quote! { #max }
+        });
 
         let mut ty_tokens = quote! { type = "INTEGER" };
         if lb.is_some() {
@@ -52,10 +54,13 @@ impl Asn1ResolvedInteger {
             });
         }
 
+        let extended_field_tokens = generator.extended_field_tokens(extended_field);
+        let tag_tokens = generator.tag_tokens(name);
+        let derive_tokens = generator.derive_tokens();
         let struct_tokens = quote! {
-            #[derive(Debug, AperCodec)]
-            #[asn(#ty_tokens)]
-            pub struct #struct_name(pub #inner_type);
+            #derive_tokens
+            #[asn(#ty_tokens #tag_tokens)]
+            pub struct #struct_name(pub #inner_type #extended_field_tokens);
         };
 
         Ok(struct_tokens)
@@ -73,13 +78,40 @@ impl Asn1ResolvedInteger {
         Ok(generator.to_type_ident(&unique_name))
     }
 
-    fn get_min_max_constraints(&self) -> (Option<i128>, Option<i128>) {
-        if self.resolved_constraints.is_none() {
-            (None, None)
+    // Whether the Type is generated with an `asn1_codecs::BigInt` value.
+    pub(crate) fn is_big_integer(&self, generator: &Generator) -> bool {
+        let (min, max) = self.get_min_max_constraints();
+        let unbounded = min.is_none() || max.is_none() || self.is_extensible();
+        generator.big_integers && unbounded
+    }
+
+    // The bit width and the signedness of the inner type.
+    //
+    // The inner type holds the values in the Extension Root. With `extended_values`, the values
+    // outside the Extension Root are not limited by the root constraints, so an extensible INTEGER
+    // is generated with a signed type at least 64 bits wide.
+    pub(crate) fn get_inner_bits_signed(&self, generator: &Generator) -> (u8, bool) {
+        if !(generator.extended_values && self.is_extensible()) {
+            return (self.bits, self.signed);
+        }
+        if self.signed {
+            (std::cmp::max(self.bits, 64), true)
+        } else if self.bits < 64 {
+            (64, true)
         } else {
-            let constraints = self.resolved_constraints.as_ref().unwrap();
+            (128, true)
+        }
+    }
 
-            (constraints.root_values.min(), constraints.root_values.max())
+    pub(crate) fn is_extensible(&self) -> bool {
+        self.resolved_constraints.is_some()
+            && self.resolved_constraints.as_ref().unwrap().has_extension()
+    }
+
+    fn get_min_max_constraints(&self) -> (Option<i128>, Option<i128>) {
+        match self.resolved_constraints {
+            None => (None, None),
+            Some(ref constraints) => (constraints.root_values.min(), constraints.root_values.max()),
         }
     }
 }
@@ -16,11 +16,17 @@ mod null;
 
 mod oid;
 
+mod any;
+
 use proc_macro2::{Ident, TokenStream};
+use quote::quote;
 
 use crate::error::Error;
+use crate::generator::visit::VisitedType;
 use crate::generator::Generator;
-use crate::resolver::asn::structs::types::base::ResolvedBaseType;
+use crate::resolver::asn::structs::types::base::{
+    ResolvedBaseType, ResolvedContents, ResolvedContentsEncoding,
+};
 
 impl ResolvedBaseType {
     pub(crate) fn generate_for_base_type(
@@ -37,6 +43,7 @@ impl ResolvedBaseType {
             ResolvedBaseType::CharacterString(ref c) => c.generate(name, generator),
             ResolvedBaseType::Null(ref n) => n.generate(name, generator),
             ResolvedBaseType::ObjectIdentifier(ref o) => o.generate(name, generator),
+            ResolvedBaseType::Any(ref a) => a.generate(name, generator),
         }
     }
 
@@ -44,7 +51,7 @@ impl ResolvedBaseType {
         &self,
         generator: &mut Generator,
     ) -> Result<Ident, Error> {
-        match self {
+        let ty_ident = match self {
             ResolvedBaseType::Integer(ref i) => i.generate_ident_and_aux_type(generator),
             ResolvedBaseType::Enum(ref e) => e.generate_ident_and_aux_type(generator),
             ResolvedBaseType::BitString(ref b) => b.generate_ident_and_aux_type(generator),
@@ -53,6 +60,78 @@ impl ResolvedBaseType {
             ResolvedBaseType::CharacterString(ref c) => c.generate_ident_and_aux_type(generator),
             ResolvedBaseType::Null(ref n) => n.generate_ident_and_aux_type(generator),
             ResolvedBaseType::ObjectIdentifier(ref o) => o.generate_ident_and_aux_type(generator),
+            ResolvedBaseType::Any(ref a) => a.generate_ident_and_aux_type(generator),
+        }?;
+        generator.add_visited_type(&ty_ident, VisitedType::Leaf);
+
+        Ok(ty_ident)
+    }
+
+    // Whether the generated Type has the second field that is set if the value is encoded as
+    // outside the Extension Root. (ie. `extended_values` is set and the Type has an extensible
+    // value or `SIZE` constraint.)
+    pub(crate) fn has_extended_field(&self, generator: &Generator) -> bool {
+        if !generator.extended_values {
+            return false;
+        }
+        match self {
+            ResolvedBaseType::Integer(ref i) => i.is_extensible(),
+            ResolvedBaseType::BitString(ref b) => {
+                b.size.as_ref().is_some_and(|s| s.has_extension())
+            }
+            ResolvedBaseType::OctetString(ref o) => {
+                o.size.as_ref().is_some_and(|s| s.has_extension())
+            }
+            ResolvedBaseType::CharacterString(ref c) => c.is_extensible(),
+            _ => false,
+        }
+    }
+}
+
+impl ResolvedContents {
+    // Generates the `decode_contained` and `from_contained` functions of the generated `OCTET
+    // STRING` (or `BIT STRING`) Type, that decode (encode) the contained value from (to) the
+    // contents. `bits` is set for a `BIT STRING`, `extended_field` is set if the Type has the
+    // field for the extension bit.
+    //
+    // Nothing is generated if the contained value uses unsupported Encoding Rules.
+    pub(crate) fn generate_contents_impl(
+        &self,
+        struct_name: &Ident,
+        bits: bool,
+        extended_field: bool,
+        generator: &Generator,
+    ) -> TokenStream {
+        let rules = match self.encoding {
+            ResolvedContentsEncoding::Aper => quote! { asn1_codecs::aper::Aper },
+            ResolvedContentsEncoding::CanonicalAper => quote! { asn1_codecs::aper::CanonicalAper },
+            ResolvedContentsEncoding::Unsupported(_) => return TokenStream::new(),
+        };
+        let contained = generator.to_type_ident(&self.ty);
+        let error = quote! { asn1_codecs::aper::AperCodecError };
+        let (contents, from_bytes) = if bits {
+            (
+                quote! { self.0.as_raw_slice() },
+                quote! { BitVec::from_vec(bytes) },
+            )
+        } else {
+            (quote! { &self.0 }, quote! { bytes })
+        };
+        let extended_value = generator.extended_field_value_tokens(extended_field);
+
+        quote! {
+            impl #struct_name {
+                pub fn decode_contained(&self) -> Result<#contained, #error> {
+                    let (value, _) =
+                        <#contained as asn1_codecs::Decode<#rules>>::from_bytes(#contents)?;
+                    Ok(value)
+                }
+
+                pub fn from_contained(value: &#contained) -> Result<Self, #error> {
+                    let bytes = <#contained as asn1_codecs::Encode<#rules>>::to_bytes(value)?;
+                    Ok(Self(#from_bytes #extended_value))
+                }
+            }
         }
     }
 }
@@ -14,9 +14,11 @@ impl Asn1ResolvedNull {
         generator: &mut Generator,
     ) -> Result<TokenStream, Error> {
         let type_name = generator.to_type_ident(name);
+        let tag_tokens = generator.tag_tokens(name);
+        let derive_tokens = generator.derive_tokens();
         Ok(quote! {
-            #[derive(Debug, AperCodec)]
-            #[asn(type = "NULL")]
+            #derive_tokens
+            #[asn(type = "NULL" #tag_tokens)]
             pub struct #type_name;
         })
     }
@@ -14,9 +14,11 @@ impl Asn1ResolvedObjectIdentifier {
         generator: &mut Generator,
     ) -> Result<TokenStream, Error> {
         let type_name = generator.to_type_ident(name);
+        let tag_tokens = generator.tag_tokens(name);
+        let derive_tokens = generator.derive_tokens();
         Ok(quote! {
-            #[derive(Debug, AperCodec)]
-            #[asn(type = "OBJECT-IDENTIFIER")]
+            #derive_tokens
+            #[asn(type = "OBJECT-IDENTIFIER" #tag_tokens)]
             pub struct #type_name;
         })
     }
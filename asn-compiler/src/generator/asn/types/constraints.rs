@@ -1,9 +1,12 @@
 //! Utilities related to genrating Type/Field attributes based on Type Constraints.
 
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 
-use crate::resolver::asn::structs::types::constraints::Asn1ConstraintValueSet;
+use crate::generator::Generator;
+use crate::resolver::asn::structs::types::{
+    constraints::Asn1ConstraintValueSet, constructed::ResolvedIntegerValues,
+};
 
 impl Asn1ConstraintValueSet {
     pub(crate) fn get_ty_size_constraints_attrs(&self) -> TokenStream {
@@ -26,3 +29,30 @@ impl Asn1ConstraintValueSet {
         ty_attributes
     }
 }
+
+impl ResolvedIntegerValues {
+    // Attributes (`<prefix>_lb` and `<prefix>_ub`) for checking the values allowed by an Inner
+    // Type Constraint, and `<prefix>_big` if the values are arbitrary precision `INTEGER`s.
+    pub(crate) fn get_inner_constraint_attrs(
+        &self,
+        prefix: &str,
+        generator: &Generator,
+    ) -> Vec<TokenStream> {
+        let mut attrs = vec![];
+        if let Some(min) = self.values.root_values.min() {
+            let attr = format_ident!("{}_lb", prefix);
+            let lb = format!("{}", min);
+            attrs.push(quote! { #attr = #lb });
+        }
+        if let Some(max) = self.values.root_values.max() {
+            let attr = format_ident!("{}_ub", prefix);
+            let ub = format!("{}", max);
+            attrs.push(quote! { #attr = #ub });
+        }
+        if !attrs.is_empty() && self.ty.is_big_integer(generator) {
+            let attr = format_ident!("{}_big", prefix);
+            attrs.push(quote! { #attr = true });
+        }
+        attrs
+    }
+}
@@ -4,6 +4,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 use crate::error::Error;
+use crate::generator::visit::VisitedType;
 use crate::generator::Generator;
 use crate::resolver::asn::structs::types::{
     constructed::{ResolvedComponent, ResolvedConstructedType},
@@ -15,6 +16,8 @@ struct ChoiceComponentToken {
     variant: Ident,
     ty: Ident,
     key: i128,
+    tag: Option<String>,
+    name_tokens: TokenStream,
 }
 
 impl ResolvedConstructedType {
@@ -39,20 +42,41 @@ impl ResolvedConstructedType {
                 ResolvedConstructedType::get_component_tokens(root_components, name, generator)?;
 
             // Get all the required information from the additions if present
-            let addition_tokens = if additions.is_some() {
-                let additions = additions.as_ref().unwrap();
+            let addition_tokens = if let Some(additions) = &additions {
                 let addition_tokens =
-                    ResolvedConstructedType::get_component_tokens(&additions, name, generator)?;
+                    ResolvedConstructedType::get_component_tokens(additions, name, generator)?;
                 Some(addition_tokens)
             } else {
                 None
             };
 
+            let variants = root_tokens
+                .iter()
+                .chain(addition_tokens.iter().flatten())
+                .map(|t| (t.variant.clone(), t.ty.clone()))
+                .collect();
+            generator.add_visited_type(
+                &type_name,
+                VisitedType::Choice {
+                    variants,
+                    unknown_extensions: addition_tokens.is_some(),
+                },
+            );
+
+            let all_components = root_components
+                .iter()
+                .chain(additions.iter().flatten())
+                .collect::<Vec<_>>();
+            let peek_key = ResolvedConstructedType::get_choice_peek_key(&all_components, generator);
+
             let struct_tokens =
                 ResolvedConstructedType::generate_struct_tokens_for_asn_choice_type(
                     &type_name,
                     &root_tokens,
                     &addition_tokens,
+                    peek_key,
+                    generator.tag_tokens(name),
+                    generator.derive_tokens(),
                 )?;
 
             let _impl_tokens = ResolvedConstructedType::generate_impl_tokens_for_asn_choice_type(
@@ -70,10 +94,33 @@ impl ResolvedConstructedType {
         }
     }
 
+    // If all the components (including the Extension Additions) of the `CHOICE` are `SEQUENCE`s
+    // with the same 'key' field, returns the name of the 'key' field. (eg. `procedure_code` for
+    // `NGAP-PDU`.)
+    fn get_choice_peek_key(
+        components: &[&ResolvedComponent],
+        generator: &Generator,
+    ) -> Option<String> {
+        let mut key_fields = components.iter().map(|c| match c.ty {
+            Asn1ResolvedType::Reference(ref r) => generator.key_fields.get(r),
+            _ => None,
+        });
+
+        let first = key_fields.next()??;
+        if key_fields.all(|k| k == Some(first)) {
+            Some(generator.to_value_ident(first).to_string())
+        } else {
+            None
+        }
+    }
+
     fn generate_struct_tokens_for_asn_choice_type(
         type_name: &Ident,
         root_tokens: &[ChoiceComponentToken],
         addition_tokens: &Option<Vec<ChoiceComponentToken>>,
+        peek_key: Option<String>,
+        tag_tokens: TokenStream,
+        derive_tokens: TokenStream,
     ) -> Result<TokenStream, Error> {
         let mut root_comp_tokens = TokenStream::new();
         for token in root_tokens {
@@ -81,7 +128,9 @@ impl ResolvedConstructedType {
             let ty_ident = token.ty.clone();
             let key_token: TokenStream = format!("{}", token.key).parse().unwrap();
             let extension_token = quote! { false };
-            let field_attributes = quote! { #[asn(key = #key_token, extended = #extension_token)] };
+            let tag_token = token.tag.as_ref().map(|tag| quote! { , tag = #tag });
+            let name_tokens = &token.name_tokens;
+            let field_attributes = quote! { #[asn(key = #key_token, extended = #extension_token #tag_token #name_tokens)] };
             let comp_token = quote! {
                 #field_attributes
                 #variant_ident(#ty_ident),
@@ -93,15 +142,19 @@ impl ResolvedConstructedType {
             // Choice Additions
         };
 
-        if addition_tokens.is_some() {
-            let addition_tokens = addition_tokens.as_ref().unwrap();
+        if let Some(addition_tokens) = &addition_tokens {
+            addition_comp_tokens.extend(quote! {
+                #[asn(unknown_extensions = true)]
+                UnknownExtension(asn1_codecs::aper::UnknownExtension),
+            });
             for token in addition_tokens {
                 let variant_ident = token.variant.clone();
                 let ty_ident = token.ty.clone();
                 let key_token: TokenStream = format!("{}", token.key).parse().unwrap();
                 let extension_token = quote! { true };
-                let field_attributes =
-                    quote! { #[asn(key = #key_token, extended = #extension_token)] };
+                let tag_token = token.tag.as_ref().map(|tag| quote! { , tag = #tag });
+                let name_tokens = &token.name_tokens;
+                let field_attributes = quote! { #[asn(key = #key_token, extended = #extension_token #tag_token #name_tokens)] };
                 let comp_token = quote! {
                     #field_attributes
                     #variant_ident(#ty_ident),
@@ -121,11 +174,15 @@ impl ResolvedConstructedType {
             quote! { false }
         };
         let additions = quote! { extensible = #additions };
+        let peek_key = match peek_key {
+            Some(peek_key) => quote! { , peek_key = #peek_key },
+            None => quote! {},
+        };
 
-        let ty_attributes = quote! { #[asn(type = "CHOICE", #lb_token, #ub_token, #additions)] };
+        let ty_attributes = quote! { #[asn(type = "CHOICE", #lb_token, #ub_token, #additions #peek_key #tag_tokens)] };
 
         Ok(quote! {
-            #[derive(Debug, AperCodec)]
+            #derive_tokens
             #ty_attributes
             pub enum #type_name {
                 #root_comp_tokens
@@ -150,11 +207,11 @@ impl ResolvedConstructedType {
         };
 
         let root_comp_tokens =
-            ResolvedConstructedType::choice_component_impl_decode_aper_tokens(&root_tokens)?;
+            ResolvedConstructedType::choice_component_impl_decode_aper_tokens(root_tokens)?;
 
         let additional_comps_tokens = if addition_tokens.is_some() {
             ResolvedConstructedType::choice_component_impl_decode_aper_tokens(
-                &addition_tokens.as_ref().unwrap(),
+                addition_tokens.as_ref().unwrap(),
             )?
         } else {
             quote! { _ => Err(AperCodecError) }
@@ -220,6 +277,8 @@ impl ResolvedConstructedType {
                 variant: comp_variant_ident,
                 ty: comp_variant_ty_ident,
                 key: i as i128,
+                tag: c.tag.map(|t| t.to_string()),
+                name_tokens: generator.name_tokens(&c.id),
             });
         }
         Ok(out_components)
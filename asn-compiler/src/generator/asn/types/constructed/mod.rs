@@ -17,16 +17,18 @@ impl ResolvedConstructedType {
     // The main `generate` function for the constucted types
     //
     // This function simply calls the appropriate `generate_{sequence|choice|sequence_of}` function
-    // for therespective types.
+    // for therespective types. `SET` and `SET OF` are generated like `SEQUENCE` and `SEQUENCE OF`.
     pub(crate) fn generate(
         &self,
         name: &str,
         generator: &mut Generator,
     ) -> Result<TokenStream, Error> {
         match self {
-            ResolvedConstructedType::Sequence { .. } => self.generate_sequence(name, generator),
+            ResolvedConstructedType::Sequence { .. } | ResolvedConstructedType::Set { .. } => {
+                self.generate_sequence(name, generator)
+            }
             ResolvedConstructedType::Choice { .. } => self.generate_choice(name, generator),
-            ResolvedConstructedType::SequenceOf { .. } => {
+            ResolvedConstructedType::SequenceOf { .. } | ResolvedConstructedType::SetOf { .. } => {
                 self.generate_sequence_of(name, generator)
             }
         }
@@ -73,6 +75,14 @@ impl ResolvedConstructedType {
                 Some(ref inp) => inp.to_string(),
                 None => generator.get_unique_name("SeqOf"),
             },
+            ResolvedConstructedType::Set { .. } => match input {
+                Some(ref inp) => inp.to_string(),
+                None => generator.get_unique_name("Set"),
+            },
+            ResolvedConstructedType::SetOf { .. } => match input {
+                Some(ref inp) => inp.to_string(),
+                None => generator.get_unique_name("SetOf"),
+            },
         };
 
         let generated_type = self.generate(&unique_name, generator)?;
@@ -1,14 +1,216 @@
-//! Implementation of Code Generation for ASN.1 `SEQUENCE` Type.
+//! Implementation of Code Generation for ASN.1 `SEQUENCE` and `SET` Types.
 
-use proc_macro2::TokenStream;
-use quote::quote;
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
 
 use crate::error::Error;
+use crate::generator::visit::VisitedType;
 use crate::generator::Generator;
 use crate::resolver::asn::structs::types::{
-    constructed::ResolvedConstructedType, Asn1ResolvedType,
+    constructed::{
+        ClassFieldComponentType, ResolvedConstructedType, ResolvedPresence, ResolvedSeqAddition,
+        ResolvedSeqComponent,
+    },
+    Asn1ResolvedType,
 };
 
+// Fields of the generated `struct` for a `SEQUENCE` (or a `SET`) and the items needed for
+// generating the `impl`s of the `struct`.
+#[derive(Default)]
+struct SequenceFields {
+    comp_tokens: TokenStream,
+    optional_fields: usize,
+    visited_fields: Vec<(Ident, Ident, bool)>,
+    default_fn_tokens: TokenStream,
+}
+
+impl SequenceFields {
+    // Adds a field for the component. If `extension_idx` is `Some`, the component is an
+    // Extension Addition with the given index and the field is either an `Option` or holds the
+    // default value if the component is absent.
+    fn add_component(
+        &mut self,
+        c: &ResolvedSeqComponent,
+        name: &str,
+        type_name: &Ident,
+        extension_idx: Option<usize>,
+        generator: &mut Generator,
+    ) -> Result<(), Error> {
+        let comp_field_ident = generator.to_value_ident(&c.component.id);
+        let comp_ty_suffix = generator.to_type_ident(&c.component.id);
+        let input_comp_ty_ident = format!("{}{}", name, comp_ty_suffix);
+        let comp_ty_ident = Asn1ResolvedType::generate_name_maybe_aux_type(
+            &c.component.ty,
+            generator,
+            Some(&input_comp_ty_ident),
+        )?;
+
+        // The default value of a `DEFAULT` component is returned by a function of the
+        // generated Type (eg. `Foo::bar_default`).
+        let default_fn = match c.default {
+            Some(ref value) => {
+                let fn_ident = format_ident!("{}_default", comp_field_ident);
+                let ctor_ident = match c.component.ty {
+                    Asn1ResolvedType::Reference(ref reference) => {
+                        generator.to_constructor_ident(reference)
+                    }
+                    _ => comp_ty_ident.clone(),
+                };
+                let value_tokens = value.generate_value(&ctor_ident, generator)?;
+                self.default_fn_tokens.extend(quote! {
+                    pub fn #fn_ident() -> #comp_ty_ident {
+                        #value_tokens
+                    }
+                });
+                Some(quote! { #type_name::#fn_ident })
+            }
+            None => None,
+        };
+
+        // An Extension Addition may be absent, even if it is not `OPTIONAL`.
+        let optional = default_fn.is_none() && (c.optional || extension_idx.is_some());
+
+        let comp_ty_ident = match c.component.ty {
+            // Lazy Open Types are not decoded and hence not visited. An `ANY DEFINED BY`
+            // component is always a Lazy Open Type: it carries the raw bytes, which are decoded
+            // to the Type for the key only when required.
+            Asn1ResolvedType::Set(_)
+                if generator.lazy_open_types
+                    || matches!(
+                        c.class_field_type,
+                        Some(ClassFieldComponentType::AnyDefinedBy)
+                    ) =>
+            {
+                quote! { asn1_codecs::aper::LazyOpenType<#comp_ty_ident> }
+            }
+            _ => {
+                self.visited_fields.push((
+                    comp_field_ident.clone(),
+                    comp_ty_ident.clone(),
+                    optional,
+                ));
+                quote! { #comp_ty_ident }
+            }
+        };
+        let mut fld_attrs = vec![];
+        if let Some(ref tag) = c.component.tag {
+            let tag = tag.to_string();
+            fld_attrs.push(quote! { tag = #tag });
+        }
+
+        // The ASN.1 identifier of the component, for the `Asn1ValueNotation` derive.
+        if generator.value_notation {
+            let id = &c.component.id;
+            fld_attrs.push(quote! { name = #id });
+        }
+
+        // An Extension Addition is not in the preamble of the `SEQUENCE`, so it does not have an
+        // `optional_idx`.
+        if let Some(extension_idx) = extension_idx {
+            let idx: proc_macro2::TokenStream = format!("{}", extension_idx).parse().unwrap();
+            fld_attrs.push(quote! { extension_idx = #idx });
+        }
+
+        // A `DEFAULT` component is encoded like an `OPTIONAL` component, but the field
+        // holds the default value if the component is absent.
+        let fld_tokens = if let Some(default_fn) = default_fn {
+            let default_fn = default_fn.to_string().replace(' ', "");
+            if extension_idx.is_none() {
+                let idx: proc_macro2::TokenStream =
+                    format!("{}", self.optional_fields).parse().unwrap();
+                fld_attrs.push(quote! { optional_idx = #idx });
+
+                self.optional_fields += 1;
+            }
+            fld_attrs.push(quote! { default = #default_fn });
+
+            quote! { pub #comp_field_ident: #comp_ty_ident, }
+        } else if optional {
+            if extension_idx.is_none() {
+                let idx: proc_macro2::TokenStream =
+                    format!("{}", self.optional_fields).parse().unwrap();
+                fld_attrs.push(quote! { optional_idx = #idx });
+
+                self.optional_fields += 1;
+            }
+
+            quote! { pub #comp_field_ident: Option<#comp_ty_ident>, }
+        } else {
+            quote! { pub #comp_field_ident: #comp_ty_ident, }
+        };
+
+        if c.key_field {
+            fld_attrs.push(quote! { key_field = true })
+        }
+
+        // See `ResolvedComponentConstraints`.
+        match c.constraints.presence {
+            Some(ResolvedPresence::Present) => fld_attrs.push(quote! { presence = "PRESENT" }),
+            Some(ResolvedPresence::Absent) => fld_attrs.push(quote! { presence = "ABSENT" }),
+            None => {}
+        }
+        if let Some(ref values) = c.constraints.values {
+            fld_attrs.extend(values.get_inner_constraint_attrs("value", generator));
+        }
+
+        let fld_attr_tokens = if !fld_attrs.is_empty() {
+            quote! { #[asn(#(#fld_attrs),*)] }
+        } else {
+            quote! {}
+        };
+
+        self.comp_tokens.extend(quote! {
+            #fld_attr_tokens #fld_tokens
+        });
+
+        Ok(())
+    }
+
+    // Adds an `Option` field for the Extension Addition Group. The components of the group are
+    // generated as a separate `SEQUENCE` Type (eg. `FooExtGroup1`), because the group is encoded
+    // as a single Open Type.
+    fn add_group(
+        &mut self,
+        components: &[ResolvedSeqComponent],
+        version: Option<&String>,
+        group_number: usize,
+        name: &str,
+        extension_idx: usize,
+        generator: &mut Generator,
+    ) -> Result<(), Error> {
+        let group_name = format!("{}ExtGroup{}", name, group_number);
+        let group = ResolvedConstructedType::Sequence {
+            name: None,
+            extensible: false,
+            components: components.to_vec(),
+            additions: vec![],
+        };
+        let group_tokens = group.generate_sequence(&group_name, generator)?;
+        generator.aux_items.push(group_tokens);
+
+        let group_ty_ident = generator.to_type_ident(&group_name);
+        let group_field_ident = format_ident!("ext_group_{}", group_number);
+
+        self.visited_fields
+            .push((group_field_ident.clone(), group_ty_ident.clone(), true));
+
+        let idx: proc_macro2::TokenStream = format!("{}", extension_idx).parse().unwrap();
+        let fld_attr_tokens = match version {
+            Some(version) => {
+                let version: proc_macro2::TokenStream = version.parse().unwrap();
+                quote! { #[asn(extension_idx = #idx, version = #version)] }
+            }
+            None => quote! { #[asn(extension_idx = #idx)] },
+        };
+        self.comp_tokens.extend(quote! {
+            #fld_attr_tokens
+            pub #group_field_ident: Option<#group_ty_ident>,
+        });
+
+        Ok(())
+    }
+}
+
 impl ResolvedConstructedType {
     pub(crate) fn generate_sequence(
         &self,
@@ -17,59 +219,78 @@ impl ResolvedConstructedType {
     ) -> Result<TokenStream, Error> {
         if let ResolvedConstructedType::Sequence {
             ref components,
+            ref additions,
+            ref extensible,
+            ..
+        }
+        | ResolvedConstructedType::Set {
+            ref components,
+            ref additions,
             ref extensible,
             ..
         } = self
         {
             let type_name = generator.to_type_ident(name);
+            let asn_type = match self {
+                ResolvedConstructedType::Set { .. } => "SET",
+                _ => "SEQUENCE",
+            };
 
+            let unknown_extensions = *extensible;
             let extensible = if *extensible {
                 quote! { true }
             } else {
                 quote! { false }
             };
 
-            let mut comp_tokens = TokenStream::new();
-            let mut optional_fields = 0;
+            let mut fields = SequenceFields::default();
             for c in components {
-                let comp_field_ident = generator.to_value_ident(&c.component.id);
-                let comp_ty_suffix = generator.to_type_ident(&c.component.id);
-                let input_comp_ty_ident = format!("{}{}", name, comp_ty_suffix);
-                let comp_ty_ident = Asn1ResolvedType::generate_name_maybe_aux_type(
-                    &c.component.ty,
-                    generator,
-                    Some(&input_comp_ty_ident),
-                )?;
-                let mut fld_attrs = vec![];
-
-                let fld_tokens = if c.optional {
-                    let idx: proc_macro2::TokenStream =
-                        format!("{}", optional_fields).parse().unwrap();
-                    fld_attrs.push(quote! { optional_idx = #idx,  });
-
-                    optional_fields += 1;
-
-                    quote! { pub #comp_field_ident: Option<#comp_ty_ident>, }
-                } else {
-                    quote! { pub #comp_field_ident: #comp_ty_ident, }
-                };
+                fields.add_component(c, name, &type_name, None, generator)?;
+            }
 
-                if c.key_field {
-                    fld_attrs.push(quote! { key_field = true })
+            // Each Extension Addition (a single component or an Extension Addition Group) has
+            // an index in the Extension Additions bitmap.
+            let mut group_number = 0;
+            for (idx, addition) in additions.iter().enumerate() {
+                match addition {
+                    ResolvedSeqAddition::Component(c) => {
+                        fields.add_component(c, name, &type_name, Some(idx), generator)?;
+                    }
+                    ResolvedSeqAddition::Group {
+                        components,
+                        version,
+                    } => {
+                        group_number += 1;
+                        fields.add_group(
+                            components,
+                            version.as_ref(),
+                            group_number,
+                            name,
+                            idx,
+                            generator,
+                        )?;
+                    }
                 }
+            }
 
-                let fld_attr_tokens = if !fld_attrs.is_empty() {
-                    quote! { #[asn(#(#fld_attrs),*)] }
-                } else {
-                    quote! {}
-                };
+            let SequenceFields {
+                mut comp_tokens,
+                optional_fields,
+                visited_fields,
+                default_fn_tokens,
+            } = fields;
 
+            // Extension Additions are retained so that they are re-encoded as they were decoded.
+            if unknown_extensions {
                 comp_tokens.extend(quote! {
-                    #fld_attr_tokens #fld_tokens
+                    #[asn(unknown_extensions = true)]
+                    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
                 });
             }
 
-            let mut ty_tokens = quote! { type = "SEQUENCE", extensible = #extensible };
+            generator.add_visited_type(&type_name, VisitedType::Sequence(visited_fields));
+
+            let mut ty_tokens = quote! { type = #asn_type, extensible = #extensible };
 
             if optional_fields > 0 {
                 let optflds: proc_macro2::TokenStream =
@@ -77,12 +298,26 @@ impl ResolvedConstructedType {
                 ty_tokens.extend(quote! { , optional_fields = #optflds });
             }
 
+            let default_tokens = if default_fn_tokens.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    impl #type_name {
+                        #default_fn_tokens
+                    }
+                }
+            };
+
+            let tag_tokens = generator.tag_tokens(name);
+            let derive_tokens = generator.derive_tokens();
             Ok(quote! {
-                #[derive(Debug, AperCodec)]
-                #[asn(#ty_tokens)]
+                #derive_tokens
+                #[asn(#ty_tokens #tag_tokens)]
                 pub struct #type_name {
                     #comp_tokens
                 }
+
+                #default_tokens
             })
         } else {
             Ok(TokenStream::new())
@@ -4,6 +4,7 @@ use proc_macro2::TokenStream;
 use quote::quote;
 
 use crate::error::Error;
+use crate::generator::visit::VisitedType;
 use crate::generator::Generator;
 use crate::resolver::asn::structs::types::{
     constructed::ResolvedConstructedType, Asn1ResolvedType,
@@ -18,13 +19,24 @@ impl ResolvedConstructedType {
         if let ResolvedConstructedType::SequenceOf {
             ref ty,
             ref size_values,
+            ref entry_values,
+            ..
+        }
+        | ResolvedConstructedType::SetOf {
+            ref ty,
+            ref size_values,
+            ref entry_values,
             ..
         } = self
         {
             let seq_of_type_ident = generator.to_type_ident(name);
+            let asn_type = match self {
+                ResolvedConstructedType::SetOf { .. } => "SET-OF",
+                _ => "SEQUENCE-OF",
+            };
             let input_type_name = format!("{}_Entry", name);
 
-            let mut ty_attrs = quote! { type = "SEQUENCE-OF" };
+            let mut ty_attrs = quote! { type = #asn_type };
             if size_values.is_some() {
                 ty_attrs.extend(
                     size_values
@@ -34,15 +46,28 @@ impl ResolvedConstructedType {
                 )
             }
 
+            // Values of the entries allowed by an Inner Type Constraint (`WITH COMPONENT`).
+            if let Some(entry_values) = entry_values {
+                for attr in entry_values.get_inner_constraint_attrs("entry", generator) {
+                    ty_attrs.extend(quote! { , #attr });
+                }
+            }
+
             let seq_of_type = Asn1ResolvedType::generate_name_maybe_aux_type(
                 ty,
                 generator,
                 Some(&input_type_name),
             )?;
+            generator.add_visited_type(
+                &seq_of_type_ident,
+                VisitedType::SequenceOf(seq_of_type.clone()),
+            );
 
+            let tag_tokens = generator.tag_tokens(name);
+            let derive_tokens = generator.derive_tokens();
             Ok(quote! {
-                #[derive(Debug, AperCodec)]
-                #[asn(#ty_attrs)]
+                #derive_tokens
+                #[asn(#ty_attrs #tag_tokens)]
                 pub struct #seq_of_type_ident(pub Vec<#seq_of_type>);
             })
         } else {
@@ -5,6 +5,7 @@ use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
 use crate::error::Error;
+use crate::generator::visit::VisitedType;
 use crate::generator::Generator;
 use crate::resolver::asn::structs::types::{Asn1ResolvedType, ResolvedSetType};
 
@@ -15,7 +16,11 @@ impl Asn1ResolvedType {
         gen: &mut Generator,
     ) -> Result<Option<TokenStream>, Error> {
         match ty {
-            Asn1ResolvedType::Base(ref b) => Ok(Some(b.generate_for_base_type(name, gen)?)),
+            Asn1ResolvedType::Base(ref b) => {
+                let ty_ident = gen.to_type_ident(name);
+                gen.add_visited_type(&ty_ident, VisitedType::Leaf);
+                Ok(Some(b.generate_for_base_type(name, gen)?))
+            }
             Asn1ResolvedType::Constructed(ref c) => Ok(Some(c.generate(name, gen)?)),
             Asn1ResolvedType::Set(ref s) => Ok(Some(s.generate(name, gen)?)),
             Asn1ResolvedType::Reference(ref reference) => Ok(Some(
@@ -57,6 +62,7 @@ impl Asn1ResolvedType {
     ) -> Result<TokenStream, Error> {
         let referring = gen.to_type_ident(name);
         let reference = gen.to_type_ident(reference);
+        gen.add_visited_alias(&referring, &reference);
 
         Ok(quote! {
             pub type #referring = #reference;
@@ -71,10 +77,11 @@ impl ResolvedSetType {
         generator: &mut Generator,
     ) -> Result<TokenStream, Error> {
         let ty_ident = generator.to_type_ident(name);
-        let ty_elements = self.generate_aux_types(generator)?;
+        let ty_elements = self.generate_aux_types(&ty_ident, generator)?;
 
+        let derive_tokens = generator.derive_tokens();
         Ok(quote! {
-            #[derive(Debug, AperCodec)]
+            #derive_tokens
             pub enum #ty_ident {
                 #ty_elements
             }
@@ -89,12 +96,13 @@ impl ResolvedSetType {
         // FIXME: This is perhaps not right
         let ty_ident = match input {
             None => generator.to_type_ident(&self.setref),
-            Some(ref inp) => generator.to_type_ident(inp),
+            Some(inp) => generator.to_type_ident(inp),
         };
-        let ty_elements = self.generate_aux_types(generator)?;
+        let ty_elements = self.generate_aux_types(&ty_ident, generator)?;
 
+        let derive_tokens = generator.derive_tokens();
         let set_ty = quote! {
-            #[derive(Debug, AperCodec)]
+            #derive_tokens
             #[asn(type = "OPEN")]
             pub enum #ty_ident {
                 #ty_elements
@@ -106,22 +114,42 @@ impl ResolvedSetType {
         Ok(ty_ident)
     }
 
-    fn generate_aux_types(&self, generator: &mut Generator) -> Result<TokenStream, Error> {
+    fn generate_aux_types(
+        &self,
+        ty_ident: &Ident,
+        generator: &mut Generator,
+    ) -> Result<TokenStream, Error> {
         let mut variant_tokens = TokenStream::new();
+        let mut variants = vec![];
         for (name, ty) in &self.types {
             let variant_ident = generator.to_type_ident(&name.0);
-            let ty_ident = Asn1ResolvedType::generate_name_maybe_aux_type(&ty.1, generator, None)?;
+            let variant_ty_ident =
+                Asn1ResolvedType::generate_name_maybe_aux_type(&ty.1, generator, None)?;
             let key: proc_macro2::TokenStream = ty.0.to_string().parse().unwrap();
+            // Open Type values are written using the name of the Type. (eg. `NGSetupRequest : { ... }`)
+            let ty_name = match ty.1 {
+                Asn1ResolvedType::Reference(ref reference) => reference.clone(),
+                _ => name.0.clone(),
+            };
+            let name_tokens = generator.name_tokens(&ty_name);
             let key_tokens = quote! {
-                #[asn(key = #key)]
+                #[asn(key = #key #name_tokens)]
             };
 
             let variant_token = quote! {
                 #key_tokens
-                #variant_ident(#ty_ident),
+                #variant_ident(#variant_ty_ident),
             };
             variant_tokens.extend(variant_token);
+            variants.push((variant_ident, variant_ty_ident));
         }
+        generator.add_visited_type(
+            ty_ident,
+            VisitedType::Choice {
+                variants,
+                unknown_extensions: false,
+            },
+        );
         Ok(variant_tokens)
     }
 }
@@ -0,0 +1,101 @@
+//! 'generator' code for the Resolved Values (eg. the `DEFAULT` values of `SEQUENCE` components.)
+
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+
+use crate::error::Error;
+
+use crate::generator::Generator;
+use crate::resolver::asn::structs::{
+    types::{base::ResolvedBaseType, Asn1ResolvedType},
+    values::{Asn1ResolvedValue, ResolvedBaseValue},
+};
+
+impl Asn1ResolvedValue {
+    // Generates an expression for the value of the generated Type `ty_ident` (eg. `Foo(1)`).
+    // `ty_ident` is the constructor of the Type and not a type alias (see
+    // `Generator::to_constructor_ident`).
+    pub(crate) fn generate_value(
+        &self,
+        ty_ident: &Ident,
+        generator: &Generator,
+    ) -> Result<TokenStream, Error> {
+        let base = self.get_base_value().ok_or_else(|| {
+            code_generate_error!("Value '{:#?}' is not a resolved Base Value.", self)
+        })?;
+
+        let inner = match base {
+            ResolvedBaseValue::Integer(ref i) => {
+                let big = match i.typeref {
+                    Asn1ResolvedType::Base(ResolvedBaseType::Integer(ref ty)) => {
+                        ty.is_big_integer(generator)
+                    }
+                    _ => false,
+                };
+                if big {
+                    let value = Literal::i128_suffixed(i.value);
+                    quote! { asn1_codecs::BigInt::from(#value) }
+                } else {
+                    let value = Literal::i128_unsuffixed(i.value);
+                    quote! { #value }
+                }
+            }
+            ResolvedBaseValue::Enum(ref e) => {
+                let name = match e.typeref {
+                    Asn1ResolvedType::Base(ResolvedBaseType::Enum(ref ty)) => {
+                        let ext_value_base = ty.named_root_values.len() as i128;
+                        ty.named_root_values
+                            .iter()
+                            .find(|(_, v)| *v == e.value as i128)
+                            .or_else(|| {
+                                ty.named_ext_values
+                                    .iter()
+                                    .find(|(_, v)| ext_value_base + *v == e.value as i128)
+                            })
+                            .map(|(name, _)| name)
+                    }
+                    _ => None,
+                };
+                let name = name.ok_or_else(|| {
+                    code_generate_error!("Value '{}' is not a named value.", e.value)
+                })?;
+                let const_ident = generator.to_const_ident(name);
+                quote! { #ty_ident::#const_ident }
+            }
+            ResolvedBaseValue::Boolean(ref b) => {
+                let value = b.value;
+                quote! { #value }
+            }
+            ResolvedBaseValue::OctetString(ref o) => {
+                let octets = o
+                    .value
+                    .iter()
+                    .map(|octet| format!("{:#04x}", octet).parse::<TokenStream>().unwrap());
+                quote! { vec![#(#octets),*] }
+            }
+            ResolvedBaseValue::BitString(ref b) => {
+                let bits = b.value.iter().map(|bit| Literal::u8_unsuffixed(*bit as u8));
+                quote! { bitvec::bitvec![u8, Msb0; #(#bits),*] }
+            }
+            ResolvedBaseValue::CharacterString(ref c) => {
+                let value = &c.value;
+                quote! { #value.to_string() }
+            }
+        };
+
+        // The value is not encoded as outside the Extension Root, if it is within the root.
+        let typeref = match base {
+            ResolvedBaseValue::Integer(ref i) => &i.typeref,
+            ResolvedBaseValue::Enum(ref e) => &e.typeref,
+            ResolvedBaseValue::Boolean(ref b) => &b.typeref,
+            ResolvedBaseValue::OctetString(ref o) => &o.typeref,
+            ResolvedBaseValue::BitString(ref b) => &b.typeref,
+            ResolvedBaseValue::CharacterString(ref c) => &c.typeref,
+        };
+        if matches!(typeref, Asn1ResolvedType::Base(ref ty) if ty.has_extended_field(generator)) {
+            Ok(quote! { #ty_ident(#inner, false) })
+        } else {
+            Ok(quote! { #ty_ident(#inner) })
+        }
+    }
+}
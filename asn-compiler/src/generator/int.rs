@@ -1,14 +1,19 @@
 //! Code Generation module
 
+use std::collections::HashMap;
+
 use heck::{ToShoutySnakeCase, ToSnakeCase};
 use proc_macro2::{Ident, Literal, Span, TokenStream};
 
 use quote::quote;
 
 use crate::error::Error;
+use crate::generator::visit::Visited;
 use crate::resolver::Resolver;
 
-use crate::resolver::asn::structs::types::Asn1ResolvedType;
+use crate::resolver::asn::structs::types::{
+    constructed::ResolvedConstructedType, Asn1ResolvedTag, Asn1ResolvedType,
+};
 
 #[derive(Debug)]
 pub(crate) struct Generator {
@@ -23,6 +28,36 @@ pub(crate) struct Generator {
 
     // Auxillary Items: These are structs/that are referenced inside constructed type.
     pub(crate) aux_items: Vec<TokenStream>,
+
+    // Generate Open Types that are decoded only on access.
+    pub(crate) lazy_open_types: bool,
+
+    // Generate `asn1_codecs::BigInt` for the INTEGER Types whose values are not bounded.
+    pub(crate) big_integers: bool,
+
+    // Retain the extension bit of the values of the Types with an extensible constraint.
+    pub(crate) extended_values: bool,
+
+    // Generate the `Visitor` and `VisitorMut` traits for the generated Types.
+    pub(crate) visitors: bool,
+
+    // Derive `Asn1Reflect` for the generated Types.
+    pub(crate) reflect: bool,
+
+    // Derive `Asn1ValueNotation` for the generated Types.
+    pub(crate) value_notation: bool,
+
+    // Names of the 'key' fields of the `SEQUENCE` Types (if they have a 'key' field).
+    pub(crate) key_fields: HashMap<String, String>,
+
+    // The referenced Types of the Types that are generated as type aliases.
+    pub(crate) type_aliases: HashMap<String, String>,
+
+    // Tags of the Tagged Type definitions.
+    pub(crate) type_tags: HashMap<String, Asn1ResolvedTag>,
+
+    // Generated Types and their children used for generating the `Visitor` traits.
+    pub(crate) visited: Visited,
 }
 
 impl Generator {
@@ -32,6 +67,16 @@ impl Generator {
             _name: name.to_string(),
             counter: 1,
             aux_items: vec![],
+            lazy_open_types: false,
+            big_integers: false,
+            extended_values: false,
+            visitors: false,
+            reflect: false,
+            value_notation: false,
+            key_fields: HashMap::new(),
+            type_aliases: HashMap::new(),
+            type_tags: HashMap::new(),
+            visited: Visited::default(),
         }
     }
 
@@ -43,6 +88,25 @@ impl Generator {
         let use_tokens = self.generate_use_tokens();
         self.items.push(use_tokens);
 
+        for (k, t) in resolver.get_resolved_types() {
+            match t {
+                Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+                    ref components,
+                    ..
+                }) => {
+                    if let Some(c) = components.iter().find(|c| c.key_field) {
+                        self.key_fields.insert(k.clone(), c.component.id.clone());
+                    }
+                }
+                Asn1ResolvedType::Reference(ref reference) => {
+                    self.type_aliases.insert(k.clone(), reference.clone());
+                }
+                _ => {}
+            }
+        }
+
+        self.type_tags.extend(resolver.tags.clone());
+
         let mut items = vec![];
         for (k, t) in resolver.get_resolved_types() {
             let item = Asn1ResolvedType::generate_for_type(k, t, self)?;
@@ -57,6 +121,11 @@ impl Generator {
 
         self.items.extend(items);
 
+        if self.visitors {
+            let visitor_tokens = self.generate_visitor_tokens();
+            self.items.push(visitor_tokens);
+        }
+
         Ok(self
             .items
             .iter()
@@ -91,7 +160,7 @@ impl Generator {
                 16 => quote!(u16),
                 32 => quote!(u32),
                 64 => quote!(u64),
-                _ => quote!(u64),
+                _ => quote!(u128),
             }
         } else {
             match bits {
@@ -99,7 +168,7 @@ impl Generator {
                 16 => quote!(i16),
                 32 => quote!(i32),
                 64 => quote!(i64),
-                _ => quote!(i64),
+                _ => quote!(i128),
             }
         }
     }
@@ -111,7 +180,7 @@ impl Generator {
                 16 => Literal::u16_suffixed(value as u16),
                 32 => Literal::u32_suffixed(value as u32),
                 64 => Literal::u64_suffixed(value as u64),
-                _ => Literal::u64_suffixed(value as u64),
+                _ => Literal::u128_suffixed(value as u128),
             }
         } else {
             match bits {
@@ -119,7 +188,7 @@ impl Generator {
                 16 => Literal::i16_suffixed(value as i16),
                 32 => Literal::i32_suffixed(value as i32),
                 64 => Literal::i64_suffixed(value as i64),
-                _ => Literal::i64_suffixed(value as i64),
+                _ => Literal::i128_suffixed(value),
             }
         }
     }
@@ -130,16 +199,98 @@ impl Generator {
         format!("{} {}", name, self.counter)
     }
 
+    // The `derive` attribute for the generated Types.
+    pub(crate) fn derive_tokens(&self) -> TokenStream {
+        let derives = self.derive_idents();
+        quote! { #[derive(Debug, PartialEq, #(#derives),*)] }
+    }
+
+    // The derive macros used for the generated Types.
+    fn derive_idents(&self) -> Vec<Ident> {
+        let mut derives = vec!["AperCodec"];
+        if self.reflect {
+            derives.push("Asn1Reflect");
+        }
+        if self.value_notation {
+            derives.push("Asn1ValueNotation");
+        }
+        derives
+            .into_iter()
+            .map(|derive| Ident::new(derive, Span::call_site()))
+            .collect()
+    }
+
+    // The `name` attribute (the ASN.1 identifier) of a component or an alternative, used by the
+    // `Asn1ValueNotation` derive.
+    pub(crate) fn name_tokens(&self, id: &str) -> TokenStream {
+        if self.value_notation {
+            quote! { , name = #id }
+        } else {
+            TokenStream::new()
+        }
+    }
+
+    // The second field of the generated Type, if the Type has an extensible value or `SIZE`
+    // constraint. The field retains the extension bit of a decoded value, so that the value is
+    // encoded as it was decoded.
+    pub(crate) fn extended_field_tokens(&self, extended_field: bool) -> TokenStream {
+        if extended_field {
+            quote! { , pub bool }
+        } else {
+            TokenStream::new()
+        }
+    }
+
+    // The value of the second field (if any) of the generated Type, for a value that is not
+    // decoded. (Such a value is encoded as outside the Extension Root only if it is not within
+    // the root.)
+    pub(crate) fn extended_field_value_tokens(&self, extended_field: bool) -> TokenStream {
+        if extended_field {
+            quote! { , false }
+        } else {
+            TokenStream::new()
+        }
+    }
+
+    // The generated Type for the Type `name` following the type aliases, since a type alias cannot
+    // be used as a tuple struct constructor.
+    pub(crate) fn to_constructor_ident(&self, name: &str) -> Ident {
+        let mut name = name;
+        while let Some(reference) = self.type_aliases.get(name) {
+            name = reference;
+        }
+        self.to_type_ident(name)
+    }
+
+    // The `tag` attribute for the Type, if the Type is a Tagged Type.
+    pub(crate) fn tag_tokens(&self, name: &str) -> TokenStream {
+        match self.type_tags.get(name) {
+            Some(tag) => {
+                let tag = tag.to_string();
+                quote! { , tag = #tag }
+            }
+            None => TokenStream::new(),
+        }
+    }
+
     fn generate_use_tokens(&self) -> TokenStream {
+        let derives = self.derive_idents();
+        let derive_use_tokens = if derives.len() > 1 {
+            quote! { use asn1_codecs_derive::{#(#derives),*}; }
+        } else {
+            quote! { use asn1_codecs_derive::AperCodec; }
+        };
+
         quote! {
             #![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+            #![allow(clippy::large_enum_variant, clippy::enum_variant_names, clippy::upper_case_acronyms)]
 
             use bitvec::vec::BitVec;
             use bitvec::order::Msb0;
 
             // FIXME: Do this based on the Codec to be supported, right now we are only supporting
             // APER Codec.
-            use asn1_codecs_derive::AperCodec;
+            #derive_use_tokens
         }
     }
 }
@@ -4,3 +4,5 @@ mod int;
 pub(crate) use int::Generator;
 
 mod asn;
+
+mod visit;
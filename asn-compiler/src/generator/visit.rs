@@ -0,0 +1,272 @@
+//! Generation of the `Visitor` and `VisitorMut` traits for the generated Types.
+//!
+//! While generating the Types, the Generator records the 'children' of every generated Type (the
+//! fields of a `SEQUENCE`, the variants of a `CHOICE` or an Open Type and the entries of a
+//! `SEQUENCE OF`). Once all the Types are generated, a `visit_<type>` method is generated for
+//! every Type, whose default implementation visits all the children of the Type.
+
+use std::collections::{BTreeMap, HashSet};
+
+use heck::ToSnakeCase;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+
+use crate::generator::Generator;
+
+// Children of a generated Type that are visited.
+#[derive(Debug)]
+pub(crate) enum VisitedType {
+    // Types without any children that are visited. (eg. INTEGER, OCTET STRING etc.)
+    Leaf,
+
+    // Fields of a `SEQUENCE`: (field, type, optional)
+    Sequence(Vec<(Ident, Ident, bool)>),
+
+    // Variants of a `CHOICE` or an Open Type: (variant, type). `unknown_extensions` is set if the
+    // type has a variant for the unknown Extension Additions.
+    Choice {
+        variants: Vec<(Ident, Ident)>,
+        unknown_extensions: bool,
+    },
+
+    // Type of the entries of a `SEQUENCE OF`.
+    SequenceOf(Ident),
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Visited {
+    // Generated Types (sorted by their names, so that the generated code is stable).
+    types: BTreeMap<String, (Ident, VisitedType)>,
+
+    // Type aliases generated for references.
+    aliases: BTreeMap<String, Ident>,
+}
+
+impl Generator {
+    pub(crate) fn add_visited_type(&mut self, ty: &Ident, visited: VisitedType) {
+        self.visited
+            .types
+            .insert(ty.to_string(), (ty.clone(), visited));
+    }
+
+    pub(crate) fn add_visited_alias(&mut self, alias: &Ident, ty: &Ident) {
+        self.visited.aliases.insert(alias.to_string(), ty.clone());
+    }
+
+    // Generates the `visit` and the `visit_mut` modules.
+    pub(crate) fn generate_visitor_tokens(&self) -> TokenStream {
+        if self.visited.types.is_empty() {
+            return TokenStream::new();
+        }
+
+        // Method names are derived from the type names. If two type names map to the same method
+        // name, the later one gets a suffix.
+        let mut used = HashSet::new();
+        let mut methods = BTreeMap::new();
+        for name in self.visited.types.keys() {
+            let base = name.to_snake_case();
+            let mut method = base.clone();
+            let mut suffix = 2;
+            while !used.insert(method.clone()) {
+                method = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+            methods.insert(name.clone(), method);
+        }
+
+        let mut visit_fns = TokenStream::new();
+        let mut walk_fns = TokenStream::new();
+        let mut visit_mut_fns = TokenStream::new();
+        let mut walk_mut_fns = TokenStream::new();
+
+        for (name, (ty, visited)) in &self.visited.types {
+            let method = &methods[name];
+            let visit = Ident::new(&format!("visit_{}", method), Span::call_site());
+            let walk = Ident::new(&format!("walk_{}", method), Span::call_site());
+            let visit_mut = Ident::new(&format!("visit_{}_mut", method), Span::call_site());
+            let walk_mut = Ident::new(&format!("walk_{}_mut", method), Span::call_site());
+
+            let (body, body_mut) = match self.generate_walk_body(ty, visited, &methods) {
+                Some(bodies) => bodies,
+                None => {
+                    visit_fns.extend(quote! {
+                        fn #visit(&mut self, _node: &#ty) {}
+                    });
+                    visit_mut_fns.extend(quote! {
+                        fn #visit_mut(&mut self, _node: &mut #ty) {}
+                    });
+                    continue;
+                }
+            };
+
+            visit_fns.extend(quote! {
+                fn #visit(&mut self, node: &#ty) {
+                    #walk(self, node)
+                }
+            });
+            walk_fns.extend(quote! {
+                pub fn #walk<V: Visitor + ?Sized>(visitor: &mut V, node: &#ty) {
+                    #body
+                }
+            });
+            visit_mut_fns.extend(quote! {
+                fn #visit_mut(&mut self, node: &mut #ty) {
+                    #walk_mut(self, node)
+                }
+            });
+            walk_mut_fns.extend(quote! {
+                pub fn #walk_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut #ty) {
+                    #body_mut
+                }
+            });
+        }
+
+        quote! {
+            /// Traversal of the generated Types by reference.
+            pub mod visit {
+                use super::*;
+
+                /// A `visit_*` method for every generated Type.
+                ///
+                /// The default implementation of a method visits all the children of the Type
+                /// using the corresponding `walk_*` function. Override the methods for the Types
+                /// of interest and call the `walk_*` function to continue visiting the children.
+                pub trait Visitor {
+                    #visit_fns
+                }
+
+                #walk_fns
+            }
+
+            /// Traversal of the generated Types by mutable reference.
+            pub mod visit_mut {
+                use super::*;
+
+                /// A `visit_*_mut` method for every generated Type.
+                ///
+                /// The default implementation of a method visits all the children of the Type
+                /// using the corresponding `walk_*_mut` function. Override the methods for the
+                /// Types of interest and call the `walk_*_mut` function to continue visiting the
+                /// children.
+                pub trait VisitorMut {
+                    #visit_mut_fns
+                }
+
+                #walk_mut_fns
+            }
+        }
+    }
+
+    // Returns the bodies of the `walk_*` and `walk_*_mut` functions for the Type or `None` if the
+    // Type does not have any children to visit.
+    fn generate_walk_body(
+        &self,
+        ty: &Ident,
+        visited: &VisitedType,
+        methods: &BTreeMap<String, String>,
+    ) -> Option<(TokenStream, TokenStream)> {
+        let mut body = TokenStream::new();
+        let mut body_mut = TokenStream::new();
+
+        match visited {
+            VisitedType::Leaf => return None,
+            VisitedType::Sequence(fields) => {
+                let mut visited_fields = 0;
+                for (field, field_ty, optional) in fields {
+                    let (visit, visit_mut) = match self.visit_idents(field_ty, methods) {
+                        Some(idents) => idents,
+                        None => continue,
+                    };
+                    visited_fields += 1;
+                    if *optional {
+                        body.extend(quote! {
+                            if let Some(ref value) = node.#field {
+                                visitor.#visit(value);
+                            }
+                        });
+                        body_mut.extend(quote! {
+                            if let Some(ref mut value) = node.#field {
+                                visitor.#visit_mut(value);
+                            }
+                        });
+                    } else {
+                        body.extend(quote! { visitor.#visit(&node.#field); });
+                        body_mut.extend(quote! { visitor.#visit_mut(&mut node.#field); });
+                    }
+                }
+                if visited_fields == 0 {
+                    return None;
+                }
+            }
+            VisitedType::Choice {
+                variants,
+                unknown_extensions,
+            } => {
+                // Open Types without any values in the Information Object Set are empty `enum`s.
+                if variants.is_empty() && !*unknown_extensions {
+                    return None;
+                }
+                let mut arms = TokenStream::new();
+                let mut arms_mut = TokenStream::new();
+                for (variant, variant_ty) in variants {
+                    match self.visit_idents(variant_ty, methods) {
+                        Some((visit, visit_mut)) => {
+                            arms.extend(quote! { #ty::#variant(value) => visitor.#visit(value), });
+                            arms_mut.extend(
+                                quote! { #ty::#variant(value) => visitor.#visit_mut(value), },
+                            );
+                        }
+                        None => {
+                            arms.extend(quote! { #ty::#variant(_) => {} });
+                            arms_mut.extend(quote! { #ty::#variant(_) => {} });
+                        }
+                    }
+                }
+                if *unknown_extensions {
+                    arms.extend(quote! { #ty::UnknownExtension(_) => {} });
+                    arms_mut.extend(quote! { #ty::UnknownExtension(_) => {} });
+                }
+                body.extend(quote! { match node { #arms } });
+                body_mut.extend(quote! { match node { #arms_mut } });
+            }
+            VisitedType::SequenceOf(entry_ty) => {
+                let (visit, visit_mut) = self.visit_idents(entry_ty, methods)?;
+                body.extend(quote! {
+                    for entry in &node.0 {
+                        visitor.#visit(entry);
+                    }
+                });
+                body_mut.extend(quote! {
+                    for entry in &mut node.0 {
+                        visitor.#visit_mut(entry);
+                    }
+                });
+            }
+        }
+
+        Some((body, body_mut))
+    }
+
+    // Returns the `visit_*` and `visit_*_mut` method identifiers for the Type (following the type
+    // aliases) or `None` if the Type is not a generated Type.
+    fn visit_idents(
+        &self,
+        ty: &Ident,
+        methods: &BTreeMap<String, String>,
+    ) -> Option<(Ident, Ident)> {
+        let mut name = ty.to_string();
+        let mut seen = HashSet::new();
+        while let Some(target) = self.visited.aliases.get(&name) {
+            if !seen.insert(name.clone()) {
+                return None;
+            }
+            name = target.to_string();
+        }
+
+        let method = methods.get(&name)?;
+        Some((
+            Ident::new(&format!("visit_{}", method), Span::call_site()),
+            Ident::new(&format!("visit_{}_mut", method), Span::call_site()),
+        ))
+    }
+}
@@ -47,7 +47,7 @@ impl Asn1Definition {
             match self.kind {
                 Asn1AssignmentKind::Object(ref mut o) => {
                     if let Asn1ObjectValue::Input(s) = &o.object.value {
-                        let parsed = parse_object_from_class(&s, &c.classref)?;
+                        let parsed = parse_object_from_class(s, &c.classref)?;
                         o.object.value = parsed;
                     }
                 }
@@ -72,16 +72,32 @@ impl Asn1Definition {
             ));
         }
 
-        for (idx, actual) in actual_params.iter().enumerate() {
-            let replace = actual.param_string();
-            let source = &params.ordered[idx].dummyref.name;
-
-            for token in params.type_tokens.iter_mut() {
-                if &token.text == source {
-                    token.text = replace.clone()
+        let mut type_tokens = vec![];
+        for (idx, token) in params.type_tokens.iter().enumerate() {
+            let dummy = params
+                .ordered
+                .iter()
+                .position(|p| p.dummyref.name == token.text);
+            match dummy {
+                // A Value Dummy Reference may have the same name as a component of the Type (eg.
+                // `Foo {INTEGER:size} ::= SEQUENCE { size INTEGER (0..size) }`), an identifier
+                // followed by a Type is a component identifier and is not replaced.
+                Some(_)
+                    if token.is_value_reference()
+                        && params.type_tokens.get(idx + 1).is_some_and(|next| {
+                            next.is_type_reference()
+                                || next.is_asn_builtin_type()
+                                || next.is_square_begin()
+                        }) =>
+                {
+                    type_tokens.push(token.clone())
                 }
+                Some(dummy) => type_tokens.extend(actual_params[dummy].param_tokens(token)),
+                None => type_tokens.push(token.clone()),
             }
         }
+        params.type_tokens = type_tokens;
+
         let (ty, _) = parse_type(&params.type_tokens)?;
         Ok(ty)
     }
@@ -475,6 +491,7 @@ fn parse_params(tokens: &[Token]) -> Result<(DefinitionParams, usize), Error> {
 mod tests {
 
     use super::*;
+    use crate::parser::asn::structs::types::{Asn1TypeKind, Asn1TypeReference};
     use crate::tokenizer::tokenize;
 
     #[test]
@@ -558,7 +575,34 @@ UE-RadioAccessCapability-LaterNonCriticalExtensions ::= SEQUENCE {
                 }
             );
         }
+    }
+
+    #[test]
+    fn apply_value_params_test() {
+        let input = r#"
+Foo {INTEGER:size, INTEGER:Values} ::= SEQUENCE {
+    size    INTEGER (0..size),
+    values  SEQUENCE (SIZE (Values)) OF BOOLEAN
+}
+"#;
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+        let (def, _) = parse_definition(&tokens).unwrap();
+
+        let reader = std::io::BufReader::new(std::io::Cursor::new("Foo {-5, {1 | maxFoo}}"));
+        let tokens = tokenize(reader).unwrap();
+        let (ty, _) = parse_type(&tokens).unwrap();
+        assert_eq!(ty.dependent_references(), vec!["Foo", "maxFoo"]);
+        let params = match ty.kind {
+            Asn1TypeKind::Reference(Asn1TypeReference::Parameterized { params, .. }) => params,
+            other => panic!("Expected a Parameterized Type, Found {:#?}", other),
+        };
 
-        assert!(true);
+        // The component `size` is not replaced, only it's constraint is.
+        let applied = format!("{:#?}", def.apply_params(&params).unwrap());
+        assert!(applied.contains("id: \"size\""), "{}", applied);
+        assert!(applied.contains("lower: \"0\""), "{}", applied);
+        assert!(applied.contains("upper: \"-5\""), "{}", applied);
+        assert!(applied.contains("value: \"maxFoo\""), "{}", applied);
     }
 }
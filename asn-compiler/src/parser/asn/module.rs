@@ -144,16 +144,15 @@ fn maybe_parse_header_tags(tokens: &[Token]) -> Result<(Asn1ModuleTag, usize), E
 
     let tag =
         if expect_one_of_keywords(&tokens[consumed..], &["EXPLICIT", "IMPLICIT", "AUTOMATIC"])? {
-            let tag: Asn1ModuleTag;
-            match tokens[consumed].text.as_str() {
-                "EXPLICIT" => tag = Asn1ModuleTag::Explicit,
-                "IMPLICIT" => tag = Asn1ModuleTag::Implicit,
-                "AUTOMATIC" => tag = Asn1ModuleTag::Automatic,
+            let tag = match tokens[consumed].text.as_str() {
+                "EXPLICIT" => Asn1ModuleTag::Explicit,
+                "IMPLICIT" => Asn1ModuleTag::Implicit,
+                "AUTOMATIC" => Asn1ModuleTag::Automatic,
                 _ => {
                     // Will never reach
                     return Err(parse_error!("Should Never Reach"));
                 }
-            }
+            };
             consumed += 1;
             if expect_keyword(&tokens[consumed..], "TAGS")? {
                 consumed += 1
@@ -222,8 +221,8 @@ mod tests {
         let tokens = tokenize(reader);
         assert!(tokens.is_ok());
 
-        let mut tokens = tokens.unwrap();
-        let module = parse_module(&mut tokens);
+        let tokens = tokens.unwrap();
+        let module = parse_module(&tokens);
         assert!(module.is_ok(), "{}: {:#?}", input, module.err());
 
         let (module, consumed) = module.unwrap();
@@ -231,7 +230,7 @@ mod tests {
 
         assert!(module.definitions.is_empty());
         assert!(module.imports.is_empty());
-        assert_eq!(module._tags, Asn1ModuleTag::Explicit);
+        assert_eq!(module.tags, Asn1ModuleTag::Explicit);
     }
     // TODO: Test Cases for imports (count), Tags (type), Definitions (count))
     // TODO: Test Cases for missing BEGIN, END, DEFINITIONS, ::=
@@ -286,8 +285,8 @@ mod tests {
             let tokens = tokenize(reader);
             assert!(tokens.is_ok());
 
-            let mut tokens = tokens.unwrap();
-            let module = parse_module_name(&mut tokens);
+            let tokens = tokens.unwrap();
+            let module = parse_module_name(&tokens);
             assert_eq!(module.is_ok(), tc.success, "{}", tc.input);
 
             if tc.success {
@@ -6,19 +6,14 @@ use topological_sort::TopologicalSort;
 
 use crate::parser::asn::structs::{defs::Asn1Definition, oid::ObjectIdentifier};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub enum Asn1ModuleTag {
+    #[default]
     Explicit,
     Implicit,
     Automatic,
 }
 
-impl Default for Asn1ModuleTag {
-    fn default() -> Self {
-        Self::Explicit
-    }
-}
-
 #[derive(Debug, Default, Clone)]
 pub struct Asn1ModuleName {
     pub(in crate::parser) name: String,
@@ -56,7 +51,7 @@ pub struct Asn1Module {
     pub(in crate::parser) imports: HashMap<String, Asn1ModuleName>,
     pub(in crate::parser) _exports: Option<Vec<Asn1Definition>>,
     pub(in crate::parser) name: Asn1ModuleName,
-    pub(in crate::parser) _tags: Asn1ModuleTag,
+    pub(in crate::parser) tags: Asn1ModuleTag,
     pub(in crate::parser) definitions: HashMap<String, Asn1Definition>,
     pub(in crate::parser) _exports_all: bool,
 }
@@ -66,8 +61,8 @@ impl Asn1Module {
         Self { name, ..self }
     }
 
-    pub fn tags(self, _tags: Asn1ModuleTag) -> Self {
-        Self { _tags, ..self }
+    pub fn tags(self, tags: Asn1ModuleTag) -> Self {
+        Self { tags, ..self }
     }
 
     pub fn imports(self, imports: HashMap<String, Asn1ModuleName>) -> Self {
@@ -93,7 +88,7 @@ impl Asn1Module {
 
         for (k, v) in self.definitions.iter() {
             for r in v.dependent_references() {
-                if self.imports.get(&r).is_none() {
+                if !self.imports.contains_key(&r) {
                     ts.add_dependency(r.clone(), k.clone());
                 }
             }
@@ -112,6 +107,12 @@ impl Asn1Module {
         out_vec
     }
 
+    // The tagging environment of the module. (`EXPLICIT`, `IMPLICIT` or `AUTOMATIC` `TAGS`.)
+    #[inline(always)]
+    pub(crate) fn get_tags(&self) -> Asn1ModuleTag {
+        self.tags
+    }
+
     #[inline(always)]
     pub(crate) fn get_module_name(&self) -> String {
         self.name.name.clone()
@@ -14,8 +14,8 @@ impl OIDComponent {
 
 impl std::fmt::Display for OIDComponent {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.name.is_some() {
-            write!(f, "{}({})", self.name.as_ref().unwrap(), self.number)
+        if let Some(ref name) = self.name {
+            write!(f, "{}({})", name, self.number)
         } else {
             write!(f, "{}", self.number)
         }
@@ -40,6 +40,11 @@ impl ObjectIdentifier {
     pub fn len(self) -> usize {
         self.components.len()
     }
+
+    /// Returns the numbers of the components of the Object Identifier (eg. `[2, 1, 3, 0, 0]`).
+    pub fn numbers(&self) -> Vec<u32> {
+        self.components.iter().map(|c| c.number).collect()
+    }
 }
 
 impl std::fmt::Display for ObjectIdentifier {
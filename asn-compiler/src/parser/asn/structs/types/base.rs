@@ -1,5 +1,6 @@
 //! Handling of Integer Type
 
+#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub(crate) enum NamedValue {
     Number(String),
@@ -18,6 +18,31 @@ pub(crate) enum SubtypeElements {
     },
     SizeConstraint(ElementSet),
     PermittedAlphabet(ElementSet),
+
+    // `WITH COMPONENT (Constraint)`: Constraint on the entries of a `SEQUENCE OF` (or a `SET OF`).
+    SingleTypeConstraint(Box<Asn1Constraint>),
+
+    // `WITH COMPONENTS { ... }`: Constraints on the components of a `SEQUENCE` (or a `SET`). In a
+    // partial specification (starting with `...`), the components not listed are not constrained.
+    MultipleTypeConstraints {
+        partial: bool,
+        constraints: Vec<NamedConstraint>,
+    },
+}
+
+// Constraint on a component listed in `WITH COMPONENTS`. (eg. `a (0..3) PRESENT`)
+#[derive(Debug, Clone)]
+pub(crate) struct NamedConstraint {
+    pub(crate) id: String,
+    pub(crate) value: Option<Asn1Constraint>,
+    pub(crate) presence: Option<PresenceConstraint>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PresenceConstraint {
+    Present,
+    Absent,
+    Optional,
 }
 
 #[derive(Debug, Clone)]
@@ -61,7 +86,7 @@ pub(crate) enum Asn1Constraint {
     Subtype(ElementSet),
     Table(TableConstraint),
     Contents {
-        _containing: String,                   // Reference to referenced type
-        _encoded_by: Option<ObjectIdentifier>, // Encoding Object Identifier (Right now always None)
+        containing: Option<String>,           // Reference to the contained type
+        encoded_by: Option<ObjectIdentifier>, // Encoding Object Identifier
     },
 }
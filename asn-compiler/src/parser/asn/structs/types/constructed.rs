@@ -19,7 +19,7 @@ impl Component {
 pub(crate) struct SeqComponent {
     pub(crate) component: Component,
     pub(crate) optional: bool,
-    pub(crate) _default: Option<String>, // FIXME: Should be Asn1Value later on
+    pub(crate) default: Option<String>,
 }
 
 impl SeqComponent {
@@ -30,16 +30,30 @@ impl SeqComponent {
 
 #[derive(Debug, Clone)]
 pub(crate) struct SeqAdditionGroup {
-    pub(crate) _version: Option<String>,
+    pub(crate) version: Option<String>,
     pub(crate) components: Vec<SeqComponent>,
 }
 
-impl SeqAdditionGroup {
+// An Extension Addition of a `SEQUENCE` is either a single component or an Extension Addition
+// Group (`[[ ... ]]`). Each of them is encoded as a single Open Type.
+#[derive(Debug, Clone)]
+pub(crate) enum SeqAddition {
+    Component(SeqComponent),
+    Group(SeqAdditionGroup),
+}
+
+impl SeqAddition {
+    pub(crate) fn components(&self) -> &[SeqComponent] {
+        match self {
+            Self::Component(ref c) => std::slice::from_ref(c),
+            Self::Group(ref g) => &g.components,
+        }
+    }
+
     pub(crate) fn dependent_references(&self) -> Vec<String> {
-        self.components
+        self.components()
             .iter()
-            .map(|c| c.dependent_references())
-            .flatten()
+            .flat_map(|c| c.dependent_references())
             .collect()
     }
 }
@@ -54,16 +68,24 @@ impl ChoiceAdditionGroup {
     pub(crate) fn dependent_references(&self) -> Vec<String> {
         self.components
             .iter()
-            .map(|c| c.dependent_references())
-            .flatten()
+            .flat_map(|c| c.dependent_references())
             .collect()
     }
 }
 
+// A `COMPONENTS OF Type` in the root components of a `SEQUENCE` (or a `SET`). The root
+// components of the `Type` are included before the root component at `position`.
+#[derive(Debug, Clone)]
+pub(crate) struct ComponentsOf {
+    pub(crate) position: usize,
+    pub(crate) ty: Asn1Type,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Asn1TypeSequence {
     pub(crate) root_components: Vec<SeqComponent>,
-    pub(crate) additions: Vec<SeqAdditionGroup>,
+    pub(crate) components_of: Vec<ComponentsOf>,
+    pub(crate) additions: Vec<SeqAddition>,
     pub(crate) extensible: bool,
 }
 
@@ -73,6 +95,9 @@ impl Asn1TypeSequence {
         for c in &self.root_components {
             dependencies.extend(c.dependent_references());
         }
+        for c in &self.components_of {
+            dependencies.extend(c.ty.dependent_references());
+        }
         for a in &self.additions {
             dependencies.extend(a.dependent_references());
         }
@@ -92,8 +117,8 @@ impl Asn1TypeChoice {
         for c in &self.root_components {
             dependencies.extend(c.dependent_references());
         }
-        if self.additions.is_some() {
-            for a in self.additions.as_ref().unwrap() {
+        if let Some(ref additions) = self.additions {
+            for a in additions {
                 dependencies.extend(a.dependent_references());
             }
         }
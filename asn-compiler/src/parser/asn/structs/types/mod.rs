@@ -11,6 +11,8 @@ use constructed::{Asn1TypeChoice, Asn1TypeSequence, Asn1TypeSequenceOf};
 
 pub(crate) mod ioc;
 
+use crate::tokenizer::{types::TokenType, Token};
+
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub(crate) enum Asn1BuiltinType {
@@ -23,14 +25,45 @@ pub(crate) enum Asn1BuiltinType {
     ObjectIdentifier,
     RelativeOid,
 
+    // `ANY` or `ANY DEFINED BY component`
+    Any { defined_by: Option<String> },
+    External,
+    EmbeddedPdv,
+
     // Consumes a lot of String Types.
     CharacterString { str_type: String },
 }
 
+// Class of a Tag. The Classes are in the canonical order of the Tags (X.680 8.6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) enum Asn1TagClass {
+    Universal,
+    Application,
+    ContextSpecific,
+    Private,
+}
+
+// `IMPLICIT` or `EXPLICIT` specified for a Tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Asn1TagMode {
+    Explicit,
+    Implicit,
+}
+
+// A Tag of a Tagged Type. (eg. `[APPLICATION 1] IMPLICIT`.) If the `mode` is not specified, it is
+// determined by the tagging environment of the module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Asn1Tag {
+    pub(crate) class: Asn1TagClass,
+    pub(crate) number: u32,
+    pub(crate) mode: Option<Asn1TagMode>,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) struct Asn1Type {
     pub(crate) kind: Asn1TypeKind,
     pub(crate) constraints: Option<Vec<Asn1Constraint>>,
+    pub(crate) tag: Option<Asn1Tag>,
 }
 
 impl Asn1Type {
@@ -42,8 +75,8 @@ impl Asn1Type {
         };
 
         let mut constraint_references = vec![];
-        if self.constraints.is_some() {
-            for constraint in self.constraints.as_ref().unwrap() {
+        if let Some(ref constraints) = self.constraints {
+            for constraint in constraints {
                 let constraint_dependent = constraint.dependent_references();
                 constraint_references.extend(constraint_dependent);
             }
@@ -71,6 +104,8 @@ impl Default for Asn1TypeKind {
 pub(crate) enum ActualParam {
     Set(String),
     Single(String),
+    Value(Vec<Token>),    // A Value that is not a reference (eg. `-5`)
+    ValueSet(Vec<Token>), // A Value Set including the `{` and `}` (eg. `{1 | 2 | 3}`)
 }
 
 impl ActualParam {
@@ -78,12 +113,37 @@ impl ActualParam {
         match self {
             Self::Set(ref s) => vec![s.clone()],
             Self::Single(ref s) => vec![s.clone()],
+            Self::Value(ref tokens) | Self::ValueSet(ref tokens) => tokens
+                .iter()
+                .filter(|t| t.is_identifier())
+                .map(|t| t.text.clone())
+                .collect(),
         }
     }
 
-    pub(crate) fn param_string(&self) -> String {
+    // Returns the tokens that replace the Dummy Reference in the Parameterized Type. A Value Set
+    // replaces the Dummy Reference as a nested Element Set (ie. `(1 | 2 | 3)`), as the Dummy
+    // Reference is used in the constraints.
+    pub(crate) fn param_tokens(&self, dummy: &Token) -> Vec<Token> {
         match self {
-            Self::Set(ref r) | Self::Single(ref r) => r.clone(),
+            Self::Set(ref r) | Self::Single(ref r) => {
+                let mut token = dummy.clone();
+                token.text = r.clone();
+                vec![token]
+            }
+            Self::Value(ref tokens) => tokens.clone(),
+            Self::ValueSet(ref tokens) => {
+                let mut tokens = tokens.clone();
+                if let Some(first) = tokens.first_mut() {
+                    first.r#type = TokenType::RoundBegin;
+                    first.text = "(".to_string();
+                }
+                if let Some(last) = tokens.last_mut() {
+                    last.r#type = TokenType::RoundEnd;
+                    last.text = ")".to_string();
+                }
+                tokens
+            }
         }
     }
 }
@@ -99,13 +159,19 @@ pub(crate) enum Asn1TypeReference {
         typeref: String,
         params: Vec<ActualParam>,
     }, // FIXME: For now We can make it a struct
+    // A Selection Type (`identifier < Type`), the Type of the alternative `identifier` of the
+    // `CHOICE` Type `typeref`.
+    Selection {
+        identifier: String,
+        typeref: String,
+    },
 }
 
 impl Asn1TypeReference {
     pub(crate) fn dependent_references(&self) -> Vec<String> {
         match self {
             Self::ClassField { classref, .. } => vec![classref.clone()],
-            Self::Reference(ref r) => vec![r.clone()],
+            Self::Reference(ref r) | Self::Selection { typeref: ref r, .. } => vec![r.clone()],
             Self::Parameterized { typeref, params } => {
                 let mut dependent_references = vec![typeref.clone()];
                 for param in params {
@@ -117,23 +183,22 @@ impl Asn1TypeReference {
     }
 }
 
-#[allow(dead_code)]
 #[derive(Debug, Clone)]
 pub(crate) enum Asn1ConstructedType {
     Choice(Asn1TypeChoice),
     Sequence(Asn1TypeSequence),
     SequenceOf(Asn1TypeSequenceOf),
-    Set,
-    SetOf,
+    // `SET` has the same components as the `SEQUENCE`, only the order of the components differs.
+    Set(Asn1TypeSequence),
+    SetOf(Asn1TypeSequenceOf),
 }
 
 impl Asn1ConstructedType {
     pub(crate) fn dependent_references(&self) -> Vec<String> {
         match self {
             Self::Choice(ref c) => c.dependent_references(),
-            Self::Sequence(ref s) => s.dependent_references(),
-            Self::SequenceOf(ref so) => so.dependent_references(),
-            _ => vec![],
+            Self::Sequence(ref s) | Self::Set(ref s) => s.dependent_references(),
+            Self::SequenceOf(ref so) | Self::SetOf(ref so) => so.dependent_references(),
         }
     }
 }
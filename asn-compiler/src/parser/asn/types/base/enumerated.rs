@@ -70,10 +70,8 @@ pub(crate) fn parse_enumerated_type(
         consumed += 1;
     }
 
-    let (ext_values, ext_values_consumed) = match parse_enum_values(&tokens[consumed..]) {
-        Ok(result) => result,
-        Err(_) => (vec![], 0),
-    };
+    let (ext_values, ext_values_consumed) =
+        parse_enum_values(&tokens[consumed..]).unwrap_or_default();
     consumed += ext_values_consumed;
 
     if !expect_token(&tokens[consumed..], Token::is_curly_end)? {
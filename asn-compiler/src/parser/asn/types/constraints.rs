@@ -4,9 +4,10 @@ use crate::error::Error;
 use crate::tokenizer::Token;
 
 use crate::parser::{
-    asn::values::parse_value,
+    asn::{oid::parse_object_identifier, values::parse_value},
     utils::{
-        expect_keyword, expect_one_of_keywords, expect_one_of_tokens, expect_token, expect_tokens,
+        expect_keyword, expect_keywords, expect_one_of_keywords, expect_one_of_tokens,
+        expect_token, expect_tokens,
     },
 };
 
@@ -39,6 +40,28 @@ pub(crate) fn parse_constraint(tokens: &[Token]) -> Result<(Asn1Constraint, usiz
     }
 }
 
+// Parses a `SIZE` Constraint that is not enclosed in `(` and `)`, as in `SET SIZE (1..MAX) OF`.
+// The Constraint is same as the `(SIZE (1..MAX))` Constraint.
+pub(crate) fn parse_size_constraint(tokens: &[Token]) -> Result<(Asn1Constraint, usize), Error> {
+    if !expect_keyword(tokens, "SIZE")? {
+        return Err(unexpected_token!("'SIZE'", tokens[0]));
+    }
+
+    let (element_set, element_set_consumed) = parse_element_set(&tokens[1..])?;
+    let size = Elements::Subtype(SubtypeElements::SizeConstraint(element_set));
+    Ok((
+        Asn1Constraint::Subtype(ElementSet {
+            root_elements: UnionSet {
+                elements: vec![IntersectionSet {
+                    elements: vec![size],
+                }],
+            },
+            additional_elements: None,
+        }),
+        element_set_consumed + 1,
+    ))
+}
+
 fn parse_table_constraint(tokens: &[Token]) -> Result<(Asn1Constraint, usize), Error> {
     let mut consumed = 0;
 
@@ -222,6 +245,14 @@ fn parse_intersection_set(tokens: &[Token]) -> Result<(Elements, usize), Error>
         }
     }
 
+    // Parse Inner Type Constraints
+    if expect_keyword(&tokens[consumed..], "WITH")? {
+        let (inner_elements, inner_consumed) = parse_inner_type_constraints(&tokens[consumed..])?;
+        consumed += inner_consumed;
+
+        return Ok((Elements::Subtype(inner_elements), consumed));
+    }
+
     // Parse Range Value
     if let Ok(result) = parse_range_elements(&tokens[consumed..]) {
         let range_elements = result.0;
@@ -341,36 +372,155 @@ fn parse_range_elements(tokens: &[Token]) -> Result<(SubtypeElements, usize), Er
     ))
 }
 
-fn parse_contents_constraint(tokens: &[Token]) -> Result<(Asn1Constraint, usize), Error> {
+// Parses the Inner Type Constraints: `WITH COMPONENT (Constraint)` or
+// `WITH COMPONENTS { [..., ] id [(Constraint)] [PRESENT | ABSENT | OPTIONAL], ... }`.
+fn parse_inner_type_constraints(tokens: &[Token]) -> Result<(SubtypeElements, usize), Error> {
     let mut consumed = 0;
 
-    if !expect_token(&tokens[consumed..], Token::is_round_begin)? {
-        return Err(unexpected_token!("'('", tokens[consumed]));
+    if !expect_keyword(&tokens[consumed..], "WITH")? {
+        return Err(unexpected_token!("'WITH'", tokens[consumed]));
     }
     consumed += 1;
 
-    if !expect_keyword(&tokens[consumed..], "CONTAINING")? {
-        return Err(unexpected_token!("'CONTAINING'", tokens[consumed]));
+    if expect_keyword(&tokens[consumed..], "COMPONENT")? {
+        consumed += 1;
+
+        let (constraint, constraint_consumed) = parse_constraint(&tokens[consumed..])?;
+        consumed += constraint_consumed;
+
+        return Ok((
+            SubtypeElements::SingleTypeConstraint(Box::new(constraint)),
+            consumed,
+        ));
+    }
+
+    if !expect_keyword(&tokens[consumed..], "COMPONENTS")? {
+        return Err(unexpected_token!(
+            "'COMPONENT' or 'COMPONENTS'",
+            tokens[consumed]
+        ));
+    }
+    consumed += 1;
+
+    if !expect_token(&tokens[consumed..], Token::is_curly_begin)? {
+        return Err(unexpected_token!("'{'", tokens[consumed]));
     }
     consumed += 1;
 
-    let _containing = if expect_token(&tokens[consumed..], Token::is_type_reference)? {
-        tokens[consumed].text.clone()
+    let partial = if expect_token(&tokens[consumed..], Token::is_extension)? {
+        consumed += 1;
+        if !expect_token(&tokens[consumed..], Token::is_comma)? {
+            return Err(unexpected_token!("','", tokens[consumed]));
+        }
+        consumed += 1;
+        true
     } else {
-        return Err(unexpected_token!("'TYPE Reference'", tokens[consumed]));
+        false
     };
+
+    let mut constraints = vec![];
+    loop {
+        if !expect_token(&tokens[consumed..], Token::is_value_reference)? {
+            return Err(unexpected_token!("'Identifier'", tokens[consumed]));
+        }
+        let id = tokens[consumed].text.clone();
+        consumed += 1;
+
+        let value = if expect_token(&tokens[consumed..], Token::is_round_begin)? {
+            let (constraint, constraint_consumed) = parse_constraint(&tokens[consumed..])?;
+            consumed += constraint_consumed;
+            Some(constraint)
+        } else {
+            None
+        };
+
+        let presence =
+            if expect_one_of_keywords(&tokens[consumed..], &["PRESENT", "ABSENT", "OPTIONAL"])? {
+                let presence = match tokens[consumed].text.as_str() {
+                    "PRESENT" => PresenceConstraint::Present,
+                    "ABSENT" => PresenceConstraint::Absent,
+                    _ => PresenceConstraint::Optional,
+                };
+                consumed += 1;
+                Some(presence)
+            } else {
+                None
+            };
+
+        constraints.push(NamedConstraint {
+            id,
+            value,
+            presence,
+        });
+
+        if expect_token(&tokens[consumed..], Token::is_comma)? {
+            consumed += 1;
+        } else if expect_token(&tokens[consumed..], Token::is_curly_end)? {
+            consumed += 1;
+            break;
+        } else {
+            return Err(unexpected_token!("',' or '}'", tokens[consumed]));
+        }
+    }
+
+    Ok((
+        SubtypeElements::MultipleTypeConstraints {
+            partial,
+            constraints,
+        },
+        consumed,
+    ))
+}
+
+// Parses a Contents Constraint (`CONTAINING Type`, `ENCODED BY Value` or
+// `CONTAINING Type ENCODED BY Value`). The Value of `ENCODED BY` is an Object Identifier.
+fn parse_contents_constraint(tokens: &[Token]) -> Result<(Asn1Constraint, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_token(&tokens[consumed..], Token::is_round_begin)? {
+        return Err(unexpected_token!("'('", tokens[consumed]));
+    }
     consumed += 1;
 
+    let containing = if expect_keyword(&tokens[consumed..], "CONTAINING")? {
+        consumed += 1;
+        if !expect_token(&tokens[consumed..], Token::is_type_reference)? {
+            return Err(unexpected_token!("'TYPE Reference'", tokens[consumed]));
+        }
+        consumed += 1;
+        Some(tokens[consumed - 1].text.clone())
+    } else {
+        None
+    };
+
+    let encoded_by = if expect_keyword(&tokens[consumed..], "ENCODED")? {
+        if !expect_keywords(&tokens[consumed..], &["ENCODED", "BY"])? {
+            return Err(unexpected_token!("'BY'", tokens[consumed + 1]));
+        }
+        consumed += 2;
+        let (oid, oid_consumed) = parse_object_identifier(&tokens[consumed..])?;
+        consumed += oid_consumed;
+        Some(oid)
+    } else {
+        None
+    };
+
+    if containing.is_none() && encoded_by.is_none() {
+        return Err(unexpected_token!(
+            "'CONTAINING' or 'ENCODED BY'",
+            tokens[consumed]
+        ));
+    }
+
     if !expect_token(&tokens[consumed..], Token::is_round_end)? {
         return Err(unexpected_token!("')'", tokens[consumed]));
     }
     consumed += 1;
 
-    let _encoded_by = None;
     Ok((
         Asn1Constraint::Contents {
-            _containing,
-            _encoded_by,
+            containing,
+            encoded_by,
         },
         consumed,
     ))
@@ -428,6 +578,27 @@ mod tests {
                 additional_elements_present: true,
                 additional_elements_count: 1,
             },
+            ParseConstraintTestCase {
+                input: "(WITH COMPONENT (1..10))",
+                success: true,
+                root_elements_count: 1,
+                additional_elements_present: false,
+                additional_elements_count: 0,
+            },
+            ParseConstraintTestCase {
+                input: "(WITH COMPONENTS { ..., a PRESENT } | WITH COMPONENTS { ..., b PRESENT })",
+                success: true,
+                root_elements_count: 2,
+                additional_elements_present: false,
+                additional_elements_count: 0,
+            },
+            ParseConstraintTestCase {
+                input: "(WITH COMPONENTS { ..., a PRESENT, })",
+                success: false,
+                root_elements_count: 0,
+                additional_elements_present: false,
+                additional_elements_count: 0,
+            },
             // FIXME: Add more test cases for subtype constraints
         ];
         for tc in test_cases {
@@ -464,29 +635,86 @@ mod tests {
                         constraint
                     );
 
-                    if constraint.additional_elements.is_some() {
+                    if let Some(ref additional_elements) = constraint.additional_elements {
                         assert_eq!(
-                            &constraint
-                                .additional_elements
-                                .as_ref()
-                                .unwrap()
-                                .elements
-                                .len(),
+                            &additional_elements.elements.len(),
                             &tc.additional_elements_count,
                             "{:#?}",
                             constraint
                         );
                     }
                 } else {
-                    assert!(false, "Expected Subtype Constraint, Found {:#?}", elem_set);
+                    panic!("Expected Subtype Constraint, Found {:#?}", elem_set);
                 }
             }
         }
     }
 
+    #[test]
+    fn parse_inner_type_constraints_testcase() {
+        let input =
+            "WITH COMPONENTS { a (0..3) PRESENT, b, c (WITH COMPONENTS { ..., x ABSENT }) }";
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+
+        let (elements, consumed) = parse_inner_type_constraints(&tokens).unwrap();
+        assert_eq!(consumed, tokens.len());
+        match elements {
+            SubtypeElements::MultipleTypeConstraints {
+                partial,
+                constraints,
+            } => {
+                assert!(!partial);
+                let ids = constraints
+                    .iter()
+                    .map(|c| c.id.as_str())
+                    .collect::<Vec<_>>();
+                assert_eq!(ids, vec!["a", "b", "c"]);
+                assert_eq!(constraints[0].presence, Some(PresenceConstraint::Present));
+                assert!(constraints[0].value.is_some());
+                assert!(constraints[1].value.is_none() && constraints[1].presence.is_none());
+                assert!(constraints[2].value.is_some() && constraints[2].presence.is_none());
+            }
+            other => panic!("Expected Multiple Type Constraints, Found {:#?}", other),
+        }
+    }
+
+    #[test]
+    fn parse_contents_constraint_testcases() {
+        let inputs = vec![
+            ("(CONTAINING Foo)", Some("Foo"), None),
+            (
+                "(CONTAINING Foo ENCODED BY { joint-iso-itu-t asn1(1) packed-encoding(3) basic(0) aligned(0) })",
+                Some("Foo"),
+                Some("joint-iso-itu-t(2).asn1(1).packed-encoding(3).basic(0).aligned(0)"),
+            ),
+            ("(ENCODED BY { 2 1 3 1 0 })", None, Some("2.1.3.1.0")),
+        ];
+        for (input, containing, encoded_by) in inputs {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+
+            let (constraint, consumed) = parse_contents_constraint(&tokens).unwrap();
+            assert_eq!(consumed, tokens.len(), "{}", input);
+            match constraint {
+                Asn1Constraint::Contents {
+                    containing: ref c,
+                    encoded_by: ref e,
+                } => {
+                    assert_eq!(c.as_deref(), containing, "{}", input);
+                    assert_eq!(e.as_ref().map(|e| e.to_string()).as_deref(), encoded_by);
+                }
+                other => panic!("Expected Contents Constraint, Found {:#?}", other),
+            }
+        }
+
+        let reader = std::io::BufReader::new(std::io::Cursor::new("(CONTAINING)"));
+        let tokens = tokenize(reader).unwrap();
+        assert!(parse_contents_constraint(&tokens).is_err());
+    }
+
     #[test]
     fn parse_table_constraint_testcases() {
         // FIXME: Add test cases
-        assert!(true);
     }
 }
@@ -6,4 +6,4 @@ mod choice;
 pub(crate) use choice::parse_choice_type;
 
 mod seq;
-pub(crate) use seq::parse_seq_or_seq_of_type;
+pub(crate) use seq::{parse_seq_or_seq_of_type, parse_set_or_set_of_type};
@@ -1,4 +1,4 @@
-//! Handling of Sequence and Sequence Of Type
+//! Handling of Sequence, Sequence Of, Set and Set Of Type
 
 use crate::error::Error;
 use crate::tokenizer::Token;
@@ -7,10 +7,16 @@ use crate::parser::utils::{expect_keyword, expect_token};
 
 use crate::parser::asn::{
     structs::types::{
-        constructed::{Asn1TypeSequence, Asn1TypeSequenceOf, SeqAdditionGroup, SeqComponent},
-        Asn1ConstructedType, Asn1TypeKind,
+        constructed::{
+            Asn1TypeSequence, Asn1TypeSequenceOf, ComponentsOf, SeqAddition, SeqAdditionGroup,
+            SeqComponent,
+        },
+        Asn1ConstructedType, Asn1Type, Asn1TypeKind,
+    },
+    types::{
+        constraints::{parse_constraint, parse_size_constraint},
+        parse_type,
     },
-    types::{constraints::parse_constraint, parse_type},
     values::parse_value,
 };
 
@@ -22,15 +28,44 @@ pub(crate) fn parse_seq_or_seq_of_type(tokens: &[Token]) -> Result<(Asn1TypeKind
     }
 
     if expect_token(&tokens[1..], Token::is_curly_begin)? {
-        parse_sequence_type(tokens)
+        let (seq, consumed) = parse_sequence_type(tokens)?;
+        Ok((
+            Asn1TypeKind::Constructed(Asn1ConstructedType::Sequence(seq)),
+            consumed,
+        ))
+    } else {
+        let (seq_of, consumed) = parse_sequence_of_type(tokens)?;
+        Ok((
+            Asn1TypeKind::Constructed(Asn1ConstructedType::SequenceOf(seq_of)),
+            consumed,
+        ))
+    }
+}
+
+// `SET` and `SET OF` have the same syntax as the `SEQUENCE` and `SEQUENCE OF`.
+pub(crate) fn parse_set_or_set_of_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error> {
+    if !expect_keyword(tokens, "SET")? {
+        return Err(unexpected_token!("'SET'", tokens[0]));
+    }
+
+    if expect_token(&tokens[1..], Token::is_curly_begin)? {
+        let (set, consumed) = parse_sequence_type(tokens)?;
+        Ok((
+            Asn1TypeKind::Constructed(Asn1ConstructedType::Set(set)),
+            consumed,
+        ))
     } else {
-        parse_sequence_of_type(tokens)
+        let (set_of, consumed) = parse_sequence_of_type(tokens)?;
+        Ok((
+            Asn1TypeKind::Constructed(Asn1ConstructedType::SetOf(set_of)),
+            consumed,
+        ))
     }
 }
 
-fn parse_sequence_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error> {
+fn parse_sequence_type(tokens: &[Token]) -> Result<(Asn1TypeSequence, usize), Error> {
     let mut consumed = 0;
-    // Initial 'SEQUENCE' is consumed by the caller. We start with '{'
+    // Initial 'SEQUENCE' (or 'SET') is consumed by the caller. We start with '{'
 
     consumed += 1; // For the SEQUENCE
 
@@ -40,15 +75,34 @@ fn parse_sequence_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error>
     consumed += 1;
 
     let mut root_components = vec![];
+    let mut components_of = vec![];
     let mut additions = vec![];
     let mut ext_marker_found = 0;
     loop {
-        let (component, component_consumed) = match parse_seq_component(&tokens[consumed..]) {
-            Ok(result) => result,
-            Err(_) => (None, 0),
-        };
-        if let Some(root_comp) = component {
-            root_components.push(root_comp);
+        let loop_start = consumed;
+        if expect_keyword(&tokens[consumed..], "COMPONENTS")? {
+            if ext_marker_found == 1 {
+                return Err(parse_error!(
+                    "COMPONENTS OF is supported only in the root components!"
+                ));
+            }
+            let (ty, ty_consumed) = parse_components_of(&tokens[consumed..])?;
+            consumed += ty_consumed;
+            components_of.push(ComponentsOf {
+                position: root_components.len(),
+                ty,
+            });
+        }
+
+        let (component, component_consumed) =
+            parse_seq_component(&tokens[consumed..]).unwrap_or_default();
+        if let Some(component) = component {
+            // Components between the two extension markers are Extension Additions.
+            if ext_marker_found == 1 {
+                additions.push(SeqAddition::Component(component));
+            } else {
+                root_components.push(component);
+            }
         }
         consumed += component_consumed;
 
@@ -59,7 +113,7 @@ fn parse_sequence_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error>
                 let (ext_group, ext_group_consumed) =
                     parse_seq_addition_group(&tokens[consumed..])?;
                 consumed += ext_group_consumed;
-                additions.push(ext_group);
+                additions.push(SeqAddition::Group(ext_group));
             } else {
                 return Err(parse_error!(
                     "Addition groups can only be added between first and second extension markers!"
@@ -83,26 +137,36 @@ fn parse_sequence_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error>
             consumed += 1;
             break;
         }
+
+        if consumed == loop_start {
+            return Err(unexpected_token!("'Component' or '}'", tokens[consumed]));
+        }
     }
 
     let extensible = ext_marker_found > 0;
     Ok((
-        Asn1TypeKind::Constructed(Asn1ConstructedType::Sequence(Asn1TypeSequence {
+        Asn1TypeSequence {
             root_components,
+            components_of,
             additions,
             extensible,
-        })),
+        },
         consumed,
     ))
 }
 
-fn parse_sequence_of_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error> {
+fn parse_sequence_of_type(tokens: &[Token]) -> Result<(Asn1TypeSequenceOf, usize), Error> {
     let mut consumed = 0;
 
-    // Initial SEQUENCE is already consumed.
+    // Initial SEQUENCE (or SET) is already consumed.
     consumed += 1;
 
-    let (size, size_consumed) = match parse_constraint(&tokens[consumed..]) {
+    let size_constraint = if expect_keyword(&tokens[consumed..], "SIZE")? {
+        parse_size_constraint(&tokens[consumed..])
+    } else {
+        parse_constraint(&tokens[consumed..])
+    };
+    let (size, size_consumed) = match size_constraint {
         Ok(result) => (Some(result.0), result.1),
         Err(_) => (None, 0),
     };
@@ -113,17 +177,16 @@ fn parse_sequence_of_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Err
     }
     consumed += 1;
 
+    // The name of the entries (eg. `SET OF value AttributeValue`) is not used.
+    if expect_token(&tokens[consumed..], Token::is_value_reference)? {
+        consumed += 1;
+    }
+
     let (ty, ty_consumed) = parse_type(&tokens[consumed..])?;
     consumed += ty_consumed;
 
     let ty = Box::new(ty);
-    Ok((
-        Asn1TypeKind::Constructed(Asn1ConstructedType::SequenceOf(Asn1TypeSequenceOf {
-            size,
-            ty,
-        })),
-        consumed,
-    ))
+    Ok((Asn1TypeSequenceOf { size, ty }, consumed))
 }
 
 fn parse_seq_component(tokens: &[Token]) -> Result<(Option<SeqComponent>, usize), Error> {
@@ -143,7 +206,7 @@ fn parse_seq_component(tokens: &[Token]) -> Result<(Option<SeqComponent>, usize)
             false
         };
 
-        let _default = if expect_keyword(&tokens[consumed..], "DEFAULT")? {
+        let default = if expect_keyword(&tokens[consumed..], "DEFAULT")? {
             consumed += 1;
             let (value, value_consumed) = parse_value(&tokens[consumed..])?;
             consumed += value_consumed;
@@ -152,7 +215,7 @@ fn parse_seq_component(tokens: &[Token]) -> Result<(Option<SeqComponent>, usize)
             None
         };
 
-        if _default.is_some() && optional {
+        if default.is_some() && optional {
             return Err(parse_error!(
                 "Both OPTIONAL and DEFAULT not allowed for a value!"
             ));
@@ -162,7 +225,7 @@ fn parse_seq_component(tokens: &[Token]) -> Result<(Option<SeqComponent>, usize)
             Some(SeqComponent {
                 component,
                 optional,
-                _default,
+                default,
             }),
             consumed,
         ))
@@ -171,6 +234,26 @@ fn parse_seq_component(tokens: &[Token]) -> Result<(Option<SeqComponent>, usize)
     }
 }
 
+// Parses `COMPONENTS OF Type`.
+fn parse_components_of(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_keyword(&tokens[consumed..], "COMPONENTS")? {
+        return Err(unexpected_token!("'COMPONENTS'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    if !expect_keyword(&tokens[consumed..], "OF")? {
+        return Err(unexpected_token!("'OF'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    let (ty, ty_consumed) = parse_type(&tokens[consumed..])?;
+    consumed += ty_consumed;
+
+    Ok((ty, consumed))
+}
+
 fn parse_seq_addition_group(tokens: &[Token]) -> Result<(SeqAdditionGroup, usize), Error> {
     let mut consumed = 0;
 
@@ -179,7 +262,7 @@ fn parse_seq_addition_group(tokens: &[Token]) -> Result<(SeqAdditionGroup, usize
     }
     consumed += 1;
 
-    let _version = match expect_token(&tokens[consumed..], Token::is_numeric) {
+    let version = match expect_token(&tokens[consumed..], Token::is_numeric) {
         Ok(success) => {
             if success {
                 let version = tokens[consumed].text.clone();
@@ -216,7 +299,7 @@ fn parse_seq_addition_group(tokens: &[Token]) -> Result<(SeqAdditionGroup, usize
         consumed += 1;
         Ok((
             SeqAdditionGroup {
-                _version,
+                version,
                 components,
             },
             consumed,
@@ -274,8 +357,8 @@ mod tests {
             ParseSequenceTestCase {
                 input: " SEQUENCE { a INTEGER, b BOOLEAN OPTIONAL, ..., c CHOICE { d INTEGER, e Enum}} ",
                 success: true,
-                root_components_count: 3,
-                additional_components_count: 0,
+                root_components_count: 2,
+                additional_components_count: 1,
                 consumed_tokens: 21,
             },
             ParseSequenceTestCase {
@@ -368,4 +451,95 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn parse_sequence_components_of() {
+        let input = " SEQUENCE { COMPONENTS OF Header, a INTEGER, COMPONENTS OF Trailer, ... } ";
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+
+        let (kind, consumed) = parse_seq_or_seq_of_type(&tokens).unwrap();
+        assert_eq!(consumed, tokens.len());
+        let seq = match kind {
+            Asn1TypeKind::Constructed(Asn1ConstructedType::Sequence(seq)) => seq,
+            other => panic!("Expected a SEQUENCE, found {:#?}", other),
+        };
+
+        assert_eq!(seq.root_components.len(), 1);
+        let positions = seq
+            .components_of
+            .iter()
+            .map(|c| c.position)
+            .collect::<Vec<_>>();
+        assert_eq!(positions, vec![0, 1]);
+        assert_eq!(
+            seq.dependent_references(),
+            vec!["Header".to_string(), "Trailer".to_string()]
+        );
+
+        // `COMPONENTS OF` is not supported in the Extension Additions.
+        let input = " SEQUENCE { a INTEGER, ..., COMPONENTS OF Trailer } ";
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+        assert!(parse_seq_or_seq_of_type(&tokens).is_err());
+    }
+
+    #[test]
+    fn parse_sequence_additions() {
+        let input = " SEQUENCE { a INTEGER, ..., b BOOLEAN, [[ 2: c INTEGER, d Enum OPTIONAL ]], [[ e INTEGER ]] } ";
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+
+        let (kind, _) = parse_seq_or_seq_of_type(&tokens).unwrap();
+        let seq = match kind {
+            Asn1TypeKind::Constructed(Asn1ConstructedType::Sequence(seq)) => seq,
+            other => panic!("Expected a SEQUENCE, found {:#?}", other),
+        };
+
+        assert_eq!(seq.additions.len(), 3);
+        assert!(matches!(seq.additions[0], SeqAddition::Component(ref c) if c.component.id == "b"));
+        match seq.additions[1] {
+            SeqAddition::Group(ref g) => {
+                assert_eq!(g.version.as_deref(), Some("2"));
+                assert_eq!(g.components.len(), 2);
+            }
+            _ => panic!("Expected an Extension Addition Group"),
+        }
+        assert!(matches!(seq.additions[2], SeqAddition::Group(ref g) if g.version.is_none()));
+    }
+
+    #[test]
+    fn parse_set_test_cases() {
+        let test_cases = vec![
+            (" SET { a INTEGER, b BOOLEAN OPTIONAL } ", true, 9),
+            (" SET {} ", true, 3),
+            (" SET OF value OCTET STRING ", true, 5),
+            (" SET SIZE (1..4) OF Foo ", true, 9),
+            (" SET (SIZE (1..4)) OF item Foo ", true, 12),
+            (" SET { a INTEGER, 5 } ", false, 0),
+            (" SEQUENCE { a INTEGER } ", false, 0),
+        ];
+
+        for (input, success, consumed) in test_cases {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+
+            let set = parse_set_or_set_of_type(&tokens);
+            assert_eq!(set.is_ok(), success, "{}", input);
+
+            if let Ok((kind, set_consumed)) = set {
+                assert!(
+                    matches!(
+                        kind,
+                        Asn1TypeKind::Constructed(
+                            Asn1ConstructedType::Set(..) | Asn1ConstructedType::SetOf(..)
+                        )
+                    ),
+                    "{}",
+                    input
+                );
+                assert_eq!(set_consumed, consumed, "{}", input);
+            }
+        }
+    }
 }
@@ -2,19 +2,94 @@ use crate::error::Error;
 use crate::tokenizer::Token;
 
 use crate::parser::asn::structs::types::{
-    ActualParam, Asn1BuiltinType, Asn1ConstructedType, Asn1Type, Asn1TypeKind, Asn1TypeReference,
+    ActualParam, Asn1BuiltinType, Asn1ConstructedType, Asn1Tag, Asn1TagClass, Asn1TagMode,
+    Asn1Type, Asn1TypeKind, Asn1TypeReference,
 };
 
-use crate::parser::utils::{expect_keywords, expect_one_of_tokens, expect_token, expect_tokens};
+use crate::parser::utils::{
+    expect_keyword, expect_keywords, expect_one_of_keywords, expect_one_of_tokens, expect_token,
+    expect_tokens,
+};
 
 use super::{
     base::{parse_bitstring_type, parse_enumerated_type, parse_integer_type},
     constraints::parse_constraints,
-    constructed::{parse_choice_type, parse_seq_or_seq_of_type},
+    constructed::{parse_choice_type, parse_seq_or_seq_of_type, parse_set_or_set_of_type},
 };
 
 // Parses the `Type` Expansion in the ASN.1 Grammar.
+//
+// The Type can be a Tagged Type (eg. `[0] IMPLICIT INTEGER`), in which case the Tag is parsed
+// first.
 pub(crate) fn parse_type(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
+    let (tag, tag_consumed) = maybe_parse_tag(tokens)?;
+
+    let (ty, ty_consumed) = parse_untagged_type(&tokens[tag_consumed..])?;
+
+    Ok((Asn1Type { tag, ..ty }, tag_consumed + ty_consumed))
+}
+
+// Parses the `Tag` (`[ Class ClassNumber ]`) followed by an optional `IMPLICIT` or `EXPLICIT`.
+fn maybe_parse_tag(tokens: &[Token]) -> Result<(Option<Asn1Tag>, usize), Error> {
+    let mut consumed = 0;
+
+    if !expect_token(tokens, Token::is_square_begin)? {
+        return Ok((None, 0));
+    }
+    consumed += 1;
+
+    let class = if expect_one_of_keywords(
+        &tokens[consumed..],
+        &["UNIVERSAL", "APPLICATION", "PRIVATE"],
+    )? {
+        let class = match tokens[consumed].text.as_str() {
+            "UNIVERSAL" => Asn1TagClass::Universal,
+            "APPLICATION" => Asn1TagClass::Application,
+            _ => Asn1TagClass::Private,
+        };
+        consumed += 1;
+        class
+    } else {
+        Asn1TagClass::ContextSpecific
+    };
+
+    if !expect_token(&tokens[consumed..], Token::is_numeric)? {
+        return Err(unexpected_token!("'NUMBER'", tokens[consumed]));
+    }
+    let number = tokens[consumed]
+        .text
+        .parse::<u32>()
+        .map_err(|_| unexpected_token!("'NUMBER'", tokens[consumed]))?;
+    consumed += 1;
+
+    if !expect_token(&tokens[consumed..], Token::is_square_end)? {
+        return Err(unexpected_token!("']'", tokens[consumed]));
+    }
+    consumed += 1;
+
+    let mode = if expect_one_of_keywords(&tokens[consumed..], &["IMPLICIT", "EXPLICIT"])? {
+        let mode = if tokens[consumed].text == "IMPLICIT" {
+            Asn1TagMode::Implicit
+        } else {
+            Asn1TagMode::Explicit
+        };
+        consumed += 1;
+        Some(mode)
+    } else {
+        None
+    };
+
+    Ok((
+        Some(Asn1Tag {
+            class,
+            number,
+            mode,
+        }),
+        consumed,
+    ))
+}
+
+fn parse_untagged_type(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
     let mut consumed = 0;
 
     if !expect_one_of_tokens(
@@ -24,6 +99,9 @@ pub(crate) fn parse_type(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
             Token::is_asn_builtin_type,
             Token::is_object_class_reference,
         ],
+    )? && !expect_tokens(
+        tokens,
+        &[&[Token::is_value_reference], &[Token::is_less_than]],
     )? {
         return Err(unexpected_token!(
             "'Type Reference' or 'Builtin Type'",
@@ -93,9 +171,41 @@ pub(crate) fn parse_type(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
             )
         }
         "SEQUENCE" => parse_seq_or_seq_of_type(tokens)?,
+        "SET" => parse_set_or_set_of_type(tokens)?,
 
         "RELATIVE-OID" => (Asn1TypeKind::Builtin(Asn1BuiltinType::RelativeOid), 1),
 
+        "ANY" => {
+            if tokens.len() > 1 && expect_keyword(&tokens[1..], "DEFINED")? {
+                if !expect_keywords(&tokens[1..], &["DEFINED", "BY"])? {
+                    return Err(unexpected_token!("'BY'", tokens[2]));
+                }
+                if !expect_token(&tokens[3..], Token::is_value_reference)? {
+                    return Err(unexpected_token!("'identifier'", tokens[3]));
+                }
+                let defined_by = tokens[3].text.clone();
+                (
+                    Asn1TypeKind::Builtin(Asn1BuiltinType::Any {
+                        defined_by: Some(defined_by),
+                    }),
+                    4,
+                )
+            } else {
+                (
+                    Asn1TypeKind::Builtin(Asn1BuiltinType::Any { defined_by: None }),
+                    1,
+                )
+            }
+        }
+        "EXTERNAL" => (Asn1TypeKind::Builtin(Asn1BuiltinType::External), 1),
+        "EMBEDDED" => {
+            if !expect_keywords(&tokens[consumed..], &["EMBEDDED", "PDV"])? {
+                return Err(unexpected_token!("'PDV'", tokens[consumed + 1]));
+            }
+
+            (Asn1TypeKind::Builtin(Asn1BuiltinType::EmbeddedPdv), 2)
+        }
+
         _ => parse_referenced_type(tokens)?,
     };
     consumed += kind_consumed;
@@ -106,7 +216,14 @@ pub(crate) fn parse_type(tokens: &[Token]) -> Result<(Asn1Type, usize), Error> {
     };
     consumed += constraints_str_consumed;
 
-    Ok((Asn1Type { kind, constraints }, consumed))
+    Ok((
+        Asn1Type {
+            kind,
+            constraints,
+            tag: None,
+        },
+        consumed,
+    ))
 }
 
 fn parse_referenced_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Error> {
@@ -133,6 +250,30 @@ fn parse_referenced_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Erro
         }
     }
 
+    if let Ok(success) = expect_tokens(
+        &tokens[consumed..],
+        &[&[Token::is_value_reference], &[Token::is_less_than]],
+    ) {
+        if success {
+            // The selected Type must be a referenced `CHOICE` Type.
+            if tokens.len() > consumed + 2 && tokens[consumed + 2].is_asn_builtin_type() {
+                return Err(unexpected_token!("'Type Reference'", tokens[consumed + 2]));
+            }
+            let identifier = tokens[consumed].text.clone();
+            let (kind, selection_consumed) = parse_referenced_type(&tokens[consumed + 2..])?;
+            return match kind {
+                Asn1TypeKind::Reference(Asn1TypeReference::Reference(typeref)) => Ok((
+                    Asn1TypeKind::Reference(Asn1TypeReference::Selection {
+                        identifier,
+                        typeref,
+                    }),
+                    selection_consumed + 2,
+                )),
+                _ => Err(unexpected_token!("'Type Reference'", tokens[consumed + 2])),
+            };
+        }
+    }
+
     let (reference, reference_consumed) = match expect_tokens(
         &tokens[consumed..],
         &[
@@ -185,6 +326,10 @@ fn parse_referenced_type(tokens: &[Token]) -> Result<(Asn1TypeKind, usize), Erro
     }
 }
 
+// Parses the Actual Parameters of a Parameterized Type (eg. `{ProtocolIE-ID, {Set}, 16}`).
+//
+// An Actual Parameter is a reference (a Type, a Value or a Class), a Set (`{ObjectSet}`), a Value
+// (eg. `-5`) or a Value Set (eg. `{1 | 2 | 3}`).
 fn parse_actual_params(tokens: &[Token]) -> Result<(Vec<ActualParam>, usize), Error> {
     let mut consumed = 0;
     if !expect_token(&tokens[consumed..], Token::is_curly_begin)? {
@@ -193,41 +338,56 @@ fn parse_actual_params(tokens: &[Token]) -> Result<(Vec<ActualParam>, usize), Er
     consumed += 1;
 
     let mut params = vec![];
+    if expect_token(&tokens[consumed..], Token::is_curly_end)? {
+        return Ok((params, consumed + 1));
+    }
     loop {
         if expect_token(&tokens[consumed..], Token::is_curly_begin)? {
-            consumed += 1;
-            let param = if expect_one_of_tokens(
-                &tokens[consumed..],
-                &[Token::is_numeric, Token::is_identifier],
-            )? {
-                let param = tokens[consumed].text.clone();
-                consumed += 1;
-                param
-            } else {
-                return Err(unexpected_token!("'IDENTIFIER'", tokens[consumed]));
-            };
+            // Find the matching `}`, the Value Set may have nested `{` and `}`.
+            let mut depth = 0;
+            let mut set_consumed = 0;
+            for token in &tokens[consumed..] {
+                set_consumed += 1;
+                if token.is_curly_begin() {
+                    depth += 1;
+                } else if token.is_curly_end() {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            if depth != 0 {
+                return Err(unexpected_end!());
+            }
+            if set_consumed == 2 {
+                return Err(unexpected_token!("'IDENTIFIER'", tokens[consumed + 1]));
+            }
 
-            if !expect_token(&tokens[consumed..], Token::is_curly_end)? {
-                return Err(unexpected_token!("'}'", tokens[consumed]));
-            };
+            let set_tokens = &tokens[consumed..consumed + set_consumed];
+            if set_consumed == 3 && set_tokens[1].is_identifier() {
+                params.push(ActualParam::Set(set_tokens[1].text.clone()));
+            } else {
+                params.push(ActualParam::ValueSet(set_tokens.to_vec()));
+            }
+            consumed += set_consumed;
+        } else if expect_token(&tokens[consumed..], Token::is_numeric)? {
+            params.push(ActualParam::Value(vec![tokens[consumed].clone()]));
             consumed += 1;
-            params.push(ActualParam::Set(param));
-        }
-
-        if expect_one_of_tokens(
-            &tokens[consumed..],
-            &[Token::is_numeric, Token::is_identifier],
-        )? {
-            let param = tokens[consumed].text.clone();
+        } else if expect_token(&tokens[consumed..], Token::is_identifier)? {
+            params.push(ActualParam::Single(tokens[consumed].text.clone()));
             consumed += 1;
-            params.push(ActualParam::Single(param));
+        } else {
+            return Err(unexpected_token!("'Actual Parameter'", tokens[consumed]));
         }
+
         if expect_token(&tokens[consumed..], Token::is_comma)? {
             consumed += 1;
-        }
-        if expect_token(&tokens[consumed..], Token::is_curly_end)? {
+        } else if expect_token(&tokens[consumed..], Token::is_curly_end)? {
             consumed += 1;
             break;
+        } else {
+            return Err(unexpected_token!("',' or '}'", tokens[consumed]));
         }
     }
     Ok((params, consumed))
@@ -246,11 +406,73 @@ mod tests {
             consumed: usize,
         }
 
-        let test_cases = vec![ParseTypeTestCase {
-            input: "OBJECT IDENTIFIER",
-            success: true,
-            consumed: 2,
-        }];
+        let test_cases = vec![
+            ParseTypeTestCase {
+                input: "OBJECT IDENTIFIER",
+                success: true,
+                consumed: 2,
+            },
+            ParseTypeTestCase {
+                input: "[0] INTEGER",
+                success: true,
+                consumed: 4,
+            },
+            ParseTypeTestCase {
+                input: "[APPLICATION 1] IMPLICIT SEQUENCE { a INTEGER }",
+                success: true,
+                consumed: 10,
+            },
+            ParseTypeTestCase {
+                input: "[PRIVATE 2] EXPLICIT Foo",
+                success: true,
+                consumed: 6,
+            },
+            ParseTypeTestCase {
+                input: "[APPLICATION] INTEGER",
+                success: false,
+                consumed: 0,
+            },
+            ParseTypeTestCase {
+                input: "ANY",
+                success: true,
+                consumed: 1,
+            },
+            ParseTypeTestCase {
+                input: "ANY DEFINED BY algorithm",
+                success: true,
+                consumed: 4,
+            },
+            ParseTypeTestCase {
+                input: "ANY DEFINED algorithm",
+                success: false,
+                consumed: 0,
+            },
+            ParseTypeTestCase {
+                input: "EXTERNAL",
+                success: true,
+                consumed: 1,
+            },
+            ParseTypeTestCase {
+                input: "EMBEDDED PDV",
+                success: true,
+                consumed: 2,
+            },
+            ParseTypeTestCase {
+                input: "request < Message",
+                success: true,
+                consumed: 3,
+            },
+            ParseTypeTestCase {
+                input: "request < Module.Message",
+                success: true,
+                consumed: 5,
+            },
+            ParseTypeTestCase {
+                input: "request < INTEGER",
+                success: false,
+                consumed: 0,
+            },
+        ];
 
         for tc in test_cases {
             let reader = std::io::BufReader::new(std::io::Cursor::new(tc.input));
@@ -3,12 +3,17 @@
 use crate::error::Error;
 use crate::tokenizer::{types::TokenType, Token};
 
-use crate::parser::utils::{expect_one_of_tokens, parse_set_ish_value};
+use crate::parser::utils::{expect_one_of_keywords, expect_one_of_tokens, parse_set_ish_value};
 
 // Parses a given set of 'tokens' as a value and returns a string corresponding to one that would
 // be generated by concatenating those tokens. Note: It should be possible to regenerate, original
 // tokens, by 'tokenize'ing the string.
 pub(crate) fn parse_value(tokens: &[Token]) -> Result<(String, usize), Error> {
+    // `BOOLEAN` values are Keywords.
+    if expect_one_of_keywords(tokens, &["TRUE", "FALSE"])? {
+        return Ok((tokens[0].text.clone(), 1));
+    }
+
     if !expect_one_of_tokens(
         tokens,
         &[
@@ -28,11 +33,13 @@ pub(crate) fn parse_value(tokens: &[Token]) -> Result<(String, usize), Error> {
     } else {
         let token = &tokens[0];
         match token.r#type {
-            TokenType::Identifier
-            | TokenType::NumberInt
-            | TokenType::BitString
-            | TokenType::HexString
-            | TokenType::TString => Ok((token.text.clone(), 1)),
+            TokenType::Identifier | TokenType::NumberInt | TokenType::TString => {
+                Ok((token.text.clone(), 1))
+            }
+            // The text of the Token does not include the 'B' or 'H' suffix, which is required for
+            // resolving the value.
+            TokenType::BitString => Ok((format!("{}B", token.text), 1)),
+            TokenType::HexString => Ok((format!("{}H", token.text), 1)),
             _ => parse_set_ish_value(tokens),
         }
     }
@@ -6,10 +6,14 @@ use crate::parser::asn::structs::defs::{
     Asn1AssignmentKind, Asn1Definition, Asn1ObjectAssignment, Asn1ObjectSetAssignment,
     Asn1TypeAssignment, Asn1ValueAssignment,
 };
-use crate::resolver::{asn::structs::defs::Asn1ResolvedDefinition, Resolver};
+use crate::parser::asn::structs::types::{Asn1TypeKind, Asn1TypeReference};
+use crate::resolver::{
+    asn::structs::{defs::Asn1ResolvedDefinition, types::Asn1ResolvedType},
+    Resolver,
+};
 
 use super::types::ioc::{resolve_object, resolve_object_set};
-use super::types::resolve_type;
+use super::types::{resolve_selected_alternative, resolve_type};
 use super::values::resolve_value;
 
 // Resolve a given Parsed Definition to a Resolved Definition
@@ -38,6 +42,29 @@ pub(crate) fn resolve_type_definition(
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedDefinition, Error> {
     let typeref = resolve_type(&def.typeref, resolver)?;
+    let tag = match def.typeref.resolve_tag(resolver)? {
+        Some(tag) => Some(tag),
+        // A referenced Type with Inner Type Constraints is resolved to a copy of the referenced
+        // Type, which has the Tag of the referenced Type.
+        None => match (&def.typeref.kind, &typeref) {
+            (
+                Asn1TypeKind::Reference(Asn1TypeReference::Reference(ref r)),
+                Asn1ResolvedType::Constructed(..),
+            ) => resolver.tags.get(r).copied(),
+            // A Selection Type has the Tag of the selected alternative.
+            (
+                Asn1TypeKind::Reference(Asn1TypeReference::Selection {
+                    ref identifier,
+                    ref typeref,
+                }),
+                _,
+            ) => resolve_selected_alternative(identifier, typeref, resolver)?.tag,
+            _ => None,
+        },
+    };
+    if let Some(tag) = tag {
+        resolver.tags.insert(def.id.clone(), tag);
+    }
     Ok(Asn1ResolvedDefinition::Type(typeref))
 }
 
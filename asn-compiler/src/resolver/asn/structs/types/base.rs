@@ -13,6 +13,7 @@ pub(crate) enum ResolvedBaseType {
     CharacterString(Asn1ResolvedCharacterString),
     ObjectIdentifier(Asn1ResolvedObjectIdentifier),
     Null(Asn1ResolvedNull),
+    Any(Asn1ResolvedAny),
 }
 
 // An intermediate representation for a Resolved Integer Type
@@ -65,12 +66,32 @@ impl Default for Asn1ResolvedEnumerated {
     }
 }
 
+// The Encoding Rules (`ENCODED BY`) of the value contained in an `OCTET STRING` or a `BIT STRING`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum ResolvedContentsEncoding {
+    // Aligned PER, which is used when `ENCODED BY` is not given.
+    Aper,
+    // Canonical Aligned PER
+    CanonicalAper,
+    // Any other Encoding Rules, identified by the Object Identifier.
+    Unsupported(String),
+}
+
+// The Type contained in an `OCTET STRING` or a `BIT STRING` (`CONTAINING` Constraint).
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedContents {
+    pub(crate) ty: String,
+    pub(crate) encoding: ResolvedContentsEncoding,
+}
+
 // A Resolved `BIT STRING` representation. Normally only the `SIZE` Constraint needs to be
 // resolved. If optional, `named_bits` are present, we maintain those in the map below.
 #[derive(Debug, Default, Clone)]
 pub(crate) struct Asn1ResolvedBitString {
     pub(crate) size: Option<Asn1ConstraintValueSet>,
 
+    pub(crate) contents: Option<Box<ResolvedContents>>,
+
     // We support only up to 128 named bits, if more than that is required, change this to appropriate. value
     pub(crate) named_values: HashMap<String, u8>,
 }
@@ -84,10 +105,11 @@ pub(crate) struct Asn1ResolvedBoolean;
 pub(crate) struct Asn1ResolvedNull;
 
 // A structure representing a Resolved `OCTET STRING`. `SIZE` Constraint is resolved as well. The
-// `CONTAINING` Constraint is not resolved.
+// `CONTAINING` Constraint is resolved to the contained Type.
 #[derive(Debug, Default, Clone)]
 pub(crate) struct Asn1ResolvedOctetString {
     pub(crate) size: Option<Asn1ConstraintValueSet>,
+    pub(crate) contents: Option<Box<ResolvedContents>>,
 }
 
 // A structure representing a Resolved `CharacterString`. `SIZE` Constraint is resolved as well. The
@@ -99,3 +121,10 @@ pub(crate) struct Asn1ResolvedCharacterString {
 
 #[derive(Debug, Default, Clone)]
 pub(crate) struct Asn1ResolvedObjectIdentifier;
+
+// A structure representing a Resolved `ANY`, `EXTERNAL` or `EMBEDDED PDV`. The values of these
+// Types are not decoded, they are carried as the raw encoded bytes.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct Asn1ResolvedAny {
+    pub(crate) any_type: String,
+}
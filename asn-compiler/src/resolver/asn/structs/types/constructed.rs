@@ -1,6 +1,12 @@
 //! Structs for the resolved Base Types
 
-use crate::resolver::asn::structs::types::{constraints::Asn1ConstraintValueSet, Asn1ResolvedType};
+use crate::resolver::asn::structs::{
+    types::{
+        base::Asn1ResolvedInteger, constraints::Asn1ConstraintValueSet, Asn1ResolvedTag,
+        Asn1ResolvedType,
+    },
+    values::Asn1ResolvedValue,
+};
 
 #[derive(Debug, Clone)]
 pub(crate) enum ResolvedConstructedType {
@@ -13,11 +19,27 @@ pub(crate) enum ResolvedConstructedType {
         name: Option<String>,
         extensible: bool,
         components: Vec<ResolvedSeqComponent>,
+        additions: Vec<ResolvedSeqAddition>,
     },
     SequenceOf {
         name: Option<String>,
         ty: Box<Asn1ResolvedType>,
         size_values: Option<Asn1ConstraintValueSet>,
+        entry_values: Option<Box<ResolvedIntegerValues>>,
+    },
+    // The components are in the canonical order of their Tags. The Extension Additions are in the
+    // order of their definition.
+    Set {
+        name: Option<String>,
+        extensible: bool,
+        components: Vec<ResolvedSeqComponent>,
+        additions: Vec<ResolvedSeqAddition>,
+    },
+    SetOf {
+        name: Option<String>,
+        ty: Box<Asn1ResolvedType>,
+        size_values: Option<Asn1ConstraintValueSet>,
+        entry_values: Option<Box<ResolvedIntegerValues>>,
     },
 }
 
@@ -25,12 +47,18 @@ pub(crate) enum ResolvedConstructedType {
 pub(crate) enum ClassFieldComponentType {
     FixedTypeValue,
     Type,
+    // An `ANY DEFINED BY` component, whose Type is looked up from the `&Type` field of the
+    // objects using the value of the key field.
+    AnyDefinedBy,
 }
 
 #[derive(Debug, Clone)]
 pub(crate) struct ResolvedComponent {
     pub(crate) id: String,
     pub(crate) ty: Asn1ResolvedType,
+
+    // Tag of the component, if the component is tagged (or automatically tagged).
+    pub(crate) tag: Option<Asn1ResolvedTag>,
 }
 
 #[derive(Debug, Clone)]
@@ -39,6 +67,41 @@ pub(crate) struct ResolvedSeqComponent {
     pub(crate) optional: bool,
     pub(crate) class_field_type: Option<ClassFieldComponentType>,
     pub(crate) key_field: bool,
-    // FIXME : Handle default
-    // pub(crate) default: Option<Asn1ResolvedType>
+    pub(crate) default: Option<Asn1ResolvedValue>,
+    pub(crate) constraints: ResolvedComponentConstraints,
+}
+
+// The Inner Type Constraints (`WITH COMPONENTS`) on a component of a `SEQUENCE` (or a `SET`).
+//
+// Inner Type Constraints are not PER-visible, so they do not change the encoding of the
+// component. They are only checked while encoding and decoding.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ResolvedComponentConstraints {
+    pub(crate) presence: Option<ResolvedPresence>,
+    pub(crate) values: Option<ResolvedIntegerValues>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResolvedPresence {
+    Present,
+    Absent,
+}
+
+// Values allowed by an Inner Type Constraint for a component (or the entries of a
+// `SEQUENCE OF`), whose Type is (or refers to) the `INTEGER` Type `ty`.
+#[derive(Debug, Clone)]
+pub(crate) struct ResolvedIntegerValues {
+    pub(crate) ty: Asn1ResolvedInteger,
+    pub(crate) values: Asn1ConstraintValueSet,
+}
+
+// An Extension Addition of a `SEQUENCE` (or a `SET`), which is either a single component or an
+// Extension Addition Group with an optional version number.
+#[derive(Debug, Clone)]
+pub(crate) enum ResolvedSeqAddition {
+    Component(Box<ResolvedSeqComponent>),
+    Group {
+        version: Option<String>,
+        components: Vec<ResolvedSeqComponent>,
+    },
 }
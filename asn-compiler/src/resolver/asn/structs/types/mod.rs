@@ -1,5 +1,7 @@
 use std::collections::BTreeMap;
 
+use crate::parser::asn::structs::types::Asn1TagClass;
+
 pub(crate) mod constructed;
 use constructed::ResolvedConstructedType;
 
@@ -18,6 +20,33 @@ pub(crate) struct ResolvedSetType {
     pub(crate) types: ResolvedSetTypeMap,
 }
 
+// A resolved Tag. Whether the Tag is `EXPLICIT` is determined using the tagging environment of the
+// module, if it is not specified.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Asn1ResolvedTag {
+    pub(crate) class: Asn1TagClass,
+    pub(crate) number: u32,
+    pub(crate) explicit: bool,
+}
+
+// Formats the Tag as in the ASN.1 definitions. (eg. `[APPLICATION 1] IMPLICIT` or `[0] EXPLICIT`)
+impl std::fmt::Display for Asn1ResolvedTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let class = match self.class {
+            Asn1TagClass::Universal => "UNIVERSAL ",
+            Asn1TagClass::Application => "APPLICATION ",
+            Asn1TagClass::ContextSpecific => "",
+            Asn1TagClass::Private => "PRIVATE ",
+        };
+        let mode = if self.explicit {
+            "EXPLICIT"
+        } else {
+            "IMPLICIT"
+        };
+        write!(f, "[{}{}] {}", class, self.number, mode)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum Asn1ResolvedType {
     // One of the resolved Base Types.
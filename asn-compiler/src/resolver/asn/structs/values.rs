@@ -10,10 +10,13 @@ pub(crate) type BaseEnum = i64;
 pub(crate) type BaseBoolean = bool;
 
 /// Any CharacterString value will be represented by a BaseCharString type when 'Resolved'.
-pub(crate) type BaseCharString = bool;
+pub(crate) type BaseCharString = String;
 
 /// Any OCTET STRING value will be represente by a BaseOctetString type when 'Resolved'.
-pub(crate) type BaseOctetSTring = Vec<u8>;
+pub(crate) type BaseOctetString = Vec<u8>;
+
+/// A BIT STRING value will be represented by a BaseBitString type when 'Resolved'.
+pub(crate) type BaseBitString = Vec<bool>;
 
 #[derive(Debug, Clone)]
 pub(crate) struct Asn1ResolvedIntegerValue {
@@ -27,10 +30,38 @@ pub(crate) struct Asn1ResolvedEnumValue {
     pub(crate) value: BaseEnum,
 }
 
+#[derive(Debug, Clone)]
+pub(crate) struct Asn1ResolvedBooleanValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseBoolean,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Asn1ResolvedOctetStringValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseOctetString,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Asn1ResolvedBitStringValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseBitString,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Asn1ResolvedCharacterStringValue {
+    pub(crate) typeref: Asn1ResolvedType,
+    pub(crate) value: BaseCharString,
+}
+
 #[derive(Debug, Clone)]
 pub(crate) enum ResolvedBaseValue {
     Integer(Asn1ResolvedIntegerValue),
     Enum(Asn1ResolvedEnumValue),
+    Boolean(Asn1ResolvedBooleanValue),
+    OctetString(Asn1ResolvedOctetStringValue),
+    BitString(Asn1ResolvedBitStringValue),
+    CharacterString(Asn1ResolvedCharacterStringValue),
 }
 
 #[derive(Debug, Clone)]
@@ -50,9 +81,17 @@ pub(crate) enum Asn1ResolvedValue {
 }
 
 impl Asn1ResolvedValue {
+    pub(crate) fn get_base_value(&self) -> Option<&ResolvedBaseValue> {
+        match self {
+            Self::Base(ref b) => Some(b),
+            Self::ReferencedType { value, .. } => value.get_base_value(),
+            _ => None,
+        }
+    }
+
     pub(crate) fn get_base_integer_value(&self) -> Option<i128> {
         match self {
-            Self::Base(ResolvedBaseValue::Integer(ref i)) => Some(i.value as i128),
+            Self::Base(ResolvedBaseValue::Integer(ref i)) => Some(i.value),
             Self::ReferencedType { value, .. } => value.get_base_integer_value(),
             _ => None,
         }
@@ -19,23 +19,24 @@ impl Asn1ResolvedBitString {
     ) -> Result<Asn1ResolvedBitString, Error> {
         let mut base = Asn1ResolvedBitString::default();
 
-        if ty.constraints.is_some() {
-            let constraints = ty.constraints.as_ref().unwrap();
+        if let Some(constraints) = &ty.constraints {
             if !constraints.is_empty() {
                 let constraint = &constraints[0];
 
                 if constraint.is_size_constraint() {
                     let value_set = constraint.get_size_valueset(resolver)?;
                     let _ = base.size.replace(value_set);
-                } else {
-                    // The constraint is a 'CONTAINING' Constraint, TODO: Handle this.
                 }
             }
+            base.contents = constraints
+                .iter()
+                .find_map(|c| c.get_contents())
+                .map(Box::new);
         }
 
-        if b.named_bits.is_some() {
+        if let Some(ref named_bits) = b.named_bits {
             let mut named_values = HashMap::new();
-            for nb in b.named_bits.as_ref().unwrap() {
+            for nb in named_bits {
                 if let NamedValue::Number(ref v) = nb.1 {
                     let parsed = v.parse::<u8>().unwrap(); // Let it panic if it does not fit in u8.
                     named_values.insert(nb.0.clone(), parsed);
@@ -16,8 +16,7 @@ impl Asn1ResolvedCharacterString {
         if let Asn1TypeKind::Builtin(Asn1BuiltinType::CharacterString { str_type }) = &ty.kind {
             base.str_type = str_type.clone();
         }
-        if ty.constraints.is_some() {
-            let constraints = ty.constraints.as_ref().unwrap();
+        if let Some(constraints) = &ty.constraints {
             if !constraints.is_empty() {
                 let constraint = &constraints[0];
 
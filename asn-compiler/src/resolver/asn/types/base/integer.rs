@@ -1,8 +1,13 @@
 //! Functionality for handling Resolved ASN.1 INTEGER Types
 
+use std::collections::HashMap;
+
 use crate::error::Error;
 
-use crate::parser::asn::structs::types::{base::Asn1TypeInteger, Asn1Type};
+use crate::parser::asn::structs::types::{
+    base::{Asn1TypeInteger, NamedValue},
+    Asn1Type,
+};
 use crate::resolver::asn::structs::types::base::Asn1ResolvedInteger;
 use crate::resolver::Resolver;
 
@@ -13,33 +18,32 @@ impl Asn1ResolvedInteger {
         i: &Asn1TypeInteger,
         resolver: &mut Resolver,
     ) -> Result<Asn1ResolvedInteger, Error> {
-        let mut base = Asn1ResolvedInteger::default();
+        let mut base = Asn1ResolvedInteger {
+            named_values: Self::resolve_named_values(i)?,
+            ..Default::default()
+        };
 
-        if ty.constraints.is_none() {
-            return Ok(base);
-        } else {
-            let constraints = ty.constraints.as_ref().unwrap();
-            if constraints.is_empty() {
-                return Ok(base);
-            }
+        match ty.constraints {
+            None => return Ok(base),
+            Some(ref constraints) if constraints.is_empty() => return Ok(base),
+            _ => {}
         }
 
         // Get the Values that are expected
         let value_set = ty.get_integer_valueset_from_constraint(resolver)?;
         if let Some(x) = value_set.root_values.min() {
-            if x < 0 {
-                base.signed = true
-            } else {
-                base.signed = false
-            }
+            base.signed = x < 0;
         }
 
         let bit_width = if base.signed {
-            let min = value_set.root_values.min().unwrap();
-            let max = value_set.root_values.max().unwrap();
-            let bits_needed_max = 128 - max.abs().leading_zeros();
-            let bits_needed_min = 128 - min.abs().leading_zeros();
-            std::cmp::max(bits_needed_min, bits_needed_max)
+            // Bits needed for the value in 2's compliment form.
+            let signed_bits = |v: i128| {
+                let magnitude = if v < 0 { !v } else { v };
+                128 - magnitude.leading_zeros() + 1
+            };
+            let min = value_set.root_values.min().unwrap_or_default();
+            let max = value_set.root_values.max().unwrap_or_default();
+            std::cmp::max(signed_bits(min), signed_bits(max))
         } else if value_set.root_values.min().is_none() {
             8_u32
         } else {
@@ -59,10 +63,78 @@ impl Asn1ResolvedInteger {
             128
         };
 
-        // TODO: If we have named values, They should be added to Global list of resolved definitions.
-        if i.named_values.is_some() {}
-
         let _ = base.resolved_constraints.replace(value_set);
         Ok(base)
     }
+
+    // Named numbers are used for resolving the values of the Type (eg. a `DEFAULT` value).
+    //
+    // TODO: Named numbers that refer to value definitions are ignored.
+    fn resolve_named_values(i: &Asn1TypeInteger) -> Result<Option<HashMap<String, i128>>, Error> {
+        let named_values = match i.named_values {
+            Some(ref named_values) => named_values,
+            None => return Ok(None),
+        };
+
+        let mut resolved = HashMap::new();
+        for (name, value) in named_values {
+            match value {
+                NamedValue::Number(ref n) => {
+                    let n = n.parse::<i128>().map_err(|_| {
+                        resolve_error!("Invalid number '{}' for the named number '{}'.", n, name)
+                    })?;
+                    resolved.insert(name.clone(), n);
+                }
+                NamedValue::ValueRef(_) => {}
+            }
+        }
+        Ok(Some(resolved))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::parser::asn::structs::types::{Asn1BuiltinType, Asn1TypeKind};
+    use crate::parser::asn::types::parse_type;
+    use crate::tokenizer::tokenize;
+
+    #[test]
+    fn resolve_integer_bits_tests() {
+        let test_cases = vec![
+            ("INTEGER", 64, true),
+            ("INTEGER (0..255)", 8, false),
+            ("INTEGER (0..256)", 16, false),
+            ("INTEGER (-128..127)", 8, true),
+            ("INTEGER (-129..127)", 16, true),
+            ("INTEGER (-200..100)", 16, true),
+            ("INTEGER (-1..128)", 16, true),
+            ("INTEGER (0..4294967295)", 32, false),
+            ("INTEGER (0..18446744073709551616)", 128, false),
+            ("INTEGER (-9223372036854775809..0)", 128, true),
+            ("INTEGER (0..255, ...)", 8, false),
+            ("INTEGER (-1..255, ...)", 16, true),
+            ("INTEGER (0..9223372036854775808, ...)", 64, false),
+        ];
+
+        for (input, bits, signed) in test_cases {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let (ty, _) = parse_type(&tokens).unwrap();
+            let i = match ty.kind {
+                Asn1TypeKind::Builtin(Asn1BuiltinType::Integer(ref i)) => i.clone(),
+                _ => panic!("{}: not an INTEGER", input),
+            };
+
+            let mut resolver = Resolver::new();
+            let resolved = Asn1ResolvedInteger::resolve_integer(&ty, &i, &mut resolver).unwrap();
+            assert_eq!(
+                (resolved.bits, resolved.signed),
+                (bits, signed),
+                "{}",
+                input
+            );
+        }
+    }
 }
@@ -16,7 +16,7 @@ use crate::parser::asn::structs::types::{Asn1BuiltinType, Asn1Type, Asn1TypeKind
 
 use crate::resolver::{
     asn::structs::types::base::{
-        Asn1ResolvedBitString, Asn1ResolvedBoolean, Asn1ResolvedCharacterString,
+        Asn1ResolvedAny, Asn1ResolvedBitString, Asn1ResolvedBoolean, Asn1ResolvedCharacterString,
         Asn1ResolvedEnumerated, Asn1ResolvedInteger, Asn1ResolvedNull,
         Asn1ResolvedObjectIdentifier, Asn1ResolvedOctetString, ResolvedBaseType,
     },
@@ -38,9 +38,7 @@ pub(crate) fn resolve_base_type(
             Asn1BuiltinType::BitString(ref b) => Ok(ResolvedBaseType::BitString(
                 Asn1ResolvedBitString::resolve_bit_string(ty, b, resolver)?,
             )),
-            Asn1BuiltinType::Boolean => {
-                Ok(ResolvedBaseType::Boolean(Asn1ResolvedBoolean::default()))
-            }
+            Asn1BuiltinType::Boolean => Ok(ResolvedBaseType::Boolean(Asn1ResolvedBoolean)),
             Asn1BuiltinType::OctetString => Ok(ResolvedBaseType::OctetString(
                 Asn1ResolvedOctetString::resolve_octet_string(ty, resolver)?,
             )),
@@ -48,9 +46,18 @@ pub(crate) fn resolve_base_type(
                 Asn1ResolvedCharacterString::resolve_character_string(ty, resolver)?,
             )),
             Asn1BuiltinType::ObjectIdentifier => Ok(ResolvedBaseType::ObjectIdentifier(
-                Asn1ResolvedObjectIdentifier::default(),
+                Asn1ResolvedObjectIdentifier,
             )),
-            Asn1BuiltinType::Null => Ok(ResolvedBaseType::Null(Asn1ResolvedNull::default())),
+            Asn1BuiltinType::Null => Ok(ResolvedBaseType::Null(Asn1ResolvedNull)),
+            Asn1BuiltinType::Any { .. } => Ok(ResolvedBaseType::Any(Asn1ResolvedAny {
+                any_type: "ANY".to_string(),
+            })),
+            Asn1BuiltinType::External => Ok(ResolvedBaseType::Any(Asn1ResolvedAny {
+                any_type: "EXTERNAL".to_string(),
+            })),
+            Asn1BuiltinType::EmbeddedPdv => Ok(ResolvedBaseType::Any(Asn1ResolvedAny {
+                any_type: "EMBEDDED PDV".to_string(),
+            })),
             _ => Err(resolve_error!(
                 "parse_base_type: Not Implemented! {:#?}",
                 ty
@@ -13,18 +13,19 @@ impl Asn1ResolvedOctetString {
     ) -> Result<Asn1ResolvedOctetString, Error> {
         let mut base = Asn1ResolvedOctetString::default();
 
-        if ty.constraints.is_some() {
-            let constraints = ty.constraints.as_ref().unwrap();
+        if let Some(constraints) = &ty.constraints {
             if !constraints.is_empty() {
                 let constraint = &constraints[0];
 
                 if constraint.is_size_constraint() {
                     let value_set = constraint.get_size_valueset(resolver)?;
                     let _ = base.size.replace(value_set);
-                } else {
-                    // The constraint is a 'CONTAINING' Constraint, TODO: Handle this.
                 }
             }
+            base.contents = constraints
+                .iter()
+                .find_map(|c| c.get_contents())
+                .map(Box::new);
         }
         Ok(base)
     }
@@ -7,7 +7,10 @@ use crate::parser::asn::structs::types::constraints::*;
 
 use crate::resolver::asn::structs::{
     defs::Asn1ResolvedDefinition,
-    types::constraints::{Asn1ConstraintValueSet, ConstraintValues},
+    types::{
+        base::{ResolvedContents, ResolvedContentsEncoding},
+        constraints::{Asn1ConstraintValueSet, ConstraintValues},
+    },
     values::{Asn1ResolvedValue, ResolvedBaseValue},
 };
 use crate::resolver::Resolver;
@@ -70,6 +73,35 @@ impl Asn1Constraint {
         }
     }
 
+    /// Returns the contained Type and its Encoding Rules for a `CONTAINING` Constraint.
+    ///
+    /// Without `ENCODED BY`, the contained value is encoded using the same Encoding Rules as the
+    /// `OCTET STRING` (or `BIT STRING`) i.e. Aligned PER.
+    pub(crate) fn get_contents(&self) -> Option<ResolvedContents> {
+        if let Self::Contents {
+            containing: Some(ref ty),
+            ref encoded_by,
+        } = self
+        {
+            let encoding = match encoded_by {
+                None => ResolvedContentsEncoding::Aper,
+                Some(oid) => match oid.numbers().as_slice() {
+                    // { joint-iso-itu-t asn1(1) packed-encoding(3) basic(0) aligned(0) }
+                    [2, 1, 3, 0, 0] => ResolvedContentsEncoding::Aper,
+                    // { joint-iso-itu-t asn1(1) packed-encoding(3) canonical(1) aligned(0) }
+                    [2, 1, 3, 1, 0] => ResolvedContentsEncoding::CanonicalAper,
+                    _ => ResolvedContentsEncoding::Unsupported(oid.to_string()),
+                },
+            };
+            Some(ResolvedContents {
+                ty: ty.clone(),
+                encoding,
+            })
+        } else {
+            None
+        }
+    }
+
     // FIXME: only gets the dependent Set for now. Types in other constraints not yet supported.
     /// Returns Dependent Components for the constraint
     pub(crate) fn dependent_references(&self) -> Vec<String> {
@@ -92,6 +124,35 @@ impl Asn1Constraint {
             )),
         }
     }
+    // Returns the Inner Type Constraints (`WITH COMPONENT` and `WITH COMPONENTS`) of the
+    // constraint.
+    //
+    // Only the Inner Type Constraints that apply to every value of the Type are returned (ie. not
+    // those in a union or in an extensible constraint), since only these can be checked for each
+    // component separately.
+    pub(crate) fn get_inner_type_constraints(&self) -> Vec<&SubtypeElements> {
+        match self {
+            Self::Subtype(ref e) if e.additional_elements.is_none() => {
+                let iset = e.get_inner_elements();
+                if iset.len() != 1 {
+                    return vec![];
+                }
+                iset[0]
+                    .elements
+                    .iter()
+                    .filter_map(|element| match element {
+                        Elements::Subtype(
+                            s @ (SubtypeElements::SingleTypeConstraint(..)
+                            | SubtypeElements::MultipleTypeConstraints { .. }),
+                        ) => Some(s),
+                        _ => None,
+                    })
+                    .collect()
+            }
+            _ => vec![],
+        }
+    }
+
     // Returns whether this constraint is a Subtype Constraint
     fn is_subtype(&self) -> bool {
         true
@@ -183,8 +244,7 @@ impl ElementSet {
             root_values.append(&element_values);
         }
 
-        let additional_values = if self.additional_elements.is_some() {
-            let additional_elements = self.additional_elements.as_ref().unwrap();
+        let additional_values = if let Some(additional_elements) = &self.additional_elements {
             let mut additional_values = ConstraintValues::new();
             for element in &additional_elements.elements {
                 let element_values = element.get_integer_valueset(resolver)?;
@@ -205,13 +265,8 @@ impl ElementSet {
         let mut output = vec![];
         output.extend(self.root_elements.dependent_references());
 
-        if self.additional_elements.is_some() {
-            output.extend(
-                self.additional_elements
-                    .as_ref()
-                    .unwrap()
-                    .dependent_references(),
-            );
+        if let Some(additional_elements) = self.additional_elements {
+            output.extend(additional_elements.dependent_references());
         }
         output
     }
@@ -282,9 +337,8 @@ impl Elements {
     fn get_integer_valueset(&self, resolver: &Resolver) -> Result<ConstraintValues, Error> {
         match self {
             Self::Subtype(ref s) => s.get_integer_valueset(resolver),
-            Self::Set(ref _s) => Err(constraint_error!(
-                "get_integer_valueset: Set Variant: Not Supported!"
-            )),
+            // A nested Element Set (eg. a Value Set Parameter) contributes only it's root values.
+            Self::Set(ref s) => Ok(s.get_integer_valueset(resolver)?.root_values),
         }
     }
 
@@ -331,8 +385,8 @@ impl SubtypeElements {
             Self::SizeConstraint(ref elems) => {
                 let size_elements = elems.get_integer_valueset(resolver)?;
                 value_set.append(&size_elements.root_values);
-                if size_elements.additional_values.is_some() {
-                    value_set.append(&size_elements.additional_values.unwrap());
+                if let Some(additional_values) = size_elements.additional_values {
+                    value_set.append(&additional_values);
                 }
             }
             _ => {
@@ -399,6 +453,14 @@ impl SubtypeElements {
             }
             Self::SizeConstraint(ref s) => s.clone().dependent_references(),
             Self::PermittedAlphabet(ref _p) => vec![], // FIXME: Should we?
+            Self::SingleTypeConstraint(ref c) => c.dependent_references(),
+            Self::MultipleTypeConstraints {
+                ref constraints, ..
+            } => constraints
+                .iter()
+                .filter_map(|c| c.value.as_ref())
+                .flat_map(|v| v.dependent_references())
+                .collect(),
         }
     }
 }
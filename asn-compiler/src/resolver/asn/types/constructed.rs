@@ -3,8 +3,11 @@ use std::collections::BTreeMap;
 use crate::error::Error;
 
 use crate::parser::asn::structs::types::{
-    constructed::{Asn1TypeChoice, Asn1TypeSequence, Asn1TypeSequenceOf, Component, SeqComponent},
-    Asn1ConstructedType, Asn1Type, Asn1TypeKind, Asn1TypeReference,
+    constraints::{Asn1Constraint, PresenceConstraint, SubtypeElements},
+    constructed::{
+        Asn1TypeChoice, Asn1TypeSequence, Asn1TypeSequenceOf, Component, SeqAddition, SeqComponent,
+    },
+    Asn1BuiltinType, Asn1ConstructedType, Asn1Type, Asn1TypeKind, Asn1TypeReference,
 };
 
 use crate::resolver::{
@@ -12,15 +15,21 @@ use crate::resolver::{
         structs::{
             defs::Asn1ResolvedDefinition,
             types::{
+                base::{Asn1ResolvedAny, ResolvedBaseType},
                 constructed::{
-                    ClassFieldComponentType, ResolvedComponent, ResolvedConstructedType,
-                    ResolvedSeqComponent,
+                    ClassFieldComponentType, ResolvedComponent, ResolvedComponentConstraints,
+                    ResolvedConstructedType, ResolvedIntegerValues, ResolvedPresence,
+                    ResolvedSeqAddition, ResolvedSeqComponent,
                 },
                 ioc::{ResolvedFieldSpec, ResolvedObjectSet, ResolvedObjectSetElement},
-                Asn1ResolvedType, ResolvedSetType, ResolvedSetTypeMap,
+                Asn1ResolvedTag, Asn1ResolvedType, ResolvedSetType, ResolvedSetTypeMap,
             },
         },
-        types::resolve_type,
+        types::{
+            canonical_tag, resolve_component_tags, resolve_tagged_component_tags, resolve_type,
+            TaggedComponent,
+        },
+        values::resolve_default_value,
     },
     Resolver,
 };
@@ -30,15 +39,14 @@ pub(crate) fn resolve_constructed_type(
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
     if let Asn1TypeKind::Constructed(ref kind) = ty.kind {
-        match kind {
+        let resolved = match kind {
             Asn1ConstructedType::Choice(ref c) => resolve_choice_type(c, resolver),
-            Asn1ConstructedType::Sequence(ref s) => resolve_sequence_type(s, resolver),
+            Asn1ConstructedType::Sequence(ref s) => resolve_sequence_type(s, false, resolver),
             Asn1ConstructedType::SequenceOf(ref so) => resolve_sequence_of_type(so, resolver),
-            _ => {
-                eprintln!("ConstructedType: {:#?}", ty);
-                Err(resolve_error!("resolve_constructed_Type: Not Implemented!"))
-            }
-        }
+            Asn1ConstructedType::Set(ref s) => resolve_set_type(s, resolver),
+            Asn1ConstructedType::SetOf(ref so) => resolve_set_of_type(so, resolver),
+        }?;
+        resolve_inner_type_constraints(resolved, ty.constraints.as_deref(), resolver)
     } else {
         Err(resolve_error!(
             "Expected Constructed Type. Found '{:#?}'",
@@ -47,31 +55,47 @@ pub(crate) fn resolve_constructed_type(
     }
 }
 
+// The Extension Additions of a `CHOICE` are encoded the same, whether they are in an Extension
+// Addition Group or not. So the Extension Addition Groups are not retained.
 fn resolve_choice_type(
     choice: &Asn1TypeChoice,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
+    let addition_components = choice
+        .additions
+        .iter()
+        .flatten()
+        .flat_map(|addition| addition.components.iter())
+        .collect::<Vec<&Component>>();
+    let all_types = choice
+        .root_components
+        .iter()
+        .chain(addition_components.iter().copied())
+        .map(|c| &c.ty)
+        .collect::<Vec<&Asn1Type>>();
+    let mut tags = resolve_component_tags(&all_types, resolver)?.into_iter();
+
     let mut root_components = vec![];
     for c in &choice.root_components {
         let ty = resolve_type(&c.ty, resolver)?;
         let component = ResolvedComponent {
             id: c.id.clone(),
             ty,
+            tag: tags.next().flatten(),
         };
         root_components.push(component);
     }
 
     let additions = if choice.additions.is_some() {
         let mut components = vec![];
-        for addition in choice.additions.as_ref().unwrap() {
-            for c in &addition.components {
-                let ty = resolve_type(&c.ty, resolver)?;
-                let component = ResolvedComponent {
-                    id: c.id.clone(),
-                    ty,
-                };
-                components.push(component);
-            }
+        for c in addition_components {
+            let ty = resolve_type(&c.ty, resolver)?;
+            let component = ResolvedComponent {
+                id: c.id.clone(),
+                ty,
+                tag: tags.next().flatten(),
+            };
+            components.push(component);
         }
         Some(components)
     } else {
@@ -87,48 +111,177 @@ fn resolve_choice_type(
     ))
 }
 
+// Resolves a `SEQUENCE` (or a `SET` if `set` is set) to a `SEQUENCE`.
 fn resolve_sequence_type(
     sequence: &Asn1TypeSequence,
+    set: bool,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
+    // The root components of the Types in `COMPONENTS OF` are included in the root components.
+    let mut root_components = vec![];
+    for (position, c) in sequence.root_components.iter().enumerate() {
+        for components_of in sequence
+            .components_of
+            .iter()
+            .filter(|c| c.position == position)
+        {
+            for included in resolve_components_of(&components_of.ty, set, resolver)? {
+                root_components.push(RootComponent::Included(Box::new(included)));
+            }
+        }
+        root_components.push(RootComponent::Defined(c));
+    }
+    for components_of in sequence
+        .components_of
+        .iter()
+        .filter(|c| c.position == sequence.root_components.len())
+    {
+        for included in resolve_components_of(&components_of.ty, set, resolver)? {
+            root_components.push(RootComponent::Included(Box::new(included)));
+        }
+    }
+
+    // Extension Additions are tagged after the root components.
+    let tagged_components = root_components
+        .iter()
+        .map(|c| match c {
+            RootComponent::Defined(c) => TaggedComponent::Defined(&c.component.ty),
+            RootComponent::Included(c) => TaggedComponent::Included(&c.component),
+        })
+        .chain(
+            sequence
+                .additions
+                .iter()
+                .flat_map(|a| a.components().iter())
+                .map(|c| TaggedComponent::Defined(&c.component.ty)),
+        )
+        .collect::<Vec<_>>();
+    let mut tags = resolve_tagged_component_tags(&tagged_components, resolver)?.into_iter();
+
     let mut components = vec![];
-    // FIXME: implement for additional_components too
-    for c in &sequence.root_components {
-        let ty = match resolve_type(&c.component.ty, resolver) {
-            Ok(ty) => ty,
-            Err(_e) => {
-                return resolve_sequence_classfield_components(sequence, resolver);
+    for c in root_components {
+        let seq_component = match c {
+            RootComponent::Defined(c) => {
+                let ty = match resolve_type(&c.component.ty, resolver) {
+                    Ok(ty) => ty,
+                    Err(_e) => {
+                        return resolve_sequence_classfield_components(sequence, resolver);
+                    }
+                };
+                resolve_seq_component(c, ty, tags.next().flatten(), resolver)?
+            }
+            RootComponent::Included(mut c) => {
+                c.component.tag = tags.next().flatten();
+                *c
             }
-        };
-        let component = ResolvedComponent {
-            id: c.component.id.clone(),
-            ty,
-        };
-        let seq_component = ResolvedSeqComponent {
-            component,
-            optional: c.optional,
-            class_field_type: None,
-            key_field: false,
         };
         components.push(seq_component);
     }
 
+    let mut additions = vec![];
+    for a in &sequence.additions {
+        let mut addition_components = vec![];
+        for c in a.components() {
+            let ty = resolve_type(&c.component.ty, resolver)?;
+            let seq_component = resolve_seq_component(c, ty, tags.next().flatten(), resolver)?;
+            addition_components.push(seq_component);
+        }
+        let addition = match a {
+            SeqAddition::Component(_) => {
+                ResolvedSeqAddition::Component(Box::new(addition_components.remove(0)))
+            }
+            SeqAddition::Group(ref g) => ResolvedSeqAddition::Group {
+                version: g.version.clone(),
+                components: addition_components,
+            },
+        };
+        additions.push(addition);
+    }
+
     Ok(Asn1ResolvedType::Constructed(
         ResolvedConstructedType::Sequence {
             components,
+            additions,
             extensible: sequence.extensible,
             name: None,
         },
     ))
 }
 
+// A root component of a `SEQUENCE`: either defined in the `SEQUENCE` or included using
+// `COMPONENTS OF`.
+enum RootComponent<'a> {
+    Defined(&'a SeqComponent),
+    Included(Box<ResolvedSeqComponent>),
+}
+
+// Resolves the Type in `COMPONENTS OF Type` and returns it's root components. The Type should be
+// a `SET` if `set` is set, else a `SEQUENCE`. The Extension Additions of the Type are not included
+// (X.680 25.5).
+fn resolve_components_of(
+    ty: &Asn1Type,
+    set: bool,
+    resolver: &mut Resolver,
+) -> Result<Vec<ResolvedSeqComponent>, Error> {
+    let mut resolved = resolve_type(ty, resolver)?;
+    loop {
+        match resolved {
+            Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+                components, ..
+            }) if !set => return Ok(components),
+            Asn1ResolvedType::Constructed(ResolvedConstructedType::Set { components, .. })
+                if set =>
+            {
+                return Ok(components)
+            }
+            Asn1ResolvedType::Reference(ref r) => match resolver.resolved_defs.get(r) {
+                Some(Asn1ResolvedDefinition::Type(ty)) => resolved = ty.clone(),
+                _ => return Err(resolve_error!("Type '{}' not resolved yet!", r)),
+            },
+            other => {
+                let expected = if set { "SET" } else { "SEQUENCE" };
+                return Err(resolve_error!(
+                    "`COMPONENTS OF` in a `{}` requires a `{}` Type. Found '{:#?}'",
+                    expected,
+                    expected,
+                    other
+                ));
+            }
+        }
+    }
+}
+
+fn resolve_seq_component(
+    c: &SeqComponent,
+    ty: Asn1ResolvedType,
+    tag: Option<Asn1ResolvedTag>,
+    resolver: &mut Resolver,
+) -> Result<ResolvedSeqComponent, Error> {
+    let default = match c.default {
+        Some(ref value) => Some(resolve_default_value(value, &ty, resolver)?),
+        None => None,
+    };
+    let component = ResolvedComponent {
+        id: c.component.id.clone(),
+        ty,
+        tag,
+    };
+    Ok(ResolvedSeqComponent {
+        component,
+        optional: c.optional,
+        class_field_type: None,
+        key_field: false,
+        default,
+        constraints: ResolvedComponentConstraints::default(),
+    })
+}
+
 fn resolve_sequence_of_type(
     sequence_of: &Asn1TypeSequenceOf,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
     let resolved = resolve_type(&sequence_of.ty, resolver)?;
-    let size_values = if sequence_of.size.is_some() {
-        let size = sequence_of.size.as_ref().unwrap();
+    let size_values = if let Some(size) = &sequence_of.size {
         Some(size.get_size_valueset(resolver)?)
     } else {
         None
@@ -139,22 +292,288 @@ fn resolve_sequence_of_type(
             ty: Box::new(resolved),
             name: None,
             size_values,
+            entry_values: None,
         },
     ))
 }
 
+// The components of a `SET` are resolved as those of a `SEQUENCE` and are then sorted in the
+// canonical order of their Tags, which is the order in which they are encoded.
+fn resolve_set_type(
+    set: &Asn1TypeSequence,
+    resolver: &mut Resolver,
+) -> Result<Asn1ResolvedType, Error> {
+    let (extensible, components, additions) = match resolve_sequence_type(set, true, resolver)? {
+        Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+            extensible,
+            components,
+            additions,
+            ..
+        }) => (extensible, components, additions),
+        other => {
+            return Err(resolve_error!(
+                "Expected resolved components of a `SET`. Found '{:#?}'",
+                other
+            ))
+        }
+    };
+
+    let mut tagged_components = vec![];
+    for c in components {
+        let tag = canonical_tag(&c.component, resolver)?;
+        tagged_components.push((tag, c));
+    }
+    tagged_components.sort_by_key(|(tag, _)| *tag);
+
+    if let Some(duplicates) = tagged_components.windows(2).find(|w| w[0].0 == w[1].0) {
+        return Err(resolve_error!(
+            "Components '{}' and '{}' of a `SET` have the same Tag.",
+            duplicates[0].1.component.id,
+            duplicates[1].1.component.id
+        ));
+    }
+
+    Ok(Asn1ResolvedType::Constructed(
+        ResolvedConstructedType::Set {
+            name: None,
+            extensible,
+            components: tagged_components.into_iter().map(|(_, c)| c).collect(),
+            additions,
+        },
+    ))
+}
+
+fn resolve_set_of_type(
+    set_of: &Asn1TypeSequenceOf,
+    resolver: &mut Resolver,
+) -> Result<Asn1ResolvedType, Error> {
+    let resolved = resolve_type(&set_of.ty, resolver)?;
+    let size_values = if let Some(size) = &set_of.size {
+        Some(size.get_size_valueset(resolver)?)
+    } else {
+        None
+    };
+
+    Ok(Asn1ResolvedType::Constructed(
+        ResolvedConstructedType::SetOf {
+            ty: Box::new(resolved),
+            name: None,
+            size_values,
+            entry_values: None,
+        },
+    ))
+}
+
+// Applies the Inner Type Constraints (`WITH COMPONENT` and `WITH COMPONENTS`) in `constraints` to
+// the resolved Type.
+//
+// A referenced Type with Inner Type Constraints is resolved to a copy of the referenced Type with
+// the constraints applied, so that the constraints are checked only for the values of the
+// constrained Type. The constraints that cannot be checked for each component separately (eg. a
+// union of `WITH COMPONENTS`) are ignored.
+pub(crate) fn resolve_inner_type_constraints(
+    resolved: Asn1ResolvedType,
+    constraints: Option<&[Asn1Constraint]>,
+    resolver: &Resolver,
+) -> Result<Asn1ResolvedType, Error> {
+    let inner_constraints = constraints
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|c| c.get_inner_type_constraints())
+        .collect::<Vec<_>>();
+    if inner_constraints.is_empty() {
+        return Ok(resolved);
+    }
+
+    let mut constructed = resolved.clone();
+    loop {
+        match constructed {
+            // Inner Type Constraints on the alternatives of a `CHOICE` are not checked.
+            Asn1ResolvedType::Constructed(ResolvedConstructedType::Choice { .. }) => {
+                return Ok(resolved)
+            }
+            Asn1ResolvedType::Constructed(..) => break,
+            Asn1ResolvedType::Reference(ref r) => match resolver.resolved_defs.get(r) {
+                Some(Asn1ResolvedDefinition::Type(ty)) => constructed = ty.clone(),
+                _ => return Err(resolve_error!("Type '{}' not resolved yet!", r)),
+            },
+            _ => {
+                return Err(resolve_error!(
+                    "Inner Type Constraints require a Constructed Type. Found '{:#?}'",
+                    constructed
+                ))
+            }
+        }
+    }
+
+    if let Asn1ResolvedType::Constructed(ref mut ty) = constructed {
+        for element in inner_constraints {
+            apply_inner_type_constraint(ty, element, resolver)?;
+        }
+    }
+    Ok(constructed)
+}
+
+fn apply_inner_type_constraint(
+    ty: &mut ResolvedConstructedType,
+    element: &SubtypeElements,
+    resolver: &Resolver,
+) -> Result<(), Error> {
+    match (ty, element) {
+        (
+            ResolvedConstructedType::SequenceOf {
+                ty, entry_values, ..
+            }
+            | ResolvedConstructedType::SetOf {
+                ty, entry_values, ..
+            },
+            SubtypeElements::SingleTypeConstraint(ref constraint),
+        ) => {
+            if let Some(values) = apply_value_constraint(ty, constraint, resolver)? {
+                *entry_values = Some(Box::new(values));
+            }
+        }
+        (
+            ResolvedConstructedType::Sequence {
+                components,
+                additions,
+                ..
+            }
+            | ResolvedConstructedType::Set {
+                components,
+                additions,
+                ..
+            },
+            SubtypeElements::MultipleTypeConstraints {
+                partial,
+                ref constraints,
+            },
+        ) => {
+            let root_components = components.iter_mut().map(|c| (c, false));
+            // A single Extension Addition may be absent, like an `OPTIONAL` component.
+            let addition_components = additions.iter_mut().flat_map(|a| match a {
+                ResolvedSeqAddition::Component(c) => vec![(c.as_mut(), true)],
+                ResolvedSeqAddition::Group { components, .. } => {
+                    components.iter_mut().map(|c| (c, false)).collect()
+                }
+            });
+            let mut all_components = root_components
+                .chain(addition_components)
+                .collect::<Vec<_>>();
+
+            if let Some(unknown) = constraints
+                .iter()
+                .find(|nc| !all_components.iter().any(|(c, _)| c.component.id == nc.id))
+            {
+                return Err(resolve_error!(
+                    "Component '{}' in `WITH COMPONENTS` not found in the constrained Type.",
+                    unknown.id
+                ));
+            }
+
+            for (c, is_addition) in all_components.iter_mut() {
+                let optional = c.default.is_none() && (c.optional || *is_addition);
+                let named = constraints.iter().find(|nc| nc.id == c.component.id);
+                let presence = match named {
+                    Some(nc) => match nc.presence {
+                        Some(PresenceConstraint::Present) => Some(ResolvedPresence::Present),
+                        Some(PresenceConstraint::Absent) => Some(ResolvedPresence::Absent),
+                        _ => None,
+                    },
+                    // In a full specification, the `OPTIONAL` components that are not listed
+                    // are absent.
+                    None if !partial && optional => Some(ResolvedPresence::Absent),
+                    None => None,
+                };
+                if presence.is_some() {
+                    if !optional {
+                        return Err(resolve_error!(
+                            "Presence constraint for the component '{}' that is not `OPTIONAL`.",
+                            c.component.id
+                        ));
+                    }
+                    c.constraints.presence = presence;
+                }
+
+                if let Some(constraint) = named.and_then(|nc| nc.value.as_ref()) {
+                    if let Some(values) =
+                        apply_value_constraint(&mut c.component.ty, constraint, resolver)?
+                    {
+                        c.constraints.values = Some(values);
+                    }
+                }
+            }
+        }
+        (ty, element) => {
+            return Err(resolve_error!(
+                "Inner Type Constraint '{:#?}' cannot be applied to the Type '{:#?}'.",
+                element,
+                ty
+            ))
+        }
+    }
+    Ok(())
+}
+
+// Applies the constraint on the values of a component (or the entries of a `SEQUENCE OF`) of
+// the Type `ty`.
+//
+// Inner Type Constraints are applied to the Type of the component. For an `INTEGER`, the allowed
+// values are returned, unless the constraint is extensible. Other constraints are ignored.
+fn apply_value_constraint(
+    ty: &mut Asn1ResolvedType,
+    constraint: &Asn1Constraint,
+    resolver: &Resolver,
+) -> Result<Option<ResolvedIntegerValues>, Error> {
+    if !constraint.get_inner_type_constraints().is_empty() {
+        *ty = resolve_inner_type_constraints(
+            ty.clone(),
+            Some(std::slice::from_ref(constraint)),
+            resolver,
+        )?;
+        return Ok(None);
+    }
+
+    let mut base = &*ty;
+    let integer = loop {
+        match base {
+            Asn1ResolvedType::Base(ResolvedBaseType::Integer(ref i)) => break i,
+            Asn1ResolvedType::Reference(ref r) => match resolver.resolved_defs.get(r) {
+                Some(Asn1ResolvedDefinition::Type(ty)) => base = ty,
+                _ => return Err(resolve_error!("Type '{}' not resolved yet!", r)),
+            },
+            _ => return Ok(None),
+        }
+    };
+
+    match constraint.get_integer_valueset(resolver) {
+        Ok(values) if !values.has_extension() && !values.root_values.is_empty() => {
+            Ok(Some(ResolvedIntegerValues {
+                ty: integer.clone(),
+                values,
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
 fn resolve_sequence_classfield_components(
     seq: &Asn1TypeSequence,
     resolver: &mut Resolver,
 ) -> Result<Asn1ResolvedType, Error> {
+    if !seq.components_of.is_empty() {
+        return Err(resolve_error!(
+            "`COMPONENTS OF` is not supported with Class Field Reference Components."
+        ));
+    }
+
     let mut all_components = vec![];
     all_components.extend(seq.root_components.clone());
     all_components.extend(
         seq.additions
             .clone()
             .iter()
-            .map(|a| a.components.clone())
-            .flatten()
+            .flat_map(|a| a.components().to_vec())
             .collect::<Vec<SeqComponent>>(),
     );
     let all_components = all_components
@@ -181,27 +600,35 @@ fn resolve_sequence_classfield_components(
             set_reference
         ));
     }
+    let all_types = all_components
+        .iter()
+        .map(|c| &c.ty)
+        .collect::<Vec<&Asn1Type>>();
+    let tags = resolve_component_tags(&all_types, resolver)?;
+
     if let Some(Asn1ResolvedDefinition::ObjectSet(ref set)) = objects {
         let objects = &set.objects;
         let components =
-            resolve_seq_components_for_objects(&all_components, &set_reference, objects)?;
+            resolve_seq_components_for_objects(&all_components, &tags, &set_reference, objects)?;
         Ok(Asn1ResolvedType::Constructed(
             ResolvedConstructedType::Sequence {
                 name: None,
                 extensible: seq.extensible,
                 components,
+                additions: vec![],
             },
         ))
     } else {
-        return Err(resolve_error!(
+        Err(resolve_error!(
             "Object Set '{}' not resolved yet!",
             set_reference
-        ));
+        ))
     }
 }
 
 fn resolve_seq_components_for_objects(
     input_components: &[Component],
+    tags: &[Option<Asn1ResolvedTag>],
     set_reference: &str,
     objects: &ResolvedObjectSet,
 ) -> Result<Vec<ResolvedSeqComponent>, Error> {
@@ -210,7 +637,7 @@ fn resolve_seq_components_for_objects(
     }
     let first = &objects.elements[0];
     let mut result = vec![];
-    for component in input_components {
+    for (component, tag) in input_components.iter().zip(tags) {
         if let Asn1TypeKind::Reference(Asn1TypeReference::ClassField { fieldref, .. }) =
             &component.ty.kind
         {
@@ -222,12 +649,15 @@ fn resolve_seq_components_for_objects(
                     let component = ResolvedComponent {
                         id: component.id.clone(),
                         ty: typeref.clone(),
+                        tag: *tag,
                     };
                     let seq_component = ResolvedSeqComponent {
                         component,
                         optional: false, // FIXME:
                         class_field_type: Some(ClassFieldComponentType::FixedTypeValue),
                         key_field: comp_spec.is_none(),
+                        default: None,
+                        constraints: ResolvedComponentConstraints::default(),
                     };
                     result.push(seq_component);
                 } else {
@@ -239,16 +669,31 @@ fn resolve_seq_components_for_objects(
                     let component = ResolvedComponent {
                         id: component.id.clone(),
                         ty: Asn1ResolvedType::Set(ty),
+                        tag: *tag,
                     };
                     let seq_component = ResolvedSeqComponent {
                         component,
                         optional: false, // FIXME:
                         class_field_type: Some(ClassFieldComponentType::Type),
                         key_field: false,
+                        default: None,
+                        constraints: ResolvedComponentConstraints::default(),
                     };
                     result.push(seq_component);
                 }
             }
+        } else if let Asn1TypeKind::Builtin(Asn1BuiltinType::Any { ref defined_by }) =
+            &component.ty.kind
+        {
+            let seq_component = resolve_any_component_for_objects(
+                component,
+                *tag,
+                defined_by.as_deref(),
+                &result,
+                set_reference,
+                objects,
+            )?;
+            result.push(seq_component);
         }
     }
     // Only add to the result if Every Component in the Input is also found in the Object. This
@@ -257,6 +702,66 @@ fn resolve_seq_components_for_objects(
     Ok(result)
 }
 
+// Resolves an `ANY` component of a `SEQUENCE` with Class Field Reference components.
+//
+// If the `ANY` is `DEFINED BY` a key field of the `SEQUENCE`, the `&Type` field of the objects in
+// the Object Set of the key field gives the actual Type for each value of the key field, so the
+// component is resolved to an Open Type. Otherwise the component is just the raw encoded bytes.
+fn resolve_any_component_for_objects(
+    component: &Component,
+    tag: Option<Asn1ResolvedTag>,
+    defined_by: Option<&str>,
+    resolved: &[ResolvedSeqComponent],
+    set_reference: &str,
+    objects: &ResolvedObjectSet,
+) -> Result<ResolvedSeqComponent, Error> {
+    let is_key_field = |id: &str| resolved.iter().any(|c| c.key_field && c.component.id == id);
+
+    // There should be exactly one `&Type` field in the objects to look up the Type.
+    let type_fields = match objects.elements.first() {
+        Some(ResolvedObjectSetElement::Object(ref o)) => o
+            .fields
+            .iter()
+            .filter(|(_, spec)| matches!(spec, ResolvedFieldSpec::Type { .. }))
+            .map(|(fieldref, _)| fieldref.clone())
+            .collect::<Vec<String>>(),
+        _ => vec![],
+    };
+
+    let (ty, class_field_type) = match defined_by {
+        Some(id) if is_key_field(id) && type_fields.len() == 1 => {
+            let types = get_seq_component_for_object_set(&type_fields[0], objects)?;
+            let ty = ResolvedSetType {
+                setref: set_reference.to_string(),
+                types,
+            };
+            (
+                Asn1ResolvedType::Set(ty),
+                Some(ClassFieldComponentType::AnyDefinedBy),
+            )
+        }
+        _ => (
+            Asn1ResolvedType::Base(ResolvedBaseType::Any(Asn1ResolvedAny {
+                any_type: "ANY".to_string(),
+            })),
+            None,
+        ),
+    };
+
+    Ok(ResolvedSeqComponent {
+        component: ResolvedComponent {
+            id: component.id.clone(),
+            ty,
+            tag,
+        },
+        optional: false, // FIXME:
+        class_field_type,
+        key_field: false,
+        default: None,
+        constraints: ResolvedComponentConstraints::default(),
+    })
+}
+
 fn get_seq_component_for_object_set(
     fieldref: &str,
     objects: &ResolvedObjectSet,
@@ -277,3 +782,90 @@ fn get_seq_component_for_object_set(
     }
     Ok(types)
 }
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::parser::asn::types::parse_type;
+    use crate::tokenizer::tokenize;
+
+    fn resolve(input: &str) -> Result<Asn1ResolvedType, Error> {
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+        let (ty, _) = parse_type(&tokens).unwrap();
+
+        let mut resolver = Resolver::new();
+        resolve_type(&ty, &mut resolver)
+    }
+
+    #[test]
+    fn resolve_inner_type_constraints_tests() {
+        let seq = "SEQUENCE { a INTEGER (0..15) OPTIONAL, b BOOLEAN OPTIONAL, c INTEGER (0..255) }";
+
+        let resolved = resolve(&format!(
+            "{} (WITH COMPONENTS {{ a (0..3) PRESENT, c }})",
+            seq
+        ));
+        let components = match resolved {
+            Ok(Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+                components,
+                ..
+            })) => components,
+            other => panic!("Expected a resolved SEQUENCE, Found {:#?}", other),
+        };
+        let presence = components
+            .iter()
+            .map(|c| c.constraints.presence)
+            .collect::<Vec<_>>();
+        // `b` is not listed in the full specification, so it is absent.
+        assert_eq!(
+            presence,
+            vec![
+                Some(ResolvedPresence::Present),
+                Some(ResolvedPresence::Absent),
+                None
+            ]
+        );
+        let values = components[0].constraints.values.as_ref().unwrap();
+        assert_eq!(values.values.root_values.min(), Some(0));
+        assert_eq!(values.values.root_values.max(), Some(3));
+        assert!(components[2].constraints.values.is_none());
+
+        // Unknown components and the presence of components that are not `OPTIONAL`.
+        assert!(resolve(&format!("{} (WITH COMPONENTS {{ ..., d PRESENT }})", seq)).is_err());
+        assert!(resolve(&format!("{} (WITH COMPONENTS {{ ..., c ABSENT }})", seq)).is_err());
+    }
+
+    #[test]
+    fn resolve_components_of_type_tests() {
+        let mut resolver = Resolver::new();
+        for (name, input) in [
+            ("Seq", "SEQUENCE { a INTEGER }"),
+            ("Set", "SET { b BOOLEAN }"),
+        ] {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let (ty, _) = parse_type(&tokens).unwrap();
+            let resolved = resolve_type(&ty, &mut resolver).unwrap();
+            resolver
+                .resolved_defs
+                .insert(name.to_string(), Asn1ResolvedDefinition::Type(resolved));
+        }
+
+        let test_cases = vec![
+            ("SEQUENCE { COMPONENTS OF Seq, c NULL }", true),
+            ("SET { COMPONENTS OF Set, c NULL }", true),
+            // A `SEQUENCE` cannot include the components of a `SET` and vice versa (X.680 25.5).
+            ("SEQUENCE { COMPONENTS OF Set, c NULL }", false),
+            ("SET { COMPONENTS OF Seq, c NULL }", false),
+        ];
+        for (input, success) in test_cases {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let (ty, _) = parse_type(&tokens).unwrap();
+            let resolved = resolve_type(&ty, &mut resolver);
+            assert_eq!(resolved.is_ok(), success, "{}", input);
+        }
+    }
+}
@@ -8,9 +8,16 @@ use crate::resolver::{
     asn::{
         structs::{
             defs::Asn1ResolvedDefinition,
-            types::{constraints::Asn1ConstraintValueSet, Asn1ResolvedType},
+            types::{
+                constraints::Asn1ConstraintValueSet,
+                constructed::{ResolvedComponent, ResolvedConstructedType},
+                Asn1ResolvedType,
+            },
+        },
+        types::{
+            base::resolve_base_type,
+            constructed::{resolve_constructed_type, resolve_inner_type_constraints},
         },
-        types::{base::resolve_base_type, constructed::resolve_constructed_type},
     },
     Resolver,
 };
@@ -65,9 +72,11 @@ fn resolve_reference_type(
                 let resolved = resolver.resolved_defs.get(r);
                 match resolved {
                     Some(res) => match res {
-                        Asn1ResolvedDefinition::Type(..) => {
-                            Ok(Asn1ResolvedType::Reference(r.to_string()))
-                        }
+                        Asn1ResolvedDefinition::Type(..) => resolve_inner_type_constraints(
+                            Asn1ResolvedType::Reference(r.to_string()),
+                            ty.constraints.as_deref(),
+                            resolver,
+                        ),
                         _ => Err(resolve_error!(
                             "Expected a Resolved Type, found {:#?}",
                             resolved
@@ -95,8 +104,122 @@ fn resolve_reference_type(
             Asn1TypeReference::ClassField { .. } => Err(resolve_error!(
                 "Supported Inside Constructed Sequence Type."
             )),
+            Asn1TypeReference::Selection {
+                identifier,
+                typeref,
+            } => {
+                let selected = resolve_selected_alternative(identifier, typeref, resolver)?;
+                resolve_inner_type_constraints(selected.ty, ty.constraints.as_deref(), resolver)
+            }
         }
     } else {
         Err(resolve_error!("Expected Reference Type. Found '{:#?}'", ty))
     }
 }
+
+// Returns the alternative `identifier` of the `CHOICE` Type `typeref`, which is the Type denoted by
+// the Selection Type `identifier < typeref`.
+pub(crate) fn resolve_selected_alternative(
+    identifier: &str,
+    typeref: &str,
+    resolver: &Resolver,
+) -> Result<ResolvedComponent, Error> {
+    let mut reference = typeref;
+    loop {
+        match resolver.resolved_defs.get(reference) {
+            Some(Asn1ResolvedDefinition::Type(Asn1ResolvedType::Reference(ref r))) => {
+                reference = r;
+            }
+            Some(Asn1ResolvedDefinition::Type(Asn1ResolvedType::Constructed(
+                ResolvedConstructedType::Choice {
+                    ref root_components,
+                    ref additions,
+                    ..
+                },
+            ))) => {
+                return root_components
+                    .iter()
+                    .chain(additions.iter().flatten())
+                    .find(|c| c.id == identifier)
+                    .cloned()
+                    .ok_or_else(|| {
+                        resolve_error!(
+                            "'{}' is not an alternative of the CHOICE Type '{}'.",
+                            identifier,
+                            typeref
+                        )
+                    });
+            }
+            Some(Asn1ResolvedDefinition::Type(..)) => {
+                return Err(resolve_error!(
+                    "Selection Type '{} < {}' requires a CHOICE Type.",
+                    identifier,
+                    typeref
+                ));
+            }
+            _ => {
+                return Err(resolve_error!(
+                    "Referenced Type for '{}' Not resolved yet!",
+                    reference
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::parser::asn::types::parse_type;
+    use crate::resolver::asn::structs::types::base::ResolvedBaseType;
+    use crate::tokenizer::tokenize;
+
+    fn parse(input: &str) -> Asn1Type {
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+        parse_type(&tokens).unwrap().0
+    }
+
+    #[test]
+    fn resolve_selection_type_tests() {
+        let mut resolver = Resolver::new();
+        let request = parse("SEQUENCE { id INTEGER }");
+        let request = resolve_type(&request, &mut resolver).unwrap();
+        resolver
+            .resolved_defs
+            .insert("Request".to_string(), Asn1ResolvedDefinition::Type(request));
+        let choice = parse("CHOICE { a [0] INTEGER, b Request, ..., c [2] IMPLICIT BOOLEAN }");
+        let choice = resolve_type(&choice, &mut resolver).unwrap();
+        resolver
+            .resolved_defs
+            .insert("Message".to_string(), Asn1ResolvedDefinition::Type(choice));
+        resolver.resolved_defs.insert(
+            "Alias".to_string(),
+            Asn1ResolvedDefinition::Type(Asn1ResolvedType::Reference("Message".to_string())),
+        );
+
+        let resolved = resolve_type(&parse("b < Message"), &mut resolver);
+        assert!(
+            matches!(resolved, Ok(Asn1ResolvedType::Reference(ref r)) if r == "Request"),
+            "{:#?}",
+            resolved
+        );
+
+        // An Extension Addition of a `CHOICE` referenced through another Type.
+        let resolved = resolve_type(&parse("c < Alias"), &mut resolver);
+        assert!(
+            matches!(
+                resolved,
+                Ok(Asn1ResolvedType::Base(ResolvedBaseType::Boolean(..)))
+            ),
+            "{:#?}",
+            resolved
+        );
+        let selected = resolve_selected_alternative("c", "Alias", &resolver).unwrap();
+        assert_eq!(selected.tag.map(|t| t.to_string()).unwrap(), "[2] IMPLICIT");
+
+        assert!(resolve_type(&parse("d < Message"), &mut resolver).is_err());
+        assert!(resolve_type(&parse("b < Unknown"), &mut resolver).is_err());
+    }
+}
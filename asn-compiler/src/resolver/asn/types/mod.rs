@@ -4,4 +4,9 @@ pub(crate) mod constructed;
 pub(crate) mod ioc;
 
 mod int;
-pub(crate) use int::resolve_type;
+pub(crate) use int::{resolve_selected_alternative, resolve_type};
+
+mod tags;
+pub(crate) use tags::{
+    canonical_tag, resolve_component_tags, resolve_tagged_component_tags, TaggedComponent,
+};
@@ -0,0 +1,445 @@
+//! Resolution of the Tags of the Tagged Types and the automatic tagging of the components.
+
+use crate::error::Error;
+
+use crate::parser::asn::structs::{
+    module::Asn1ModuleTag,
+    types::{
+        Asn1ConstructedType, Asn1TagClass, Asn1TagMode, Asn1Type, Asn1TypeKind, Asn1TypeReference,
+    },
+};
+
+use crate::resolver::{
+    asn::structs::{
+        defs::Asn1ResolvedDefinition,
+        types::{
+            base::ResolvedBaseType,
+            constructed::{ResolvedComponent, ResolvedConstructedType},
+            Asn1ResolvedTag, Asn1ResolvedType,
+        },
+    },
+    Resolver,
+};
+
+use super::resolve_selected_alternative;
+
+impl Asn1Type {
+    // Resolves the Tag of the Type, if the Type is a Tagged Type.
+    //
+    // If the Tag does not specify `IMPLICIT` or `EXPLICIT`, the Tag is `EXPLICIT` in a module with
+    // `EXPLICIT TAGS` and `IMPLICIT` otherwise, except when the Type being tagged is an untagged
+    // `CHOICE` or an Open Type, for which the Tag is always `EXPLICIT`.
+    pub(crate) fn resolve_tag(
+        &self,
+        resolver: &Resolver,
+    ) -> Result<Option<Asn1ResolvedTag>, Error> {
+        let tag = match self.tag {
+            Some(ref tag) => tag,
+            None => return Ok(None),
+        };
+
+        let requires_explicit = requires_explicit_tag(self, resolver)?;
+        let explicit = match tag.mode {
+            Some(Asn1TagMode::Explicit) => true,
+            Some(Asn1TagMode::Implicit) => {
+                if requires_explicit {
+                    return Err(resolve_error!(
+                        "`IMPLICIT` Tag cannot be used for a `CHOICE` or an Open Type: {:#?}",
+                        self
+                    ));
+                }
+                false
+            }
+            None => resolver.tag_default == Asn1ModuleTag::Explicit || requires_explicit,
+        };
+
+        Ok(Some(Asn1ResolvedTag {
+            class: tag.class,
+            number: tag.number,
+            explicit,
+        }))
+    }
+}
+
+// A component whose Tag is to be resolved: either a component defined in the Type or a component
+// included using `COMPONENTS OF` (which is already resolved).
+pub(crate) enum TaggedComponent<'a> {
+    Defined(&'a Asn1Type),
+    Included(&'a ResolvedComponent),
+}
+
+// Resolves the Tags of the components of a `SEQUENCE` or a `CHOICE` (in the order of the
+// definition, the root components followed by the Extension Additions).
+//
+// In a module with `AUTOMATIC TAGS`, if none of the components is tagged, the components are
+// tagged `[0]`, `[1]` ... (`IMPLICIT`, except for `CHOICE`s and Open Types).
+pub(crate) fn resolve_component_tags(
+    components: &[&Asn1Type],
+    resolver: &Resolver,
+) -> Result<Vec<Option<Asn1ResolvedTag>>, Error> {
+    let components = components
+        .iter()
+        .map(|ty| TaggedComponent::Defined(ty))
+        .collect::<Vec<_>>();
+
+    resolve_tagged_component_tags(&components, resolver)
+}
+
+// Resolves the Tags of the components, including the components included using `COMPONENTS OF`.
+//
+// The components included using `COMPONENTS OF` are not considered for deciding whether the
+// automatic tagging applies (X.680 25.3), but they are tagged automatically if it does (the Tags
+// they were resolved with are replaced). Else they retain the Tags they were resolved with.
+pub(crate) fn resolve_tagged_component_tags(
+    components: &[TaggedComponent<'_>],
+    resolver: &Resolver,
+) -> Result<Vec<Option<Asn1ResolvedTag>>, Error> {
+    let automatic = resolver.tag_default == Asn1ModuleTag::Automatic
+        && components.iter().all(|c| match c {
+            TaggedComponent::Defined(ty) => ty.tag.is_none(),
+            TaggedComponent::Included(_) => true,
+        });
+
+    let mut tags = vec![];
+    for (number, c) in components.iter().enumerate() {
+        let tag = match c {
+            TaggedComponent::Defined(ty) if automatic => Some(Asn1ResolvedTag {
+                class: Asn1TagClass::ContextSpecific,
+                number: number as u32,
+                explicit: requires_explicit_tag(ty, resolver)?,
+            }),
+            TaggedComponent::Defined(ty) => ty.resolve_tag(resolver)?,
+            TaggedComponent::Included(c) if automatic => Some(Asn1ResolvedTag {
+                class: Asn1TagClass::ContextSpecific,
+                number: number as u32,
+                explicit: is_untagged_choice_or_open_resolved_type(&c.ty, resolver),
+            }),
+            TaggedComponent::Included(c) => c.tag,
+        };
+        tags.push(tag);
+    }
+
+    Ok(tags)
+}
+
+// Whether the Type (ignoring it's own Tag) is an untagged `CHOICE` or an Open Type.
+fn requires_explicit_tag(ty: &Asn1Type, resolver: &Resolver) -> Result<bool, Error> {
+    match ty.kind {
+        Asn1TypeKind::Constructed(Asn1ConstructedType::Choice(..)) => Ok(true),
+        Asn1TypeKind::Reference(Asn1TypeReference::ClassField { ref fieldref, .. }) => {
+            // Type fields (eg. `&Value`) are Open Types, value fields (eg. `&id`) are not.
+            Ok(fieldref
+                .chars()
+                .nth(1)
+                .is_some_and(|c| c.is_ascii_uppercase()))
+        }
+        Asn1TypeKind::Reference(Asn1TypeReference::Reference(ref r)) => {
+            Ok(is_untagged_choice_or_open_type(r, resolver))
+        }
+        Asn1TypeKind::Reference(Asn1TypeReference::Parameterized {
+            ref typeref,
+            ref params,
+        }) => match resolver.parameterized_defs.get(typeref) {
+            Some(def) => {
+                let ty = def.apply_params(params)?;
+                Ok(ty.tag.is_none() && requires_explicit_tag(&ty, resolver)?)
+            }
+            None => Ok(false),
+        },
+        Asn1TypeKind::Reference(Asn1TypeReference::Selection {
+            ref identifier,
+            ref typeref,
+        }) => {
+            let selected = resolve_selected_alternative(identifier, typeref, resolver)?;
+            Ok(selected.tag.is_none()
+                && is_untagged_choice_or_open_resolved_type(&selected.ty, resolver))
+        }
+        _ => Ok(false),
+    }
+}
+
+// Whether the Type definition `reference` is (or refers to) an untagged `CHOICE` or an Open Type.
+fn is_untagged_choice_or_open_type(reference: &str, resolver: &Resolver) -> bool {
+    if resolver.tags.contains_key(reference) {
+        return false;
+    }
+    match resolver.resolved_defs.get(reference) {
+        Some(Asn1ResolvedDefinition::Type(ty)) => {
+            is_untagged_choice_or_open_resolved_type(ty, resolver)
+        }
+        _ => false,
+    }
+}
+
+// Whether the resolved Type (ignoring it's own Tag) is an untagged `CHOICE` or an Open Type.
+fn is_untagged_choice_or_open_resolved_type(ty: &Asn1ResolvedType, resolver: &Resolver) -> bool {
+    match ty {
+        Asn1ResolvedType::Constructed(ResolvedConstructedType::Choice { .. }) => true,
+        Asn1ResolvedType::Set(..) => true,
+        Asn1ResolvedType::Reference(ref r) => is_untagged_choice_or_open_type(r, resolver),
+        _ => false,
+    }
+}
+
+// Returns the Tag (class and number) of the component used for the canonical ordering of the
+// components of a `SET` (X.680 8.6). For an untagged component this is the Universal Tag of it's
+// Type, or the smallest Tag of the alternatives for an untagged `CHOICE`.
+pub(crate) fn canonical_tag(
+    component: &ResolvedComponent,
+    resolver: &Resolver,
+) -> Result<(Asn1TagClass, u32), Error> {
+    match component.tag {
+        Some(tag) => Ok((tag.class, tag.number)),
+        None => resolved_type_tag(&component.ty, resolver)?.ok_or_else(|| {
+            resolve_error!(
+                "Unable to determine the Tag of the component '{}'. An untagged Open Type cannot be a component of a `SET`.",
+                component.id
+            )
+        }),
+    }
+}
+
+// The Tag of an untagged resolved Type. Open Types (and `ANY`) do not have a Tag.
+fn resolved_type_tag(
+    ty: &Asn1ResolvedType,
+    resolver: &Resolver,
+) -> Result<Option<(Asn1TagClass, u32)>, Error> {
+    let number = match ty {
+        Asn1ResolvedType::Base(ref base) => match base {
+            ResolvedBaseType::Boolean(..) => 1,
+            ResolvedBaseType::Integer(..) => 2,
+            ResolvedBaseType::BitString(..) => 3,
+            ResolvedBaseType::OctetString(..) => 4,
+            ResolvedBaseType::Null(..) => 5,
+            ResolvedBaseType::ObjectIdentifier(..) => 6,
+            ResolvedBaseType::Enum(..) => 10,
+            ResolvedBaseType::CharacterString(ref c) => match c.str_type.as_str() {
+                "UTF8String" => 12,
+                "PrintableString" => 19,
+                "IA5String" => 22,
+                "VisibleString" => 26,
+                _ => 29,
+            },
+            ResolvedBaseType::Any(ref a) => match a.any_type.as_str() {
+                "EXTERNAL" => 8,
+                "EMBEDDED PDV" => 11,
+                _ => return Ok(None),
+            },
+        },
+        Asn1ResolvedType::Constructed(ref constructed) => match constructed {
+            ResolvedConstructedType::Sequence { .. }
+            | ResolvedConstructedType::SequenceOf { .. } => 16,
+            ResolvedConstructedType::Set { .. } | ResolvedConstructedType::SetOf { .. } => 17,
+            ResolvedConstructedType::Choice {
+                ref root_components,
+                ..
+            } => {
+                let mut tags = vec![];
+                for c in root_components {
+                    tags.push(canonical_tag(c, resolver)?);
+                }
+                return Ok(tags.into_iter().min());
+            }
+        },
+        Asn1ResolvedType::Reference(ref r) => {
+            if let Some(tag) = resolver.tags.get(r) {
+                return Ok(Some((tag.class, tag.number)));
+            }
+            return match resolver.resolved_defs.get(r) {
+                Some(Asn1ResolvedDefinition::Type(ty)) => resolved_type_tag(ty, resolver),
+                _ => Err(resolve_error!("Type '{}' not resolved yet!", r)),
+            };
+        }
+        Asn1ResolvedType::Set(..) => return Ok(None),
+    };
+
+    Ok(Some((Asn1TagClass::Universal, number)))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::parser::asn::types::parse_type;
+    use crate::resolver::asn::types::resolve_type;
+    use crate::tokenizer::tokenize;
+
+    #[test]
+    fn resolve_component_tags_tests() {
+        let test_cases = vec![
+            (
+                Asn1ModuleTag::Automatic,
+                "SEQUENCE { a INTEGER, b CHOICE { x NULL }, ..., c BOOLEAN }",
+                vec![
+                    Some("[0] IMPLICIT"),
+                    Some("[1] EXPLICIT"),
+                    Some("[2] IMPLICIT"),
+                ],
+            ),
+            (
+                Asn1ModuleTag::Automatic,
+                "SEQUENCE { a [5] INTEGER, b BOOLEAN }",
+                vec![Some("[5] IMPLICIT"), None],
+            ),
+            (
+                Asn1ModuleTag::Explicit,
+                "SEQUENCE { a INTEGER, b [APPLICATION 1] BOOLEAN, c [2] IMPLICIT NULL }",
+                vec![None, Some("[APPLICATION 1] EXPLICIT"), Some("[2] IMPLICIT")],
+            ),
+            (
+                Asn1ModuleTag::Implicit,
+                "SEQUENCE { a [0] INTEGER, b [1] CHOICE { x NULL } }",
+                vec![Some("[0] IMPLICIT"), Some("[1] EXPLICIT")],
+            ),
+        ];
+
+        for (tag_default, input, expected) in test_cases {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let (ty, _) = parse_type(&tokens).unwrap();
+            let seq = match ty.kind {
+                Asn1TypeKind::Constructed(Asn1ConstructedType::Sequence(ref seq)) => seq,
+                _ => panic!("{}: not a SEQUENCE", input),
+            };
+            let components = seq
+                .root_components
+                .iter()
+                .chain(seq.additions.iter().flat_map(|a| a.components().iter()))
+                .map(|c| &c.component.ty)
+                .collect::<Vec<&Asn1Type>>();
+
+            let mut resolver = Resolver::new();
+            resolver.tag_default = tag_default;
+            let tags = resolve_component_tags(&components, &resolver).unwrap();
+            let tags = tags
+                .iter()
+                .map(|t| t.map(|t| t.to_string()))
+                .collect::<Vec<_>>();
+            let expected = expected
+                .iter()
+                .map(|t| t.map(str::to_string))
+                .collect::<Vec<_>>();
+            assert_eq!(tags, expected, "{}", input);
+        }
+    }
+
+    #[test]
+    fn resolve_implicit_choice_tag_fails() {
+        let input = "[0] IMPLICIT CHOICE { x NULL }";
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+        let (ty, _) = parse_type(&tokens).unwrap();
+
+        let resolver = Resolver::new();
+        assert!(ty.resolve_tag(&resolver).is_err());
+    }
+
+    #[test]
+    fn resolve_set_canonical_order() {
+        let test_cases = vec![
+            (
+                "SET { a [1] INTEGER, b BOOLEAN, c [APPLICATION 0] NULL, d OCTET STRING }",
+                vec!["b", "d", "c", "a"],
+            ),
+            (
+                "SET { a CHOICE { x [4] NULL, y [3] NULL }, b [2] BOOLEAN, c [1] INTEGER }",
+                vec!["c", "b", "a"],
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let (ty, _) = parse_type(&tokens).unwrap();
+
+            let mut resolver = Resolver::new();
+            resolver.tag_default = Asn1ModuleTag::Implicit;
+            let resolved = resolve_type(&ty, &mut resolver).unwrap();
+            let ids = match resolved {
+                Asn1ResolvedType::Constructed(ResolvedConstructedType::Set {
+                    ref components,
+                    ..
+                }) => components
+                    .iter()
+                    .map(|c| c.component.id.as_str())
+                    .collect::<Vec<_>>(),
+                _ => panic!("{}: not a SET", input),
+            };
+            assert_eq!(ids, expected, "{}", input);
+        }
+
+        // Components with the same Tag are not allowed.
+        let input = "SET { a INTEGER, b INTEGER }";
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+        let (ty, _) = parse_type(&tokens).unwrap();
+        let mut resolver = Resolver::new();
+        assert!(resolve_type(&ty, &mut resolver).is_err());
+    }
+
+    #[test]
+    fn resolve_components_of_tags() {
+        fn resolve(input: &str, resolver: &mut Resolver) -> Asn1ResolvedType {
+            let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+            let tokens = tokenize(reader).unwrap();
+            let (ty, _) = parse_type(&tokens).unwrap();
+            resolve_type(&ty, resolver).unwrap()
+        }
+
+        let mut resolver = Resolver::new();
+        resolver.tag_default = Asn1ModuleTag::Automatic;
+        let header = resolve(
+            "SEQUENCE { a INTEGER, b CHOICE { x NULL }, ..., c BOOLEAN }",
+            &mut resolver,
+        );
+        resolver
+            .resolved_defs
+            .insert("Header".to_string(), Asn1ResolvedDefinition::Type(header));
+
+        // The included components are tagged automatically along with the others, the Extension
+        // Additions of the included Type are not included.
+        let test_cases = vec![
+            (
+                "SEQUENCE { d NULL, COMPONENTS OF Header, e BOOLEAN }",
+                vec![
+                    ("d", Some("[0] IMPLICIT")),
+                    ("a", Some("[1] IMPLICIT")),
+                    ("b", Some("[2] EXPLICIT")),
+                    ("e", Some("[3] IMPLICIT")),
+                ],
+            ),
+            (
+                "SEQUENCE { COMPONENTS OF Header, e [7] BOOLEAN }",
+                vec![
+                    ("a", Some("[0] IMPLICIT")),
+                    ("b", Some("[1] EXPLICIT")),
+                    ("e", Some("[7] IMPLICIT")),
+                ],
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            let components = match resolve(input, &mut resolver) {
+                Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+                    components,
+                    ..
+                }) => components,
+                _ => panic!("{}: not a SEQUENCE", input),
+            };
+            let components = components
+                .iter()
+                .map(|c| {
+                    (
+                        c.component.id.clone(),
+                        c.component.tag.map(|t| t.to_string()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let expected = expected
+                .iter()
+                .map(|(id, t)| (id.to_string(), t.map(str::to_string)))
+                .collect::<Vec<_>>();
+            assert_eq!(components, expected, "{}", input);
+        }
+    }
+}
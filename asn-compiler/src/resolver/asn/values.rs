@@ -7,8 +7,9 @@ use crate::resolver::{
         defs::Asn1ResolvedDefinition,
         types::{base::ResolvedBaseType, Asn1ResolvedType},
         values::{
-            Asn1ResolvedEnumValue, Asn1ResolvedIntegerValue, Asn1ResolvedValue, BaseInteger,
-            ResolvedBaseValue,
+            Asn1ResolvedBitStringValue, Asn1ResolvedBooleanValue, Asn1ResolvedCharacterStringValue,
+            Asn1ResolvedEnumValue, Asn1ResolvedIntegerValue, Asn1ResolvedOctetStringValue,
+            Asn1ResolvedValue, BaseBitString, BaseEnum, BaseInteger, ResolvedBaseValue,
         },
     },
     Resolver,
@@ -21,59 +22,9 @@ pub(crate) fn resolve_value(
 ) -> Result<Asn1ResolvedValue, Error> {
     let referenced_value = resolver.resolved_defs.get(value);
     match referenced_value {
-        None => {
-            match typeref {
-                Asn1ResolvedType::Base(ref b) => match b {
-                    ResolvedBaseType::Integer(ref _i) => {
-                        let value = value.parse::<BaseInteger>().unwrap();
-                        Ok(Asn1ResolvedValue::Base(ResolvedBaseValue::Integer(
-                            Asn1ResolvedIntegerValue {
-                                typeref: typeref.clone(),
-                                value,
-                            },
-                        )))
-                    }
-                    ResolvedBaseType::Enum(ref _e) => {
-                        // FIXME: When Enum is resolved we'd get it right
-                        // let value = value.parse::<BaseEnum>().unwrap();
-                        let value = 0;
-                        Ok(Asn1ResolvedValue::Base(ResolvedBaseValue::Enum(
-                            Asn1ResolvedEnumValue {
-                                typeref: typeref.clone(),
-                                value,
-                            },
-                        )))
-                    }
-                    _ => Err(resolve_error!(
-                        "resolve_value: Not Supported Yet! {:#?}",
-                        typeref
-                    )),
-                },
-                Asn1ResolvedType::Reference(ref r) => {
-                    let typedef = resolver.resolved_defs.get(r);
-                    match typedef {
-                        None => Err(resolve_error!(
-                            "Definition for Reference '{}' not found or not Resolved yet!",
-                            r
-                        )),
-                        Some(def) => match def {
-                            Asn1ResolvedDefinition::Type(ref t) => {
-                                let v = resolve_value(value, &t.clone(), resolver)?;
-                                Ok(Asn1ResolvedValue::ReferencedType {
-                                    value: Box::new(v),
-                                    typeref: r.clone(),
-                                })
-                            }
-                            _ => Err(resolve_error!(
-                                "Resolved Definition '{:#?}' is not a Type definition!",
-                                typedef
-                            )),
-                        },
-                    }
-                }
-                _ => Err(resolve_error!("resolve_value: Not Implemented!")),
-            }
-        }
+        None => resolve_typed_value(typeref, resolver, &|base, typeref| {
+            resolve_base_value(value, base, typeref)
+        }),
         Some(ref_value) => match ref_value {
             Asn1ResolvedDefinition::Value(ref _v) => {
                 Ok(Asn1ResolvedValue::Reference(value.to_string()))
@@ -82,3 +33,327 @@ pub(crate) fn resolve_value(
         },
     }
 }
+
+// Resolves the `DEFAULT` value of a component of the Type `typeref`. Unlike other values, a
+// reference to a value definition is replaced by the referenced value, so that the default value
+// can be generated.
+pub(crate) fn resolve_default_value(
+    value: &str,
+    typeref: &Asn1ResolvedType,
+    resolver: &mut Resolver,
+) -> Result<Asn1ResolvedValue, Error> {
+    match resolve_value(value, typeref, resolver)? {
+        Asn1ResolvedValue::Reference(reference) => {
+            let base = match resolver.resolved_defs.get(&reference) {
+                Some(Asn1ResolvedDefinition::Value(ref v)) => v.get_base_value().cloned(),
+                _ => None,
+            };
+            match base {
+                Some(base) => resolve_typed_value(typeref, resolver, &|b, typeref| {
+                    retype_base_value(&base, b, typeref)
+                }),
+                None => Err(resolve_error!(
+                    "Value '{}' not found or not Resolved yet!",
+                    reference
+                )),
+            }
+        }
+        resolved => Ok(resolved),
+    }
+}
+
+// Follows the references in `typeref` to the Base Type and resolves the value for the Base Type
+// using `resolve_base`.
+fn resolve_typed_value(
+    typeref: &Asn1ResolvedType,
+    resolver: &mut Resolver,
+    resolve_base: &dyn Fn(&ResolvedBaseType, &Asn1ResolvedType) -> Result<ResolvedBaseValue, Error>,
+) -> Result<Asn1ResolvedValue, Error> {
+    match typeref {
+        Asn1ResolvedType::Base(ref b) => Ok(Asn1ResolvedValue::Base(resolve_base(b, typeref)?)),
+        Asn1ResolvedType::Reference(ref r) => {
+            let typedef = resolver.resolved_defs.get(r);
+            match typedef {
+                None => Err(resolve_error!(
+                    "Definition for Reference '{}' not found or not Resolved yet!",
+                    r
+                )),
+                Some(def) => match def {
+                    Asn1ResolvedDefinition::Type(ref t) => {
+                        let v = resolve_typed_value(&t.clone(), resolver, resolve_base)?;
+                        Ok(Asn1ResolvedValue::ReferencedType {
+                            value: Box::new(v),
+                            typeref: r.clone(),
+                        })
+                    }
+                    _ => Err(resolve_error!(
+                        "Resolved Definition '{:#?}' is not a Type definition!",
+                        typedef
+                    )),
+                },
+            }
+        }
+        _ => Err(resolve_error!("resolve_value: Not Implemented!")),
+    }
+}
+
+fn resolve_base_value(
+    value: &str,
+    base: &ResolvedBaseType,
+    typeref: &Asn1ResolvedType,
+) -> Result<ResolvedBaseValue, Error> {
+    match base {
+        ResolvedBaseType::Integer(ref i) => {
+            // The value is either a number or one of the named numbers of the Type.
+            let named = i.named_values.as_ref().and_then(|n| n.get(value));
+            let value = match value.parse::<BaseInteger>() {
+                Ok(v) => v,
+                Err(_) => *named.ok_or_else(|| {
+                    resolve_error!("'{}' is not a valid value for an INTEGER.", value)
+                })?,
+            };
+            Ok(ResolvedBaseValue::Integer(Asn1ResolvedIntegerValue {
+                typeref: typeref.clone(),
+                value,
+            }))
+        }
+        ResolvedBaseType::Enum(ref e) => {
+            // The value is the index of the named value, the indices of the Extension Additions
+            // follow the indices of the Extension Root.
+            let root = e.named_root_values.iter().find(|(name, _)| name == value);
+            let ext = e.named_ext_values.iter().find(|(name, _)| name == value);
+            let index = match (root, ext) {
+                (Some((_, index)), _) => *index,
+                (None, Some((_, index))) => e.named_root_values.len() as i128 + *index,
+                (None, None) => {
+                    return Err(resolve_error!(
+                        "'{}' is not a named value of the ENUMERATED.",
+                        value
+                    ))
+                }
+            };
+            Ok(ResolvedBaseValue::Enum(Asn1ResolvedEnumValue {
+                typeref: typeref.clone(),
+                value: index as BaseEnum,
+            }))
+        }
+        ResolvedBaseType::Boolean(_) => {
+            let value = match value {
+                "TRUE" => true,
+                "FALSE" => false,
+                _ => {
+                    return Err(resolve_error!(
+                        "'{}' is not a valid value for a BOOLEAN.",
+                        value
+                    ))
+                }
+            };
+            Ok(ResolvedBaseValue::Boolean(Asn1ResolvedBooleanValue {
+                typeref: typeref.clone(),
+                value,
+            }))
+        }
+        ResolvedBaseType::BitString(_) => {
+            let value = parse_bstring_or_hstring(value).ok_or_else(|| {
+                resolve_error!("'{}' is not a valid value for a BIT STRING.", value)
+            })?;
+            Ok(ResolvedBaseValue::BitString(Asn1ResolvedBitStringValue {
+                typeref: typeref.clone(),
+                value,
+            }))
+        }
+        ResolvedBaseType::OctetString(_) => {
+            // The last octet is padded with zero bits.
+            let bits = parse_bstring_or_hstring(value).ok_or_else(|| {
+                resolve_error!("'{}' is not a valid value for an OCTET STRING.", value)
+            })?;
+            let value = bits
+                .chunks(8)
+                .map(|octet| {
+                    octet
+                        .iter()
+                        .enumerate()
+                        .fold(0u8, |acc, (i, bit)| acc | ((*bit as u8) << (7 - i)))
+                })
+                .collect();
+            Ok(ResolvedBaseValue::OctetString(
+                Asn1ResolvedOctetStringValue {
+                    typeref: typeref.clone(),
+                    value,
+                },
+            ))
+        }
+        ResolvedBaseType::CharacterString(_) => {
+            // A `"` inside the string is written as `""`.
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .ok_or_else(|| {
+                    resolve_error!("'{}' is not a valid value for a Character String.", value)
+                })?
+                .replace("\"\"", "\"");
+            Ok(ResolvedBaseValue::CharacterString(
+                Asn1ResolvedCharacterStringValue {
+                    typeref: typeref.clone(),
+                    value,
+                },
+            ))
+        }
+        _ => Err(resolve_error!(
+            "resolve_value: Not Supported Yet! {:#?}",
+            typeref
+        )),
+    }
+}
+
+// Uses the value of a referenced value definition as the value of the Base Type `base`.
+fn retype_base_value(
+    value: &ResolvedBaseValue,
+    base: &ResolvedBaseType,
+    typeref: &Asn1ResolvedType,
+) -> Result<ResolvedBaseValue, Error> {
+    let typeref = typeref.clone();
+    match (base, value) {
+        (ResolvedBaseType::Integer(_), ResolvedBaseValue::Integer(ref v)) => {
+            Ok(ResolvedBaseValue::Integer(Asn1ResolvedIntegerValue {
+                typeref,
+                value: v.value,
+            }))
+        }
+        (ResolvedBaseType::Enum(_), ResolvedBaseValue::Enum(ref v)) => {
+            Ok(ResolvedBaseValue::Enum(Asn1ResolvedEnumValue {
+                typeref,
+                value: v.value,
+            }))
+        }
+        (ResolvedBaseType::Boolean(_), ResolvedBaseValue::Boolean(ref v)) => {
+            Ok(ResolvedBaseValue::Boolean(Asn1ResolvedBooleanValue {
+                typeref,
+                value: v.value,
+            }))
+        }
+        (ResolvedBaseType::BitString(_), ResolvedBaseValue::BitString(ref v)) => {
+            Ok(ResolvedBaseValue::BitString(Asn1ResolvedBitStringValue {
+                typeref,
+                value: v.value.clone(),
+            }))
+        }
+        (ResolvedBaseType::OctetString(_), ResolvedBaseValue::OctetString(ref v)) => Ok(
+            ResolvedBaseValue::OctetString(Asn1ResolvedOctetStringValue {
+                typeref,
+                value: v.value.clone(),
+            }),
+        ),
+        (ResolvedBaseType::CharacterString(_), ResolvedBaseValue::CharacterString(ref v)) => Ok(
+            ResolvedBaseValue::CharacterString(Asn1ResolvedCharacterStringValue {
+                typeref,
+                value: v.value.clone(),
+            }),
+        ),
+        _ => Err(resolve_error!(
+            "Value '{:#?}' is not a value of the Type '{:#?}'.",
+            value,
+            typeref
+        )),
+    }
+}
+
+// Parses a `bstring` (eg. `'0101'B`) or an `hstring` (eg. `'0A'H`) into bits.
+fn parse_bstring_or_hstring(value: &str) -> Option<BaseBitString> {
+    let (digits, radix) = if let Some(bits) = value.strip_suffix('B') {
+        (bits, 2)
+    } else {
+        (value.strip_suffix('H')?, 16)
+    };
+    let digits = digits.strip_prefix('\'')?.strip_suffix('\'')?;
+
+    let width = if radix == 2 { 1 } else { 4 };
+    let mut bits = vec![];
+    for c in digits.chars() {
+        let digit = c.to_digit(radix)?;
+        bits.extend((0..width).rev().map(|i| digit & (1 << i) != 0));
+    }
+    Some(bits)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::parser::asn::types::parse_type;
+    use crate::resolver::asn::structs::types::constructed::ResolvedConstructedType;
+    use crate::resolver::asn::types::resolve_type;
+    use crate::tokenizer::tokenize;
+
+    fn resolve_defaults(input: &str) -> Result<Vec<Option<ResolvedBaseValue>>, Error> {
+        let reader = std::io::BufReader::new(std::io::Cursor::new(input));
+        let tokens = tokenize(reader).unwrap();
+        let (ty, _) = parse_type(&tokens).unwrap();
+
+        let mut resolver = Resolver::new();
+        match resolve_type(&ty, &mut resolver)? {
+            Asn1ResolvedType::Constructed(ResolvedConstructedType::Sequence {
+                components, ..
+            }) => Ok(components
+                .iter()
+                .map(|c| c.default.as_ref().and_then(|d| d.get_base_value().cloned()))
+                .collect()),
+            _ => panic!("{}: not a SEQUENCE", input),
+        }
+    }
+
+    #[test]
+    fn resolve_default_values() {
+        let input = r#"SEQUENCE {
+            a INTEGER DEFAULT 5,
+            b INTEGER { one(1) } DEFAULT one,
+            c ENUMERATED { x, y, ..., z } DEFAULT z,
+            d BOOLEAN DEFAULT FALSE,
+            e OCTET STRING DEFAULT '0A1'H,
+            f BIT STRING DEFAULT '101'B,
+            g UTF8String DEFAULT "a""b",
+            h INTEGER OPTIONAL
+        }"#;
+        let defaults = resolve_defaults(input).unwrap();
+        assert_eq!(defaults.len(), 8);
+
+        assert!(matches!(defaults[0], Some(ResolvedBaseValue::Integer(ref i)) if i.value == 5));
+        assert!(matches!(defaults[1], Some(ResolvedBaseValue::Integer(ref i)) if i.value == 1));
+        assert!(matches!(defaults[2], Some(ResolvedBaseValue::Enum(ref e)) if e.value == 2));
+        assert!(matches!(defaults[3], Some(ResolvedBaseValue::Boolean(ref b)) if !b.value));
+        assert!(
+            matches!(defaults[4], Some(ResolvedBaseValue::OctetString(ref o)) if o.value == vec![0x0a, 0x10])
+        );
+        assert!(
+            matches!(defaults[5], Some(ResolvedBaseValue::BitString(ref b)) if b.value == vec![true, false, true])
+        );
+        assert!(
+            matches!(defaults[6], Some(ResolvedBaseValue::CharacterString(ref c)) if c.value == "a\"b")
+        );
+        assert!(defaults[7].is_none());
+
+        // The value should be a value of the Type of the component.
+        for input in [
+            "SEQUENCE { a BOOLEAN DEFAULT 5 }",
+            "SEQUENCE { a ENUMERATED { x, y } DEFAULT z }",
+            "SEQUENCE { a OCTET STRING DEFAULT TRUE }",
+        ] {
+            assert!(resolve_defaults(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn parse_bstring_or_hstring_values() {
+        assert_eq!(
+            parse_bstring_or_hstring("'101'B"),
+            Some(vec![true, false, true])
+        );
+        assert_eq!(
+            parse_bstring_or_hstring("'A1'H"),
+            Some(vec![true, false, true, false, false, false, false, true])
+        );
+        assert_eq!(parse_bstring_or_hstring("''B"), Some(vec![]));
+        assert_eq!(parse_bstring_or_hstring("'12'B"), None);
+        assert_eq!(parse_bstring_or_hstring("\"12\""), None);
+    }
+}
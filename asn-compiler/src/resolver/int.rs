@@ -4,9 +4,15 @@ use std::collections::{BTreeMap, HashMap};
 
 use crate::error::Error;
 
-use crate::parser::asn::structs::{defs::Asn1Definition, module::Asn1Module};
+use crate::parser::asn::structs::{
+    defs::Asn1Definition,
+    module::{Asn1Module, Asn1ModuleTag},
+};
 
-use crate::resolver::asn::structs::{defs::Asn1ResolvedDefinition, types::Asn1ResolvedType};
+use crate::resolver::asn::structs::{
+    defs::Asn1ResolvedDefinition,
+    types::{Asn1ResolvedTag, Asn1ResolvedType},
+};
 
 use crate::resolver::asn::defs::resolve_definition;
 
@@ -20,6 +26,12 @@ pub(crate) struct Resolver {
 
     // Object Classes: Used by Objects and Object Sets to resolves themselves.
     pub(crate) classes: HashMap<String, Asn1Definition>,
+
+    // Tags of the Type definitions that are Tagged Types.
+    pub(crate) tags: BTreeMap<String, Asn1ResolvedTag>,
+
+    // Tagging environment of the module being resolved.
+    pub(crate) tag_default: Asn1ModuleTag,
 }
 
 impl Resolver {
@@ -28,6 +40,8 @@ impl Resolver {
             resolved_defs: BTreeMap::new(),
             parameterized_defs: HashMap::new(),
             classes: HashMap::new(),
+            tags: BTreeMap::new(),
+            tag_default: Asn1ModuleTag::default(),
         }
     }
 
@@ -46,6 +60,8 @@ impl Resolver {
     // After that we resolve definitions in a Topologically sorted order. Fairly straight forward.
     // We do not need to do any `Pending` definitions, as we were doing before.
     pub(crate) fn resolve_definitions(&mut self, module: &mut Asn1Module) -> Result<(), Error> {
+        self.tag_default = module.get_tags();
+
         // We need to first get Classes in the current module - resolved
         self.resolve_classes_in_current_module(module);
 
@@ -13,6 +13,7 @@ const KEYWORDS: &[&str] = &[
     "ABSENT",
     "ABSTRACT-SYNTAX",
     "ALL",
+    "ANY",
     "APPLICATION",
     "AUTOMATIC",
     "BEGIN",
@@ -28,6 +29,7 @@ const KEYWORDS: &[&str] = &[
     "CONSTRAINED",
     "CONTAINING",
     "DEFAULT",
+    "DEFINED",
     "DEFINITIONS",
     "EMBEDDED",
     "ENCODED",
@@ -102,11 +104,14 @@ const BASE_TYPES: &[&str] = &[
     "IA5String",
     "PrintableString",
     "VisibleString",
+    "ANY",
+    "EXTERNAL",
     // Spliced types (Note: actual ASN.1 Type names are different.
     "OBJECT",
     "OCTET",
     "BIT",
     "CHARACTER",
+    "EMBEDDED",
 ];
 
 const CONSTRUCTED_TYPES: &[&str] = &["SEQUENCE", "SET", "CHOICE"];
@@ -295,15 +300,12 @@ impl Token {
 
     /// Checks whether the given token is a builtin type.
     pub(crate) fn is_asn_builtin_type(&self) -> bool {
-        BASE_TYPES.iter().any(|&t| t == self.text.as_str())
-            || CONSTRUCTED_TYPES.iter().any(|&t| t == self.text.as_str())
+        BASE_TYPES.contains(&self.text.as_str()) || CONSTRUCTED_TYPES.contains(&self.text.as_str())
     }
 
     /// Checks whether a given token is a with syntax reserved word
     pub(crate) fn is_with_syntax_reserved_word(&self) -> bool {
-        WITH_SYNTAX_RESERVED_WORDS
-            .iter()
-            .any(|&t| t == self.text.as_str())
+        WITH_SYNTAX_RESERVED_WORDS.contains(&self.text.as_str())
     }
 
     /// Returns the 'span' of the current token.
@@ -717,20 +719,19 @@ fn get_seq_extension_or_square_brackets_token(
 //
 // Note: square brackets need a special treatment due to "[[" and "]]"
 fn get_single_char_token(token: char, line: usize, begin: usize) -> Result<Token, Error> {
-    let token_type: TokenType;
-    match token {
-        '{' => token_type = TokenType::CurlyBegin,
-        '}' => token_type = TokenType::CurlyEnd,
-        '(' => token_type = TokenType::RoundBegin,
-        ')' => token_type = TokenType::RoundEnd,
-        '!' => token_type = TokenType::ExceptionMarker,
-        ';' => token_type = TokenType::SemiColon,
-        ',' => token_type = TokenType::Comma,
-        '|' => token_type = TokenType::SetUnionToken,
-        '^' => token_type = TokenType::SetIntersectionToken,
-        '<' => token_type = TokenType::LessThan,
+    let token_type = match token {
+        '{' => TokenType::CurlyBegin,
+        '}' => TokenType::CurlyEnd,
+        '(' => TokenType::RoundBegin,
+        ')' => TokenType::RoundEnd,
+        '!' => TokenType::ExceptionMarker,
+        ';' => TokenType::SemiColon,
+        ',' => TokenType::Comma,
+        '|' => TokenType::SetUnionToken,
+        '^' => TokenType::SetIntersectionToken,
+        '<' => TokenType::LessThan,
         _ => return Err(Error::TokenizeError(21, line, begin)),
-    }
+    };
     Ok(Token {
         r#type: token_type,
         span: Span::new(
@@ -1081,8 +1082,7 @@ mod tests {
             let reader = std::io::BufReader::new(std::io::Cursor::new(t.input));
             let result = tokenize(reader);
             assert_eq!(result.is_ok(), t.success, "{:#?}", result.unwrap()[0]);
-            if result.is_ok() {
-                let tokens = result.unwrap();
+            if let Ok(tokens) = result {
                 assert!(tokens.len() == 1, "{:#?}", tokens[0]);
                 let token = &tokens[0];
                 assert!(
@@ -1150,8 +1150,7 @@ mod tests {
                 "{}",
                 result.err().unwrap()
             );
-            if result.is_ok() {
-                let tokens = result.unwrap();
+            if let Ok(tokens) = result {
                 assert!(tokens.len() == test_case.len, "{:#?}", tokens);
             }
         }
@@ -1246,8 +1245,7 @@ mod tests {
                 "{}",
                 String::from_utf8(test_case.input.to_vec()).unwrap()
             );
-            if result.is_ok() {
-                let tokens = result.unwrap();
+            if let Ok(tokens) = result {
                 assert!(tokens.len() == test_case.count, "{:#?}", tokens);
             }
         }
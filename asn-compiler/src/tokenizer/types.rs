@@ -34,7 +34,7 @@ pub(crate) enum TokenType {
     SetUnionToken,        // A single '|'
     SetIntersectionToken, // A single '^'
     AtComponentIdList,    // @Component.Id.List form
-    LessThan,             // A single '<' (Used in Ranges)
+    LessThan,             // A single '<' (Used in Ranges and Selection Types)
 }
 
 pub(crate) type TokenChecker = fn(&Token) -> bool;
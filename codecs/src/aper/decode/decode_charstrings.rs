@@ -1,4 +1,5 @@
 //! Functionality for decoding character strings
+use crate::prelude::*;
 use bitvec::field::BitField;
 
 use crate::aper::AperCodecData;
@@ -8,12 +9,15 @@ use super::decode_internal::decode_length_determinent;
 
 // 27.5.3 and 27.5.4
 /// Decode a VisibleString CharacterString Type.
+///
+/// Returns the value and whether the size of the value is encoded as outside the Extension Root.
+/// (Same for the other CharacterString Types.)
 pub fn decode_visible_string(
     data: &mut AperCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-) -> Result<String, AperCodecError> {
+) -> Result<(String, bool), AperCodecError> {
     log::debug!(
         "decode_visible_string: lb: {:?}, ub: {:?}, is_extensible: {}",
         lb,
@@ -29,7 +33,7 @@ pub fn decode_printable_string(
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-) -> Result<String, AperCodecError> {
+) -> Result<(String, bool), AperCodecError> {
     log::debug!(
         "decode_printable_string: lb: {:?}, ub: {:?}, is_extensible: {}",
         lb,
@@ -43,19 +47,22 @@ pub fn decode_printable_string(
 // almost like decoding an octet string.
 // 27.6
 /// Decode a UTF8String CharacterString Type.
+///
+/// The `SIZE` constraint of a UTF8String is not PER-visible, so `lb`, `ub` and `is_extensible` are
+/// not used. The length is the number of octets and there is no extension bit.
 pub fn decode_utf8_string(
     data: &mut AperCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-) -> Result<String, AperCodecError> {
+) -> Result<(String, bool), AperCodecError> {
     log::debug!(
         "decode_utf8_string: lb: {:?}, ub: {:?}, is_extensible: {}",
         lb,
         ub,
         is_extensible
     );
-    decode_string(data, lb, ub, is_extensible)
+    decode_string(data, None, None, false)
 }
 
 fn decode_string(
@@ -63,7 +70,7 @@ fn decode_string(
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-) -> Result<String, AperCodecError> {
+) -> Result<(String, bool), AperCodecError> {
     let is_extended = if is_extensible {
         data.decode_bool()?
     } else {
@@ -84,12 +91,19 @@ fn decode_string(
     let bits = data.get_bitvec(length)?;
     let bytes = bits
         .chunks_exact(num_bits)
-        .map(|c| c.load::<u8>())
+        .map(|c| c.load_be::<u8>())
         .collect::<Vec<u8>>();
 
     data.dump();
 
-    std::str::from_utf8(&bytes)
+    let value = core::str::from_utf8(&bytes)
         .map(|s| s.to_string())
-        .map_err(|_| AperCodecError::new("UTF decode failed"))
+        .map_err(|_| AperCodecError::new("UTF decode failed"))?;
+
+    // The size of a CharacterString is the number of characters.
+    if is_extended {
+        super::check_canonical_extended(data, lb, ub, value.chars().count() as i128)?;
+    }
+
+    Ok((value, is_extended))
 }
@@ -1,5 +1,6 @@
 //! Internal decode functions.
-use std::convert::TryInto;
+use crate::prelude::*;
+use core::convert::TryInto;
 
 use crate::aper::AperCodecData;
 use crate::aper::AperCodecError;
@@ -15,7 +16,11 @@ pub(super) fn decode_normally_small_non_negative_whole_number(
     if !is_small {
         data.decode_bits_as_integer(6, false)
     } else {
-        decode_semi_constrained_whole_number(data, 0_i128)
+        let value = decode_semi_constrained_whole_number(data, 0_i128)?;
+        if value <= 63 {
+            data.check_canonical("normally small number not encoded in 6 bits")?;
+        }
+        Ok(value)
     }
 }
 // Decode "Normally Small" Length Determinent
@@ -31,7 +36,11 @@ fn decode_normally_small_length_determinent(
     if !is_small {
         Ok(data.decode_bits_as_integer(6, false)? as usize + 1_usize)
     } else {
-        decode_indefinite_length_determinent(data)
+        let length = decode_indefinite_length_determinent(data)?;
+        if length <= 64 {
+            data.check_canonical("normally small length not encoded in 6 bits")?;
+        }
+        Ok(length)
     }
 }
 
@@ -106,14 +115,18 @@ fn decode_constrained_length_determinent(
 // Called when `ub` is not determined or `ub ` - `lb` is greater than 64K and in this case value of
 // `lb` is don't care.
 fn decode_indefinite_length_determinent(data: &mut AperCodecData) -> Result<usize, AperCodecError> {
-    let _ = data.decode_align()?;
+    data.decode_align()?;
     let first = data.decode_bool()?;
     let length = if !first {
         data.decode_bits_as_integer(7, false)?
     } else {
         let second = data.decode_bool()?;
         if !second {
-            data.decode_bits_as_integer(14, false)?
+            let length = data.decode_bits_as_integer(14, false)?;
+            if length < 128 {
+                data.check_canonical("length determinant not encoded in a single octet")?;
+            }
+            length
         } else {
             let length = data.decode_bits_as_integer(6, false)?;
             if !(1..=4).contains(&length) {
@@ -137,7 +150,12 @@ pub(super) fn decode_unconstrained_whole_number(
 
     let length = decode_length_determinent(data, None, None, false)?;
     let bits = length * 8;
-    data.decode_bits_as_integer(bits, true)
+    let value = data.decode_bits_as_integer(bits, true)?;
+    if length != signed_octets_needed(value) {
+        data.check_canonical("unconstrained whole number not encoded in minimum octets")?;
+    }
+
+    Ok(value)
 }
 
 // Section 10.7 X.691
@@ -151,6 +169,9 @@ pub(super) fn decode_semi_constrained_whole_number(
 
     let bits = length * 8;
     let val = data.decode_bits_as_integer(bits, false)?;
+    if length != unsigned_octets_needed(val) {
+        data.check_canonical("semi-constrained whole number not encoded in minimum octets")?;
+    }
 
     Ok(val + lb)
 }
@@ -185,23 +206,57 @@ pub(super) fn decode_constrained_whole_number(
             };
             data.decode_bits_as_integer(bits, false)?
         } else if range == 256 {
-            let _ = data.decode_align()?;
+            data.decode_align()?;
             data.decode_bits_as_integer(8, false)?
         } else if range <= 65536 {
-            let _ = data.decode_align()?;
+            data.decode_align()?;
             data.decode_bits_as_integer(16, false)?
         } else {
             let bytes_needed = crate::aper::bytes_needed_for_range(range);
             log::trace!("bytes_needed : {}", bytes_needed);
             let length = decode_constrained_length_determinent(data, 1, bytes_needed as usize)?;
-            let _ = data.decode_align()?;
-            data.decode_bits_as_integer(length * 8, false)?
+            data.decode_align()?;
+            let value = data.decode_bits_as_integer(length * 8, false)?;
+            if length != unsigned_octets_needed(value) {
+                data.check_canonical("constrained whole number not encoded in minimum octets")?;
+            }
+            value
         };
 
+        if value + lb > ub {
+            let mode = data.decode_options().out_of_range;
+            data.strict_or_warn(
+                mode,
+                format!(
+                    "constrained whole number {} larger than the upper bound {}",
+                    value + lb,
+                    ub
+                )
+                .as_str(),
+            )?;
+        }
+
         Ok(value + lb)
     }
 }
 
+// Minimum number of octets for encoding a non-negative `value`.
+fn unsigned_octets_needed(value: i128) -> usize {
+    let bits = 128 - value.leading_zeros() as usize;
+    core::cmp::max(1, bits.div_ceil(8))
+}
+
+// Minimum number of octets for encoding `value` in 2's complement form.
+fn signed_octets_needed(value: i128) -> usize {
+    let redundant = if value < 0 {
+        value.leading_ones()
+    } else {
+        value.leading_zeros()
+    } as usize;
+    // One sign bit is required.
+    (128 - redundant + 1).div_ceil(8)
+}
+
 #[cfg(test)]
 mod tests {
 
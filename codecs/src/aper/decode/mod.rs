@@ -1,8 +1,10 @@
 //! Decode APIs for APER Codec
+use crate::prelude::*;
 use bitvec::prelude::*;
 
 use crate::aper::AperCodecData;
 use crate::aper::AperCodecError;
+use crate::aper::{compare_set_of_encodings, set_of_encodings, AperCodec};
 
 mod decode_internal;
 use decode_internal::*;
@@ -74,6 +76,26 @@ pub fn decode_sequence_header(
     Ok((bitmap, extended))
 }
 
+/// Decode the Extension Additions bitmap of a Sequence
+///
+/// When the sequence header indicates that the extension additions are present, the 'normally
+/// small' length of the extension additions bitmap and the bitmap follow the root components. Each
+/// of the extension additions present in the bitmap is then encoded as an open type.
+pub fn decode_sequence_extensions_bitmap(
+    data: &mut AperCodecData,
+) -> Result<BitVec<u8, Msb0>, AperCodecError> {
+    log::debug!("decode_sequence_extensions_bitmap");
+
+    let length = decode_length_determinent(data, None, None, true)?;
+    let bitmap = data.get_bitvec(length)?;
+    if bitmap.not_any() {
+        data.check_canonical("extension bit set without any extension additions")?;
+    }
+
+    data.dump();
+    Ok(bitmap)
+}
+
 /// Decode an Integer
 ///
 /// Given an Integer Specification with PER Visible Constraints, decode an Integer Value to obtain
@@ -133,11 +155,115 @@ pub fn decode_integer(
         }
     };
 
+    if extended_value {
+        check_canonical_extended(data, lb, ub, value)?;
+    }
+
     data.dump();
 
     Ok((value, extended_value))
 }
 
+/// Decode an Arbitrary Precision Integer.
+///
+/// Same as [`decode_integer`], except that the value is returned as a `BigInt`, so the values of
+/// the unconstrained (and semi-constrained) integers are not limited to an `i128` value.
+#[cfg(feature = "bigint")]
+pub fn decode_big_integer(
+    data: &mut AperCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+) -> Result<(num_bigint::BigInt, bool), AperCodecError> {
+    use core::convert::TryFrom;
+    use num_bigint::{BigInt, BigUint};
+
+    log::debug!(
+        "decode_big_integer: Lower: {:?} Upper:{:?} Extensible: {}",
+        lb,
+        ub,
+        is_extensible
+    );
+
+    let extended_value = if is_extensible {
+        data.decode_bool()?
+    } else {
+        false
+    };
+
+    let value = match (lb, ub) {
+        (Some(lb), Some(ub)) if !extended_value => {
+            BigInt::from(decode_constrained_whole_number(data, lb, ub)?)
+        }
+        (Some(lb), None) if !extended_value => {
+            let length = decode_length_determinent(data, None, None, false)?;
+            let value = BigUint::from_bytes_be(&data.get_bytes(length)?);
+            if length != value.to_bytes_be().len() {
+                data.check_canonical(
+                    "semi-constrained whole number not encoded in minimum octets",
+                )?;
+            }
+            BigInt::from(value) + lb
+        }
+        _ => {
+            let length = decode_length_determinent(data, None, None, false)?;
+            let value = BigInt::from_signed_bytes_be(&data.get_bytes(length)?);
+            if length != value.to_signed_bytes_be().len() {
+                data.check_canonical("unconstrained whole number not encoded in minimum octets")?;
+            }
+            value
+        }
+    };
+
+    if extended_value {
+        // Values that do not fit in an `i128` are always outside the Extension Root.
+        if let Ok(value) = i128::try_from(&value) {
+            check_canonical_extended(data, lb, ub, value)?;
+        }
+    }
+
+    data.dump();
+
+    Ok((value, extended_value))
+}
+
+// In the Canonical mode, a value (or a size) inside the Extension Root should not be encoded with
+// the extension bit set.
+fn check_canonical_extended(
+    data: &AperCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    value: i128,
+) -> Result<(), AperCodecError> {
+    if lb.is_none_or(|lb| value >= lb) && ub.is_none_or(|ub| value <= ub) {
+        data.check_canonical("extension bit set for a value inside the extension root")
+    } else {
+        Ok(())
+    }
+}
+
+/// Check the order of the decoded components of a `SET OF`.
+///
+/// In the Canonical mode, the components should be in the ascending order of their encodings
+/// (see [`encode_set_of_items`][crate::aper::encode::encode_set_of_items]).
+pub fn check_set_of_order<T: AperCodec>(
+    data: &AperCodecData,
+    items: &[T],
+) -> Result<(), AperCodecError> {
+    if !data.canonical {
+        return Ok(());
+    }
+
+    let encodings = set_of_encodings(items)?;
+    if encodings
+        .windows(2)
+        .any(|w| compare_set_of_encodings(&w[0], &w[1]).is_gt())
+    {
+        data.check_canonical("SET OF components not in the ascending order of their encodings")?;
+    }
+    Ok(())
+}
+
 /// Decode a Boolean
 ///
 /// Decode a Boolean value. Returns the decoded value as a `bool`.
@@ -190,13 +316,14 @@ pub fn decode_enumerated(
 
 /// Decode a Bit String
 ///
-/// Decodes the value of the BIT STRING from the Buffer.
+/// Decodes the value of the BIT STRING from the Buffer. Returns the value and whether the size of
+/// the value is encoded as outside the Extension Root.
 pub fn decode_bitstring(
     data: &mut AperCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-) -> Result<BitVec<u8, Msb0>, AperCodecError> {
+) -> Result<(BitVec<u8, Msb0>, bool), AperCodecError> {
     log::debug!(
         "decode_bitstring: lb: {:?}, ub: {:?}, is_extensible: {}",
         lb,
@@ -220,7 +347,7 @@ pub fn decode_bitstring(
 
         if length > 0 {
             if length > 16 {
-                let _ = data.decode_align()?;
+                data.decode_align()?;
             }
             bv.extend(data.get_bitvec(length)?);
         }
@@ -233,20 +360,25 @@ pub fn decode_bitstring(
         }
     }
 
+    if is_extended {
+        check_canonical_extended(data, lb, ub, bv.len() as i128)?;
+    }
+
     data.dump();
 
-    Ok(bv)
+    Ok((bv, is_extended))
 }
 
 /// Decode an OCTET STRING
 ///
-/// Decodes the value of the OCTET STRING from the Buffer.
+/// Decodes the value of the OCTET STRING from the Buffer. Returns the value and whether the size
+/// of the value is encoded as outside the Extension Root.
 pub fn decode_octetstring(
     data: &mut AperCodecData,
     lb: Option<i128>,
     ub: Option<i128>,
     is_extensible: bool,
-) -> Result<Vec<u8>, AperCodecError> {
+) -> Result<(Vec<u8>, bool), AperCodecError> {
     log::debug!(
         "decode_bitstring: lb: {:?}, ub: {:?}, is_extensible: {}",
         lb,
@@ -270,7 +402,7 @@ pub fn decode_octetstring(
 
         if length > 0 {
             if length > 2 {
-                let _ = data.decode_align()?;
+                data.decode_align()?;
             }
             octets.extend(data.get_bytes(length)?);
         }
@@ -283,9 +415,30 @@ pub fn decode_octetstring(
         }
     }
 
+    if is_extended {
+        check_canonical_extended(data, lb, ub, octets.len() as i128)?;
+    }
+
+    data.dump();
+
+    Ok((octets, is_extended))
+}
+
+/// Decode an Open Type and return the encoded bytes of the contained value.
+///
+/// An Open Type is encoded as an unconstrained length determinant followed by the octet aligned
+/// encoding of the contained value. The contained value is not decoded. This allows the caller to
+/// skip the value or to decode it later.
+pub fn decode_open_type(data: &mut AperCodecData) -> Result<Vec<u8>, AperCodecError> {
+    log::debug!("decode_open_type");
+
+    let length = decode_length_determinent(data, None, None, false)?;
+    data.decode_align()?;
+    let bytes = data.get_bytes(length)?;
+
     data.dump();
 
-    Ok(octets)
+    Ok(bytes)
 }
 
 mod decode_charstrings;
@@ -1,6 +1,7 @@
 use crate::aper::encode::encode_length_determinent;
 use crate::aper::AperCodecData;
 use crate::aper::AperCodecError;
+use crate::prelude::*;
 use bitvec::prelude::*;
 
 pub(super) fn encode_unconstrained_whole_number(
@@ -41,7 +42,13 @@ pub(super) fn encode_semi_constrained_whole_number(
         )));
     }
 
-    encode_unconstrained_whole_number(data, value - lb)
+    // 10.7: Encoded as a non-negative-binary-integer in the minimum number of octets.
+    let value = (value - lb) as u128;
+    let bytes_needed = core::cmp::max(1, (128 - value.leading_zeros() as usize).div_ceil(8));
+    encode_length_determinent(data, None, None, false, bytes_needed)?;
+    let bytes = value.to_be_bytes();
+    data.append_bits(bytes[16 - bytes_needed..16].view_bits());
+    Ok(())
 }
 
 pub(super) fn encode_constrained_whole_number(
@@ -113,10 +120,10 @@ pub(super) fn encode_indefinite_length_determinent(
     data.align();
     if value < 128 {
         let byte = value as u8;
-        data.append_bits(&byte.view_bits::<Msb0>());
+        data.append_bits(byte.view_bits::<Msb0>());
     } else if value < 16384 {
         let bytes = (value as u16 | 0x8000).to_be_bytes();
-        data.append_bits(&bytes.view_bits::<Msb0>());
+        data.append_bits(bytes.view_bits::<Msb0>());
     } else {
         return Err(AperCodecError::new(
             "Length determinent >= 16384 not implemented",
@@ -125,6 +132,32 @@ pub(super) fn encode_indefinite_length_determinent(
     Ok(())
 }
 
+// Encode a "Normally Small" non-negative number
+//
+// This is typically used when encoding Choice Indexes and Enumerated values that are not present
+// in the extension root.
+pub(super) fn encode_normally_small_non_negative_whole_number(
+    data: &mut AperCodecData,
+    value: i128,
+) -> Result<(), AperCodecError> {
+    if value < 0 {
+        return Err(AperCodecError::new(format!(
+            "Cannot encode normally small non-negative number {} - less than 0",
+            value,
+        )));
+    }
+
+    if value <= 63 {
+        let byte = value as u8;
+        data.encode_bool(false);
+        data.append_bits(&byte.view_bits::<Msb0>()[2..8]);
+    } else {
+        data.encode_bool(true);
+        encode_semi_constrained_whole_number(data, 0, value)?;
+    }
+    Ok(())
+}
+
 pub(super) fn encode_normally_small_length_determinent(
     data: &mut AperCodecData,
     value: usize,
@@ -145,6 +178,17 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn encode_normally_small_number() {
+        let mut data = AperCodecData::new();
+        encode_normally_small_non_negative_whole_number(&mut data, 5).unwrap();
+        assert_eq!(data.bits.len(), 7);
+        assert_eq!(data.into_bytes(), [0x0a]);
+
+        let mut data = AperCodecData::new();
+        assert!(encode_normally_small_non_negative_whole_number(&mut data, -1).is_err());
+    }
+
     #[test]
     fn encode_unconstrained() {
         let mut data = AperCodecData::new();
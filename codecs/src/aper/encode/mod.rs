@@ -2,6 +2,8 @@
 
 use crate::aper::AperCodecData;
 use crate::aper::AperCodecError;
+use crate::aper::{compare_set_of_encodings, set_of_encodings, AperCodec};
+use crate::prelude::*;
 use bitvec::prelude::*;
 mod encode_internal;
 use bitvec::view::AsBits;
@@ -28,9 +30,9 @@ pub fn encode_choice_idx(
         extended
     );
 
-    if extended {
+    if extended && !is_extensible {
         return Err(AperCodecError::new(
-            "Encode of extended choice not yet implemented",
+            "Cannot encode extended choice index for a non-extensible choice",
         ));
     }
 
@@ -38,7 +40,29 @@ pub fn encode_choice_idx(
         data.encode_bool(extended);
     }
 
-    encode_integer(data, Some(lb), Some(ub), false, idx, false)
+    if extended {
+        encode_normally_small_non_negative_whole_number(data, idx)
+    } else {
+        encode_integer(data, Some(lb), Some(ub), false, idx, false)
+    }
+}
+
+// In the Canonical mode, a value (or a size) inside the Extension Root cannot be encoded with the
+// extension bit set.
+fn check_canonical_extended(
+    data: &AperCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    value: i128,
+) -> Result<(), AperCodecError> {
+    if data.is_canonical() && lb.is_none_or(|lb| value >= lb) && ub.is_none_or(|ub| value <= ub) {
+        Err(AperCodecError::new(format!(
+            "Cannot encode value {} inside the extension root as extended in canonical mode",
+            value
+        )))
+    } else {
+        Ok(())
+    }
 }
 
 /// Encode sequence header
@@ -55,9 +79,9 @@ pub fn encode_sequence_header(
         extended
     );
 
-    if extended {
+    if extended && !is_extensible {
         return Err(AperCodecError::new(
-            "Encode of extended sequence not yet implemented",
+            "Cannot encode extensions for a non-extensible sequence",
         ));
     }
 
@@ -72,6 +96,31 @@ pub fn encode_sequence_header(
     Ok(())
 }
 
+/// Encode the Extension Additions bitmap of a sequence
+///
+/// When extension additions are present in a 'SEQUENCE', the bitmap of the extension additions
+/// that are present is encoded after the root components, preceded by the 'normally small'
+/// length of the bitmap. Each of the present extension additions is then encoded as an open type.
+pub fn encode_sequence_extensions_bitmap(
+    data: &mut AperCodecData,
+    bitmap: &BitSlice<u8, Msb0>,
+) -> Result<(), AperCodecError> {
+    log::debug!("encode_sequence_extensions_bitmap: bitmap: {:?}", bitmap);
+
+    if bitmap.is_empty() {
+        return Err(AperCodecError::new(
+            "Cannot encode an empty extension additions bitmap",
+        ));
+    }
+
+    encode_length_determinent(data, None, None, true, bitmap.len())?;
+    data.append_bits(bitmap);
+
+    data.dump_encode();
+
+    Ok(())
+}
+
 /// Encode an INTEGER
 ///
 /// This API is also used by other `encode` functions to encode an integer value.
@@ -95,17 +144,23 @@ pub fn encode_integer(
         extended
     );
 
-    if extended {
+    if extended && !is_extensible {
         return Err(AperCodecError::new(
-            "Encode of extended integer not yet implemented",
+            "Cannot encode extended value for a non-extensible integer",
         ));
     }
 
+    if extended {
+        check_canonical_extended(data, lb, ub, value)?;
+    }
+
     if is_extensible {
         data.encode_bool(extended);
     }
 
-    let _ = match (lb, ub) {
+    match (lb, ub) {
+        // 12.1: Values outside the Extension Root are encoded as unconstrained whole numbers.
+        _ if extended => encode_unconstrained_whole_number(data, value)?,
         (None, _) => encode_unconstrained_whole_number(data, value)?,
         (Some(lb), None) => encode_semi_constrained_whole_number(data, lb, value)?,
         (Some(lb), Some(ub)) => encode_constrained_whole_number(data, lb, ub, value)?,
@@ -116,6 +171,87 @@ pub fn encode_integer(
     Ok(())
 }
 
+/// Encode an Arbitrary Precision Integer.
+///
+/// Same as [`encode_integer`], except that the value is a `BigInt`, so the values of the
+/// unconstrained (and semi-constrained) integers are not limited to an `i128` value. For an
+/// extensible integer, the value is encoded as outside the Extension Root if it is not within
+/// `lb` and `ub` or if `extended` is set.
+#[cfg(feature = "bigint")]
+pub fn encode_big_integer(
+    data: &mut AperCodecData,
+    lb: Option<i128>,
+    ub: Option<i128>,
+    is_extensible: bool,
+    value: &num_bigint::BigInt,
+    extended: bool,
+) -> Result<(), AperCodecError> {
+    use core::convert::TryFrom;
+    use num_bigint::{BigInt, Sign};
+
+    log::debug!(
+        "encode_big_integer: lb: {:?}, ub: {:?}, is_extensible: {}, value: {}, extended: {}",
+        lb,
+        ub,
+        is_extensible,
+        value,
+        extended
+    );
+
+    if extended && !is_extensible {
+        return Err(AperCodecError::new(
+            "Cannot encode extended value for a non-extensible integer",
+        ));
+    }
+
+    let in_root = lb.is_none_or(|lb| *value >= BigInt::from(lb))
+        && ub.is_none_or(|ub| *value <= BigInt::from(ub));
+    if extended && in_root {
+        if let Ok(value) = i128::try_from(value) {
+            check_canonical_extended(data, lb, ub, value)?;
+        }
+    }
+    let extended = is_extensible && (extended || !in_root);
+
+    if is_extensible {
+        data.encode_bool(extended);
+    }
+
+    let bytes = match (lb, ub) {
+        // 12.1: Values outside the Extension Root are encoded as unconstrained whole numbers.
+        _ if extended => value.to_signed_bytes_be(),
+        (None, _) => value.to_signed_bytes_be(),
+        (Some(lb), None) => {
+            let (sign, bytes) = (value - lb).to_bytes_be();
+            if sign == Sign::Minus {
+                return Err(AperCodecError::new(format!(
+                    "Cannot encode integer {} - less than lower bound {}",
+                    value, lb,
+                )));
+            }
+            bytes
+        }
+        (Some(lb), Some(ub)) => {
+            let value = i128::try_from(value).map_err(|_| {
+                AperCodecError::new(format!(
+                    "Cannot encode integer {} - outside the bounds {} and {}",
+                    value, lb, ub,
+                ))
+            })?;
+            encode_constrained_whole_number(data, lb, ub, value)?;
+            data.dump_encode();
+            return Ok(());
+        }
+    };
+
+    encode_length_determinent(data, None, None, false, bytes.len())?;
+    data.append_bits(bytes.view_bits());
+
+    data.dump_encode();
+
+    Ok(())
+}
+
 /// Encode a BOOLEAN Value
 ///
 /// Encodes a boolean value into the passed `AperCodecData` structure.
@@ -145,9 +281,9 @@ pub fn encode_enumerated(
         extended
     );
 
-    if extended {
+    if extended && !is_extensible {
         return Err(AperCodecError::new(
-            "Encode of extended enumerated not yet implemented",
+            "Cannot encode extended value for a non-extensible enumerated",
         ));
     }
 
@@ -155,7 +291,11 @@ pub fn encode_enumerated(
         data.encode_bool(extended);
     }
 
-    let _ = encode_integer(data, lb, ub, false, value, false)?;
+    if extended {
+        encode_normally_small_non_negative_whole_number(data, value)?;
+    } else {
+        encode_integer(data, lb, ub, false, value, false)?;
+    }
 
     data.dump();
 
@@ -180,16 +320,23 @@ pub fn encode_bitstring(
         extended
     );
 
-    if extended {
+    if extended && !is_extensible {
         return Err(AperCodecError::new(
-            "Encode of extended bitstring not yet implemented",
+            "Cannot encode extended size for a non-extensible bitstring",
         ));
     }
 
+    if extended {
+        check_canonical_extended(data, lb, ub, bit_string.len() as i128)?;
+    }
+
     if is_extensible {
         data.encode_bool(extended);
     }
 
+    // Sizes outside the Extension Root are encoded as if there was no size constraint.
+    let (lb, ub) = if extended { (None, None) } else { (lb, ub) };
+
     let length = bit_string.len();
     if length >= 16384 {
         return Err(AperCodecError::new(
@@ -228,16 +375,23 @@ pub fn encode_octetstring(
         extended
     );
 
-    if extended {
+    if extended && !is_extensible {
         return Err(AperCodecError::new(
-            "Encode of extended octetstring not yet implemented",
+            "Cannot encode extended size for a non-extensible octetstring",
         ));
     }
 
+    if extended {
+        check_canonical_extended(data, lb, ub, octet_string.len() as i128)?;
+    }
+
     if is_extensible {
         data.encode_bool(extended);
     }
 
+    // Sizes outside the Extension Root are encoded as if there was no size constraint.
+    let (lb, ub) = if extended { (None, None) } else { (lb, ub) };
+
     let length = octet_string.len();
     if length >= 16384 {
         return Err(AperCodecError::new(
@@ -258,6 +412,75 @@ pub fn encode_octetstring(
     Ok(())
 }
 
+/// Encode an Open Type from the already encoded bytes of the contained value.
+///
+/// An empty encoding of the contained value is encoded as a single zero octet.
+pub fn encode_open_type(data: &mut AperCodecData, value: &[u8]) -> Result<(), AperCodecError> {
+    log::debug!("encode_open_type: length: {}", value.len());
+
+    let value = if value.is_empty() { &[0_u8][..] } else { value };
+
+    encode_length_determinent(data, None, None, false, value.len())?;
+    data.align();
+    data.append_bits(value.view_bits());
+
+    data.dump_encode();
+    Ok(())
+}
+
+/// Encode an Open Type from the `AperCodecData` into which the contained value is encoded.
+///
+/// The contained value should be encoded into an `AperCodecData` obtained using
+/// [`AperCodecData::nested`], so that the Open Type is also encoded correctly when only the length
+/// of the encoding is computed.
+pub fn encode_open_type_data(
+    data: &mut AperCodecData,
+    mut value: AperCodecData,
+) -> Result<(), AperCodecError> {
+    log::debug!(
+        "encode_open_type_data: length in bits: {}",
+        value.length_in_bits()
+    );
+
+    if value.length_in_bits() == 0 {
+        return encode_open_type(data, &[]);
+    }
+
+    encode_length_determinent(data, None, None, false, value.length_in_bytes())?;
+    data.append_aligned(&mut value);
+
+    data.dump_encode();
+    Ok(())
+}
+
+/// Encode the components of a `SET OF`.
+///
+/// In the Canonical mode, the components are encoded in the ascending order of their encodings
+/// (compared as bit strings padded with trailing '0' bits), irrespective of their order in
+/// `items`.
+pub fn encode_set_of_items<T: AperCodec>(
+    data: &mut AperCodecData,
+    items: &[T],
+) -> Result<(), AperCodecError> {
+    log::debug!("encode_set_of_items: items: {}", items.len());
+
+    // The order does not change the length of the encoding.
+    if !data.canonical || data.length_only {
+        for item in items {
+            item.encode(data)?;
+        }
+        return Ok(());
+    }
+
+    let encodings = set_of_encodings(items)?;
+    let mut order = (0..items.len()).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| compare_set_of_encodings(&encodings[a], &encodings[b]));
+    for idx in order {
+        items[idx].encode(data)?;
+    }
+    Ok(())
+}
+
 // Encode a Length Determinent
 pub fn encode_length_determinent(
     data: &mut AperCodecData,
@@ -275,13 +498,13 @@ pub fn encode_length_determinent(
     );
 
     if normally_small {
-        let _ = encode_normally_small_length_determinent(data, value)?;
+        encode_normally_small_length_determinent(data, value)?;
         data.dump_encode();
 
         return Ok(());
     }
 
-    let _ = match ub {
+    match ub {
         Some(ub) if ub < 65_536 => {
             encode_constrained_whole_number(data, lb.unwrap_or(0), ub, value as i128)?
         }
@@ -321,15 +544,23 @@ fn encode_string(
     value: &String,
     extended: bool,
 ) -> Result<(), AperCodecError> {
-    if extended {
+    if extended && !is_extensible {
         return Err(AperCodecError::new(
-            "Encode of extended visible string not yet implemented",
+            "Cannot encode extended size for a non-extensible string",
         ));
     }
 
+    // The size of a CharacterString is the number of characters.
+    if extended {
+        check_canonical_extended(data, lb, ub, value.chars().count() as i128)?;
+    }
+
     if is_extensible {
         data.encode_bool(extended);
     }
+
+    // Sizes outside the Extension Root are encoded as if there was no size constraint.
+    let (lb, ub) = if extended { (None, None) } else { (lb, ub) };
     encode_length_determinent(data, lb, ub, false, value.len())?;
     if value.len() > 2 {
         data.align();
@@ -383,6 +614,9 @@ pub fn encode_printable_string(
 }
 
 /// Encode a UTF8String CharacterString Type.
+///
+/// The `SIZE` constraint of a UTF8String is not PER-visible, so `lb`, `ub`, `is_extensible` and
+/// `extended` are not used. The length is the number of octets and there is no extension bit.
 pub fn encode_utf8_string(
     data: &mut AperCodecData,
     lb: Option<i128>,
@@ -400,7 +634,7 @@ pub fn encode_utf8_string(
         extended
     );
 
-    encode_string(data, lb, ub, is_extensible, value, extended)
+    encode_string(data, None, None, false, value, false)
 }
 #[cfg(test)]
 mod tests {
@@ -414,7 +648,20 @@ mod tests {
         let result = encode_bool(&mut data, true);
         assert!(result.is_ok());
         assert_eq!(data.bits.len(), 1);
-        assert_eq!(data.bits[0], true);
+        assert!(data.bits[0]);
+    }
+
+    #[test]
+    fn encode_decode_open_type() {
+        let mut data = AperCodecData::new();
+        data.append_bits(bits![u8, Msb0; 1, 0, 1]);
+
+        encode_open_type(&mut data, &[0xde, 0xad, 0xbe, 0xef]).unwrap();
+        assert_eq!(data.length_in_bytes(), 6);
+
+        let _ = data.get_bitvec(3).unwrap();
+        let decoded = crate::aper::decode::decode_open_type(&mut data).unwrap();
+        assert_eq!(decoded, vec![0xde, 0xad, 0xbe, 0xef]);
     }
 
     #[test]
@@ -536,4 +783,118 @@ mod tests {
         )
         .is_err());
     }
+
+    #[test]
+    fn encode_decode_extended_integer() {
+        let mut data = AperCodecData::new();
+        encode_integer(&mut data, Some(0), Some(255), true, 1000, true).unwrap();
+
+        let mut data = AperCodecData::from_slice(&data.into_bytes());
+        let decoded = crate::aper::decode::decode_integer(&mut data, Some(0), Some(255), true);
+        assert_eq!(decoded.unwrap(), (1000, true));
+
+        assert!(encode_integer(
+            &mut AperCodecData::new(),
+            Some(0),
+            Some(255),
+            false,
+            1000,
+            true
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn encode_semi_constrained_minimum_octets() {
+        let mut data = AperCodecData::new();
+        encode_integer(&mut data, Some(0), None, false, 200, false).unwrap();
+        assert_eq!(data.into_bytes(), [0x01, 0xc8]);
+
+        let mut data = AperCodecData::new();
+        encode_integer(&mut data, Some(-1), None, false, 255, false).unwrap();
+        assert_eq!(data.into_bytes(), [0x02, 0x01, 0x00]);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn encode_decode_big_integer() {
+        use crate::aper::decode::decode_big_integer;
+        use num_bigint::BigInt;
+
+        let big = BigInt::from(i128::MAX) * BigInt::from(1000);
+        let cases = [
+            (None, None, false, big.clone(), false),
+            (None, None, false, -big.clone(), false),
+            (Some(-5), None, false, big.clone(), false),
+            (Some(0), Some(255), false, BigInt::from(200), false),
+            (Some(0), Some(255), true, BigInt::from(200), false),
+            (Some(0), Some(255), true, big.clone(), true),
+        ];
+        for (lb, ub, ext, value, extended) in cases {
+            let mut data = AperCodecData::new();
+            encode_big_integer(&mut data, lb, ub, ext, &value, false).unwrap();
+            let mut data = AperCodecData::from_slice(&data.into_bytes());
+            data.set_canonical(true);
+            let decoded = decode_big_integer(&mut data, lb, ub, ext).unwrap();
+            assert_eq!(decoded, (value, extended));
+        }
+
+        // Same encoding as the `i128` values.
+        let mut data = AperCodecData::new();
+        encode_big_integer(&mut data, None, None, false, &BigInt::from(-129), false).unwrap();
+        let mut expected = AperCodecData::new();
+        encode_integer(&mut expected, None, None, false, -129, false).unwrap();
+        assert_eq!(data.into_bytes(), expected.into_bytes());
+
+        assert!(encode_big_integer(
+            &mut AperCodecData::new(),
+            Some(0),
+            Some(255),
+            false,
+            &big,
+            false
+        )
+        .is_err());
+        assert!(encode_big_integer(
+            &mut AperCodecData::new(),
+            Some(0),
+            None,
+            false,
+            &-big,
+            false
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn encode_decode_extended_octetstring() {
+        let mut data = AperCodecData::new();
+        encode_octetstring(&mut data, Some(1), Some(2), true, &vec![1, 2, 3, 4], true).unwrap();
+
+        let mut data = AperCodecData::from_slice(&data.into_bytes());
+        let decoded = crate::aper::decode::decode_octetstring(&mut data, Some(1), Some(2), true);
+        assert_eq!(decoded.unwrap(), (vec![1, 2, 3, 4], true));
+    }
+
+    #[test]
+    fn encode_decode_extended_bitstring() {
+        let mut data = AperCodecData::new();
+        let bits = bits![u8, Msb0; 1, 0, 1, 1, 0];
+        encode_bitstring(&mut data, Some(1), Some(2), true, bits, true).unwrap();
+
+        let mut data = AperCodecData::from_slice(&data.into_bytes());
+        let decoded = crate::aper::decode::decode_bitstring(&mut data, Some(1), Some(2), true);
+        assert_eq!(decoded.unwrap(), (bits.to_bitvec(), true));
+    }
+
+    #[test]
+    fn encode_decode_extended_string() {
+        let mut data = AperCodecData::new();
+        let value = "hampi".to_string();
+        encode_visible_string(&mut data, Some(1), Some(4), true, &value, true).unwrap();
+
+        let mut data = AperCodecData::from_slice(&data.into_bytes());
+        let decoded = crate::aper::decode::decode_visible_string(&mut data, Some(1), Some(4), true);
+        assert_eq!(decoded.unwrap(), (value, true));
+    }
 }
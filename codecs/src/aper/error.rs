@@ -1,6 +1,7 @@
 //! APER Codec Errors
 //!
-use std::fmt::Display;
+use crate::prelude::*;
+use core::fmt::Display;
 
 #[derive(Debug)]
 pub struct Error {
@@ -20,8 +21,8 @@ impl Error {
         self
     }
 }
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.context.is_empty() {
             write!(f, "{}", self.msg)
         } else {
@@ -30,4 +31,12 @@ impl std::fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
+
+// Errors from the transport when used as a `tokio_util::codec` Encoder or Decoder.
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::new(format!("IO Error: {}", e))
+    }
+}
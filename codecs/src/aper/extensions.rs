@@ -0,0 +1,302 @@
+//! Extension Additions that are not known to the decoder.
+
+use crate::prelude::*;
+use bitvec::prelude::*;
+
+use crate::aper::{decode, encode, AperCodec, AperCodecData, AperCodecError};
+
+/// An Extension Addition that is not known to the decoder.
+///
+/// Extension Additions are always encoded as Open Types, so the encoding of an Extension Addition
+/// that is not known (eg. defined in a later version of the specification) can still be retained.
+/// `index` is the index of the Extension Addition (starting at 0 for the first Extension Addition)
+/// and `bytes` is the encoding of the value contained in the Open Type.
+///
+/// An unknown Extension Addition of a `CHOICE` is retained only if the `unknown_choice_index`
+/// Decode Option is Lenient (see [`DecodeOptions`][super::DecodeOptions]).
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnknownExtension {
+    pub index: usize,
+    pub bytes: Vec<u8>,
+}
+
+/// Unknown Extension Additions of a `SEQUENCE`.
+///
+/// The length of the Extension Additions bitmap as received is retained, so that the Extension
+/// Additions are re-encoded exactly as they were decoded.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct UnknownExtensions {
+    bitmap_len: usize,
+    additions: Vec<UnknownExtension>,
+    // Indices and lengths (in bits) of the Extension Additions inserted when only the length of
+    // the encoding is computed.
+    addition_lengths: Vec<(usize, usize)>,
+}
+
+impl UnknownExtensions {
+    /// Create `UnknownExtensions` from the Extension Additions.
+    pub fn new(mut additions: Vec<UnknownExtension>) -> Self {
+        additions.sort_by_key(|a| a.index);
+        let bitmap_len = additions.last().map_or(0, |a| a.index + 1);
+
+        Self {
+            bitmap_len,
+            additions,
+            addition_lengths: vec![],
+        }
+    }
+
+    /// Returns `true` if there are no Extension Additions present.
+    pub fn is_empty(&self) -> bool {
+        self.additions.is_empty() && self.addition_lengths.is_empty()
+    }
+
+    /// Get the Extension Additions present.
+    pub fn additions(&self) -> &[UnknownExtension] {
+        &self.additions
+    }
+
+    /// Remove the Extension Addition with the `index` and decode its value.
+    ///
+    /// This is used for decoding the Extension Additions known to the decoder (eg. the Extension
+    /// Addition Groups of a `SEQUENCE`), after the Extension Additions are decoded using
+    /// [`UnknownExtensions::decode`]. Returns `None` if the Extension Addition is not present.
+    pub fn take_addition<T: AperCodec>(
+        &mut self,
+        data: &mut AperCodecData,
+        index: usize,
+    ) -> Result<Option<T>, AperCodecError> {
+        let position = match self.additions.iter().position(|a| a.index == index) {
+            Some(position) => position,
+            None => return Ok(None),
+        };
+        let addition = self.additions.remove(position);
+
+        let mut ext_data = data.nested_from_slice(&addition.bytes);
+        let value = T::decode(&mut ext_data)?;
+        data.append_warnings(&mut ext_data);
+
+        Ok(Some(value))
+    }
+
+    /// Encode the `value` and add it as the Extension Addition with the `index`.
+    ///
+    /// An Extension Addition already present with the same `index` is replaced. If `data` only
+    /// computes the length of the encoding, only the length of the encoding of the `value` is
+    /// retained.
+    pub fn insert_addition<T: AperCodec>(
+        &mut self,
+        data: &AperCodecData,
+        index: usize,
+        value: &T,
+    ) -> Result<(), AperCodecError> {
+        let mut ext_data = data.nested();
+        value.encode(&mut ext_data)?;
+
+        self.additions.retain(|a| a.index != index);
+        self.addition_lengths.retain(|(i, _)| *i != index);
+        if data.length_only {
+            let position = self.addition_lengths.partition_point(|(i, _)| *i < index);
+            self.addition_lengths
+                .insert(position, (index, ext_data.length_in_bits()));
+        } else {
+            let bytes = ext_data.into_bytes();
+            let position = self.additions.partition_point(|a| a.index < index);
+            self.additions
+                .insert(position, UnknownExtension { index, bytes });
+        }
+
+        Ok(())
+    }
+
+    /// Extend the length of the Extension Additions bitmap to at least `bitmap_len`.
+    ///
+    /// This is used for encoding the Extension Additions bitmap with all the Extension Additions
+    /// known to the encoder.
+    pub fn extend_bitmap_len(&mut self, bitmap_len: usize) {
+        self.bitmap_len = self.bitmap_len.max(bitmap_len);
+    }
+
+    /// Decode the Extension Additions bitmap and the Extension Additions present.
+    ///
+    /// This is to be called after decoding the root components of a `SEQUENCE`, when the
+    /// `SEQUENCE` header indicates that Extension Additions are present.
+    pub fn decode(data: &mut AperCodecData) -> Result<Self, AperCodecError> {
+        log::debug!("decode: UnknownExtensions");
+
+        let bitmap = decode::decode_sequence_extensions_bitmap(data)?;
+
+        let mut additions = vec![];
+        for index in bitmap.iter_ones() {
+            let bytes = decode::decode_open_type(data)?;
+            additions.push(UnknownExtension { index, bytes });
+        }
+
+        Ok(Self {
+            bitmap_len: bitmap.len(),
+            additions,
+            addition_lengths: vec![],
+        })
+    }
+
+    /// Encode the Extension Additions bitmap and the Extension Additions present.
+    ///
+    /// Nothing is encoded if there are no Extension Additions present.
+    pub fn encode(&self, data: &mut AperCodecData) -> Result<(), AperCodecError> {
+        log::debug!("encode: UnknownExtensions");
+
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        let bitmap_len = self
+            .additions
+            .iter()
+            .map(|a| a.index)
+            .chain(self.addition_lengths.iter().map(|(index, _)| *index))
+            .map(|index| index + 1)
+            .max()
+            .unwrap_or_default()
+            .max(self.bitmap_len);
+
+        let mut bitmap = bitvec![u8, Msb0; 0; bitmap_len];
+        for addition in &self.additions {
+            bitmap.set(addition.index, true);
+        }
+        for (index, _) in &self.addition_lengths {
+            bitmap.set(*index, true);
+        }
+
+        encode::encode_sequence_extensions_bitmap(data, &bitmap)?;
+
+        // The Open Types are encoded in the order of the indices.
+        let mut lengths = self.addition_lengths.iter().peekable();
+        for addition in &self.additions {
+            while let Some((_, bits)) = lengths.next_if(|(index, _)| *index < addition.index) {
+                Self::encode_addition_length(data, *bits)?;
+            }
+            encode::encode_open_type(data, &addition.bytes)?;
+        }
+        for (_, bits) in lengths {
+            Self::encode_addition_length(data, *bits)?;
+        }
+
+        Ok(())
+    }
+
+    // Encode an Extension Addition of which only the length of the encoding is retained.
+    fn encode_addition_length(data: &mut AperCodecData, bits: usize) -> Result<(), AperCodecError> {
+        let mut value = data.nested();
+        value.length_only_bits = bits;
+        encode::encode_open_type_data(data, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_decode_unknown_extensions() {
+        let extensions = UnknownExtensions::new(vec![
+            UnknownExtension {
+                index: 2,
+                bytes: vec![0x12, 0x34],
+            },
+            UnknownExtension {
+                index: 0,
+                bytes: vec![0x56],
+            },
+        ]);
+
+        let mut data = AperCodecData::new();
+        extensions.encode(&mut data).unwrap();
+        // Bitmap length 3 ('normally small' 2), bitmap 101, aligned open types.
+        let bytes = data.into_bytes();
+        assert_eq!(bytes, [0x05, 0x40, 0x01, 0x56, 0x02, 0x12, 0x34]);
+
+        let mut data = AperCodecData::from_slice(&bytes);
+        let decoded = UnknownExtensions::decode(&mut data).unwrap();
+        assert_eq!(decoded, extensions);
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct TestFlag(bool);
+
+    impl AperCodec for TestFlag {
+        fn decode(data: &mut AperCodecData) -> Result<Self, AperCodecError> {
+            Ok(Self(decode::decode_bool(data)?))
+        }
+
+        fn encode(&self, data: &mut AperCodecData) -> Result<(), AperCodecError> {
+            encode::encode_bool(data, self.0)
+        }
+    }
+
+    #[test]
+    fn insert_take_known_additions() {
+        let mut extensions = UnknownExtensions::new(vec![UnknownExtension {
+            index: 3,
+            bytes: vec![0x56],
+        }]);
+        let data = AperCodecData::new();
+        extensions
+            .insert_addition(&data, 1, &TestFlag(true))
+            .unwrap();
+        extensions.extend_bitmap_len(5);
+
+        let mut data = AperCodecData::new();
+        extensions.encode(&mut data).unwrap();
+        // Bitmap length 5 ('normally small' 4), bitmap 01010, aligned open types.
+        let bytes = data.into_bytes();
+        assert_eq!(bytes, [0x08, 0xa0, 0x01, 0x80, 0x01, 0x56]);
+
+        let mut data = AperCodecData::from_slice(&bytes);
+        let mut decoded = UnknownExtensions::decode(&mut data).unwrap();
+        assert_eq!(
+            decoded.take_addition::<TestFlag>(&mut data, 1).unwrap(),
+            Some(TestFlag(true))
+        );
+        assert_eq!(
+            decoded.take_addition::<TestFlag>(&mut data, 2).unwrap(),
+            None
+        );
+        assert_eq!(decoded.additions().len(), 1);
+        assert_eq!(decoded.additions()[0].index, 3);
+    }
+
+    #[test]
+    fn insert_additions_length_only() {
+        let extensions = UnknownExtensions::new(vec![UnknownExtension {
+            index: 3,
+            bytes: vec![0x56, 0x78],
+        }]);
+
+        // Before and after the Extension Addition that is not known.
+        for index in [1, 4] {
+            let mut encoded = extensions.clone();
+            let mut data = AperCodecData::new();
+            encoded
+                .insert_addition(&data, index, &TestFlag(true))
+                .unwrap();
+            encoded.encode(&mut data).unwrap();
+
+            let mut length_only = extensions.clone();
+            let mut length_data = AperCodecData::length_only();
+            length_only
+                .insert_addition(&length_data, index, &TestFlag(true))
+                .unwrap();
+            length_only.encode(&mut length_data).unwrap();
+
+            assert_eq!(length_only.additions().len(), 1);
+            assert_eq!(length_data.length_in_bits(), data.length_in_bits());
+        }
+    }
+
+    #[test]
+    fn encode_empty_unknown_extensions() {
+        let mut data = AperCodecData::new();
+        UnknownExtensions::default().encode(&mut data).unwrap();
+        assert!(data.into_bytes().is_empty());
+    }
+}
@@ -0,0 +1,305 @@
+//! `tokio_util::codec` Encoder and Decoder for APER encoded PDUs.
+
+use core::marker::PhantomData;
+
+use bytes::{Buf, Bytes, BytesMut};
+use tokio_util::codec::{Decoder, Encoder, LengthDelimitedCodec};
+
+use crate::aper::{AperCodec, AperCodecData, AperCodecError, DecodeOptions, DecodeWarning};
+
+// Same as the default of `tokio_util::codec::LengthDelimitedCodec`.
+const DEFAULT_MAX_FRAME_LENGTH: usize = 8 * 1024 * 1024;
+
+/// A `tokio_util::codec` Encoder and Decoder for the PDU type `T`.
+///
+/// Each frame carries exactly one APER encoded PDU. For message oriented transports (eg. SCTP),
+/// [`AperFramed::new`] decodes the PDUs back to back from the received data, the end of a PDU is
+/// determined by decoding it. For stream transports (eg. TCP), [`AperFramed::length_delimited`]
+/// prefixes each PDU with its length (see `tokio_util::codec::LengthDelimitedCodec`).
+///
+/// The undelimited framing is meant only for message oriented transports, where each read
+/// returns whole PDUs. On a stream transport, a partially received PDU is decoded again every
+/// time more data arrives, and the received data is buffered until a PDU can be decoded or the
+/// maximum frame length (see [`AperFramed::set_max_frame_length`]) is exceeded.
+///
+/// The `AperFramed` can be used with `tokio_util::codec::Framed` (or `FramedRead` and
+/// `FramedWrite`) to obtain a `Stream` and a `Sink` of PDUs.
+#[derive(Debug)]
+pub struct AperFramed<T> {
+    length_codec: Option<LengthDelimitedCodec>,
+    decode_options: DecodeOptions,
+    max_frame_length: usize,
+    warnings: Vec<DecodeWarning>,
+    _pdu: PhantomData<fn() -> T>,
+}
+
+impl<T> AperFramed<T> {
+    /// Create an `AperFramed` where the PDUs are not delimited.
+    pub fn new() -> Self {
+        Self {
+            length_codec: None,
+            decode_options: DecodeOptions::default(),
+            max_frame_length: DEFAULT_MAX_FRAME_LENGTH,
+            warnings: vec![],
+            _pdu: PhantomData,
+        }
+    }
+
+    /// Create an `AperFramed` where each PDU is prefixed by a 4 octet big-endian length.
+    pub fn length_delimited() -> Self {
+        Self::with_length_codec(LengthDelimitedCodec::new())
+    }
+
+    /// Create an `AperFramed` where each PDU is framed using the given `LengthDelimitedCodec`.
+    ///
+    /// This allows a length prefix of a different size or a limit on the length of the PDU. (See
+    /// `tokio_util::codec::LengthDelimitedCodec::builder`.)
+    pub fn with_length_codec(length_codec: LengthDelimitedCodec) -> Self {
+        Self {
+            max_frame_length: length_codec.max_frame_length(),
+            length_codec: Some(length_codec),
+            ..Self::new()
+        }
+    }
+
+    /// Returns the maximum length of a frame.
+    pub fn max_frame_length(&self) -> usize {
+        self.max_frame_length
+    }
+
+    /// Sets the maximum length of a frame. The default is 8 MB.
+    ///
+    /// Without a length prefix, decoding fails once more than `max_frame_length` bytes are
+    /// buffered without a complete PDU. With a length prefix, this is the maximum length of the
+    /// PDU as per `tokio_util::codec::LengthDelimitedCodec::set_max_frame_length`.
+    pub fn set_max_frame_length(&mut self, max_frame_length: usize) {
+        self.max_frame_length = max_frame_length;
+        if let Some(ref mut length_codec) = self.length_codec {
+            length_codec.set_max_frame_length(max_frame_length);
+        }
+    }
+
+    /// Sets the Decode Options used for decoding the PDUs.
+    ///
+    /// With [`AperFramed::length_delimited`], the bytes remaining in a frame after the PDU are
+    /// checked as per the `trailing_data` option.
+    pub fn set_decode_options(&mut self, options: DecodeOptions) {
+        self.decode_options = options;
+    }
+
+    /// Take the warnings recorded while decoding the PDUs, leaving no warnings.
+    ///
+    /// With `FramedRead`, the `AperFramed` is available from `FramedRead::decoder_mut`.
+    pub fn take_warnings(&mut self) -> Vec<DecodeWarning> {
+        core::mem::take(&mut self.warnings)
+    }
+}
+
+impl<T> Default for AperFramed<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AperCodec> Decoder for AperFramed<T> {
+    type Item = T;
+    type Error = AperCodecError;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<T>, AperCodecError> {
+        match self.length_codec {
+            Some(ref mut length_codec) => {
+                let frame = match length_codec.decode(src)? {
+                    Some(frame) => frame,
+                    None => return Ok(None),
+                };
+
+                let mut data = AperCodecData::from_slice(&frame);
+                data.set_decode_options(self.decode_options);
+                let pdu = T::decode(&mut data)?;
+                data.check_trailing_data()?;
+                self.warnings.append(&mut data.take_warnings());
+
+                Ok(Some(pdu))
+            }
+            None => {
+                if src.is_empty() {
+                    return Ok(None);
+                }
+
+                let mut data = AperCodecData::from_slice(src);
+                data.set_decode_options(self.decode_options);
+                let pdu = match T::decode(&mut data) {
+                    Ok(pdu) => pdu,
+                    Err(_) if data.is_end_of_data() => {
+                        if src.len() > self.max_frame_length {
+                            return Err(AperCodecError::new(format!(
+                                "No PDU decoded from {} bytes, exceeds the maximum frame length {}.",
+                                src.len(),
+                                self.max_frame_length
+                            )));
+                        }
+                        return Ok(None);
+                    }
+                    Err(e) => return Err(e),
+                };
+                data.decode_align()?;
+                self.warnings.append(&mut data.take_warnings());
+
+                // X.691 11.1.3: An empty encoding of the PDU is sent as a single zero octet.
+                src.advance(data.decoded_length_in_bytes().max(1));
+
+                Ok(Some(pdu))
+            }
+        }
+    }
+}
+
+impl<T: AperCodec> Encoder<T> for AperFramed<T> {
+    type Error = AperCodecError;
+
+    fn encode(&mut self, item: T, dst: &mut BytesMut) -> Result<(), AperCodecError> {
+        let mut data = AperCodecData::new();
+        item.encode(&mut data)?;
+        let mut bytes = data.into_bytes();
+        // X.691 11.1.3: An empty encoding of the PDU is replaced by a single zero octet.
+        if bytes.is_empty() {
+            bytes.push(0);
+        }
+
+        match self.length_codec {
+            Some(ref mut length_codec) => length_codec.encode(Bytes::from(bytes), dst)?,
+            None => dst.extend_from_slice(&bytes),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aper::decode::{decode_integer, decode_octetstring};
+    use crate::aper::encode::{encode_integer, encode_octetstring};
+
+    #[derive(Debug, PartialEq)]
+    struct Message {
+        procedure_code: i128,
+        payload: Vec<u8>,
+    }
+
+    impl AperCodec for Message {
+        fn decode(data: &mut AperCodecData) -> Result<Self, AperCodecError> {
+            let (procedure_code, _) = decode_integer(data, Some(0), Some(100), false)?;
+            let (payload, _) = decode_octetstring(data, None, None, false)?;
+            Ok(Self {
+                procedure_code,
+                payload,
+            })
+        }
+
+        fn encode(&self, data: &mut AperCodecData) -> Result<(), AperCodecError> {
+            encode_integer(data, Some(0), Some(100), false, self.procedure_code, false)?;
+            encode_octetstring(data, None, None, false, &self.payload, false)
+        }
+    }
+
+    fn message(code: i128, len: usize) -> Message {
+        Message {
+            procedure_code: code,
+            payload: vec![code as u8; len],
+        }
+    }
+
+    fn encode_all(framed: &mut AperFramed<Message>, messages: &[(i128, usize)]) -> BytesMut {
+        let mut buf = BytesMut::new();
+        for &(code, len) in messages {
+            framed.encode(message(code, len), &mut buf).unwrap();
+        }
+        buf
+    }
+
+    #[test]
+    fn length_delimited_pdus() {
+        let mut framed = AperFramed::<Message>::length_delimited();
+        let mut buf = encode_all(&mut framed, &[(1, 3), (2, 200), (3, 0)]);
+
+        assert_eq!(framed.decode(&mut buf).unwrap(), Some(message(1, 3)));
+        assert_eq!(framed.decode(&mut buf).unwrap(), Some(message(2, 200)));
+        assert_eq!(framed.decode(&mut buf).unwrap(), Some(message(3, 0)));
+        assert_eq!(framed.decode(&mut buf).unwrap(), None);
+    }
+
+    #[test]
+    fn length_delimited_trailing_data() {
+        let mut framed = AperFramed::<Message>::length_delimited();
+        let mut buf = BytesMut::new();
+        LengthDelimitedCodec::new()
+            .encode(Bytes::from_static(&[0x02, 0x00, 0xAA, 0xBB]), &mut buf)
+            .unwrap();
+        let mut lenient_buf = buf.clone();
+
+        assert!(framed.decode(&mut buf).is_err());
+
+        framed.set_decode_options(DecodeOptions::lenient());
+        assert_eq!(
+            framed.decode(&mut lenient_buf).unwrap(),
+            Some(message(1, 0))
+        );
+        assert_eq!(framed.take_warnings().len(), 1);
+        assert!(framed.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn undelimited_pdus() {
+        let mut framed = AperFramed::<Message>::new();
+        let mut buf = encode_all(&mut framed, &[(4, 10), (5, 0), (6, 300)]);
+
+        assert_eq!(framed.decode(&mut buf).unwrap(), Some(message(4, 10)));
+        assert_eq!(framed.decode(&mut buf).unwrap(), Some(message(5, 0)));
+        assert_eq!(framed.decode(&mut buf).unwrap(), Some(message(6, 300)));
+        assert!(buf.is_empty());
+        assert_eq!(framed.decode(&mut buf).unwrap(), None);
+        assert!(framed.take_warnings().is_empty());
+    }
+
+    #[test]
+    fn undelimited_partial_pdu() {
+        let mut framed = AperFramed::<Message>::new();
+        let encoded = encode_all(&mut framed, &[(7, 20), (8, 1)]);
+
+        // The PDUs are decoded only when all of their bytes are received.
+        let mut buf = BytesMut::new();
+        let mut received = vec![];
+        for byte in encoded.iter() {
+            buf.extend_from_slice(&[*byte]);
+            while let Some(pdu) = framed.decode(&mut buf).unwrap() {
+                received.push(pdu);
+            }
+        }
+        assert_eq!(received, vec![message(7, 20), message(8, 1)]);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn undelimited_invalid_pdu() {
+        let mut framed = AperFramed::<Message>::new();
+        framed.set_decode_options(DecodeOptions::strict());
+
+        // The procedure code is out of range.
+        let mut buf = BytesMut::from(&[0xFE, 0x00][..]);
+        assert!(framed.decode(&mut buf).is_err());
+    }
+
+    #[test]
+    fn undelimited_max_frame_length() {
+        let mut framed = AperFramed::<Message>::new();
+        framed.set_max_frame_length(8);
+        let encoded = encode_all(&mut framed, &[(9, 20)]);
+
+        // A partial PDU is buffered only up to the maximum frame length.
+        let mut buf = BytesMut::from(&encoded[..8]);
+        assert_eq!(framed.decode(&mut buf).unwrap(), None);
+        buf.extend_from_slice(&encoded[8..9]);
+        assert!(framed.decode(&mut buf).is_err());
+    }
+}
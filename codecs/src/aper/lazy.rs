@@ -0,0 +1,79 @@
+//! Lazily decoded ASN.1 Open Types.
+
+use crate::prelude::*;
+use core::marker::PhantomData;
+
+use crate::aper::{decode, encode, AperCodec, AperCodecData, AperCodecError};
+
+/// An Open Type that is decoded on access.
+///
+/// Open Types are encoded with a length prefix, so the contained value can be skipped during
+/// decoding. `LazyOpenType` keeps the raw encoded bytes of the value (along with the `key`
+/// determined while decoding the containing type) and decodes the value only when
+/// [`LazyOpenType::decode_value`] is called. Encoding a `LazyOpenType` writes the raw bytes back
+/// as they are.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LazyOpenType<T> {
+    key: Option<i128>,
+    bytes: Vec<u8>,
+    _value: PhantomData<T>,
+}
+
+impl<T: AperCodec> LazyOpenType<T> {
+    /// Create a `LazyOpenType` by encoding the `value`.
+    ///
+    /// The `key` is the value of the key field that determines the actual type of the `value`
+    /// (eg. the `procedureCode` of an NGAP `InitiatingMessage`).
+    pub fn new(key: i128, value: &T) -> Result<Self, AperCodecError> {
+        let mut data = AperCodecData::new();
+        value.encode(&mut data)?;
+        let bytes = decode::decode_open_type(&mut data)?;
+
+        Ok(Self {
+            key: Some(key),
+            bytes,
+            _value: PhantomData,
+        })
+    }
+
+    /// Get the `key` used for decoding the value.
+    pub fn key(&self) -> Option<i128> {
+        self.key
+    }
+
+    /// Get the raw encoded bytes of the value.
+    pub fn raw_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Decode the value from the raw encoded bytes.
+    pub fn decode_value(&self) -> Result<T, AperCodecError> {
+        let mut data = AperCodecData::new();
+        encode::encode_open_type(&mut data, &self.bytes)?;
+        if let Some(key) = self.key {
+            data.set_key(key);
+        }
+        T::decode(&mut data)
+    }
+}
+
+impl<T: AperCodec> AperCodec for LazyOpenType<T> {
+    fn decode(data: &mut AperCodecData) -> Result<Self, AperCodecError> {
+        log::debug!("decode: LazyOpenType");
+
+        let key = data.get_key();
+        let bytes = decode::decode_open_type(data)?;
+
+        Ok(Self {
+            key,
+            bytes,
+            _value: PhantomData,
+        })
+    }
+
+    fn encode(&self, data: &mut AperCodecData) -> Result<(), AperCodecError> {
+        log::debug!("encode: LazyOpenType");
+
+        encode::encode_open_type(data, &self.bytes)
+    }
+}
@@ -9,16 +9,116 @@ pub mod decode;
 
 pub mod encode;
 
+mod lazy;
+pub use lazy::LazyOpenType;
+
+mod extensions;
+pub use extensions::{UnknownExtension, UnknownExtensions};
+
+mod options;
+pub use options::{DecodeMode, DecodeOptions, DecodeWarning};
+
+#[cfg(feature = "tokio-codec")]
+mod framed;
+#[cfg(feature = "tokio-codec")]
+pub use framed::AperFramed;
+
+use crate::prelude::*;
+use crate::{Decode, Encode, EncodingRules};
+
 /// Trait representing an 'APER Codec'.
 ///
-/// This 'trait' is to be derived by any `struct` or `enum` representing an ASN.1 Type.
-pub trait AperCodec {
-    type Output;
+/// This 'trait' is to be derived by any `struct` or `enum` representing an ASN.1 Type. Any type
+/// implementing this trait also implements [`Encode<Aper>`][Encode] and
+/// [`Decode<Aper>`][Decode].
+pub trait AperCodec: Sized {
+    /// Decode the value from the `AperCodecData`.
+    fn decode(data: &mut AperCodecData) -> Result<Self, AperCodecError>;
+
+    /// Encode the value into the `AperCodecData`.
+    fn encode(&self, data: &mut AperCodecData) -> Result<(), AperCodecError>;
+
+    /// Compute the length in bits of the encoding of the value.
+    ///
+    /// The length includes the alignment padding and the length determinants of any Open Types
+    /// contained in the value. The value is encoded into an [`AperCodecData::length_only`] codec,
+    /// so no encoding buffer is allocated. The length excludes the padding at the end of the
+    /// encoding, (ie. the encoding of a PDU takes `encoded_len_bits().div_ceil(8)` bytes.)
+    fn encoded_len_bits(&self) -> Result<usize, AperCodecError> {
+        let mut data = AperCodecData::length_only();
+        self.encode(&mut data)?;
+        Ok(data.length_in_bits())
+    }
+}
+
+/// Trait for decoding only the 'key' of a value.
+///
+/// This 'trait' is derived for ASN.1 `SEQUENCE` Types that have a 'key' field (a field which
+/// determines the type of an Open Type field in the same `SEQUENCE`) and for ASN.1 `CHOICE` Types
+/// whose all variants have the same 'key' field. This allows looking up the 'key' (for example the
+/// `procedureCode` of an NGAP PDU) without decoding the whole value.
+pub trait AperPeekKey {
+    /// Type of the 'key' field.
+    type Key;
+
+    /// Decode the value only up to (and including) the 'key' field and return the 'key'.
+    fn peek_key(data: &mut AperCodecData) -> Result<Self::Key, AperCodecError>;
+}
+
+/// Marker type for the ASN.1 Aligned PER Encoding Rules.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Aper;
+
+impl EncodingRules for Aper {
+    type Error = AperCodecError;
+}
 
-    fn decode(data: &mut AperCodecData) -> Result<Self::Output, AperCodecError>;
+impl<T: AperCodec> Encode<Aper> for T {
+    fn to_bytes(&self) -> Result<Vec<u8>, AperCodecError> {
+        let mut data = AperCodecData::new();
+        self.encode(&mut data)?;
+        data.align();
+        Ok(data.into_bytes())
+    }
+}
 
-    fn encode(&self, _data: &mut AperCodecData) -> Result<(), AperCodecError> {
-        todo!();
+impl<T: AperCodec> Decode<Aper> for T {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), AperCodecError> {
+        let mut data = AperCodecData::from_slice(bytes);
+        let value = T::decode(&mut data)?;
+        Ok((value, data.decoded_length_in_bytes()))
+    }
+}
+
+/// Marker type for the ASN.1 Canonical Aligned PER Encoding Rules.
+///
+/// Uses the APER codec in the Canonical mode (see [`AperCodecData::set_canonical`]). Decoding
+/// returns an error for non-canonical encodings, including non-zero padding bits at the end of
+/// the encoding.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CanonicalAper;
+
+impl EncodingRules for CanonicalAper {
+    type Error = AperCodecError;
+}
+
+impl<T: AperCodec> Encode<CanonicalAper> for T {
+    fn to_bytes(&self) -> Result<Vec<u8>, AperCodecError> {
+        let mut data = AperCodecData::new();
+        data.set_canonical(true);
+        self.encode(&mut data)?;
+        data.align();
+        Ok(data.into_bytes())
+    }
+}
+
+impl<T: AperCodec> Decode<CanonicalAper> for T {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), AperCodecError> {
+        let mut data = AperCodecData::from_slice(bytes);
+        data.set_canonical(true);
+        let value = T::decode(&mut data)?;
+        data.decode_align()?;
+        Ok((value, data.decoded_length_in_bytes()))
     }
 }
 
@@ -33,6 +133,15 @@ pub struct AperCodecData {
     bits: BitVec<u8, Msb0>,
     decode_offset: usize,
     key: Option<i128>,
+    // If set, only the length of the encoding (`length_only_bits`) is tracked during encode.
+    length_only: bool,
+    length_only_bits: usize,
+    // If set, only the canonical encodings are produced and accepted.
+    canonical: bool,
+    decode_options: DecodeOptions,
+    warnings: Vec<DecodeWarning>,
+    // Set if a decode failed because the end of the data was reached.
+    end_of_data: bool,
 }
 
 impl AperCodecData {
@@ -41,12 +150,47 @@ impl AperCodecData {
         Self::default()
     }
 
+    /// Create an `AperCodecData` that only computes the length of the encoding.
+    ///
+    /// Encoding into this `AperCodecData` does not store the encoded bits, the length of the
+    /// encoding is available from [`AperCodecData::length_in_bits`].
+    pub fn length_only() -> Self {
+        Self {
+            length_only: true,
+            ..Self::default()
+        }
+    }
+
+    /// Create an empty `AperCodecData` for encoding a contained value (eg. an Open Type).
+    ///
+    /// The returned `AperCodecData` computes only the length of the encoding if `self` does.
+    pub fn nested(&self) -> Self {
+        Self {
+            length_only: self.length_only,
+            canonical: self.canonical,
+            decode_options: self.decode_options,
+            ..Self::default()
+        }
+    }
+
+    /// Create an `AperCodecData` for decoding a contained value (eg. an Open Type) from `bytes`.
+    ///
+    /// The returned `AperCodecData` uses the Canonical mode and the Decode Options of `self`. The
+    /// warnings recorded while decoding the contained value can be added to `self` using
+    /// [`AperCodecData::append_warnings`].
+    pub fn nested_from_slice(&self, bytes: &[u8]) -> Self {
+        Self {
+            canonical: self.canonical,
+            decode_options: self.decode_options,
+            ..Self::from_slice(bytes)
+        }
+    }
+
     /// Create Our `AperCodecData` Structure from a slice of u8
     pub fn from_slice(bytes: &[u8]) -> Self {
         Self {
             bits: BitSlice::<_, _>::from_slice(bytes).to_bitvec(),
-            decode_offset: 0,
-            key: None,
+            ..Self::default()
         }
     }
 
@@ -68,27 +212,45 @@ impl AperCodecData {
             .iter()
             .all(|b| b == false)
         {
-            Err(AperCodecError::new(
-                format!(
-                    "{} Padding bits at Offset {} not all '0'.",
-                    remaining, self.decode_offset,
-                )
-                .as_str(),
-            ))
-        } else {
-            self.decode_offset += remaining;
-            Ok(())
+            self.check_canonical("padding bits not all '0'")?;
+            self.strict_or_warn(
+                self.decode_options.padding,
+                format!("{} Padding bits not all '0'.", remaining).as_str(),
+            )?;
+        }
+
+        self.decode_offset += remaining;
+        Ok(())
+    }
+
+    /// Check for the data remaining after decoding a value.
+    ///
+    /// This is to be called after decoding a PDU. The padding bits up to the octet boundary are
+    /// checked (as in [`AperCodecData::decode_align`]) and the number of bytes remaining after the
+    /// decoded value is returned. If any bytes remain, it is an error or a warning as per the
+    /// `trailing_data` Decode Option.
+    pub fn check_trailing_data(&mut self) -> Result<usize, AperCodecError> {
+        self.decode_align()?;
+
+        let remaining = (self.bits.len() - self.decode_offset) / 8;
+        if remaining > 0 {
+            self.strict_or_warn(
+                self.decode_options.trailing_data,
+                format!("{} bytes remaining after the decoded value.", remaining).as_str(),
+            )?;
         }
+
+        Ok(remaining)
     }
 
     fn decode_bool(&mut self) -> Result<bool, AperCodecError> {
         if self.bits.len() == self.decode_offset {
-            return Err(AperCodecError::new(
+            return Err(self.end_of_data_error(
                 "AperCodec:DecodeError:End of Bitstream reached while trying to decode bool.",
             ));
         }
         let bit = *self.bits.get(self.decode_offset).as_deref().unwrap();
-        let _ = self.advance_maybe_err(1, true)?;
+        self.advance_maybe_err(1, true)?;
 
         Ok(bit)
     }
@@ -100,7 +262,7 @@ impl AperCodecData {
     ) -> Result<i128, AperCodecError> {
         let remaining = self.bits.len() - self.decode_offset;
         if remaining < bits {
-            Err(AperCodecError::new(
+            Err(self.end_of_data_error(
                 format!(
                     "AperCodec:DecodeError:Requested Bits to decode {}, Remaining bits {}",
                     bits, remaining
@@ -113,96 +275,25 @@ impl AperCodecData {
                 self.decode_offset,
                 bits
             );
-            let value = if !signed {
-                if bits == 0 {
-                    0_i128
-                } else {
-                    self.bits[self.decode_offset..self.decode_offset + bits].load_be::<u128>()
-                        as i128
-                }
+            let value = if bits == 0 {
+                0_i128
+            } else if bits > 128 {
+                return Err(AperCodecError::new(
+                    format!(
+                        "Requested bits {} larger than the 128 bits supported for an Integer.",
+                        bits
+                    )
+                    .as_str(),
+                ));
             } else {
-                match bits {
-                    8 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u128>() as i8;
-                        inner as i128
-                    }
-                    16 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u128>() as i16;
-                        inner as i128
-                    }
-                    24 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u32>() as u32;
-                        let inner = if self.bits[self.decode_offset] {
-                            // Bit is 1 negative no.
-                            inner | 0xFF000000
-                        } else {
-                            inner & 0x00FFFFFF
-                        };
-                        let inner = inner as i32;
-                        inner as i128
-                    }
-                    32 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u128>() as i32;
-                        inner as i128
-                    }
-                    40 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u64>() as u64;
-                        let inner = if self.bits[self.decode_offset] {
-                            // Bit is 1 negative no.
-                            inner | 0xFFFFFF0000000000
-                        } else {
-                            inner & 0x000000FFFFFFFFFF
-                        };
-                        let inner = inner as i64;
-                        inner as i128
-                    }
-                    48 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u64>() as u64;
-                        let inner = if self.bits[self.decode_offset] {
-                            // Bit is 1 negative no.
-                            inner | 0xFFFF000000000000
-                        } else {
-                            inner & 0x0000FFFFFFFFFFFF
-                        };
-                        let inner = inner as i64;
-                        inner as i128
-                    }
-                    56 => {
-                        eprintln!("{}", self.decode_offset);
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u64>() as u64;
-                        let inner = if self.bits[self.decode_offset] {
-                            // Bit is 1 negative no.
-                            inner | 0xFF00000000000000
-                        } else {
-                            inner & 0x00FFFFFFFFFFFFFF
-                        };
-                        let inner = inner as i64;
-                        inner as i128
-                    }
-                    64 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u128>() as i64;
-                        inner as i128
-                    }
-                    128 => {
-                        let inner = self.bits[self.decode_offset..self.decode_offset + bits]
-                            .load_be::<u128>() as i128;
-                        inner as i128
-                    }
-                    _ => {
-                        return Err(
-                            AperCodecError::new(
-                                format!(
-                                    "For a signed number in 2's compliment form, requested bits {} not supported!",
-                                    bits)));
-                    }
+                let value =
+                    self.bits[self.decode_offset..self.decode_offset + bits].load_be::<u128>();
+                if signed {
+                    // Sign extend the number in 2's compliment form.
+                    let shift = 128 - bits;
+                    ((value << shift) as i128) >> shift
+                } else {
+                    value as i128
                 }
             };
             log::trace!("Decoded Value: {:?}", value);
@@ -218,7 +309,7 @@ impl AperCodecData {
                 self.decode_offset = self.bits.len()
             } else {
                 let remaining = self.bits.len() - self.decode_offset;
-                return Err(AperCodecError::new(
+                return Err(self.end_of_data_error(
                     format!(
                         "AperCodec:DecodeError:Requested Bits to advance {}, Remaining bits {}",
                         bits, remaining
@@ -232,6 +323,19 @@ impl AperCodecData {
         Ok(())
     }
 
+    fn end_of_data_error(&mut self, msg: &str) -> AperCodecError {
+        self.end_of_data = true;
+        AperCodecError::new(msg)
+    }
+
+    /// Returns `true` if a decode failed because the end of the data was reached.
+    ///
+    /// This is useful to find out whether more data is required to decode a value, when decoding
+    /// from a stream.
+    pub fn is_end_of_data(&self) -> bool {
+        self.end_of_data
+    }
+
     fn get_bit(&self) -> Result<bool, AperCodecError> {
         if self.decode_offset >= self.bits.len() {
             return Err(AperCodecError::new(
@@ -249,7 +353,7 @@ impl AperCodecData {
 
     fn get_bitvec(&mut self, length: usize) -> Result<BitVec<u8, Msb0>, AperCodecError> {
         if length + self.decode_offset > self.bits.len() {
-            return Err(AperCodecError::new(
+            return Err(self.end_of_data_error(
                 format!(
                     "AperCodec:GetBitError:Requested Bit {}, Remaining bits {}",
                     length,
@@ -259,7 +363,7 @@ impl AperCodecData {
             ));
         }
         let bv = BitVec::from_bitslice(&self.bits[self.decode_offset..self.decode_offset + length]);
-        let _ = self.advance_maybe_err(length, true)?;
+        self.advance_maybe_err(length, true)?;
 
         Ok(bv)
     }
@@ -267,7 +371,7 @@ impl AperCodecData {
     fn get_bytes(&mut self, length: usize) -> Result<Vec<u8>, AperCodecError> {
         let length = length * 8;
         if length + self.decode_offset > self.bits.len() {
-            return Err(AperCodecError::new(
+            return Err(self.end_of_data_error(
                 format!(
                     "AperCodec:GetBitError:Requested Bits {}, Remaining bits {}",
                     length,
@@ -305,6 +409,87 @@ impl AperCodecData {
         let _ = self.key.replace(key);
     }
 
+    /// Sets the Canonical mode.
+    ///
+    /// In the Canonical mode (CANONICAL-PER, X.691 Clause 11.1), the 'encode' functions return an
+    /// error instead of producing a non-canonical encoding and the 'decode' functions return an
+    /// error when a non-canonical encoding is found. (eg. a length determinant or a whole number
+    /// that is not encoded in the minimum number of octets, the extension bit set for a value
+    /// inside the Extension Root or non-zero padding bits.)
+    pub fn set_canonical(&mut self, canonical: bool) {
+        self.canonical = canonical;
+    }
+
+    /// Returns `true` if the Canonical mode is set.
+    pub fn is_canonical(&self) -> bool {
+        self.canonical
+    }
+
+    /// Returns an error if in the Canonical mode.
+    ///
+    /// Used by the 'decode' functions when a non-canonical encoding is found.
+    pub fn check_canonical(&self, what: &str) -> Result<(), AperCodecError> {
+        if self.canonical {
+            Err(AperCodecError::new(format!(
+                "Non-canonical encoding at offset {}: {}",
+                self.decode_offset, what
+            )))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Sets the Decode Options.
+    ///
+    /// The Decode Options determine whether an encoding that does not strictly follow the
+    /// specification (eg. non-zero padding bits, data remaining after the PDU, out of range values
+    /// or unknown `CHOICE` indices as sent by some buggy implementations) is an error or is
+    /// recorded as a warning. (See [`DecodeOptions`].)
+    pub fn set_decode_options(&mut self, options: DecodeOptions) {
+        self.decode_options = options;
+    }
+
+    /// Get the Decode Options.
+    pub fn decode_options(&self) -> DecodeOptions {
+        self.decode_options
+    }
+
+    /// Get the warnings recorded during decode.
+    pub fn warnings(&self) -> &[DecodeWarning] {
+        &self.warnings
+    }
+
+    /// Take the warnings recorded during decode, leaving no warnings.
+    pub fn take_warnings(&mut self) -> Vec<DecodeWarning> {
+        core::mem::take(&mut self.warnings)
+    }
+
+    /// Add the warnings recorded in a nested `AperCodecData` to the warnings of `self`.
+    pub fn append_warnings(&mut self, nested: &mut Self) {
+        self.warnings.append(&mut nested.warnings);
+    }
+
+    /// Returns an error if `mode` is Strict, otherwise records a warning.
+    ///
+    /// Used by the 'decode' functions when an encoding that does not strictly follow the
+    /// specification is found.
+    pub fn strict_or_warn(&mut self, mode: DecodeMode, what: &str) -> Result<(), AperCodecError> {
+        match mode {
+            DecodeMode::Strict => Err(AperCodecError::new(format!(
+                "Invalid encoding at offset {}: {}",
+                self.decode_offset, what
+            ))),
+            DecodeMode::Lenient => {
+                log::warn!("Decode warning at offset {}: {}", self.decode_offset, what);
+                self.warnings.push(DecodeWarning {
+                    offset: self.decode_offset,
+                    message: what.to_string(),
+                });
+                Ok(())
+            }
+        }
+    }
+
     /// Dump current 'offset'.
     #[inline]
     pub fn dump(&self) {
@@ -313,7 +498,7 @@ impl AperCodecData {
 
     #[inline]
     pub fn dump_encode(&self) {
-        log::debug!("AperCodecData: current_len : {}", self.bits.len());
+        log::debug!("AperCodecData: current_len : {}", self.length_in_bits());
     }
 
     /// Reserve certain bits at the current `offset`.
@@ -340,26 +525,55 @@ impl AperCodecData {
 
     /// Encode a bool.
     fn encode_bool(&mut self, value: bool) {
-        self.bits.push(value);
+        if self.length_only {
+            self.length_only_bits += 1;
+        } else {
+            self.bits.push(value);
+        }
     }
 
     /// Add bits to the encoding buffer.
     fn append_bits(&mut self, bits: &BitSlice<u8, Msb0>) {
-        self.bits.extend_from_bitslice(bits);
+        if self.length_only {
+            self.length_only_bits += bits.len();
+        } else {
+            self.bits.extend_from_bitslice(bits);
+        }
     }
 
     /// Byte align the encoding buffer by padding with zero bits.
     fn align(&mut self) {
-        let remaining = 8 - (self.bits.len() & 0x7_usize);
+        let remaining = 8 - (self.length_in_bits() & 0x7_usize);
         if remaining < 8 {
-            self.bits.resize(self.bits.len() + remaining, false);
+            if self.length_only {
+                self.length_only_bits += remaining;
+            } else {
+                self.bits.resize(self.bits.len() + remaining, false);
+            }
+        }
+    }
+
+    /// Get the length of the data in bits.
+    pub fn length_in_bits(&self) -> usize {
+        if self.length_only {
+            self.length_only_bits
+        } else {
+            self.bits.len()
         }
     }
 
     /// Get the length of the data in bytes
     /// This is useful when encoding an open type.
     pub fn length_in_bytes(&self) -> usize {
-        ((self.bits.len() - 1) / 8) + 1
+        ((self.length_in_bits() - 1) / 8) + 1
+    }
+
+    /// Get the number of bytes consumed so far during decode.
+    ///
+    /// A partially consumed byte is counted as consumed, since the APER encoding of a PDU is
+    /// always padded to an octet boundary.
+    pub fn decoded_length_in_bytes(&self) -> usize {
+        self.decode_offset.div_ceil(8)
     }
 
     /// Append one encoding to another preserving byte alignment.
@@ -367,10 +581,38 @@ impl AperCodecData {
     pub fn append_aligned(&mut self, other: &mut Self) {
         self.align();
         other.align();
-        self.append_bits(&other.bits)
+        if other.length_only {
+            self.length_only_bits += other.length_only_bits;
+        } else {
+            self.append_bits(&other.bits)
+        }
     }
 }
 
+// The complete Canonical encodings of the components of a `SET OF`, whose ascending order is the
+// order in which the components are encoded in the Canonical mode.
+fn set_of_encodings<T: AperCodec>(items: &[T]) -> Result<Vec<Vec<u8>>, AperCodecError> {
+    let mut encodings = vec![];
+    for item in items {
+        let mut data = AperCodecData::new();
+        data.set_canonical(true);
+        item.encode(&mut data)?;
+        data.align();
+        encodings.push(data.into_bytes());
+    }
+    Ok(encodings)
+}
+
+// Compares the encodings as bit strings padded with trailing '0' bits to the same length.
+fn compare_set_of_encodings(a: &[u8], b: &[u8]) -> core::cmp::Ordering {
+    let len = a.len().max(b.len());
+    let padded = |e: &[u8], i: usize| e.get(i).copied().unwrap_or_default();
+    (0..len)
+        .map(|i| padded(a, i).cmp(&padded(b, i)))
+        .find(|o| o.is_ne())
+        .unwrap_or(core::cmp::Ordering::Equal)
+}
+
 fn bytes_needed_for_range(range: i128) -> u8 {
     let bits_needed: u8 = 128 - range.leading_zeros() as u8;
     let mut bytes_needed = bits_needed / 8;
@@ -387,7 +629,7 @@ mod tests {
     // A test that would fail if it were not for the `force_align()` in AperCodecData::get_bytes().
     #[test]
     fn get_bytes_unaligned() {
-        let mut d = AperCodecData::from_slice(&vec![0x0f, 0xf0]);
+        let mut d = AperCodecData::from_slice(&[0x0f, 0xf0]);
         let _ = d.get_bitvec(4);
         let bytes = d.get_bytes(1).unwrap();
         assert_eq!(bytes, vec![0xff]);
@@ -396,6 +638,8 @@ mod tests {
     #[test]
     fn test_encode_decode_unconstrained_whole_number() {
         let numbers: Vec<i128> = vec![
+            i128::MAX,
+            1 << 70,
             140737488355328,
             140737488355327,
             549755813888,
@@ -423,6 +667,8 @@ mod tests {
             -549755813889,
             -140737488355328,
             -140737488355329,
+            -(1 << 70),
+            i128::MIN,
         ];
         //let numbers: Vec<i128> = vec![-256, -1, -65537, 0, 11, 127, 128, 65536, 1234567, 123456789];
         for num in numbers {
@@ -459,7 +705,7 @@ mod tests {
         let mut d = AperCodecData::new();
         let s1 = "hello".to_string();
         encode::encode_printable_string(&mut d, None, None, false, &s1, false).unwrap();
-        let s2 = decode::decode_printable_string(&mut d, None, None, false).unwrap();
+        let (s2, _) = decode::decode_printable_string(&mut d, None, None, false).unwrap();
         assert_eq!(s1, s2);
     }
 
@@ -468,7 +714,196 @@ mod tests {
         let mut d = AperCodecData::new();
         let s1 = "".to_string();
         encode::encode_printable_string(&mut d, None, None, false, &s1, false).unwrap();
-        let s2 = decode::decode_printable_string(&mut d, None, None, false).unwrap();
+        let (s2, _) = decode::decode_printable_string(&mut d, None, None, false).unwrap();
         assert_eq!(s1, s2);
     }
+
+    #[derive(Debug, PartialEq)]
+    struct TestInteger(u16);
+
+    impl AperCodec for TestInteger {
+        fn decode(data: &mut AperCodecData) -> Result<Self, AperCodecError> {
+            let (value, _) = decode::decode_integer(data, Some(0), Some(1000), false)?;
+            Ok(Self(value as u16))
+        }
+
+        fn encode(&self, data: &mut AperCodecData) -> Result<(), AperCodecError> {
+            encode::encode_integer(data, Some(0), Some(1000), false, self.0 as i128, false)
+        }
+    }
+
+    #[test]
+    fn encode_decode_using_encoding_rules() {
+        fn round_trip<R: EncodingRules, T: Encode<R> + Decode<R>>(value: &T) -> (T, usize) {
+            let mut encoded = value.to_bytes().unwrap();
+            let encoded_len = encoded.len();
+            // Trailing bytes should not be consumed.
+            encoded.extend([0xde, 0xad]);
+            let (decoded, consumed) = T::from_bytes(&encoded).unwrap();
+            assert_eq!(consumed, encoded_len);
+            (decoded, consumed)
+        }
+
+        let value = TestInteger(1000);
+        let (decoded, consumed) = round_trip::<Aper, _>(&value);
+        assert_eq!(decoded, value);
+        assert_eq!(consumed, 2);
+    }
+
+    #[test]
+    fn encoded_len_bits_matches_encoding() {
+        assert_eq!(TestInteger(1000).encoded_len_bits().unwrap(), 16);
+
+        // A bit followed by an Open Type, requires alignment after the length determinant.
+        fn encode_with_open_type(data: &mut AperCodecData) {
+            data.encode_bool(true);
+            let mut inner = data.nested();
+            TestInteger(1000).encode(&mut inner).unwrap();
+            encode::encode_open_type_data(data, inner).unwrap();
+        }
+
+        let mut data = AperCodecData::new();
+        encode_with_open_type(&mut data);
+        let mut length_only = AperCodecData::length_only();
+        encode_with_open_type(&mut length_only);
+
+        assert_eq!(length_only.length_in_bits(), data.length_in_bits());
+        assert_eq!(length_only.length_in_bits(), 32);
+        assert!(length_only.into_bytes().is_empty());
+    }
+
+    #[test]
+    fn canonical_encoding_rules() {
+        let value = TestInteger(1000);
+        let encoded = Encode::<CanonicalAper>::to_bytes(&value).unwrap();
+        assert_eq!(encoded, Encode::<Aper>::to_bytes(&value).unwrap());
+
+        let (decoded, _) = <TestInteger as Decode<CanonicalAper>>::from_bytes(&encoded).unwrap();
+        assert_eq!(decoded, value);
+
+        // A single bit value followed by non-zero padding.
+        let mut data = AperCodecData::from_slice(&[0x81]);
+        data.set_canonical(true);
+        assert!(decode::decode_bool(&mut data).unwrap());
+        assert!(data.decode_align().is_err());
+    }
+
+    #[test]
+    fn set_of_encodings_order() {
+        use core::cmp::Ordering;
+
+        // Compared as bit strings padded with trailing '0' bits.
+        assert_eq!(
+            compare_set_of_encodings(&[0x01], &[0x01, 0x00]),
+            Ordering::Equal
+        );
+        assert_eq!(
+            compare_set_of_encodings(&[0x01], &[0x01, 0x01]),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_set_of_encodings(&[0x02], &[0x01, 0xff]),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn canonical_rejects_non_minimal_encodings() {
+        fn decode_canonical<T>(
+            bytes: &[u8],
+            decode_fn: impl Fn(&mut AperCodecData) -> Result<T, AperCodecError>,
+        ) -> (bool, bool) {
+            let mut data = AperCodecData::from_slice(bytes);
+            let lenient = decode_fn(&mut data).is_ok();
+            let mut data = AperCodecData::from_slice(bytes);
+            data.set_canonical(true);
+            (lenient, decode_fn(&mut data).is_ok())
+        }
+
+        // Length 5 encoded in two octets.
+        let result = decode_canonical(&[0x80, 0x05], |d| {
+            decode::decode_length_determinent(d, None, None, false)
+        });
+        assert_eq!(result, (true, false));
+
+        // Unconstrained whole number 5 encoded in two octets.
+        let result = decode_canonical(&[0x02, 0x00, 0x05], |d| {
+            decode::decode_integer(d, None, None, false)
+        });
+        assert_eq!(result, (true, false));
+
+        // Semi-constrained whole number 5 encoded in two octets.
+        let result = decode_canonical(&[0x02, 0x00, 0x05], |d| {
+            decode::decode_integer(d, Some(0), None, false)
+        });
+        assert_eq!(result, (true, false));
+
+        // Value 5 inside the extension root encoded with the extension bit set.
+        let result = decode_canonical(&[0x80, 0x01, 0x05], |d| {
+            decode::decode_integer(d, Some(0), Some(10), true)
+        });
+        assert_eq!(result, (true, false));
+
+        // Value 20 outside the extension root.
+        let result = decode_canonical(&[0x80, 0x01, 0x14], |d| {
+            decode::decode_integer(d, Some(0), Some(10), true)
+        });
+        assert_eq!(result, (true, true));
+
+        let mut data = AperCodecData::new();
+        data.set_canonical(true);
+        assert!(encode::encode_integer(&mut data, Some(0), Some(10), true, 5, true).is_err());
+        assert!(encode::encode_integer(&mut data, Some(0), Some(10), true, 20, true).is_ok());
+    }
+
+    #[test]
+    fn decode_options_strict_and_lenient() {
+        fn decode_with<T>(
+            bytes: &[u8],
+            options: DecodeOptions,
+            decode_fn: impl Fn(&mut AperCodecData) -> Result<T, AperCodecError>,
+        ) -> (Result<T, AperCodecError>, usize) {
+            let mut data = AperCodecData::from_slice(bytes);
+            data.set_decode_options(options);
+            let result = decode_fn(&mut data);
+            (result, data.warnings().len())
+        }
+
+        // Non-zero padding bits after the value 1 (of 0..10).
+        let align = |d: &mut AperCodecData| {
+            let value = decode::decode_integer(d, Some(0), Some(10), false)?;
+            d.decode_align()?;
+            Ok(value)
+        };
+        let (result, _) = decode_with(&[0x11], DecodeOptions::default(), align);
+        assert!(result.is_err());
+        let (result, warnings) = decode_with(&[0x11], DecodeOptions::lenient(), align);
+        assert_eq!((result.unwrap(), warnings), ((1, false), 1));
+
+        // Value 15 larger than the upper bound 10.
+        let integer = |d: &mut AperCodecData| decode::decode_integer(d, Some(0), Some(10), false);
+        let (result, _) = decode_with(&[0xF0], DecodeOptions::strict(), integer);
+        assert!(result.is_err());
+        let (result, warnings) = decode_with(&[0xF0], DecodeOptions::default(), integer);
+        assert_eq!((result.unwrap(), warnings), ((15, false), 1));
+
+        // Two bytes remaining after the value.
+        let trailing = |d: &mut AperCodecData| {
+            decode::decode_integer(d, Some(0), Some(10), false)?;
+            d.check_trailing_data()
+        };
+        let (result, _) = decode_with(&[0x10, 0x00, 0x00], DecodeOptions::default(), trailing);
+        assert!(result.is_err());
+        let (result, warnings) =
+            decode_with(&[0x10, 0x00, 0x00], DecodeOptions::lenient(), trailing);
+        assert_eq!((result.unwrap(), warnings), (2, 1));
+        let (result, warnings) = decode_with(&[0x10], DecodeOptions::strict(), trailing);
+        assert_eq!((result.unwrap(), warnings), (0, 0));
+
+        // The Canonical mode rejects non-zero padding bits irrespective of the Decode Options.
+        let mut data = AperCodecData::from_slice(&[0x11]);
+        data.set_canonical(true);
+        data.set_decode_options(DecodeOptions::lenient());
+        assert!(align(&mut data).is_err());
+    }
 }
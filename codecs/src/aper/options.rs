@@ -0,0 +1,93 @@
+//! Options controlling the handling of encodings that do not strictly follow the specification.
+
+use crate::prelude::*;
+
+/// How an encoding that does not strictly follow the specification is handled while decoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeMode {
+    /// Return an error.
+    Strict,
+
+    /// Record a [`DecodeWarning`] and continue decoding.
+    Lenient,
+}
+
+/// Options for decoding APER encoded data.
+///
+/// The options are set using [`AperCodecData::set_decode_options`][super::AperCodecData] and
+/// the warnings for the issues handled leniently are available from
+/// [`AperCodecData::warnings`][super::AperCodecData].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeOptions {
+    /// Padding bits (added for octet alignment) that are not all zero.
+    pub padding: DecodeMode,
+
+    /// Bytes remaining after the decoded value. (See
+    /// [`AperCodecData::check_trailing_data`][super::AperCodecData].)
+    pub trailing_data: DecodeMode,
+
+    /// Constrained whole numbers (including lengths and CHOICE indices) that are larger than the
+    /// upper bound.
+    pub out_of_range: DecodeMode,
+
+    /// CHOICE Extension Addition indices that are not known to the decoder.
+    ///
+    /// When handled leniently, the Extension Addition is retained if the CHOICE has a variant for
+    /// the unknown Extension Additions.
+    pub unknown_choice_index: DecodeMode,
+
+    /// Values that violate an Inner Type Constraint (`WITH COMPONENT` or `WITH COMPONENTS`).
+    ///
+    /// Inner Type Constraints are not PER-visible, so such values are correctly encoded, but are
+    /// not valid values of the Type.
+    pub inner_constraints: DecodeMode,
+}
+
+impl DecodeOptions {
+    /// All the issues are handled as errors.
+    pub fn strict() -> Self {
+        Self {
+            padding: DecodeMode::Strict,
+            trailing_data: DecodeMode::Strict,
+            out_of_range: DecodeMode::Strict,
+            unknown_choice_index: DecodeMode::Strict,
+            inner_constraints: DecodeMode::Strict,
+        }
+    }
+
+    /// All the issues are recorded as warnings.
+    pub fn lenient() -> Self {
+        Self {
+            padding: DecodeMode::Lenient,
+            trailing_data: DecodeMode::Lenient,
+            out_of_range: DecodeMode::Lenient,
+            unknown_choice_index: DecodeMode::Lenient,
+            inner_constraints: DecodeMode::Lenient,
+        }
+    }
+}
+
+impl Default for DecodeOptions {
+    /// All the issues are errors, except the constrained whole numbers that are larger than the
+    /// upper bound, which are recorded as warnings. (Such values were always accepted by the
+    /// decoder.) Use [`DecodeOptions::lenient`] to accept the other issues as well.
+    fn default() -> Self {
+        Self {
+            out_of_range: DecodeMode::Lenient,
+            ..Self::strict()
+        }
+    }
+}
+
+/// An issue found while decoding that was handled leniently.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodeWarning {
+    /// Offset (in bits) at which the issue was found.
+    ///
+    /// For values contained in an Open Type, the offset is relative to the start of the Open
+    /// Type.
+    pub offset: usize,
+
+    /// Description of the issue.
+    pub message: String,
+}
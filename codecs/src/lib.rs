@@ -1,4 +1,39 @@
 //! Codec support for ASN.1 Types.
+//!
+//! Without the default `std` feature, the crate is `no_std` and requires only `alloc`.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![allow(dead_code)]
+
+extern crate alloc;
+
+// Items from the `std` prelude that are available in `alloc`.
+mod prelude {
+    pub(crate) use alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+}
+
+// Not a public API. Used by the code generated by `asn1_codecs_derive`, so that the generated code
+// does not require `std`.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::format;
+    pub use alloc::vec;
+}
+
 pub mod aper;
+
+pub mod notation;
+
+pub mod reflect;
+
+pub mod traits;
+pub use traits::{Decode, Encode, EncodingRules};
+
+/// Arbitrary precision integer used for the ASN.1 INTEGER Types with large values.
+#[cfg(feature = "bigint")]
+pub use num_bigint::BigInt;
@@ -0,0 +1,649 @@
+//! ASN.1 Value Notation.
+//!
+//! Types generated by the ASN.1 Compiler (with the `--value-notation` option) derive
+//! [`Asn1ValueNotation`], which formats the values in the ASN.1 value notation (eg.
+//! `{ procedureCode 21, criticality reject, value NGSetupRequest : { ... } }`) and parses the
+//! values from the ASN.1 value notation. This module provides the helpers for the formatting and
+//! the parsing used by the derived code.
+
+use crate::prelude::*;
+use core::convert::TryFrom;
+use core::fmt;
+
+use bitvec::order::Msb0;
+use bitvec::slice::BitSlice;
+use bitvec::vec::BitVec;
+
+use crate::aper::{AperCodec, LazyOpenType, UnknownExtension, UnknownExtensions};
+
+/// Formatting and Parsing of the values in the ASN.1 value notation.
+pub trait Asn1ValueNotation: Sized {
+    /// Format the value in the ASN.1 value notation.
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Parse the value in the ASN.1 value notation.
+    fn parse_value(parser: &mut ValueParser<'_>) -> Result<Self, ValueNotationError>;
+
+    /// The key of the chosen variant of an Open Type. Used for creating a [`LazyOpenType`] from
+    /// the parsed value.
+    fn open_type_key(&self) -> Option<i128> {
+        None
+    }
+
+    /// The identifiers of the components of a `SEQUENCE` value.
+    ///
+    /// The components of an Extension Addition Group are written as the components of the
+    /// containing `SEQUENCE` value, these identify them while parsing the containing value.
+    fn component_identifiers() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Format the components of a `SEQUENCE` value, as the components of the containing
+    /// `SEQUENCE` value (see [`Asn1ValueNotation::component_identifiers`]).
+    fn fmt_components(&self, components: &mut Components<'_, '_>) -> fmt::Result {
+        components.component(None, self)
+    }
+
+    /// The value in the ASN.1 value notation.
+    fn to_value_notation(&self) -> String {
+        ValueNotation(self).to_string()
+    }
+
+    /// Parse the value from the ASN.1 value notation in `input`.
+    fn from_value_notation(input: &str) -> Result<Self, ValueNotationError> {
+        let mut parser = ValueParser::new(input);
+        let value = Self::parse_value(&mut parser)?;
+        parser.expect_end()?;
+
+        Ok(value)
+    }
+}
+
+/// `Display` implementation for a value using the ASN.1 value notation.
+pub struct ValueNotation<'a, T>(pub &'a T);
+
+impl<T: Asn1ValueNotation> fmt::Display for ValueNotation<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_value(f)
+    }
+}
+
+/// Error while parsing the ASN.1 value notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueNotationError {
+    /// Offset (in bytes) in the input at which the error was found.
+    pub offset: usize,
+
+    /// Description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ValueNotationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Value Notation Error at offset {}: {}",
+            self.offset, self.message
+        )
+    }
+}
+
+impl core::error::Error for ValueNotationError {}
+
+/// Format a BOOLEAN value.
+pub fn fmt_boolean(f: &mut fmt::Formatter<'_>, value: bool) -> fmt::Result {
+    f.write_str(if value { "TRUE" } else { "FALSE" })
+}
+
+/// Format an OCTET STRING value as a `hstring`. (eg. `'0A0B'H`)
+pub fn fmt_octets(f: &mut fmt::Formatter<'_>, value: &[u8]) -> fmt::Result {
+    f.write_str("'")?;
+    for byte in value {
+        write!(f, "{:02X}", byte)?;
+    }
+    f.write_str("'H")
+}
+
+/// Format a BIT STRING value as a `bstring`. (eg. `'1010'B`)
+pub fn fmt_bits(f: &mut fmt::Formatter<'_>, value: &BitSlice<u8, Msb0>) -> fmt::Result {
+    f.write_str("'")?;
+    for bit in value.iter() {
+        f.write_str(if *bit { "1" } else { "0" })?;
+    }
+    f.write_str("'B")
+}
+
+/// Format a character string value as a `cstring`. (eg. `"gNB"`)
+pub fn fmt_string(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    f.write_str("\"")?;
+    for (i, part) in value.split('"').enumerate() {
+        if i > 0 {
+            f.write_str("\"\"")?;
+        }
+        f.write_str(part)?;
+    }
+    f.write_str("\"")
+}
+
+/// Format a `CHOICE` value. (eg. `initiatingMessage : { ... }`)
+pub fn fmt_choice<T: Asn1ValueNotation>(
+    f: &mut fmt::Formatter<'_>,
+    identifier: &str,
+    value: &T,
+) -> fmt::Result {
+    write!(f, "{} : ", identifier)?;
+    value.fmt_value(f)
+}
+
+/// Formatter for the components of a `SEQUENCE` value (eg. `{ id 10, criticality reject }`) or
+/// the entries of a `SEQUENCE OF` value (eg. `{ 1, 2 }`).
+pub struct Components<'a, 'b> {
+    f: &'a mut fmt::Formatter<'b>,
+    empty: bool,
+}
+
+impl<'a, 'b> Components<'a, 'b> {
+    /// Start formatting the components.
+    pub fn new(f: &'a mut fmt::Formatter<'b>) -> Result<Self, fmt::Error> {
+        f.write_str("{")?;
+        Ok(Self { f, empty: true })
+    }
+
+    /// Format a component with the `identifier` (or an entry without the identifier).
+    pub fn component<T: Asn1ValueNotation>(
+        &mut self,
+        identifier: Option<&str>,
+        value: &T,
+    ) -> fmt::Result {
+        self.f.write_str(if self.empty { " " } else { ", " })?;
+        self.empty = false;
+        if let Some(identifier) = identifier {
+            write!(self.f, "{} ", identifier)?;
+        }
+        value.fmt_value(self.f)
+    }
+
+    /// Finish formatting the components.
+    pub fn finish(self) -> fmt::Result {
+        self.f.write_str(" }")
+    }
+}
+
+/// Parser for the ASN.1 value notation.
+///
+/// White space and comments (`-- ...`) between the tokens are ignored.
+pub struct ValueParser<'a> {
+    input: &'a str,
+    offset: usize,
+}
+
+impl<'a> ValueParser<'a> {
+    /// Create a parser for the `input`.
+    pub fn new(input: &'a str) -> Self {
+        Self { input, offset: 0 }
+    }
+
+    /// Error at the current offset.
+    pub fn error(&self, message: &str) -> ValueNotationError {
+        ValueNotationError {
+            offset: self.offset,
+            message: message.to_string(),
+        }
+    }
+
+    /// Error for an identifier that is not expected.
+    pub fn unexpected(&self, identifier: &str) -> ValueNotationError {
+        self.error(&format!("Unexpected identifier `{}`", identifier))
+    }
+
+    /// Error for a component of a `SEQUENCE` that is not present.
+    pub fn missing(&self, identifier: &str) -> ValueNotationError {
+        self.error(&format!("Missing component `{}`", identifier))
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.offset..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        loop {
+            let rest = self.rest();
+            let trimmed = rest.trim_start();
+            self.offset += rest.len() - trimmed.len();
+
+            // Comments end at the next `--` or at the end of the line.
+            if let Some(comment) = trimmed.strip_prefix("--") {
+                let end = match (comment.find("--"), comment.find('\n')) {
+                    (Some(dashes), Some(newline)) if dashes < newline => dashes + 2,
+                    (_, Some(newline)) => newline,
+                    (Some(dashes), None) => dashes + 2,
+                    (None, None) => comment.len(),
+                };
+                self.offset += 2 + end;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Whether the next token is `token`.
+    pub fn peek(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        self.rest().starts_with(token)
+    }
+
+    /// Consume the next token if it is `token`.
+    pub fn consume(&mut self, token: &str) -> bool {
+        if self.peek(token) {
+            self.offset += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consume the next token, which should be `token`.
+    pub fn expect(&mut self, token: &str) -> Result<(), ValueNotationError> {
+        if self.consume(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected `{}`", token)))
+        }
+    }
+
+    /// Expect the end of the input.
+    pub fn expect_end(&mut self) -> Result<(), ValueNotationError> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(self.error("Expected end of the input"))
+        }
+    }
+
+    /// Whether there is a next component of a `SEQUENCE` value (or a next entry of a
+    /// `SEQUENCE OF` value), consumes the `,` before it or the `}` after the last component.
+    /// `first` should be `true` before the first component (after the `{`).
+    pub fn next_component(&mut self, first: &mut bool) -> Result<bool, ValueNotationError> {
+        if self.consume("}") {
+            return Ok(false);
+        }
+        if !*first {
+            self.expect(",")?;
+        }
+        *first = false;
+
+        Ok(true)
+    }
+
+    /// Skip a value of any Type, returns the text of the value.
+    ///
+    /// The value ends at a `,` or a `}` that is not within the value.
+    pub fn skip_value(&mut self) -> Result<&'a str, ValueNotationError> {
+        self.skip_whitespace();
+        let start = self.offset;
+        let mut end = start;
+        let mut depth = 0_usize;
+        loop {
+            self.skip_whitespace();
+            match self.rest().chars().next() {
+                None => break,
+                Some(',') | Some('}') if depth == 0 => break,
+                Some('"') => {
+                    self.char_string()?;
+                }
+                Some('\'') => {
+                    self.quoted_string()?;
+                }
+                Some(c) => {
+                    match c {
+                        '{' => depth += 1,
+                        '}' => depth -= 1,
+                        _ => {}
+                    }
+                    self.offset += c.len_utf8();
+                }
+            }
+            end = self.offset;
+        }
+        if end == start {
+            return Err(self.error("Expected a value"));
+        }
+
+        Ok(&self.input[start..end])
+    }
+
+    // Consume the characters while `accept` returns true for them.
+    fn take_while(&mut self, accept: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !accept(c)).unwrap_or(rest.len());
+        self.offset += len;
+        &rest[..len]
+    }
+
+    /// Parse an identifier (or a Type reference).
+    pub fn identifier(&mut self) -> Result<&'a str, ValueNotationError> {
+        self.skip_whitespace();
+        if !self.rest().starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return Err(self.error("Expected an identifier"));
+        }
+        Ok(self.take_while(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+    }
+
+    fn number(&mut self) -> Result<&'a str, ValueNotationError> {
+        self.skip_whitespace();
+        let start = self.offset;
+        let _ = self.consume("-");
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        if digits.is_empty() {
+            self.offset = start;
+            return Err(self.error("Expected a number"));
+        }
+        Ok(&self.input[start..self.offset])
+    }
+
+    /// Parse an INTEGER value.
+    pub fn integer(&mut self) -> Result<i128, ValueNotationError> {
+        let start = self.offset;
+        let number = self.number()?;
+        number.parse::<i128>().map_err(|_| ValueNotationError {
+            offset: start,
+            message: format!("Integer `{}` is too large", number),
+        })
+    }
+
+    /// Parse an INTEGER value of arbitrary precision.
+    #[cfg(feature = "bigint")]
+    pub fn big_integer(&mut self) -> Result<crate::BigInt, ValueNotationError> {
+        let number = self.number()?;
+        number
+            .parse::<crate::BigInt>()
+            .map_err(|_| self.error("Invalid integer"))
+    }
+
+    /// Parse a BOOLEAN value.
+    pub fn boolean(&mut self) -> Result<bool, ValueNotationError> {
+        match self.identifier()? {
+            "TRUE" => Ok(true),
+            "FALSE" => Ok(false),
+            other => Err(self.unexpected(other)),
+        }
+    }
+
+    /// Parse a NULL value.
+    pub fn null(&mut self) -> Result<(), ValueNotationError> {
+        match self.identifier()? {
+            "NULL" => Ok(()),
+            other => Err(self.unexpected(other)),
+        }
+    }
+
+    // Parse a `bstring` or an `hstring`, returns the digits and the radix character.
+    fn quoted_string(&mut self) -> Result<(&'a str, char), ValueNotationError> {
+        self.expect("'")?;
+        let digits = self.take_while(|c| c != '\'');
+        self.expect("'")?;
+        let radix = if self.rest().starts_with('B') {
+            'B'
+        } else if self.rest().starts_with('H') {
+            'H'
+        } else {
+            return Err(self.error("Expected `B` or `H`"));
+        };
+        self.offset += 1;
+
+        let valid = |c: char| match radix {
+            'B' => c == '0' || c == '1',
+            _ => c.is_ascii_hexdigit(),
+        };
+        if !digits.chars().filter(|c| !c.is_whitespace()).all(valid) {
+            return Err(self.error("Invalid digits"));
+        }
+
+        Ok((digits, radix))
+    }
+
+    /// Parse a BIT STRING value. (Either a `bstring` or an `hstring`.)
+    pub fn bit_string(&mut self) -> Result<BitVec<u8, Msb0>, ValueNotationError> {
+        let (digits, radix) = self.quoted_string()?;
+        let mut bits = BitVec::new();
+        for c in digits.chars().filter(|c| !c.is_whitespace()) {
+            match radix {
+                'B' => bits.push(c == '1'),
+                _ => {
+                    let nibble = c.to_digit(16).unwrap();
+                    for i in (0..4).rev() {
+                        bits.push(nibble & (1 << i) != 0);
+                    }
+                }
+            }
+        }
+
+        Ok(bits)
+    }
+
+    /// Parse an OCTET STRING value. (Either an `hstring` or a `bstring` with a multiple of 8
+    /// bits.)
+    pub fn octet_string(&mut self) -> Result<Vec<u8>, ValueNotationError> {
+        let bits = self.bit_string()?;
+        if bits.len() % 8 != 0 {
+            return Err(self.error("OCTET STRING value should be a multiple of 8 bits"));
+        }
+
+        Ok(bits.into_vec())
+    }
+
+    /// Parse a character string value (`cstring`).
+    pub fn char_string(&mut self) -> Result<String, ValueNotationError> {
+        self.expect("\"")?;
+        let mut value = String::new();
+        loop {
+            let part = self.take_while(|c| c != '"');
+            value.push_str(part);
+            if self.rest().is_empty() {
+                return Err(self.error("Unterminated string"));
+            }
+            self.offset += 1;
+            // A `"` within the string is written as `""`.
+            if self.rest().starts_with('"') {
+                value.push('"');
+                self.offset += 1;
+            } else {
+                return Ok(value);
+            }
+        }
+    }
+}
+
+/// Components of an Extension Addition Group, collected while parsing the containing `SEQUENCE`
+/// value (see [`Asn1ValueNotation::component_identifiers`]).
+#[derive(Debug, Default)]
+pub struct GroupComponents {
+    components: String,
+}
+
+impl GroupComponents {
+    /// Collect the value of the component `identifier` of the group.
+    pub fn parse_component(
+        &mut self,
+        identifier: &str,
+        parser: &mut ValueParser<'_>,
+    ) -> Result<(), ValueNotationError> {
+        let value = parser.skip_value()?;
+        if !self.components.is_empty() {
+            self.components.push_str(", ");
+        }
+        self.components.push_str(identifier);
+        self.components.push(' ');
+        self.components.push_str(value);
+
+        Ok(())
+    }
+
+    /// Parse the value of the group from the collected components. The group is absent (`None`)
+    /// if none of its components are present.
+    pub fn finish<T: Asn1ValueNotation>(
+        self,
+        parser: &ValueParser<'_>,
+    ) -> Result<Option<T>, ValueNotationError> {
+        if self.components.is_empty() {
+            return Ok(None);
+        }
+
+        T::from_value_notation(&format!("{{ {} }}", self.components))
+            .map(Some)
+            .map_err(|e| parser.error(&e.message))
+    }
+}
+
+// The value of an Open Type that is decoded lazily is formatted and parsed as the decoded value.
+impl<T: AperCodec + Asn1ValueNotation> Asn1ValueNotation for LazyOpenType<T> {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.decode_value() {
+            Ok(value) => value.fmt_value(f),
+            Err(_) => fmt_octets(f, self.raw_bytes()),
+        }
+    }
+
+    fn parse_value(parser: &mut ValueParser<'_>) -> Result<Self, ValueNotationError> {
+        let value = T::parse_value(parser)?;
+        let key = value
+            .open_type_key()
+            .ok_or_else(|| parser.error("Value is not an Open Type value"))?;
+        LazyOpenType::new(key, &value).map_err(|_| parser.error("Failed to encode the value"))
+    }
+}
+
+// An unknown Extension Addition is formatted as `{ index 0, bytes '01'H }`.
+impl Asn1ValueNotation for UnknownExtension {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{ index {}, bytes ", self.index)?;
+        fmt_octets(f, &self.bytes)?;
+        f.write_str(" }")
+    }
+
+    fn parse_value(parser: &mut ValueParser<'_>) -> Result<Self, ValueNotationError> {
+        parser.expect("{")?;
+        match parser.identifier()? {
+            "index" => {}
+            other => return Err(parser.unexpected(other)),
+        }
+        let index = usize::try_from(parser.integer()?)
+            .map_err(|_| parser.error("Invalid index of the Extension Addition"))?;
+        parser.expect(",")?;
+        match parser.identifier()? {
+            "bytes" => {}
+            other => return Err(parser.unexpected(other)),
+        }
+        let bytes = parser.octet_string()?;
+        parser.expect("}")?;
+
+        Ok(Self { index, bytes })
+    }
+}
+
+impl Asn1ValueNotation for UnknownExtensions {
+    fn fmt_value(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut components = Components::new(f)?;
+        for addition in self.additions() {
+            components.component(None, addition)?;
+        }
+        components.finish()
+    }
+
+    fn parse_value(parser: &mut ValueParser<'_>) -> Result<Self, ValueNotationError> {
+        parser.expect("{")?;
+        let mut additions = vec![];
+        let mut first = true;
+        while parser.next_component(&mut first)? {
+            additions.push(UnknownExtension::parse_value(parser)?);
+        }
+
+        Ok(Self::new(additions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_value_notation_tokens() {
+        let mut parser = ValueParser::new(
+            " { -- comment\n id-1 -42, TRUE NULL '0A 0b'H '101'B \"a \"\"b\"\" c\" } -- end",
+        );
+        parser.expect("{").unwrap();
+        assert_eq!(parser.identifier().unwrap(), "id-1");
+        assert_eq!(parser.integer().unwrap(), -42);
+        assert!(parser.consume(","));
+        assert!(parser.boolean().unwrap());
+        parser.null().unwrap();
+        assert_eq!(parser.octet_string().unwrap(), vec![0x0a, 0x0b]);
+        assert_eq!(
+            parser.bit_string().unwrap(),
+            bitvec::bitvec![u8, Msb0; 1, 0, 1]
+        );
+        assert_eq!(parser.char_string().unwrap(), "a \"b\" c");
+        parser.expect("}").unwrap();
+        parser.expect_end().unwrap();
+    }
+
+    #[test]
+    fn parse_value_notation_errors() {
+        let mut parser = ValueParser::new("  '101'B");
+        let err = parser.octet_string().err().unwrap();
+        assert_eq!(err.offset, 8);
+
+        let mut parser = ValueParser::new("  abc");
+        let err = parser.integer().err().unwrap();
+        assert_eq!(err.offset, 2);
+        assert!(parser.expect_end().is_err());
+
+        let mut parser = ValueParser::new("'12'B");
+        assert!(parser.bit_string().is_err());
+    }
+
+    #[test]
+    fn skip_value_notation_values() {
+        let mut parser =
+            ValueParser::new("{ a { b \"},{\", c '7D'H } -- , --, d { -- x\n } , e 1 }");
+        parser.expect("{").unwrap();
+        assert_eq!(parser.identifier().unwrap(), "a");
+        assert_eq!(parser.skip_value().unwrap(), "{ b \"},{\", c '7D'H }");
+        parser.expect(",").unwrap();
+        assert_eq!(parser.identifier().unwrap(), "d");
+        assert_eq!(parser.skip_value().unwrap(), "{ -- x\n }");
+        parser.expect(",").unwrap();
+        assert_eq!(parser.identifier().unwrap(), "e");
+        assert_eq!(parser.skip_value().unwrap(), "1");
+        parser.expect("}").unwrap();
+        assert!(parser.skip_value().is_err());
+    }
+
+    #[test]
+    fn unknown_extensions_value_notation() {
+        let extensions = UnknownExtensions::new(vec![
+            UnknownExtension {
+                index: 0,
+                bytes: vec![0x56],
+            },
+            UnknownExtension {
+                index: 2,
+                bytes: vec![0x12, 0x34],
+            },
+        ]);
+        let notation = extensions.to_value_notation();
+        assert_eq!(
+            notation,
+            "{ { index 0, bytes '56'H }, { index 2, bytes '1234'H } }"
+        );
+        assert_eq!(
+            UnknownExtensions::from_value_notation(&notation).unwrap(),
+            extensions
+        );
+        assert!(UnknownExtensions::from_value_notation("{ }")
+            .unwrap()
+            .is_empty());
+        assert!(UnknownExtensions::from_value_notation("{ { index 0 } }").is_err());
+    }
+}
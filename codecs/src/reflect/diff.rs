@@ -0,0 +1,330 @@
+//! Structural comparison of two values of the same Type.
+
+use crate::prelude::*;
+use core::fmt;
+
+use super::{Asn1Kind, Asn1Reflect, Asn1Value};
+
+/// How a value differs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    /// The value is present only in the new value. (eg. an `OPTIONAL` field or an entry of a
+    /// `SEQUENCE OF`.)
+    Added,
+
+    /// The value is present only in the old value.
+    Removed,
+
+    /// The value (or the chosen variant of a `CHOICE`) is changed.
+    Changed,
+}
+
+/// A value that differs between the old and the new value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// Path of the value. (See [`Asn1Reflect::get`].)
+    ///
+    /// The entries of a `SEQUENCE OF` are identified by their index in the new value (or in the
+    /// old value for the removed entries).
+    pub path: String,
+
+    pub kind: DiffKind,
+
+    /// The old value in the ASN.1 value notation (or the name of the Type for the constructed
+    /// Types and the name of the variant for a changed `CHOICE`). Not present for
+    /// [`DiffKind::Added`].
+    pub old: Option<String>,
+
+    /// The new value. Not present for [`DiffKind::Removed`].
+    pub new: Option<String>,
+}
+
+/// Differences between two values of the same Type.
+///
+/// The `Display` implementation lists one difference per line and [`Diff::to_json`] returns the
+/// differences as a JSON array.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Diff {
+    pub differences: Vec<Difference>,
+}
+
+impl Diff {
+    /// Whether the values are the same.
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+
+    /// The differences as a JSON array of objects with `path`, `kind`, `old` and `new` members.
+    pub fn to_json(&self) -> String {
+        let mut json = String::from("[");
+        for (i, d) in self.differences.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            let kind = match d.kind {
+                DiffKind::Added => "added",
+                DiffKind::Removed => "removed",
+                DiffKind::Changed => "changed",
+            };
+            json.push_str("{\"path\":");
+            push_json_string(&mut json, &d.path);
+            json.push_str(",\"kind\":");
+            push_json_string(&mut json, kind);
+            for (name, value) in [("old", &d.old), ("new", &d.new)] {
+                json.push_str(&format!(",\"{}\":", name));
+                match value {
+                    Some(value) => push_json_string(&mut json, value),
+                    None => json.push_str("null"),
+                }
+            }
+            json.push('}');
+        }
+        json.push(']');
+        json
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for d in &self.differences {
+            writeln!(f, "{}", d)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let old = self.old.as_deref().unwrap_or_default();
+        let new = self.new.as_deref().unwrap_or_default();
+        match self.kind {
+            DiffKind::Added => write!(f, "+ {}: {}", self.path, new),
+            DiffKind::Removed => write!(f, "- {}: {}", self.path, old),
+            DiffKind::Changed => write!(f, "~ {}: {} -> {}", self.path, old, new),
+        }
+    }
+}
+
+/// Compare two values of the same Type.
+///
+/// Fields of the `SEQUENCE`s are compared by their names and the entries of the `SEQUENCE OF`s
+/// are compared by their index, except when all the entries have a 'key' field (see
+/// [`Asn1Reflect::key`]), in which case the entries are compared by the values of the key field.
+/// So the entries with a key field (eg. the Protocol IEs) that are only reordered are the same.
+pub fn diff(old: &dyn Asn1Reflect, new: &dyn Asn1Reflect) -> Diff {
+    let mut differences = vec![];
+    diff_values("", old, new, &mut differences);
+
+    Diff { differences }
+}
+
+fn diff_values(
+    path: &str,
+    old: &dyn Asn1Reflect,
+    new: &dyn Asn1Reflect,
+    differences: &mut Vec<Difference>,
+) {
+    match old.kind() {
+        Asn1Kind::Sequence | Asn1Kind::Set => {
+            let old_children = old.children();
+            let new_children = new.children();
+            for (name, old_child) in &old_children {
+                let child_path = child_path(path, name.unwrap_or_default());
+                match new_children.iter().find(|(n, _)| n == name) {
+                    Some((_, new_child)) => {
+                        diff_values(&child_path, *old_child, *new_child, differences)
+                    }
+                    None => differences.push(removed(child_path, *old_child)),
+                }
+            }
+            for (name, new_child) in &new_children {
+                if !old_children.iter().any(|(n, _)| n == name) {
+                    let child_path = child_path(path, name.unwrap_or_default());
+                    differences.push(added(child_path, *new_child));
+                }
+            }
+        }
+        Asn1Kind::SequenceOf | Asn1Kind::SetOf => diff_entries(path, old, new, differences),
+        Asn1Kind::Choice | Asn1Kind::Open => {
+            match (old.children().first(), new.children().first()) {
+                (Some((old_name, old_child)), Some((new_name, new_child))) => {
+                    if old_name == new_name {
+                        let child_path = child_path(path, old_name.unwrap_or_default());
+                        diff_values(&child_path, *old_child, *new_child, differences);
+                    } else {
+                        differences.push(Difference {
+                            path: path.to_string(),
+                            kind: DiffKind::Changed,
+                            old: Some(old_name.unwrap_or_default().to_string()),
+                            new: Some(new_name.unwrap_or_default().to_string()),
+                        });
+                    }
+                }
+                _ => diff_scalars(path, old, new, differences),
+            }
+        }
+        _ => diff_scalars(path, old, new, differences),
+    }
+}
+
+fn diff_entries(
+    path: &str,
+    old: &dyn Asn1Reflect,
+    new: &dyn Asn1Reflect,
+    differences: &mut Vec<Difference>,
+) {
+    let old_entries = old.children();
+    let new_entries = new.children();
+
+    let old_keys = entry_keys(&old_entries);
+    let new_keys = entry_keys(&new_entries);
+    let (old_keys, new_keys) = match (old_keys, new_keys) {
+        (Some(old_keys), Some(new_keys)) => (old_keys, new_keys),
+        _ => {
+            for (i, (_, old_entry)) in old_entries.iter().enumerate() {
+                let entry_path = child_path(path, &i.to_string());
+                match new_entries.get(i) {
+                    Some((_, new_entry)) => {
+                        diff_values(&entry_path, *old_entry, *new_entry, differences)
+                    }
+                    None => differences.push(removed(entry_path, *old_entry)),
+                }
+            }
+            for (i, (_, new_entry)) in new_entries.iter().enumerate().skip(old_entries.len()) {
+                differences.push(added(child_path(path, &i.to_string()), *new_entry));
+            }
+            return;
+        }
+    };
+
+    // Entries with the same key are matched in their order.
+    let mut matched = vec![false; new_entries.len()];
+    for (i, (_, old_entry)) in old_entries.iter().enumerate() {
+        let found = (0..new_entries.len()).find(|&j| !matched[j] && new_keys[j] == old_keys[i]);
+        match found {
+            Some(j) => {
+                matched[j] = true;
+                let entry_path = child_path(path, &j.to_string());
+                diff_values(&entry_path, *old_entry, new_entries[j].1, differences);
+            }
+            None => differences.push(removed(child_path(path, &i.to_string()), *old_entry)),
+        }
+    }
+    for (j, (_, new_entry)) in new_entries.iter().enumerate() {
+        if !matched[j] {
+            differences.push(added(child_path(path, &j.to_string()), *new_entry));
+        }
+    }
+}
+
+// Values of the key fields of all the entries, if all the entries have a key field with a
+// scalar value.
+fn entry_keys<'a>(entries: &'a super::Asn1Children<'_>) -> Option<Vec<Asn1Value<'a>>> {
+    if entries.is_empty() {
+        return None;
+    }
+    entries
+        .iter()
+        .map(|(_, entry)| {
+            entry
+                .key()
+                .map(|key| key.value())
+                .filter(|value| !matches!(value, Asn1Value::Constructed | Asn1Value::Unsupported))
+        })
+        .collect()
+}
+
+fn diff_scalars(
+    path: &str,
+    old: &dyn Asn1Reflect,
+    new: &dyn Asn1Reflect,
+    differences: &mut Vec<Difference>,
+) {
+    if old.value() != new.value() {
+        differences.push(Difference {
+            path: path.to_string(),
+            kind: DiffKind::Changed,
+            old: Some(describe(old)),
+            new: Some(describe(new)),
+        });
+    }
+}
+
+fn added(path: String, value: &dyn Asn1Reflect) -> Difference {
+    Difference {
+        path,
+        kind: DiffKind::Added,
+        old: None,
+        new: Some(describe(value)),
+    }
+}
+
+fn removed(path: String, value: &dyn Asn1Reflect) -> Difference {
+    Difference {
+        path,
+        kind: DiffKind::Removed,
+        old: Some(describe(value)),
+        new: None,
+    }
+}
+
+fn describe(value: &dyn Asn1Reflect) -> String {
+    match value.value() {
+        Asn1Value::Constructed | Asn1Value::Unsupported => value.type_name().to_string(),
+        v => v.to_string(),
+    }
+}
+
+fn child_path(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", path, name)
+    }
+}
+
+fn push_json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_json_escapes_strings() {
+        let diff = Diff {
+            differences: vec![
+                Difference {
+                    path: "a/0".to_string(),
+                    kind: DiffKind::Changed,
+                    old: Some("\"x\"".to_string()),
+                    new: Some("\"y\\\n\"".to_string()),
+                },
+                Difference {
+                    path: "b".to_string(),
+                    kind: DiffKind::Added,
+                    old: None,
+                    new: Some("1".to_string()),
+                },
+            ],
+        };
+        assert_eq!(
+            diff.to_json(),
+            r#"[{"path":"a/0","kind":"changed","old":"\"x\"","new":"\"y\\\n\""},{"path":"b","kind":"added","old":null,"new":"1"}]"#
+        );
+        assert_eq!(diff.to_string(), "~ a/0: \"x\" -> \"y\\\n\"\n+ b: 1\n");
+    }
+}
@@ -0,0 +1,269 @@
+//! Run time reflection of the ASN.1 Types.
+//!
+//! The Types generated by the ASN.1 Compiler (with `asn1_codecs_derive::Asn1Reflect` derived)
+//! implement [`Asn1Reflect`], which allows inspecting any value without knowing it's type at
+//! compile time. This is useful for generic tools (eg. filtering or logging of selected fields)
+//! that should work for all the protocols.
+//!
+//! Values within a value can be selected using a 'path' (see [`Asn1Reflect::get`]) and values
+//! can be formatted with the sensitive values redacted (see [`Redacted`]). Two values of the same
+//! Type can be compared (see [`diff`]).
+
+use crate::prelude::*;
+use core::fmt;
+
+use bitvec::order::Msb0;
+use bitvec::slice::BitSlice;
+
+use crate::aper::{AperCodec, LazyOpenType, UnknownExtension};
+use crate::notation;
+
+mod redact;
+pub use redact::Redacted;
+
+mod diff;
+pub use diff::{diff, Diff, DiffKind, Difference};
+
+/// The ASN.1 Type of a value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Asn1Kind {
+    Boolean,
+    Integer,
+    Enumerated,
+    BitString,
+    OctetString,
+    CharacterString,
+    Null,
+    ObjectIdentifier,
+    Sequence,
+    SequenceOf,
+    Set,
+    SetOf,
+    Choice,
+    Open,
+}
+
+/// Constraints of an ASN.1 Type.
+///
+/// The value bounds are used by INTEGER and ENUMERATED Types and the size bounds are used by the
+/// string Types and the `SEQUENCE OF` Types.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Asn1Constraints {
+    pub lb: Option<i128>,
+    pub ub: Option<i128>,
+    pub extensible: bool,
+    pub sz_lb: Option<i128>,
+    pub sz_ub: Option<i128>,
+    pub sz_extensible: bool,
+}
+
+/// A view of the value of an ASN.1 Type.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Asn1Value<'a> {
+    Boolean(bool),
+
+    /// Value of an INTEGER or the index of an ENUMERATED value.
+    Integer(i128),
+
+    #[cfg(feature = "bigint")]
+    BigInteger(&'a crate::BigInt),
+
+    BitString(&'a BitSlice<u8, Msb0>),
+
+    OctetString(&'a [u8]),
+
+    CharacterString(&'a str),
+
+    Null,
+
+    /// Encoded value that is not decoded. (eg. a `LazyOpenType` or an unknown Extension Addition.)
+    Encoded(&'a [u8]),
+
+    /// Value of a `SEQUENCE`, `SEQUENCE OF`, `CHOICE` or an Open Type. (See
+    /// [`Asn1Reflect::children`].)
+    Constructed,
+
+    /// Value that is not supported. (eg. OBJECT IDENTIFIER)
+    Unsupported,
+}
+
+/// Formats the scalar values in the ASN.1 value notation. (eg. `'0A0B'H` for an OCTET STRING.)
+impl fmt::Display for Asn1Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Asn1Value::Boolean(b) => notation::fmt_boolean(f, *b),
+            Asn1Value::Integer(i) => write!(f, "{}", i),
+            #[cfg(feature = "bigint")]
+            Asn1Value::BigInteger(i) => write!(f, "{}", i),
+            Asn1Value::BitString(bits) => notation::fmt_bits(f, bits),
+            Asn1Value::OctetString(bytes) | Asn1Value::Encoded(bytes) => {
+                notation::fmt_octets(f, bytes)
+            }
+            Asn1Value::CharacterString(s) => notation::fmt_string(f, s),
+            Asn1Value::Null => f.write_str("NULL"),
+            Asn1Value::Constructed => f.write_str("{ ... }"),
+            Asn1Value::Unsupported => f.write_str("<unsupported>"),
+        }
+    }
+}
+
+/// Children of a value: The name of the field (`SEQUENCE`) or the variant (`CHOICE` and Open
+/// Types) and the value. The entries of a `SEQUENCE OF` do not have a name.
+pub type Asn1Children<'a> = Vec<(Option<&'static str>, &'a dyn Asn1Reflect)>;
+
+/// Run time reflection of ASN.1 Types.
+pub trait Asn1Reflect {
+    /// Name of the Type.
+    fn type_name(&self) -> &'static str;
+
+    /// ASN.1 Type.
+    fn kind(&self) -> Asn1Kind;
+
+    /// Constraints of the Type.
+    fn constraints(&self) -> Asn1Constraints;
+
+    /// The value.
+    fn value(&self) -> Asn1Value<'_>;
+
+    /// Fields present in a `SEQUENCE`, the chosen variant of a `CHOICE` or an Open Type and the
+    /// entries of a `SEQUENCE OF`. Empty for the other Types.
+    fn children(&self) -> Asn1Children<'_>;
+
+    /// The value as an `Asn1Reflect` trait object.
+    fn as_reflect(&self) -> &dyn Asn1Reflect;
+
+    /// Value of the 'key' field of a `SEQUENCE` (eg. the `id` of a Protocol IE).
+    fn key(&self) -> Option<&dyn Asn1Reflect> {
+        None
+    }
+
+    /// Get all the values matching the `path`.
+    ///
+    /// The `path` consists of segments separated by `/`. Each segment selects the children of the
+    /// values selected so far, where a segment matches a child if it is
+    ///  - `*` (matches all the children),
+    ///  - the name of the field or the variant or the name of the Type of the child,
+    ///  - the index of an entry of a `SEQUENCE OF` (or a `SET OF`).
+    ///
+    /// Names are compared ignoring the case and the characters other than letters and digits,
+    /// so that both the ASN.1 names (eg. `protocolIEs`) and the Rust names (eg. `protocol_i_es`)
+    /// can be used. Open Types are 'transparent': A segment not matching the value of the Open
+    /// Type is matched against the children of the value.
+    ///
+    /// For example, `initiatingMessage/value/protocolIEs/*/value/RAN_UE_NGAP_ID` selects the
+    /// `RAN_UE_NGAP_ID` values of all the Protocol IEs of an NGAP Initiating Message.
+    fn get(&self, path: &str) -> Vec<&dyn Asn1Reflect> {
+        let mut selected = vec![self.as_reflect()];
+        for segment in path.split('/').filter(|s| !s.is_empty()) {
+            let mut next = vec![];
+            for value in selected {
+                select_children(value, segment, &mut next);
+            }
+            selected = next;
+        }
+        selected
+    }
+
+    /// `Debug` formatting of the value with the values of the `types` redacted. (See
+    /// [`Redacted`].)
+    fn redacted<'a>(&'a self, types: &'a [&'a str]) -> Redacted<'a> {
+        Redacted::new(self.as_reflect(), types)
+    }
+}
+
+fn select_children<'a>(
+    value: &'a dyn Asn1Reflect,
+    segment: &str,
+    selected: &mut Vec<&'a dyn Asn1Reflect>,
+) {
+    for (index, (name, child)) in value.children().into_iter().enumerate() {
+        let matches = segment == "*"
+            || match name {
+                Some(name) => names_match(segment, name),
+                None => segment.parse::<usize>() == Ok(index),
+            }
+            || names_match(segment, child.type_name());
+
+        if matches {
+            selected.push(child);
+        } else if value.kind() == Asn1Kind::Open {
+            select_children(child, segment, selected);
+        }
+    }
+}
+
+// Compare the names ignoring the case and the characters other than letters and digits.
+fn names_match(left: &str, right: &str) -> bool {
+    normalized(left).eq(normalized(right))
+}
+
+fn normalized(s: &str) -> impl Iterator<Item = char> + '_ {
+    s.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+}
+
+impl<T: AperCodec> Asn1Reflect for LazyOpenType<T> {
+    fn type_name(&self) -> &'static str {
+        "LazyOpenType"
+    }
+
+    fn kind(&self) -> Asn1Kind {
+        Asn1Kind::Open
+    }
+
+    fn constraints(&self) -> Asn1Constraints {
+        Asn1Constraints::default()
+    }
+
+    fn value(&self) -> Asn1Value<'_> {
+        Asn1Value::Encoded(self.raw_bytes())
+    }
+
+    fn children(&self) -> Asn1Children<'_> {
+        Asn1Children::new()
+    }
+
+    fn as_reflect(&self) -> &dyn Asn1Reflect {
+        self
+    }
+}
+
+impl Asn1Reflect for UnknownExtension {
+    fn type_name(&self) -> &'static str {
+        "UnknownExtension"
+    }
+
+    fn kind(&self) -> Asn1Kind {
+        Asn1Kind::Open
+    }
+
+    fn constraints(&self) -> Asn1Constraints {
+        Asn1Constraints::default()
+    }
+
+    fn value(&self) -> Asn1Value<'_> {
+        Asn1Value::Encoded(&self.bytes)
+    }
+
+    fn children(&self) -> Asn1Children<'_> {
+        Asn1Children::new()
+    }
+
+    fn as_reflect(&self) -> &dyn Asn1Reflect {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_match_ignoring_case_and_separators() {
+        assert!(names_match("protocolIEs", "protocol_i_es"));
+        assert!(names_match("initiatingMessage", "InitiatingMessage"));
+        assert!(names_match("RAN-UE-NGAP-ID", "RAN_UE_NGAP_ID"));
+        assert!(!names_match("value", "values"));
+    }
+}
@@ -0,0 +1,115 @@
+//! Redaction of sensitive values while formatting.
+
+use crate::prelude::*;
+use core::fmt;
+
+use super::{names_match, Asn1Kind, Asn1Reflect, Asn1Value};
+
+/// `Debug` formatting of a value with the sensitive values redacted.
+///
+/// The values of the Types named in `types` (and all the values contained in them) are formatted
+/// as `<redacted>`. A name ending with `*` matches all the Types whose names start with the
+/// rest of the name (eg. `UE_Identity*`). Names are compared the same way as the path segments of
+/// [`Asn1Reflect::get`], so `NAS-PDU` and `NAS_PDU` are the same.
+///
+/// Absent `OPTIONAL` fields are not formatted and the BIT STRING values are formatted as bits,
+/// otherwise the formatting is similar to the derived `Debug` implementation.
+pub struct Redacted<'a> {
+    value: &'a dyn Asn1Reflect,
+    types: &'a [&'a str],
+}
+
+impl<'a> Redacted<'a> {
+    /// Redact the values of the `types` in the `value`.
+    pub fn new(value: &'a dyn Asn1Reflect, types: &'a [&'a str]) -> Self {
+        Self { value, types }
+    }
+
+    fn is_redacted(&self) -> bool {
+        let type_name = self.value.type_name();
+        self.types.iter().any(|name| match name.strip_suffix('*') {
+            Some(prefix) => starts_with_name(type_name, prefix),
+            None => names_match(name, type_name),
+        })
+    }
+
+    fn child(&self, value: &'a dyn Asn1Reflect) -> Self {
+        Self::new(value, self.types)
+    }
+}
+
+impl fmt::Debug for Redacted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_name = self.value.type_name();
+        if self.is_redacted() {
+            return write!(f, "{}(<redacted>)", type_name);
+        }
+
+        let children = self.value.children();
+        match self.value.kind() {
+            Asn1Kind::Sequence | Asn1Kind::Set => {
+                let mut s = f.debug_struct(type_name);
+                for (name, child) in children {
+                    s.field(name.unwrap_or_default(), &self.child(child));
+                }
+                s.finish()
+            }
+            Asn1Kind::SequenceOf | Asn1Kind::SetOf => {
+                let entries = RedactedEntries(
+                    children
+                        .into_iter()
+                        .map(|(_, child)| self.child(child))
+                        .collect(),
+                );
+                f.debug_tuple(type_name).field(&entries).finish()
+            }
+            Asn1Kind::Choice | Asn1Kind::Open if !children.is_empty() => {
+                let (name, child) = children[0];
+                f.debug_tuple(name.unwrap_or(type_name))
+                    .field(&self.child(child))
+                    .finish()
+            }
+            _ => {
+                let mut t = f.debug_tuple(type_name);
+                match self.value.value() {
+                    Asn1Value::Boolean(b) => t.field(&b),
+                    Asn1Value::Integer(i) => t.field(&i),
+                    #[cfg(feature = "bigint")]
+                    Asn1Value::BigInteger(i) => t.field(i),
+                    Asn1Value::BitString(b) => t.field(&format_args!("{}", b)),
+                    Asn1Value::OctetString(o) => t.field(&o),
+                    Asn1Value::CharacterString(s) => t.field(&s),
+                    Asn1Value::Encoded(e) => t.field(&e),
+                    Asn1Value::Null | Asn1Value::Constructed | Asn1Value::Unsupported => &mut t,
+                };
+                t.finish()
+            }
+        }
+    }
+}
+
+struct RedactedEntries<'a>(Vec<Redacted<'a>>);
+
+impl fmt::Debug for RedactedEntries<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.0.iter()).finish()
+    }
+}
+
+// Whether the `name` starts with the `prefix`, compared the same way as `names_match`.
+fn starts_with_name(name: &str, prefix: &str) -> bool {
+    let mut name = super::normalized(name);
+    super::normalized(prefix).all(|c| name.next() == Some(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_with_name_ignoring_case_and_separators() {
+        assert!(starts_with_name("UE_Identity_Index_Value", "UE-Identity"));
+        assert!(starts_with_name("NAS_PDU", ""));
+        assert!(!starts_with_name("UE", "UE_Identity"));
+    }
+}
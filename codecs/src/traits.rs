@@ -0,0 +1,29 @@
+//! Encoding Rules agnostic traits for ASN.1 Types.
+
+use crate::prelude::*;
+
+/// Trait representing an ASN.1 Encoding Rule.
+///
+/// Each supported Encoding Rule (eg. APER) provides a 'marker' type implementing this trait.
+/// Application code can be written generic over `R: EncodingRules` and use the [`Encode`] and
+/// [`Decode`] traits, so that switching the Encoding Rules does not require changing the call
+/// sites.
+pub trait EncodingRules {
+    /// Error returned while En(De)coding using these Encoding Rules.
+    type Error: core::error::Error;
+}
+
+/// Trait for ASN.1 Types that can be encoded using Encoding Rules `R`.
+pub trait Encode<R: EncodingRules> {
+    /// Encode the value and return the encoded bytes.
+    fn to_bytes(&self) -> Result<Vec<u8>, R::Error>;
+}
+
+/// Trait for ASN.1 Types that can be decoded using Encoding Rules `R`.
+pub trait Decode<R: EncodingRules>: Sized {
+    /// Decode the value from the given bytes.
+    ///
+    /// Returns the decoded value and the number of bytes consumed from `bytes`. Any bytes
+    /// remaining after the consumed bytes are not inspected.
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, usize), R::Error>;
+}
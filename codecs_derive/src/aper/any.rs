@@ -0,0 +1,34 @@
+//! Handling of ASN.1 ANY Type (and `EXTERNAL` and `EMBEDDED PDV`)
+
+use quote::quote;
+
+use crate::attrs::TyCodecParams;
+
+pub(super) fn generate_aper_codec_for_asn_any(
+    ast: &syn::DeriveInput,
+    _params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+
+    let tokens = quote! {
+
+        impl asn1_codecs::aper::AperCodec for #name {
+
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+                log::debug!(concat!("decode: ", stringify!(#name)));
+
+                let bytes = asn1_codecs::aper::decode::decode_open_type(data)?;
+
+                Ok(Self(bytes))
+            }
+
+            fn encode(&self, data: &mut asn1_codecs::aper::AperCodecData) -> Result<(), asn1_codecs::aper::AperCodecError> {
+                log::debug!(concat!("encode: ", stringify!(#name)));
+
+                asn1_codecs::aper::encode::encode_open_type(data, &self.0)
+            }
+        }
+    };
+
+    tokens.into()
+}
@@ -19,24 +19,63 @@ pub(super) fn generate_aper_codec_for_asn_choice(
     if variant_tokens.is_err() {
         return variant_tokens.err().unwrap().to_compile_error().into();
     }
-    let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
+    let ChoiceVariantTokens {
+        decode_tokens: variant_decode_tokens,
+        ext_decode_tokens: variant_ext_decode_tokens,
+        encode_tokens: variant_encode_tokens,
+        peek_key_tokens: variant_peek_key_tokens,
+        ext_peek_key_tokens: variant_ext_peek_key_tokens,
+        unknown_extension_variant,
+    } = variant_tokens.unwrap();
+
+    // Extension Additions not known are retained in the `unknown_extensions` variant if present
+    // and the `unknown_choice_index` Decode Option is Lenient.
+    let unknown_ext_decode_tokens = if let Some(ref variant) = unknown_extension_variant {
+        quote! {
+            _ => {
+                let mode = data.decode_options().unknown_choice_index;
+                data.strict_or_warn(mode, asn1_codecs::__private::format!("Unknown Choice Extension Index {}", idx).as_str())?;
+                Ok(Self::#variant(asn1_codecs::aper::UnknownExtension { index: idx as usize, bytes }))
+            }
+        }
+    } else {
+        quote! {
+            _ => Err(asn1_codecs::aper::AperCodecError::new(asn1_codecs::__private::format!("Index {} is not a valid Choice Extension Index", idx).as_str())),
+        }
+    };
+
+    let peek_key_tokens = if let Some(ref peek_key) = params.peek_key {
+        generate_choice_peek_key_tokens(
+            name,
+            peek_key,
+            lb,
+            ub,
+            ext,
+            &variant_peek_key_tokens,
+            &variant_ext_peek_key_tokens,
+        )
+    } else {
+        quote! {}
+    };
 
     let tokens = quote! {
 
         impl asn1_codecs::aper::AperCodec for #name {
-            type Output = Self;
-
-            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
                 let (idx, extended) = asn1_codecs::aper::decode::decode_choice_idx(data, #lb, #ub, #ext)?;
                 if !extended {
                     match idx {
                         #(#variant_decode_tokens)*
-                        _ => Err(asn1_codecs::aper::AperCodecError::new(format!("Index {} is not a valid Choice Index", idx).as_str()))
+                        _ => Err(asn1_codecs::aper::AperCodecError::new(asn1_codecs::__private::format!("Index {} is not a valid Choice Index", idx).as_str()))
                     }
                 } else {
-                    Err(asn1_codecs::aper::AperCodecError::new("CHOICE Additions not supported yet."))
+                    let bytes = asn1_codecs::aper::decode::decode_open_type(data)?;
+                    match idx {
+                        #(#variant_ext_decode_tokens)*
+                        #unknown_ext_decode_tokens
+                    }
                 }
             }
 
@@ -48,19 +87,107 @@ pub(super) fn generate_aper_codec_for_asn_choice(
                 }
             }
         }
+
+        #peek_key_tokens
     };
 
     TokenStream::from(tokens)
 }
 
+// Generates `AperPeekKey` implementation and a `peek_<key>` function for the `CHOICE`.
+//
+// All the variants of the `CHOICE` should implement `AperPeekKey` with the same `Key` type. The
+// key of an Extension Addition is peeked from the Open Type it is encoded in, the unknown
+// Extension Additions are an error.
+fn generate_choice_peek_key_tokens(
+    name: &syn::Ident,
+    peek_key: &syn::LitStr,
+    lb: i128,
+    ub: i128,
+    ext: Option<&syn::LitBool>,
+    variant_tokens: &[(syn::LitInt, syn::Type)],
+    ext_variant_tokens: &[(syn::LitInt, syn::Type)],
+) -> proc_macro2::TokenStream {
+    let first_ty = match variant_tokens.first() {
+        Some((_, ty)) => ty,
+        None => {
+            return syn::Error::new_spanned(peek_key, "`peek_key` requires at least one variant.")
+                .to_compile_error()
+        }
+    };
+
+    let peek_fn_ident = quote::format_ident!("peek_{}", peek_key.value());
+    let peek_fn_doc = format!(
+        "Decode only the `{}` from the APER encoded `bytes`.",
+        peek_key.value()
+    );
+    let match_tokens = variant_tokens.iter().map(|(key, ty)| {
+        quote! {
+            #key => <#ty as asn1_codecs::aper::AperPeekKey>::peek_key(data),
+        }
+    });
+    let ext_match_tokens = ext_variant_tokens.iter().map(|(key, ty)| {
+        quote! {
+            #key => <#ty as asn1_codecs::aper::AperPeekKey>::peek_key(&mut ext_data),
+        }
+    });
+
+    quote! {
+        impl asn1_codecs::aper::AperPeekKey for #name {
+            type Key = <#first_ty as asn1_codecs::aper::AperPeekKey>::Key;
+
+            fn peek_key(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Key, asn1_codecs::aper::AperCodecError> {
+                log::debug!(concat!("peek_key: ", stringify!(#name)));
+
+                let (idx, extended) = asn1_codecs::aper::decode::decode_choice_idx(data, #lb, #ub, #ext)?;
+                if !extended {
+                    match idx {
+                        #(#match_tokens)*
+                        _ => Err(asn1_codecs::aper::AperCodecError::new(asn1_codecs::__private::format!("Index {} is not a valid Choice Index", idx).as_str()))
+                    }
+                } else {
+                    let bytes = asn1_codecs::aper::decode::decode_open_type(data)?;
+                    let mut ext_data = data.nested_from_slice(&bytes);
+                    match idx {
+                        #(#ext_match_tokens)*
+                        _ => Err(asn1_codecs::aper::AperCodecError::new(asn1_codecs::__private::format!("Index {} is not a valid Choice Extension Index", idx).as_str()))
+                    }
+                }
+            }
+        }
+
+        impl #name {
+            #[doc = #peek_fn_doc]
+            pub fn #peek_fn_ident(bytes: &[u8]) -> Result<<Self as asn1_codecs::aper::AperPeekKey>::Key, asn1_codecs::aper::AperCodecError> {
+                let mut data = asn1_codecs::aper::AperCodecData::from_slice(bytes);
+                <Self as asn1_codecs::aper::AperPeekKey>::peek_key(&mut data)
+            }
+        }
+    }
+}
+
+// Tokens for the variants of a `CHOICE`.
+//
+// Variants from the Extension Root and the Extension Additions are decoded separately, since the
+// Choice Index of the Extension Additions starts at 0. `unknown_extension_variant` is the variant
+// (if any) that holds the Extension Additions unknown to the decoder.
+#[derive(Default)]
+struct ChoiceVariantTokens {
+    decode_tokens: Vec<proc_macro2::TokenStream>,
+    ext_decode_tokens: Vec<proc_macro2::TokenStream>,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+    peek_key_tokens: Vec<(syn::LitInt, syn::Type)>,
+    ext_peek_key_tokens: Vec<(syn::LitInt, syn::Type)>,
+    unknown_extension_variant: Option<syn::Ident>,
+}
+
 fn generate_choice_variant_decode_tokens_using_attrs(
     ast: &syn::DeriveInput,
     lb: i128,
     ub: i128,
     ext: Option<&syn::LitBool>,
-) -> Result<(Vec<proc_macro2::TokenStream>, Vec<proc_macro2::TokenStream>), syn::Error> {
-    let mut decode_tokens = vec![];
-    let mut encode_tokens = vec![];
+) -> Result<ChoiceVariantTokens, syn::Error> {
+    let mut tokens = ChoiceVariantTokens::default();
 
     let mut errors = vec![];
     if let syn::Data::Enum(ref data) = ast.data {
@@ -68,6 +195,23 @@ fn generate_choice_variant_decode_tokens_using_attrs(
             let codec_params = parse_fld_meta_as_codec_params(&variant.attrs);
             match codec_params {
                 Err(e) => errors.push(e),
+                Ok(cp) if cp.unknown_extensions.as_ref().is_some_and(|u| u.value()) => {
+                    if tokens.unknown_extension_variant.is_some() {
+                        errors.push(syn::Error::new_spanned(
+                            variant,
+                            "Only one variant can hold the unknown Extension Additions.",
+                        ));
+                        continue;
+                    }
+                    let variant_ident = &variant.ident;
+                    tokens.encode_tokens.push(quote! {
+                        Self::#variant_ident(ref v) => {
+                            asn1_codecs::aper::encode::encode_choice_idx(data, #lb, #ub, #ext, v.index as i128, true)?;
+                            asn1_codecs::aper::encode::encode_open_type(data, &v.bytes)
+                        }
+                    });
+                    tokens.unknown_extension_variant = Some(variant_ident.clone());
+                }
                 Ok(cp) => {
                     let key = cp.key.as_ref();
                     if key.is_none() {
@@ -82,17 +226,42 @@ fn generate_choice_variant_decode_tokens_using_attrs(
                     if let syn::Fields::Unnamed(ref fields) = variant.fields {
                         if fields.unnamed.len() == 1 {
                             let ty = &fields.unnamed.first().as_ref().unwrap().ty;
-                            let variant_decode_token = quote! {
-                                #key => Ok(Self::#variant_ident(#ty::decode(data)?)),
-                            };
-                            let variant_encode_token = quote! {
-                                Self::#variant_ident(ref v) => {
-                                    asn1_codecs::aper::encode::encode_choice_idx(data, #lb, #ub, #ext, #key, #extended)?;
-                                    v.encode(data)
-                                }
-                            };
-                            decode_tokens.push(variant_decode_token);
-                            encode_tokens.push(variant_encode_token);
+                            let is_extended = extended.map(|e| e.value()).unwrap_or_default();
+                            if !is_extended {
+                                tokens.decode_tokens.push(quote! {
+                                    #key => Ok(Self::#variant_ident(<#ty as asn1_codecs::aper::AperCodec>::decode(data)?)),
+                                });
+                                tokens.encode_tokens.push(quote! {
+                                    Self::#variant_ident(ref v) => {
+                                        asn1_codecs::aper::encode::encode_choice_idx(data, #lb, #ub, #ext, #key, false)?;
+                                        v.encode(data)
+                                    }
+                                });
+                                tokens
+                                    .peek_key_tokens
+                                    .push((key.unwrap().clone(), ty.clone()));
+                            } else {
+                                // Extension Additions are encoded as Open Types.
+                                tokens.ext_decode_tokens.push(quote! {
+                                    #key => {
+                                        let mut ext_data = data.nested_from_slice(&bytes);
+                                        let value = <#ty as asn1_codecs::aper::AperCodec>::decode(&mut ext_data)?;
+                                        data.append_warnings(&mut ext_data);
+                                        Ok(Self::#variant_ident(value))
+                                    }
+                                });
+                                tokens.encode_tokens.push(quote! {
+                                    Self::#variant_ident(ref v) => {
+                                        asn1_codecs::aper::encode::encode_choice_idx(data, #lb, #ub, #ext, #key, true)?;
+                                        let mut ext_data = data.nested();
+                                        v.encode(&mut ext_data)?;
+                                        asn1_codecs::aper::encode::encode_open_type_data(data, ext_data)
+                                    }
+                                });
+                                tokens
+                                    .ext_peek_key_tokens
+                                    .push((key.unwrap().clone(), ty.clone()));
+                            }
                         } else {
                             errors.push(syn::Error::new_spanned(
                                 variant,
@@ -111,6 +280,6 @@ fn generate_choice_variant_decode_tokens_using_attrs(
         }
         Err(first.clone())
     } else {
-        Ok((decode_tokens, encode_tokens))
+        Ok(tokens)
     }
 }
@@ -34,23 +34,37 @@ pub(super) fn generate_aper_codec_for_asn_enumerated(
 
     let (lb, ub, ext) = utils::get_bounds_extensible_from_params(params);
 
+    // Values outside the Extension Root follow the values in the Extension Root. ie. The value of
+    // the first Extension Addition is `ub + 1`.
+    let ext_value_base = params
+        .ub
+        .as_ref()
+        .map_or(0, |ub| ub.value().parse::<i128>().unwrap() + 1);
+
     let tokens = quote! {
 
         impl asn1_codecs::aper::AperCodec for #name {
-            type Output = Self;
-
-            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
-                let decoded = asn1_codecs::aper::decode::decode_enumerated(data, #lb, #ub, #ext)?;
+                let (value, extended) = asn1_codecs::aper::decode::decode_enumerated(data, #lb, #ub, #ext)?;
+                let decoded = if extended { value.checked_add(#ext_value_base) } else { Some(value) };
+                let value = decoded.and_then(|decoded| <#ty as ::core::convert::TryFrom<i128>>::try_from(decoded).ok()).ok_or_else(|| {
+                    asn1_codecs::aper::AperCodecError::new(asn1_codecs::__private::format!(concat!("Value {} (extended: {}) cannot be represented in ", stringify!(#name)), value, extended).as_str())
+                })?;
 
-                Ok(Self(decoded.0 as #ty))
+                Ok(Self(value))
             }
 
             fn encode(&self, data: &mut asn1_codecs::aper::AperCodecData) -> Result<(), asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("encode: ", stringify!(#name)));
 
-                asn1_codecs::aper::encode::encode_enumerated(data, #lb, #ub, #ext, self.0 as i128, false)
+                let value = self.0 as i128;
+                if #ext && value >= #ext_value_base {
+                    asn1_codecs::aper::encode::encode_enumerated(data, #lb, #ub, #ext, value - #ext_value_base, true)
+                } else {
+                    asn1_codecs::aper::encode::encode_enumerated(data, #lb, #ub, #ext, value, false)
+                }
             }
         }
     };
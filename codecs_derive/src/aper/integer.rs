@@ -10,45 +10,68 @@ pub(super) fn generate_aper_codec_for_asn_integer(
 ) -> proc_macro::TokenStream {
     let name = &ast.ident;
 
-    let ty = if let syn::Data::Struct(ref d) = &ast.data {
-        match d.fields {
-            syn::Fields::Unnamed(ref f) => {
-                if f.unnamed.len() == 1 {
-                    let first = f.unnamed.first().unwrap();
-                    Some(first.ty.clone())
-                } else {
-                    None
-                }
-            }
-            _ => None,
+    let (ty, extended_field) = match utils::get_value_type_and_extended_field(ast) {
+        Some((ty, extended_field)) => (ty, extended_field),
+        None => {
+            return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+                .to_compile_error()
+                .into();
         }
-    } else {
-        None
     };
 
-    if ty.is_none() {
-        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
-            .to_compile_error()
-            .into();
+    let (lb, ub, ext) = utils::get_bounds_extensible_from_params(params);
+
+    // Arbitrary precision integers (`BigInt`) are not limited to `i128` values.
+    let is_big_integer = matches!(ty, syn::Type::Path(ref typepath)
+        if typepath.path.segments.last().is_some_and(|s| s.ident == "BigInt"));
+    if is_big_integer {
+        let (decoded_tokens, value_tokens, extended_tokens) =
+            utils::get_extended_field_tokens(extended_field, quote! { false });
+        let tokens = quote! {
+
+            impl asn1_codecs::aper::AperCodec for #name {
+                fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+                    log::debug!(concat!("decode: ", stringify!(#name)));
+
+                    let #decoded_tokens = asn1_codecs::aper::decode::decode_big_integer(data, #lb, #ub, #ext)?;
+                    Ok(#value_tokens)
+                }
+                fn encode(&self, data: &mut asn1_codecs::aper::AperCodecData) -> Result<(), asn1_codecs::aper::AperCodecError> {
+                    log::debug!(concat!("encode: ", stringify!(#name)));
+
+                    let extended = #extended_tokens;
+                    asn1_codecs::aper::encode::encode_big_integer(data, #lb, #ub, #ext, &self.0, extended)
+                }
+            }
+        };
+
+        return tokens.into();
     }
 
-    let (lb, ub, ext) = utils::get_bounds_extensible_from_params(params);
+    let extended =
+        utils::get_extended_tokens(&params.lb, &params.ub, &params.ext, quote! { value });
+    let (decoded_tokens, value_tokens, extended_tokens) =
+        utils::get_extended_field_tokens(extended_field, extended);
 
     let tokens = quote! {
 
         impl asn1_codecs::aper::AperCodec for #name {
-            type Output = Self;
-
-            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
-                let decoded = asn1_codecs::aper::decode::decode_integer(data, #lb, #ub, #ext)?;
-                Ok(Self(decoded.0 as #ty))
+                let #decoded_tokens = asn1_codecs::aper::decode::decode_integer(data, #lb, #ub, #ext)?;
+                let decoded = <#ty as ::core::convert::TryFrom<i128>>::try_from(decoded).map_err(|_| {
+                    asn1_codecs::aper::AperCodecError::new(asn1_codecs::__private::format!(concat!("Value {} cannot be represented in ", stringify!(#name)), decoded).as_str())
+                })?;
+
+                Ok(#value_tokens)
             }
             fn encode(&self, data: &mut asn1_codecs::aper::AperCodecData) -> Result<(), asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("encode: ", stringify!(#name)));
 
-                asn1_codecs::aper::encode::encode_integer(data, #lb, #ub, #ext, self.0 as i128, false)
+                let value = self.0 as i128;
+                let extended = #extended_tokens;
+                asn1_codecs::aper::encode::encode_integer(data, #lb, #ub, #ext, value, extended)
             }
         }
     };
@@ -2,6 +2,7 @@
 
 use super::attrs::TyCodecParams;
 
+mod any;
 mod bitstring;
 mod boolean;
 mod charstring;
@@ -31,9 +32,14 @@ pub(crate) fn generate_codec(
             charstring::generate_aper_codec_for_asn_charstring(ast, params)
         }
         "NULL" => null::generate_aper_codec_for_asn_null(ast, params),
-        "SEQUENCE" => seq::generate_aper_codec_for_asn_sequence(ast, params),
+        // The components of a `SET` are in the canonical order of their Tags, which is the order
+        // in which they are encoded.
+        "SEQUENCE" | "SET" => seq::generate_aper_codec_for_asn_sequence(ast, params),
         "OPEN" => open::generate_aper_codec_for_asn_open_type(ast, params),
-        "SEQUENCE-OF" => seqof::generate_aper_codec_for_asn_sequence_of(ast, params),
+        "SEQUENCE-OF" => seqof::generate_aper_codec_for_asn_sequence_of(ast, params, false),
+        "SET-OF" => seqof::generate_aper_codec_for_asn_sequence_of(ast, params, true),
+        // Values of `ANY`, `EXTERNAL` and `EMBEDDED PDV` are carried as raw bytes of an Open Type.
+        "ANY" => any::generate_aper_codec_for_asn_any(ast, params),
         "OBJECT-IDENTIFIER" => oid::generate_aper_codec_for_asn_object_identifier(ast, params),
         _ => syn::Error::new_spanned(ty.clone(), "This ASN.1 Type is not supported.")
             .to_compile_error()
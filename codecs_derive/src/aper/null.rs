@@ -14,9 +14,7 @@ pub(super) fn generate_aper_codec_for_asn_null(
 
         impl asn1_codecs::aper::AperCodec for #name {
 
-            type Output = Self;
-
-            fn decode(_data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(_data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
                 Ok(Self{})
@@ -10,46 +10,42 @@ pub(super) fn generate_aper_codec_for_asn_octetstring(
 ) -> proc_macro::TokenStream {
     let name = &ast.ident;
 
-    let ty = if let syn::Data::Struct(ref d) = &ast.data {
-        match d.fields {
-            syn::Fields::Unnamed(ref f) => {
-                if f.unnamed.len() == 1 {
-                    let first = f.unnamed.first().unwrap();
-                    Some(first.ty.clone())
-                } else {
-                    None
-                }
-            }
-            _ => None,
+    let extended_field = match utils::get_value_type_and_extended_field(ast) {
+        Some((_, extended_field)) => extended_field,
+        None => {
+            return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
+                .to_compile_error()
+                .into();
         }
-    } else {
-        None
     };
 
-    if ty.is_none() {
-        return syn::Error::new_spanned(ast, format!("{} Should be a Unit Struct.", name))
-            .to_compile_error()
-            .into();
-    }
-
     let (sz_lb, sz_ub, sz_ext) = utils::get_sz_bounds_extensible_from_params(params);
+    let extended = utils::get_extended_tokens(
+        &params.sz_lb,
+        &params.sz_ub,
+        &params.sz_ext,
+        quote! { length },
+    );
+
+    let (decoded_tokens, value_tokens, extended_tokens) =
+        utils::get_extended_field_tokens(extended_field, extended);
 
     let tokens = quote! {
 
         impl asn1_codecs::aper::AperCodec for #name {
-            type Output = Self;
-
-            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
-                let decoded = asn1_codecs::aper::decode::decode_octetstring(data, #sz_lb, #sz_ub, #sz_ext)?;
-                Ok(Self(decoded))
+                let #decoded_tokens = asn1_codecs::aper::decode::decode_octetstring(data, #sz_lb, #sz_ub, #sz_ext)?;
+                Ok(#value_tokens)
             }
 
             fn encode(&self, data: &mut asn1_codecs::aper::AperCodecData) -> Result<(), asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("encode: ", stringify!(#name)));
 
-                asn1_codecs::aper::encode::encode_octetstring(data, #sz_lb, #sz_ub, #sz_ext, &self.0, false)
+                let length = self.0.len() as i128;
+                let extended = #extended_tokens;
+                asn1_codecs::aper::encode::encode_octetstring(data, #sz_lb, #sz_ub, #sz_ext, &self.0, extended)
             }
         }
     };
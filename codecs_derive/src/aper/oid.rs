@@ -14,9 +14,7 @@ pub(super) fn generate_aper_codec_for_asn_object_identifier(
 
         impl asn1_codecs::aper::AperCodec for #name {
 
-            type Output = Self;
-
-            fn decode(_data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(_data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
                 Err(asn1_codecs::aper::AperCodecError::new("Object Identifier Decode Not Supported!"))
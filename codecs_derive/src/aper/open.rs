@@ -16,9 +16,9 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
     }
     let (variant_decode_tokens, variant_encode_tokens) = variant_tokens.unwrap();
 
-    let encode_tokens = if variant_encode_tokens.len() > 0 {
+    let encode_tokens = if !variant_encode_tokens.is_empty() {
         quote! {
-                let mut inner = asn1_codecs::aper::AperCodecData::new();
+                let mut inner = data.nested();
                 let _ = match self {
                     #(#variant_encode_tokens)*
                 };
@@ -35,9 +35,7 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
 
     let tokens = quote! {
         impl asn1_codecs::aper::AperCodec for #name {
-            type Output = Self;
-
-            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
                 let length = asn1_codecs::aper::decode::decode_length_determinent(data, None, None, false)?;
@@ -52,7 +50,7 @@ pub(super) fn generate_aper_codec_for_asn_open_type(
 
                 match key {
                     #(#variant_decode_tokens)*
-                    _ => Err(asn1_codecs::aper::AperCodecError::new(format!("Key {} Not Found", key).as_str()))
+                    _ => Err(asn1_codecs::aper::AperCodecError::new(asn1_codecs::__private::format!("Key {} Not Found", key).as_str()))
                 }
             }
 
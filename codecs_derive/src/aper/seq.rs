@@ -2,7 +2,8 @@
 
 use quote::quote;
 
-use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::attrs::{parse_fld_meta_as_codec_params, FieldVarCodecParams, TyCodecParams};
+use crate::utils;
 
 pub(super) fn generate_aper_codec_for_asn_sequence(
     ast: &syn::DeriveInput,
@@ -11,61 +12,246 @@ pub(super) fn generate_aper_codec_for_asn_sequence(
     let name = &ast.ident;
 
     let ext = params.ext.as_ref();
-    let opt_count = if params.optional_fields.is_some() {
-        params.optional_fields.as_ref().unwrap().clone()
-    } else {
-        syn::LitInt::new("0", proc_macro2::Span::call_site())
-    };
+    let opt_count = params
+        .optional_fields
+        .clone()
+        .unwrap_or_else(|| syn::LitInt::new("0", proc_macro2::Span::call_site()));
 
-    let fld_tokens = generate_seq_field_codec_tokens_using_attrs(ast);
+    let fld_tokens = generate_seq_field_codec_tokens_using_attrs(ast, name);
     if fld_tokens.is_err() {
         return fld_tokens.err().unwrap().to_compile_error().into();
     }
-    let (fld_decode_tokens, hdr_encode_tokens, fld_encode_tokens) = fld_tokens.unwrap();
+    let SeqFieldCodecTokens {
+        decode_tokens: fld_decode_tokens,
+        hdr_encode_tokens,
+        encode_tokens: fld_encode_tokens,
+        peek_key_tokens,
+        unknown_extensions_field,
+        ext_decode_tokens: ext_fld_decode_tokens,
+        ext_encode_tokens: ext_fld_encode_tokens,
+        ext_field_ids,
+        ext_bitmap_len,
+        constraint_checks,
+    } = fld_tokens.unwrap();
+
+    // Inner Type Constraints: see `DecodeOptions::inner_constraints`.
+    let (constraint_decode_tokens, constraint_encode_tokens) = if constraint_checks.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        let checks = constraint_checks.iter().map(|(check, _)| check);
+        let messages = constraint_checks.iter().map(|(_, message)| message);
+        let decode_tokens = quote! {
+            #(
+                if !(#checks) {
+                    let mode = data.decode_options().inner_constraints;
+                    data.strict_or_warn(mode, #messages)?;
+                }
+            )*
+        };
+        let checks = constraint_checks.iter().map(|(check, _)| check);
+        let messages = constraint_checks.iter().map(|(_, message)| message);
+        let encode_tokens = quote! {
+            let value = self;
+            #(
+                if !(#checks) {
+                    return Err(asn1_codecs::aper::AperCodecError::new(#messages));
+                }
+            )*
+        };
+        (decode_tokens, encode_tokens)
+    };
+
+    let (decode_tokens, ext_prepare_tokens, ext_hdr_encode_tokens, ext_encode_tokens) =
+        if ext_field_ids.is_empty() {
+            let fld_decode_tokens = fld_decode_tokens
+                .iter()
+                .map(|(id, tokens)| quote! { #id: #tokens, });
+
+            // Extension Additions are retained in the `unknown_extensions` field if present, else
+            // they are decoded and ignored.
+            let (
+                ext_decode_tokens,
+                ext_fld_decode_tokens,
+                ext_hdr_encode_tokens,
+                ext_encode_tokens,
+            ) = if let Some(ref id) = unknown_extensions_field {
+                (
+                    quote! {},
+                    quote! {
+                        #id: if extensions_present {
+                            asn1_codecs::aper::UnknownExtensions::decode(data)?
+                        } else {
+                            asn1_codecs::aper::UnknownExtensions::default()
+                        },
+                    },
+                    quote! { !self.#id.is_empty() },
+                    quote! { self.#id.encode(data)?; },
+                )
+            } else {
+                (
+                    quote! {
+                        if extensions_present {
+                            let _ = asn1_codecs::aper::UnknownExtensions::decode(data)?;
+                        }
+                    },
+                    quote! {},
+                    quote! { false },
+                    quote! {},
+                )
+            };
+
+            (
+                quote! {
+                    let value = Self{#(#fld_decode_tokens)* #ext_fld_decode_tokens};
+                    #ext_decode_tokens
+                    #constraint_decode_tokens
+
+                    Ok(value)
+                },
+                quote! {},
+                ext_hdr_encode_tokens,
+                ext_encode_tokens,
+            )
+        } else {
+            // The known Extension Additions are taken from the Extension Additions decoded after
+            // the root components, the remaining ones are retained in the `unknown_extensions`
+            // field if present. While encoding, the known Extension Additions present are added
+            // to them.
+            let fld_ids = fld_decode_tokens.iter().map(|(id, _)| id);
+            let fld_decode_tokens = fld_decode_tokens
+                .iter()
+                .map(|(id, tokens)| quote! { let #id = #tokens; });
+            let (unknown_fld_decode_tokens, extensions_tokens) = match unknown_extensions_field {
+                // The length of the bitmap is not retained if all the Extension Additions are
+                // known.
+                Some(ref id) => (
+                    quote! {
+                        #id: if extensions.is_empty() {
+                            asn1_codecs::aper::UnknownExtensions::default()
+                        } else {
+                            extensions
+                        },
+                    },
+                    quote! { self.#id.clone() },
+                ),
+                None => (
+                    quote! {},
+                    quote! { asn1_codecs::aper::UnknownExtensions::default() },
+                ),
+            };
+
+            (
+                quote! {
+                    #(#fld_decode_tokens)*
+                    let mut extensions = if extensions_present {
+                        asn1_codecs::aper::UnknownExtensions::decode(data)?
+                    } else {
+                        asn1_codecs::aper::UnknownExtensions::default()
+                    };
+                    #(#ext_fld_decode_tokens)*
+
+                    let value = Self{#(#fld_ids,)* #(#ext_field_ids,)* #unknown_fld_decode_tokens};
+                    #constraint_decode_tokens
+
+                    Ok(value)
+                },
+                quote! {
+                    let mut extensions = #extensions_tokens;
+                    #(#ext_fld_encode_tokens)*
+                    extensions.extend_bitmap_len(#ext_bitmap_len);
+                },
+                quote! { !extensions.is_empty() },
+                quote! { extensions.encode(data)?; },
+            )
+        };
+
+    let peek_key_impl_tokens = if let Some((key_ty, skip_tokens)) = peek_key_tokens {
+        quote! {
+            impl asn1_codecs::aper::AperPeekKey for #name {
+                type Key = #key_ty;
+
+                fn peek_key(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Key, asn1_codecs::aper::AperCodecError> {
+                    log::debug!(concat!("peek_key: ", stringify!(#name)));
+
+                    let (bitmap, _extensions_present) = asn1_codecs::aper::decode::decode_sequence_header(data, #ext, #opt_count)?;
+                    #(#skip_tokens)*
+                    <#key_ty as asn1_codecs::aper::AperCodec>::decode(data)
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let tokens = quote! {
         impl asn1_codecs::aper::AperCodec for #name {
-            type Output = Self;
-
-            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
-                let (bitmap, _extensions_present) = asn1_codecs::aper::decode::decode_sequence_header(data, #ext, #opt_count)?;
-                Ok(Self{#(#fld_decode_tokens)*})
+                let (bitmap, extensions_present) = asn1_codecs::aper::decode::decode_sequence_header(data, #ext, #opt_count)?;
+                #decode_tokens
             }
 
             fn encode(&self, data: &mut asn1_codecs::aper::AperCodecData) -> Result<(), asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("encode: ", stringify!(#name)));
 
+                #constraint_encode_tokens
+
                 let mut bitmap = bitvec::bitvec![u8, bitvec::prelude::Msb0; 0; #opt_count];
 
                 #(#hdr_encode_tokens)*
 
-                asn1_codecs::aper::encode::encode_sequence_header(data, #ext, &bitmap, false)?;
+                #ext_prepare_tokens
+
+                asn1_codecs::aper::encode::encode_sequence_header(data, #ext, &bitmap, #ext_hdr_encode_tokens)?;
 
                 #(#fld_encode_tokens)*
 
+                #ext_encode_tokens
+
                 Ok(())
             }
         }
+
+        #peek_key_impl_tokens
     };
 
     tokens.into()
 }
 
+// Tokens for the fields of a `SEQUENCE`.
+//
+// `peek_key_tokens` is present only if the `SEQUENCE` has a 'key' field, it contains the type of
+// the key field and the tokens to 'skip' (decode and ignore) the fields preceding the key field.
+//
+// `unknown_extensions_field` is the field (if any) that holds the Extension Additions.
+//
+// `ext_decode_tokens` and `ext_encode_tokens` are the tokens for the Extension Addition fields
+// (`ext_field_ids`), that are decoded from (and encoded into) the `extensions` local.
+// `ext_bitmap_len` is the number of Extension Additions known.
+//
+// `constraint_checks` are the checks of the Inner Type Constraints on the fields (expressions on
+// the `value` being encoded or decoded), each with a message for the value that fails the check.
+#[derive(Default)]
+struct SeqFieldCodecTokens {
+    decode_tokens: Vec<(syn::Ident, proc_macro2::TokenStream)>,
+    hdr_encode_tokens: Vec<proc_macro2::TokenStream>,
+    encode_tokens: Vec<proc_macro2::TokenStream>,
+    peek_key_tokens: Option<(syn::Type, Vec<proc_macro2::TokenStream>)>,
+    unknown_extensions_field: Option<syn::Ident>,
+    ext_decode_tokens: Vec<proc_macro2::TokenStream>,
+    ext_encode_tokens: Vec<proc_macro2::TokenStream>,
+    ext_field_ids: Vec<syn::Ident>,
+    ext_bitmap_len: usize,
+    constraint_checks: Vec<(proc_macro2::TokenStream, String)>,
+}
+
 fn generate_seq_field_codec_tokens_using_attrs(
     ast: &syn::DeriveInput,
-) -> Result<
-    (
-        Vec<proc_macro2::TokenStream>,
-        Vec<proc_macro2::TokenStream>,
-        Vec<proc_macro2::TokenStream>,
-    ),
-    syn::Error,
-> {
-    let mut decode_tokens = vec![];
-    let mut encode_tokens = vec![];
-    let mut hdr_encode_tokens = vec![];
+    name: &syn::Ident,
+) -> Result<SeqFieldCodecTokens, syn::Error> {
+    let mut tokens = SeqFieldCodecTokens::default();
+    let mut skip_tokens = vec![];
 
     let mut errors: Vec<syn::Error> = vec![];
     if let syn::Data::Struct(ref data) = ast.data {
@@ -74,18 +260,89 @@ fn generate_seq_field_codec_tokens_using_attrs(
                 let codec_params = parse_fld_meta_as_codec_params(&field.attrs);
                 match codec_params {
                     Err(e) => errors.push(e),
-                    Ok(cp) => {
-                        let field_type = get_field_type(field);
-                        if field_type.ty.is_none() {
+                    Ok(cp) if cp.unknown_extensions.as_ref().is_some_and(|u| u.value()) => {
+                        if tokens.unknown_extensions_field.is_some() {
                             errors.push(syn::Error::new_spanned(
                                 field,
-                                "Field Type is not in supported Format!",
+                                "Only one field can hold the unknown Extension Additions.",
                             ));
-                            continue;
                         } else {
-                            let ty_ident = field_type.ty.unwrap();
+                            tokens.unknown_extensions_field = field.ident.clone();
+                        }
+                    }
+                    Ok(cp) if cp.extension_idx.is_some() => {
+                        generate_inner_constraint_checks(
+                            field,
+                            &cp,
+                            name,
+                            &mut tokens,
+                            &mut errors,
+                        );
+                        generate_seq_extension_field_codec_tokens(
+                            field,
+                            &cp,
+                            &mut tokens,
+                            &mut errors,
+                        );
+                    }
+                    Ok(cp) if cp.version.is_some() => {
+                        errors.push(syn::Error::new_spanned(
+                            field,
+                            "`version` is valid only for an Extension Addition Field.",
+                        ));
+                    }
+                    Ok(cp) => {
+                        generate_inner_constraint_checks(
+                            field,
+                            &cp,
+                            name,
+                            &mut tokens,
+                            &mut errors,
+                        );
+                        let field_type = get_field_type(field);
+                        if let Some(ty) = field_type.ty {
                             let optional = field_type.is_optional;
-                            let fld_decode_tokens = if optional {
+                            let key_field = cp.key_field.as_ref();
+                            let is_key_field = if let Some(kf) = key_field {
+                                kf.value()
+                            } else {
+                                false
+                            };
+                            let default = cp.default.as_ref();
+                            let fld_decode_tokens = if optional && default.is_some() {
+                                errors.push(syn::Error::new_spanned(
+                                    field,
+                                    "Default Field cannot be an `Option`.",
+                                ));
+                                quote! {}
+                            } else if let Some(default) = default {
+                                // A `DEFAULT` Field absent from the encoding has the default value.
+                                match cp.optional_idx.as_ref() {
+                                    None => {
+                                        errors.push(syn::Error::new_spanned(
+                                            field,
+                                            "Default Field without Optional Index.",
+                                        ));
+                                        quote! {}
+                                    }
+                                    Some(optidx) => {
+                                        quote! {
+                                            {
+                                            let present = bitmap[#optidx];
+                                            if present {
+                                                let value = <#ty as asn1_codecs::aper::AperCodec>::decode(data)?;
+                                                if data.is_canonical() && value == #default() {
+                                                    data.check_canonical("DEFAULT component present with the default value")?;
+                                                }
+                                                value
+                                            } else {
+                                                #default()
+                                            }
+                                            }
+                                        }
+                                    }
+                                }
+                            } else if optional {
                                 let optional_idx = cp.optional_idx.as_ref();
 
                                 match optional_idx {
@@ -102,7 +359,7 @@ fn generate_seq_field_codec_tokens_using_attrs(
                                             {
                                             let present = bitmap[#optidx];
                                             if present {
-                                                Some(#ty_ident::decode(data)?)
+                                                Some(<#ty as asn1_codecs::aper::AperCodec>::decode(data)?)
                                             } else {
                                                 None
                                             }
@@ -110,33 +367,39 @@ fn generate_seq_field_codec_tokens_using_attrs(
                                         }
                                     }
                                 }
-                            } else {
-                                let key_field = cp.key_field.as_ref();
-                                let is_key_field = if let Some(kf) = key_field {
-                                    kf.value()
-                                } else {
-                                    false
-                                };
-
-                                if !is_key_field {
-                                    quote! {
-                                        {
-                                        #ty_ident::decode(data)?
-                                        }
+                            } else if !is_key_field {
+                                quote! {
+                                    {
+                                    <#ty as asn1_codecs::aper::AperCodec>::decode(data)?
                                     }
-                                } else {
-                                    quote! {
-                                        {
-                                        let value = #ty_ident::decode(data)?;
-                                        let _ = data.set_key(value.0 as i128);
-                                        value
-                                        }
+                                }
+                            } else {
+                                quote! {
+                                    {
+                                    let value = <#ty as asn1_codecs::aper::AperCodec>::decode(data)?;
+                                    let _ = data.set_key(value.0 as i128);
+                                    value
                                     }
                                 }
                             };
 
+                            if is_key_field && tokens.peek_key_tokens.is_none() {
+                                tokens.peek_key_tokens = Some((ty.clone(), skip_tokens.clone()));
+                            } else {
+                                skip_tokens.push(quote! {
+                                    let _ = #fld_decode_tokens;
+                                });
+                            }
+
                             let id = field.ident.as_ref().unwrap();
-                            let field_encode_token = if optional {
+                            let field_encode_token = if default.is_some() {
+                                let optional_idx = cp.optional_idx.as_ref();
+                                quote! {
+                                    if bitmap[#optional_idx] {
+                                        self.#id.encode(data)?;
+                                    }
+                                }
+                            } else if optional {
                                 quote! {
                                     if self.#id.is_some() {
                                         let #id = self.#id.as_ref().unwrap();
@@ -149,7 +412,15 @@ fn generate_seq_field_codec_tokens_using_attrs(
                                     self.#id.encode(data)?;
                                 }
                             };
-                            let header_encode_token = if optional {
+                            let header_encode_token = if let Some(default) = default {
+                                // A `DEFAULT` Field is not encoded if it has the default value.
+                                let optional_idx = cp.optional_idx.as_ref();
+                                quote! {
+                                    if self.#id != #default() {
+                                        bitmap.set(#optional_idx, true);
+                                    }
+                                }
+                            } else if optional {
                                 let optional_idx = cp.optional_idx.as_ref();
                                 quote! {
                                     if self.#id.is_some() {
@@ -160,10 +431,14 @@ fn generate_seq_field_codec_tokens_using_attrs(
                             } else {
                                 quote! {}
                             };
-                            let field_decode_token = quote! { #id: #fld_decode_tokens, };
-                            decode_tokens.push(field_decode_token);
-                            encode_tokens.push(field_encode_token);
-                            hdr_encode_tokens.push(header_encode_token);
+                            tokens.decode_tokens.push((id.clone(), fld_decode_tokens));
+                            tokens.encode_tokens.push(field_encode_token);
+                            tokens.hdr_encode_tokens.push(header_encode_token);
+                        } else {
+                            errors.push(syn::Error::new_spanned(
+                                field,
+                                "Field Type is not in supported Format!",
+                            ));
                         }
                     }
                 }
@@ -177,12 +452,166 @@ fn generate_seq_field_codec_tokens_using_attrs(
         }
         Err(first.clone())
     } else {
-        Ok((decode_tokens, hdr_encode_tokens, encode_tokens))
+        Ok(tokens)
+    }
+}
+
+// Extension Additions are encoded as Open Types after the root components. Each of them (or each
+// Extension Addition Group) has an index in the Extension Additions bitmap.
+fn generate_seq_extension_field_codec_tokens(
+    field: &syn::Field,
+    cp: &FieldVarCodecParams,
+    tokens: &mut SeqFieldCodecTokens,
+    errors: &mut Vec<syn::Error>,
+) {
+    let field_type = get_field_type(field);
+    let ty = match field_type.ty {
+        Some(ty) => ty,
+        None => {
+            errors.push(syn::Error::new_spanned(
+                field,
+                "Field Type is not in supported Format!",
+            ));
+            return;
+        }
+    };
+    if cp.key_field.as_ref().is_some_and(|kf| kf.value()) {
+        errors.push(syn::Error::new_spanned(
+            field,
+            "Extension Addition Field cannot be a Key Field.",
+        ));
+        return;
+    }
+    if cp.optional_idx.is_some() {
+        errors.push(syn::Error::new_spanned(
+            field,
+            "Extension Addition Field cannot have an Optional Index.",
+        ));
+        return;
+    }
+    let ext_idx = cp.extension_idx.as_ref().unwrap();
+    let idx = match ext_idx.base10_parse::<usize>() {
+        Ok(idx) => idx,
+        Err(e) => {
+            errors.push(e);
+            return;
+        }
+    };
+
+    let id = field.ident.as_ref().unwrap();
+    let (decode_tokens, encode_tokens) = match cp.default {
+        Some(_) if field_type.is_optional => {
+            errors.push(syn::Error::new_spanned(
+                field,
+                "Default Field cannot be an `Option`.",
+            ));
+            return;
+        }
+        Some(ref default) => (
+            quote! {
+                match extensions.take_addition::<#ty>(data, #ext_idx)? {
+                    Some(value) => {
+                        if data.is_canonical() && value == #default() {
+                            data.check_canonical("DEFAULT component present with the default value")?;
+                        }
+                        value
+                    }
+                    None => #default(),
+                }
+            },
+            quote! {
+                if self.#id != #default() {
+                    extensions.insert_addition(data, #ext_idx, &self.#id)?;
+                }
+            },
+        ),
+        None if field_type.is_optional => (
+            quote! {
+                extensions.take_addition::<#ty>(data, #ext_idx)?
+            },
+            quote! {
+                if let Some(ref value) = self.#id {
+                    extensions.insert_addition(data, #ext_idx, value)?;
+                }
+            },
+        ),
+        None => {
+            errors.push(syn::Error::new_spanned(
+                field,
+                "Extension Addition Field should be an `Option` or have a default value.",
+            ));
+            return;
+        }
+    };
+
+    tokens.ext_decode_tokens.push(quote! {
+        let #id = #decode_tokens;
+    });
+    tokens.ext_encode_tokens.push(encode_tokens);
+    tokens.ext_field_ids.push(id.clone());
+    tokens.ext_bitmap_len = tokens.ext_bitmap_len.max(idx + 1);
+}
+
+// Checks of the Inner Type Constraints on the field: the presence of an `Option` field
+// (`presence`) and the bounds of an `INTEGER` value (`value_lb` and `value_ub`).
+fn generate_inner_constraint_checks(
+    field: &syn::Field,
+    cp: &FieldVarCodecParams,
+    name: &syn::Ident,
+    tokens: &mut SeqFieldCodecTokens,
+    errors: &mut Vec<syn::Error>,
+) {
+    let is_optional = get_field_type(field).is_optional;
+    let id = field.ident.as_ref().unwrap();
+    let message = format!(
+        "Value of `{}` violates the Inner Type Constraint on `{}`.",
+        name, id
+    );
+
+    if let Some(ref presence) = cp.presence {
+        if !is_optional {
+            errors.push(syn::Error::new_spanned(
+                field,
+                "`presence` is valid only for an `Option` Field.",
+            ));
+            return;
+        }
+        let check = if presence.value() == "PRESENT" {
+            quote! { value.#id.is_some() }
+        } else {
+            quote! { value.#id.is_none() }
+        };
+        tokens.constraint_checks.push((check, message.clone()));
+    }
+
+    if cp.value_lb.is_some() || cp.value_ub.is_some() {
+        let check = if is_optional {
+            let within_bounds = utils::get_within_bounds_tokens(
+                &cp.value_lb,
+                &cp.value_ub,
+                &cp.value_big,
+                quote! { v.0 },
+            );
+            quote! {
+                match value.#id {
+                    Some(ref v) => #within_bounds,
+                    None => true,
+                }
+            }
+        } else {
+            utils::get_within_bounds_tokens(
+                &cp.value_lb,
+                &cp.value_ub,
+                &cp.value_big,
+                quote! { value.#id.0 },
+            )
+        };
+        tokens.constraint_checks.push((check, message));
     }
 }
 
 struct StructFieldType {
-    ty: Option<syn::Ident>,
+    ty: Option<syn::Type>,
     is_optional: bool,
 }
 
@@ -205,8 +634,8 @@ fn get_field_type(field: &syn::Field) -> StructFieldType {
             match type_params {
                 syn::PathArguments::AngleBracketed(params) => {
                     let generic_args = params.args.iter().next().unwrap();
-                    if let syn::GenericArgument::Type(syn::Type::Path(tpinner)) = generic_args {
-                        Some(tpinner.path.segments.iter().next().unwrap().ident.clone())
+                    if let syn::GenericArgument::Type(ty @ syn::Type::Path(_)) = generic_args {
+                        Some(ty.clone())
                     } else {
                         None
                     }
@@ -216,8 +645,8 @@ fn get_field_type(field: &syn::Field) -> StructFieldType {
         } else {
             None
         }
-    } else if let syn::Type::Path(ref tp) = field.ty {
-        Some(tp.path.segments.iter().next().unwrap().ident.clone())
+    } else if let syn::Type::Path(_) = field.ty {
+        Some(field.ty.clone())
     } else {
         None
     };
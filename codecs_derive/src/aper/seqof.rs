@@ -4,9 +4,12 @@ use quote::quote;
 
 use crate::{attrs::TyCodecParams, utils};
 
+// `set_of` is set for a `SET OF`, whose components are ordered by their encodings in the Canonical
+// mode.
 pub(super) fn generate_aper_codec_for_asn_sequence_of(
     ast: &syn::DeriveInput,
     params: &TyCodecParams,
+    set_of: bool,
 ) -> proc_macro::TokenStream {
     let name = &ast.ident;
 
@@ -35,17 +38,62 @@ pub(super) fn generate_aper_codec_for_asn_sequence_of(
 
     let (sz_lb, sz_ub, sz_ext) = utils::get_sz_bounds_extensible_from_params(params);
 
+    // The bounds of the entries (`WITH COMPONENT`): see `DecodeOptions::inner_constraints`.
+    let (entry_decode_tokens, entry_encode_tokens) =
+        if params.entry_lb.is_some() || params.entry_ub.is_some() {
+            let within_bounds = utils::get_within_bounds_tokens(
+                &params.entry_lb,
+                &params.entry_ub,
+                &params.entry_big,
+                quote! { item.0 },
+            );
+            let message = format!("Entry of `{}` violates the Inner Type Constraint.", name);
+            (
+                quote! {
+                    if !items.iter().all(|item| #within_bounds) {
+                        let mode = data.decode_options().inner_constraints;
+                        data.strict_or_warn(mode, #message)?;
+                    }
+                },
+                quote! {
+                    if !self.0.iter().all(|item| #within_bounds) {
+                        return Err(asn1_codecs::aper::AperCodecError::new(#message));
+                    }
+                },
+            )
+        } else {
+            (quote! {}, quote! {})
+        };
+
+    let (order_decode_tokens, items_encode_tokens) = if set_of {
+        (
+            quote! {
+                asn1_codecs::aper::decode::check_set_of_order(data, &items)?;
+            },
+            quote! {
+                asn1_codecs::aper::encode::encode_set_of_items(data, &self.0)?;
+            },
+        )
+    } else {
+        (
+            quote! {},
+            quote! {
+                for elem in &self.0 {
+                    let _ = elem.encode(data)?;
+                }
+            },
+        )
+    };
+
     let tokens = quote! {
 
         impl asn1_codecs::aper::AperCodec for #name {
-            type Output = Self;
-
-            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self::Output, asn1_codecs::aper::AperCodecError> {
+            fn decode(data: &mut asn1_codecs::aper::AperCodecData) -> Result<Self, asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("decode: ", stringify!(#name)));
 
                 let length = asn1_codecs::aper::decode::decode_length_determinent(data, #sz_lb, #sz_ub, #sz_ext)?;
 
-                let mut items = vec![];
+                let mut items = asn1_codecs::__private::vec![];
                 let mut count = 0;
                 loop {
                     items.push(#ty::decode(data)?);
@@ -54,6 +102,8 @@ pub(super) fn generate_aper_codec_for_asn_sequence_of(
                         break;
                     }
                 }
+                #entry_decode_tokens
+                #order_decode_tokens
 
                 Ok(Self(items))
             }
@@ -61,11 +111,11 @@ pub(super) fn generate_aper_codec_for_asn_sequence_of(
             fn encode(&self, data:&mut asn1_codecs::aper::AperCodecData) -> Result<(), asn1_codecs::aper::AperCodecError> {
                 log::debug!(concat!("encode: ", stringify!(#name)));
 
+                #entry_encode_tokens
+
                 let _ = asn1_codecs::aper::encode::encode_length_determinent(data, #sz_lb, #sz_ub, #sz_ext, self.0.len());
 
-                for elem in &self.0 {
-                    let _ = elem.encode(data)?;
-                }
+                #items_encode_tokens
                 Ok(())
             }
         }
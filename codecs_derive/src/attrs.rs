@@ -30,6 +30,27 @@ pub(crate) struct TyCodecParams {
     // Number of Optional Fields (In ASN.1 SEQUENCE types.)
     pub(crate) optional_fields: Option<syn::LitInt>,
 
+    // Name of the 'key' field of all the variants (In ASN.1 CHOICE types.)
+    pub(crate) peek_key: Option<syn::LitStr>,
+
+    // Tag of the Type (eg. `[APPLICATION 1] IMPLICIT`), if the Type is a Tagged Type.
+    pub(crate) tag: Option<syn::LitStr>,
+
+    // Lower Bound for the values of the entries (In ASN.1 SEQUENCE OF types with an Inner Type
+    // Constraint.)
+    pub(crate) entry_lb: Option<syn::LitStr>,
+
+    // Upper Bound for the values of the entries (In ASN.1 SEQUENCE OF types with an Inner Type
+    // Constraint.)
+    pub(crate) entry_ub: Option<syn::LitStr>,
+
+    // Whether the entries are arbitrary precision `INTEGER`s (`asn1_codecs::BigInt`).
+    pub(crate) entry_big: Option<syn::LitBool>,
+
+    // Identifiers of the values in the order of the values (In ASN.1 ENUMERATED types, used for
+    // the value notation.)
+    pub(crate) names: Option<syn::LitStr>,
+
     // The actual 'attribute' from the Syntax tree from which this struct is generated. This will
     // be used mainly for error reporting inside the functions where this struct is passed.
     pub(crate) attr: Option<syn::Attribute>,
@@ -166,6 +187,84 @@ pub(crate) fn parse_ty_meta_as_codec_params(
                                 )),
                             }
                         }
+                        // parses #[asn(peek_key = "procedure_code")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == PEEK_KEY => {
+                            match m.lit {
+                                syn::Lit::Str(ref peek_key) => {
+                                    let peek_key = peek_key.clone();
+                                    codec_params.peek_key.replace(peek_key);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`peek_key` value should be a String Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(tag = "[APPLICATION 1] IMPLICIT")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == TAG => {
+                            match m.lit {
+                                syn::Lit::Str(ref tag) if is_valid_tag(&tag.value()) => {
+                                    let tag = tag.clone();
+                                    codec_params.tag.replace(tag);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`tag` value should be a String Literal of the form `[APPLICATION 1] IMPLICIT`",
+                                )),
+                            }
+                        }
+                        // parses #[asn(entry_lb = "0")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == ENTRY_LB => {
+                            match m.lit {
+                                syn::Lit::Str(ref entry_lb) => {
+                                    let entry_lb = entry_lb.clone();
+                                    codec_params.entry_lb.replace(entry_lb);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`entry_lb` value should be an Integer Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(entry_ub = "10")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == ENTRY_UB => {
+                            match m.lit {
+                                syn::Lit::Str(ref entry_ub) => {
+                                    let entry_ub = entry_ub.clone();
+                                    codec_params.entry_ub.replace(entry_ub);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`entry_ub` value should be an Integer Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(entry_big = true)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == ENTRY_BIG => {
+                            match m.lit {
+                                syn::Lit::Bool(ref entry_big) => {
+                                    let entry_big = entry_big.clone();
+                                    codec_params.entry_big.replace(entry_big);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`entry_big` value should be a Bool Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(names = "reject ignore notify")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == NAMES => {
+                            match m.lit {
+                                syn::Lit::Str(ref names) => {
+                                    let names = names.clone();
+                                    codec_params.names.replace(names);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`names` value should be a String Literal",
+                                )),
+                            }
+                        }
                         _ => errors.push(syn::Error::new_spanned(
                             &nested,
                             "Unsupported attribute value. Attribute values should be of the form `a = b`"
@@ -207,6 +306,39 @@ pub(crate) struct FieldVarCodecParams {
     // If this is a Key Field
     pub(crate) key_field: Option<syn::LitBool>,
 
+    // If this Field (or Variant) holds the Extension Additions unknown to the decoder.
+    pub(crate) unknown_extensions: Option<syn::LitBool>,
+
+    // Tag of the Field (or Variant), including the Tags assigned by automatic tagging.
+    pub(crate) tag: Option<syn::LitStr>,
+
+    // Path of the function returning the default value of a `DEFAULT` Field. The Field is encoded
+    // only if it is not equal to the default value (the type of the Field implements `PartialEq`).
+    pub(crate) default: Option<syn::Path>,
+
+    // Index of the Extension Addition (or the Extension Addition Group) in the Extension
+    // Additions bitmap, if the Field is an Extension Addition.
+    pub(crate) extension_idx: Option<syn::LitInt>,
+
+    // Version number of the Extension Addition Group.
+    pub(crate) version: Option<syn::LitInt>,
+
+    // Presence of the Field (`PRESENT` or `ABSENT`) required by an Inner Type Constraint.
+    pub(crate) presence: Option<syn::LitStr>,
+
+    // Lower Bound for the value of the Field required by an Inner Type Constraint.
+    pub(crate) value_lb: Option<syn::LitStr>,
+
+    // Upper Bound for the value of the Field required by an Inner Type Constraint.
+    pub(crate) value_ub: Option<syn::LitStr>,
+
+    // Whether the Field is an arbitrary precision `INTEGER` (`asn1_codecs::BigInt`).
+    pub(crate) value_big: Option<syn::LitBool>,
+
+    // ASN.1 identifier of the component (or the alternative), or the name of the Type of an Open
+    // Type variant. (Used for the value notation.)
+    pub(crate) name: Option<syn::LitStr>,
+
     // The actual 'attribute' from the Syntax tree from which this struct is generated. This will
     // be used mainly for error reporting inside the functions where this struct is passed.
     pub(crate) attr: Option<syn::Attribute>,
@@ -284,6 +416,141 @@ pub(crate) fn parse_fld_meta_as_codec_params(
                                 )),
                             }
                         }
+                        // parses #[asn(unknown_extensions = true)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == UNKNOWN_EXTENSIONS => {
+                            match m.lit {
+                                syn::Lit::Bool(ref unknown) => {
+                                    let unknown = unknown.clone();
+                                    codec_params.unknown_extensions.replace(unknown);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`unknown_extensions` value should be a Bool Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(tag = "[APPLICATION 1] IMPLICIT")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == TAG => {
+                            match m.lit {
+                                syn::Lit::Str(ref tag) if is_valid_tag(&tag.value()) => {
+                                    let tag = tag.clone();
+                                    codec_params.tag.replace(tag);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`tag` value should be a String Literal of the form `[APPLICATION 1] IMPLICIT`",
+                                )),
+                            }
+                        }
+                        // parses #[asn(default = "Type::field_default")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == DEFAULT => {
+                            match m.lit {
+                                syn::Lit::Str(ref default) => match default.parse::<syn::Path>() {
+                                    Ok(path) => {
+                                        codec_params.default.replace(path);
+                                    }
+                                    Err(_) => errors.push(syn::Error::new_spanned(
+                                        nested,
+                                        "`default` value should be a String Literal containing the path of a function",
+                                    )),
+                                },
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`default` value should be a String Literal containing the path of a function",
+                                )),
+                            }
+                        }
+                        // parses #[asn(extension_idx = 0)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == EXTENSION_IDX => {
+                            match m.lit {
+                                syn::Lit::Int(ref ext_idx) => {
+                                    let ext_idx = ext_idx.clone();
+                                    codec_params.extension_idx.replace(ext_idx);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`extension_idx` value should be an Integer Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(version = 2)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == VERSION => {
+                            match m.lit {
+                                syn::Lit::Int(ref version) => {
+                                    let version = version.clone();
+                                    codec_params.version.replace(version);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`version` value should be an Integer Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(presence = "PRESENT")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == PRESENCE => {
+                            match m.lit {
+                                syn::Lit::Str(ref presence) if ["PRESENT", "ABSENT"].contains(&presence.value().as_str()) => {
+                                    let presence = presence.clone();
+                                    codec_params.presence.replace(presence);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`presence` value should be \"PRESENT\" or \"ABSENT\"",
+                                )),
+                            }
+                        }
+                        // parses #[asn(value_lb = "0")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == VALUE_LB => {
+                            match m.lit {
+                                syn::Lit::Str(ref value_lb) => {
+                                    let value_lb = value_lb.clone();
+                                    codec_params.value_lb.replace(value_lb);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`value_lb` value should be an Integer Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(value_ub = "10")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == VALUE_UB => {
+                            match m.lit {
+                                syn::Lit::Str(ref value_ub) => {
+                                    let value_ub = value_ub.clone();
+                                    codec_params.value_ub.replace(value_ub);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`value_ub` value should be an Integer Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(value_big = true)]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == VALUE_BIG => {
+                            match m.lit {
+                                syn::Lit::Bool(ref value_big) => {
+                                    let value_big = value_big.clone();
+                                    codec_params.value_big.replace(value_big);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`value_big` value should be a Bool Literal",
+                                )),
+                            }
+                        }
+                        // parses #[asn(name = "procedureCode")]
+                        syn::NestedMeta::Meta(syn::Meta::NameValue(ref m)) if m.path == NAME => {
+                            match m.lit {
+                                syn::Lit::Str(ref name) => {
+                                    let name = name.clone();
+                                    codec_params.name.replace(name);
+                                }
+                                _ => errors.push(syn::Error::new_spanned(
+                                    nested,
+                                    "`name` value should be a String Literal",
+                                )),
+                            }
+                        }
                         _ => errors.push(syn::Error::new_spanned(
                             &nested,
                             "Unsupported attribute value. Attribute values should be of the form `a = b`"
@@ -307,3 +574,20 @@ pub(crate) fn parse_fld_meta_as_codec_params(
         Ok(codec_params)
     }
 }
+
+// Checks that the Tag is of the form `[CLASS number] IMPLICIT|EXPLICIT`, where `CLASS` is one of
+// `UNIVERSAL`, `APPLICATION` or `PRIVATE` or is absent for the context specific Tags.
+fn is_valid_tag(tag: &str) -> bool {
+    let (number, mode) = match tag.strip_prefix('[').and_then(|t| t.split_once(']')) {
+        Some(parts) => parts,
+        None => return false,
+    };
+
+    let number = match number.split_once(' ') {
+        Some((class, number)) if ["UNIVERSAL", "APPLICATION", "PRIVATE"].contains(&class) => number,
+        Some(_) => return false,
+        None => number,
+    };
+
+    number.parse::<u32>().is_ok() && [" IMPLICIT", " EXPLICIT"].contains(&mode)
+}
@@ -9,6 +9,10 @@ mod symbol;
 
 mod aper;
 
+mod reflect;
+
+mod notation;
+
 mod utils;
 
 /// APER Codec Derive Macro support.
@@ -16,26 +20,64 @@ mod utils;
 pub fn derive(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
 
+    match parse_ty_codec_params(&ast) {
+        Ok(codec_params) => aper::generate_codec(&ast, &codec_params),
+        Err(e) => e,
+    }
+}
+
+/// `Asn1Reflect` Derive Macro support.
+///
+/// Uses the same `asn` attributes as the `AperCodec` Derive Macro.
+#[proc_macro_derive(Asn1Reflect, attributes(asn))]
+pub fn derive_reflect(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match parse_ty_codec_params(&ast) {
+        Ok(codec_params) => reflect::generate_reflect(&ast, &codec_params),
+        Err(e) => e,
+    }
+}
+
+/// `Asn1ValueNotation` Derive Macro support.
+///
+/// Uses the same `asn` attributes as the `AperCodec` Derive Macro. The ASN.1 identifiers are given
+/// by the `name` attribute of the fields and the variants (eg. `#[asn(name = "procedureCode")]`)
+/// and by the `names` attribute of the `ENUMERATED` Types, in the order of their values (eg.
+/// `#[asn(type = "ENUMERATED", names = "reject ignore notify", ...)]`).
+#[proc_macro_derive(Asn1ValueNotation, attributes(asn))]
+pub fn derive_notation(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+
+    match parse_ty_codec_params(&ast) {
+        Ok(codec_params) => notation::generate_notation(&ast, &codec_params),
+        Err(e) => e,
+    }
+}
+
+fn parse_ty_codec_params(ast: &DeriveInput) -> Result<attrs::TyCodecParams, TokenStream> {
     let codec_params = attrs::parse_ty_meta_as_codec_params(&ast.attrs);
     if codec_params.is_err() {
-        return codec_params.err().unwrap().to_compile_error().into();
+        return Err(codec_params.err().unwrap().to_compile_error().into());
     }
 
     let codec_params = codec_params.unwrap();
     if codec_params.attr.is_none() {
-        return syn::Error::new_spanned(ast, "Missing attribute 'asn' for the struct.")
-            .to_compile_error()
-            .into();
+        return Err(
+            syn::Error::new_spanned(ast, "Missing attribute 'asn' for the struct.")
+                .to_compile_error()
+                .into(),
+        );
     }
 
     if codec_params.ty.is_none() {
-        return syn::Error::new_spanned(
+        return Err(syn::Error::new_spanned(
             codec_params.attr,
             "Missing parameter 'type' for the attribute.",
         )
         .to_compile_error()
-        .into();
+        .into());
     }
 
-    aper::generate_codec(&ast, &codec_params)
+    Ok(codec_params)
 }
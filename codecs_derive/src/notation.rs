@@ -0,0 +1,512 @@
+//! Implementation of `Asn1ValueNotation` generation for different ASN Types.
+//!
+//! The value notation uses the identifiers from the ASN.1 definitions (eg. `procedureCode` for the
+//! `procedure_code` field), which are given by the `name` attribute of the fields and the variants
+//! and by the `names` attribute of the `ENUMERATED` Types.
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils;
+
+pub(crate) fn generate_notation(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let ty = params.ty.as_ref().unwrap();
+
+    let tokens = match ty.value().as_str() {
+        "INTEGER" => generate_integer_notation_tokens(ast),
+        "ENUMERATED" => generate_enumerated_notation_tokens(ast, params),
+        "BOOLEAN" => generate_value_notation_tokens(
+            ast,
+            quote! { asn1_codecs::notation::fmt_boolean(f, self.0) },
+            quote! { parser.boolean()? },
+        ),
+        "BITSTRING" => generate_value_notation_tokens(
+            ast,
+            quote! { asn1_codecs::notation::fmt_bits(f, &self.0) },
+            quote! { parser.bit_string()? },
+        ),
+        "OCTET-STRING" | "ANY" => generate_value_notation_tokens(
+            ast,
+            quote! { asn1_codecs::notation::fmt_octets(f, &self.0) },
+            quote! { parser.octet_string()? },
+        ),
+        "UTF8String" | "PrintableString" | "VisibleString" => generate_value_notation_tokens(
+            ast,
+            quote! { asn1_codecs::notation::fmt_string(f, &self.0) },
+            quote! { parser.char_string()? },
+        ),
+        "NULL" => Ok((
+            quote! { f.write_str("NULL") },
+            quote! {
+                parser.null()?;
+                Ok(Self)
+            },
+            quote! {},
+        )),
+        // The value of an OBJECT IDENTIFIER is not retained.
+        "OBJECT-IDENTIFIER" => Ok((
+            quote! { f.write_str("{ }") },
+            quote! {
+                parser.expect("{")?;
+                parser.expect("}")?;
+                Ok(Self)
+            },
+            quote! {},
+        )),
+        "SEQUENCE" | "SET" => generate_sequence_notation_tokens(ast),
+        "CHOICE" => generate_choice_notation_tokens(ast),
+        "OPEN" => generate_open_notation_tokens(ast),
+        "SEQUENCE-OF" | "SET-OF" => Ok((
+            quote! {
+                let mut components = asn1_codecs::notation::Components::new(f)?;
+                for entry in &self.0 {
+                    components.component(None, entry)?;
+                }
+                components.finish()
+            },
+            quote! {
+                parser.expect("{")?;
+                let mut entries = asn1_codecs::__private::vec![];
+                let mut first = true;
+                while parser.next_component(&mut first)? {
+                    entries.push(asn1_codecs::notation::Asn1ValueNotation::parse_value(parser)?);
+                }
+                Ok(Self(entries))
+            },
+            quote! {},
+        )),
+        _ => Err(syn::Error::new_spanned(
+            ty.clone(),
+            "This ASN.1 Type is not supported.",
+        )),
+    };
+    let (fmt_tokens, parse_tokens, extra_tokens) = match tokens {
+        Ok(tokens) => tokens,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let tokens = quote! {
+        impl asn1_codecs::notation::Asn1ValueNotation for #name {
+            fn fmt_value(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                #fmt_tokens
+            }
+
+            fn parse_value(
+                parser: &mut asn1_codecs::notation::ValueParser<'_>,
+            ) -> ::core::result::Result<Self, asn1_codecs::notation::ValueNotationError> {
+                #parse_tokens
+            }
+
+            #extra_tokens
+        }
+    };
+
+    tokens.into()
+}
+
+type NotationTokens = (TokenStream, TokenStream, TokenStream);
+
+// The value of the field holding the extension bit (if any) of a parsed value. A parsed value is
+// encoded as a conforming encoder would.
+fn get_extended_value_tokens(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
+    match utils::get_value_type_and_extended_field(ast) {
+        Some((_, true)) => Ok(quote! { , false }),
+        Some((_, false)) => Ok(quote! {}),
+        None => Err(syn::Error::new_spanned(
+            ast,
+            "The Type should be a Unit Struct (eg. `struct Foo(u8)`).",
+        )),
+    }
+}
+
+fn generate_integer_notation_tokens(ast: &syn::DeriveInput) -> Result<NotationTokens, syn::Error> {
+    let extended_value = get_extended_value_tokens(ast)?;
+    let (inner, _) = utils::get_value_type_and_extended_field(ast).unwrap();
+
+    let big = matches!(inner, syn::Type::Path(ref typepath)
+        if typepath.path.segments.last().is_some_and(|s| s.ident == "BigInt"));
+    let parse_tokens = if big {
+        quote! { Ok(Self(parser.big_integer()? #extended_value)) }
+    } else {
+        quote! {
+            let value = parser.integer()?;
+            <#inner as ::core::convert::TryFrom<i128>>::try_from(value)
+                .map(|value| Self(value #extended_value))
+                .map_err(|_| parser.error("INTEGER value is out of range"))
+        }
+    };
+
+    Ok((
+        quote! { ::core::write!(f, "{}", self.0) },
+        parse_tokens,
+        quote! {},
+    ))
+}
+
+// The values of an `ENUMERATED` Type are the positions of their identifiers in the `names`
+// attribute. Values without an identifier (unknown Extension Additions) are formatted as numbers.
+fn generate_enumerated_notation_tokens(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> Result<NotationTokens, syn::Error> {
+    let names = match params.names {
+        Some(ref names) => names.value(),
+        None => {
+            return Err(syn::Error::new_spanned(
+                params.attr.as_ref().unwrap(),
+                "Missing parameter 'names' for the attribute.",
+            ))
+        }
+    };
+    let extended_value = get_extended_value_tokens(ast)?;
+
+    let names = names.split_whitespace().collect::<Vec<_>>();
+    let values = (0..names.len()).map(proc_macro2::Literal::usize_unsuffixed);
+    let fmt_arms = names
+        .iter()
+        .zip(values.clone())
+        .map(|(name, value)| quote! { #value => #name, });
+    let parse_arms = names
+        .iter()
+        .zip(values)
+        .map(|(name, value)| quote! { #name => Ok(Self(#value #extended_value)), });
+
+    Ok((
+        quote! {
+            f.write_str(match self.0 {
+                #(#fmt_arms)*
+                _ => return ::core::write!(f, "{}", self.0),
+            })
+        },
+        quote! {
+            match parser.identifier()? {
+                #(#parse_arms)*
+                other => Err(parser.unexpected(other)),
+            }
+        },
+        quote! {},
+    ))
+}
+
+// Types whose value is the first field of the Unit Struct.
+fn generate_value_notation_tokens(
+    ast: &syn::DeriveInput,
+    fmt_tokens: TokenStream,
+    value_tokens: TokenStream,
+) -> Result<NotationTokens, syn::Error> {
+    let extended_value = get_extended_value_tokens(ast)?;
+
+    Ok((
+        fmt_tokens,
+        quote! { Ok(Self(#value_tokens #extended_value)) },
+        quote! {},
+    ))
+}
+
+// The identifier given by the `name` attribute of a field or a variant.
+fn get_name(
+    params: &crate::attrs::FieldVarCodecParams,
+    spanned: &dyn quote::ToTokens,
+) -> Result<String, syn::Error> {
+    params
+        .name
+        .as_ref()
+        .map(|name| name.value())
+        .ok_or_else(|| {
+            syn::Error::new_spanned(spanned, "Missing parameter 'name' for the attribute.")
+        })
+}
+
+// The `T` of an `Option<T>` field.
+fn get_option_inner_type(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(ref typepath) = ty else {
+        return None;
+    };
+    let segment = typepath.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref args) => match args.args.first()? {
+            syn::GenericArgument::Type(ref ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Components are formatted in the order of the definition and the absent `OPTIONAL` components
+// are not formatted. While parsing, the components can be in any order.
+//
+// An Extension Addition Group (an Extension Addition without a `name`) is formatted and parsed as
+// the components of the `SEQUENCE`, the group is present if any of its components is present.
+fn generate_sequence_notation_tokens(ast: &syn::DeriveInput) -> Result<NotationTokens, syn::Error> {
+    let fields = match ast.data {
+        syn::Data::Struct(ref s) => &s.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ast,
+                "`SEQUENCE` should be a `struct`.",
+            ))
+        }
+    };
+
+    let mut fmt_tokens = TokenStream::new();
+    let mut parse_arms = TokenStream::new();
+    let mut group_arms = TokenStream::new();
+    let mut init_tokens = TokenStream::new();
+    let mut field_tokens = TokenStream::new();
+    let mut names = vec![];
+    for field in fields {
+        let params = parse_fld_meta_as_codec_params(&field.attrs)?;
+        let id = field.ident.as_ref().unwrap();
+
+        // Unknown Extension Additions are formatted only if present, so that the value is
+        // re-encoded as it was decoded.
+        if params
+            .unknown_extensions
+            .as_ref()
+            .is_some_and(|u| u.value())
+        {
+            fmt_tokens.extend(quote! {
+                if !self.#id.is_empty() {
+                    components.component(Some("unknownExtensions"), &self.#id)?;
+                }
+            });
+            parse_arms.extend(quote! {
+                "unknownExtensions" => #id = Some(asn1_codecs::notation::Asn1ValueNotation::parse_value(parser)?),
+            });
+            init_tokens.extend(quote! { let mut #id = None; });
+            field_tokens.extend(quote! { #id: #id.unwrap_or_default(), });
+            continue;
+        }
+
+        let optional = params.default.is_none()
+            && (params.optional_idx.is_some() || params.extension_idx.is_some());
+        if params.name.is_none() && params.extension_idx.is_some() {
+            let group_ty = get_option_inner_type(&field.ty).ok_or_else(|| {
+                syn::Error::new_spanned(field, "Extension Addition Group should be an `Option`.")
+            })?;
+            fmt_tokens.extend(quote! {
+                if let Some(ref group) = self.#id {
+                    asn1_codecs::notation::Asn1ValueNotation::fmt_components(group, components)?;
+                }
+            });
+            group_arms.extend(quote! {
+                other if <#group_ty as asn1_codecs::notation::Asn1ValueNotation>::component_identifiers().contains(&other) => {
+                    #id.parse_component(other, parser)?
+                }
+            });
+            init_tokens.extend(quote! {
+                let mut #id = asn1_codecs::notation::GroupComponents::default();
+            });
+            field_tokens.extend(quote! { #id: #id.finish(parser)?, });
+            continue;
+        }
+
+        let name = get_name(&params, field)?;
+        if optional {
+            fmt_tokens.extend(quote! {
+                if let Some(ref value) = self.#id {
+                    components.component(Some(#name), value)?;
+                }
+            });
+            field_tokens.extend(quote! { #id, });
+        } else {
+            fmt_tokens.extend(quote! {
+                components.component(Some(#name), &self.#id)?;
+            });
+            match params.default {
+                Some(ref default) => {
+                    field_tokens.extend(quote! { #id: #id.unwrap_or_else(#default), });
+                }
+                None => {
+                    field_tokens.extend(quote! { #id: #id.ok_or_else(|| parser.missing(#name))?, });
+                }
+            }
+        }
+        parse_arms.extend(quote! {
+            #name => #id = Some(asn1_codecs::notation::Asn1ValueNotation::parse_value(parser)?),
+        });
+        init_tokens.extend(quote! { let mut #id = None; });
+        names.push(name);
+    }
+
+    let components_param = if fmt_tokens.is_empty() {
+        format_ident!("_components")
+    } else {
+        format_ident!("components")
+    };
+    let parse_tokens = if parse_arms.is_empty() && group_arms.is_empty() {
+        quote! {
+            parser.expect("{")?;
+            parser.expect("}")?;
+            Ok(Self {})
+        }
+    } else {
+        quote! {
+            #init_tokens
+            parser.expect("{")?;
+            let mut first = true;
+            while parser.next_component(&mut first)? {
+                match parser.identifier()? {
+                    #parse_arms
+                    #group_arms
+                    other => return Err(parser.unexpected(other)),
+                }
+            }
+            Ok(Self {
+                #field_tokens
+            })
+        }
+    };
+
+    Ok((
+        quote! {
+            let mut components = asn1_codecs::notation::Components::new(f)?;
+            asn1_codecs::notation::Asn1ValueNotation::fmt_components(self, &mut components)?;
+            components.finish()
+        },
+        parse_tokens,
+        quote! {
+            fn component_identifiers() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            fn fmt_components(
+                &self,
+                #components_param: &mut asn1_codecs::notation::Components<'_, '_>,
+            ) -> ::core::fmt::Result {
+                #fmt_tokens
+                Ok(())
+            }
+        },
+    ))
+}
+
+// A `CHOICE` value is formatted as `identifier : value`. The variant holding an unknown Extension
+// Addition is `unknownExtension`.
+fn generate_choice_notation_tokens(ast: &syn::DeriveInput) -> Result<NotationTokens, syn::Error> {
+    let variants = get_variants(ast, "`CHOICE` should be an `enum`.")?;
+
+    let mut names = vec![];
+    for variant in variants {
+        let params = parse_fld_meta_as_codec_params(&variant.attrs)?;
+        let name = if params
+            .unknown_extensions
+            .as_ref()
+            .is_some_and(|u| u.value())
+        {
+            "unknownExtension".to_string()
+        } else {
+            get_name(&params, variant)?
+        };
+        names.push((name, variant.ident.clone()));
+    }
+
+    let (fmt_tokens, parse_tokens) = generate_variants_notation_tokens(&names);
+    Ok((fmt_tokens, parse_tokens, quote! {}))
+}
+
+// An Open Type value is formatted as `Type : value`, the variants are named after their Types.
+fn generate_open_notation_tokens(ast: &syn::DeriveInput) -> Result<NotationTokens, syn::Error> {
+    let variants = get_variants(ast, "`OPEN` should be an `enum`.")?;
+
+    let mut names: Vec<(String, syn::Ident)> = vec![];
+    let mut key_arms = vec![];
+    for variant in variants {
+        let params = parse_fld_meta_as_codec_params(&variant.attrs)?;
+        let name = get_name(&params, variant)?;
+        let key = params.key.as_ref().ok_or_else(|| {
+            syn::Error::new_spanned(variant, "Missing parameter 'key' for the attribute.")
+        })?;
+        let id = &variant.ident;
+        key_arms.push(quote! { Self::#id(_) => Some(#key), });
+        names.push((name, id.clone()));
+    }
+
+    let (fmt_tokens, parse_tokens) = generate_variants_notation_tokens(&names);
+    let key_tokens = if key_arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #(#key_arms)*
+            }
+        }
+    };
+
+    Ok((
+        fmt_tokens,
+        parse_tokens,
+        quote! {
+            fn open_type_key(&self) -> Option<i128> {
+                #key_tokens
+            }
+        },
+    ))
+}
+
+fn get_variants<'a>(
+    ast: &'a syn::DeriveInput,
+    message: &str,
+) -> Result<&'a syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>, syn::Error> {
+    match ast.data {
+        syn::Data::Enum(ref e) => Ok(&e.variants),
+        _ => Err(syn::Error::new_spanned(ast, message)),
+    }
+}
+
+// If more than one variant has the same identifier, the first of them is parsed.
+fn generate_variants_notation_tokens(
+    variants: &[(String, syn::Ident)],
+) -> (TokenStream, TokenStream) {
+    if variants.is_empty() {
+        return (
+            quote! {
+                let _ = f;
+                match *self {}
+            },
+            quote! {
+                let identifier = parser.identifier()?;
+                Err(parser.unexpected(identifier))
+            },
+        );
+    }
+
+    let fmt_arms = variants.iter().map(|(name, variant)| {
+        quote! {
+            Self::#variant(value) => asn1_codecs::notation::fmt_choice(f, #name, value),
+        }
+    });
+    let parse_arms = variants
+        .iter()
+        .enumerate()
+        .filter(|(idx, (name, _))| variants[..*idx].iter().all(|(n, _)| n != name))
+        .map(|(_, (name, variant))| {
+            quote! {
+                #name => Ok(Self::#variant(asn1_codecs::notation::Asn1ValueNotation::parse_value(parser)?)),
+            }
+        });
+
+    (
+        quote! {
+            match self {
+                #(#fmt_arms)*
+            }
+        },
+        quote! {
+            let identifier = parser.identifier()?;
+            parser.expect(":")?;
+            match identifier {
+                #(#parse_arms)*
+                other => Err(parser.unexpected(other)),
+            }
+        },
+    )
+}
@@ -0,0 +1,233 @@
+//! Implementation of `Asn1Reflect` generation for different ASN Types.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::attrs::{parse_fld_meta_as_codec_params, TyCodecParams};
+use crate::utils;
+
+pub(crate) fn generate_reflect(
+    ast: &syn::DeriveInput,
+    params: &TyCodecParams,
+) -> proc_macro::TokenStream {
+    let name = &ast.ident;
+    let ty = params.ty.as_ref().unwrap();
+
+    let kind = match ty.value().as_str() {
+        "BOOLEAN" => quote! { Boolean },
+        "CHOICE" => quote! { Choice },
+        "INTEGER" => quote! { Integer },
+        "ENUMERATED" => quote! { Enumerated },
+        "BITSTRING" => quote! { BitString },
+        "OCTET-STRING" => quote! { OctetString },
+        "UTF8String" | "PrintableString" | "VisibleString" => quote! { CharacterString },
+        "NULL" => quote! { Null },
+        "SEQUENCE" => quote! { Sequence },
+        "OPEN" | "ANY" => quote! { Open },
+        "SEQUENCE-OF" => quote! { SequenceOf },
+        "SET" => quote! { Set },
+        "SET-OF" => quote! { SetOf },
+        "OBJECT-IDENTIFIER" => quote! { ObjectIdentifier },
+        _ => {
+            return syn::Error::new_spanned(ty.clone(), "This ASN.1 Type is not supported.")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let value_tokens = match ty.value().as_str() {
+        "BOOLEAN" => quote! { Boolean(self.0) },
+        "INTEGER" if is_big_integer(ast) => quote! { BigInteger(&self.0) },
+        "INTEGER" | "ENUMERATED" => quote! { Integer(self.0 as i128) },
+        "BITSTRING" => quote! { BitString(&self.0) },
+        "OCTET-STRING" | "ANY" => quote! { OctetString(&self.0) },
+        "UTF8String" | "PrintableString" | "VisibleString" => {
+            quote! { CharacterString(&self.0) }
+        }
+        "NULL" => quote! { Null },
+        "OBJECT-IDENTIFIER" => quote! { Unsupported },
+        _ => quote! { Constructed },
+    };
+
+    let key_tokens = match ty.value().as_str() {
+        "SEQUENCE" => generate_sequence_key_tokens(ast),
+        _ => Ok(quote! {}),
+    };
+    let key_tokens = match key_tokens {
+        Ok(tokens) => tokens,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let children_tokens = match ty.value().as_str() {
+        "SEQUENCE" | "SET" => generate_sequence_children_tokens(ast),
+        "SEQUENCE-OF" | "SET-OF" => Ok(quote! {
+            self.0
+                .iter()
+                .map(|entry| (None, entry as &dyn asn1_codecs::reflect::Asn1Reflect))
+                .collect()
+        }),
+        "CHOICE" | "OPEN" => generate_variant_children_tokens(ast),
+        _ => Ok(quote! { asn1_codecs::reflect::Asn1Children::new() }),
+    };
+    let children_tokens = match children_tokens {
+        Ok(tokens) => tokens,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let (lb, ub, ext) = utils::get_bounds_extensible_from_params(params);
+    let (sz_lb, sz_ub, sz_ext) = utils::get_sz_bounds_extensible_from_params(params);
+
+    let tokens = quote! {
+        impl asn1_codecs::reflect::Asn1Reflect for #name {
+            fn type_name(&self) -> &'static str {
+                stringify!(#name)
+            }
+
+            fn kind(&self) -> asn1_codecs::reflect::Asn1Kind {
+                asn1_codecs::reflect::Asn1Kind::#kind
+            }
+
+            fn constraints(&self) -> asn1_codecs::reflect::Asn1Constraints {
+                asn1_codecs::reflect::Asn1Constraints {
+                    lb: #lb,
+                    ub: #ub,
+                    extensible: #ext,
+                    sz_lb: #sz_lb,
+                    sz_ub: #sz_ub,
+                    sz_extensible: #sz_ext,
+                }
+            }
+
+            #[allow(clippy::unnecessary_cast)]
+            fn value(&self) -> asn1_codecs::reflect::Asn1Value<'_> {
+                asn1_codecs::reflect::Asn1Value::#value_tokens
+            }
+
+            fn children(&self) -> asn1_codecs::reflect::Asn1Children<'_> {
+                #children_tokens
+            }
+
+            fn as_reflect(&self) -> &dyn asn1_codecs::reflect::Asn1Reflect {
+                self
+            }
+
+            #key_tokens
+        }
+    };
+
+    tokens.into()
+}
+
+// Arbitrary precision integers are represented as `BigInt`.
+fn is_big_integer(ast: &syn::DeriveInput) -> bool {
+    match ast.data {
+        syn::Data::Struct(ref s) => s.fields.iter().next().is_some_and(|f| {
+            matches!(f.ty, syn::Type::Path(ref typepath)
+                if typepath.path.segments.last().is_some_and(|s| s.ident == "BigInt"))
+        }),
+        _ => false,
+    }
+}
+
+// Present fields of the `SEQUENCE`. The field holding the unknown Extension Additions is not a
+// child.
+fn generate_sequence_children_tokens(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
+    let fields = match ast.data {
+        syn::Data::Struct(ref s) => &s.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ast,
+                "`SEQUENCE` should be a `struct`.",
+            ))
+        }
+    };
+
+    let mut child_tokens = vec![];
+    for field in fields {
+        let params = parse_fld_meta_as_codec_params(&field.attrs)?;
+        if params.unknown_extensions.is_some_and(|u| u.value()) {
+            continue;
+        }
+        let id = field.ident.as_ref().unwrap();
+        // `OPTIONAL` fields and Extension Additions are `Option`s, unless they have a default
+        // value.
+        let optional = params.default.is_none()
+            && (params.optional_idx.is_some() || params.extension_idx.is_some());
+        if optional {
+            child_tokens.push(quote! {
+                if let Some(ref value) = self.#id {
+                    children.push((Some(stringify!(#id)), value as &dyn asn1_codecs::reflect::Asn1Reflect));
+                }
+            });
+        } else {
+            child_tokens.push(quote! {
+                children.push((Some(stringify!(#id)), &self.#id as &dyn asn1_codecs::reflect::Asn1Reflect));
+            });
+        }
+    }
+
+    Ok(quote! {
+        let mut children = asn1_codecs::reflect::Asn1Children::new();
+        #(#child_tokens)*
+        children
+    })
+}
+
+// The 'key' field of the `SEQUENCE` if present.
+fn generate_sequence_key_tokens(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
+    let fields = match ast.data {
+        syn::Data::Struct(ref s) => &s.fields,
+        _ => return Ok(quote! {}),
+    };
+
+    for field in fields {
+        let params = parse_fld_meta_as_codec_params(&field.attrs)?;
+        if params.key_field.is_some_and(|k| k.value()) {
+            let id = field.ident.as_ref().unwrap();
+            let key = if params.optional_idx.is_some() && params.default.is_none() {
+                quote! { self.#id.as_ref().map(|key| key as &dyn asn1_codecs::reflect::Asn1Reflect) }
+            } else {
+                quote! { Some(&self.#id) }
+            };
+            return Ok(quote! {
+                fn key(&self) -> Option<&dyn asn1_codecs::reflect::Asn1Reflect> {
+                    #key
+                }
+            });
+        }
+    }
+
+    Ok(quote! {})
+}
+
+// The chosen variant of a `CHOICE` or an Open Type.
+fn generate_variant_children_tokens(ast: &syn::DeriveInput) -> Result<TokenStream, syn::Error> {
+    let variants = match ast.data {
+        syn::Data::Enum(ref e) => &e.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                ast,
+                "`CHOICE` and Open Types should be an `enum`.",
+            ))
+        }
+    };
+
+    if variants.is_empty() {
+        return Ok(quote! { match *self {} });
+    }
+
+    let variant_tokens = variants.iter().map(|variant| {
+        let id = &variant.ident;
+        quote! {
+            Self::#id(value) => asn1_codecs::__private::vec![
+                (Some(stringify!(#id)), value as &dyn asn1_codecs::reflect::Asn1Reflect)
+            ],
+        }
+    });
+
+    Ok(quote! {
+        match self {
+            #(#variant_tokens)*
+        }
+    })
+}
@@ -16,6 +16,21 @@ pub(crate) const EXTENDED: Symbol = Symbol("extended");
 pub(crate) const OPTIONAL_FIELDS: Symbol = Symbol("optional_fields");
 pub(crate) const OPTIONAL_IDX: Symbol = Symbol("optional_idx");
 pub(crate) const KEY_FIELD: Symbol = Symbol("key_field");
+pub(crate) const PEEK_KEY: Symbol = Symbol("peek_key");
+pub(crate) const UNKNOWN_EXTENSIONS: Symbol = Symbol("unknown_extensions");
+pub(crate) const TAG: Symbol = Symbol("tag");
+pub(crate) const DEFAULT: Symbol = Symbol("default");
+pub(crate) const EXTENSION_IDX: Symbol = Symbol("extension_idx");
+pub(crate) const VERSION: Symbol = Symbol("version");
+pub(crate) const PRESENCE: Symbol = Symbol("presence");
+pub(crate) const VALUE_LB: Symbol = Symbol("value_lb");
+pub(crate) const VALUE_UB: Symbol = Symbol("value_ub");
+pub(crate) const VALUE_BIG: Symbol = Symbol("value_big");
+pub(crate) const ENTRY_LB: Symbol = Symbol("entry_lb");
+pub(crate) const ENTRY_UB: Symbol = Symbol("entry_ub");
+pub(crate) const ENTRY_BIG: Symbol = Symbol("entry_big");
+pub(crate) const NAME: Symbol = Symbol("name");
+pub(crate) const NAMES: Symbol = Symbol("names");
 
 impl PartialEq<Symbol> for Ident {
     fn eq(&self, word: &Symbol) -> bool {
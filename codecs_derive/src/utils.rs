@@ -16,6 +16,96 @@ pub(super) fn get_sz_bounds_extensible_from_params(
     get_bounds_extensible_from_lb_ub_ext(&params.sz_lb, &params.sz_ub, &params.sz_ext)
 }
 
+// The type of the value of a Unit Struct (eg. `struct Foo(u8)`), and whether the struct has a
+// second field (eg. `struct Foo(u8, bool)`) that is set if the value is encoded as outside the
+// Extension Root.
+//
+// A value inside the Extension Root may be encoded as outside the Extension Root (though not by a
+// conforming encoder), the second field retains that, so that a decoded value is encoded as it
+// was received.
+pub(super) fn get_value_type_and_extended_field(
+    ast: &syn::DeriveInput,
+) -> Option<(syn::Type, bool)> {
+    match ast.data {
+        syn::Data::Struct(ref d) => match d.fields {
+            syn::Fields::Unnamed(ref f) if f.unnamed.len() <= 2 => f
+                .unnamed
+                .first()
+                .map(|first| (first.ty.clone(), f.unnamed.len() == 2)),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Tokens for the pattern binding the decoded value and the extension bit (`decoded` and
+// `extended`), for the value of `Self` from them and for whether the value is to be encoded as
+// outside the Extension Root (`extended` evaluates that from the value).
+pub(super) fn get_extended_field_tokens(
+    extended_field: bool,
+    extended: TokenStream,
+) -> (TokenStream, TokenStream, TokenStream) {
+    if extended_field {
+        (
+            quote! { (decoded, extended) },
+            quote! { Self(decoded, extended) },
+            quote! { self.1 || #extended },
+        )
+    } else {
+        (quote! { (decoded, _) }, quote! { Self(decoded) }, extended)
+    }
+}
+
+// Tokens evaluating whether the `value` (an `i128` expression) is outside the Extension Root.
+pub(super) fn get_extended_tokens(
+    lb: &Option<LitStr>,
+    ub: &Option<LitStr>,
+    ext: &Option<LitBool>,
+    value: TokenStream,
+) -> TokenStream {
+    if !ext.as_ref().is_some_and(|e| e.value()) {
+        return quote! { false };
+    }
+
+    let lb = lb.as_ref().map(|lb| lb.value().parse::<i128>().unwrap());
+    let ub = ub.as_ref().map(|ub| ub.value().parse::<i128>().unwrap());
+    match (lb, ub) {
+        (Some(lb), Some(ub)) => quote! { !(#lb..=#ub).contains(&#value) },
+        (Some(lb), None) => quote! { #value < #lb },
+        (None, Some(ub)) => quote! { #value > #ub },
+        (None, None) => quote! { false },
+    }
+}
+
+// Tokens evaluating whether the `value` (an `INTEGER` expression) is within the bounds. If `big`
+// is set, the `value` is an `asn1_codecs::BigInt` and is compared with the bounds as such.
+pub(super) fn get_within_bounds_tokens(
+    lb: &Option<LitStr>,
+    ub: &Option<LitStr>,
+    big: &Option<LitBool>,
+    value: TokenStream,
+) -> TokenStream {
+    let lb = lb.as_ref().map(|lb| lb.value().parse::<i128>().unwrap());
+    let ub = ub.as_ref().map(|ub| ub.value().parse::<i128>().unwrap());
+    if big.as_ref().is_some_and(|big| big.value) {
+        let lb = lb.map(|lb| quote! { asn1_codecs::BigInt::from(#lb) });
+        let ub = ub.map(|ub| quote! { asn1_codecs::BigInt::from(#ub) });
+        return match (lb, ub) {
+            (Some(lb), Some(ub)) => quote! { (#lb..=#ub).contains(&#value) },
+            (Some(lb), None) => quote! { #value >= #lb },
+            (None, Some(ub)) => quote! { #value <= #ub },
+            (None, None) => quote! { true },
+        };
+    }
+    let value = quote! { (#value as i128) };
+    match (lb, ub) {
+        (Some(lb), Some(ub)) => quote! { (#lb..=#ub).contains(&#value) },
+        (Some(lb), None) => quote! { #value >= #lb },
+        (None, Some(ub)) => quote! { #value <= #ub },
+        (None, None) => quote! { true },
+    }
+}
+
 fn get_bounds_extensible_from_lb_ub_ext(
     lb: &Option<LitStr>,
     ub: &Option<LitStr>,
@@ -0,0 +1,130 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::{AperCodec, AperCodecData, LazyOpenType};
+use asn1_codecs_derive::AperCodec;
+
+#[derive(Debug, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ProcedureCode(u8);
+
+#[derive(Debug, AperCodec)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(u8);
+
+#[derive(Debug, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
+pub struct MME_UE_S1AP_ID(u32);
+
+#[derive(Debug, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "16777215")]
+pub struct ENB_UE_S1AP_ID(u32);
+
+#[derive(Debug, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum InitiatingMessageValue {
+    #[asn(key = 0)]
+    MME_UE_S1AP_ID(MME_UE_S1AP_ID),
+    #[asn(key = 8)]
+    ENB_UE_S1AP_ID(ENB_UE_S1AP_ID),
+}
+
+#[derive(Debug, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct InitiatingMessage {
+    #[asn(key_field = true)]
+    pub procedure_code: ProcedureCode,
+    pub criticality: Criticality,
+    pub value: LazyOpenType<InitiatingMessageValue>,
+}
+
+#[derive(Debug, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct SuccessfulOutcome {
+    #[asn(optional_idx = 0)]
+    pub criticality: Option<Criticality>,
+    #[asn(key_field = true)]
+    pub procedure_code: ProcedureCode,
+    pub value: LazyOpenType<InitiatingMessageValue>,
+}
+
+#[derive(Debug, AperCodec)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "1",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
+pub enum PDU {
+    #[asn(key = 0, extended = false)]
+    InitiatingMessage(InitiatingMessage),
+    #[asn(key = 1, extended = false)]
+    SuccessfulOutcome(SuccessfulOutcome),
+    #[asn(key = 0, extended = true)]
+    UnsuccessfulOutcome(SuccessfulOutcome),
+}
+
+fn main() {
+    eprintln!("Lazy Open");
+
+    let value = InitiatingMessageValue::ENB_UE_S1AP_ID(ENB_UE_S1AP_ID(0x123456));
+    let pdu = PDU::InitiatingMessage(InitiatingMessage {
+        procedure_code: ProcedureCode(8),
+        criticality: Criticality(1),
+        value: LazyOpenType::new(8, &value).unwrap(),
+    });
+
+    let mut data = AperCodecData::new();
+    pdu.encode(&mut data).unwrap();
+    assert_eq!(pdu.encoded_len_bits().unwrap(), data.length_in_bits());
+    let encoded = data.into_bytes();
+
+    assert_eq!(PDU::peek_procedure_code(&encoded).unwrap().0, 8);
+
+    let mut data = AperCodecData::from_slice(&encoded);
+    let decoded = PDU::decode(&mut data).unwrap();
+    if let PDU::InitiatingMessage(ref msg) = decoded {
+        assert_eq!(msg.value.key(), Some(8));
+        assert_eq!(msg.value.raw_bytes(), &[0x80, 0x12, 0x34, 0x56]);
+        match msg.value.decode_value().unwrap() {
+            InitiatingMessageValue::ENB_UE_S1AP_ID(id) => assert_eq!(id.0, 0x123456),
+            _ => panic!("Unexpected Open Type value"),
+        }
+    } else {
+        panic!("Unexpected PDU variant");
+    }
+
+    let mut data = AperCodecData::new();
+    decoded.encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), encoded);
+
+    let pdu = PDU::SuccessfulOutcome(SuccessfulOutcome {
+        criticality: Some(Criticality(0)),
+        procedure_code: ProcedureCode(42),
+        value: LazyOpenType::new(
+            0,
+            &InitiatingMessageValue::MME_UE_S1AP_ID(MME_UE_S1AP_ID(1)),
+        )
+        .unwrap(),
+    });
+    let mut data = AperCodecData::new();
+    pdu.encode(&mut data).unwrap();
+    assert_eq!(PDU::peek_procedure_code(&data.into_bytes()).unwrap().0, 42);
+
+    // The key of an Extension Addition is peeked from it's Open Type.
+    let pdu = PDU::UnsuccessfulOutcome(SuccessfulOutcome {
+        criticality: None,
+        procedure_code: ProcedureCode(7),
+        value: LazyOpenType::new(
+            0,
+            &InitiatingMessageValue::MME_UE_S1AP_ID(MME_UE_S1AP_ID(1)),
+        )
+        .unwrap(),
+    });
+    let mut data = AperCodecData::new();
+    pdu.encode(&mut data).unwrap();
+    assert_eq!(PDU::peek_procedure_code(&data.into_bytes()).unwrap().0, 7);
+
+    // Unknown Extension Additions.
+    assert!(PDU::peek_procedure_code(&[0x81, 0x01, 0x00]).is_err());
+}
@@ -0,0 +1,162 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::{
+    Aper, AperCodec, AperCodecData, DecodeOptions, UnknownExtension, UnknownExtensions,
+};
+use asn1_codecs::{Decode, Encode};
+use asn1_codecs_derive::AperCodec;
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct Counter(u8);
+
+// Version 2 of the `ENUMERATED` has an Extension Addition.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct StateV2(u8);
+impl StateV2 {
+    const IDLE: u8 = 0u8;
+    const ACTIVE: u8 = 1u8;
+    const SUSPENDED: u8 = 2u8;
+}
+
+// Version 1 of the `ENUMERATED` only knows about the Extension Root.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct StateV1(u8);
+
+// Version 2 of the `CHOICE` has an Extension Addition.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum EventV2 {
+    #[asn(key = 0, extended = false)]
+    Started(Counter),
+    #[asn(key = 1, extended = false)]
+    Stopped(Counter),
+    #[asn(key = 0, extended = true)]
+    Paused(Counter),
+}
+
+// Version 1 of the `CHOICE` retains the unknown Extension Additions.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum EventV1 {
+    #[asn(key = 0, extended = false)]
+    Started(Counter),
+    #[asn(key = 1, extended = false)]
+    Stopped(Counter),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(UnknownExtension),
+}
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct Report {
+    pub state: StateV1,
+    #[asn(optional_idx = 0)]
+    pub counter: Option<Counter>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: UnknownExtensions,
+}
+
+// Same as `Report` but ignores the Extension Additions.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct ReportNoExtensions {
+    pub state: StateV1,
+    #[asn(optional_idx = 0)]
+    pub counter: Option<Counter>,
+}
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct Reports {
+    pub first: ReportNoExtensions,
+    pub second: Report,
+}
+
+fn main() {
+    eprintln!("Extensions");
+
+    // ENUMERATED
+    let encoded = Encode::<Aper>::to_bytes(&StateV2(StateV2::SUSPENDED)).unwrap();
+    let decoded = <StateV1 as Decode<Aper>>::from_bytes(&encoded).unwrap().0;
+    assert_eq!(decoded, StateV1(2));
+    assert_eq!(Encode::<Aper>::to_bytes(&decoded).unwrap(), encoded);
+    assert_eq!(
+        <StateV2 as Decode<Aper>>::from_bytes(&encoded).unwrap().0,
+        StateV2(StateV2::SUSPENDED)
+    );
+
+    // CHOICE: The unknown Extension Additions are retained only with the lenient Decode Options.
+    let encoded = Encode::<Aper>::to_bytes(&EventV2::Paused(Counter(42))).unwrap();
+    assert!(<EventV1 as Decode<Aper>>::from_bytes(&encoded).is_err());
+    let mut data = AperCodecData::from_slice(&encoded);
+    data.set_decode_options(DecodeOptions::lenient());
+    let decoded = EventV1::decode(&mut data).unwrap();
+    assert_eq!(
+        decoded,
+        EventV1::UnknownExtension(UnknownExtension {
+            index: 0,
+            bytes: vec![42]
+        })
+    );
+    assert_eq!(Encode::<Aper>::to_bytes(&decoded).unwrap(), encoded);
+    assert_eq!(
+        <EventV2 as Decode<Aper>>::from_bytes(&encoded).unwrap().0,
+        EventV2::Paused(Counter(42))
+    );
+
+    let encoded = Encode::<Aper>::to_bytes(&EventV2::Stopped(Counter(7))).unwrap();
+    assert_eq!(
+        <EventV1 as Decode<Aper>>::from_bytes(&encoded).unwrap().0,
+        EventV1::Stopped(Counter(7))
+    );
+
+    // SEQUENCE
+    let report = Report {
+        state: StateV1(StateV2::ACTIVE),
+        counter: Some(Counter(3)),
+        unknown_extensions: UnknownExtensions::new(vec![UnknownExtension {
+            index: 1,
+            bytes: vec![0xAB, 0xCD],
+        }]),
+    };
+    let encoded = Encode::<Aper>::to_bytes(&report).unwrap();
+    let decoded = <Report as Decode<Aper>>::from_bytes(&encoded).unwrap().0;
+    assert_eq!(decoded, report);
+    assert_eq!(Encode::<Aper>::to_bytes(&decoded).unwrap(), encoded);
+
+    let reports = Reports {
+        first: ReportNoExtensions {
+            state: StateV1(StateV2::IDLE),
+            counter: None,
+        },
+        second: report,
+    };
+    let encoded = Encode::<Aper>::to_bytes(&reports).unwrap();
+    let decoded = <Reports as Decode<Aper>>::from_bytes(&encoded).unwrap().0;
+    assert_eq!(decoded, reports);
+
+    // The length of the retained Extension Additions is computed without encoding them.
+    let mut data = AperCodecData::new();
+    reports.encode(&mut data).unwrap();
+    assert_eq!(reports.encoded_len_bits().unwrap(), data.length_in_bits());
+
+    // Extension Additions are skipped if not retained.
+    let report = Report {
+        state: StateV1(StateV2::IDLE),
+        counter: None,
+        unknown_extensions: UnknownExtensions::new(vec![UnknownExtension {
+            index: 0,
+            bytes: vec![0x01],
+        }]),
+    };
+    let mut encoded = Encode::<Aper>::to_bytes(&report).unwrap();
+    encoded.extend(Encode::<Aper>::to_bytes(&report).unwrap());
+    let mut data = AperCodecData::from_slice(&encoded);
+    let first = ReportNoExtensions::decode(&mut data).unwrap();
+    assert_eq!(first.state, StateV1(StateV2::IDLE));
+    let second = Report::decode(&mut data).unwrap();
+    assert_eq!(second, report);
+}
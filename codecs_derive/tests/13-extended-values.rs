@@ -0,0 +1,133 @@
+#![allow(dead_code)]
+
+use asn1_codecs::aper::{AperCodec, AperCodecData};
+use asn1_codecs_derive::AperCodec;
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
+pub struct Counter(i64);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
+pub struct NarrowCounter(u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct State(u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct WideState(u16);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "OCTET-STRING", sz_extensible = true, sz_lb = "2", sz_ub = "2")]
+pub struct Identifier(Vec<u8>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "4", sz_ub = "4")]
+pub struct Flags(bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(
+    type = "PrintableString",
+    sz_extensible = true,
+    sz_lb = "1",
+    sz_ub = "4"
+)]
+pub struct Name(String);
+
+// The second field retains the extension bit as decoded.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
+pub struct Retained(i64, bool);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "OCTET-STRING", sz_extensible = true, sz_lb = "2", sz_ub = "2")]
+pub struct RetainedIdentifier(Vec<u8>, bool);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "VisibleString", sz_extensible = true, sz_lb = "1", sz_ub = "4")]
+pub struct RetainedName(String, bool);
+
+// The `SIZE` constraint of a UTF8String is not PER-visible.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "UTF8String", sz_extensible = true, sz_lb = "1", sz_ub = "4")]
+pub struct Utf8Name(String);
+
+fn round_trip<T: AperCodec + PartialEq + std::fmt::Debug>(value: T) -> Vec<u8> {
+    let mut data = AperCodecData::new();
+    value.encode(&mut data).unwrap();
+    assert_eq!(value.encoded_len_bits().unwrap(), data.length_in_bits());
+    let encoded = data.into_bytes();
+
+    let mut data = AperCodecData::from_slice(&encoded);
+    let decoded = T::decode(&mut data).unwrap();
+    assert_eq!(decoded, value);
+
+    let mut data = AperCodecData::new();
+    decoded.encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), encoded);
+
+    encoded
+}
+
+fn main() {
+    eprintln!("Extended Values");
+
+    // Values inside the Extension Root are encoded with the extension bit cleared.
+    assert_eq!(round_trip(Counter(3)), [0x00, 0x03]);
+    // Values outside the Extension Root are encoded as unconstrained whole numbers.
+    assert_eq!(round_trip(Counter(1000)), [0x80, 0x02, 0x03, 0xe8]);
+    assert_eq!(round_trip(Counter(-1)), [0x80, 0x01, 0xff]);
+
+    round_trip(State(1));
+    round_trip(State(5));
+
+    round_trip(Identifier(vec![1, 2]));
+    round_trip(Identifier(vec![1, 2, 3, 4, 5]));
+
+    round_trip(Flags(bitvec::bitvec![u8, bitvec::order::Msb0; 1, 0, 1, 0]));
+    round_trip(Flags(
+        bitvec::bitvec![u8, bitvec::order::Msb0; 1, 0, 1, 0, 1, 1],
+    ));
+
+    round_trip(Name("abc".to_string()));
+    round_trip(Name("hampi".to_string()));
+
+    // A value inside the Extension Root encoded as outside the Extension Root is encoded back as
+    // it was decoded.
+    let encoded = round_trip(Retained(3, true));
+    assert_eq!(encoded, [0x80, 0x01, 0x03]);
+    let mut data = AperCodecData::from_slice(&encoded);
+    assert_eq!(Counter::decode(&mut data).unwrap(), Counter(3));
+    assert_eq!(round_trip(Retained(3, false)), [0x00, 0x03]);
+    assert_eq!(round_trip(Retained(1000, true)), [0x80, 0x02, 0x03, 0xe8]);
+    // A value outside the Extension Root is always encoded as such.
+    let mut data = AperCodecData::new();
+    Retained(1000, false).encode(&mut data).unwrap();
+    assert_eq!(data.into_bytes(), [0x80, 0x02, 0x03, 0xe8]);
+
+    round_trip(RetainedIdentifier(vec![1, 2], true));
+    round_trip(RetainedIdentifier(vec![1, 2, 3], true));
+
+    round_trip(RetainedName("abc".to_string(), true));
+    assert_eq!(
+        round_trip(RetainedName("ab".to_string(), false))[0] & 0x80,
+        0
+    );
+
+    // The length of a UTF8String is the number of octets.
+    assert_eq!(
+        round_trip(Utf8Name("äöüß".to_string())),
+        [0x08, 0xc3, 0xa4, 0xc3, 0xb6, 0xc3, 0xbc, 0xc3, 0x9f]
+    );
+
+    // Values that cannot be represented in the type are not silently truncated.
+    let mut data = AperCodecData::from_slice(&[0x80, 0x02, 0x03, 0xe8]);
+    assert!(NarrowCounter::decode(&mut data).is_err());
+
+    let mut data = AperCodecData::new();
+    WideState(300).encode(&mut data).unwrap();
+    let mut data = AperCodecData::from_slice(&data.into_bytes());
+    assert!(State::decode(&mut data).is_err());
+}
@@ -0,0 +1,64 @@
+#![allow(dead_code)]
+
+use asn1_codecs::aper::{AperCodec, AperCodecData, DecodeOptions, UnknownExtension};
+use asn1_codecs_derive::AperCodec;
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "10")]
+pub struct Level(u8);
+
+// Version 2 of the `CHOICE` has an Extension Addition.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
+pub enum EventV2 {
+    #[asn(key = 0, extended = false)]
+    Started(Level),
+    #[asn(key = 0, extended = true)]
+    Paused(Level),
+}
+
+// Version 1 of the `CHOICE` only knows about the Extension Root.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
+pub enum EventV1 {
+    #[asn(key = 0, extended = false)]
+    Started(Level),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(UnknownExtension),
+}
+
+fn decode_with<T: AperCodec>(
+    bytes: &[u8],
+    options: DecodeOptions,
+) -> (Result<T, asn1_codecs::aper::AperCodecError>, usize) {
+    let mut data = AperCodecData::from_slice(bytes);
+    data.set_decode_options(options);
+    let result = T::decode(&mut data);
+    (result, data.warnings().len())
+}
+
+fn main() {
+    eprintln!("Decode Options");
+
+    let mut data = AperCodecData::new();
+    EventV2::Paused(Level(5)).encode(&mut data).unwrap();
+    let encoded = data.into_bytes();
+
+    // Unknown Choice Extension Indices.
+    let (result, warnings) = decode_with::<EventV1>(&encoded, DecodeOptions::lenient());
+    assert!(matches!(result, Ok(EventV1::UnknownExtension(_))));
+    assert_eq!(warnings, 1);
+    let (result, _) = decode_with::<EventV1>(&encoded, DecodeOptions::default());
+    assert!(result.is_err());
+    let (result, warnings) = decode_with::<EventV2>(&encoded, DecodeOptions::strict());
+    assert_eq!(result.unwrap(), EventV2::Paused(Level(5)));
+    assert_eq!(warnings, 0);
+
+    // Out of range value (15) inside an Extension Addition.
+    let encoded = [0x80, 0x01, 0xF0];
+    let (result, _) = decode_with::<EventV2>(&encoded, DecodeOptions::strict());
+    assert!(result.is_err());
+    let (result, warnings) = decode_with::<EventV2>(&encoded, DecodeOptions::lenient());
+    assert_eq!(result.unwrap(), EventV2::Paused(Level(15)));
+    assert_eq!(warnings, 1);
+}
@@ -0,0 +1,49 @@
+use asn1_codecs::aper::{AperCodec, AperCodecData};
+use asn1_codecs::BigInt;
+use asn1_codecs_derive::AperCodec;
+
+// eg. `CertificateSerialNumber ::= INTEGER`
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER")]
+pub struct SerialNumber(BigInt);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0")]
+pub struct Volume(asn1_codecs::BigInt);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
+pub struct Counter(BigInt);
+
+fn round_trip<T: AperCodec + PartialEq + std::fmt::Debug>(value: T) -> Vec<u8> {
+    let mut data = AperCodecData::new();
+    value.encode(&mut data).unwrap();
+    assert_eq!(value.encoded_len_bits().unwrap(), data.length_in_bits());
+    let encoded = data.into_bytes();
+
+    let mut data = AperCodecData::from_slice(&encoded);
+    let decoded = T::decode(&mut data).unwrap();
+    assert_eq!(decoded, value);
+
+    encoded
+}
+
+fn main() {
+    eprintln!("Big Integers");
+
+    // A 20 octets serial number.
+    let serial = BigInt::from_signed_bytes_be(&[0x7f; 20]);
+    let encoded = round_trip(SerialNumber(serial));
+    assert_eq!(encoded.len(), 21);
+    assert_eq!(encoded[0], 20);
+
+    round_trip(SerialNumber(BigInt::from(-1)));
+    round_trip(Volume(BigInt::from(u128::MAX) * BigInt::from(u128::MAX)));
+
+    assert_eq!(round_trip(Counter(BigInt::from(3))), [0x00, 0x03]);
+    assert_eq!(
+        round_trip(Counter(BigInt::from(1000))),
+        [0x80, 0x02, 0x03, 0xe8]
+    );
+    round_trip(Counter(BigInt::from(i128::MIN) * BigInt::from(2)));
+}
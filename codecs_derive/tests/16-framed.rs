@@ -0,0 +1,132 @@
+#![allow(dead_code)]
+
+use asn1_codecs::aper::{AperFramed, DecodeOptions};
+use asn1_codecs_derive::AperCodec;
+use bytes::BytesMut;
+use futures::{SinkExt, StreamExt};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio_util::codec::{Encoder, Framed, FramedRead, FramedWrite};
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProcedureCode(u16);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct Payload(Vec<u8>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct Message {
+    pub procedure_code: ProcedureCode,
+    pub payload: Payload,
+}
+
+fn message(code: u16, len: usize) -> Message {
+    Message {
+        procedure_code: ProcedureCode(code),
+        payload: Payload(vec![code as u8; len]),
+    }
+}
+
+const MESSAGES: [(u16, usize); 4] = [(1, 3), (2, 200), (3, 0), (4, 1000)];
+
+// Writes the `MESSAGES` through `FramedWrite` and reads them back through `FramedRead`.
+async fn send_and_receive(framed: fn() -> AperFramed<Message>) {
+    let (client, server) = tokio::io::duplex(64);
+    let mut sink = FramedWrite::new(client, framed());
+    let mut stream = FramedRead::new(server, framed());
+
+    let writer = async {
+        for (code, len) in MESSAGES {
+            sink.send(message(code, len)).await.unwrap();
+        }
+        sink.close().await.unwrap();
+    };
+    let reader = async {
+        let mut received = vec![];
+        while let Some(m) = stream.next().await {
+            received.push(m.unwrap());
+        }
+        received
+    };
+    let ((), received) = tokio::join!(writer, reader);
+
+    let expected = MESSAGES
+        .iter()
+        .map(|&(code, len)| message(code, len))
+        .collect::<Vec<_>>();
+    assert_eq!(received, expected);
+}
+
+// Writes each of the PDUs in two parts, the reader receives the first part before the second.
+async fn write_split<W: AsyncWrite + Unpin>(
+    io: &mut W,
+    framed: &mut AperFramed<Message>,
+    messages: &[(u16, usize)],
+) {
+    for &(code, len) in messages {
+        let mut buf = BytesMut::new();
+        framed.encode(message(code, len), &mut buf).unwrap();
+        let (first, second) = buf.split_at(buf.len() / 2);
+
+        io.write_all(first).await.unwrap();
+        io.flush().await.unwrap();
+        tokio::task::yield_now().await;
+        io.write_all(second).await.unwrap();
+        io.flush().await.unwrap();
+    }
+}
+
+// PDUs split across two writes are decoded once the second part is received.
+async fn split_writes(framed: fn() -> AperFramed<Message>) {
+    let (mut client, server) = tokio::io::duplex(1024);
+    let mut stream = Framed::new(server, framed());
+
+    let writer = async {
+        write_split(&mut client, &mut framed(), &[(5, 20), (6, 1)]).await;
+        client.shutdown().await.unwrap();
+    };
+    let reader = async {
+        let mut received = vec![];
+        while let Some(m) = stream.next().await {
+            received.push(m.unwrap());
+        }
+        received
+    };
+    let ((), received) = tokio::join!(writer, reader);
+
+    assert_eq!(received, vec![message(5, 20), message(6, 1)]);
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    eprintln!("Framed");
+
+    // Stream transport: PDUs are length delimited.
+    send_and_receive(AperFramed::length_delimited).await;
+    split_writes(AperFramed::length_delimited).await;
+
+    // Message transport: the PDUs are not delimited.
+    send_and_receive(AperFramed::new).await;
+    split_writes(AperFramed::new).await;
+
+    // Bytes remaining after the PDU in a frame.
+    let (client, server) = tokio::io::duplex(1024);
+    let mut sink = FramedWrite::new(client, AperFramed::<Payload>::length_delimited());
+    let mut framed = AperFramed::<Message>::length_delimited();
+    framed.set_decode_options(DecodeOptions::strict());
+    let mut stream = FramedRead::new(server, framed);
+    sink.send(Payload(vec![0, 1, 0xAA, 0xBB])).await.unwrap();
+    assert!(stream.next().await.unwrap().is_err());
+
+    // A partial PDU is not buffered beyond the maximum frame length.
+    let (mut client, server) = tokio::io::duplex(1024);
+    let mut framed = AperFramed::<Message>::new();
+    framed.set_max_frame_length(16);
+    let mut stream = FramedRead::new(server, framed);
+    let mut buf = BytesMut::new();
+    AperFramed::new().encode(message(7, 100), &mut buf).unwrap();
+    client.write_all(&buf[..50]).await.unwrap();
+    assert!(stream.next().await.unwrap().is_err());
+}
@@ -0,0 +1,124 @@
+#![no_std]
+#![allow(non_camel_case_types, dead_code)]
+
+// The derived code only requires `core` and `alloc`.
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use asn1_codecs::aper::{AperCodec, AperCodecData, UnknownExtension, UnknownExtensions};
+use asn1_codecs_derive::AperCodec;
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
+pub struct Criticality(u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(bool);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "NULL")]
+pub struct Empty;
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "OCTET-STRING", sz_lb = "1", sz_ub = "8")]
+pub struct Payload(Vec<u8>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "BITSTRING", sz_lb = "4", sz_ub = "4")]
+pub struct Flags(bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "PrintableString", sz_lb = "1", sz_ub = "16")]
+pub struct Name(String);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum Value {
+    #[asn(key = 1)]
+    Payload(Payload),
+    #[asn(key = 2)]
+    Name(Name),
+}
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct Item {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub criticality: Criticality,
+    pub value: Value,
+    #[asn(optional_idx = 0)]
+    pub flag: Option<Flag>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: UnknownExtensions,
+}
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
+pub struct Items(Vec<Item>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Message {
+    #[asn(key = 0, extended = false)]
+    Items(Items),
+    #[asn(key = 1, extended = false)]
+    Flags(Flags),
+    #[asn(key = 0, extended = true)]
+    Empty(Empty),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(UnknownExtension),
+}
+
+// An extensible `CHOICE` without a variant for the unknown Extension Additions.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
+pub enum Reply {
+    #[asn(key = 0, extended = false)]
+    Flag(Flag),
+    #[asn(key = 0, extended = true)]
+    Name(Name),
+}
+
+fn main() {
+    let message = Message::Items(Items(alloc::vec![
+        Item {
+            id: ProtocolIE_ID(1),
+            criticality: Criticality(0),
+            value: Value::Payload(Payload(alloc::vec![1, 2, 3])),
+            flag: Some(Flag(true)),
+            unknown_extensions: UnknownExtensions::default(),
+        },
+        Item {
+            id: ProtocolIE_ID(2),
+            criticality: Criticality(2),
+            value: Value::Name(Name(String::from("hampi"))),
+            flag: None,
+            unknown_extensions: UnknownExtensions::default(),
+        },
+    ]));
+
+    for message in [message, Message::Empty(Empty)] {
+        let mut data = AperCodecData::new();
+        message.encode(&mut data).unwrap();
+        let mut data = AperCodecData::from_slice(&data.into_bytes());
+        assert_eq!(Message::decode(&mut data).unwrap(), message);
+    }
+
+    for reply in [
+        Reply::Flag(Flag(false)),
+        Reply::Name(Name(String::from("gNB"))),
+    ] {
+        let mut data = AperCodecData::new();
+        reply.encode(&mut data).unwrap();
+        let mut data = AperCodecData::from_slice(&data.into_bytes());
+        assert_eq!(Reply::decode(&mut data).unwrap(), reply);
+    }
+}
@@ -0,0 +1,133 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::UnknownExtensions;
+use asn1_codecs::reflect::{Asn1Constraints, Asn1Kind, Asn1Reflect, Asn1Value};
+use asn1_codecs_derive::{AperCodec, Asn1Reflect};
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct UE_ID(u16);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "PrintableString",
+    sz_extensible = true,
+    sz_lb = "1",
+    sz_ub = "150"
+)]
+pub struct NodeName(String);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "OPEN")]
+pub enum IEValue {
+    #[asn(key = 10)]
+    Id_UE_ID(UE_ID),
+    #[asn(key = 82)]
+    Id_NodeName(NodeName),
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct IE {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub value: IEValue,
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct ProtocolIEs(Vec<IE>);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+pub struct Message {
+    pub protocol_i_es: ProtocolIEs,
+    #[asn(optional_idx = 0)]
+    pub node_name: Option<NodeName>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: UnknownExtensions,
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
+pub enum PDU {
+    #[asn(key = 0, extended = false)]
+    InitiatingMessage(Message),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+fn main() {
+    eprintln!("Reflect");
+
+    let pdu = PDU::InitiatingMessage(Message {
+        protocol_i_es: ProtocolIEs(vec![
+            IE {
+                id: ProtocolIE_ID(10),
+                value: IEValue::Id_UE_ID(UE_ID(42)),
+            },
+            IE {
+                id: ProtocolIE_ID(82),
+                value: IEValue::Id_NodeName(NodeName("gNB".to_string())),
+            },
+            IE {
+                id: ProtocolIE_ID(10),
+                value: IEValue::Id_UE_ID(UE_ID(7)),
+            },
+        ]),
+        node_name: None,
+        unknown_extensions: UnknownExtensions::default(),
+    });
+
+    assert_eq!(pdu.type_name(), "PDU");
+    assert_eq!(pdu.kind(), Asn1Kind::Choice);
+    assert_eq!(pdu.value(), Asn1Value::Constructed);
+
+    let message = &pdu.children()[0];
+    assert_eq!(message.0, Some("InitiatingMessage"));
+    // Absent `OPTIONAL` fields and the unknown Extension Additions are not children.
+    let fields = message.1.children();
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].0, Some("protocol_i_es"));
+
+    // Variants are selected by their names or by the names of their Types.
+    let ue_ids = pdu.get("initiatingMessage/protocolIEs/*/value/UE-ID");
+    let ue_ids = ue_ids.iter().map(|v| v.value()).collect::<Vec<_>>();
+    assert_eq!(ue_ids, vec![Asn1Value::Integer(42), Asn1Value::Integer(7)]);
+
+    let ue_ids = pdu.get("initiatingMessage/protocolIEs/*/value/Id_UE_ID");
+    assert_eq!(ue_ids.len(), 2);
+
+    let names = pdu.get("InitiatingMessage/protocol_i_es/1/value/*");
+    assert_eq!(names.len(), 1);
+    assert_eq!(names[0].type_name(), "NodeName");
+    assert_eq!(names[0].kind(), Asn1Kind::CharacterString);
+    assert_eq!(names[0].value(), Asn1Value::CharacterString("gNB"));
+    assert_eq!(
+        names[0].constraints(),
+        Asn1Constraints {
+            sz_lb: Some(1),
+            sz_ub: Some(150),
+            sz_extensible: true,
+            ..Default::default()
+        }
+    );
+
+    let ies = pdu.get("initiatingMessage/protocolIEs");
+    assert_eq!(ies[0].kind(), Asn1Kind::SequenceOf);
+    assert_eq!(ies[0].children().len(), 3);
+    assert_eq!(ies[0].children()[0].0, None);
+
+    assert!(pdu.get("initiatingMessage/nodeName").is_empty());
+    assert!(pdu.get("successfulOutcome").is_empty());
+    assert_eq!(pdu.get("").len(), 1);
+}
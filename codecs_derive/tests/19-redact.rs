@@ -0,0 +1,81 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::reflect::{Asn1Reflect, Redacted};
+use asn1_codecs_derive::{AperCodec, Asn1Reflect};
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
+pub struct AMF_UE_NGAP_ID(u32);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "OCTET-STRING")]
+pub struct NAS_PDU(Vec<u8>);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "48", sz_ub = "48")]
+pub struct UE_IdentityIndexValue(bitvec::vec::BitVec<u8, bitvec::order::Msb0>);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "CHOICE", lb = "0", ub = "0", extensible = false)]
+pub enum UE_Identity {
+    #[asn(key = 0, extended = false)]
+    IndexLength10(UE_IdentityIndexValue),
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
+pub struct NAS_PDUs(Vec<NAS_PDU>);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct DownlinkNASTransport {
+    pub amf_ue_ngap_id: AMF_UE_NGAP_ID,
+    pub nas_pdus: NAS_PDUs,
+    pub ue_identity: UE_Identity,
+    #[asn(optional_idx = 0)]
+    pub old_amf_ue_ngap_id: Option<AMF_UE_NGAP_ID>,
+}
+
+fn main() {
+    eprintln!("Redact");
+
+    let message = DownlinkNASTransport {
+        amf_ue_ngap_id: AMF_UE_NGAP_ID(1),
+        nas_pdus: NAS_PDUs(vec![NAS_PDU(vec![0x7e, 0x00]), NAS_PDU(vec![0x7e, 0x01])]),
+        ue_identity: UE_Identity::IndexLength10(UE_IdentityIndexValue(bitvec::bitvec![
+            u8,
+            bitvec::order::Msb0;
+            1;
+            48
+        ])),
+        old_amf_ue_ngap_id: None,
+    };
+
+    let formatted = format!("{:?}", message.redacted(&["NAS-PDU", "UE_Identity*"]));
+    assert_eq!(
+        formatted,
+        "DownlinkNASTransport { amf_ue_ngap_id: AMF_UE_NGAP_ID(1), \
+         nas_pdus: NAS_PDUs([NAS_PDU(<redacted>), NAS_PDU(<redacted>)]), \
+         ue_identity: UE_Identity(<redacted>) }"
+    );
+
+    // Without any redacted Types, the formatting is same as the derived `Debug`, except for the
+    // absent `OPTIONAL` fields and the BIT STRING values.
+    assert_eq!(
+        format!("{:#?}", Redacted::new(&message.nas_pdus, &[])),
+        format!("{:#?}", message.nas_pdus)
+    );
+    assert_eq!(
+        format!("{:?}", Redacted::new(&message.ue_identity, &[])),
+        format!(
+            "IndexLength10(UE_IdentityIndexValue([{}]))",
+            vec!["1"; 48].join(", ")
+        )
+    );
+    let message = DownlinkNASTransport {
+        old_amf_ue_ngap_id: Some(AMF_UE_NGAP_ID(2)),
+        ..message
+    };
+    assert!(format!("{:?}", Redacted::new(&message, &[]))
+        .ends_with("old_amf_ue_ngap_id: AMF_UE_NGAP_ID(2) }"));
+}
@@ -0,0 +1,146 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::reflect::{diff, DiffKind, Difference};
+use asn1_codecs_derive::{AperCodec, Asn1Reflect};
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(u16);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct UE_ID(u16);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "PrintableString",
+    sz_extensible = true,
+    sz_lb = "1",
+    sz_ub = "150"
+)]
+pub struct NodeName(String);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "OPEN")]
+pub enum IEValue {
+    #[asn(key = 10)]
+    Id_UE_ID(UE_ID),
+    #[asn(key = 82)]
+    Id_NodeName(NodeName),
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct IE {
+    #[asn(key_field = true)]
+    pub id: ProtocolIE_ID,
+    pub value: IEValue,
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct ProtocolIEs(Vec<IE>);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "0", sz_ub = "16")]
+pub struct UE_IDs(Vec<UE_ID>);
+
+#[derive(Debug, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct Message {
+    pub protocol_i_es: ProtocolIEs,
+    pub ue_ids: UE_IDs,
+    #[asn(optional_idx = 0)]
+    pub node_name: Option<NodeName>,
+}
+
+fn ue_id(id: u16) -> IE {
+    IE {
+        id: ProtocolIE_ID(10),
+        value: IEValue::Id_UE_ID(UE_ID(id)),
+    }
+}
+
+fn node_name(name: &str) -> IE {
+    IE {
+        id: ProtocolIE_ID(82),
+        value: IEValue::Id_NodeName(NodeName(name.to_string())),
+    }
+}
+
+fn main() {
+    eprintln!("Diff");
+
+    let old = Message {
+        protocol_i_es: ProtocolIEs(vec![ue_id(1), node_name("gNB")]),
+        ue_ids: UE_IDs(vec![UE_ID(1), UE_ID(2)]),
+        node_name: None,
+    };
+
+    // Reordered Protocol IEs are the same.
+    let new = Message {
+        protocol_i_es: ProtocolIEs(vec![node_name("gNB"), ue_id(1)]),
+        ue_ids: UE_IDs(vec![UE_ID(1), UE_ID(2)]),
+        node_name: None,
+    };
+    assert!(diff(&old, &new).is_empty());
+
+    let new = Message {
+        protocol_i_es: ProtocolIEs(vec![node_name("gNB-2"), ue_id(1), ue_id(3)]),
+        ue_ids: UE_IDs(vec![UE_ID(1)]),
+        node_name: Some(NodeName("gNB".to_string())),
+    };
+    let d = diff(&old, &new);
+    assert_eq!(
+        d.differences,
+        vec![
+            Difference {
+                path: "protocol_i_es/0/value/Id_NodeName".to_string(),
+                kind: DiffKind::Changed,
+                old: Some("\"gNB\"".to_string()),
+                new: Some("\"gNB-2\"".to_string()),
+            },
+            Difference {
+                path: "protocol_i_es/2".to_string(),
+                kind: DiffKind::Added,
+                old: None,
+                new: Some("IE".to_string()),
+            },
+            Difference {
+                path: "ue_ids/1".to_string(),
+                kind: DiffKind::Removed,
+                old: Some("2".to_string()),
+                new: None,
+            },
+            Difference {
+                path: "node_name".to_string(),
+                kind: DiffKind::Added,
+                old: None,
+                new: Some("\"gNB\"".to_string()),
+            },
+        ]
+    );
+    assert_eq!(
+        d.to_string(),
+        "~ protocol_i_es/0/value/Id_NodeName: \"gNB\" -> \"gNB-2\"\n\
+         + protocol_i_es/2: IE\n\
+         - ue_ids/1: 2\n\
+         + node_name: \"gNB\"\n"
+    );
+    assert_eq!(
+        d.to_json(),
+        r#"[{"path":"protocol_i_es/0/value/Id_NodeName","kind":"changed","old":"\"gNB\"","new":"\"gNB-2\""},{"path":"protocol_i_es/2","kind":"added","old":null,"new":"IE"},{"path":"ue_ids/1","kind":"removed","old":"2","new":null},{"path":"node_name","kind":"added","old":null,"new":"\"gNB\""}]"#
+    );
+
+    // Changed variant of an Open Type.
+    let d = diff(&ue_id(1), &node_name("gNB"));
+    assert_eq!(
+        d.to_string(),
+        "~ id: 10 -> 82\n~ value: Id_UE_ID -> Id_NodeName\n"
+    );
+}
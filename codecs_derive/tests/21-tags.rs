@@ -0,0 +1,57 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::Aper;
+use asn1_codecs::{Decode, Encode};
+use asn1_codecs_derive::AperCodec;
+
+// Tags are not used by the APER Codec, the encoding of the Tagged Types is the same as that of the
+// untagged Types.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "5", tag = "[PRIVATE 3] EXPLICIT")]
+pub struct Tagged(u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "5")]
+pub struct Untagged(u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
+pub enum Ch {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    X(Untagged),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    Y(Tagged),
+}
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(
+    type = "SEQUENCE",
+    extensible = false,
+    optional_fields = 1,
+    tag = "[APPLICATION 1] IMPLICIT"
+)]
+pub struct Rec {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub a: Untagged,
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
+    pub b: Option<Ch>,
+    #[asn(tag = "[UNIVERSAL 2] IMPLICIT")]
+    pub c: Tagged,
+}
+
+fn main() {
+    eprintln!("Tags");
+
+    assert_eq!(
+        Encode::<Aper>::to_bytes(&Tagged(4)).unwrap(),
+        Encode::<Aper>::to_bytes(&Untagged(4)).unwrap()
+    );
+
+    let rec = Rec {
+        a: Untagged(1),
+        b: Some(Ch::Y(Tagged(2))),
+        c: Tagged(3),
+    };
+    let encoded = Encode::<Aper>::to_bytes(&rec).unwrap();
+    assert_eq!(<Rec as Decode<Aper>>::from_bytes(&encoded).unwrap().0, rec);
+}
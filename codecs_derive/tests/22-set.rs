@@ -0,0 +1,107 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::{Aper, CanonicalAper};
+use asn1_codecs::reflect::{Asn1Kind, Asn1Reflect};
+use asn1_codecs::{Decode, Encode};
+use asn1_codecs_derive::{AperCodec, Asn1Reflect};
+
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(bool);
+
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "10")]
+pub struct Num(u8);
+
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct Name(Vec<u8>);
+
+// The components of a `SET` are in the canonical order of their Tags.
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "SET", extensible = false, optional_fields = 1)]
+pub struct Rec {
+    #[asn(optional_idx = 0)]
+    pub flag: Option<Flag>,
+    pub num: Num,
+    #[asn(tag = "[APPLICATION 2] IMPLICIT")]
+    pub name: Name,
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct SeqRec {
+    #[asn(optional_idx = 0)]
+    pub flag: Option<Flag>,
+    pub num: Num,
+    pub name: Name,
+}
+
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "SET-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
+pub struct Recs(Vec<Rec>);
+
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "SET-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
+pub struct Nums(Vec<Num>);
+
+fn main() {
+    eprintln!("Set");
+
+    let rec = Rec {
+        flag: Some(Flag(true)),
+        num: Num(7),
+        name: Name(b"hampi".to_vec()),
+    };
+
+    // A `SET` is encoded as a `SEQUENCE` with the same components.
+    let seq_rec = SeqRec {
+        flag: Some(Flag(true)),
+        num: Num(7),
+        name: Name(b"hampi".to_vec()),
+    };
+    assert_eq!(
+        Encode::<Aper>::to_bytes(&rec).unwrap(),
+        Encode::<Aper>::to_bytes(&seq_rec).unwrap()
+    );
+
+    let recs = Recs(vec![
+        rec,
+        Rec {
+            flag: None,
+            num: Num(1),
+            name: Name(vec![]),
+        },
+    ]);
+    let encoded = Encode::<Aper>::to_bytes(&recs).unwrap();
+    assert_eq!(
+        <Recs as Decode<Aper>>::from_bytes(&encoded).unwrap().0,
+        recs
+    );
+
+    assert_eq!(recs.kind(), Asn1Kind::SetOf);
+    assert_eq!(recs.0[0].kind(), Asn1Kind::Set);
+    let nums = recs.get("*/num");
+    assert_eq!(nums.len(), 2);
+    assert_eq!(nums[1].value().to_string(), "1");
+
+    // In the Canonical mode, the components of a `SET OF` are encoded in the ascending order of
+    // their encodings and an encoding with the components in any other order is rejected.
+    let unsorted = Nums(vec![Num(5), Num(1), Num(3)]);
+    let sorted = Nums(vec![Num(1), Num(3), Num(5)]);
+    let encoded = Encode::<CanonicalAper>::to_bytes(&unsorted).unwrap();
+    assert_eq!(encoded, Encode::<Aper>::to_bytes(&sorted).unwrap());
+    assert_eq!(
+        <Nums as Decode<CanonicalAper>>::from_bytes(&encoded)
+            .unwrap()
+            .0,
+        sorted
+    );
+
+    let encoded = Encode::<Aper>::to_bytes(&unsorted).unwrap();
+    assert_eq!(
+        <Nums as Decode<Aper>>::from_bytes(&encoded).unwrap().0,
+        unsorted
+    );
+    assert!(<Nums as Decode<CanonicalAper>>::from_bytes(&encoded).is_err());
+}
@@ -0,0 +1,68 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::{Aper, AperCodec, AperCodecData};
+use asn1_codecs::{Decode, Encode};
+use asn1_codecs_derive::AperCodec;
+
+mod common;
+use common::{Flag, Level};
+
+pub type Alias = Level;
+
+// `DEFAULT` components are encoded like `OPTIONAL` components, that are absent when the value is
+// the default value.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
+pub struct Rec {
+    #[asn(optional_idx = 0, default = "Rec::level_default")]
+    pub level: Alias,
+    pub flag: Flag,
+    #[asn(optional_idx = 1, default = "Rec::other_default")]
+    pub other: Flag,
+}
+
+impl Rec {
+    pub fn level_default() -> Alias {
+        Level(15)
+    }
+
+    pub fn other_default() -> Flag {
+        Flag(true)
+    }
+}
+
+fn main() {
+    eprintln!("Default");
+
+    // Both the components have the default value and are omitted.
+    let rec = Rec {
+        level: Level(15),
+        flag: Flag(false),
+        other: Flag(true),
+    };
+    let encoded = Encode::<Aper>::to_bytes(&rec).unwrap();
+    assert_eq!(encoded, vec![0b0000_0000]);
+    assert_eq!(<Rec as Decode<Aper>>::from_bytes(&encoded).unwrap().0, rec);
+
+    // Only `level` differs from the default value.
+    let rec = Rec {
+        level: Level(3),
+        flag: Flag(true),
+        other: Flag(true),
+    };
+    let encoded = Encode::<Aper>::to_bytes(&rec).unwrap();
+    assert_eq!(encoded, vec![0b1000_1110]);
+    assert_eq!(<Rec as Decode<Aper>>::from_bytes(&encoded).unwrap().0, rec);
+
+    // `level` is present with the default value, this is not a canonical encoding.
+    let encoded = vec![0b1011_1100];
+    let rec = Rec {
+        level: Level(15),
+        flag: Flag(false),
+        other: Flag(true),
+    };
+    assert_eq!(<Rec as Decode<Aper>>::from_bytes(&encoded).unwrap().0, rec);
+    let mut data = AperCodecData::from_slice(&encoded);
+    data.set_canonical(true);
+    assert!(Rec::decode(&mut data).is_err());
+}
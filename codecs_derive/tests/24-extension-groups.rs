@@ -0,0 +1,73 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::{Aper, UnknownExtensions};
+use asn1_codecs::{Decode, Encode};
+use asn1_codecs_derive::AperCodec;
+
+mod common;
+use common::{Flag, Level};
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct RecExtGroup1 {
+    pub count: Level,
+    pub flag: Flag,
+}
+
+// Extension Additions are encoded as Open Types after the root components. An Extension Addition
+// Group is encoded as a single Open Type.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Rec {
+    pub level: Level,
+    #[asn(extension_idx = 0)]
+    pub flag: Option<Flag>,
+    #[asn(extension_idx = 1, version = 2)]
+    pub ext_group_1: Option<RecExtGroup1>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: UnknownExtensions,
+}
+
+fn main() {
+    eprintln!("Extension Groups");
+
+    // No Extension Additions present.
+    let rec = Rec {
+        level: Level(3),
+        flag: None,
+        ext_group_1: None,
+        unknown_extensions: UnknownExtensions::default(),
+    };
+    let encoded = Encode::<Aper>::to_bytes(&rec).unwrap();
+    assert_eq!(encoded, vec![0x18]);
+    assert_eq!(<Rec as Decode<Aper>>::from_bytes(&encoded).unwrap().0, rec);
+
+    // Only the Extension Addition Group is present.
+    let rec = Rec {
+        level: Level(3),
+        flag: None,
+        ext_group_1: Some(RecExtGroup1 {
+            count: Level(5),
+            flag: Flag(true),
+        }),
+        unknown_extensions: UnknownExtensions::default(),
+    };
+    let encoded = Encode::<Aper>::to_bytes(&rec).unwrap();
+    assert_eq!(encoded, vec![0x98, 0x14, 0x01, 0x58]);
+    assert_eq!(<Rec as Decode<Aper>>::from_bytes(&encoded).unwrap().0, rec);
+
+    // An Extension Addition not known is retained along with the known ones.
+    let encoded = vec![0x98, 0x26, 0x01, 0x58, 0x01, 0xab];
+    let rec = <Rec as Decode<Aper>>::from_bytes(&encoded).unwrap().0;
+    assert_eq!(rec.flag, None);
+    assert_eq!(
+        rec.ext_group_1,
+        Some(RecExtGroup1 {
+            count: Level(5),
+            flag: Flag(true),
+        })
+    );
+    assert_eq!(rec.unknown_extensions.additions().len(), 1);
+    assert_eq!(rec.unknown_extensions.additions()[0].index, 2);
+    assert_eq!(Encode::<Aper>::to_bytes(&rec).unwrap(), encoded);
+}
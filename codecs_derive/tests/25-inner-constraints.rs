@@ -0,0 +1,166 @@
+#![allow(dead_code)]
+
+use asn1_codecs::aper::{Aper, AperCodec, AperCodecData, DecodeOptions};
+use asn1_codecs::Encode;
+use asn1_codecs_derive::AperCodec;
+
+mod common;
+use common::{Flag, Level};
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
+pub struct Rec {
+    #[asn(optional_idx = 0)]
+    pub level: Option<Level>,
+    #[asn(optional_idx = 1)]
+    pub flag: Option<Flag>,
+    pub other: Level,
+}
+
+// `Rec (WITH COMPONENTS { ..., level (0..3) PRESENT, flag ABSENT, other (1..10) })`, which is
+// encoded the same as `Rec`.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
+pub struct Constrained {
+    #[asn(optional_idx = 0, presence = "PRESENT", value_lb = "0", value_ub = "3")]
+    pub level: Option<Level>,
+    #[asn(optional_idx = 1, presence = "ABSENT")]
+    pub flag: Option<Flag>,
+    #[asn(value_lb = "1", value_ub = "10")]
+    pub other: Level,
+}
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
+pub struct Levels(Vec<Level>);
+
+// `Levels (WITH COMPONENT (1..10))`
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "1",
+    sz_ub = "4",
+    entry_lb = "1",
+    entry_ub = "10"
+)]
+pub struct ConstrainedLevels(Vec<Level>);
+
+// eg. `SerialNumber ::= INTEGER` generated with `--big-integers`.
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER")]
+pub struct SerialNumber(asn1_codecs::BigInt);
+
+// `SEQUENCE { serial SerialNumber } (WITH COMPONENTS { serial (1..MAX) })`
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct Certificate {
+    #[asn(value_lb = "1", value_big = true)]
+    pub serial: SerialNumber,
+}
+
+// `SEQUENCE OF SerialNumber (WITH COMPONENT (1..100))`
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    entry_lb = "1",
+    entry_ub = "100",
+    entry_big = true
+)]
+pub struct SerialNumbers(Vec<SerialNumber>);
+
+fn decode_with<T: AperCodec>(
+    bytes: &[u8],
+    options: DecodeOptions,
+) -> (Result<T, asn1_codecs::aper::AperCodecError>, usize) {
+    let mut data = AperCodecData::from_slice(bytes);
+    data.set_decode_options(options);
+    let result = T::decode(&mut data);
+    (result, data.warnings().len())
+}
+
+fn main() {
+    eprintln!("Inner Type Constraints");
+
+    // A value satisfying the constraints is encoded as the unconstrained value.
+    let encoded = Encode::<Aper>::to_bytes(&Rec {
+        level: Some(Level(2)),
+        flag: None,
+        other: Level(5),
+    })
+    .unwrap();
+    let constrained = Constrained {
+        level: Some(Level(2)),
+        flag: None,
+        other: Level(5),
+    };
+    assert_eq!(Encode::<Aper>::to_bytes(&constrained).unwrap(), encoded);
+    let (result, warnings) = decode_with::<Constrained>(&encoded, DecodeOptions::strict());
+    assert_eq!(result.unwrap(), constrained);
+    assert_eq!(warnings, 0);
+
+    // Values violating the constraints are not encoded.
+    let mut data = AperCodecData::new();
+    let absent = Constrained {
+        level: None,
+        flag: None,
+        other: Level(5),
+    };
+    assert!(absent.encode(&mut data).is_err());
+    let out_of_range = Constrained {
+        level: Some(Level(2)),
+        flag: None,
+        other: Level(0),
+    };
+    assert!(out_of_range.encode(&mut data).is_err());
+
+    // Values violating the constraints are an error by default.
+    let encoded = Encode::<Aper>::to_bytes(&Rec {
+        level: Some(Level(7)),
+        flag: Some(Flag(true)),
+        other: Level(5),
+    })
+    .unwrap();
+    let (result, _) = decode_with::<Constrained>(&encoded, DecodeOptions::default());
+    assert!(result.is_err());
+    let (result, warnings) = decode_with::<Constrained>(&encoded, DecodeOptions::lenient());
+    assert_eq!(result.unwrap().level, Some(Level(7)));
+    assert_eq!(warnings, 2);
+
+    // Constraints on the entries of a `SEQUENCE OF`.
+    let encoded = Encode::<Aper>::to_bytes(&Levels(vec![Level(1), Level(10)])).unwrap();
+    assert_eq!(
+        Encode::<Aper>::to_bytes(&ConstrainedLevels(vec![Level(1), Level(10)])).unwrap(),
+        encoded
+    );
+    let (result, warnings) = decode_with::<ConstrainedLevels>(&encoded, DecodeOptions::strict());
+    assert_eq!(
+        result.unwrap(),
+        ConstrainedLevels(vec![Level(1), Level(10)])
+    );
+    assert_eq!(warnings, 0);
+
+    let mut data = AperCodecData::new();
+    assert!(ConstrainedLevels(vec![Level(0)]).encode(&mut data).is_err());
+    let encoded = Encode::<Aper>::to_bytes(&Levels(vec![Level(15)])).unwrap();
+    let (result, _) = decode_with::<ConstrainedLevels>(&encoded, DecodeOptions::strict());
+    assert!(result.is_err());
+    let (result, warnings) = decode_with::<ConstrainedLevels>(&encoded, DecodeOptions::lenient());
+    assert_eq!(result.unwrap(), ConstrainedLevels(vec![Level(15)]));
+    assert_eq!(warnings, 1);
+
+    // Arbitrary precision `INTEGER`s are compared as such.
+    let serial = SerialNumber(asn1_codecs::BigInt::from(u128::MAX) * 2);
+    let mut data = AperCodecData::new();
+    assert!(Certificate { serial }.encode(&mut data).is_ok());
+    let serial = SerialNumber(asn1_codecs::BigInt::from(0));
+    let mut data = AperCodecData::new();
+    assert!(Certificate { serial }.encode(&mut data).is_err());
+    let serials = SerialNumbers(vec![SerialNumber(asn1_codecs::BigInt::from(100))]);
+    let mut data = AperCodecData::new();
+    assert!(serials.encode(&mut data).is_ok());
+    let serials = SerialNumbers(vec![SerialNumber(asn1_codecs::BigInt::from(101))]);
+    let mut data = AperCodecData::new();
+    assert!(serials.encode(&mut data).is_err());
+}
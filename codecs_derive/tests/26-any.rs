@@ -0,0 +1,94 @@
+#![allow(dead_code)]
+
+use asn1_codecs::aper::{Aper, LazyOpenType};
+use asn1_codecs::reflect::{Asn1Kind, Asn1Reflect, Asn1Value};
+use asn1_codecs::{Decode, Encode};
+use asn1_codecs_derive::{AperCodec, Asn1Reflect};
+
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "ANY")]
+pub struct Opaque(pub Vec<u8>);
+
+// `EXTERNAL` is carried as the raw bytes as well.
+#[derive(Debug, AperCodec, Asn1Reflect, PartialEq)]
+#[asn(type = "ANY", tag = "[UNIVERSAL 8] IMPLICIT")]
+pub struct External(pub Vec<u8>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct Octets(pub Vec<u8>);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct AlgorithmId(pub u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct Params1(pub u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Params2(pub bool);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum AlgorithmIdentifierParameters {
+    #[asn(key = 1)]
+    Params1(Params1),
+    #[asn(key = 2)]
+    Params2(Params2),
+}
+
+// `AlgorithmIdentifier ::= SEQUENCE { algorithm ..., parameters ANY DEFINED BY algorithm }`
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
+pub struct AlgorithmIdentifier {
+    #[asn(key_field = true)]
+    pub algorithm: AlgorithmId,
+    pub parameters: LazyOpenType<AlgorithmIdentifierParameters>,
+    #[asn(optional_idx = 0)]
+    pub extra: Option<Opaque>,
+}
+
+fn main() {
+    eprintln!("ANY");
+
+    // The raw bytes are encoded as an Open Type, ie. the same as an unconstrained OCTET STRING.
+    let opaque = Opaque(vec![0x01, 0x02, 0x03]);
+    let encoded = Encode::<Aper>::to_bytes(&opaque).unwrap();
+    assert_eq!(
+        encoded,
+        Encode::<Aper>::to_bytes(&Octets(vec![0x01, 0x02, 0x03])).unwrap()
+    );
+    assert_eq!(
+        <Opaque as Decode<Aper>>::from_bytes(&encoded).unwrap().0,
+        opaque
+    );
+
+    let external = External(vec![0xde, 0xad]);
+    assert_eq!(
+        <External as Decode<Aper>>::from_bytes(&Encode::<Aper>::to_bytes(&external).unwrap())
+            .unwrap()
+            .0,
+        external
+    );
+
+    assert_eq!(opaque.kind(), Asn1Kind::Open);
+    assert_eq!(opaque.value(), Asn1Value::OctetString(&[0x01, 0x02, 0x03]));
+
+    // The Type of the `parameters` is looked up using the `algorithm`.
+    let parameters = AlgorithmIdentifierParameters::Params2(Params2(true));
+    let algorithm = AlgorithmIdentifier {
+        algorithm: AlgorithmId(2),
+        parameters: LazyOpenType::new(2, &parameters).unwrap(),
+        extra: Some(Opaque(vec![0xff])),
+    };
+    let decoded = <AlgorithmIdentifier as Decode<Aper>>::from_bytes(
+        &Encode::<Aper>::to_bytes(&algorithm).unwrap(),
+    )
+    .unwrap()
+    .0;
+    assert_eq!(decoded, algorithm);
+    assert_eq!(decoded.parameters.key(), Some(2));
+    assert_eq!(decoded.parameters.decode_value().unwrap(), parameters);
+}
@@ -0,0 +1,188 @@
+#![allow(non_camel_case_types, dead_code)]
+
+use asn1_codecs::aper::{Aper, UnknownExtension, UnknownExtensions};
+use asn1_codecs::notation::Asn1ValueNotation;
+use asn1_codecs::{Decode, Encode};
+use asn1_codecs_derive::{AperCodec, Asn1ValueNotation};
+
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "15", extensible = true)]
+pub struct Level(pub i64, pub bool);
+
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(pub bool);
+
+// The identifiers are in the order of the values, the Extension Additions follow the root.
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(
+    type = "ENUMERATED",
+    extensible = true,
+    lb = "0",
+    ub = "1",
+    names = "reject ignore notify"
+)]
+pub struct Criticality(pub u8);
+
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "OCTET-STRING")]
+pub struct Payload(pub Vec<u8>);
+
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "SEQUENCE-OF", sz_lb = "0", sz_ub = "4")]
+pub struct Levels(pub Vec<Level>);
+
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct RecExtGroup1 {
+    #[asn(name = "count")]
+    pub count: Level,
+    #[asn(name = "enabled")]
+    pub enabled: Flag,
+}
+
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
+pub struct Rec {
+    #[asn(name = "level")]
+    pub level: Level,
+    #[asn(
+        optional_idx = 0,
+        name = "criticality",
+        default = "Rec::criticality_default"
+    )]
+    pub criticality: Criticality,
+    #[asn(optional_idx = 1, name = "payload")]
+    pub payload: Option<Payload>,
+    #[asn(extension_idx = 0, name = "levels")]
+    pub levels: Option<Levels>,
+    #[asn(extension_idx = 1)]
+    pub ext_group_1: Option<RecExtGroup1>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: UnknownExtensions,
+}
+
+impl Rec {
+    pub fn criticality_default() -> Criticality {
+        Criticality(1)
+    }
+}
+
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Event {
+    #[asn(key = 0, extended = false, name = "level")]
+    Level(Level),
+    #[asn(key = 1, extended = false, name = "rec")]
+    Rec(Rec),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(UnknownExtension),
+}
+
+// Open Type values are written with the name of the Type, more than one key may have the Type.
+#[derive(Debug, AperCodec, Asn1ValueNotation, PartialEq)]
+#[asn(type = "OPEN")]
+pub enum Value {
+    #[asn(key = 1, name = "Level")]
+    Level(Level),
+    #[asn(key = 2, name = "Flag")]
+    Flag(Flag),
+    #[asn(key = 3, name = "Flag")]
+    OtherFlag(Flag),
+}
+
+fn main() {
+    eprintln!("Value Notation");
+
+    let rec = Rec {
+        level: Level(3, false),
+        criticality: Criticality(0),
+        payload: Some(Payload(vec![0xab, 0x01])),
+        levels: None,
+        ext_group_1: Some(RecExtGroup1 {
+            count: Level(20, false),
+            enabled: Flag(true),
+        }),
+        unknown_extensions: UnknownExtensions::default(),
+    };
+    let notation = rec.to_value_notation();
+    assert_eq!(
+        notation,
+        "{ level 3, criticality reject, payload 'AB01'H, count 20, enabled TRUE }"
+    );
+    assert_eq!(Rec::from_value_notation(&notation).unwrap(), rec);
+
+    // Components may be in any order, absent components are absent or have the default value.
+    let rec = Rec::from_value_notation("{ levels { 1, 2 }, level 0 }").unwrap();
+    assert_eq!(
+        rec,
+        Rec {
+            level: Level(0, false),
+            criticality: Criticality(1),
+            payload: None,
+            levels: Some(Levels(vec![Level(1, false), Level(2, false)])),
+            ext_group_1: None,
+            unknown_extensions: UnknownExtensions::default(),
+        }
+    );
+    assert_eq!(
+        rec.to_value_notation(),
+        "{ level 0, criticality ignore, levels { 1, 2 } }"
+    );
+
+    // Missing required components (of the `SEQUENCE` or of the Extension Addition Group) and
+    // unknown components.
+    assert!(Rec::from_value_notation("{ level 0, count 1 }").is_err());
+    assert!(Rec::from_value_notation("{ criticality notify }").is_err());
+    assert!(Rec::from_value_notation("{ level 0, unknown 1 }").is_err());
+
+    // Unknown Extension Additions are written only if present.
+    let event = Event::Rec(Rec {
+        level: Level(1, false),
+        criticality: Criticality(1),
+        payload: None,
+        levels: None,
+        ext_group_1: None,
+        unknown_extensions: UnknownExtensions::new(vec![UnknownExtension {
+            index: 2,
+            bytes: vec![0x56],
+        }]),
+    });
+    let notation = event.to_value_notation();
+    assert_eq!(
+        notation,
+        "rec : { level 1, criticality ignore, unknownExtensions { { index 2, bytes '56'H } } }"
+    );
+    assert_eq!(Event::from_value_notation(&notation).unwrap(), event);
+    let event = Event::UnknownExtension(UnknownExtension {
+        index: 3,
+        bytes: vec![0x12],
+    });
+    let notation = event.to_value_notation();
+    assert_eq!(notation, "unknownExtension : { index 3, bytes '12'H }");
+    assert_eq!(Event::from_value_notation(&notation).unwrap(), event);
+
+    // A parsed value is encoded like any other value.
+    let event = Event::from_value_notation("rec : { level 7 }").unwrap();
+    let encoded = Encode::<Aper>::to_bytes(&event).unwrap();
+    let decoded = <Event as Decode<Aper>>::from_bytes(&encoded).unwrap().0;
+    assert_eq!(
+        decoded.to_value_notation(),
+        "rec : { level 7, criticality ignore }"
+    );
+
+    // The key of an Open Type value is the key of its variant.
+    let value = Value::from_value_notation("Flag : TRUE").unwrap();
+    assert_eq!(value, Value::Flag(Flag(true)));
+    assert_eq!(value.open_type_key(), Some(2));
+    assert_eq!(
+        Value::OtherFlag(Flag(false)).to_value_notation(),
+        "Flag : FALSE"
+    );
+    assert_eq!(Value::OtherFlag(Flag(false)).open_type_key(), Some(3));
+
+    // An unknown value of an extensible ENUMERATED is written as a number.
+    assert_eq!(Criticality(2).to_value_notation(), "notify");
+    assert_eq!(Criticality(5).to_value_notation(), "5");
+    assert!(Criticality::from_value_notation("critical").is_err());
+}
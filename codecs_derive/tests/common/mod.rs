@@ -0,0 +1,11 @@
+//! Types shared by the test cases.
+
+use asn1_codecs_derive::AperCodec;
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "INTEGER", lb = "0", ub = "15")]
+pub struct Level(pub u8);
+
+#[derive(Debug, AperCodec, PartialEq)]
+#[asn(type = "BOOLEAN")]
+pub struct Flag(pub bool);
@@ -12,4 +12,21 @@ fn tests() {
     t.pass("tests/08-seq.rs");
     t.pass("tests/09-open.rs");
     t.pass("tests/10-seqof.rs");
+    t.pass("tests/11-lazy-open.rs");
+    t.pass("tests/12-extensions.rs");
+    t.pass("tests/13-extended-values.rs");
+    t.pass("tests/14-decode-options.rs");
+    t.pass("tests/15-big-integer.rs");
+    t.pass("tests/16-framed.rs");
+    t.pass("tests/17-no-std.rs");
+    t.pass("tests/18-reflect.rs");
+    t.pass("tests/19-redact.rs");
+    t.pass("tests/20-diff.rs");
+    t.pass("tests/21-tags.rs");
+    t.pass("tests/22-set.rs");
+    t.pass("tests/23-default.rs");
+    t.pass("tests/24-extension-groups.rs");
+    t.pass("tests/25-inner-constraints.rs");
+    t.pass("tests/26-any.rs");
+    t.pass("tests/27-value-notation.rs");
 }
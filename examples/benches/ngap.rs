@@ -1,92 +1,122 @@
 #![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::enum_variant_names,
+    clippy::upper_case_acronyms
+)]
 use asn1_codecs_derive::AperCodec;
 use bitvec::order::Msb0;
 use bitvec::vec::BitVec;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AMF_TNLAssociationSetupItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub amf_tnl_association_address: CPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AMF_TNLAssociationSetupItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationSetupList(pub Vec<AMF_TNLAssociationSetupItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AMF_TNLAssociationToAddItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub amf_tnl_association_address: CPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub tnl_association_usage: Option<TNLAssociationUsage>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub tnl_address_weight_factor: TNLAddressWeightFactor,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<AMF_TNLAssociationToAddItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationToAddList(pub Vec<AMF_TNLAssociationToAddItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AMF_TNLAssociationToRemoveItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub amf_tnl_association_address: CPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AMF_TNLAssociationToRemoveItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationToRemoveList(pub Vec<AMF_TNLAssociationToRemoveItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct AMF_TNLAssociationToUpdateItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub amf_tnl_association_address: CPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub tnl_association_usage: Option<TNLAssociationUsage>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub tnl_address_weight_factor: Option<TNLAddressWeightFactor>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<AMF_TNLAssociationToUpdateItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AMF_TNLAssociationToUpdateList(pub Vec<AMF_TNLAssociationToUpdateItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1099511627775")]
 pub struct AMF_UE_NGAP_ID(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFCPRelocationIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: AMFCPRelocationIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFConfigurationUpdate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: AMFConfigurationUpdateProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFConfigurationUpdateAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: AMFConfigurationUpdateAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFConfigurationUpdateFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: AMFConfigurationUpdateFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "PrintableString",
     sz_extensible = true,
@@ -95,11 +125,11 @@ pub struct AMFConfigurationUpdateFailure {
 )]
 pub struct AMFName(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "UTF8String", sz_extensible = true, sz_lb = "1", sz_ub = "150")]
 pub struct AMFNameUTF8String(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "VisibleString",
     sz_extensible = true,
@@ -108,68 +138,80 @@ pub struct AMFNameUTF8String(pub String);
 )]
 pub struct AMFNameVisibleString(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum AMFPagingTarget {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] EXPLICIT")]
     GlobalRANNodeID(GlobalRANNodeID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TAI(TAI),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(AMFPagingTargetchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "6", sz_ub = "6")]
 pub struct AMFPointer(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct AMFRegionID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "10", sz_ub = "10")]
 pub struct AMFSetID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct AMFStatusIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: AMFStatusIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AdditionalDLUPTNLInformationForHOItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub additional_dl_ngu_up_tnl_information: UPTransportLayerInformation,
+    #[asn(tag = "[1] IMPLICIT")]
     pub additional_qos_flow_setup_response_list: QosFlowListWithDataForwarding,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 0)]
     pub additional_dl_forwarding_uptnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<AdditionalDLUPTNLInformationForHOItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct AdditionalDLUPTNLInformationForHOList(pub Vec<AdditionalDLUPTNLInformationForHOItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct AdditionalQosFlowInformation(pub u8);
 impl AdditionalQosFlowInformation {
     pub const MORE_LIKELY: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AllocationAndRetentionPriority {
+    #[asn(tag = "[0] IMPLICIT")]
     pub priority_level_arp: PriorityLevelARP,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pre_emption_capability: Pre_emptionCapability,
+    #[asn(tag = "[2] IMPLICIT")]
     pub pre_emption_vulnerability: Pre_emptionVulnerability,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AllocationAndRetentionPriorityIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -178,86 +220,102 @@ pub struct AllocationAndRetentionPriority {
 )]
 pub struct Allowed_CAG_List_per_PLMN(pub Vec<CAG_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Allowed_PNI_NPN_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pni_npn_restricted: ENUMERATED_2,
+    #[asn(tag = "[2] IMPLICIT")]
     pub allowed_cag_list_per_plmn: Allowed_CAG_List_per_PLMN,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Allowed_PNI_NPN_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Allowed_PNI_NPN_List(pub Vec<Allowed_PNI_NPN_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct AllowedNSSAI(pub Vec<AllowedNSSAI_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AllowedNSSAI_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub s_nssai: S_NSSAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AllowedNSSAI_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct AllowedTACs(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "8", extensible = true)]
 pub struct AlternativeQoSParaSetIndex(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct AlternativeQoSParaSetItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub alternative_qo_s_para_set_index: AlternativeQoSParaSetIndex,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub guaranteed_flow_bit_rate_dl: Option<BitRate>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub guaranteed_flow_bit_rate_ul: Option<BitRate>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub packet_delay_budget: Option<PacketDelayBudget>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub packet_error_rate: Option<PacketErrorRate>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<AlternativeQoSParaSetItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct AlternativeQoSParaSetList(pub Vec<AlternativeQoSParaSetItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "8", extensible = true)]
 pub struct AlternativeQoSParaSetNotifyIndex(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct AreaOfInterest {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub area_of_interest_tai_list: Option<AreaOfInterestTAIList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub area_of_interest_cell_list: Option<AreaOfInterestCellList>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub area_of_interest_ran_node_list: Option<AreaOfInterestRANNodeList>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<AreaOfInterestIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestCellItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ngran_cgi: NGRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AreaOfInterestCellItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -266,167 +324,194 @@ pub struct AreaOfInterestCellItem {
 )]
 pub struct AreaOfInterestCellList(pub Vec<AreaOfInterestCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub area_of_interest: AreaOfInterest,
+    #[asn(tag = "[1] IMPLICIT")]
     pub location_reporting_reference_id: LocationReportingReferenceID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AreaOfInterestItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct AreaOfInterestList(pub Vec<AreaOfInterestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestRANNodeItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub global_ran_node_id: GlobalRANNodeID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AreaOfInterestRANNodeItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct AreaOfInterestRANNodeList(pub Vec<AreaOfInterestRANNodeItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AreaOfInterestTAIItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AreaOfInterestTAIItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct AreaOfInterestTAIList(pub Vec<AreaOfInterestTAIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum AreaScopeOfMDT_EUTRA {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellBased(CellBasedMDT_EUTRA),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TABased(TABasedMDT),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     PLMNWide(NULL_3),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     TAIBased(TAIBasedMDT),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Choice_Extensions(AreaScopeOfMDT_EUTRAchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum AreaScopeOfMDT_NR {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellBased(CellBasedMDT_NR),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TABased(TABasedMDT),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     PLMNWide(NULL_4),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     TAIBased(TAIBasedMDT),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Choice_Extensions(AreaScopeOfMDT_NRchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AreaScopeOfNeighCellsItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_frequency_info: NRFrequencyInfo,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub pci_list_for_mdt: Option<PCIListForMDT>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<AreaScopeOfNeighCellsItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct AreaScopeOfNeighCellsList(pub Vec<AreaScopeOfNeighCellsItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct AssistanceDataForPaging {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub assistance_data_for_recommended_cells: Option<AssistanceDataForRecommendedCells>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub paging_attempt_information: Option<PagingAttemptInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<AssistanceDataForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AssistanceDataForRecommendedCells {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_cells_for_paging: RecommendedCellsForPaging,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AssistanceDataForRecommendedCellsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AssociatedQosFlowItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub qos_flow_mapping_indication: Option<ENUMERATED_5>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<AssociatedQosFlowItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct AssociatedQosFlowList(pub Vec<AssociatedQosFlowItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct AuthenticatedIndication(pub u8);
 impl AuthenticatedIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095", extensible = true)]
 pub struct AveragingWindow(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4000000000000", extensible = true)]
 pub struct BitRate(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct BluetoothMeasConfig(pub u8);
 impl BluetoothMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct BluetoothMeasConfigNameItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub bluetooth_name: BluetoothName,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<BluetoothMeasConfigNameItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct BluetoothMeasConfigNameList(pub Vec<BluetoothMeasConfigNameItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct BluetoothMeasurementConfiguration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub bluetooth_meas_config: BluetoothMeasConfig,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub bluetooth_meas_config_name_list: Option<BluetoothMeasConfigNameList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub bt_rssi: Option<ENUMERATED_6>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<BluetoothMeasurementConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -435,73 +520,77 @@ pub struct BluetoothMeasurementConfiguration {
 )]
 pub struct BluetoothName(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "6", extensible = false)]
 pub enum BroadcastCancelledAreaList {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellIDCancelledEUTRA(CellIDCancelledEUTRA),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TAICancelledEUTRA(TAICancelledEUTRA),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     EmergencyAreaIDCancelledEUTRA(EmergencyAreaIDCancelledEUTRA),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     CellIDCancelledNR(CellIDCancelledNR),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     TAICancelledNR(TAICancelledNR),
-    #[asn(key = 5, extended = false)]
+    #[asn(key = 5, extended = false, tag = "[5] IMPLICIT")]
     EmergencyAreaIDCancelledNR(EmergencyAreaIDCancelledNR),
-    #[asn(key = 6, extended = false)]
+    #[asn(key = 6, extended = false, tag = "[6] IMPLICIT")]
     Choice_Extensions(BroadcastCancelledAreaListchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "6", extensible = false)]
 pub enum BroadcastCompletedAreaList {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellIDBroadcastEUTRA(CellIDBroadcastEUTRA),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TAIBroadcastEUTRA(TAIBroadcastEUTRA),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     EmergencyAreaIDBroadcastEUTRA(EmergencyAreaIDBroadcastEUTRA),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     CellIDBroadcastNR(CellIDBroadcastNR),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     TAIBroadcastNR(TAIBroadcastNR),
-    #[asn(key = 5, extended = false)]
+    #[asn(key = 5, extended = false, tag = "[5] IMPLICIT")]
     EmergencyAreaIDBroadcastNR(EmergencyAreaIDBroadcastNR),
-    #[asn(key = 6, extended = false)]
+    #[asn(key = 6, extended = false, tag = "[6] IMPLICIT")]
     Choice_Extensions(BroadcastCompletedAreaListchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct BroadcastPLMNItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tai_slice_support_list: SliceSupportList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<BroadcastPLMNItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "12")]
 pub struct BroadcastPLMNList(pub Vec<BroadcastPLMNItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct BurstArrivalTime(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "32", sz_ub = "32")]
 pub struct CAG_ID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CEmodeBSupport_Indicator(pub u8);
 impl CEmodeBSupport_Indicator {
     pub const SUPPORTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CEmodeBrestricted(pub u8);
 impl CEmodeBrestricted {
@@ -509,70 +598,84 @@ impl CEmodeBrestricted {
     pub const NOT_RESTRICTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct CNAssistedRANTuning {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub expected_ue_behaviour: Option<ExpectedUEBehaviour>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<CNAssistedRANTuningIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "15")]
 pub struct CNTypeRestrictionsForEquivalent(pub Vec<CNTypeRestrictionsForEquivalentItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CNTypeRestrictionsForEquivalentItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cn_type: ENUMERATED_7,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CNTypeRestrictionsForEquivalentItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CNTypeRestrictionsForServing(pub u8);
 impl CNTypeRestrictionsForServing {
     pub const EPC_FORBIDDEN: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct COUNTValueForPDCP_SN12 {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdcp_sn12: INTEGER_8,
+    #[asn(tag = "[1] IMPLICIT")]
     pub hfn_pdcp_sn12: INTEGER_9,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<COUNTValueForPDCP_SN12IE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct COUNTValueForPDCP_SN18 {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdcp_sn18: INTEGER_10,
+    #[asn(tag = "[1] IMPLICIT")]
     pub hfn_pdcp_sn18: INTEGER_11,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<COUNTValueForPDCP_SN18IE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum CPTransportLayerInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     EndpointIPAddress(TransportLayerAddress),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(CPTransportLayerInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CancelAllWarningMessages(pub u8);
 impl CancelAllWarningMessages {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -581,16 +684,20 @@ impl CancelAllWarningMessages {
 )]
 pub struct CancelledCellsInEAI_EUTRA(pub Vec<CancelledCellsInEAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInEAI_EUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CancelledCellsInEAI_EUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -599,16 +706,20 @@ pub struct CancelledCellsInEAI_EUTRA_Item {
 )]
 pub struct CancelledCellsInEAI_NR(pub Vec<CancelledCellsInEAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInEAI_NR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_cgi: NR_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CancelledCellsInEAI_NR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -617,16 +728,20 @@ pub struct CancelledCellsInEAI_NR_Item {
 )]
 pub struct CancelledCellsInTAI_EUTRA(pub Vec<CancelledCellsInTAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInTAI_EUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CancelledCellsInTAI_EUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -635,73 +750,87 @@ pub struct CancelledCellsInTAI_EUTRA_Item {
 )]
 pub struct CancelledCellsInTAI_NR(pub Vec<CancelledCellsInTAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellsInTAI_NR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_cgi: NR_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CancelledCellsInTAI_NR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum CandidateCell {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CandidateCGI(CandidateCellID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     CandidatePCI(CandidatePCI),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(CandidateCellchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CandidateCellID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub candidate_cell_id: NR_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CandidateCellIDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CandidateCellItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub candidate_cell: CandidateCell,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CandidateCellItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CandidateCellList(pub Vec<CandidateCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CandidatePCI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub candidate_pci: INTEGER_12,
+    #[asn(tag = "[1] IMPLICIT")]
     pub candidate_nrarfcn: INTEGER_13,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CandidatePCIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "5", extensible = false)]
 pub enum Cause {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RadioNetwork(CauseRadioNetwork),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Transport(CauseTransport),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Nas(CauseNas),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Protocol(CauseProtocol),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Misc(CauseMisc),
-    #[asn(key = 5, extended = false)]
+    #[asn(key = 5, extended = false, tag = "[5] IMPLICIT")]
     Choice_Extensions(Causechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct CauseMisc(pub u8);
 impl CauseMisc {
@@ -713,7 +842,7 @@ impl CauseMisc {
     pub const UNSPECIFIED: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct CauseNas(pub u8);
 impl CauseNas {
@@ -723,7 +852,7 @@ impl CauseNas {
     pub const UNSPECIFIED: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "6")]
 pub struct CauseProtocol(pub u8);
 impl CauseProtocol {
@@ -736,7 +865,7 @@ impl CauseProtocol {
     pub const UNSPECIFIED: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "44")]
 pub struct CauseRadioNetwork(pub u8);
 impl CauseRadioNetwork {
@@ -785,9 +914,16 @@ impl CauseRadioNetwork {
     pub const RESOURCES_NOT_AVAILABLE_FOR_THE_SLICE: u8 = 42u8;
     pub const UE_MAX_INTEGRITY_PROTECTED_DATA_RATE_REASON: u8 = 43u8;
     pub const RELEASE_DUE_TO_CN_DETECTED_MOBILITY: u8 = 44u8;
+    pub const N26_INTERFACE_NOT_AVAILABLE: u8 = 45u8;
+    pub const RELEASE_DUE_TO_PRE_EMPTION: u8 = 46u8;
+    pub const MULTIPLE_LOCATION_REPORTING_REFERENCE_ID_INSTANCES: u8 = 47u8;
+    pub const RSN_NOT_AVAILABLE_FOR_THE_UP: u8 = 48u8;
+    pub const NPN_ACCESS_DENIED: u8 = 49u8;
+    pub const CAG_ONLY_ACCESS_DENIED: u8 = 50u8;
+    pub const INSUFFICIENT_UE_CAPABILITIES: u8 = 51u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CauseTransport(pub u8);
 impl CauseTransport {
@@ -795,36 +931,46 @@ impl CauseTransport {
     pub const UNSPECIFIED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Cell_CAGInformation {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ngran_cgi: NGRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cell_cag_list: CellCAGList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Cell_CAGInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellBasedMDT_EUTRA {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id_listfor_mdt: CellIdListforMDT_EUTRA,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellBasedMDT_EUTRAIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellBasedMDT_NR {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id_listfor_mdt: CellIdListforMDT_NR,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellBasedMDT_NRIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct CellCAGList(pub Vec<CAG_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -833,15 +979,18 @@ pub struct CellCAGList(pub Vec<CAG_ID>);
 )]
 pub struct CellIDBroadcastEUTRA(pub Vec<CellIDBroadcastEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDBroadcastEUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellIDBroadcastEUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -850,15 +999,18 @@ pub struct CellIDBroadcastEUTRA_Item {
 )]
 pub struct CellIDBroadcastNR(pub Vec<CellIDBroadcastNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDBroadcastNR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_cgi: NR_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellIDBroadcastNR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -867,16 +1019,20 @@ pub struct CellIDBroadcastNR_Item {
 )]
 pub struct CellIDCancelledEUTRA(pub Vec<CellIDCancelledEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDCancelledEUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellIDCancelledEUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -885,35 +1041,39 @@ pub struct CellIDCancelledEUTRA_Item {
 )]
 pub struct CellIDCancelledNR(pub Vec<CellIDCancelledNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIDCancelledNR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_cgi: NR_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellIDCancelledNR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum CellIDListForRestart {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     EUTRA_CGIListforRestart(EUTRA_CGIList),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     NR_CGIListforRestart(NR_CGIList),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(CellIDListForRestartchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CellIdListforMDT_EUTRA(pub Vec<EUTRA_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CellIdListforMDT_NR(pub Vec<NR_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct CellSize(pub u8);
 impl CellSize {
@@ -923,25 +1083,31 @@ impl CellSize {
     pub const LARGE: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct CellTrafficTrace {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: CellTrafficTraceProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellType {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_size: CellSize,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellTypeIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct CommonNetworkInstance(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -950,15 +1116,18 @@ pub struct CommonNetworkInstance(pub Vec<u8>);
 )]
 pub struct CompletedCellsInEAI_EUTRA(pub Vec<CompletedCellsInEAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInEAI_EUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CompletedCellsInEAI_EUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -967,15 +1136,18 @@ pub struct CompletedCellsInEAI_EUTRA_Item {
 )]
 pub struct CompletedCellsInEAI_NR(pub Vec<CompletedCellsInEAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInEAI_NR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_cgi: NR_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CompletedCellsInEAI_NR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -984,15 +1156,18 @@ pub struct CompletedCellsInEAI_NR_Item {
 )]
 pub struct CompletedCellsInTAI_EUTRA(pub Vec<CompletedCellsInTAI_EUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInTAI_EUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CompletedCellsInTAI_EUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1001,22 +1176,25 @@ pub struct CompletedCellsInTAI_EUTRA_Item {
 )]
 pub struct CompletedCellsInTAI_NR(pub Vec<CompletedCellsInTAI_NR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellsInTAI_NR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_cgi: NR_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CompletedCellsInTAI_NR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ConcurrentWarningMessageInd(pub u8);
 impl ConcurrentWarningMessageInd {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct ConfidentialityProtectionIndication(pub u8);
 impl ConfidentialityProtectionIndication {
@@ -1025,7 +1203,7 @@ impl ConfidentialityProtectionIndication {
     pub const NOT_NEEDED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ConfidentialityProtectionResult(pub u8);
 impl ConfidentialityProtectionResult {
@@ -1033,7 +1211,7 @@ impl ConfidentialityProtectionResult {
     pub const NOT_PERFORMED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -1042,40 +1220,48 @@ impl ConfidentialityProtectionResult {
 )]
 pub struct ConfiguredNSSAI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ConfiguredTACIndication(pub u8);
 impl ConfiguredTACIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ConnectionEstablishmentIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ConnectionEstablishmentIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct CoreNetworkAssistanceInformationForInactive {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ue_identity_index_value: UEIdentityIndexValue,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ue_specific_drx: Option<PagingDRX>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub periodic_registration_update_timer: PeriodicRegistrationUpdateTimer,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub mico_mode_indication: Option<MICOModeIndication>,
+    #[asn(tag = "[4] IMPLICIT")]
     pub tai_list_for_inactive: TAIListForInactive,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub expected_ue_behaviour: Option<ExpectedUEBehaviour>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<CoreNetworkAssistanceInformationForInactiveIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct CoverageEnhancementLevel(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Criticality(pub u8);
 impl Criticality {
@@ -1084,32 +1270,39 @@ impl Criticality {
     pub const NOTIFY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct CriticalityDiagnostics {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub procedure_code: Option<ProcedureCode>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub triggering_message: Option<TriggeringMessage>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub procedure_criticality: Option<Criticality>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub i_es_criticality_diagnostics: Option<CriticalityDiagnostics_IE_List>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<CriticalityDiagnosticsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CriticalityDiagnostics_IE_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ie_criticality: Criticality,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ie_id: ProtocolIE_ID,
+    #[asn(tag = "[2] IMPLICIT")]
     pub type_of_error: TypeOfError,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CriticalityDiagnostics_IE_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1118,198 +1311,239 @@ pub struct CriticalityDiagnostics_IE_Item {
 )]
 pub struct CriticalityDiagnostics_IE_List(pub Vec<CriticalityDiagnostics_IE_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSRequestInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub daps_indicator: ENUMERATED_14,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DAPSRequestInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSResponseInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dapsresponseindicator: ENUMERATED_15,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DAPSResponseInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSResponseInfoItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub drb_id: DRB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub daps_response_info: DAPSResponseInfo,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<DAPSResponseInfoItemIE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DAPSResponseInfoList(pub Vec<DAPSResponseInfoItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DL_CP_SecurityInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_nas_mac: DL_NAS_MAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DL_CP_SecurityInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct DL_NAS_MAC(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DL_NGU_TNLInformationReused(pub u8);
 impl DL_NGU_TNLInformationReused {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DLForwarding(pub u8);
 impl DLForwarding {
     pub const DL_FORWARDING_PROPOSED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "32", extensible = true)]
 pub struct DRB_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum DRBStatusDL {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     DRBStatusDL12(DRBStatusDL12),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     DRBStatusDL18(DRBStatusDL18),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(DRBStatusDLchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBStatusDL12 {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_count_value: COUNTValueForPDCP_SN12,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<DRBStatusDL12IE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBStatusDL18 {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_count_value: COUNTValueForPDCP_SN18,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<DRBStatusDL18IE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum DRBStatusUL {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     DRBStatusUL12(DRBStatusUL12),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     DRBStatusUL18(DRBStatusUL18),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(DRBStatusULchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct DRBStatusUL12 {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ul_count_value: COUNTValueForPDCP_SN12,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub receive_status_of_ul_pdcp_sd_us: Option<BIT_STRING_16>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extension: Option<DRBStatusUL12IE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct DRBStatusUL18 {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ul_count_value: COUNTValueForPDCP_SN18,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub receive_status_of_ul_pdcp_sd_us: Option<BIT_STRING_17>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extension: Option<DRBStatusUL18IE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBsSubjectToEarlyStatusTransfer_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub drb_id: DRB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub first_dlcount: DRBStatusDL,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DRBsSubjectToEarlyStatusTransfer_List(pub Vec<DRBsSubjectToEarlyStatusTransfer_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBsSubjectToStatusTransferItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub drb_id: DRB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub drb_status_ul: DRBStatusUL,
+    #[asn(tag = "[2] EXPLICIT")]
     pub drb_status_dl: DRBStatusDL,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<DRBsSubjectToStatusTransferItemIE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DRBsSubjectToStatusTransferList(pub Vec<DRBsSubjectToStatusTransferItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DRBsToQosFlowsMappingItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub drb_id: DRB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub associated_qos_flow_list: AssociatedQosFlowList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DRBsToQosFlowsMappingItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DRBsToQosFlowsMappingList(pub Vec<DRBsToQosFlowsMappingItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct DataCodingScheme(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DataForwardingAccepted(pub u8);
 impl DataForwardingAccepted {
     pub const DATA_FORWARDING_ACCEPTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DataForwardingNotPossible(pub u8);
 impl DataForwardingNotPossible {
     pub const DATA_FORWARDING_NOT_POSSIBLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct DataForwardingResponseDRBItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub drb_id: DRB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
     pub dl_forwarding_up_tnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 1)]
     pub ul_forwarding_up_tnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<DataForwardingResponseDRBItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct DataForwardingResponseDRBList(pub Vec<DataForwardingResponseDRBItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1318,22 +1552,29 @@ pub struct DataForwardingResponseDRBList(pub Vec<DataForwardingResponseDRBItem>)
 )]
 pub struct DataForwardingResponseERABList(pub Vec<DataForwardingResponseERABListItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DataForwardingResponseERABListItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub dl_forwarding_up_tnl_information: UPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DataForwardingResponseERABListItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DeactivateTrace {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DeactivateTraceProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct DelayCritical(pub u8);
 impl DelayCritical {
@@ -1341,88 +1582,117 @@ impl DelayCritical {
     pub const NON_DELAY_CRITICAL: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DirectForwardingPathAvailability(pub u8);
 impl DirectForwardingPathAvailability {
     pub const DIRECT_PATH_AVAILABLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkNASTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkNASTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkNonUEAssociatedNRPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkNonUEAssociatedNRPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRANConfigurationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkRANConfigurationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRANEarlyStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkRANEarlyStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRANStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkRANStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkRIMInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkRIMInformationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkUEAssociatedNRPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkUEAssociatedNRPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct Dynamic5QIDescriptor {
+    #[asn(tag = "[0] IMPLICIT")]
     pub priority_level_qos: PriorityLevelQos,
+    #[asn(tag = "[1] IMPLICIT")]
     pub packet_delay_budget: PacketDelayBudget,
+    #[asn(tag = "[2] IMPLICIT")]
     pub packet_error_rate: PacketErrorRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub five_qi: Option<FiveQI>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub delay_critical: Option<DelayCritical>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub averaging_window: Option<AveragingWindow>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 3)]
     pub maximum_data_burst_volume: Option<MaximumDataBurstVolume>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<Dynamic5QIDescriptorIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15", extensible = true)]
 pub struct E_RAB_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct E_RABInformationItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_forwarding: Option<DLForwarding>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<E_RABInformationItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1431,55 +1701,63 @@ pub struct E_RABInformationItem {
 )]
 pub struct E_RABInformationList(pub Vec<E_RABInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct EDT_Session(pub u8);
 impl EDT_Session {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct EN_DCSONConfigurationTransfer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum ENB_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     MacroENB_ID(BIT_STRING_18),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     HomeENB_ID(BIT_STRING_19),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Short_macroENB_ID(BIT_STRING_20),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Long_macroENB_ID(BIT_STRING_21),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Choice_Extensions(ENB_IDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct EPS_TAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EPS_TAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub eps_tac: EPS_TAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EPS_TAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EUTRA_CGI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub eutra_cell_identity: EUTRACellIdentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EUTRA_CGIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1488,7 +1766,7 @@ pub struct EUTRA_CGI {
 )]
 pub struct EUTRA_CGIList(pub Vec<EUTRA_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1497,31 +1775,34 @@ pub struct EUTRA_CGIList(pub Vec<EUTRA_CGI>);
 )]
 pub struct EUTRA_CGIListForWarning(pub Vec<EUTRA_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "28", sz_ub = "28")]
 pub struct EUTRACellIdentity(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct EUTRAencryptionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct EUTRAintegrityProtectionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EarlyStatusTransfer_TransparentContainer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub procedure_stage: ProcedureStageChoice,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EarlyStatusTransfer_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct EmergencyAreaID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1530,16 +1811,20 @@ pub struct EmergencyAreaID(pub Vec<u8>);
 )]
 pub struct EmergencyAreaIDBroadcastEUTRA(pub Vec<EmergencyAreaIDBroadcastEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDBroadcastEUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub emergency_area_id: EmergencyAreaID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub completed_cells_in_eai_eutra: CompletedCellsInEAI_EUTRA,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1548,16 +1833,20 @@ pub struct EmergencyAreaIDBroadcastEUTRA_Item {
 )]
 pub struct EmergencyAreaIDBroadcastNR(pub Vec<EmergencyAreaIDBroadcastNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDBroadcastNR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub emergency_area_id: EmergencyAreaID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub completed_cells_in_eai_nr: CompletedCellsInEAI_NR,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EmergencyAreaIDBroadcastNR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1566,16 +1855,20 @@ pub struct EmergencyAreaIDBroadcastNR_Item {
 )]
 pub struct EmergencyAreaIDCancelledEUTRA(pub Vec<EmergencyAreaIDCancelledEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDCancelledEUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub emergency_area_id: EmergencyAreaID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cancelled_cells_in_eai_eutra: CancelledCellsInEAI_EUTRA,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1584,16 +1877,20 @@ pub struct EmergencyAreaIDCancelledEUTRA_Item {
 )]
 pub struct EmergencyAreaIDCancelledNR(pub Vec<EmergencyAreaIDCancelledNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaIDCancelledNR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub emergency_area_id: EmergencyAreaID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cancelled_cells_in_eai_nr: CancelledCellsInEAI_NR,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EmergencyAreaIDCancelledNR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1602,7 +1899,7 @@ pub struct EmergencyAreaIDCancelledNR_Item {
 )]
 pub struct EmergencyAreaIDList(pub Vec<EmergencyAreaID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1611,24 +1908,27 @@ pub struct EmergencyAreaIDList(pub Vec<EmergencyAreaID>);
 )]
 pub struct EmergencyAreaIDListForRestart(pub Vec<EmergencyAreaID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct EmergencyFallbackIndicator {
+    #[asn(tag = "[0] IMPLICIT")]
     pub emergency_fallback_request_indicator: EmergencyFallbackRequestIndicator,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub emergency_service_target_cn: Option<EmergencyServiceTargetCN>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<EmergencyFallbackIndicatorIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct EmergencyFallbackRequestIndicator(pub u8);
 impl EmergencyFallbackRequestIndicator {
     pub const EMERGENCY_FALLBACK_REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct EmergencyServiceTargetCN(pub u8);
 impl EmergencyServiceTargetCN {
@@ -1636,7 +1936,7 @@ impl EmergencyServiceTargetCN {
     pub const EPC: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct EndIndication(pub u8);
 impl EndIndication {
@@ -1644,54 +1944,64 @@ impl EndIndication {
     pub const FURTHER_DATA_EXISTS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct EndpointIPAddressAndPort {
+    #[asn(tag = "[0] IMPLICIT")]
     pub endpoint_ip_address: TransportLayerAddress,
+    #[asn(tag = "[1] IMPLICIT")]
     pub port_number: PortNumber,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EndpointIPAddressAndPortIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Enhanced_CoverageRestriction(pub u8);
 impl Enhanced_CoverageRestriction {
     pub const RESTRICTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "15")]
 pub struct EquivalentPLMNs(pub Vec<PLMNIdentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ErrorIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ErrorIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EventL1LoggedMDTConfig {
+    #[asn(tag = "[0] EXPLICIT")]
     pub l1_threshold: MeasurementThresholdL1LoggedMDT,
+    #[asn(tag = "[1] IMPLICIT")]
     pub hysteresis: Hysteresis,
+    #[asn(tag = "[2] IMPLICIT")]
     pub time_to_trigger: TimeToTrigger,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EventL1LoggedMDTConfigIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum EventTrigger {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     OutOfCoverage(ENUMERATED_22),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     EventL1LoggedMDTConfig(EventL1LoggedMDTConfig),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(EventTriggerchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct EventType(pub u8);
 impl EventType {
@@ -1703,11 +2013,11 @@ impl EventType {
     pub const CANCEL_LOCATION_REPORTING_FOR_THE_UE: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "30", extensible = true)]
 pub struct ExpectedActivityPeriod(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "6")]
 pub struct ExpectedHOInterval(pub u8);
 impl ExpectedHOInterval {
@@ -1720,39 +2030,43 @@ impl ExpectedHOInterval {
     pub const LONG_TIME: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "30", extensible = true)]
 pub struct ExpectedIdlePeriod(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct ExpectedUEActivityBehaviour {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub expected_activity_period: Option<ExpectedActivityPeriod>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub expected_idle_period: Option<ExpectedIdlePeriod>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub source_of_ue_activity_behaviour_information: Option<SourceOfUEActivityBehaviourInformation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<ExpectedUEActivityBehaviourIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct ExpectedUEBehaviour {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub expected_ue_activity_behaviour: Option<ExpectedUEActivityBehaviour>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub expected_ho_interval: Option<ExpectedHOInterval>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub expected_ue_mobility: Option<ExpectedUEMobility>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub expected_ue_moving_trajectory: Option<ExpectedUEMovingTrajectory>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<ExpectedUEBehaviourIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ExpectedUEMobility(pub u8);
 impl ExpectedUEMobility {
@@ -1760,64 +2074,75 @@ impl ExpectedUEMobility {
     pub const MOBILE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ExpectedUEMovingTrajectory(pub Vec<ExpectedUEMovingTrajectoryItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct ExpectedUEMovingTrajectoryItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ngran_cgi: NGRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub time_stayed_in_cell: Option<INTEGER_23>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<ExpectedUEMovingTrajectoryItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct Extended_AMFName {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub amf_name_visible_string: Option<AMFNameVisibleString>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub amf_name_utf8_string: Option<AMFNameUTF8String>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<Extended_AMFNameIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct Extended_ConnectedTime(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct Extended_RANNodeName {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub ran_node_name_visible_string: Option<RANNodeNameVisibleString>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ran_node_name_utf8_string: Option<RANNodeNameUTF8String>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<Extended_RANNodeNameIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "65535", extensible = true)]
 pub struct ExtendedPacketDelayBudget(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ExtendedRATRestrictionInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub primary_rat_restriction: BIT_STRING_24,
+    #[asn(tag = "[1] IMPLICIT")]
     pub secondary_rat_restriction: BIT_STRING_25,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ExtendedRATRestrictionInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "4096", ub = "65535")]
 pub struct ExtendedRNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1826,58 +2151,73 @@ pub struct ExtendedRNC_ID(pub u16);
 )]
 pub struct ExtendedSliceSupportList(pub Vec<SliceSupportItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct ExtendedUEIdentityIndexValue(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct FailureIndication {
+    #[asn(tag = "[0] EXPLICIT")]
     pub uerlf_report_container: UERLFReportContainer,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<FailureIndicationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct FirstDLCount {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dr_bs_subject_to_early_status_transfer: DRBsSubjectToEarlyStatusTransfer_List,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<FirstDLCountIE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct FiveG_S_TMSI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub amf_set_id: AMFSetID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub amf_pointer: AMFPointer,
+    #[asn(tag = "[2] IMPLICIT")]
     pub five_g_tmsi: FiveG_TMSI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<FiveG_S_TMSIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct FiveG_TMSI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
 pub struct FiveQI(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ForbiddenAreaInformation(pub Vec<ForbiddenAreaInformation_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ForbiddenAreaInformation_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub forbidden_ta_cs: ForbiddenTACs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ForbiddenAreaInformation_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1886,79 +2226,99 @@ pub struct ForbiddenAreaInformation_Item {
 )]
 pub struct ForbiddenTACs(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct FromEUTRANtoNGRAN {
+    #[asn(tag = "[0] IMPLICIT")]
     pub sourcee_nbid: IntersystemSONeNBID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub target_ngra_nnode_id: IntersystemSONNGRANnodeID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<FromEUTRANtoNGRANIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct FromNGRANtoEUTRAN {
+    #[asn(tag = "[0] IMPLICIT")]
     pub source_ngra_nnode_id: IntersystemSONNGRANnodeID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub targete_nbid: IntersystemSONeNBID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<FromNGRANtoEUTRANIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct GBR_QosInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub maximum_flow_bit_rate_dl: BitRate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub maximum_flow_bit_rate_ul: BitRate,
+    #[asn(tag = "[2] IMPLICIT")]
     pub guaranteed_flow_bit_rate_dl: BitRate,
+    #[asn(tag = "[3] IMPLICIT")]
     pub guaranteed_flow_bit_rate_ul: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub notification_control: Option<NotificationControl>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 1)]
     pub maximum_packet_loss_rate_dl: Option<PacketLossRate>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 2)]
     pub maximum_packet_loss_rate_ul: Option<PacketLossRate>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<GBR_QosInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum GNB_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GNB_ID(BIT_STRING_26),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(GNB_IDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "22", sz_ub = "22")]
 pub struct GNBSetID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct GTP_TEID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GTPTunnel {
+    #[asn(tag = "[0] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[1] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GTPTunnelIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GUAMI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub amf_region_id: AMFRegionID,
+    #[asn(tag = "[2] IMPLICIT")]
     pub amf_set_id: AMFSetID,
+    #[asn(tag = "[3] IMPLICIT")]
     pub amf_pointer: AMFPointer,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GUAMIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct GUAMIType(pub u8);
 impl GUAMIType {
@@ -1966,263 +2326,345 @@ impl GUAMIType {
     pub const MAPPED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct GlobalCable_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalENB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNIdentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub enb_id: ENB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GlobalENB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalGNB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub gnb_id: GNB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GlobalGNB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct GlobalLine_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_line_identity: GlobalLineIdentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub line_type: Option<LineType>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<GlobalLine_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct GlobalLineIdentity(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalN3IWF_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub n3iwf_id: N3IWF_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GlobalN3IWF_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalNgENB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub ng_enb_id: NgENB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GlobalNgENB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum GlobalRANNodeID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GlobalGNB_ID(GlobalGNB_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     GlobalNgENB_ID(GlobalNgENB_ID),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     GlobalN3IWF_ID(GlobalN3IWF_ID),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Choice_Extensions(GlobalRANNodeIDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalTNGF_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub tngf_id: TNGF_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GlobalTNGF_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalTWIF_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub twif_id: TWIF_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GlobalTWIF_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GlobalW_AGF_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub w_agf_id: W_AGF_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GlobalW_AGF_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct HFCNode_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 6)]
 pub struct HOReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub handover_report_type: ENUMERATED_27,
+    #[asn(tag = "[1] EXPLICIT")]
     pub handover_cause: Cause,
+    #[asn(tag = "[2] EXPLICIT")]
     pub sourcecell_cgi: NGRAN_CGI,
+    #[asn(tag = "[3] EXPLICIT")]
     pub targetcell_cgi: NGRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] EXPLICIT", optional_idx = 0)]
     pub reestablishmentcell_cgi: Option<NGRAN_CGI>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 1)]
     pub sourcecell_c_rnti: Option<BIT_STRING_28>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 2)]
     pub targetcellin_e_utran: Option<EUTRA_CGI>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 3)]
     pub mobility_information: Option<MobilityInformation>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[8] EXPLICIT", optional_idx = 4)]
     pub uerlf_report_container: Option<UERLFReportContainer>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 5)]
     pub ie_extensions: Option<HOReportIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCancel {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverCancelProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCancelAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverCancelAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverCommandProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct HandoverCommandTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] EXPLICIT", optional_idx = 0)]
     pub dl_forwarding_up_tnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub qos_flow_to_be_forwarded_list: Option<QosFlowToBeForwardedList>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub data_forwarding_response_drb_list: Option<DataForwardingResponseDRBList>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<HandoverCommandTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct HandoverFlag(pub u8);
 impl HandoverFlag {
     pub const HANDOVER_PREPARATION: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverNotify {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverNotifyProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverPreparationFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverPreparationFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct HandoverPreparationUnsuccessfulTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<HandoverPreparationUnsuccessfulTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequestAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverRequestAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct HandoverRequestAcknowledgeTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub dl_ngu_up_tnl_information: UPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
     pub dl_forwarding_up_tnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub security_result: Option<SecurityResult>,
+    #[asn(tag = "[3] IMPLICIT")]
     pub qos_flow_setup_response_list: QosFlowListWithDataForwarding,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub qos_flow_failed_to_setup_list: Option<QosFlowListWithCause>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 3)]
     pub data_forwarding_response_drb_list: Option<DataForwardingResponseDRBList>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<HandoverRequestAcknowledgeTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequired {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverRequiredProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct HandoverRequiredTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub direct_forwarding_path_availability: Option<DirectForwardingPathAvailability>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<HandoverRequiredTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct HandoverResourceAllocationUnsuccessfulTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub criticality_diagnostics: Option<CriticalityDiagnostics>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<HandoverResourceAllocationUnsuccessfulTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverSuccess {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverSuccessProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct HandoverType(pub u8);
 impl HandoverType {
     pub const INTRA5GS: u8 = 0u8;
     pub const FIVEGS_TO_EPS: u8 = 1u8;
     pub const EPS_TO_5GS: u8 = 2u8;
+    pub const FIVEGS_TO_UTRAN: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "30")]
 pub struct Hysteresis(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct IAB_Authorized(pub u8);
 impl IAB_Authorized {
@@ -2230,21 +2672,21 @@ impl IAB_Authorized {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct IAB_Supported(pub u8);
 impl IAB_Supported {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct IABNodeIndication(pub u8);
 impl IABNodeIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct IMSVoiceSupportIndicator(pub u8);
 impl IMSVoiceSupportIndicator {
@@ -2252,79 +2694,100 @@ impl IMSVoiceSupportIndicator {
     pub const NOT_SUPPORTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 10)]
 pub struct ImmediateMDTNr {
+    #[asn(tag = "[0] IMPLICIT")]
     pub measurements_to_activate: MeasurementsToActivate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub m1_configuration: Option<M1Configuration>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub m4_configuration: Option<M4Configuration>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub m5_configuration: Option<M5Configuration>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub m6_configuration: Option<M6Configuration>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub m7_configuration: Option<M7Configuration>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 5)]
     pub bluetooth_measurement_configuration: Option<BluetoothMeasurementConfiguration>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 6)]
     pub wlan_measurement_configuration: Option<WLANMeasurementConfiguration>,
-    #[asn(optional_idx = 7)]
+    #[asn(tag = "[8] IMPLICIT", optional_idx = 7)]
     pub mdt_location_info: Option<MDT_Location_Info>,
-    #[asn(optional_idx = 8)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 8)]
     pub sensor_measurement_configuration: Option<SensorMeasurementConfiguration>,
-    #[asn(optional_idx = 9)]
+    #[asn(tag = "[10] IMPLICIT", optional_idx = 9)]
     pub ie_extensions: Option<ImmediateMDTNrIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "256", extensible = true)]
 pub struct IndexToRFSP(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InfoOnRecommendedCellsAndRANNodesForPaging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_cells_for_paging: RecommendedCellsForPaging,
+    #[asn(tag = "[1] IMPLICIT")]
     pub recommend_ran_nodes_for_paging: RecommendedRANNodesForPaging,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialContextSetupFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialContextSetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialContextSetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialUEMessage {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialUEMessageProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitiatingMessage {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitiatingMessageValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct IntegrityProtectionIndication(pub u8);
 impl IntegrityProtectionIndication {
@@ -2333,7 +2796,7 @@ impl IntegrityProtectionIndication {
     pub const NOT_NEEDED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct IntegrityProtectionResult(pub u8);
 impl IntegrityProtectionResult {
@@ -2341,204 +2804,244 @@ impl IntegrityProtectionResult {
     pub const NOT_PERFORMED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "16", extensible = true)]
 pub struct IntendedNumberOfPagingAttempts(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct InterSystemFailureIndication {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] EXPLICIT", optional_idx = 0)]
     pub uerlf_report_container: Option<UERLFReportContainer>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<InterSystemFailureIndicationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InterSystemHOReport {
+    #[asn(tag = "[0] EXPLICIT")]
     pub handover_report_type: InterSystemHandoverReportType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<InterSystemHOReportIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum InterSystemHandoverReportType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TooearlyIntersystemHO(TooearlyIntersystemHO),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     IntersystemUnnecessaryHO(IntersystemUnnecessaryHO),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(InterSystemHandoverReportTypechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct InterfacesToTrace(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemSONConfigurationTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub transfer_type: IntersystemSONTransferType,
+    #[asn(tag = "[1] EXPLICIT")]
     pub intersystem_son_information: IntersystemSONInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<IntersystemSONConfigurationTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum IntersystemSONInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] EXPLICIT")]
     IntersystemSONInformationReport(IntersystemSONInformationReport),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(IntersystemSONInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum IntersystemSONInformationReport {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     HOReportInformation(InterSystemHOReport),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     FailureIndicationInformation(InterSystemFailureIndication),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(IntersystemSONInformationReportchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemSONNGRANnodeID {
+    #[asn(tag = "[0] EXPLICIT")]
     pub global_ran_node_id: GlobalRANNodeID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<IntersystemSONNGRANnodeIDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum IntersystemSONTransferType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     FromEUTRANtoNGRAN(FromEUTRANtoNGRAN),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     FromNGRANtoEUTRAN(FromNGRANtoEUTRAN),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(IntersystemSONTransferTypechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemSONeNBID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub globale_nbid: GlobalENB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_epstai: EPS_TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<IntersystemSONeNBIDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct IntersystemUnnecessaryHO {
+    #[asn(tag = "[0] EXPLICIT")]
     pub sourcecell_id: NGRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub targetcell_id: EUTRA_CGI,
+    #[asn(tag = "[2] IMPLICIT")]
     pub early_iratho: ENUMERATED_29,
+    #[asn(tag = "[3] IMPLICIT")]
     pub candidate_cell_list: CandidateCellList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<IntersystemUnnecessaryHOIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct LAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub lac: LAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct LTEM_Indication(pub u8);
 impl LTEM_Indication {
     pub const LTE_M: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct LTEUERLFReportContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LTEUESidelinkAggregateMaximumBitrate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ue_sidelink_aggregate_maximum_bit_rate: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LTEUESidelinkAggregateMaximumBitrateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct LTEV2XServicesAuthorized {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub vehicle_ue: Option<VehicleUE>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub pedestrian_ue: Option<PedestrianUE>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<LTEV2XServicesAuthorizedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum LastVisitedCellInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     NGRANCell(LastVisitedNGRANCellInformation),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     EUTRANCell(LastVisitedEUTRANCellInformation),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     UTRANCell(LastVisitedUTRANCellInformation),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     GERANCell(LastVisitedGERANCellInformation),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Choice_Extensions(LastVisitedCellInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LastVisitedCellItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub last_visited_cell_information: LastVisitedCellInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LastVisitedCellItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedEUTRANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedGERANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct LastVisitedNGRANCellInformation {
+    #[asn(tag = "[0] EXPLICIT")]
     pub global_cell_id: NGRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cell_type: CellType,
+    #[asn(tag = "[2] IMPLICIT")]
     pub time_ue_stayed_in_cell: TimeUEStayedInCell,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub time_ue_stayed_in_cell_enhanced_granularity: Option<TimeUEStayedInCellEnhancedGranularity>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] EXPLICIT", optional_idx = 1)]
     pub ho_cause_value: Option<Cause>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<LastVisitedNGRANCellInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedUTRANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct LineType(pub u8);
 impl LineType {
@@ -2546,7 +3049,7 @@ impl LineType {
     pub const PON: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Links_to_log(pub u8);
 impl Links_to_log {
@@ -2555,78 +3058,96 @@ impl Links_to_log {
     pub const BOTH_UPLINK_AND_DOWNLINK: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct LocationReportingAdditionalInfo(pub u8);
 impl LocationReportingAdditionalInfo {
     pub const INCLUDE_PS_CELL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReportingControl {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportingControlProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReportingFailureIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportingFailureIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "64", extensible = true)]
 pub struct LocationReportingReferenceID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct LocationReportingRequestType {
+    #[asn(tag = "[0] IMPLICIT")]
     pub event_type: EventType,
+    #[asn(tag = "[1] IMPLICIT")]
     pub report_area: ReportArea,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub area_of_interest_list: Option<AreaOfInterestList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub location_reporting_reference_id_to_be_cancelled: Option<LocationReportingReferenceID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<LocationReportingRequestTypeIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct LoggedMDTNr {
+    #[asn(tag = "[0] IMPLICIT")]
     pub logging_interval: LoggingInterval,
+    #[asn(tag = "[1] IMPLICIT")]
     pub logging_duration: LoggingDuration,
+    #[asn(tag = "[2] EXPLICIT")]
     pub logged_mdt_trigger: LoggedMDTTrigger,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub bluetooth_measurement_configuration: Option<BluetoothMeasurementConfiguration>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub wlan_measurement_configuration: Option<WLANMeasurementConfiguration>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub sensor_measurement_configuration: Option<SensorMeasurementConfiguration>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 3)]
     pub area_scope_of_neigh_cells_list: Option<AreaScopeOfNeighCellsList>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<LoggedMDTNrIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum LoggedMDTTrigger {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Periodical(NULL_30),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] EXPLICIT")]
     EventTrigger(EventTrigger),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(LoggedMDTTriggerchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct LoggingDuration(pub u8);
 impl LoggingDuration {
@@ -2638,7 +3159,7 @@ impl LoggingDuration {
     pub const M120: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "10")]
 pub struct LoggingInterval(pub u8);
 impl LoggingInterval {
@@ -2655,28 +3176,35 @@ impl LoggingInterval {
     pub const INFINITY: u8 = 10u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct M1Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m1reporting_trigger: M1ReportingTrigger,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub m1threshold_event_a2: Option<M1ThresholdEventA2>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub m1periodic_reporting: Option<M1PeriodicReporting>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<M1ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M1PeriodicReporting {
+    #[asn(tag = "[0] IMPLICIT")]
     pub report_interval: ReportIntervalMDT,
+    #[asn(tag = "[1] IMPLICIT")]
     pub report_amount: ReportAmountMDT,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M1PeriodicReportingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct M1ReportingTrigger(pub u8);
 impl M1ReportingTrigger {
@@ -2685,37 +3213,44 @@ impl M1ReportingTrigger {
     pub const A2EVENTTRIGGERED_PERIODIC: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M1ThresholdEventA2 {
+    #[asn(tag = "[0] EXPLICIT")]
     pub m1_threshold_type: M1ThresholdType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M1ThresholdEventA2IE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum M1ThresholdType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Threshold_RSRP(Threshold_RSRP),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Threshold_RSRQ(Threshold_RSRQ),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Threshold_SINR(Threshold_SINR),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Choice_Extensions(M1ThresholdTypechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M4Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m4period: M4period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m4_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M4ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct M4period(pub u8);
 impl M4period {
@@ -2726,16 +3261,20 @@ impl M4period {
     pub const MIN1: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M5Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m5period: M5period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m5_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M5ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct M5period(pub u8);
 impl M5period {
@@ -2746,16 +3285,20 @@ impl M5period {
     pub const MIN1: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M6Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m6report_interval: M6report_Interval,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m6_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M6ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct M6report_Interval(pub u8);
 impl M6report_Interval {
@@ -2775,20 +3318,24 @@ impl M6report_Interval {
     pub const MIN30: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M7Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m7period: M7period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m7_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M7ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "60", extensible = true)]
 pub struct M7period(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct MDT_Activation(pub u8);
 impl MDT_Activation {
@@ -2797,88 +3344,103 @@ impl MDT_Activation {
     pub const IMMEDIATE_MDT_AND_TRACE: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct MDT_Configuration {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub mdt_config_nr: Option<MDT_Configuration_NR>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub mdt_config_eutra: Option<MDT_Configuration_EUTRA>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<MDT_ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct MDT_Configuration_EUTRA {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mdt_activation: MDT_Activation,
+    #[asn(tag = "[1] EXPLICIT")]
     pub area_scope_of_mdt: AreaScopeOfMDT_EUTRA,
+    #[asn(tag = "[2] IMPLICIT")]
     pub mdt_mode: MDTModeEutra,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub signalling_based_mdtplmn_list: Option<MDTPLMNList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<MDT_Configuration_EUTRAIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct MDT_Configuration_NR {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mdt_activation: MDT_Activation,
+    #[asn(tag = "[1] EXPLICIT")]
     pub area_scope_of_mdt: AreaScopeOfMDT_NR,
+    #[asn(tag = "[2] EXPLICIT")]
     pub mdt_mode_nr: MDTModeNr,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub signalling_based_mdtplmn_list: Option<MDTPLMNList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<MDT_Configuration_NRIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MDT_Location_Info {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mdt_location_information: MDT_Location_Information,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<MDT_Location_InfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct MDT_Location_Information(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct MDTModeEutra(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum MDTModeNr {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     ImmediateMDTNr(ImmediateMDTNr),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     LoggedMDTNr(LoggedMDTNr),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(MDTModeNrchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct MDTPLMNList(pub Vec<PLMNIdentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct MICOModeIndication(pub u8);
 impl MICOModeIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "64", sz_ub = "64")]
 pub struct MaskedIMEISV(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095", extensible = true)]
 pub struct MaximumDataBurstVolume(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MaximumIntegrityProtectedDataRate(pub u8);
 impl MaximumIntegrityProtectedDataRate {
@@ -2886,69 +3448,75 @@ impl MaximumIntegrityProtectedDataRate {
     pub const MAXIMUM_UE_RATE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum MeasurementThresholdL1LoggedMDT {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Threshold_RSRP(Threshold_RSRP),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Threshold_RSRQ(Threshold_RSRQ),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(MeasurementThresholdL1LoggedMDTchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct MeasurementsToActivate(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct MessageIdentifier(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct MobilityInformation(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct MobilityRestrictionList {
+    #[asn(tag = "[0] IMPLICIT")]
     pub serving_plmn: PLMNIdentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub equivalent_plm_ns: Option<EquivalentPLMNs>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub rat_restrictions: Option<RATRestrictions>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub forbidden_area_information: Option<ForbiddenAreaInformation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub service_area_information: Option<ServiceAreaInformation>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<MobilityRestrictionListIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum N3IWF_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     N3IWF_ID(BIT_STRING_31),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(N3IWF_IDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NAS_PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NASNonDeliveryIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NASNonDeliveryIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NASSecurityParametersFromNGRAN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct NB_IoT_DefaultPagingDRX(pub u8);
 impl NB_IoT_DefaultPagingDRX {
@@ -2958,7 +3526,7 @@ impl NB_IoT_DefaultPagingDRX {
     pub const RF1024: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "15")]
 pub struct NB_IoT_Paging_TimeWindow(pub u8);
 impl NB_IoT_Paging_TimeWindow {
@@ -2980,7 +3548,7 @@ impl NB_IoT_Paging_TimeWindow {
     pub const S16: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct NB_IoT_Paging_eDRXCycle(pub u8);
 impl NB_IoT_Paging_eDRXCycle {
@@ -3000,17 +3568,20 @@ impl NB_IoT_Paging_eDRXCycle {
     pub const HF1024: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct NB_IoT_Paging_eDRXInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nb_io_t_paging_e_drx_cycle: NB_IoT_Paging_eDRXCycle,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub nb_io_t_paging_time_window: Option<NB_IoT_Paging_TimeWindow>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<NB_IoT_Paging_eDRXInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct NB_IoT_PagingDRX(pub u8);
 impl NB_IoT_PagingDRX {
@@ -3022,132 +3593,160 @@ impl NB_IoT_PagingDRX {
     pub const RF1024: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
 pub struct NB_IoT_UEPriority(pub u8);
 
-#[derive(Debug, AperCodec)]
-#[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "2",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
 pub enum NGAP_PDU {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     InitiatingMessage(InitiatingMessage),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SuccessfulOutcome(SuccessfulOutcome),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     UnsuccessfulOutcome(UnsuccessfulOutcome),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum NGRAN_CGI {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     NR_CGI(NR_CGI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     EUTRA_CGI(EUTRA_CGI),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(NGRAN_CGIchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
 pub struct NGRAN_TNLAssociationToRemoveItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub tnl_association_transport_layer_address: CPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
     pub tnl_association_transport_layer_address_amf: Option<CPTransportLayerInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<NGRAN_TNLAssociationToRemoveItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct NGRAN_TNLAssociationToRemoveList(pub Vec<NGRAN_TNLAssociationToRemoveItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct NGRANTraceID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGReset {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NGResetProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGResetAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NGResetAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGSetupFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NGSetupFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGSetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NGSetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NGSetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NGSetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "44", sz_ub = "44")]
 pub struct NID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum NPN_AccessInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     PNI_NPN_Access_Information(CellCAGList),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(NPN_AccessInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum NPN_MobilityInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SNPN_MobilityInformation(SNPN_MobilityInformation),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     PNI_NPN_MobilityInformation(PNI_NPN_MobilityInformation),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(NPN_MobilityInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum NPN_PagingAssistanceInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     PNI_NPN_PagingAssistance(Allowed_PNI_NPN_List),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(NPN_PagingAssistanceInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum NPN_Support {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SNPN(NID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(NPN_Supportchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NR_CGI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub nr_cell_identity: NRCellIdentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<NR_CGIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3156,7 +3755,7 @@ pub struct NR_CGI {
 )]
 pub struct NR_CGIList(pub Vec<NR_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3165,98 +3764,110 @@ pub struct NR_CGIList(pub Vec<NR_CGI>);
 )]
 pub struct NR_CGIListForWarning(pub Vec<NR_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1007", extensible = true)]
 pub struct NR_PCI(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "3279165")]
 pub struct NRARFCN(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "36", sz_ub = "36")]
 pub struct NRCellIdentity(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "1024", extensible = true)]
 pub struct NRFrequencyBand(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct NRFrequencyBand_List(pub Vec<NRFrequencyBandItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRFrequencyBandItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_frequency_band: NRFrequencyBand,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<NRFrequencyBandItemIE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRFrequencyInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_arfcn: NRARFCN,
+    #[asn(tag = "[1] IMPLICIT")]
     pub frequency_band_list: NRFrequencyBand_List,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extension: Option<NRFrequencyInfoIE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NRMobilityHistoryReport(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NRPPa_PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NRUERLFReportContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRUESidelinkAggregateMaximumBitrate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ue_sidelink_aggregate_maximum_bit_rate: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<NRUESidelinkAggregateMaximumBitrateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct NRV2XServicesAuthorized {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub vehicle_ue: Option<VehicleUE>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub pedestrian_ue: Option<PedestrianUE>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<NRV2XServicesAuthorizedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct NRencryptionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct NRintegrityProtectionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "256", extensible = true)]
 pub struct NetworkInstance(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NewSecurityContextInd(pub u8);
 impl NewSecurityContextInd {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "7")]
 pub struct NextHopChainingCount(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct NextPagingAreaScope(pub u8);
 impl NextPagingAreaScope {
@@ -3264,38 +3875,41 @@ impl NextPagingAreaScope {
     pub const CHANGED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum NgENB_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     MacroNgENB_ID(BIT_STRING_32),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     ShortMacroNgENB_ID(BIT_STRING_33),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     LongMacroNgENB_ID(BIT_STRING_34),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Choice_Extensions(NgENB_IDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct NonDynamic5QIDescriptor {
+    #[asn(tag = "[0] IMPLICIT")]
     pub five_qi: FiveQI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub priority_level_qos: Option<PriorityLevelQos>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub averaging_window: Option<AveragingWindow>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub maximum_data_burst_volume: Option<MaximumDataBurstVolume>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<NonDynamic5QIDescriptorIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct NotAllowedTACs(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct NotificationCause(pub u8);
 impl NotificationCause {
@@ -3303,29 +3917,29 @@ impl NotificationCause {
     pub const NOT_FULFILLED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NotificationControl(pub u8);
 impl NotificationControl {
     pub const NOTIFICATION_REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NotifySourceNGRANNode(pub u8);
 impl NotifySourceNGRANNode {
     pub const NOTIFY_SOURCE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct NumberOfBroadcasts(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct NumberOfBroadcastsRequested(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct OverloadAction(pub u8);
 impl OverloadAction {
@@ -3335,34 +3949,40 @@ impl OverloadAction {
     pub const PERMIT_HIGH_PRIORITY_SESSIONS_AND_MOBILE_TERMINATED_SERVICES_ONLY: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum OverloadResponse {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     OverloadAction(OverloadAction),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(OverloadResponsechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct OverloadStart {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: OverloadStartProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct OverloadStartNSSAIItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub slice_overload_list: SliceOverloadList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
     pub slice_overload_response: Option<OverloadResponse>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub slice_traffic_load_reduction_indication: Option<TrafficLoadReductionIndication>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<OverloadStartNSSAIItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3371,34 +3991,44 @@ pub struct OverloadStartNSSAIItem {
 )]
 pub struct OverloadStartNSSAIList(pub Vec<OverloadStartNSSAIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct OverloadStop {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: OverloadStopProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PC5FlowBitRates {
+    #[asn(tag = "[0] IMPLICIT")]
     pub guaranteed_flow_bit_rate: BitRate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub maximum_flow_bit_rate: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PC5FlowBitRatesIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PC5QoSFlowItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pqi: FiveQI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub pc5_flow_bit_rates: Option<PC5FlowBitRates>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub range: Option<Range>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<PC5QoSFlowItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3407,43 +4037,54 @@ pub struct PC5QoSFlowItem {
 )]
 pub struct PC5QoSFlowList(pub Vec<PC5QoSFlowItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PC5QoSParameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pc5_qo_s_flow_list: PC5QoSFlowList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub pc5_link_aggregate_bit_rates: Option<BitRate>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PC5QoSParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct PCIListForMDT(pub Vec<NR_PCI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionAggregateMaximumBitRate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_aggregate_maximum_bit_rate_dl: BitRate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_aggregate_maximum_bit_rate_ul: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionAggregateMaximumBitRateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct PDUSessionID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceAdmittedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub handover_request_acknowledge_transfer: OCTET_STRING_35,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceAdmittedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3452,25 +4093,33 @@ pub struct PDUSessionResourceAdmittedItem {
 )]
 pub struct PDUSessionResourceAdmittedList(pub Vec<PDUSessionResourceAdmittedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToModifyItemModCfm {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_modify_indication_unsuccessful_transfer: OCTET_STRING_36,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToModifyItemModCfmIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToModifyItemModRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_modify_unsuccessful_transfer: OCTET_STRING_37,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToModifyItemModResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3481,7 +4130,7 @@ pub struct PDUSessionResourceFailedToModifyListModCfm(
     pub Vec<PDUSessionResourceFailedToModifyItemModCfm>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3492,25 +4141,33 @@ pub struct PDUSessionResourceFailedToModifyListModRes(
     pub Vec<PDUSessionResourceFailedToModifyItemModRes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToResumeItemRESReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToResumeItemRESReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToResumeItemRESRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToResumeItemRESResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3521,7 +4178,7 @@ pub struct PDUSessionResourceFailedToResumeListRESReq(
     pub Vec<PDUSessionResourceFailedToResumeItemRESReq>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3532,52 +4189,72 @@ pub struct PDUSessionResourceFailedToResumeListRESRes(
     pub Vec<PDUSessionResourceFailedToResumeItemRESRes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemCxtFail {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_setup_unsuccessful_transfer: OCTET_STRING_38,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemCxtRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_setup_unsuccessful_transfer: OCTET_STRING_39,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemCxtResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemHOAck {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub handover_resource_allocation_unsuccessful_transfer: OCTET_STRING_40,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemHOAckIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemPSReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub path_switch_request_setup_failed_transfer: OCTET_STRING_41,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemPSReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceFailedToSetupItemSURes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_setup_unsuccessful_transfer: OCTET_STRING_42,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceFailedToSetupItemSUResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3588,7 +4265,7 @@ pub struct PDUSessionResourceFailedToSetupListCxtFail(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtFail>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3599,7 +4276,7 @@ pub struct PDUSessionResourceFailedToSetupListCxtRes(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtRes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3610,7 +4287,7 @@ pub struct PDUSessionResourceFailedToSetupListHOAck(
     pub Vec<PDUSessionResourceFailedToSetupItemHOAck>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3621,7 +4298,7 @@ pub struct PDUSessionResourceFailedToSetupListPSReq(
     pub Vec<PDUSessionResourceFailedToSetupItemPSReq>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3632,16 +4309,20 @@ pub struct PDUSessionResourceFailedToSetupListSURes(
     pub Vec<PDUSessionResourceFailedToSetupItemSURes>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceHandoverItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub handover_command_transfer: OCTET_STRING_43,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceHandoverItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3650,18 +4331,22 @@ pub struct PDUSessionResourceHandoverItem {
 )]
 pub struct PDUSessionResourceHandoverList(pub Vec<PDUSessionResourceHandoverItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceInformationItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub qos_flow_information_list: QosFlowInformationList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub dr_bs_to_qos_flows_mapping_list: Option<DRBsToQosFlowsMappingList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceInformationItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3670,32 +4355,42 @@ pub struct PDUSessionResourceInformationItem {
 )]
 pub struct PDUSessionResourceInformationList(pub Vec<PDUSessionResourceInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceItemCxtRelCpl {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceItemCxtRelCplIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceItemCxtRelReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceItemCxtRelReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceItemHORqd {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub handover_required_transfer: OCTET_STRING_44,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceItemHORqdIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3704,7 +4399,7 @@ pub struct PDUSessionResourceItemHORqd {
 )]
 pub struct PDUSessionResourceListCxtRelCpl(pub Vec<PDUSessionResourceItemCxtRelCpl>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3713,7 +4408,7 @@ pub struct PDUSessionResourceListCxtRelCpl(pub Vec<PDUSessionResourceItemCxtRelC
 )]
 pub struct PDUSessionResourceListCxtRelReq(pub Vec<PDUSessionResourceItemCxtRelReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3722,88 +4417,120 @@ pub struct PDUSessionResourceListCxtRelReq(pub Vec<PDUSessionResourceItemCxtRelR
 )]
 pub struct PDUSessionResourceListHORqd(pub Vec<PDUSessionResourceItemHORqd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyConfirm {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceModifyConfirmProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PDUSessionResourceModifyConfirmTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_modify_confirm_list: QosFlowModifyConfirmList,
+    #[asn(tag = "[1] EXPLICIT")]
     pub ulngu_up_tnl_information: UPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub additional_ng_uuptnl_information: Option<UPTransportLayerInformationPairList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub qos_flow_failed_to_modify_list: Option<QosFlowListWithCause>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<PDUSessionResourceModifyConfirmTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceModifyIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceModifyIndicationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_qos_flow_per_tnl_information: QosFlowPerTNLInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub additional_dl_qos_flow_per_tnl_information: Option<QosFlowPerTNLInformationList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceModifyIndicationTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyIndicationUnsuccessfulTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyItemModCfm {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_modify_confirm_transfer: OCTET_STRING_45,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModCfmIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyItemModInd {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_modify_indication_transfer: OCTET_STRING_46,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModIndIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceModifyItemModReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub nas_pdu: Option<NAS_PDU>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub pdu_session_resource_modify_request_transfer: OCTET_STRING_47,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceModifyItemModRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_modify_response_transfer: OCTET_STRING_48,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceModifyItemModResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3812,7 +4539,7 @@ pub struct PDUSessionResourceModifyItemModRes {
 )]
 pub struct PDUSessionResourceModifyListModCfm(pub Vec<PDUSessionResourceModifyItemModCfm>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3821,7 +4548,7 @@ pub struct PDUSessionResourceModifyListModCfm(pub Vec<PDUSessionResourceModifyIt
 )]
 pub struct PDUSessionResourceModifyListModInd(pub Vec<PDUSessionResourceModifyItemModInd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3830,7 +4557,7 @@ pub struct PDUSessionResourceModifyListModInd(pub Vec<PDUSessionResourceModifyIt
 )]
 pub struct PDUSessionResourceModifyListModReq(pub Vec<PDUSessionResourceModifyItemModReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3839,67 +4566,88 @@ pub struct PDUSessionResourceModifyListModReq(pub Vec<PDUSessionResourceModifyIt
 )]
 pub struct PDUSessionResourceModifyListModRes(pub Vec<PDUSessionResourceModifyItemModRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceModifyRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyRequestTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceModifyRequestTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceModifyResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceModifyResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 6)]
 pub struct PDUSessionResourceModifyResponseTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] EXPLICIT", optional_idx = 0)]
     pub dl_ngu_up_tnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 1)]
     pub ul_ngu_up_tnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub qos_flow_add_or_modify_response_list: Option<QosFlowAddOrModifyResponseList>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub additional_dl_qos_flow_per_tnl_information: Option<QosFlowPerTNLInformationList>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub qos_flow_failed_to_add_or_modify_list: Option<QosFlowListWithCause>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 5)]
     pub ie_extensions: Option<PDUSessionResourceModifyResponseTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceModifyUnsuccessfulTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub criticality_diagnostics: Option<CriticalityDiagnostics>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceNotify {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceNotifyProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceNotifyItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_notify_transfer: OCTET_STRING_49,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceNotifyItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3908,89 +4656,121 @@ pub struct PDUSessionResourceNotifyItem {
 )]
 pub struct PDUSessionResourceNotifyList(pub Vec<PDUSessionResourceNotifyItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceNotifyReleasedTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceNotifyReleasedTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PDUSessionResourceNotifyTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub qos_flow_notify_list: Option<QosFlowNotifyList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub qos_flow_released_list: Option<QosFlowListWithCause>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<PDUSessionResourceNotifyTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceReleaseCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceReleaseCommandProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleaseCommandTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleaseCommandTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceReleaseResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceReleaseResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleaseResponseTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleaseResponseTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemNot {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_notify_released_transfer: OCTET_STRING_50,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemNotIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemPSAck {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub path_switch_request_unsuccessful_transfer: OCTET_STRING_51,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemPSAckIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemPSFail {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub path_switch_request_unsuccessful_transfer: OCTET_STRING_52,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemPSFailIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceReleasedItemRelRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_release_response_transfer: OCTET_STRING_53,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceReleasedItemRelResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3999,7 +4779,7 @@ pub struct PDUSessionResourceReleasedItemRelRes {
 )]
 pub struct PDUSessionResourceReleasedListNot(pub Vec<PDUSessionResourceReleasedItemNot>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4008,7 +4788,7 @@ pub struct PDUSessionResourceReleasedListNot(pub Vec<PDUSessionResourceReleasedI
 )]
 pub struct PDUSessionResourceReleasedListPSAck(pub Vec<PDUSessionResourceReleasedItemPSAck>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4017,7 +4797,7 @@ pub struct PDUSessionResourceReleasedListPSAck(pub Vec<PDUSessionResourceRelease
 )]
 pub struct PDUSessionResourceReleasedListPSFail(pub Vec<PDUSessionResourceReleasedItemPSFail>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4026,25 +4806,33 @@ pub struct PDUSessionResourceReleasedListPSFail(pub Vec<PDUSessionResourceReleas
 )]
 pub struct PDUSessionResourceReleasedListRelRes(pub Vec<PDUSessionResourceReleasedItemRelRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceResumeItemRESReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ue_context_resume_request_transfer: OCTET_STRING_54,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceResumeItemRESReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceResumeItemRESRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ue_context_resume_response_transfer: OCTET_STRING_55,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceResumeItemRESResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4053,7 +4841,7 @@ pub struct PDUSessionResourceResumeItemRESRes {
 )]
 pub struct PDUSessionResourceResumeListRESReq(pub Vec<PDUSessionResourceResumeItemRESReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4062,16 +4850,20 @@ pub struct PDUSessionResourceResumeListRESReq(pub Vec<PDUSessionResourceResumeIt
 )]
 pub struct PDUSessionResourceResumeListRESRes(pub Vec<PDUSessionResourceResumeItemRESRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSecondaryRATUsageItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub secondary_rat_data_usage_report_transfer: OCTET_STRING_56,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSecondaryRATUsageItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4082,59 +4874,82 @@ pub struct PDUSessionResourceSecondaryRATUsageList(
     pub Vec<PDUSessionResourceSecondaryRATUsageItem>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceSetupItemCxtReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub nas_pdu: Option<NAS_PDU>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub s_nssai: S_NSSAI,
+    #[asn(tag = "[3] IMPLICIT")]
     pub pdu_session_resource_setup_request_transfer: OCTET_STRING_57,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemCxtReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSetupItemCxtRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_setup_response_transfer: OCTET_STRING_58,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemCxtResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSetupItemHOReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub s_nssai: S_NSSAI,
+    #[asn(tag = "[2] IMPLICIT")]
     pub handover_request_transfer: OCTET_STRING_59,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemHOReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceSetupItemSUReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub pdu_session_nas_pdu: Option<NAS_PDU>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub s_nssai: S_NSSAI,
+    #[asn(tag = "[3] IMPLICIT")]
     pub pdu_session_resource_setup_request_transfer: OCTET_STRING_60,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemSUReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSetupItemSURes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_setup_response_transfer: OCTET_STRING_61,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSetupItemSUResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4143,7 +4958,7 @@ pub struct PDUSessionResourceSetupItemSURes {
 )]
 pub struct PDUSessionResourceSetupListCxtReq(pub Vec<PDUSessionResourceSetupItemCxtReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4152,7 +4967,7 @@ pub struct PDUSessionResourceSetupListCxtReq(pub Vec<PDUSessionResourceSetupItem
 )]
 pub struct PDUSessionResourceSetupListCxtRes(pub Vec<PDUSessionResourceSetupItemCxtRes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4161,7 +4976,7 @@ pub struct PDUSessionResourceSetupListCxtRes(pub Vec<PDUSessionResourceSetupItem
 )]
 pub struct PDUSessionResourceSetupListHOReq(pub Vec<PDUSessionResourceSetupItemHOReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4170,7 +4985,7 @@ pub struct PDUSessionResourceSetupListHOReq(pub Vec<PDUSessionResourceSetupItemH
 )]
 pub struct PDUSessionResourceSetupListSUReq(pub Vec<PDUSessionResourceSetupItemSUReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4179,58 +4994,77 @@ pub struct PDUSessionResourceSetupListSUReq(pub Vec<PDUSessionResourceSetupItemS
 )]
 pub struct PDUSessionResourceSetupListSURes(pub Vec<PDUSessionResourceSetupItemSURes>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceSetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceSetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceSetupRequestTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceSetupRequestTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PDUSessionResourceSetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PDUSessionResourceSetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct PDUSessionResourceSetupResponseTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_qos_flow_per_tnl_information: QosFlowPerTNLInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub additional_dl_qos_flow_per_tnl_information: Option<QosFlowPerTNLInformationList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub security_result: Option<SecurityResult>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub qos_flow_failed_to_setup_list: Option<QosFlowListWithCause>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<PDUSessionResourceSetupResponseTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PDUSessionResourceSetupUnsuccessfulTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub criticality_diagnostics: Option<CriticalityDiagnostics>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSuspendItemSUSReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ue_context_suspend_request_transfer: OCTET_STRING_62,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSuspendItemSUSReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4239,16 +5073,20 @@ pub struct PDUSessionResourceSuspendItemSUSReq {
 )]
 pub struct PDUSessionResourceSuspendListSUSReq(pub Vec<PDUSessionResourceSuspendItemSUSReq>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceSwitchedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub path_switch_request_acknowledge_transfer: OCTET_STRING_63,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceSwitchedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4257,16 +5095,20 @@ pub struct PDUSessionResourceSwitchedItem {
 )]
 pub struct PDUSessionResourceSwitchedList(pub Vec<PDUSessionResourceSwitchedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceToBeSwitchedDLItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub path_switch_request_transfer: OCTET_STRING_64,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceToBeSwitchedDLItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4275,25 +5117,33 @@ pub struct PDUSessionResourceToBeSwitchedDLItem {
 )]
 pub struct PDUSessionResourceToBeSwitchedDLList(pub Vec<PDUSessionResourceToBeSwitchedDLItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceToReleaseItemHOCmd {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub handover_preparation_unsuccessful_transfer: OCTET_STRING_65,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceToReleaseItemHOCmdIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionResourceToReleaseItemRelCmd {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdu_session_id: PDUSessionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_resource_release_command_transfer: OCTET_STRING_66,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionResourceToReleaseItemRelCmdIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4302,7 +5152,7 @@ pub struct PDUSessionResourceToReleaseItemRelCmd {
 )]
 pub struct PDUSessionResourceToReleaseListHOCmd(pub Vec<PDUSessionResourceToReleaseItemHOCmd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4311,7 +5161,7 @@ pub struct PDUSessionResourceToReleaseListHOCmd(pub Vec<PDUSessionResourceToRele
 )]
 pub struct PDUSessionResourceToReleaseListRelCmd(pub Vec<PDUSessionResourceToReleaseItemRelCmd>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct PDUSessionType(pub u8);
 impl PDUSessionType {
@@ -4322,99 +5172,129 @@ impl PDUSessionType {
     pub const UNSTRUCTURED: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PDUSessionUsageReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rat_type: ENUMERATED_67,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pdu_session_timed_report_list: VolumeTimedReportList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PDUSessionUsageReportIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct PLMNIdentity(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PLMNSupportItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub slice_support_list: SliceSupportList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PLMNSupportItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "12")]
 pub struct PLMNSupportList(pub Vec<PLMNSupportItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PNI_NPN_MobilityInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub allowed_pni_npi_list: Allowed_PNI_NPN_List,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PNI_NPN_MobilityInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSCancelRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PWSCancelRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSCancelResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PWSCancelResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum PWSFailedCellIDList {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     EUTRA_CGI_PWSFailedList(EUTRA_CGIList),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     NR_CGI_PWSFailedList(NR_CGIList),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(PWSFailedCellIDListchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSFailureIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PWSFailureIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSRestartIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PWSRestartIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1023", extensible = true)]
 pub struct PacketDelayBudget(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PacketErrorRate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub per_scalar: INTEGER_68,
+    #[asn(tag = "[1] IMPLICIT")]
     pub per_exponent: INTEGER_69,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PacketErrorRateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1000", extensible = true)]
 pub struct PacketLossRate(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct Paging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PagingProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "15")]
 pub struct Paging_Time_Window(pub u8);
 impl Paging_Time_Window {
@@ -4436,7 +5316,7 @@ impl Paging_Time_Window {
     pub const S16: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct Paging_eDRX_Cycle(pub u8);
 impl Paging_eDRX_Cycle {
@@ -4456,31 +5336,39 @@ impl Paging_eDRX_Cycle {
     pub const HF256: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PagingAssisDataforCEcapabUE {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub coverage_enhancement_level: CoverageEnhancementLevel,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PagingAssisDataforCEcapabUEIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "16", extensible = true)]
 pub struct PagingAttemptCount(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PagingAttemptInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub paging_attempt_count: PagingAttemptCount,
+    #[asn(tag = "[1] IMPLICIT")]
     pub intended_number_of_paging_attempts: IntendedNumberOfPagingAttempts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub next_paging_area_scope: Option<NextPagingAreaScope>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PagingAttemptInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct PagingDRX(pub u8);
 impl PagingDRX {
@@ -4490,14 +5378,14 @@ impl PagingDRX {
     pub const V256: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct PagingOrigin(pub u8);
 impl PagingOrigin {
     pub const NON_3GPP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct PagingPriority(pub u8);
 impl PagingPriority {
@@ -4511,7 +5399,7 @@ impl PagingPriority {
     pub const PRIOLEVEL8: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "20")]
 pub struct PagingProbabilityInformation(pub u8);
 impl PagingProbabilityInformation {
@@ -4538,75 +5426,99 @@ impl PagingProbabilityInformation {
     pub const P100: u8 = 20u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PagingeDRXInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub paging_e_drx_cycle: Paging_eDRX_Cycle,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub paging_time_window: Option<Paging_Time_Window>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PagingeDRXInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PathSwitchRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequestAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PathSwitchRequestAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PathSwitchRequestAcknowledgeTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] EXPLICIT", optional_idx = 0)]
     pub ul_ngu_up_tnl_information: Option<UPTransportLayerInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub security_indication: Option<SecurityIndication>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<PathSwitchRequestAcknowledgeTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequestFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PathSwitchRequestFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PathSwitchRequestSetupFailedTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PathSwitchRequestSetupFailedTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PathSwitchRequestTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub dl_ngu_up_tnl_information: UPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_ngu_tnl_information_reused: Option<DL_NGU_TNLInformationReused>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub user_plane_security_information: Option<UserPlaneSecurityInformation>,
+    #[asn(tag = "[3] IMPLICIT")]
     pub qos_flow_accepted_list: QosFlowAcceptedList,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<PathSwitchRequestTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PathSwitchRequestUnsuccessfulTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PathSwitchRequestUnsuccessfulTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PedestrianUE(pub u8);
 impl PedestrianUE {
@@ -4614,19 +5526,19 @@ impl PedestrianUE {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct PeriodicRegistrationUpdateTimer(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "640000", extensible = true)]
 pub struct Periodicity(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct PortNumber(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct Pre_emptionCapability(pub u8);
 impl Pre_emptionCapability {
@@ -4634,7 +5546,7 @@ impl Pre_emptionCapability {
     pub const MAY_TRIGGER_PRE_EMPTION: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct Pre_emptionVulnerability(pub u8);
 impl Pre_emptionVulnerability {
@@ -4642,7 +5554,7 @@ impl Pre_emptionVulnerability {
     pub const PRE_EMPTABLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Presence(pub u8);
 impl Presence {
@@ -4651,15 +5563,15 @@ impl Presence {
     pub const MANDATORY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "15")]
 pub struct PriorityLevelARP(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "127", extensible = true)]
 pub struct PriorityLevelQos(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PrivacyIndicator(pub u8);
 impl PrivacyIndicator {
@@ -4667,314 +5579,385 @@ impl PrivacyIndicator {
     pub const LOGGED_MDT: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum PrivateIE_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Local(INTEGER_70),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Global(OBJECT_IDENTIFIER_71),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PrivateMessage {
+    #[asn(tag = "[0] IMPLICIT")]
     pub private_i_es: PrivateMessagePrivateIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct ProcedureCode(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum ProcedureStageChoice {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     First_dl_count(FirstDLCount),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(ProcedureStageChoicechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolExtensionID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolIE_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QoSFlowsUsageReport_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rat_type: ENUMERATED_72,
+    #[asn(tag = "[2] IMPLICIT")]
     pub qo_s_flows_timed_report_list: VolumeTimedReportList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QoSFlowsUsageReport_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QoSFlowsUsageReportList(pub Vec<QoSFlowsUsageReport_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum QosCharacteristics {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     NonDynamic5QI(NonDynamic5QIDescriptor),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Dynamic5QI(Dynamic5QIDescriptor),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(QosCharacteristicschoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowAcceptedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowAcceptedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowAcceptedList(pub Vec<QosFlowAcceptedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct QosFlowAddOrModifyRequestItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub qos_flow_level_qos_parameters: Option<QosFlowLevelQosParameters>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub e_rab_id: Option<E_RAB_ID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<QosFlowAddOrModifyRequestItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowAddOrModifyRequestList(pub Vec<QosFlowAddOrModifyRequestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowAddOrModifyResponseItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowAddOrModifyResponseItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowAddOrModifyResponseList(pub Vec<QosFlowAddOrModifyResponseItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct QosFlowFeedbackItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub update_feedback: Option<UpdateFeedback>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub c_npacket_delay_budget_dl: Option<ExtendedPacketDelayBudget>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub c_npacket_delay_budget_ul: Option<ExtendedPacketDelayBudget>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<QosFlowFeedbackItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowFeedbackList(pub Vec<QosFlowFeedbackItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "63", extensible = true)]
 pub struct QosFlowIdentifier(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowInformationItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_forwarding: Option<DLForwarding>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<QosFlowInformationItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowInformationList(pub Vec<QosFlowInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowItemWithDataForwarding {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub data_forwarding_accepted: Option<DataForwardingAccepted>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<QosFlowItemWithDataForwardingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct QosFlowLevelQosParameters {
+    #[asn(tag = "[0] EXPLICIT")]
     pub qos_characteristics: QosCharacteristics,
+    #[asn(tag = "[1] IMPLICIT")]
     pub allocation_and_retention_priority: AllocationAndRetentionPriority,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub gbr_qos_information: Option<GBR_QosInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub reflective_qos_attribute: Option<ReflectiveQosAttribute>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub additional_qos_flow_information: Option<AdditionalQosFlowInformation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<QosFlowLevelQosParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowListWithCause(pub Vec<QosFlowWithCauseItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowListWithDataForwarding(pub Vec<QosFlowItemWithDataForwarding>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowModifyConfirmItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowModifyConfirmItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowModifyConfirmList(pub Vec<QosFlowModifyConfirmItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowNotifyItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
+    #[asn(tag = "[1] IMPLICIT")]
     pub notification_cause: NotificationCause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowNotifyItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowNotifyList(pub Vec<QosFlowNotifyItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowParametersItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub alternative_qo_s_para_set_list: Option<AlternativeQoSParaSetList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<QosFlowParametersItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowParametersList(pub Vec<QosFlowParametersItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowPerTNLInformation {
+    #[asn(tag = "[0] EXPLICIT")]
     pub up_transport_layer_information: UPTransportLayerInformation,
+    #[asn(tag = "[1] IMPLICIT")]
     pub associated_qos_flow_list: AssociatedQosFlowList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowPerTNLInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowPerTNLInformationItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_per_tnl_information: QosFlowPerTNLInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowPerTNLInformationItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct QosFlowPerTNLInformationList(pub Vec<QosFlowPerTNLInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct QosFlowSetupRequestItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
+    #[asn(tag = "[1] IMPLICIT")]
     pub qos_flow_level_qos_parameters: QosFlowLevelQosParameters,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub e_rab_id: Option<E_RAB_ID>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<QosFlowSetupRequestItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowSetupRequestList(pub Vec<QosFlowSetupRequestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowToBeForwardedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowToBeForwardedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct QosFlowToBeForwardedList(pub Vec<QosFlowToBeForwardedItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct QosFlowWithCauseItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qos_flow_identifier: QosFlowIdentifier,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<QosFlowWithCauseItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "1800", extensible = true)]
 pub struct QosMonitoringReportingFrequency(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct QosMonitoringRequest(pub u8);
 impl QosMonitoringRequest {
     pub const UL: u8 = 0u8;
     pub const DL: u8 = 1u8;
     pub const BOTH: u8 = 2u8;
+    pub const STOP: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
 pub struct RAN_UE_NGAP_ID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANCPRelocationIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RANCPRelocationIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANConfigurationUpdate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RANConfigurationUpdateProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANConfigurationUpdateAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RANConfigurationUpdateAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RANConfigurationUpdateFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RANConfigurationUpdateFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "PrintableString",
     sz_extensible = true,
@@ -4983,11 +5966,11 @@ pub struct RANConfigurationUpdateFailure {
 )]
 pub struct RANNodeName(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "UTF8String", sz_extensible = true, sz_lb = "1", sz_ub = "150")]
 pub struct RANNodeNameUTF8String(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "VisibleString",
     sz_extensible = true,
@@ -4996,19 +5979,22 @@ pub struct RANNodeNameUTF8String(pub String);
 )]
 pub struct RANNodeNameVisibleString(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "256")]
 pub struct RANPagingPriority(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RANStatusTransfer_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dr_bs_subject_to_status_transfer_list: DRBsSubjectToStatusTransferList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RANStatusTransfer_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RAT_Information(pub u8);
 impl RAT_Information {
@@ -5016,55 +6002,68 @@ impl RAT_Information {
     pub const NB_IO_T: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
 pub struct RATRestrictionInformation(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RATRestrictions(pub Vec<RATRestrictions_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RATRestrictions_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rat_restriction_information: RATRestrictionInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RATRestrictions_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct RGLevelWirelineAccessCharacteristics(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RIMInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub targetg_nb_set_id: GNBSetID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rim_rs_detection: ENUMERATED_73,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RIMInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RIMInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub target_ran_node_id: TargetRANNodeID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub source_ran_node_id: SourceRANNodeID,
+    #[asn(tag = "[2] IMPLICIT")]
     pub rim_information: RIMInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RIMInformationTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct RNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct RRCContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "9")]
 pub struct RRCEstablishmentCause(pub u8);
 impl RRCEstablishmentCause {
@@ -5078,15 +6077,20 @@ impl RRCEstablishmentCause {
     pub const MO_SMS: u8 = 7u8;
     pub const MPS_PRIORITY_ACCESS: u8 = 8u8;
     pub const MCS_PRIORITY_ACCESS: u8 = 9u8;
+    pub const NOT_AVAILABLE: u8 = 10u8;
+    pub const MO_EXCEPTION_DATA: u8 = 11u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RRCInactiveTransitionReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RRCInactiveTransitionReportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct RRCInactiveTransitionReportRequest(pub u8);
 impl RRCInactiveTransitionReportRequest {
@@ -5095,7 +6099,7 @@ impl RRCInactiveTransitionReportRequest {
     pub const CANCEL_REPORT: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RRCState(pub u8);
 impl RRCState {
@@ -5103,7 +6107,7 @@ impl RRCState {
     pub const CONNECTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RSN(pub u8);
 impl RSN {
@@ -5111,7 +6115,7 @@ impl RSN {
     pub const V2: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "8")]
 pub struct Range(pub u8);
 impl Range {
@@ -5126,49 +6130,61 @@ impl Range {
     pub const M1000: u8 = 8u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct RecommendedCellItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ngran_cgi: NGRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub time_stayed_in_cell: Option<INTEGER_74>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RecommendedCellItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RecommendedCellList(pub Vec<RecommendedCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedCellsForPaging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_cell_list: RecommendedCellList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RecommendedCellsForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedRANNodeItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub amf_paging_target: AMFPagingTarget,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RecommendedRANNodeItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RecommendedRANNodeList(pub Vec<RecommendedRANNodeItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedRANNodesForPaging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_ran_node_list: RecommendedRANNodeList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RecommendedRANNodesForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RedirectionVoiceFallback(pub u8);
 impl RedirectionVoiceFallback {
@@ -5176,15 +6192,18 @@ impl RedirectionVoiceFallback {
     pub const NOT_POSSIBLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RedundantPDUSessionInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rsn: RSN,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RedundantPDUSessionInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct RedundantQosFlowIndicator(pub u8);
 impl RedundantQosFlowIndicator {
@@ -5192,14 +6211,14 @@ impl RedundantQosFlowIndicator {
     pub const FALSE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ReflectiveQosAttribute(pub u8);
 impl ReflectiveQosAttribute {
     pub const SUBJECT_TO: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5208,7 +6227,7 @@ impl ReflectiveQosAttribute {
 )]
 pub struct RejectedNSSAIinPLMN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5217,15 +6236,15 @@ pub struct RejectedNSSAIinPLMN(pub Vec<u8>);
 )]
 pub struct RejectedNSSAIinTA(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct RelativeAMFCapacity(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "131071")]
 pub struct RepetitionPeriod(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "7")]
 pub struct ReportAmountMDT(pub u8);
 impl ReportAmountMDT {
@@ -5239,14 +6258,14 @@ impl ReportAmountMDT {
     pub const RINFINITY: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ReportArea(pub u8);
 impl ReportArea {
     pub const CELL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "12")]
 pub struct ReportIntervalMDT(pub u8);
 impl ReportIntervalMDT {
@@ -5265,117 +6284,136 @@ impl ReportIntervalMDT {
     pub const MIN60: u8 = 12u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RerouteNASRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RerouteNASRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ResetAll(pub u8);
 impl ResetAll {
     pub const RESET_ALL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum ResetType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     NG_Interface(ResetAll),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     PartOfNG_Interface(UE_associatedLogicalNG_connectionList),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(ResetTypechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RetrieveUEInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RetrieveUEInformationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct RoutingID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct S_NSSAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub sst: SST,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub sd: Option<SD>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<S_NSSAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct SCTP_TLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct SD(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SNPN_MobilityInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub serving_nid: NID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SNPN_MobilityInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SONConfigurationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub target_ran_node_id: TargetRANNodeID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub source_ran_node_id: SourceRANNodeID,
+    #[asn(tag = "[2] EXPLICIT")]
     pub son_information: SONInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub xn_tnl_configuration_info: Option<XnTNLConfigurationInfo>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<SONConfigurationTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum SONInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SONInformationRequest(SONInformationRequest),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SONInformationReply(SONInformationReply),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(SONInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SONInformationReply {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub xn_tnl_configuration_info: Option<XnTNLConfigurationInfo>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<SONInformationReplyIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum SONInformationReport {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     FailureIndicationInformation(FailureIndication),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     HOReportInformation(HOReport),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(SONInformationReportchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SONInformationRequest(pub u8);
 impl SONInformationRequest {
     pub const XN_TNL_CONFIGURATION_INFO: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SRVCCOperationPossible(pub u8);
 impl SRVCCOperationPossible {
@@ -5383,70 +6421,87 @@ impl SRVCCOperationPossible {
     pub const NOT_POSSIBLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct SST(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct ScheduledCommunicationTime {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub dayof_week: Option<BIT_STRING_75>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub timeof_day_start: Option<INTEGER_76>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub timeof_day_end: Option<INTEGER_77>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<ScheduledCommunicationTimeIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct SecondaryRATDataUsageReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SecondaryRATDataUsageReportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SecondaryRATDataUsageReportTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub secondary_rat_usage_information: Option<SecondaryRATUsageInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<SecondaryRATDataUsageReportTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct SecondaryRATUsageInformation {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub pdu_session_usage_report: Option<PDUSessionUsageReport>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub qos_flows_usage_report_list: Option<QoSFlowsUsageReportList>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extension: Option<SecondaryRATUsageInformationIE_Extension>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityContext {
+    #[asn(tag = "[0] IMPLICIT")]
     pub next_hop_chaining_count: NextHopChainingCount,
+    #[asn(tag = "[1] IMPLICIT")]
     pub next_hop_nh: SecurityKey,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SecurityContextIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SecurityIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub integrity_protection_indication: IntegrityProtectionIndication,
+    #[asn(tag = "[1] IMPLICIT")]
     pub confidentiality_protection_indication: ConfidentialityProtectionIndication,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub maximum_integrity_protected_data_rate_ul: Option<MaximumIntegrityProtectedDataRate>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<SecurityIndicationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -5455,72 +6510,85 @@ pub struct SecurityIndication {
 )]
 pub struct SecurityKey(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityResult {
+    #[asn(tag = "[0] IMPLICIT")]
     pub integrity_protection_result: IntegrityProtectionResult,
+    #[asn(tag = "[1] IMPLICIT")]
     pub confidentiality_protection_result: ConfidentialityProtectionResult,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SecurityResultIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SensorMeasConfig(pub u8);
 impl SensorMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SensorMeasConfigNameItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub sensor_name_config: SensorNameConfig,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SensorMeasConfigNameItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct SensorMeasConfigNameList(pub Vec<SensorMeasConfigNameItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SensorMeasurementConfiguration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub sensor_meas_config: SensorMeasConfig,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub sensor_meas_config_name_list: Option<SensorMeasConfigNameList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<SensorMeasurementConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum SensorNameConfig {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     UncompensatedBarometricConfig(ENUMERATED_78),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     UeSpeedConfig(ENUMERATED_79),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     UeOrientationConfig(ENUMERATED_80),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Choice_Extensions(SensorNameConfigchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct SerialNumber(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct ServedGUAMIItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub guami: GUAMI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub backup_amf_name: Option<AMFName>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<ServedGUAMIItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5529,35 +6597,41 @@ pub struct ServedGUAMIItem {
 )]
 pub struct ServedGUAMIList(pub Vec<ServedGUAMIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ServiceAreaInformation(pub Vec<ServiceAreaInformation_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct ServiceAreaInformation_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub allowed_ta_cs: Option<AllowedTACs>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub not_allowed_ta_cs: Option<NotAllowedTACs>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<ServiceAreaInformation_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
 pub struct SgNB_UE_X2AP_ID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SliceOverloadItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub s_nssai: S_NSSAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SliceOverloadItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5566,15 +6640,18 @@ pub struct SliceOverloadItem {
 )]
 pub struct SliceOverloadList(pub Vec<SliceOverloadItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SliceSupportItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub s_nssai: S_NSSAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SliceSupportItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5583,23 +6660,28 @@ pub struct SliceSupportItem {
 )]
 pub struct SliceSupportList(pub Vec<SliceSupportItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rrc_container: RRCContainer,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub pdu_session_resource_information_list: Option<PDUSessionResourceInformationList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub e_rab_information_list: Option<E_RABInformationList>,
+    #[asn(tag = "[3] EXPLICIT")]
     pub target_cell_id: NGRAN_CGI,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub index_to_rfsp: Option<IndexToRFSP>,
+    #[asn(tag = "[5] IMPLICIT")]
     pub ue_history_information: UEHistoryInformation,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SourceOfUEActivityBehaviourInformation(pub u8);
 impl SourceOfUEActivityBehaviourInformation {
@@ -5607,51 +6689,63 @@ impl SourceOfUEActivityBehaviourInformation {
     pub const STATISTICS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SourceRANNodeID {
+    #[asn(tag = "[0] EXPLICIT")]
     pub global_ran_node_id: GlobalRANNodeID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SourceRANNodeIDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct SourceToTarget_AMFInformationReroute {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub configured_nssai: Option<ConfiguredNSSAI>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub rejected_nssa_iin_plmn: Option<RejectedNSSAIinPLMN>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub rejected_nssa_iin_ta: Option<RejectedNSSAIinTA>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<SourceToTarget_AMFInformationRerouteIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct SourceToTarget_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SuccessfulOutcome {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SupportedTAItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tac: TAC,
+    #[asn(tag = "[1] IMPLICIT")]
     pub broadcast_plmn_list: BroadcastPLMNList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SupportedTAItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5660,57 +6754,67 @@ pub struct SupportedTAItem {
 )]
 pub struct SupportedTAList(pub Vec<SupportedTAItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Suspend_Request_Indication(pub u8);
 impl Suspend_Request_Indication {
     pub const SUSPEND_REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Suspend_Response_Indication(pub u8);
 impl Suspend_Response_Indication {
     pub const SUSPEND_INDICATED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SuspendIndicator(pub u8);
 impl SuspendIndicator {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TABasedMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ta_listfor_mdt: TAListforMDT,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TABasedMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct TAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNIdentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tac: TAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBasedMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai_listfor_mdt: TAIListforMDT,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIBasedMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5719,16 +6823,20 @@ pub struct TAIBasedMDT {
 )]
 pub struct TAIBroadcastEUTRA(pub Vec<TAIBroadcastEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBroadcastEUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub completed_cells_in_tai_eutra: CompletedCellsInTAI_EUTRA,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIBroadcastEUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5737,16 +6845,20 @@ pub struct TAIBroadcastEUTRA_Item {
 )]
 pub struct TAIBroadcastNR(pub Vec<TAIBroadcastNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBroadcastNR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub completed_cells_in_tai_nr: CompletedCellsInTAI_NR,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIBroadcastNR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5755,16 +6867,20 @@ pub struct TAIBroadcastNR_Item {
 )]
 pub struct TAICancelledEUTRA(pub Vec<TAICancelledEUTRA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAICancelledEUTRA_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cancelled_cells_in_tai_eutra: CancelledCellsInTAI_EUTRA,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAICancelledEUTRA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5773,40 +6889,50 @@ pub struct TAICancelledEUTRA_Item {
 )]
 pub struct TAICancelledNR(pub Vec<TAICancelledNR_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAICancelledNR_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cancelled_cells_in_tai_nr: CancelledCellsInTAI_NR,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAICancelledNR_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct TAIListForInactive(pub Vec<TAIListForInactiveItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIListForInactiveItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIListForInactiveItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct TAIListForPaging(pub Vec<TAIListForPagingItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIListForPagingItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIListForPagingItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5815,7 +6941,7 @@ pub struct TAIListForPagingItem {
 )]
 pub struct TAIListForRestart(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5824,45 +6950,49 @@ pub struct TAIListForRestart(pub Vec<TAI>);
 )]
 pub struct TAIListForWarning(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAIListforMDT(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAListforMDT(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TNAP_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum TNGF_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TNGF_ID(BIT_STRING_81),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(TNGF_IDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct TNLAddressWeightFactor(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TNLAssociationItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub tnl_association_address: CPTransportLayerInformation,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TNLAssociationItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct TNLAssociationList(pub Vec<TNLAssociationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct TNLAssociationUsage(pub u8);
 impl TNLAssociationUsage {
@@ -5871,122 +7001,145 @@ impl TNLAssociationUsage {
     pub const BOTH: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TSCAssistanceInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub periodicity: Periodicity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub burst_arrival_time: Option<BurstArrivalTime>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TSCAssistanceInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct TSCTrafficCharacteristics {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub tsc_assistance_information_dl: Option<TSCAssistanceInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub tsc_assistance_information_ul: Option<TSCAssistanceInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<TSCTrafficCharacteristicsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TWAP_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum TWIF_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TWIF_ID(BIT_STRING_82),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(TWIF_IDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum TargetID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TargetRANNodeID(TargetRANNodeID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TargeteNB_ID(TargeteNB_ID),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(TargetIDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_cag_information: Cell_CAGInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions:
         Option<TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rrc_container: RRCContainer,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetRANNodeID {
+    #[asn(tag = "[0] EXPLICIT")]
     pub global_ran_node_id: GlobalRANNodeID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TargetRANNodeIDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TargetRNC_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lai: LAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rnc_id: RNC_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub extended_rnc_id: Option<ExtendedRNC_ID>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TargetRNC_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargetToSource_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargeteNB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_enb_id: GlobalNgENB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_eps_tai: EPS_TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TargeteNB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargettoSource_Failure_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct Threshold_RSRP(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct Threshold_RSRQ(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct Threshold_SINR(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct TimeStamp(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "15")]
 pub struct TimeToTrigger(pub u8);
 impl TimeToTrigger {
@@ -6008,7 +7161,7 @@ impl TimeToTrigger {
     pub const MS5120: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct TimeToWait(pub u8);
 impl TimeToWait {
@@ -6020,44 +7173,54 @@ impl TimeToWait {
     pub const V60S: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct TimeUEStayedInCell(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "40950")]
 pub struct TimeUEStayedInCellEnhancedGranularity(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct TimerApproachForGUAMIRemoval(pub u8);
 impl TimerApproachForGUAMIRemoval {
     pub const APPLY_TIMER: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TooearlyIntersystemHO {
+    #[asn(tag = "[0] IMPLICIT")]
     pub sourcecell_id: EUTRA_CGI,
+    #[asn(tag = "[1] EXPLICIT")]
     pub failurecell_id: NGRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 0)]
     pub uerlf_report_container: Option<UERLFReportContainer>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TooearlyIntersystemHOIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TraceActivation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ngran_trace_id: NGRANTraceID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub interfaces_to_trace: InterfacesToTrace,
+    #[asn(tag = "[2] IMPLICIT")]
     pub trace_depth: TraceDepth,
+    #[asn(tag = "[3] IMPLICIT")]
     pub trace_collection_entity_ip_address: TransportLayerAddress,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TraceActivationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct TraceDepth(pub u8);
 impl TraceDepth {
@@ -6069,27 +7232,33 @@ impl TraceDepth {
     pub const MAXIMUM_WITHOUT_VENDOR_SPECIFIC_EXTENSION: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct TraceFailureIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: TraceFailureIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct TraceStart {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: TraceStartProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "99")]
 pub struct TrafficLoadReductionIndication(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "1", sz_ub = "160")]
 pub struct TransportLayerAddress(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct TriggeringMessage(pub u8);
 impl TriggeringMessage {
@@ -6098,7 +7267,7 @@ impl TriggeringMessage {
     pub const UNSUCCESSFULL_OUTCOME: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct TypeOfError(pub u8);
 impl TypeOfError {
@@ -6106,64 +7275,72 @@ impl TypeOfError {
     pub const MISSING: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 7)]
 pub struct UE_DifferentiationInfo {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub periodic_communication_indicator: Option<ENUMERATED_83>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub periodic_time: Option<INTEGER_84>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub scheduled_communication_time: Option<ScheduledCommunicationTime>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub stationary_indication: Option<ENUMERATED_85>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub traffic_profile: Option<ENUMERATED_86>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 5)]
     pub battery_indication: Option<ENUMERATED_87>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 6)]
     pub ie_extensions: Option<UE_DifferentiationInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UE_NGAP_ID_pair {
+    #[asn(tag = "[0] IMPLICIT")]
     pub amf_ue_ngap_id: AMF_UE_NGAP_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ran_ue_ngap_id: RAN_UE_NGAP_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UE_NGAP_ID_pairIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum UE_NGAP_IDs {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     UE_NGAP_ID_pair(UE_NGAP_ID_pair),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     AMF_UE_NGAP_ID(AMF_UE_NGAP_ID),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(UE_NGAP_IDschoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UE_UP_CIoT_Support(pub u8);
 impl UE_UP_CIoT_Support {
     pub const SUPPORTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UE_associatedLogicalNG_connectionItem {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub amf_ue_ngap_id: Option<AMF_UE_NGAP_ID>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ran_ue_ngap_id: Option<RAN_UE_NGAP_ID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<UE_associatedLogicalNG_connectionItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6172,166 +7349,215 @@ pub struct UE_associatedLogicalNG_connectionItem {
 )]
 pub struct UE_associatedLogicalNG_connectionList(pub Vec<UE_associatedLogicalNG_connectionItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UEAggregateMaximumBitRate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ue_aggregate_maximum_bit_rate_dl: BitRate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ue_aggregate_maximum_bit_rate_ul: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UEAggregateMaximumBitRateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UECapabilityInfoRequest(pub u8);
 impl UECapabilityInfoRequest {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextReleaseCommandProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseComplete {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextReleaseCompleteProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextReleaseRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UEContextRequest(pub u8);
 impl UEContextRequest {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextResumeFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextResumeRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UEContextResumeRequestTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub qos_flow_failed_to_resume_list: Option<QosFlowListWithCause>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<UEContextResumeRequestTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextResumeResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UEContextResumeResponseTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub qos_flow_failed_to_resume_list: Option<QosFlowListWithCause>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<UEContextResumeResponseTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextSuspendFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextSuspendRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UEContextSuspendRequestTransfer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub suspend_indicator: Option<SuspendIndicator>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<UEContextSuspendRequestTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextSuspendResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct UEHistoryInformation(pub Vec<LastVisitedCellItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UEHistoryInformationFromTheUE {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     NR(NRMobilityHistoryReport),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(UEHistoryInformationFromTheUEchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UEIdentityIndexValue {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     IndexLength10(BIT_STRING_88),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(UEIdentityIndexValuechoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEInformationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UEPagingIdentity {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     FiveG_S_TMSI(FiveG_S_TMSI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(UEPagingIdentitychoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct UEPresence(pub u8);
 impl UEPresence {
@@ -6340,203 +7566,251 @@ impl UEPresence {
     pub const UNKNOWN: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UEPresenceInAreaOfInterestItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub location_reporting_reference_id: LocationReportingReferenceID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ue_presence: UEPresence,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UEPresenceInAreaOfInterestItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct UEPresenceInAreaOfInterestList(pub Vec<UEPresenceInAreaOfInterestItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum UERLFReportContainer {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     NR(NRUERLFReportContainer),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     LTE(LTEUERLFReportContainer),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(UERLFReportContainerchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapability(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityCheckRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityCheckRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityCheckResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityCheckResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UERadioCapabilityForPaging {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub ue_radio_capability_for_paging_of_nr: Option<UERadioCapabilityForPagingOfNR>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ue_radio_capability_for_paging_of_eutra: Option<UERadioCapabilityForPagingOfEUTRA>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<UERadioCapabilityForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityForPagingOfEUTRA(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityForPagingOfNB_IoT(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityForPagingOfNR(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityIDMappingRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityIDMappingRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityIDMappingResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityIDMappingResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityInfoIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityInfoIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UERetentionInformation(pub u8);
 impl UERetentionInformation {
     pub const UES_RETAINED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UESecurityCapabilities {
+    #[asn(tag = "[0] IMPLICIT")]
     pub n_rencryption_algorithms: NRencryptionAlgorithms,
+    #[asn(tag = "[1] IMPLICIT")]
     pub n_rintegrity_protection_algorithms: NRintegrityProtectionAlgorithms,
+    #[asn(tag = "[2] IMPLICIT")]
     pub eutr_aencryption_algorithms: EUTRAencryptionAlgorithms,
+    #[asn(tag = "[3] IMPLICIT")]
     pub eutr_aintegrity_protection_algorithms: EUTRAintegrityProtectionAlgorithms,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UESecurityCapabilitiesIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UETNLABindingReleaseRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UETNLABindingReleaseRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UL_CP_SecurityInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ul_nas_mac: UL_NAS_MAC,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ul_nas_count: UL_NAS_Count,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UL_CP_SecurityInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "5", sz_ub = "5")]
 pub struct UL_NAS_Count(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct UL_NAS_MAC(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UL_NGU_UP_TNLModifyItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ul_ngu_up_tnl_information: UPTransportLayerInformation,
+    #[asn(tag = "[1] EXPLICIT")]
     pub dl_ngu_up_tnl_information: UPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UL_NGU_UP_TNLModifyItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct UL_NGU_UP_TNLModifyList(pub Vec<UL_NGU_UP_TNLModifyItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ULForwarding(pub u8);
 impl ULForwarding {
     pub const UL_FORWARDING_PROPOSED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UPTransportLayerInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GTPTunnel(GTPTunnel),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(UPTransportLayerInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UPTransportLayerInformationItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ngu_up_tnl_information: UPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UPTransportLayerInformationItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct UPTransportLayerInformationList(pub Vec<UPTransportLayerInformationItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UPTransportLayerInformationPairItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub ul_ngu_up_tnl_information: UPTransportLayerInformation,
+    #[asn(tag = "[1] EXPLICIT")]
     pub dl_ngu_up_tnl_information: UPTransportLayerInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UPTransportLayerInformationPairItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "3")]
 pub struct UPTransportLayerInformationPairList(pub Vec<UPTransportLayerInformationPairItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "VisibleString")]
 pub struct URI_address(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UnavailableGUAMIItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub guami: GUAMI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub timer_approach_for_guami_removal: Option<TimerApproachForGUAMIRemoval>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub backup_amf_name: Option<AMFName>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<UnavailableGUAMIItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6545,148 +7819,195 @@ pub struct UnavailableGUAMIItem {
 )]
 pub struct UnavailableGUAMIList(pub Vec<UnavailableGUAMIItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UnsuccessfulOutcome {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UnsuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
 pub struct UpdateFeedback(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkNASTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkNASTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkNonUEAssociatedNRPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkNonUEAssociatedNRPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRANConfigurationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkRANConfigurationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRANEarlyStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkRANEarlyStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRANStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkRANStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkRIMInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkRIMInformationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkUEAssociatedNRPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkUEAssociatedNRPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = false)]
 pub enum UserLocationInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     UserLocationInformationEUTRA(UserLocationInformationEUTRA),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     UserLocationInformationNR(UserLocationInformationNR),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     UserLocationInformationN3IWF(UserLocationInformationN3IWF),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Choice_Extensions(UserLocationInformationchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationEUTRA {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutra_cgi: EUTRA_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub time_stamp: Option<TimeStamp>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<UserLocationInformationEUTRAIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UserLocationInformationN3IWF {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ip_address: TransportLayerAddress,
+    #[asn(tag = "[1] IMPLICIT")]
     pub port_number: PortNumber,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UserLocationInformationN3IWFIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationNR {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nr_cgi: NR_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub time_stamp: Option<TimeStamp>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<UserLocationInformationNRIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationTNGF {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tnap_id: TNAP_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ip_address: TransportLayerAddress,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub port_number: Option<PortNumber>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<UserLocationInformationTNGFIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UserLocationInformationTWIF {
+    #[asn(tag = "[0] IMPLICIT")]
     pub twap_id: TWAP_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ip_address: TransportLayerAddress,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub port_number: Option<PortNumber>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<UserLocationInformationTWIFIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = false)]
 pub enum UserLocationInformationW_AGF {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GlobalLine_ID(GlobalLine_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     HFCNode_ID(HFCNode_ID),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Choice_Extensions(UserLocationInformationW_AGFchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UserPlaneSecurityInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub security_result: SecurityResult,
+    #[asn(tag = "[1] IMPLICIT")]
     pub security_indication: SecurityIndication,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UserPlaneSecurityInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct VehicleUE(pub u8);
 impl VehicleUE {
@@ -6694,64 +8015,76 @@ impl VehicleUE {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct VolumeTimedReport_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub start_time_stamp: OCTET_STRING_89,
+    #[asn(tag = "[1] IMPLICIT")]
     pub end_time_stamp: OCTET_STRING_90,
+    #[asn(tag = "[2] IMPLICIT")]
     pub usage_count_ul: INTEGER_91,
+    #[asn(tag = "[3] IMPLICIT")]
     pub usage_count_dl: INTEGER_92,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<VolumeTimedReport_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct VolumeTimedReportList(pub Vec<VolumeTimedReport_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum W_AGF_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     W_AGF_ID(BIT_STRING_93),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Choice_Extensions(W_AGF_IDchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct WLANMeasConfig(pub u8);
 impl WLANMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct WLANMeasConfigNameItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub wlan_name: WLANName,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<WLANMeasConfigNameItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct WLANMeasConfigNameList(pub Vec<WLANMeasConfigNameItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct WLANMeasurementConfiguration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub wlan_meas_config: WLANMeasConfig,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub wlan_meas_config_name_list: Option<WLANMeasConfigNameList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub wlan_rssi: Option<ENUMERATED_94>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub wlan_rtt: Option<ENUMERATED_95>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<WLANMeasurementConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6760,15 +8093,18 @@ pub struct WLANMeasurementConfiguration {
 )]
 pub struct WLANName(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct WUS_Assistance_Information {
+    #[asn(tag = "[0] IMPLICIT")]
     pub paging_probability_information: PagingProbabilityInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<WUS_Assistance_InformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6777,22 +8113,22 @@ pub struct WUS_Assistance_Information {
 )]
 pub struct WarningAreaCoordinates(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = false)]
 pub enum WarningAreaList {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     EUTRA_CGIListForWarning(EUTRA_CGIListForWarning),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     NR_CGIListForWarning(NR_CGIListForWarning),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     TAIListForWarning(TAIListForWarning),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     EmergencyAreaIDList(EmergencyAreaIDList),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Choice_Extensions(WarningAreaListchoice_Extensions),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6801,7 +8137,7 @@ pub enum WarningAreaList {
 )]
 pub struct WarningMessageContents(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -6810,60 +8146,71 @@ pub struct WarningMessageContents(pub Vec<u8>);
 )]
 pub struct WarningSecurityInfo(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct WarningType(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct WriteReplaceWarningRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: WriteReplaceWarningRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct WriteReplaceWarningResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: WriteReplaceWarningResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct XnExtTLA_Item {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub i_psec_tla: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub gtp_tl_as: Option<XnGTP_TLAs>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<XnExtTLA_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct XnExtTLAs(pub Vec<XnExtTLA_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct XnGTP_TLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct XnTLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct XnTNLConfigurationInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub xn_transport_layer_addresses: XnTLAs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub xn_extended_transport_layer_addresses: Option<XnExtTLAs>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<XnTNLConfigurationInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationSetupItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6874,11 +8221,11 @@ pub struct AMF_TNLAssociationSetupItemIE_Extensions(
     pub Vec<AMF_TNLAssociationSetupItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationToAddItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6889,23 +8236,25 @@ pub struct AMF_TNLAssociationToAddItemIE_Extensions(
     pub Vec<AMF_TNLAssociationToAddItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AMF_TNLAssociationToRemoveItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 168)]
     Id_TNLAssociationTransportLayerAddressNGRAN(CPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationToRemoveItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: AMF_TNLAssociationToRemoveItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6916,11 +8265,11 @@ pub struct AMF_TNLAssociationToRemoveItemIE_Extensions(
     pub Vec<AMF_TNLAssociationToRemoveItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMF_TNLAssociationToUpdateItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6931,7 +8280,7 @@ pub struct AMF_TNLAssociationToUpdateItemIE_Extensions(
     pub Vec<AMF_TNLAssociationToUpdateItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AMFCPRelocationIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -6944,16 +8293,18 @@ pub enum AMFCPRelocationIndicationProtocolIEs_EntryValue {
     Id_S_NSSAI(S_NSSAI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFCPRelocationIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: AMFCPRelocationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6964,7 +8315,7 @@ pub struct AMFCPRelocationIndicationProtocolIEs(
     pub Vec<AMFCPRelocationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AMFConfigurationUpdateProtocolIEs_EntryValue {
     #[asn(key = 6)]
@@ -6985,16 +8336,18 @@ pub enum AMFConfigurationUpdateProtocolIEs_EntryValue {
     Id_ServedGUAMIList(ServedGUAMIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFConfigurationUpdateProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: AMFConfigurationUpdateProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7003,7 +8356,7 @@ pub struct AMFConfigurationUpdateProtocolIEs_Entry {
 )]
 pub struct AMFConfigurationUpdateProtocolIEs(pub Vec<AMFConfigurationUpdateProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AMFConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7014,16 +8367,18 @@ pub enum AMFConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFConfigurationUpdateAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: AMFConfigurationUpdateAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7034,7 +8389,7 @@ pub struct AMFConfigurationUpdateAcknowledgeProtocolIEs(
     pub Vec<AMFConfigurationUpdateAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AMFConfigurationUpdateFailureProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -7045,16 +8400,18 @@ pub enum AMFConfigurationUpdateFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFConfigurationUpdateFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: AMFConfigurationUpdateFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7065,27 +8422,29 @@ pub struct AMFConfigurationUpdateFailureProtocolIEs(
     pub Vec<AMFConfigurationUpdateFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFPagingTargetchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AMFStatusIndicationProtocolIEs_EntryValue {
     #[asn(key = 120)]
     Id_UnavailableGUAMIList(UnavailableGUAMIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AMFStatusIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: AMFStatusIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7094,23 +8453,25 @@ pub struct AMFStatusIndicationProtocolIEs_Entry {
 )]
 pub struct AMFStatusIndicationProtocolIEs(pub Vec<AMFStatusIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AdditionalDLUPTNLInformationForHOItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 183)]
     Id_AdditionalRedundantDL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AdditionalDLUPTNLInformationForHOItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: AdditionalDLUPTNLInformationForHOItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7121,11 +8482,11 @@ pub struct AdditionalDLUPTNLInformationForHOItemIE_Extensions(
     pub Vec<AdditionalDLUPTNLInformationForHOItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AllocationAndRetentionPriorityIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7136,7 +8497,7 @@ pub struct AllocationAndRetentionPriorityIE_Extensions(
     pub Vec<AllocationAndRetentionPriorityIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_2(pub u8);
 impl ENUMERATED_2 {
@@ -7144,11 +8505,11 @@ impl ENUMERATED_2 {
     pub const NOT_RESTRICTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Allowed_PNI_NPN_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7157,11 +8518,11 @@ pub struct Allowed_PNI_NPN_ItemIE_Extensions_Entry {}
 )]
 pub struct Allowed_PNI_NPN_ItemIE_Extensions(pub Vec<Allowed_PNI_NPN_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AllowedNSSAI_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7170,11 +8531,11 @@ pub struct AllowedNSSAI_ItemIE_Extensions_Entry {}
 )]
 pub struct AllowedNSSAI_ItemIE_Extensions(pub Vec<AllowedNSSAI_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AlternativeQoSParaSetItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7185,11 +8546,11 @@ pub struct AlternativeQoSParaSetItemIE_Extensions(
     pub Vec<AlternativeQoSParaSetItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7198,11 +8559,11 @@ pub struct AreaOfInterestIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestIE_Extensions(pub Vec<AreaOfInterestIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7211,11 +8572,11 @@ pub struct AreaOfInterestCellItemIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestCellItemIE_Extensions(pub Vec<AreaOfInterestCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7224,11 +8585,11 @@ pub struct AreaOfInterestItemIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestItemIE_Extensions(pub Vec<AreaOfInterestItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestRANNodeItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7239,11 +8600,11 @@ pub struct AreaOfInterestRANNodeItemIE_Extensions(
     pub Vec<AreaOfInterestRANNodeItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaOfInterestTAIItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7252,27 +8613,27 @@ pub struct AreaOfInterestTAIItemIE_Extensions_Entry {}
 )]
 pub struct AreaOfInterestTAIItemIE_Extensions(pub Vec<AreaOfInterestTAIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_3;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaScopeOfMDT_EUTRAchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_4;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaScopeOfMDT_NRchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AreaScopeOfNeighCellsItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7283,7 +8644,7 @@ pub struct AreaScopeOfNeighCellsItemIE_Extensions(
     pub Vec<AreaScopeOfNeighCellsItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AssistanceDataForPagingIE_Extensions_EntryExtensionValue {
     #[asn(key = 260)]
@@ -7292,16 +8653,18 @@ pub enum AssistanceDataForPagingIE_Extensions_EntryExtensionValue {
     Id_PagingAssisDataforCEcapabUE(PagingAssisDataforCEcapabUE),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssistanceDataForPagingIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: AssistanceDataForPagingIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7312,11 +8675,11 @@ pub struct AssistanceDataForPagingIE_Extensions(
     pub Vec<AssistanceDataForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssistanceDataForRecommendedCellsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7327,7 +8690,7 @@ pub struct AssistanceDataForRecommendedCellsIE_Extensions(
     pub Vec<AssistanceDataForRecommendedCellsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_5(pub u8);
 impl ENUMERATED_5 {
@@ -7335,23 +8698,25 @@ impl ENUMERATED_5 {
     pub const DL: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum AssociatedQosFlowItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssociatedQosFlowItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: AssociatedQosFlowItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7360,11 +8725,11 @@ pub struct AssociatedQosFlowItemIE_Extensions_Entry {
 )]
 pub struct AssociatedQosFlowItemIE_Extensions(pub Vec<AssociatedQosFlowItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BluetoothMeasConfigNameItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7375,18 +8740,18 @@ pub struct BluetoothMeasConfigNameItemIE_Extensions(
     pub Vec<BluetoothMeasConfigNameItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_6(pub u8);
 impl ENUMERATED_6 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BluetoothMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7397,15 +8762,15 @@ pub struct BluetoothMeasurementConfigurationIE_Extensions(
     pub Vec<BluetoothMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BroadcastCancelledAreaListchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BroadcastCompletedAreaListchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum BroadcastPLMNItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 271)]
@@ -7414,16 +8779,18 @@ pub enum BroadcastPLMNItemIE_Extensions_EntryExtensionValue {
     Id_NPN_Support(NPN_Support),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BroadcastPLMNItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: BroadcastPLMNItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7432,11 +8799,11 @@ pub struct BroadcastPLMNItemIE_Extensions_Entry {
 )]
 pub struct BroadcastPLMNItemIE_Extensions(pub Vec<BroadcastPLMNItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CNAssistedRANTuningIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7445,7 +8812,7 @@ pub struct CNAssistedRANTuningIE_Extensions_Entry {}
 )]
 pub struct CNAssistedRANTuningIE_Extensions(pub Vec<CNAssistedRANTuningIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_7(pub u8);
 impl ENUMERATED_7 {
@@ -7453,11 +8820,11 @@ impl ENUMERATED_7 {
     pub const FIVE_GC_FORBIDDEN: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CNTypeRestrictionsForEquivalentItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7468,19 +8835,19 @@ pub struct CNTypeRestrictionsForEquivalentItemIE_Extensions(
     pub Vec<CNTypeRestrictionsForEquivalentItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct INTEGER_8(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1048575")]
 pub struct INTEGER_9(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct COUNTValueForPDCP_SN12IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7489,19 +8856,19 @@ pub struct COUNTValueForPDCP_SN12IE_Extensions_Entry {}
 )]
 pub struct COUNTValueForPDCP_SN12IE_Extensions(pub Vec<COUNTValueForPDCP_SN12IE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "262143")]
 pub struct INTEGER_10(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "16383")]
 pub struct INTEGER_11(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct COUNTValueForPDCP_SN18IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7510,27 +8877,29 @@ pub struct COUNTValueForPDCP_SN18IE_Extensions_Entry {}
 )]
 pub struct COUNTValueForPDCP_SN18IE_Extensions(pub Vec<COUNTValueForPDCP_SN18IE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CPTransportLayerInformationchoice_ExtensionsValue {
     #[asn(key = 169)]
     Id_EndpointIPAddressAndPort(EndpointIPAddressAndPort),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CPTransportLayerInformationchoice_Extensions {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: CPTransportLayerInformationchoice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInEAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7541,11 +8910,11 @@ pub struct CancelledCellsInEAI_EUTRA_ItemIE_Extensions(
     pub Vec<CancelledCellsInEAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInEAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7556,11 +8925,11 @@ pub struct CancelledCellsInEAI_NR_ItemIE_Extensions(
     pub Vec<CancelledCellsInEAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInTAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7571,11 +8940,11 @@ pub struct CancelledCellsInTAI_EUTRA_ItemIE_Extensions(
     pub Vec<CancelledCellsInTAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellsInTAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7586,15 +8955,15 @@ pub struct CancelledCellsInTAI_NR_ItemIE_Extensions(
     pub Vec<CancelledCellsInTAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CandidateCellchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CandidateCellIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7603,11 +8972,11 @@ pub struct CandidateCellIDIE_Extensions_Entry {}
 )]
 pub struct CandidateCellIDIE_Extensions(pub Vec<CandidateCellIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CandidateCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7616,19 +8985,19 @@ pub struct CandidateCellItemIE_Extensions_Entry {}
 )]
 pub struct CandidateCellItemIE_Extensions(pub Vec<CandidateCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1007", extensible = true)]
 pub struct INTEGER_12(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "3279165")]
 pub struct INTEGER_13(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CandidatePCIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7637,15 +9006,15 @@ pub struct CandidatePCIIE_Extensions_Entry {}
 )]
 pub struct CandidatePCIIE_Extensions(pub Vec<CandidatePCIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Causechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Cell_CAGInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7654,11 +9023,11 @@ pub struct Cell_CAGInformationIE_Extensions_Entry {}
 )]
 pub struct Cell_CAGInformationIE_Extensions(pub Vec<Cell_CAGInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellBasedMDT_EUTRAIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7667,11 +9036,11 @@ pub struct CellBasedMDT_EUTRAIE_Extensions_Entry {}
 )]
 pub struct CellBasedMDT_EUTRAIE_Extensions(pub Vec<CellBasedMDT_EUTRAIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellBasedMDT_NRIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7680,11 +9049,11 @@ pub struct CellBasedMDT_NRIE_Extensions_Entry {}
 )]
 pub struct CellBasedMDT_NRIE_Extensions(pub Vec<CellBasedMDT_NRIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDBroadcastEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7695,11 +9064,11 @@ pub struct CellIDBroadcastEUTRA_ItemIE_Extensions(
     pub Vec<CellIDBroadcastEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDBroadcastNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7708,11 +9077,11 @@ pub struct CellIDBroadcastNR_ItemIE_Extensions_Entry {}
 )]
 pub struct CellIDBroadcastNR_ItemIE_Extensions(pub Vec<CellIDBroadcastNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDCancelledEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7723,11 +9092,11 @@ pub struct CellIDCancelledEUTRA_ItemIE_Extensions(
     pub Vec<CellIDCancelledEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDCancelledNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7736,11 +9105,11 @@ pub struct CellIDCancelledNR_ItemIE_Extensions_Entry {}
 )]
 pub struct CellIDCancelledNR_ItemIE_Extensions(pub Vec<CellIDCancelledNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIDListForRestartchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CellTrafficTraceProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -7759,16 +9128,18 @@ pub enum CellTrafficTraceProtocolIEs_EntryValue {
     Id_TraceCollectionEntityURI(URI_address),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellTrafficTraceProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: CellTrafficTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7777,11 +9148,11 @@ pub struct CellTrafficTraceProtocolIEs_Entry {
 )]
 pub struct CellTrafficTraceProtocolIEs(pub Vec<CellTrafficTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellTypeIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7790,11 +9161,11 @@ pub struct CellTypeIE_Extensions_Entry {}
 )]
 pub struct CellTypeIE_Extensions(pub Vec<CellTypeIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInEAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7805,11 +9176,11 @@ pub struct CompletedCellsInEAI_EUTRA_ItemIE_Extensions(
     pub Vec<CompletedCellsInEAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInEAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7820,11 +9191,11 @@ pub struct CompletedCellsInEAI_NR_ItemIE_Extensions(
     pub Vec<CompletedCellsInEAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInTAI_EUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7835,11 +9206,11 @@ pub struct CompletedCellsInTAI_EUTRA_ItemIE_Extensions(
     pub Vec<CompletedCellsInTAI_EUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellsInTAI_NR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7850,7 +9221,7 @@ pub struct CompletedCellsInTAI_NR_ItemIE_Extensions(
     pub Vec<CompletedCellsInTAI_NR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ConnectionEstablishmentIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -7879,16 +9250,18 @@ pub enum ConnectionEstablishmentIndicationProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ConnectionEstablishmentIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ConnectionEstablishmentIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7899,7 +9272,7 @@ pub struct ConnectionEstablishmentIndicationProtocolIEs(
     pub Vec<ConnectionEstablishmentIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CoreNetworkAssistanceInformationForInactiveIE_Extensions_EntryExtensionValue {
     #[asn(key = 280)]
@@ -7910,17 +9283,19 @@ pub enum CoreNetworkAssistanceInformationForInactiveIE_Extensions_EntryExtension
     Id_UERadioCapabilityForPaging(UERadioCapabilityForPaging),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CoreNetworkAssistanceInformationForInactiveIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         CoreNetworkAssistanceInformationForInactiveIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7931,11 +9306,11 @@ pub struct CoreNetworkAssistanceInformationForInactiveIE_Extensions(
     pub Vec<CoreNetworkAssistanceInformationForInactiveIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7944,11 +9319,11 @@ pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 )]
 pub struct CriticalityDiagnosticsIE_Extensions(pub Vec<CriticalityDiagnosticsIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnostics_IE_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7959,18 +9334,18 @@ pub struct CriticalityDiagnostics_IE_ItemIE_Extensions(
     pub Vec<CriticalityDiagnostics_IE_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_14(pub u8);
 impl ENUMERATED_14 {
     pub const DAPS_HO_REQUIRED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSRequestInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7979,7 +9354,7 @@ pub struct DAPSRequestInfoIE_Extensions_Entry {}
 )]
 pub struct DAPSRequestInfoIE_Extensions(pub Vec<DAPSRequestInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_15(pub u8);
 impl ENUMERATED_15 {
@@ -7987,11 +9362,11 @@ impl ENUMERATED_15 {
     pub const DAPS_HO_NOT_ACCEPTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSResponseInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8000,11 +9375,11 @@ pub struct DAPSResponseInfoIE_Extensions_Entry {}
 )]
 pub struct DAPSResponseInfoIE_Extensions(pub Vec<DAPSResponseInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSResponseInfoItemIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8013,11 +9388,11 @@ pub struct DAPSResponseInfoItemIE_Extension_Entry {}
 )]
 pub struct DAPSResponseInfoItemIE_Extension(pub Vec<DAPSResponseInfoItemIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DL_CP_SecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8028,15 +9403,15 @@ pub struct DL_CP_SecurityInformationIE_Extensions(
     pub Vec<DL_CP_SecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusDLchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusDL12IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8045,11 +9420,11 @@ pub struct DRBStatusDL12IE_Extension_Entry {}
 )]
 pub struct DRBStatusDL12IE_Extension(pub Vec<DRBStatusDL12IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusDL18IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8058,19 +9433,19 @@ pub struct DRBStatusDL18IE_Extension_Entry {}
 )]
 pub struct DRBStatusDL18IE_Extension(pub Vec<DRBStatusDL18IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusULchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "1", sz_ub = "2048")]
 pub struct BIT_STRING_16(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusUL12IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8079,7 +9454,7 @@ pub struct DRBStatusUL12IE_Extension_Entry {}
 )]
 pub struct DRBStatusUL12IE_Extension(pub Vec<DRBStatusUL12IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -8088,11 +9463,11 @@ pub struct DRBStatusUL12IE_Extension(pub Vec<DRBStatusUL12IE_Extension_Entry>);
 )]
 pub struct BIT_STRING_17(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBStatusUL18IE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8101,11 +9476,11 @@ pub struct DRBStatusUL18IE_Extension_Entry {}
 )]
 pub struct DRBStatusUL18IE_Extension(pub Vec<DRBStatusUL18IE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8116,23 +9491,25 @@ pub struct DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension(
     pub Vec<DRBsSubjectToEarlyStatusTransfer_ItemIE_Extension_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DRBsSubjectToStatusTransferItemIE_Extension_EntryExtensionValue {
     #[asn(key = 159)]
     Id_OldAssociatedQosFlowList_ULendmarkerexpected(AssociatedQosFlowList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBsSubjectToStatusTransferItemIE_Extension_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: DRBsSubjectToStatusTransferItemIE_Extension_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8143,23 +9520,25 @@ pub struct DRBsSubjectToStatusTransferItemIE_Extension(
     pub Vec<DRBsSubjectToStatusTransferItemIE_Extension_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DRBsToQosFlowsMappingItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 266)]
     Id_DAPSRequestInfo(DAPSRequestInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DRBsToQosFlowsMappingItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: DRBsToQosFlowsMappingItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8170,11 +9549,11 @@ pub struct DRBsToQosFlowsMappingItemIE_Extensions(
     pub Vec<DRBsToQosFlowsMappingItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataForwardingResponseDRBItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8185,11 +9564,11 @@ pub struct DataForwardingResponseDRBItemIE_Extensions(
     pub Vec<DataForwardingResponseDRBItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataForwardingResponseERABListItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8200,7 +9579,7 @@ pub struct DataForwardingResponseERABListItemIE_Extensions(
     pub Vec<DataForwardingResponseERABListItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DeactivateTraceProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8211,16 +9590,18 @@ pub enum DeactivateTraceProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DeactivateTraceProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DeactivateTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8229,7 +9610,7 @@ pub struct DeactivateTraceProtocolIEs_Entry {
 )]
 pub struct DeactivateTraceProtocolIEs(pub Vec<DeactivateTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkNASTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8270,16 +9651,18 @@ pub enum DownlinkNASTransportProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkNASTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkNASTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8288,7 +9671,7 @@ pub struct DownlinkNASTransportProtocolIEs_Entry {
 )]
 pub struct DownlinkNASTransportProtocolIEs(pub Vec<DownlinkNASTransportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 46)]
@@ -8297,16 +9680,18 @@ pub enum DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8317,7 +9702,7 @@ pub struct DownlinkNonUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<DownlinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRANConfigurationTransferProtocolIEs_EntryValue {
     #[asn(key = 157)]
@@ -8328,16 +9713,18 @@ pub enum DownlinkRANConfigurationTransferProtocolIEs_EntryValue {
     Id_SONConfigurationTransferDL(SONConfigurationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRANConfigurationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkRANConfigurationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8348,7 +9735,7 @@ pub struct DownlinkRANConfigurationTransferProtocolIEs(
     pub Vec<DownlinkRANConfigurationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8359,16 +9746,18 @@ pub enum DownlinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRANEarlyStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkRANEarlyStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8379,7 +9768,7 @@ pub struct DownlinkRANEarlyStatusTransferProtocolIEs(
     pub Vec<DownlinkRANEarlyStatusTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRANStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8390,16 +9779,18 @@ pub enum DownlinkRANStatusTransferProtocolIEs_EntryValue {
     Id_RANStatusTransfer_TransparentContainer(RANStatusTransfer_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRANStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkRANStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8410,23 +9801,25 @@ pub struct DownlinkRANStatusTransferProtocolIEs(
     pub Vec<DownlinkRANStatusTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkRIMInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 175)]
     Id_RIMInformationTransfer(RIMInformationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkRIMInformationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkRIMInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8437,7 +9830,7 @@ pub struct DownlinkRIMInformationTransferProtocolIEs(
     pub Vec<DownlinkRIMInformationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8450,16 +9843,18 @@ pub enum DownlinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkUEAssociatedNRPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8470,7 +9865,7 @@ pub struct DownlinkUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<DownlinkUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Dynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     #[asn(key = 187)]
@@ -8481,16 +9876,18 @@ pub enum Dynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     Id_ExtendedPacketDelayBudget(ExtendedPacketDelayBudget),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Dynamic5QIDescriptorIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: Dynamic5QIDescriptorIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8499,11 +9896,11 @@ pub struct Dynamic5QIDescriptorIE_Extensions_Entry {
 )]
 pub struct Dynamic5QIDescriptorIE_Extensions(pub Vec<Dynamic5QIDescriptorIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8512,31 +9909,31 @@ pub struct E_RABInformationItemIE_Extensions_Entry {}
 )]
 pub struct E_RABInformationItemIE_Extensions(pub Vec<E_RABInformationItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "20", sz_ub = "20")]
 pub struct BIT_STRING_18(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "28", sz_ub = "28")]
 pub struct BIT_STRING_19(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "18", sz_ub = "18")]
 pub struct BIT_STRING_20(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "21", sz_ub = "21")]
 pub struct BIT_STRING_21(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENB_IDchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EPS_TAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8545,11 +9942,11 @@ pub struct EPS_TAIIE_Extensions_Entry {}
 )]
 pub struct EPS_TAIIE_Extensions(pub Vec<EPS_TAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EUTRA_CGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8558,11 +9955,11 @@ pub struct EUTRA_CGIIE_Extensions_Entry {}
 )]
 pub struct EUTRA_CGIIE_Extensions(pub Vec<EUTRA_CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EarlyStatusTransfer_TransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8573,11 +9970,11 @@ pub struct EarlyStatusTransfer_TransparentContainerIE_Extensions(
     pub Vec<EarlyStatusTransfer_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8588,11 +9985,11 @@ pub struct EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDBroadcastEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDBroadcastNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8603,11 +10000,11 @@ pub struct EmergencyAreaIDBroadcastNR_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDBroadcastNR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8618,11 +10015,11 @@ pub struct EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDCancelledEUTRA_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaIDCancelledNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8633,11 +10030,11 @@ pub struct EmergencyAreaIDCancelledNR_ItemIE_Extensions(
     pub Vec<EmergencyAreaIDCancelledNR_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyFallbackIndicatorIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8648,11 +10045,11 @@ pub struct EmergencyFallbackIndicatorIE_Extensions(
     pub Vec<EmergencyFallbackIndicatorIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EndpointIPAddressAndPortIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8663,7 +10060,7 @@ pub struct EndpointIPAddressAndPortIE_Extensions(
     pub Vec<EndpointIPAddressAndPortIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ErrorIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -8678,16 +10075,18 @@ pub enum ErrorIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ErrorIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ErrorIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8696,11 +10095,11 @@ pub struct ErrorIndicationProtocolIEs_Entry {
 )]
 pub struct ErrorIndicationProtocolIEs(pub Vec<ErrorIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EventL1LoggedMDTConfigIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8709,22 +10108,22 @@ pub struct EventL1LoggedMDTConfigIE_Extensions_Entry {}
 )]
 pub struct EventL1LoggedMDTConfigIE_Extensions(pub Vec<EventL1LoggedMDTConfigIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_22(pub u8);
 impl ENUMERATED_22 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EventTriggerchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEActivityBehaviourIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8735,11 +10134,11 @@ pub struct ExpectedUEActivityBehaviourIE_Extensions(
     pub Vec<ExpectedUEActivityBehaviourIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEBehaviourIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8748,15 +10147,15 @@ pub struct ExpectedUEBehaviourIE_Extensions_Entry {}
 )]
 pub struct ExpectedUEBehaviourIE_Extensions(pub Vec<ExpectedUEBehaviourIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct INTEGER_23(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEMovingTrajectoryItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8767,11 +10166,11 @@ pub struct ExpectedUEMovingTrajectoryItemIE_Extensions(
     pub Vec<ExpectedUEMovingTrajectoryItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Extended_AMFNameIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8780,11 +10179,11 @@ pub struct Extended_AMFNameIE_Extensions_Entry {}
 )]
 pub struct Extended_AMFNameIE_Extensions(pub Vec<Extended_AMFNameIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Extended_RANNodeNameIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8793,19 +10192,19 @@ pub struct Extended_RANNodeNameIE_Extensions_Entry {}
 )]
 pub struct Extended_RANNodeNameIE_Extensions(pub Vec<Extended_RANNodeNameIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
 pub struct BIT_STRING_24(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "8", sz_ub = "8")]
 pub struct BIT_STRING_25(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExtendedRATRestrictionInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8816,11 +10215,11 @@ pub struct ExtendedRATRestrictionInformationIE_Extensions(
     pub Vec<ExtendedRATRestrictionInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FailureIndicationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8829,11 +10228,11 @@ pub struct FailureIndicationIE_Extensions_Entry {}
 )]
 pub struct FailureIndicationIE_Extensions(pub Vec<FailureIndicationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FirstDLCountIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8842,11 +10241,11 @@ pub struct FirstDLCountIE_Extension_Entry {}
 )]
 pub struct FirstDLCountIE_Extension(pub Vec<FirstDLCountIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FiveG_S_TMSIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8855,11 +10254,11 @@ pub struct FiveG_S_TMSIIE_Extensions_Entry {}
 )]
 pub struct FiveG_S_TMSIIE_Extensions(pub Vec<FiveG_S_TMSIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ForbiddenAreaInformation_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8870,11 +10269,11 @@ pub struct ForbiddenAreaInformation_ItemIE_Extensions(
     pub Vec<ForbiddenAreaInformation_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FromEUTRANtoNGRANIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8883,11 +10282,11 @@ pub struct FromEUTRANtoNGRANIE_Extensions_Entry {}
 )]
 pub struct FromEUTRANtoNGRANIE_Extensions(pub Vec<FromEUTRANtoNGRANIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FromNGRANtoEUTRANIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8896,23 +10295,25 @@ pub struct FromNGRANtoEUTRANIE_Extensions_Entry {}
 )]
 pub struct FromNGRANtoEUTRANIE_Extensions(pub Vec<FromNGRANtoEUTRANIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum GBR_QosInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 220)]
     Id_AlternativeQoSParaSetList(AlternativeQoSParaSetList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GBR_QosInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: GBR_QosInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8921,19 +10322,19 @@ pub struct GBR_QosInformationIE_Extensions_Entry {
 )]
 pub struct GBR_QosInformationIE_Extensions(pub Vec<GBR_QosInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "22", sz_ub = "32")]
 pub struct BIT_STRING_26(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GNB_IDchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GTPTunnelIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8942,11 +10343,11 @@ pub struct GTPTunnelIE_Extensions_Entry {}
 )]
 pub struct GTPTunnelIE_Extensions(pub Vec<GTPTunnelIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GUAMIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8955,11 +10356,11 @@ pub struct GUAMIIE_Extensions_Entry {}
 )]
 pub struct GUAMIIE_Extensions(pub Vec<GUAMIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalENB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8968,11 +10369,11 @@ pub struct GlobalENB_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalENB_IDIE_Extensions(pub Vec<GlobalENB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalGNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8981,11 +10382,11 @@ pub struct GlobalGNB_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalGNB_IDIE_Extensions(pub Vec<GlobalGNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalLine_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8994,11 +10395,11 @@ pub struct GlobalLine_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalLine_IDIE_Extensions(pub Vec<GlobalLine_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalN3IWF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9007,11 +10408,11 @@ pub struct GlobalN3IWF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalN3IWF_IDIE_Extensions(pub Vec<GlobalN3IWF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalNgENB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9020,7 +10421,7 @@ pub struct GlobalNgENB_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalNgENB_IDIE_Extensions(pub Vec<GlobalNgENB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum GlobalRANNodeIDchoice_ExtensionsValue {
     #[asn(key = 240)]
@@ -9031,20 +10432,22 @@ pub enum GlobalRANNodeIDchoice_ExtensionsValue {
     Id_GlobalW_AGF_ID(GlobalW_AGF_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalRANNodeIDchoice_Extensions {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: GlobalRANNodeIDchoice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalTNGF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9053,11 +10456,11 @@ pub struct GlobalTNGF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalTNGF_IDIE_Extensions(pub Vec<GlobalTNGF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalTWIF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9066,11 +10469,11 @@ pub struct GlobalTWIF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalTWIF_IDIE_Extensions(pub Vec<GlobalTWIF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalW_AGF_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9079,7 +10482,7 @@ pub struct GlobalW_AGF_IDIE_Extensions_Entry {}
 )]
 pub struct GlobalW_AGF_IDIE_Extensions(pub Vec<GlobalW_AGF_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct ENUMERATED_27(pub u8);
 impl ENUMERATED_27 {
@@ -9088,15 +10491,15 @@ impl ENUMERATED_27 {
     pub const INTERSYSTEM_PING_PONG: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct BIT_STRING_28(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HOReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9105,7 +10508,7 @@ pub struct HOReportIE_Extensions_Entry {}
 )]
 pub struct HOReportIE_Extensions(pub Vec<HOReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverCancelProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9116,16 +10519,18 @@ pub enum HandoverCancelProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCancelProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverCancelProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9134,7 +10539,7 @@ pub struct HandoverCancelProtocolIEs_Entry {
 )]
 pub struct HandoverCancelProtocolIEs(pub Vec<HandoverCancelProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverCancelAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9145,16 +10550,18 @@ pub enum HandoverCancelAcknowledgeProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCancelAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverCancelAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9165,7 +10572,7 @@ pub struct HandoverCancelAcknowledgeProtocolIEs(
     pub Vec<HandoverCancelAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverCommandProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9186,16 +10593,18 @@ pub enum HandoverCommandProtocolIEs_EntryValue {
     Id_TargetToSource_TransparentContainer(TargetToSource_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9204,7 +10613,7 @@ pub struct HandoverCommandProtocolIEs_Entry {
 )]
 pub struct HandoverCommandProtocolIEs(pub Vec<HandoverCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverCommandTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 152)]
@@ -9217,16 +10626,18 @@ pub enum HandoverCommandTransferIE_Extensions_EntryExtensionValue {
     Id_ULForwardingUP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCommandTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: HandoverCommandTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9237,7 +10648,7 @@ pub struct HandoverCommandTransferIE_Extensions(
     pub Vec<HandoverCommandTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9250,16 +10661,18 @@ pub enum HandoverFailureProtocolIEs_EntryValue {
     Id_TargettoSource_Failure_TransparentContainer(TargettoSource_Failure_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9268,7 +10681,7 @@ pub struct HandoverFailureProtocolIEs_Entry {
 )]
 pub struct HandoverFailureProtocolIEs(pub Vec<HandoverFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverNotifyProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9281,16 +10694,18 @@ pub enum HandoverNotifyProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverNotifyProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverNotifyProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9299,7 +10714,7 @@ pub struct HandoverNotifyProtocolIEs_Entry {
 )]
 pub struct HandoverNotifyProtocolIEs(pub Vec<HandoverNotifyProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverPreparationFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9314,16 +10729,18 @@ pub enum HandoverPreparationFailureProtocolIEs_EntryValue {
     Id_TargettoSource_Failure_TransparentContainer(TargettoSource_Failure_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverPreparationFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverPreparationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9334,11 +10751,11 @@ pub struct HandoverPreparationFailureProtocolIEs(
     pub Vec<HandoverPreparationFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverPreparationUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9349,7 +10766,7 @@ pub struct HandoverPreparationUnsuccessfulTransferIE_Extensions(
     pub Vec<HandoverPreparationUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9422,16 +10839,18 @@ pub enum HandoverRequestProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9440,7 +10859,7 @@ pub struct HandoverRequestProtocolIEs_Entry {
 )]
 pub struct HandoverRequestProtocolIEs(pub Vec<HandoverRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9457,16 +10876,18 @@ pub enum HandoverRequestAcknowledgeProtocolIEs_EntryValue {
     Id_TargetToSource_TransparentContainer(TargetToSource_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverRequestAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9477,7 +10898,7 @@ pub struct HandoverRequestAcknowledgeProtocolIEs(
     pub Vec<HandoverRequestAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 153)]
@@ -9496,16 +10917,18 @@ pub enum HandoverRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     Id_UsedRSNInformation(RedundantPDUSessionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestAcknowledgeTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: HandoverRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9516,7 +10939,7 @@ pub struct HandoverRequestAcknowledgeTransferIE_Extensions(
     pub Vec<HandoverRequestAcknowledgeTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequiredProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9537,16 +10960,18 @@ pub enum HandoverRequiredProtocolIEs_EntryValue {
     Id_TargetID(TargetID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequiredProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverRequiredProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9555,11 +10980,11 @@ pub struct HandoverRequiredProtocolIEs_Entry {
 )]
 pub struct HandoverRequiredProtocolIEs(pub Vec<HandoverRequiredProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequiredTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9570,11 +10995,11 @@ pub struct HandoverRequiredTransferIE_Extensions(
     pub Vec<HandoverRequiredTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverResourceAllocationUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9585,7 +11010,7 @@ pub struct HandoverResourceAllocationUnsuccessfulTransferIE_Extensions(
     pub Vec<HandoverResourceAllocationUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverSuccessProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9594,16 +11019,18 @@ pub enum HandoverSuccessProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverSuccessProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverSuccessProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9612,11 +11039,11 @@ pub struct HandoverSuccessProtocolIEs_Entry {
 )]
 pub struct HandoverSuccessProtocolIEs(pub Vec<HandoverSuccessProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ImmediateMDTNrIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9625,11 +11052,11 @@ pub struct ImmediateMDTNrIE_Extensions_Entry {}
 )]
 pub struct ImmediateMDTNrIE_Extensions(pub Vec<ImmediateMDTNrIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9640,7 +11067,7 @@ pub struct InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions(
     pub Vec<InfoOnRecommendedCellsAndRANNodesForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9655,16 +11082,18 @@ pub enum InitialContextSetupFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialContextSetupFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9675,7 +11104,7 @@ pub struct InitialContextSetupFailureProtocolIEs(
     pub Vec<InitialContextSetupFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9754,16 +11183,18 @@ pub enum InitialContextSetupRequestProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialContextSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9774,7 +11205,7 @@ pub struct InitialContextSetupRequestProtocolIEs(
     pub Vec<InitialContextSetupRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -9789,16 +11220,18 @@ pub enum InitialContextSetupResponseProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialContextSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9809,7 +11242,7 @@ pub struct InitialContextSetupResponseProtocolIEs(
     pub Vec<InitialContextSetupResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialUEMessageProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -9846,16 +11279,18 @@ pub enum InitialUEMessageProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialUEMessageProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialUEMessageProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9864,7 +11299,7 @@ pub struct InitialUEMessageProtocolIEs_Entry {
 )]
 pub struct InitialUEMessageProtocolIEs(pub Vec<InitialUEMessageProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitiatingMessageValue {
     #[asn(key = 64)]
@@ -10001,11 +11436,11 @@ pub enum InitiatingMessageValue {
     Id_WriteReplaceWarning(WriteReplaceWarningRequest),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemFailureIndicationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10016,11 +11451,11 @@ pub struct InterSystemFailureIndicationIE_Extensions(
     pub Vec<InterSystemFailureIndicationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemHOReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10029,15 +11464,15 @@ pub struct InterSystemHOReportIE_Extensions_Entry {}
 )]
 pub struct InterSystemHOReportIE_Extensions(pub Vec<InterSystemHOReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemHandoverReportTypechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONConfigurationTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10048,19 +11483,19 @@ pub struct IntersystemSONConfigurationTransferIE_Extensions(
     pub Vec<IntersystemSONConfigurationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONInformationchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONInformationReportchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONNGRANnodeIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10071,15 +11506,15 @@ pub struct IntersystemSONNGRANnodeIDIE_Extensions(
     pub Vec<IntersystemSONNGRANnodeIDIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONTransferTypechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemSONeNBIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10088,7 +11523,7 @@ pub struct IntersystemSONeNBIDIE_Extensions_Entry {}
 )]
 pub struct IntersystemSONeNBIDIE_Extensions(pub Vec<IntersystemSONeNBIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_29(pub u8);
 impl ENUMERATED_29 {
@@ -10096,11 +11531,11 @@ impl ENUMERATED_29 {
     pub const FALSE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemUnnecessaryHOIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10111,11 +11546,11 @@ pub struct IntersystemUnnecessaryHOIE_Extensions(
     pub Vec<IntersystemUnnecessaryHOIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10124,11 +11559,11 @@ pub struct LAIIE_Extensions_Entry {}
 )]
 pub struct LAIIE_Extensions(pub Vec<LAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LTEUESidelinkAggregateMaximumBitrateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10139,11 +11574,11 @@ pub struct LTEUESidelinkAggregateMaximumBitrateIE_Extensions(
     pub Vec<LTEUESidelinkAggregateMaximumBitrateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LTEV2XServicesAuthorizedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10154,15 +11589,15 @@ pub struct LTEV2XServicesAuthorizedIE_Extensions(
     pub Vec<LTEV2XServicesAuthorizedIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastVisitedCellInformationchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastVisitedCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10171,11 +11606,11 @@ pub struct LastVisitedCellItemIE_Extensions_Entry {}
 )]
 pub struct LastVisitedCellItemIE_Extensions(pub Vec<LastVisitedCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastVisitedNGRANCellInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10186,7 +11621,7 @@ pub struct LastVisitedNGRANCellInformationIE_Extensions(
     pub Vec<LastVisitedNGRANCellInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10201,16 +11636,18 @@ pub enum LocationReportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10219,7 +11656,7 @@ pub struct LocationReportProtocolIEs_Entry {
 )]
 pub struct LocationReportProtocolIEs(pub Vec<LocationReportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingControlProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10230,16 +11667,18 @@ pub enum LocationReportingControlProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingControlProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportingControlProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10248,7 +11687,7 @@ pub struct LocationReportingControlProtocolIEs_Entry {
 )]
 pub struct LocationReportingControlProtocolIEs(pub Vec<LocationReportingControlProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10259,16 +11698,18 @@ pub enum LocationReportingFailureIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingFailureIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportingFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10279,23 +11720,25 @@ pub struct LocationReportingFailureIndicationProtocolIEs(
     pub Vec<LocationReportingFailureIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingRequestTypeIE_Extensions_EntryExtensionValue {
     #[asn(key = 170)]
     Id_LocationReportingAdditionalInfo(LocationReportingAdditionalInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingRequestTypeIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LocationReportingRequestTypeIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10306,11 +11749,11 @@ pub struct LocationReportingRequestTypeIE_Extensions(
     pub Vec<LocationReportingRequestTypeIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LoggedMDTNrIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10319,19 +11762,19 @@ pub struct LoggedMDTNrIE_Extensions_Entry {}
 )]
 pub struct LoggedMDTNrIE_Extensions(pub Vec<LoggedMDTNrIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_30;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LoggedMDTTriggerchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10340,11 +11783,11 @@ pub struct M1ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M1ConfigurationIE_Extensions(pub Vec<M1ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1PeriodicReportingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10353,11 +11796,11 @@ pub struct M1PeriodicReportingIE_Extensions_Entry {}
 )]
 pub struct M1PeriodicReportingIE_Extensions(pub Vec<M1PeriodicReportingIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1ThresholdEventA2IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10366,15 +11809,15 @@ pub struct M1ThresholdEventA2IE_Extensions_Entry {}
 )]
 pub struct M1ThresholdEventA2IE_Extensions(pub Vec<M1ThresholdEventA2IE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1ThresholdTypechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M4ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10383,11 +11826,11 @@ pub struct M4ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M4ConfigurationIE_Extensions(pub Vec<M4ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M5ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10396,11 +11839,11 @@ pub struct M5ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M5ConfigurationIE_Extensions(pub Vec<M5ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M6ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10409,11 +11852,11 @@ pub struct M6ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M6ConfigurationIE_Extensions(pub Vec<M6ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M7ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10422,11 +11865,11 @@ pub struct M7ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M7ConfigurationIE_Extensions(pub Vec<M7ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10435,11 +11878,11 @@ pub struct MDT_ConfigurationIE_Extensions_Entry {}
 )]
 pub struct MDT_ConfigurationIE_Extensions(pub Vec<MDT_ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_Configuration_EUTRAIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10450,11 +11893,11 @@ pub struct MDT_Configuration_EUTRAIE_Extensions(
     pub Vec<MDT_Configuration_EUTRAIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_Configuration_NRIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10463,11 +11906,11 @@ pub struct MDT_Configuration_NRIE_Extensions_Entry {}
 )]
 pub struct MDT_Configuration_NRIE_Extensions(pub Vec<MDT_Configuration_NRIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_Location_InfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10476,15 +11919,15 @@ pub struct MDT_Location_InfoIE_Extensions_Entry {}
 )]
 pub struct MDT_Location_InfoIE_Extensions(pub Vec<MDT_Location_InfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDTModeNrchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MeasurementThresholdL1LoggedMDTchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MobilityRestrictionListIE_Extensions_EntryExtensionValue {
     #[asn(key = 160)]
@@ -10497,16 +11940,18 @@ pub enum MobilityRestrictionListIE_Extensions_EntryExtensionValue {
     Id_NPN_MobilityInformation(NPN_MobilityInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MobilityRestrictionListIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: MobilityRestrictionListIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10517,15 +11962,15 @@ pub struct MobilityRestrictionListIE_Extensions(
     pub Vec<MobilityRestrictionListIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct BIT_STRING_31(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct N3IWF_IDchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NASNonDeliveryIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -10538,16 +11983,18 @@ pub enum NASNonDeliveryIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NASNonDeliveryIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NASNonDeliveryIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10556,11 +12003,11 @@ pub struct NASNonDeliveryIndicationProtocolIEs_Entry {
 )]
 pub struct NASNonDeliveryIndicationProtocolIEs(pub Vec<NASNonDeliveryIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NB_IoT_Paging_eDRXInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10569,15 +12016,15 @@ pub struct NB_IoT_Paging_eDRXInfoIE_Extensions_Entry {}
 )]
 pub struct NB_IoT_Paging_eDRXInfoIE_Extensions(pub Vec<NB_IoT_Paging_eDRXInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGRAN_CGIchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGRAN_TNLAssociationToRemoveItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10588,7 +12035,7 @@ pub struct NGRAN_TNLAssociationToRemoveItemIE_Extensions(
     pub Vec<NGRAN_TNLAssociationToRemoveItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NGResetProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -10597,16 +12044,18 @@ pub enum NGResetProtocolIEs_EntryValue {
     Id_ResetType(ResetType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGResetProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NGResetProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10615,7 +12064,7 @@ pub struct NGResetProtocolIEs_Entry {
 )]
 pub struct NGResetProtocolIEs(pub Vec<NGResetProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NGResetAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 19)]
@@ -10624,16 +12073,18 @@ pub enum NGResetAcknowledgeProtocolIEs_EntryValue {
     Id_UE_associatedLogicalNG_connectionList(UE_associatedLogicalNG_connectionList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGResetAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NGResetAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10642,7 +12093,7 @@ pub struct NGResetAcknowledgeProtocolIEs_Entry {
 )]
 pub struct NGResetAcknowledgeProtocolIEs(pub Vec<NGResetAcknowledgeProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NGSetupFailureProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -10653,16 +12104,18 @@ pub enum NGSetupFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGSetupFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NGSetupFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10671,7 +12124,7 @@ pub struct NGSetupFailureProtocolIEs_Entry {
 )]
 pub struct NGSetupFailureProtocolIEs(pub Vec<NGSetupFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NGSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 21)]
@@ -10690,16 +12143,18 @@ pub enum NGSetupRequestProtocolIEs_EntryValue {
     Id_UERetentionInformation(UERetentionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGSetupRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NGSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10708,7 +12163,7 @@ pub struct NGSetupRequestProtocolIEs_Entry {
 )]
 pub struct NGSetupRequestProtocolIEs(pub Vec<NGSetupRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NGSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 1)]
@@ -10729,16 +12184,18 @@ pub enum NGSetupResponseProtocolIEs_EntryValue {
     Id_UERetentionInformation(UERetentionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NGSetupResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NGSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10747,27 +12204,27 @@ pub struct NGSetupResponseProtocolIEs_Entry {
 )]
 pub struct NGSetupResponseProtocolIEs(pub Vec<NGSetupResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NPN_AccessInformationchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NPN_MobilityInformationchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NPN_PagingAssistanceInformationchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NPN_Supportchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NR_CGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10776,11 +12233,11 @@ pub struct NR_CGIIE_Extensions_Entry {}
 )]
 pub struct NR_CGIIE_Extensions(pub Vec<NR_CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRFrequencyBandItemIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10789,11 +12246,11 @@ pub struct NRFrequencyBandItemIE_Extension_Entry {}
 )]
 pub struct NRFrequencyBandItemIE_Extension(pub Vec<NRFrequencyBandItemIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRFrequencyInfoIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10802,11 +12259,11 @@ pub struct NRFrequencyInfoIE_Extension_Entry {}
 )]
 pub struct NRFrequencyInfoIE_Extension(pub Vec<NRFrequencyInfoIE_Extension_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRUESidelinkAggregateMaximumBitrateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10817,11 +12274,11 @@ pub struct NRUESidelinkAggregateMaximumBitrateIE_Extensions(
     pub Vec<NRUESidelinkAggregateMaximumBitrateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRV2XServicesAuthorizedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10832,23 +12289,23 @@ pub struct NRV2XServicesAuthorizedIE_Extensions(
     pub Vec<NRV2XServicesAuthorizedIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "20", sz_ub = "20")]
 pub struct BIT_STRING_32(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "18", sz_ub = "18")]
 pub struct BIT_STRING_33(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "21", sz_ub = "21")]
 pub struct BIT_STRING_34(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NgENB_IDchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NonDynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     #[asn(key = 187)]
@@ -10857,16 +12314,18 @@ pub enum NonDynamic5QIDescriptorIE_Extensions_EntryExtensionValue {
     Id_CNPacketDelayBudgetUL(ExtendedPacketDelayBudget),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NonDynamic5QIDescriptorIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: NonDynamic5QIDescriptorIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10877,11 +12336,11 @@ pub struct NonDynamic5QIDescriptorIE_Extensions(
     pub Vec<NonDynamic5QIDescriptorIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadResponsechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum OverloadStartProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -10892,16 +12351,18 @@ pub enum OverloadStartProtocolIEs_EntryValue {
     Id_OverloadStartNSSAIList(OverloadStartNSSAIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStartProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: OverloadStartProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10910,11 +12371,11 @@ pub struct OverloadStartProtocolIEs_Entry {
 )]
 pub struct OverloadStartProtocolIEs(pub Vec<OverloadStartProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStartNSSAIItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10923,11 +12384,11 @@ pub struct OverloadStartNSSAIItemIE_Extensions_Entry {}
 )]
 pub struct OverloadStartNSSAIItemIE_Extensions(pub Vec<OverloadStartNSSAIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStopProtocolIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10936,11 +12397,11 @@ pub struct OverloadStopProtocolIEs_Entry {}
 )]
 pub struct OverloadStopProtocolIEs(pub Vec<OverloadStopProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5FlowBitRatesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10949,11 +12410,11 @@ pub struct PC5FlowBitRatesIE_Extensions_Entry {}
 )]
 pub struct PC5FlowBitRatesIE_Extensions(pub Vec<PC5FlowBitRatesIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5QoSFlowItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10962,11 +12423,11 @@ pub struct PC5QoSFlowItemIE_Extensions_Entry {}
 )]
 pub struct PC5QoSFlowItemIE_Extensions(pub Vec<PC5QoSFlowItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5QoSParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10975,11 +12436,11 @@ pub struct PC5QoSParametersIE_Extensions_Entry {}
 )]
 pub struct PC5QoSParametersIE_Extensions(pub Vec<PC5QoSParametersIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionAggregateMaximumBitRateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10990,15 +12451,33 @@ pub struct PDUSessionAggregateMaximumBitRateIE_Extensions(
     pub Vec<PDUSessionAggregateMaximumBitRateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_35(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_35 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<HandoverRequestAcknowledgeTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <HandoverRequestAcknowledgeTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &HandoverRequestAcknowledgeTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <HandoverRequestAcknowledgeTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceAdmittedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11009,15 +12488,38 @@ pub struct PDUSessionResourceAdmittedItemIE_Extensions(
     pub Vec<PDUSessionResourceAdmittedItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_36(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_36 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<
+        PDUSessionResourceModifyIndicationUnsuccessfulTransfer,
+        asn1_codecs::aper::AperCodecError,
+    > {
+        let (value, _) =
+            <PDUSessionResourceModifyIndicationUnsuccessfulTransfer as asn1_codecs::Decode<
+                asn1_codecs::aper::Aper,
+            >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceModifyIndicationUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <PDUSessionResourceModifyIndicationUnsuccessfulTransfer as asn1_codecs::Encode<
+                asn1_codecs::aper::Aper,
+            >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToModifyItemModCfmIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11028,15 +12530,34 @@ pub struct PDUSessionResourceFailedToModifyItemModCfmIE_Extensions(
     pub Vec<PDUSessionResourceFailedToModifyItemModCfmIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_37(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_37 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceModifyUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError>
+    {
+        let (value, _) = <PDUSessionResourceModifyUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceModifyUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceModifyUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToModifyItemModResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11047,11 +12568,11 @@ pub struct PDUSessionResourceFailedToModifyItemModResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToModifyItemModResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToResumeItemRESReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11062,11 +12583,11 @@ pub struct PDUSessionResourceFailedToResumeItemRESReqIE_Extensions(
     pub Vec<PDUSessionResourceFailedToResumeItemRESReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToResumeItemRESResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11077,15 +12598,34 @@ pub struct PDUSessionResourceFailedToResumeItemRESResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToResumeItemRESResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_38(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_38 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError>
+    {
+        let (value, _) = <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11096,15 +12636,34 @@ pub struct PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtFailIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_39(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_39 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError>
+    {
+        let (value, _) = <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemCxtResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11115,15 +12674,34 @@ pub struct PDUSessionResourceFailedToSetupItemCxtResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemCxtResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_40(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_40 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<HandoverResourceAllocationUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError>
+    {
+        let (value, _) = <HandoverResourceAllocationUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &HandoverResourceAllocationUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <HandoverResourceAllocationUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemHOAckIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11134,15 +12712,33 @@ pub struct PDUSessionResourceFailedToSetupItemHOAckIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemHOAckIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_41(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_41 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PathSwitchRequestSetupFailedTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PathSwitchRequestSetupFailedTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PathSwitchRequestSetupFailedTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PathSwitchRequestSetupFailedTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemPSReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11153,15 +12749,34 @@ pub struct PDUSessionResourceFailedToSetupItemPSReqIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemPSReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_42(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_42 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError>
+    {
+        let (value, _) = <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceFailedToSetupItemSUResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11172,15 +12787,34 @@ pub struct PDUSessionResourceFailedToSetupItemSUResIE_Extensions(
     pub Vec<PDUSessionResourceFailedToSetupItemSUResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_43(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_43 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<HandoverCommandTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <HandoverCommandTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &HandoverCommandTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <HandoverCommandTransfer as asn1_codecs::Encode<asn1_codecs::aper::Aper>>::to_bytes(
+                value,
+            )?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceHandoverItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11191,11 +12825,11 @@ pub struct PDUSessionResourceHandoverItemIE_Extensions(
     pub Vec<PDUSessionResourceHandoverItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11206,20 +12840,22 @@ pub struct PDUSessionResourceInformationItemIE_Extensions(
     pub Vec<PDUSessionResourceInformationItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceItemCxtRelCplIE_Extensions_EntryExtensionValue {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceItemCxtRelCplIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceItemCxtRelCplIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11230,11 +12866,11 @@ pub struct PDUSessionResourceItemCxtRelCplIE_Extensions(
     pub Vec<PDUSessionResourceItemCxtRelCplIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceItemCxtRelReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11245,15 +12881,34 @@ pub struct PDUSessionResourceItemCxtRelReqIE_Extensions(
     pub Vec<PDUSessionResourceItemCxtRelReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_44(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_44 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<HandoverRequiredTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <HandoverRequiredTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &HandoverRequiredTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <HandoverRequiredTransfer as asn1_codecs::Encode<asn1_codecs::aper::Aper>>::to_bytes(
+                value,
+            )?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceItemHORqdIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11264,7 +12919,7 @@ pub struct PDUSessionResourceItemHORqdIE_Extensions(
     pub Vec<PDUSessionResourceItemHORqdIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyConfirmProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11279,16 +12934,18 @@ pub enum PDUSessionResourceModifyConfirmProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyConfirmProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceModifyConfirmProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11299,7 +12956,7 @@ pub struct PDUSessionResourceModifyConfirmProtocolIEs(
     pub Vec<PDUSessionResourceModifyConfirmProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyConfirmTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 185)]
@@ -11308,16 +12965,18 @@ pub enum PDUSessionResourceModifyConfirmTransferIE_Extensions_EntryExtensionValu
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyConfirmTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceModifyConfirmTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11328,7 +12987,7 @@ pub struct PDUSessionResourceModifyConfirmTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyConfirmTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11341,16 +13000,18 @@ pub enum PDUSessionResourceModifyIndicationProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceModifyIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11361,7 +13022,7 @@ pub struct PDUSessionResourceModifyIndicationProtocolIEs(
     pub Vec<PDUSessionResourceModifyIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyIndicationTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 184)]
@@ -11376,17 +13037,19 @@ pub enum PDUSessionResourceModifyIndicationTransferIE_Extensions_EntryExtensionV
     Id_SecurityResult(SecurityResult),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyIndicationTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         PDUSessionResourceModifyIndicationTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11397,11 +13060,11 @@ pub struct PDUSessionResourceModifyIndicationTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyIndicationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11412,15 +13075,33 @@ pub struct PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyIndicationUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_45(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_45 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceModifyConfirmTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceModifyConfirmTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceModifyConfirmTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceModifyConfirmTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModCfmIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11431,15 +13112,33 @@ pub struct PDUSessionResourceModifyItemModCfmIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModCfmIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_46(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_46 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceModifyIndicationTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceModifyIndicationTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceModifyIndicationTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceModifyIndicationTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModIndIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11450,27 +13149,47 @@ pub struct PDUSessionResourceModifyItemModIndIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModIndIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_47(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_47 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceModifyRequestTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceModifyRequestTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceModifyRequestTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceModifyRequestTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyItemModReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 148)]
     Id_S_NSSAI(S_NSSAI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModReqIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceModifyItemModReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11481,15 +13200,33 @@ pub struct PDUSessionResourceModifyItemModReqIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_48(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_48 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceModifyResponseTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceModifyResponseTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceModifyResponseTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceModifyResponseTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyItemModResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11500,7 +13237,7 @@ pub struct PDUSessionResourceModifyItemModResIE_Extensions(
     pub Vec<PDUSessionResourceModifyItemModResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11513,16 +13250,18 @@ pub enum PDUSessionResourceModifyRequestProtocolIEs_EntryValue {
     Id_RANPagingPriority(RANPagingPriority),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceModifyRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11533,7 +13272,7 @@ pub struct PDUSessionResourceModifyRequestProtocolIEs(
     pub Vec<PDUSessionResourceModifyRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyRequestTransferProtocolIEs_EntryValue {
     #[asn(key = 186)]
@@ -11560,16 +13299,18 @@ pub enum PDUSessionResourceModifyRequestTransferProtocolIEs_EntryValue {
     Id_UL_NGU_UP_TNLModifyList(UL_NGU_UP_TNLModifyList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyRequestTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceModifyRequestTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11580,7 +13321,7 @@ pub struct PDUSessionResourceModifyRequestTransferProtocolIEs(
     pub Vec<PDUSessionResourceModifyRequestTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11597,16 +13338,18 @@ pub enum PDUSessionResourceModifyResponseProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceModifyResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11617,7 +13360,7 @@ pub struct PDUSessionResourceModifyResponseProtocolIEs(
     pub Vec<PDUSessionResourceModifyResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceModifyResponseTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 154)]
@@ -11632,16 +13375,18 @@ pub enum PDUSessionResourceModifyResponseTransferIE_Extensions_EntryExtensionVal
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyResponseTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceModifyResponseTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11652,11 +13397,11 @@ pub struct PDUSessionResourceModifyResponseTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11667,7 +13412,7 @@ pub struct PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions(
     pub Vec<PDUSessionResourceModifyUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceNotifyProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11682,16 +13427,18 @@ pub enum PDUSessionResourceNotifyProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceNotifyProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11700,15 +13447,33 @@ pub struct PDUSessionResourceNotifyProtocolIEs_Entry {
 )]
 pub struct PDUSessionResourceNotifyProtocolIEs(pub Vec<PDUSessionResourceNotifyProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_49(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_49 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceNotifyTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceNotifyTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceNotifyTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceNotifyTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11719,23 +13484,25 @@ pub struct PDUSessionResourceNotifyItemIE_Extensions(
     pub Vec<PDUSessionResourceNotifyItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceNotifyReleasedTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 144)]
     Id_SecondaryRATUsageInformation(SecondaryRATUsageInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyReleasedTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceNotifyReleasedTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11746,7 +13513,7 @@ pub struct PDUSessionResourceNotifyReleasedTransferIE_Extensions(
     pub Vec<PDUSessionResourceNotifyReleasedTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceNotifyTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 278)]
@@ -11755,16 +13522,18 @@ pub enum PDUSessionResourceNotifyTransferIE_Extensions_EntryExtensionValue {
     Id_SecondaryRATUsageInformation(SecondaryRATUsageInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceNotifyTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceNotifyTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11775,7 +13544,7 @@ pub struct PDUSessionResourceNotifyTransferIE_Extensions(
     pub Vec<PDUSessionResourceNotifyTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceReleaseCommandProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11790,16 +13559,18 @@ pub enum PDUSessionResourceReleaseCommandProtocolIEs_EntryValue {
     Id_RANPagingPriority(RANPagingPriority),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceReleaseCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11810,11 +13581,11 @@ pub struct PDUSessionResourceReleaseCommandProtocolIEs(
     pub Vec<PDUSessionResourceReleaseCommandProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseCommandTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11825,7 +13596,7 @@ pub struct PDUSessionResourceReleaseCommandTransferIE_Extensions(
     pub Vec<PDUSessionResourceReleaseCommandTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceReleaseResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -11840,16 +13611,18 @@ pub enum PDUSessionResourceReleaseResponseProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceReleaseResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11860,23 +13633,25 @@ pub struct PDUSessionResourceReleaseResponseProtocolIEs(
     pub Vec<PDUSessionResourceReleaseResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceReleaseResponseTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 144)]
     Id_SecondaryRATUsageInformation(SecondaryRATUsageInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleaseResponseTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceReleaseResponseTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11887,15 +13662,33 @@ pub struct PDUSessionResourceReleaseResponseTransferIE_Extensions(
     pub Vec<PDUSessionResourceReleaseResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_50(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_50 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceNotifyReleasedTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceNotifyReleasedTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceNotifyReleasedTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceNotifyReleasedTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemNotIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11906,15 +13699,33 @@ pub struct PDUSessionResourceReleasedItemNotIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemNotIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_51(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_51 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PathSwitchRequestUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PathSwitchRequestUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PathSwitchRequestUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PathSwitchRequestUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemPSAckIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11925,15 +13736,33 @@ pub struct PDUSessionResourceReleasedItemPSAckIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemPSAckIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_52(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_52 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PathSwitchRequestUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PathSwitchRequestUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PathSwitchRequestUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PathSwitchRequestUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemPSFailIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11944,15 +13773,33 @@ pub struct PDUSessionResourceReleasedItemPSFailIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemPSFailIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_53(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_53 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceReleaseResponseTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceReleaseResponseTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceReleaseResponseTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceReleaseResponseTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceReleasedItemRelResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11963,15 +13810,33 @@ pub struct PDUSessionResourceReleasedItemRelResIE_Extensions(
     pub Vec<PDUSessionResourceReleasedItemRelResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_54(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_54 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<UEContextResumeRequestTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <UEContextResumeRequestTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &UEContextResumeRequestTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <UEContextResumeRequestTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceResumeItemRESReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11982,15 +13847,33 @@ pub struct PDUSessionResourceResumeItemRESReqIE_Extensions(
     pub Vec<PDUSessionResourceResumeItemRESReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_55(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_55 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<UEContextResumeResponseTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <UEContextResumeResponseTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &UEContextResumeResponseTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <UEContextResumeResponseTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceResumeItemRESResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12001,15 +13884,33 @@ pub struct PDUSessionResourceResumeItemRESResIE_Extensions(
     pub Vec<PDUSessionResourceResumeItemRESResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_56(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_56 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<SecondaryRATDataUsageReportTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <SecondaryRATDataUsageReportTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &SecondaryRATDataUsageReportTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <SecondaryRATDataUsageReportTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSecondaryRATUsageItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12020,15 +13921,33 @@ pub struct PDUSessionResourceSecondaryRATUsageItemIE_Extensions(
     pub Vec<PDUSessionResourceSecondaryRATUsageItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_57(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_57 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupRequestTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceSetupRequestTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupRequestTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupRequestTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemCxtReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12039,15 +13958,33 @@ pub struct PDUSessionResourceSetupItemCxtReqIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemCxtReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_58(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_58 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupResponseTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceSetupResponseTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupResponseTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupResponseTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemCxtResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12058,15 +13995,33 @@ pub struct PDUSessionResourceSetupItemCxtResIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemCxtResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_59(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_59 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupRequestTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceSetupRequestTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupRequestTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupRequestTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemHOReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12077,15 +14032,33 @@ pub struct PDUSessionResourceSetupItemHOReqIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemHOReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_60(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_60 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupRequestTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceSetupRequestTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupRequestTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupRequestTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemSUReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12096,15 +14069,33 @@ pub struct PDUSessionResourceSetupItemSUReqIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemSUReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_61(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_61 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceSetupResponseTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceSetupResponseTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceSetupResponseTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceSetupResponseTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupItemSUResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12115,7 +14106,7 @@ pub struct PDUSessionResourceSetupItemSUResIE_Extensions(
     pub Vec<PDUSessionResourceSetupItemSUResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12132,16 +14123,18 @@ pub enum PDUSessionResourceSetupRequestProtocolIEs_EntryValue {
     Id_UEAggregateMaximumBitRate(UEAggregateMaximumBitRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12152,7 +14145,7 @@ pub struct PDUSessionResourceSetupRequestProtocolIEs(
     pub Vec<PDUSessionResourceSetupRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupRequestTransferProtocolIEs_EntryValue {
     #[asn(key = 186)]
@@ -12185,16 +14178,18 @@ pub enum PDUSessionResourceSetupRequestTransferProtocolIEs_EntryValue {
     Id_UL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupRequestTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceSetupRequestTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12205,7 +14200,7 @@ pub struct PDUSessionResourceSetupRequestTransferProtocolIEs(
     pub Vec<PDUSessionResourceSetupRequestTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12220,16 +14215,18 @@ pub enum PDUSessionResourceSetupResponseProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PDUSessionResourceSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12240,7 +14237,7 @@ pub struct PDUSessionResourceSetupResponseProtocolIEs(
     pub Vec<PDUSessionResourceSetupResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PDUSessionResourceSetupResponseTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 184)]
@@ -12253,16 +14250,18 @@ pub enum PDUSessionResourceSetupResponseTransferIE_Extensions_EntryExtensionValu
     Id_UsedRSNInformation(RedundantPDUSessionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupResponseTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PDUSessionResourceSetupResponseTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12273,11 +14272,11 @@ pub struct PDUSessionResourceSetupResponseTransferIE_Extensions(
     pub Vec<PDUSessionResourceSetupResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12288,15 +14287,33 @@ pub struct PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions(
     pub Vec<PDUSessionResourceSetupUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_62(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_62 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<UEContextSuspendRequestTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <UEContextSuspendRequestTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &UEContextSuspendRequestTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <UEContextSuspendRequestTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSuspendItemSUSReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12307,15 +14324,33 @@ pub struct PDUSessionResourceSuspendItemSUSReqIE_Extensions(
     pub Vec<PDUSessionResourceSuspendItemSUSReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_63(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_63 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PathSwitchRequestAcknowledgeTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PathSwitchRequestAcknowledgeTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PathSwitchRequestAcknowledgeTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PathSwitchRequestAcknowledgeTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceSwitchedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12326,15 +14361,34 @@ pub struct PDUSessionResourceSwitchedItemIE_Extensions(
     pub Vec<PDUSessionResourceSwitchedItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_64(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_64 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PathSwitchRequestTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PathSwitchRequestTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PathSwitchRequestTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <PathSwitchRequestTransfer as asn1_codecs::Encode<asn1_codecs::aper::Aper>>::to_bytes(
+                value,
+            )?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceToBeSwitchedDLItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12345,15 +14399,33 @@ pub struct PDUSessionResourceToBeSwitchedDLItemIE_Extensions(
     pub Vec<PDUSessionResourceToBeSwitchedDLItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_65(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_65 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<HandoverPreparationUnsuccessfulTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <HandoverPreparationUnsuccessfulTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &HandoverPreparationUnsuccessfulTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <HandoverPreparationUnsuccessfulTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceToReleaseItemHOCmdIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12364,15 +14436,33 @@ pub struct PDUSessionResourceToReleaseItemHOCmdIE_Extensions(
     pub Vec<PDUSessionResourceToReleaseItemHOCmdIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_66(pub Vec<u8>);
-
-#[derive(Debug, AperCodec)]
+impl OCTET_STRING_66 {
+    pub fn decode_contained(
+        &self,
+    ) -> Result<PDUSessionResourceReleaseCommandTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) = <PDUSessionResourceReleaseCommandTransfer as asn1_codecs::Decode<
+            asn1_codecs::aper::Aper,
+        >>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(
+        value: &PDUSessionResourceReleaseCommandTransfer,
+    ) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes = <PDUSessionResourceReleaseCommandTransfer as asn1_codecs::Encode<
+            asn1_codecs::aper::Aper,
+        >>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionResourceToReleaseItemRelCmdIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12383,19 +14473,21 @@ pub struct PDUSessionResourceToReleaseItemRelCmdIE_Extensions(
     pub Vec<PDUSessionResourceToReleaseItemRelCmdIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_67(pub u8);
 impl ENUMERATED_67 {
     pub const NR: u8 = 0u8;
     pub const EUTRA: u8 = 1u8;
+    pub const NR_UNLICENSED: u8 = 2u8;
+    pub const E_UTRA_UNLICENSED: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PDUSessionUsageReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12404,7 +14496,7 @@ pub struct PDUSessionUsageReportIE_Extensions_Entry {}
 )]
 pub struct PDUSessionUsageReportIE_Extensions(pub Vec<PDUSessionUsageReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PLMNSupportItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 270)]
@@ -12413,16 +14505,18 @@ pub enum PLMNSupportItemIE_Extensions_EntryExtensionValue {
     Id_NPN_Support(NPN_Support),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PLMNSupportItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PLMNSupportItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12431,11 +14525,11 @@ pub struct PLMNSupportItemIE_Extensions_Entry {
 )]
 pub struct PLMNSupportItemIE_Extensions(pub Vec<PLMNSupportItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PNI_NPN_MobilityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12446,7 +14540,7 @@ pub struct PNI_NPN_MobilityInformationIE_Extensions(
     pub Vec<PNI_NPN_MobilityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PWSCancelRequestProtocolIEs_EntryValue {
     #[asn(key = 14)]
@@ -12459,16 +14553,18 @@ pub enum PWSCancelRequestProtocolIEs_EntryValue {
     Id_WarningAreaList(WarningAreaList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSCancelRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PWSCancelRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12477,7 +14573,7 @@ pub struct PWSCancelRequestProtocolIEs_Entry {
 )]
 pub struct PWSCancelRequestProtocolIEs(pub Vec<PWSCancelRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PWSCancelResponseProtocolIEs_EntryValue {
     #[asn(key = 12)]
@@ -12490,16 +14586,18 @@ pub enum PWSCancelResponseProtocolIEs_EntryValue {
     Id_SerialNumber(SerialNumber),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSCancelResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PWSCancelResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12508,11 +14606,11 @@ pub struct PWSCancelResponseProtocolIEs_Entry {
 )]
 pub struct PWSCancelResponseProtocolIEs(pub Vec<PWSCancelResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSFailedCellIDListchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PWSFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 27)]
@@ -12521,16 +14619,18 @@ pub enum PWSFailureIndicationProtocolIEs_EntryValue {
     Id_PWSFailedCellIDList(PWSFailedCellIDList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSFailureIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PWSFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12539,7 +14639,7 @@ pub struct PWSFailureIndicationProtocolIEs_Entry {
 )]
 pub struct PWSFailureIndicationProtocolIEs(pub Vec<PWSFailureIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PWSRestartIndicationProtocolIEs_EntryValue {
     #[asn(key = 16)]
@@ -12552,16 +14652,18 @@ pub enum PWSRestartIndicationProtocolIEs_EntryValue {
     Id_TAIListForRestart(TAIListForRestart),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSRestartIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PWSRestartIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12570,19 +14672,19 @@ pub struct PWSRestartIndicationProtocolIEs_Entry {
 )]
 pub struct PWSRestartIndicationProtocolIEs(pub Vec<PWSRestartIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "9", extensible = true)]
 pub struct INTEGER_68(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "9", extensible = true)]
 pub struct INTEGER_69(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PacketErrorRateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12591,7 +14693,7 @@ pub struct PacketErrorRateIE_Extensions_Entry {}
 )]
 pub struct PacketErrorRateIE_Extensions(pub Vec<PacketErrorRateIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PagingProtocolIEs_EntryValue {
     #[asn(key = 11)]
@@ -12622,16 +14724,18 @@ pub enum PagingProtocolIEs_EntryValue {
     Id_WUS_Assistance_Information(WUS_Assistance_Information),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PagingProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12640,11 +14744,11 @@ pub struct PagingProtocolIEs_Entry {
 )]
 pub struct PagingProtocolIEs(pub Vec<PagingProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingAssisDataforCEcapabUEIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12655,11 +14759,11 @@ pub struct PagingAssisDataforCEcapabUEIE_Extensions(
     pub Vec<PagingAssisDataforCEcapabUEIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingAttemptInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12670,11 +14774,11 @@ pub struct PagingAttemptInformationIE_Extensions(
     pub Vec<PagingAttemptInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingeDRXInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12683,7 +14787,7 @@ pub struct PagingeDRXInformationIE_Extensions_Entry {}
 )]
 pub struct PagingeDRXInformationIE_Extensions(pub Vec<PagingeDRXInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestProtocolIEs_EntryValue {
     #[asn(key = 57)]
@@ -12702,16 +14806,18 @@ pub enum PathSwitchRequestProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PathSwitchRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12720,7 +14826,7 @@ pub struct PathSwitchRequestProtocolIEs_Entry {
 )]
 pub struct PathSwitchRequestProtocolIEs(pub Vec<PathSwitchRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12775,16 +14881,18 @@ pub enum PathSwitchRequestAcknowledgeProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PathSwitchRequestAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12795,7 +14903,7 @@ pub struct PathSwitchRequestAcknowledgeProtocolIEs(
     pub Vec<PathSwitchRequestAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 154)]
@@ -12808,16 +14916,18 @@ pub enum PathSwitchRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue {
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestAcknowledgeTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PathSwitchRequestAcknowledgeTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12828,7 +14938,7 @@ pub struct PathSwitchRequestAcknowledgeTransferIE_Extensions(
     pub Vec<PathSwitchRequestAcknowledgeTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -12841,16 +14951,18 @@ pub enum PathSwitchRequestFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PathSwitchRequestFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12859,11 +14971,11 @@ pub struct PathSwitchRequestFailureProtocolIEs_Entry {
 )]
 pub struct PathSwitchRequestFailureProtocolIEs(pub Vec<PathSwitchRequestFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestSetupFailedTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12874,7 +14986,7 @@ pub struct PathSwitchRequestSetupFailedTransferIE_Extensions(
     pub Vec<PathSwitchRequestSetupFailedTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 155)]
@@ -12891,16 +15003,18 @@ pub enum PathSwitchRequestTransferIE_Extensions_EntryExtensionValue {
     Id_UsedRSNInformation(RedundantPDUSessionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PathSwitchRequestTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12911,11 +15025,11 @@ pub struct PathSwitchRequestTransferIE_Extensions(
     pub Vec<PathSwitchRequestTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestUnsuccessfulTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12926,19 +15040,19 @@ pub struct PathSwitchRequestUnsuccessfulTransferIE_Extensions(
     pub Vec<PathSwitchRequestUnsuccessfulTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct INTEGER_70(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OBJECT-IDENTIFIER")]
 pub struct OBJECT_IDENTIFIER_71;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PrivateMessagePrivateIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12947,23 +15061,25 @@ pub struct PrivateMessagePrivateIEs_Entry {}
 )]
 pub struct PrivateMessagePrivateIEs(pub Vec<PrivateMessagePrivateIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ProcedureStageChoicechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_72(pub u8);
 impl ENUMERATED_72 {
     pub const NR: u8 = 0u8;
     pub const EUTRA: u8 = 1u8;
+    pub const NR_UNLICENSED: u8 = 2u8;
+    pub const E_UTRA_UNLICENSED: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QoSFlowsUsageReport_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12974,27 +15090,29 @@ pub struct QoSFlowsUsageReport_ItemIE_Extensions(
     pub Vec<QoSFlowsUsageReport_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosCharacteristicschoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowAcceptedItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowAcceptedItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowAcceptedItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13003,7 +15121,7 @@ pub struct QosFlowAcceptedItemIE_Extensions_Entry {
 )]
 pub struct QosFlowAcceptedItemIE_Extensions(pub Vec<QosFlowAcceptedItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowAddOrModifyRequestItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 194)]
@@ -13012,16 +15130,18 @@ pub enum QosFlowAddOrModifyRequestItemIE_Extensions_EntryExtensionValue {
     Id_TSCTrafficCharacteristics(TSCTrafficCharacteristics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowAddOrModifyRequestItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowAddOrModifyRequestItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13032,23 +15152,25 @@ pub struct QosFlowAddOrModifyRequestItemIE_Extensions(
     pub Vec<QosFlowAddOrModifyRequestItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowAddOrModifyResponseItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowAddOrModifyResponseItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowAddOrModifyResponseItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13059,11 +15181,11 @@ pub struct QosFlowAddOrModifyResponseItemIE_Extensions(
     pub Vec<QosFlowAddOrModifyResponseItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowFeedbackItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13072,23 +15194,25 @@ pub struct QosFlowFeedbackItemIE_Extensions_Entry {}
 )]
 pub struct QosFlowFeedbackItemIE_Extensions(pub Vec<QosFlowFeedbackItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowInformationItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 163)]
     Id_ULForwarding(ULForwarding),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowInformationItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowInformationItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13097,23 +15221,25 @@ pub struct QosFlowInformationItemIE_Extensions_Entry {
 )]
 pub struct QosFlowInformationItemIE_Extensions(pub Vec<QosFlowInformationItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowItemWithDataForwardingIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowItemWithDataForwardingIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowItemWithDataForwardingIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13124,7 +15250,7 @@ pub struct QosFlowItemWithDataForwardingIE_Extensions(
     pub Vec<QosFlowItemWithDataForwardingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowLevelQosParametersIE_Extensions_EntryExtensionValue {
     #[asn(key = 276)]
@@ -13133,16 +15259,18 @@ pub enum QosFlowLevelQosParametersIE_Extensions_EntryExtensionValue {
     Id_QosMonitoringRequest(QosMonitoringRequest),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowLevelQosParametersIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowLevelQosParametersIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13153,11 +15281,11 @@ pub struct QosFlowLevelQosParametersIE_Extensions(
     pub Vec<QosFlowLevelQosParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowModifyConfirmItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13168,23 +15296,25 @@ pub struct QosFlowModifyConfirmItemIE_Extensions(
     pub Vec<QosFlowModifyConfirmItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowNotifyItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 221)]
     Id_CurrentQoSParaSetIndex(AlternativeQoSParaSetNotifyIndex),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowNotifyItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowNotifyItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13193,7 +15323,7 @@ pub struct QosFlowNotifyItemIE_Extensions_Entry {
 )]
 pub struct QosFlowNotifyItemIE_Extensions(pub Vec<QosFlowNotifyItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowParametersItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 279)]
@@ -13204,16 +15334,18 @@ pub enum QosFlowParametersItemIE_Extensions_EntryExtensionValue {
     Id_CNPacketDelayBudgetUL(ExtendedPacketDelayBudget),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowParametersItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowParametersItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13222,11 +15354,11 @@ pub struct QosFlowParametersItemIE_Extensions_Entry {
 )]
 pub struct QosFlowParametersItemIE_Extensions(pub Vec<QosFlowParametersItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowPerTNLInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13237,11 +15369,11 @@ pub struct QosFlowPerTNLInformationIE_Extensions(
     pub Vec<QosFlowPerTNLInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowPerTNLInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13252,7 +15384,7 @@ pub struct QosFlowPerTNLInformationItemIE_Extensions(
     pub Vec<QosFlowPerTNLInformationItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum QosFlowSetupRequestItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 194)]
@@ -13261,16 +15393,18 @@ pub enum QosFlowSetupRequestItemIE_Extensions_EntryExtensionValue {
     Id_TSCTrafficCharacteristics(TSCTrafficCharacteristics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowSetupRequestItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: QosFlowSetupRequestItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13281,11 +15415,11 @@ pub struct QosFlowSetupRequestItemIE_Extensions(
     pub Vec<QosFlowSetupRequestItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowToBeForwardedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13296,11 +15430,11 @@ pub struct QosFlowToBeForwardedItemIE_Extensions(
     pub Vec<QosFlowToBeForwardedItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct QosFlowWithCauseItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13309,7 +15443,7 @@ pub struct QosFlowWithCauseItemIE_Extensions_Entry {}
 )]
 pub struct QosFlowWithCauseItemIE_Extensions(pub Vec<QosFlowWithCauseItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANCPRelocationIndicationProtocolIEs_EntryValue {
     #[asn(key = 25)]
@@ -13324,16 +15458,18 @@ pub enum RANCPRelocationIndicationProtocolIEs_EntryValue {
     Id_UL_CP_SecurityInformation(UL_CP_SecurityInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANCPRelocationIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RANCPRelocationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13344,7 +15480,7 @@ pub struct RANCPRelocationIndicationProtocolIEs(
     pub Vec<RANCPRelocationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANConfigurationUpdateProtocolIEs_EntryValue {
     #[asn(key = 21)]
@@ -13363,16 +15499,18 @@ pub enum RANConfigurationUpdateProtocolIEs_EntryValue {
     Id_SupportedTAList(SupportedTAList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANConfigurationUpdateProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RANConfigurationUpdateProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13381,23 +15519,25 @@ pub struct RANConfigurationUpdateProtocolIEs_Entry {
 )]
 pub struct RANConfigurationUpdateProtocolIEs(pub Vec<RANConfigurationUpdateProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 19)]
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANConfigurationUpdateAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RANConfigurationUpdateAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13408,7 +15548,7 @@ pub struct RANConfigurationUpdateAcknowledgeProtocolIEs(
     pub Vec<RANConfigurationUpdateAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANConfigurationUpdateFailureProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -13419,16 +15559,18 @@ pub enum RANConfigurationUpdateFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANConfigurationUpdateFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RANConfigurationUpdateFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13439,11 +15581,11 @@ pub struct RANConfigurationUpdateFailureProtocolIEs(
     pub Vec<RANConfigurationUpdateFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANStatusTransfer_TransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13454,23 +15596,25 @@ pub struct RANStatusTransfer_TransparentContainerIE_Extensions(
     pub Vec<RANStatusTransfer_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RATRestrictions_ItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 180)]
     Id_ExtendedRATRestrictionInformation(ExtendedRATRestrictionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RATRestrictions_ItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RATRestrictions_ItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13479,7 +15623,7 @@ pub struct RATRestrictions_ItemIE_Extensions_Entry {
 )]
 pub struct RATRestrictions_ItemIE_Extensions(pub Vec<RATRestrictions_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_73(pub u8);
 impl ENUMERATED_73 {
@@ -13487,11 +15631,11 @@ impl ENUMERATED_73 {
     pub const RS_DISAPPEARED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RIMInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13500,11 +15644,11 @@ pub struct RIMInformationIE_Extensions_Entry {}
 )]
 pub struct RIMInformationIE_Extensions(pub Vec<RIMInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RIMInformationTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13513,7 +15657,7 @@ pub struct RIMInformationTransferIE_Extensions_Entry {}
 )]
 pub struct RIMInformationTransferIE_Extensions(pub Vec<RIMInformationTransferIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RRCInactiveTransitionReportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -13526,16 +15670,18 @@ pub enum RRCInactiveTransitionReportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RRCInactiveTransitionReportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RRCInactiveTransitionReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13546,15 +15692,15 @@ pub struct RRCInactiveTransitionReportProtocolIEs(
     pub Vec<RRCInactiveTransitionReportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct INTEGER_74(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13563,11 +15709,11 @@ pub struct RecommendedCellItemIE_Extensions_Entry {}
 )]
 pub struct RecommendedCellItemIE_Extensions(pub Vec<RecommendedCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedCellsForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13578,11 +15724,11 @@ pub struct RecommendedCellsForPagingIE_Extensions(
     pub Vec<RecommendedCellsForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedRANNodeItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13591,11 +15737,11 @@ pub struct RecommendedRANNodeItemIE_Extensions_Entry {}
 )]
 pub struct RecommendedRANNodeItemIE_Extensions(pub Vec<RecommendedRANNodeItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedRANNodesForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13606,11 +15752,11 @@ pub struct RecommendedRANNodesForPagingIE_Extensions(
     pub Vec<RecommendedRANNodesForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RedundantPDUSessionInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13621,7 +15767,7 @@ pub struct RedundantPDUSessionInformationIE_Extensions(
     pub Vec<RedundantPDUSessionInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RerouteNASRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -13636,16 +15782,18 @@ pub enum RerouteNASRequestProtocolIEs_EntryValue {
     Id_SourceToTarget_AMFInformationReroute(SourceToTarget_AMFInformationReroute),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RerouteNASRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RerouteNASRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13654,27 +15802,29 @@ pub struct RerouteNASRequestProtocolIEs_Entry {
 )]
 pub struct RerouteNASRequestProtocolIEs(pub Vec<RerouteNASRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetTypechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RetrieveUEInformationProtocolIEs_EntryValue {
     #[asn(key = 26)]
     Id_FiveG_S_TMSI(FiveG_S_TMSI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RetrieveUEInformationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RetrieveUEInformationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13683,11 +15833,11 @@ pub struct RetrieveUEInformationProtocolIEs_Entry {
 )]
 pub struct RetrieveUEInformationProtocolIEs(pub Vec<RetrieveUEInformationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct S_NSSAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13696,11 +15846,11 @@ pub struct S_NSSAIIE_Extensions_Entry {}
 )]
 pub struct S_NSSAIIE_Extensions(pub Vec<S_NSSAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SNPN_MobilityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13711,11 +15861,11 @@ pub struct SNPN_MobilityInformationIE_Extensions(
     pub Vec<SNPN_MobilityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONConfigurationTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13726,27 +15876,29 @@ pub struct SONConfigurationTransferIE_Extensions(
     pub Vec<SONConfigurationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SONInformationchoice_ExtensionsValue {
     #[asn(key = 252)]
     Id_SONInformationReport(SONInformationReport),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONInformationchoice_Extensions {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SONInformationchoice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONInformationReplyIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13755,27 +15907,27 @@ pub struct SONInformationReplyIE_Extensions_Entry {}
 )]
 pub struct SONInformationReplyIE_Extensions(pub Vec<SONInformationReplyIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONInformationReportchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "7", sz_ub = "7")]
 pub struct BIT_STRING_75(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "86399", extensible = true)]
 pub struct INTEGER_76(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "86399", extensible = true)]
 pub struct INTEGER_77(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ScheduledCommunicationTimeIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13786,7 +15938,7 @@ pub struct ScheduledCommunicationTimeIE_Extensions(
     pub Vec<ScheduledCommunicationTimeIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SecondaryRATDataUsageReportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -13801,16 +15953,18 @@ pub enum SecondaryRATDataUsageReportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATDataUsageReportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SecondaryRATDataUsageReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13821,11 +15975,11 @@ pub struct SecondaryRATDataUsageReportProtocolIEs(
     pub Vec<SecondaryRATDataUsageReportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATDataUsageReportTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13836,11 +15990,11 @@ pub struct SecondaryRATDataUsageReportTransferIE_Extensions(
     pub Vec<SecondaryRATDataUsageReportTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATUsageInformationIE_Extension_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13851,11 +16005,11 @@ pub struct SecondaryRATUsageInformationIE_Extension(
     pub Vec<SecondaryRATUsageInformationIE_Extension_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityContextIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13864,23 +16018,25 @@ pub struct SecurityContextIE_Extensions_Entry {}
 )]
 pub struct SecurityContextIE_Extensions(pub Vec<SecurityContextIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SecurityIndicationIE_Extensions_EntryExtensionValue {
     #[asn(key = 151)]
     Id_MaximumIntegrityProtectedDataRate_DL(MaximumIntegrityProtectedDataRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityIndicationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: SecurityIndicationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13889,11 +16045,11 @@ pub struct SecurityIndicationIE_Extensions_Entry {
 )]
 pub struct SecurityIndicationIE_Extensions(pub Vec<SecurityIndicationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityResultIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13902,11 +16058,11 @@ pub struct SecurityResultIE_Extensions_Entry {}
 )]
 pub struct SecurityResultIE_Extensions(pub Vec<SecurityResultIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SensorMeasConfigNameItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13917,11 +16073,11 @@ pub struct SensorMeasConfigNameItemIE_Extensions(
     pub Vec<SensorMeasConfigNameItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SensorMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13932,48 +16088,50 @@ pub struct SensorMeasurementConfigurationIE_Extensions(
     pub Vec<SensorMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_78(pub u8);
 impl ENUMERATED_78 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_79(pub u8);
 impl ENUMERATED_79 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_80(pub u8);
 impl ENUMERATED_80 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SensorNameConfigchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ServedGUAMIItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 176)]
     Id_GUAMIType(GUAMIType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ServedGUAMIItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ServedGUAMIItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13982,11 +16140,11 @@ pub struct ServedGUAMIItemIE_Extensions_Entry {
 )]
 pub struct ServedGUAMIItemIE_Extensions(pub Vec<ServedGUAMIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ServiceAreaInformation_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -13997,11 +16155,11 @@ pub struct ServiceAreaInformation_ItemIE_Extensions(
     pub Vec<ServiceAreaInformation_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SliceOverloadItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14010,11 +16168,11 @@ pub struct SliceOverloadItemIE_Extensions_Entry {}
 )]
 pub struct SliceOverloadItemIE_Extensions(pub Vec<SliceOverloadItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SliceSupportItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14023,7 +16181,7 @@ pub struct SliceSupportItemIE_Extensions_Entry {}
 )]
 pub struct SliceSupportItemIE_Extensions(pub Vec<SliceSupportItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 182)]
@@ -14032,17 +16190,19 @@ pub enum SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_Ent
     Id_UEHistoryInformationFromTheUE(UEHistoryInformationFromTheUE),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14053,11 +16213,11 @@ pub struct SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions(
     pub Vec<SourceNGRANNode_ToTargetNGRANNode_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceRANNodeIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14066,11 +16226,11 @@ pub struct SourceRANNodeIDIE_Extensions_Entry {}
 )]
 pub struct SourceRANNodeIDIE_Extensions(pub Vec<SourceRANNodeIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceToTarget_AMFInformationRerouteIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14081,7 +16241,7 @@ pub struct SourceToTarget_AMFInformationRerouteIE_Extensions(
     pub Vec<SourceToTarget_AMFInformationRerouteIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SuccessfulOutcomeValue {
     #[asn(key = 0)]
@@ -14128,7 +16288,7 @@ pub enum SuccessfulOutcomeValue {
     Id_WriteReplaceWarning(WriteReplaceWarningResponse),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SupportedTAItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 272)]
@@ -14137,16 +16297,18 @@ pub enum SupportedTAItemIE_Extensions_EntryExtensionValue {
     Id_RAT_Information(RAT_Information),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SupportedTAItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: SupportedTAItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14155,11 +16317,11 @@ pub struct SupportedTAItemIE_Extensions_Entry {
 )]
 pub struct SupportedTAItemIE_Extensions(pub Vec<SupportedTAItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TABasedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14168,11 +16330,11 @@ pub struct TABasedMDTIE_Extensions_Entry {}
 )]
 pub struct TABasedMDTIE_Extensions(pub Vec<TABasedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14181,11 +16343,11 @@ pub struct TAIIE_Extensions_Entry {}
 )]
 pub struct TAIIE_Extensions(pub Vec<TAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBasedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14194,11 +16356,11 @@ pub struct TAIBasedMDTIE_Extensions_Entry {}
 )]
 pub struct TAIBasedMDTIE_Extensions(pub Vec<TAIBasedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBroadcastEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14207,11 +16369,11 @@ pub struct TAIBroadcastEUTRA_ItemIE_Extensions_Entry {}
 )]
 pub struct TAIBroadcastEUTRA_ItemIE_Extensions(pub Vec<TAIBroadcastEUTRA_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBroadcastNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14220,11 +16382,11 @@ pub struct TAIBroadcastNR_ItemIE_Extensions_Entry {}
 )]
 pub struct TAIBroadcastNR_ItemIE_Extensions(pub Vec<TAIBroadcastNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAICancelledEUTRA_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14233,11 +16395,11 @@ pub struct TAICancelledEUTRA_ItemIE_Extensions_Entry {}
 )]
 pub struct TAICancelledEUTRA_ItemIE_Extensions(pub Vec<TAICancelledEUTRA_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAICancelledNR_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14246,11 +16408,11 @@ pub struct TAICancelledNR_ItemIE_Extensions_Entry {}
 )]
 pub struct TAICancelledNR_ItemIE_Extensions(pub Vec<TAICancelledNR_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIListForInactiveItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14259,11 +16421,11 @@ pub struct TAIListForInactiveItemIE_Extensions_Entry {}
 )]
 pub struct TAIListForInactiveItemIE_Extensions(pub Vec<TAIListForInactiveItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIListForPagingItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14272,19 +16434,19 @@ pub struct TAIListForPagingItemIE_Extensions_Entry {}
 )]
 pub struct TAIListForPagingItemIE_Extensions(pub Vec<TAIListForPagingItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "32", sz_ub = "32")]
 pub struct BIT_STRING_81(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TNGF_IDchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TNLAssociationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14293,11 +16455,11 @@ pub struct TNLAssociationItemIE_Extensions_Entry {}
 )]
 pub struct TNLAssociationItemIE_Extensions(pub Vec<TNLAssociationItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TSCAssistanceInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14308,11 +16470,11 @@ pub struct TSCAssistanceInformationIE_Extensions(
     pub Vec<TSCAssistanceInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TSCTrafficCharacteristicsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14323,35 +16485,37 @@ pub struct TSCTrafficCharacteristicsIE_Extensions(
     pub Vec<TSCTrafficCharacteristicsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "32", sz_ub = "32")]
 pub struct BIT_STRING_82(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TWIF_IDchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TargetIDchoice_ExtensionsValue {
     #[asn(key = 178)]
     Id_TargetRNC_ID(TargetRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetIDchoice_Extensions {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: TargetIDchoice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14362,24 +16526,26 @@ pub struct TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Exten
     pub Vec<TargetNGRANNode_ToSourceNGRANNode_FailureTransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 267)]
     Id_DAPSResponseInfoList(DAPSResponseInfoList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14390,11 +16556,11 @@ pub struct TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions(
     pub Vec<TargetNGRANNode_ToSourceNGRANNode_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetRANNodeIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14403,11 +16569,11 @@ pub struct TargetRANNodeIDIE_Extensions_Entry {}
 )]
 pub struct TargetRANNodeIDIE_Extensions(pub Vec<TargetRANNodeIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetRNC_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14416,11 +16582,11 @@ pub struct TargetRNC_IDIE_Extensions_Entry {}
 )]
 pub struct TargetRNC_IDIE_Extensions(pub Vec<TargetRNC_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargeteNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14429,11 +16595,11 @@ pub struct TargeteNB_IDIE_Extensions_Entry {}
 )]
 pub struct TargeteNB_IDIE_Extensions(pub Vec<TargeteNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TooearlyIntersystemHOIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14442,7 +16608,7 @@ pub struct TooearlyIntersystemHOIE_Extensions_Entry {}
 )]
 pub struct TooearlyIntersystemHOIE_Extensions(pub Vec<TooearlyIntersystemHOIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TraceActivationIE_Extensions_EntryExtensionValue {
     #[asn(key = 255)]
@@ -14451,16 +16617,18 @@ pub enum TraceActivationIE_Extensions_EntryExtensionValue {
     Id_TraceCollectionEntityURI(URI_address),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceActivationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: TraceActivationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14469,7 +16637,7 @@ pub struct TraceActivationIE_Extensions_Entry {
 )]
 pub struct TraceActivationIE_Extensions(pub Vec<TraceActivationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TraceFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14482,16 +16650,18 @@ pub enum TraceFailureIndicationProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceFailureIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: TraceFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14500,7 +16670,7 @@ pub struct TraceFailureIndicationProtocolIEs_Entry {
 )]
 pub struct TraceFailureIndicationProtocolIEs(pub Vec<TraceFailureIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TraceStartProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14511,16 +16681,18 @@ pub enum TraceStartProtocolIEs_EntryValue {
     Id_TraceActivation(TraceActivation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceStartProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: TraceStartProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14529,7 +16701,7 @@ pub struct TraceStartProtocolIEs_Entry {
 )]
 pub struct TraceStartProtocolIEs(pub Vec<TraceStartProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_83(pub u8);
 impl ENUMERATED_83 {
@@ -14537,11 +16709,11 @@ impl ENUMERATED_83 {
     pub const ONDEMAND: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "3600", extensible = true)]
 pub struct INTEGER_84(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_85(pub u8);
 impl ENUMERATED_85 {
@@ -14549,7 +16721,7 @@ impl ENUMERATED_85 {
     pub const MOBILE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct ENUMERATED_86(pub u8);
 impl ENUMERATED_86 {
@@ -14558,7 +16730,7 @@ impl ENUMERATED_86 {
     pub const MULTIPLE_PACKETS: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct ENUMERATED_87(pub u8);
 impl ENUMERATED_87 {
@@ -14567,11 +16739,11 @@ impl ENUMERATED_87 {
     pub const NOT_BATTERY_POWERED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_DifferentiationInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14580,11 +16752,11 @@ pub struct UE_DifferentiationInfoIE_Extensions_Entry {}
 )]
 pub struct UE_DifferentiationInfoIE_Extensions(pub Vec<UE_DifferentiationInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_NGAP_ID_pairIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14593,15 +16765,15 @@ pub struct UE_NGAP_ID_pairIE_Extensions_Entry {}
 )]
 pub struct UE_NGAP_ID_pairIE_Extensions(pub Vec<UE_NGAP_ID_pairIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_NGAP_IDschoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_associatedLogicalNG_connectionItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14612,11 +16784,11 @@ pub struct UE_associatedLogicalNG_connectionItemIE_Extensions(
     pub Vec<UE_associatedLogicalNG_connectionItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEAggregateMaximumBitRateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14627,7 +16799,7 @@ pub struct UEAggregateMaximumBitRateIE_Extensions(
     pub Vec<UEAggregateMaximumBitRateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14640,16 +16812,18 @@ pub enum UEContextModificationFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14660,7 +16834,7 @@ pub struct UEContextModificationFailureProtocolIEs(
     pub Vec<UEContextModificationFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14709,16 +16883,18 @@ pub enum UEContextModificationRequestProtocolIEs_EntryValue {
     Id_UESecurityCapabilities(UESecurityCapabilities),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14729,7 +16905,7 @@ pub struct UEContextModificationRequestProtocolIEs(
     pub Vec<UEContextModificationRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14744,16 +16920,18 @@ pub enum UEContextModificationResponseProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14764,7 +16942,7 @@ pub struct UEContextModificationResponseProtocolIEs(
     pub Vec<UEContextModificationResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseCommandProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -14773,16 +16951,18 @@ pub enum UEContextReleaseCommandProtocolIEs_EntryValue {
     Id_UE_NGAP_IDs(UE_NGAP_IDs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextReleaseCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14791,7 +16971,7 @@ pub struct UEContextReleaseCommandProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseCommandProtocolIEs(pub Vec<UEContextReleaseCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseCompleteProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14810,16 +16990,18 @@ pub enum UEContextReleaseCompleteProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseCompleteProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextReleaseCompleteProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14828,7 +17010,7 @@ pub struct UEContextReleaseCompleteProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseCompleteProtocolIEs(pub Vec<UEContextReleaseCompleteProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14841,16 +17023,18 @@ pub enum UEContextReleaseRequestProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14859,7 +17043,7 @@ pub struct UEContextReleaseRequestProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseRequestProtocolIEs(pub Vec<UEContextReleaseRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14872,16 +17056,18 @@ pub enum UEContextResumeFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextResumeFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14890,7 +17076,7 @@ pub struct UEContextResumeFailureProtocolIEs_Entry {
 )]
 pub struct UEContextResumeFailureProtocolIEs(pub Vec<UEContextResumeFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14911,16 +17097,18 @@ pub enum UEContextResumeRequestProtocolIEs_EntryValue {
     Id_Suspend_Request_Indication(Suspend_Request_Indication),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextResumeRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14929,11 +17117,11 @@ pub struct UEContextResumeRequestProtocolIEs_Entry {
 )]
 pub struct UEContextResumeRequestProtocolIEs(pub Vec<UEContextResumeRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeRequestTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14944,7 +17132,7 @@ pub struct UEContextResumeRequestTransferIE_Extensions(
     pub Vec<UEContextResumeRequestTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -14965,16 +17153,18 @@ pub enum UEContextResumeResponseProtocolIEs_EntryValue {
     Id_Suspend_Response_Indication(Suspend_Response_Indication),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextResumeResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14983,11 +17173,11 @@ pub struct UEContextResumeResponseProtocolIEs_Entry {
 )]
 pub struct UEContextResumeResponseProtocolIEs(pub Vec<UEContextResumeResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeResponseTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -14998,7 +17188,7 @@ pub struct UEContextResumeResponseTransferIE_Extensions(
     pub Vec<UEContextResumeResponseTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendFailureProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15011,16 +17201,18 @@ pub enum UEContextSuspendFailureProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextSuspendFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15029,7 +17221,7 @@ pub struct UEContextSuspendFailureProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendFailureProtocolIEs(pub Vec<UEContextSuspendFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15044,16 +17236,18 @@ pub enum UEContextSuspendRequestProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextSuspendRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15062,11 +17256,11 @@ pub struct UEContextSuspendRequestProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendRequestProtocolIEs(pub Vec<UEContextSuspendRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendRequestTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15077,7 +17271,7 @@ pub struct UEContextSuspendRequestTransferIE_Extensions(
     pub Vec<UEContextSuspendRequestTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15090,16 +17284,18 @@ pub enum UEContextSuspendResponseProtocolIEs_EntryValue {
     Id_SecurityContext(SecurityContext),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextSuspendResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15108,19 +17304,19 @@ pub struct UEContextSuspendResponseProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendResponseProtocolIEs(pub Vec<UEContextSuspendResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEHistoryInformationFromTheUEchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "10", sz_ub = "10")]
 pub struct BIT_STRING_88(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEIdentityIndexValuechoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -15137,16 +17333,18 @@ pub enum UEInformationTransferProtocolIEs_EntryValue {
     Id_UERadioCapability(UERadioCapability),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEInformationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15155,15 +17353,15 @@ pub struct UEInformationTransferProtocolIEs_Entry {
 )]
 pub struct UEInformationTransferProtocolIEs(pub Vec<UEInformationTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEPagingIdentitychoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEPresenceInAreaOfInterestItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15174,11 +17372,11 @@ pub struct UEPresenceInAreaOfInterestItemIE_Extensions(
     pub Vec<UEPresenceInAreaOfInterestItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERLFReportContainerchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityCheckRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15191,16 +17389,18 @@ pub enum UERadioCapabilityCheckRequestProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityCheckRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityCheckRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15211,7 +17411,7 @@ pub struct UERadioCapabilityCheckRequestProtocolIEs(
     pub Vec<UERadioCapabilityCheckRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityCheckResponseProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15224,16 +17424,18 @@ pub enum UERadioCapabilityCheckResponseProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityCheckResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityCheckResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15244,23 +17446,25 @@ pub struct UERadioCapabilityCheckResponseProtocolIEs(
     pub Vec<UERadioCapabilityCheckResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityForPagingIE_Extensions_EntryExtensionValue {
     #[asn(key = 214)]
     Id_UERadioCapabilityForPagingOfNB_IoT(UERadioCapabilityForPagingOfNB_IoT),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityForPagingIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UERadioCapabilityForPagingIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15271,23 +17475,25 @@ pub struct UERadioCapabilityForPagingIE_Extensions(
     pub Vec<UERadioCapabilityForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityIDMappingRequestProtocolIEs_EntryValue {
     #[asn(key = 264)]
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityIDMappingRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityIDMappingRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15298,7 +17504,7 @@ pub struct UERadioCapabilityIDMappingRequestProtocolIEs(
     pub Vec<UERadioCapabilityIDMappingRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue {
     #[asn(key = 19)]
@@ -15309,16 +17515,18 @@ pub enum UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityIDMappingResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15329,7 +17537,7 @@ pub struct UERadioCapabilityIDMappingResponseProtocolIEs(
     pub Vec<UERadioCapabilityIDMappingResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityInfoIndicationProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15344,16 +17552,18 @@ pub enum UERadioCapabilityInfoIndicationProtocolIEs_EntryValue {
     Id_UERadioCapabilityForPaging(UERadioCapabilityForPaging),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityInfoIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityInfoIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15364,11 +17574,11 @@ pub struct UERadioCapabilityInfoIndicationProtocolIEs(
     pub Vec<UERadioCapabilityInfoIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UESecurityCapabilitiesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15377,7 +17587,7 @@ pub struct UESecurityCapabilitiesIE_Extensions_Entry {}
 )]
 pub struct UESecurityCapabilitiesIE_Extensions(pub Vec<UESecurityCapabilitiesIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UETNLABindingReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15386,16 +17596,18 @@ pub enum UETNLABindingReleaseRequestProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UETNLABindingReleaseRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UETNLABindingReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15406,11 +17618,11 @@ pub struct UETNLABindingReleaseRequestProtocolIEs(
     pub Vec<UETNLABindingReleaseRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UL_CP_SecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15421,7 +17633,7 @@ pub struct UL_CP_SecurityInformationIE_Extensions(
     pub Vec<UL_CP_SecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UL_NGU_UP_TNLModifyItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 192)]
@@ -15430,16 +17642,18 @@ pub enum UL_NGU_UP_TNLModifyItemIE_Extensions_EntryExtensionValue {
     Id_RedundantUL_NGU_UP_TNLInformation(UPTransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UL_NGU_UP_TNLModifyItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UL_NGU_UP_TNLModifyItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15450,15 +17664,15 @@ pub struct UL_NGU_UP_TNLModifyItemIE_Extensions(
     pub Vec<UL_NGU_UP_TNLModifyItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UPTransportLayerInformationchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UPTransportLayerInformationItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15469,11 +17683,11 @@ pub struct UPTransportLayerInformationItemIE_Extensions(
     pub Vec<UPTransportLayerInformationItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UPTransportLayerInformationPairItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15484,11 +17698,11 @@ pub struct UPTransportLayerInformationPairItemIE_Extensions(
     pub Vec<UPTransportLayerInformationPairItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UnavailableGUAMIItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15497,7 +17711,7 @@ pub struct UnavailableGUAMIItemIE_Extensions_Entry {}
 )]
 pub struct UnavailableGUAMIItemIE_Extensions(pub Vec<UnavailableGUAMIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UnsuccessfulOutcomeValue {
     #[asn(key = 0)]
@@ -15522,7 +17736,7 @@ pub enum UnsuccessfulOutcomeValue {
     Id_UEContextSuspend(UEContextSuspendFailure),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkNASTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15535,16 +17749,18 @@ pub enum UplinkNASTransportProtocolIEs_EntryValue {
     Id_UserLocationInformation(UserLocationInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkNASTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkNASTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15553,7 +17769,7 @@ pub struct UplinkNASTransportProtocolIEs_Entry {
 )]
 pub struct UplinkNASTransportProtocolIEs(pub Vec<UplinkNASTransportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 46)]
@@ -15562,16 +17778,18 @@ pub enum UplinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkNonUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15582,7 +17800,7 @@ pub struct UplinkNonUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<UplinkNonUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkRANConfigurationTransferProtocolIEs_EntryValue {
     #[asn(key = 158)]
@@ -15593,16 +17811,18 @@ pub enum UplinkRANConfigurationTransferProtocolIEs_EntryValue {
     Id_SONConfigurationTransferUL(SONConfigurationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRANConfigurationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkRANConfigurationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15613,7 +17833,7 @@ pub struct UplinkRANConfigurationTransferProtocolIEs(
     pub Vec<UplinkRANConfigurationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15624,16 +17844,18 @@ pub enum UplinkRANEarlyStatusTransferProtocolIEs_EntryValue {
     Id_RAN_UE_NGAP_ID(RAN_UE_NGAP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRANEarlyStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkRANEarlyStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15644,7 +17866,7 @@ pub struct UplinkRANEarlyStatusTransferProtocolIEs(
     pub Vec<UplinkRANEarlyStatusTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkRANStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15655,16 +17877,18 @@ pub enum UplinkRANStatusTransferProtocolIEs_EntryValue {
     Id_RANStatusTransfer_TransparentContainer(RANStatusTransfer_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRANStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkRANStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15673,23 +17897,25 @@ pub struct UplinkRANStatusTransferProtocolIEs_Entry {
 )]
 pub struct UplinkRANStatusTransferProtocolIEs(pub Vec<UplinkRANStatusTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkRIMInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 175)]
     Id_RIMInformationTransfer(RIMInformationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkRIMInformationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkRIMInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15700,7 +17926,7 @@ pub struct UplinkRIMInformationTransferProtocolIEs(
     pub Vec<UplinkRIMInformationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 10)]
@@ -15713,16 +17939,18 @@ pub enum UplinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue {
     Id_RoutingID(RoutingID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkUEAssociatedNRPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkUEAssociatedNRPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15733,7 +17961,7 @@ pub struct UplinkUEAssociatedNRPPaTransportProtocolIEs(
     pub Vec<UplinkUEAssociatedNRPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UserLocationInformationchoice_ExtensionsValue {
     #[asn(key = 244)]
@@ -15744,32 +17972,36 @@ pub enum UserLocationInformationchoice_ExtensionsValue {
     Id_UserLocationInformationW_AGF(UserLocationInformationW_AGF),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationchoice_Extensions {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UserLocationInformationchoice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UserLocationInformationEUTRAIE_Extensions_EntryExtensionValue {
     #[asn(key = 149)]
     Id_PSCellInformation(NGRAN_CGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationEUTRAIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UserLocationInformationEUTRAIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15780,11 +18012,11 @@ pub struct UserLocationInformationEUTRAIE_Extensions(
     pub Vec<UserLocationInformationEUTRAIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationN3IWFIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15795,7 +18027,7 @@ pub struct UserLocationInformationN3IWFIE_Extensions(
     pub Vec<UserLocationInformationN3IWFIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UserLocationInformationNRIE_Extensions_EntryExtensionValue {
     #[asn(key = 263)]
@@ -15804,16 +18036,18 @@ pub enum UserLocationInformationNRIE_Extensions_EntryExtensionValue {
     Id_PSCellInformation(NGRAN_CGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationNRIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UserLocationInformationNRIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15824,11 +18058,11 @@ pub struct UserLocationInformationNRIE_Extensions(
     pub Vec<UserLocationInformationNRIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationTNGFIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15839,11 +18073,11 @@ pub struct UserLocationInformationTNGFIE_Extensions(
     pub Vec<UserLocationInformationTNGFIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationTWIFIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15854,27 +18088,29 @@ pub struct UserLocationInformationTWIFIE_Extensions(
     pub Vec<UserLocationInformationTWIFIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UserLocationInformationW_AGFchoice_ExtensionsValue {
     #[asn(key = 275)]
     Id_GlobalCable_ID(GlobalCable_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationW_AGFchoice_Extensions {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UserLocationInformationW_AGFchoice_ExtensionsValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserPlaneSecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15885,27 +18121,27 @@ pub struct UserPlaneSecurityInformationIE_Extensions(
     pub Vec<UserPlaneSecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct OCTET_STRING_89(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct OCTET_STRING_90(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "18446744073709551615")]
 pub struct INTEGER_91(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "18446744073709551615")]
 pub struct INTEGER_92(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct VolumeTimedReport_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15914,19 +18150,19 @@ pub struct VolumeTimedReport_ItemIE_Extensions_Entry {}
 )]
 pub struct VolumeTimedReport_ItemIE_Extensions(pub Vec<VolumeTimedReport_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct BIT_STRING_93(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct W_AGF_IDchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WLANMeasConfigNameItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15935,25 +18171,25 @@ pub struct WLANMeasConfigNameItemIE_Extensions_Entry {}
 )]
 pub struct WLANMeasConfigNameItemIE_Extensions(pub Vec<WLANMeasConfigNameItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_94(pub u8);
 impl ENUMERATED_94 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_95(pub u8);
 impl ENUMERATED_95 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WLANMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15964,11 +18200,11 @@ pub struct WLANMeasurementConfigurationIE_Extensions(
     pub Vec<WLANMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WUS_Assistance_InformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -15979,11 +18215,11 @@ pub struct WUS_Assistance_InformationIE_Extensions(
     pub Vec<WUS_Assistance_InformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WarningAreaListchoice_Extensions {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum WriteReplaceWarningRequestProtocolIEs_EntryValue {
     #[asn(key = 17)]
@@ -16010,16 +18246,18 @@ pub enum WriteReplaceWarningRequestProtocolIEs_EntryValue {
     Id_WarningType(WarningType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WriteReplaceWarningRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: WriteReplaceWarningRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -16030,7 +18268,7 @@ pub struct WriteReplaceWarningRequestProtocolIEs(
     pub Vec<WriteReplaceWarningRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum WriteReplaceWarningResponseProtocolIEs_EntryValue {
     #[asn(key = 13)]
@@ -16043,16 +18281,18 @@ pub enum WriteReplaceWarningResponseProtocolIEs_EntryValue {
     Id_SerialNumber(SerialNumber),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WriteReplaceWarningResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: WriteReplaceWarningResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -16063,23 +18303,25 @@ pub struct WriteReplaceWarningResponseProtocolIEs(
     pub Vec<WriteReplaceWarningResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum XnExtTLA_ItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 173)]
     Id_SCTP_TLAs(SCTP_TLAs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct XnExtTLA_ItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: XnExtTLA_ItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -16088,11 +18330,11 @@ pub struct XnExtTLA_ItemIE_Extensions_Entry {
 )]
 pub struct XnExtTLA_ItemIE_Extensions(pub Vec<XnExtTLA_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct XnTNLConfigurationInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1,9 +1,14 @@
 #![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::enum_variant_names,
+    clippy::upper_case_acronyms
+)]
 use asn1_codecs_derive::AperCodec;
 use bitvec::order::Msb0;
 use bitvec::vec::BitVec;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -12,7 +17,7 @@ use bitvec::vec::BitVec;
 )]
 pub struct APN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct AccuracyFulfilmentIndicator(pub u8);
 impl AccuracyFulfilmentIndicator {
@@ -20,87 +25,104 @@ impl AccuracyFulfilmentIndicator {
     pub const REQUESTED_ACCURACY_NOT_FULFILLED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct Additional_CSPS_coordination_information {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub old_lai: Option<LAI>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub old_rac: Option<RAC>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub nri: Option<BIT_STRING_2>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ue_is_attaching: Option<NULL_3>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<Additional_CSPS_coordination_informationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct Additional_PositioningDataSet(pub Vec<Additional_PositioningMethodAndUsage>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct Additional_PositioningMethodAndUsage(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AllocationOrRetentionPriority {
+    #[asn(tag = "[0] IMPLICIT")]
     pub priority_level: PriorityLevel,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pre_emption_capability: Pre_emptionCapability,
+    #[asn(tag = "[2] IMPLICIT")]
     pub pre_emption_vulnerability: Pre_emptionVulnerability,
+    #[asn(tag = "[3] IMPLICIT")]
     pub queuing_allowed: QueuingAllowed,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AllocationOrRetentionPriorityIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Alt_RAB_Parameter_ExtendedGuaranteedBitrateInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub alt_extended_guaranteed_bitrate_type: Alt_RAB_Parameter_GuaranteedBitrateType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub alt_extended_guaranteed_bitrates: Option<Alt_RAB_Parameter_ExtendedGuaranteedBitrates>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Alt_RAB_Parameter_ExtendedGuaranteedBitrateList(pub Vec<ExtendedGuaranteedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Alt_RAB_Parameter_ExtendedGuaranteedBitrates(
     pub Vec<Alt_RAB_Parameter_ExtendedGuaranteedBitrateList>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Alt_RAB_Parameter_ExtendedMaxBitrateInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub alt_extended_max_bitrate_type: Alt_RAB_Parameter_MaxBitrateType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub alt_extended_max_bitrates: Option<Alt_RAB_Parameter_ExtendedMaxBitrates>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Alt_RAB_Parameter_ExtendedMaxBitrateList(pub Vec<ExtendedMaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Alt_RAB_Parameter_ExtendedMaxBitrates(pub Vec<Alt_RAB_Parameter_ExtendedMaxBitrateList>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Alt_RAB_Parameter_GuaranteedBitrateInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub alt_guaranteed_bitrate_type: Alt_RAB_Parameter_GuaranteedBitrateType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub alt_guaranteed_bitrates: Option<Alt_RAB_Parameter_GuaranteedBitrates>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Alt_RAB_Parameter_GuaranteedBitrateList(pub Vec<GuaranteedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Alt_RAB_Parameter_GuaranteedBitrateType(pub u8);
 impl Alt_RAB_Parameter_GuaranteedBitrateType {
@@ -109,23 +131,26 @@ impl Alt_RAB_Parameter_GuaranteedBitrateType {
     pub const DISCRETE_VALUES: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Alt_RAB_Parameter_GuaranteedBitrates(pub Vec<Alt_RAB_Parameter_GuaranteedBitrateList>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Alt_RAB_Parameter_MaxBitrateInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub alt_max_bitrate_type: Alt_RAB_Parameter_MaxBitrateType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub alt_max_bitrates: Option<Alt_RAB_Parameter_MaxBitrates>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Alt_RAB_Parameter_MaxBitrateList(pub Vec<MaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Alt_RAB_Parameter_MaxBitrateType(pub u8);
 impl Alt_RAB_Parameter_MaxBitrateType {
@@ -134,112 +159,126 @@ impl Alt_RAB_Parameter_MaxBitrateType {
     pub const DISCRETE_VALUES: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Alt_RAB_Parameter_MaxBitrates(pub Vec<Alt_RAB_Parameter_MaxBitrateList>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct Alt_RAB_Parameter_SupportedGuaranteedBitrateInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub alt_supported_guaranteed_bitrate_type: Alt_RAB_Parameter_GuaranteedBitrateType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub alt_supported_guaranteed_bitrates: Option<Alt_RAB_Parameter_SupportedGuaranteedBitrates>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<Alt_RAB_Parameter_SupportedGuaranteedBitrateInfIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Alt_RAB_Parameter_SupportedGuaranteedBitrates(
     pub Vec<SupportedRAB_ParameterBitrateList>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct Alt_RAB_Parameter_SupportedMaxBitrateInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub alt_supported_max_bitrate_type: Alt_RAB_Parameter_MaxBitrateType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub alt_supported_max_bitrates: Option<Alt_RAB_Parameter_SupportedMaxBitrates>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<Alt_RAB_Parameter_SupportedMaxBitrateInfIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct Alt_RAB_Parameter_SupportedMaxBitrates(pub Vec<SupportedRAB_ParameterBitrateList>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct Alt_RAB_Parameters {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub alt_max_bitrate_inf: Option<Alt_RAB_Parameter_MaxBitrateInf>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub alt_guaranteed_bit_rate_inf: Option<Alt_RAB_Parameter_GuaranteedBitrateInf>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<Alt_RAB_ParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct AlternativeRABConfigurationRequest(pub u8);
 impl AlternativeRABConfigurationRequest {
     pub const ALTERNATIVE_RAB_CONFIGURATION_REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum AreaIdentity {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SAI(SAI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] EXPLICIT")]
     GeographicalArea(GeographicalArea),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = true)]
 pub enum AreaScopeForUEApplicationLayerMeasurementConfiguration {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Cellbased(CellBased),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Labased(LABased),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Rabased(RABased),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Plmn_area_based(PLMNBased),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Ass_RAB_Parameter_ExtendedGuaranteedBitrateList(pub Vec<ExtendedGuaranteedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Ass_RAB_Parameter_ExtendedMaxBitrateList(pub Vec<ExtendedMaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Ass_RAB_Parameter_GuaranteedBitrateList(pub Vec<GuaranteedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Ass_RAB_Parameter_MaxBitrateList(pub Vec<MaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct Ass_RAB_Parameters {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub ass_max_bitrate_inf: Option<Ass_RAB_Parameter_MaxBitrateList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ass_guaranteed_bit_rate_inf: Option<Ass_RAB_Parameter_GuaranteedBitrateList>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<Ass_RAB_ParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct AuthorisedPLMNs(pub Vec<AuthorisedPLMNs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -248,45 +287,56 @@ pub struct AuthorisedPLMNs(pub Vec<AuthorisedPLMNs_Entry>);
 )]
 pub struct AuthorisedSNAs(pub Vec<SNAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "30000", ub = "115000")]
 pub struct BarometricPressure(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct BindingID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct BroadcastAssistanceDataDecipheringKeys {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ciphering_key_flag: BIT_STRING_4,
+    #[asn(tag = "[1] IMPLICIT")]
     pub current_deciphering_key: BIT_STRING_5,
+    #[asn(tag = "[2] IMPLICIT")]
     pub next_deciphering_key: BIT_STRING_6,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct CGI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub lac: LAC,
+    #[asn(tag = "[2] IMPLICIT")]
     pub ci: CI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CGIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct CI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CN_DeactivateTrace {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: CN_DeactivateTraceProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<CN_DeactivateTraceProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct CN_DomainIndicator(pub u8);
 impl CN_DomainIndicator {
@@ -294,27 +344,33 @@ impl CN_DomainIndicator {
     pub const PS_DOMAIN: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct CN_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CN_InvokeTrace {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: CN_InvokeTraceProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<CN_InvokeTraceProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CNMBMSLinkingInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub joined_mbms_bearer_service_i_es: JoinedMBMSBearerService_IEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CNMBMSLinkingInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CSFB_Information(pub u8);
 impl CSFB_Information {
@@ -322,11 +378,11 @@ impl CSFB_Information {
     pub const CSFB_HIGH_PRIORITY: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "27", sz_ub = "27")]
 pub struct CSG_Id(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -335,7 +391,7 @@ pub struct CSG_Id(pub BitVec<u8, Msb0>);
 )]
 pub struct CSG_Id_List(pub Vec<CSG_Id>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CSG_Membership_Status(pub u8);
 impl CSG_Membership_Status {
@@ -343,106 +399,118 @@ impl CSG_Membership_Status {
     pub const NON_MEMBER: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "5", extensible = true)]
 pub enum Cause {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RadioNetwork(CauseRadioNetwork),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TransmissionNetwork(CauseTransmissionNetwork),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     NAS(CauseNAS),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Protocol(CauseProtocol),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Misc(CauseMisc),
-    #[asn(key = 5, extended = false)]
+    #[asn(key = 5, extended = false, tag = "[5] IMPLICIT")]
     Non_Standard(CauseNon_Standard),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[6] IMPLICIT")]
     RadioNetworkExtension(CauseRadioNetworkExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "113", ub = "128")]
 pub struct CauseMisc(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "81", ub = "96")]
 pub struct CauseNAS(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "129", ub = "256")]
 pub struct CauseNon_Standard(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "97", ub = "112")]
 pub struct CauseProtocol(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "64")]
 pub struct CauseRadioNetwork(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "257", ub = "512")]
 pub struct CauseRadioNetworkExtension(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "65", ub = "80")]
 pub struct CauseTransmissionNetwork(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Cell_Access_Mode(pub u8);
 impl Cell_Access_Mode {
     pub const HYBRID: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "100", extensible = true)]
 pub struct Cell_Capacity_Class_Value(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "268435455")]
 pub struct Cell_Id(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellBased {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id_list: CellIdList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellBasedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CellIdList(pub Vec<Cell_Id>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct CellLoadInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_capacity_class_value: Cell_Capacity_Class_Value,
+    #[asn(tag = "[1] IMPLICIT")]
     pub load_value: LoadValue,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub rt_load_value: Option<RTLoadValue>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub nrt_load_information_value: Option<NRTLoadInformationValue>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<CellLoadInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct CellLoadInformationGroup {
+    #[asn(tag = "[0] EXPLICIT")]
     pub source_cell_id: SourceCellID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub uplink_cell_load_information: Option<CellLoadInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub downlink_cell_load_information: Option<CellLoadInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<CellLoadInformationGroupIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct CellType(pub u8);
 impl CellType {
@@ -456,19 +524,19 @@ pub type ChosenEncryptionAlgorithm = EncryptionAlgorithm;
 
 pub type ChosenIntegrityProtectionAlgorithm = IntegrityProtectionAlgorithm;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct CivicAddress(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct ClassmarkInformation2(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct ClassmarkInformation3(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct ClientType(pub u8);
 impl ClientType {
@@ -482,19 +550,22 @@ impl ClientType {
     pub const P_LMN_OPERATOR_TARGET_MS_SERVICE_SUPPORT: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CommonID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: CommonIDProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<CommonIDProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct Correlation_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Criticality(pub u8);
 impl Criticality {
@@ -503,22 +574,24 @@ impl Criticality {
     pub const NOTIFY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct CriticalityDiagnostics {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub procedure_code: Option<ProcedureCode>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub triggering_message: Option<TriggeringMessage>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub procedure_criticality: Option<Criticality>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub i_es_criticality_diagnostics: Option<CriticalityDiagnostics_IE_List>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<CriticalityDiagnosticsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -527,35 +600,35 @@ pub struct CriticalityDiagnostics {
 )]
 pub struct CriticalityDiagnostics_IE_List(pub Vec<CriticalityDiagnostics_IE_List_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1048575")]
 pub struct D_RNTI(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct DCH_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct DCN_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct DL_GTP_PDU_SequenceNumber(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct DL_N_PDU_SequenceNumber(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "6", ub = "9")]
 pub struct DRX_CycleLengthCoefficient(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct DSCH_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct DataPDUType(pub u8);
 impl DataPDUType {
@@ -563,31 +636,37 @@ impl DataPDUType {
     pub const P_D_UTYPE1: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct DataVolumeList(pub Vec<DataVolumeList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct DataVolumeReference(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DataVolumeReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DataVolumeReportProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<DataVolumeReportProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DataVolumeReportRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DataVolumeReportRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<DataVolumeReportRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct DataVolumeReportingIndication(pub u8);
 impl DataVolumeReportingIndication {
@@ -595,7 +674,7 @@ impl DataVolumeReportingIndication {
     pub const DO_NOT_REPORT: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct DeliveryOfErroneousSDU(pub u8);
 impl DeliveryOfErroneousSDU {
@@ -604,7 +683,7 @@ impl DeliveryOfErroneousSDU {
     pub const NO_ERROR_DETECTION_CONSIDERATION: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct DeliveryOrder(pub u8);
 impl DeliveryOrder {
@@ -612,26 +691,29 @@ impl DeliveryOrder {
     pub const DELIVERY_ORDER_NOT_REQUESTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 3)]
 pub struct DeltaRAListofIdleModeUEs {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub new_ra_listof_idle_mode_u_es: Option<NewRAListofIdleModeUEs>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ra_listwith_no_idle_mode_u_es_any_more: Option<RAListwithNoIdleModeUEsAnyMore>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<DeltaRAListofIdleModeUEsIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DirectInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DirectInformationTransferProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<DirectInformationTransferProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct DirectReportingIndicator(pub u8);
 impl DirectReportingIndicator {
@@ -639,76 +721,88 @@ impl DirectReportingIndicator {
     pub const DIRECT_GEO: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DirectTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DirectTransferProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<DirectTransferProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DirectTransferInformationItem_RANAP_RelocInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nas_pdu: NAS_PDU,
+    #[asn(tag = "[1] IMPLICIT")]
     pub sapi: SAPI,
+    #[asn(tag = "[2] IMPLICIT")]
     pub cn_domain_indicator: CN_DomainIndicator,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DirectTransferInformationItem_RANAP_RelocInfIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "15")]
 pub struct DirectTransferInformationList_RANAP_RelocInf(
     pub Vec<DirectTransferInformationList_RANAP_RelocInf_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "7")]
 pub struct E_DCH_MAC_d_Flow_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct E_UTRAN_Service_Handover(pub u8);
 impl E_UTRAN_Service_Handover {
     pub const HANDOVER_TO_E_UTRAN_SHALL_NOT_BE_PERFORMED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "65536", ub = "262143", extensible = true)]
 pub struct EARFCN_Extended(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum ENB_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     MacroENB_ID(BIT_STRING_7),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     HomeENB_ID(BIT_STRING_8),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[2] IMPLICIT")]
     Short_macroENB_ID(BIT_STRING_9),
-    #[asn(key = 1, extended = true)]
+    #[asn(key = 1, extended = true, tag = "[3] IMPLICIT")]
     Long_macroENB_ID(BIT_STRING_10),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct EUTRANFrequencies(pub Vec<EUTRANFrequencies_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15")]
 pub struct EncryptionAlgorithm(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct EncryptionInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub permitted_algorithms: PermittedEncryptionAlgorithms,
+    #[asn(tag = "[1] IMPLICIT")]
     pub key: EncryptionKey,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EncryptionInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -717,244 +811,319 @@ pub struct EncryptionInformation {
 )]
 pub struct EncryptionKey(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct End_Of_CSFB(pub u8);
 impl End_Of_CSFB {
     pub const END_OF_CSFB: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EnhancedRelocationCompleteConfirm {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: EnhancedRelocationCompleteConfirmProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<EnhancedRelocationCompleteConfirmProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EnhancedRelocationCompleteFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: EnhancedRelocationCompleteFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<EnhancedRelocationCompleteFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EnhancedRelocationCompleteRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: EnhancedRelocationCompleteRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<EnhancedRelocationCompleteRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EnhancedRelocationCompleteResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: EnhancedRelocationCompleteResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<EnhancedRelocationCompleteResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = true)]
 pub enum EquipmentsToBeTraced {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     IMEIlist(IMEIList),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     IMEISVlist(IMEISVList),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     IMEIgroup(IMEIGroup),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     IMEISVgroup(IMEISVGroup),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ErrorIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ErrorIndicationProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<ErrorIndicationProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Event(pub u8);
 impl Event {
     pub const STOP_CHANGE_OF_SERVICE_AREA: u8 = 0u8;
     pub const DIRECT: u8 = 1u8;
     pub const CHANGE_OF_SERVICEAREA: u8 = 2u8;
+    pub const STOP_DIRECT: u8 = 3u8;
+    pub const PERIODIC: u8 = 4u8;
+    pub const STOP_PERIODIC: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct Event1F_Parameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub measurement_quantity: MeasurementQuantity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub threshold: INTEGER_12,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct Event1I_Parameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub threshold: INTEGER_13,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "16000001", ub = "256000000")]
 pub struct ExtendedGuaranteedBitrate(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "16000001", ub = "256000000")]
 pub struct ExtendedMaxBitrate(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "4096", ub = "65535")]
 pub struct ExtendedRNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ForwardSRNS_Context {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ForwardSRNS_ContextProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<ForwardSRNS_ContextProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ForwardingIndication(pub u8);
 impl ForwardingIndication {
     pub const FORWARDING_ADMITTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15")]
 pub struct FrameSequenceNumber(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct FrequenceLayerConvergenceFlag(pub u8);
 impl FrequenceLayerConvergenceFlag {
     pub const NO_FLC_FLAG: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct GA_AltitudeAndDirection {
+    #[asn(tag = "[0] IMPLICIT")]
     pub direction_of_altitude: ENUMERATED_14,
+    #[asn(tag = "[1] IMPLICIT")]
     pub altitude: INTEGER_15,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GA_EllipsoidArc {
+    #[asn(tag = "[0] IMPLICIT")]
     pub geographical_coordinates: GeographicalCoordinates,
+    #[asn(tag = "[1] IMPLICIT")]
     pub inner_radius: INTEGER_16,
+    #[asn(tag = "[2] IMPLICIT")]
     pub uncertainty_radius: INTEGER_17,
+    #[asn(tag = "[3] IMPLICIT")]
     pub offset_angle: INTEGER_18,
+    #[asn(tag = "[4] IMPLICIT")]
     pub included_angle: INTEGER_19,
+    #[asn(tag = "[5] IMPLICIT")]
     pub confidence: INTEGER_20,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GA_EllipsoidArcIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GA_Point {
+    #[asn(tag = "[0] IMPLICIT")]
     pub geographical_coordinates: GeographicalCoordinates,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GA_PointIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GA_PointWithAltitude {
+    #[asn(tag = "[0] IMPLICIT")]
     pub geographical_coordinates: GeographicalCoordinates,
+    #[asn(tag = "[1] IMPLICIT")]
     pub altitude_and_direction: GA_AltitudeAndDirection,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GA_PointWithAltitudeIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GA_PointWithAltitudeAndUncertaintyEllipsoid {
+    #[asn(tag = "[0] IMPLICIT")]
     pub geographical_coordinates: GeographicalCoordinates,
+    #[asn(tag = "[1] IMPLICIT")]
     pub altitude_and_direction: GA_AltitudeAndDirection,
+    #[asn(tag = "[2] IMPLICIT")]
     pub uncertainty_ellipse: GA_UncertaintyEllipse,
+    #[asn(tag = "[3] IMPLICIT")]
     pub uncertainty_altitude: INTEGER_21,
+    #[asn(tag = "[4] IMPLICIT")]
     pub confidence: INTEGER_22,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GA_PointWithAltitudeAndUncertaintyEllipsoidIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct GA_PointWithUnCertainty {
+    #[asn(tag = "[0] IMPLICIT")]
     pub geographical_coordinates: GeographicalCoordinates,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GA_PointWithUnCertaintyIE_Extensions>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub uncertainty_code: INTEGER_23,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GA_PointWithUnCertaintyEllipse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub geographical_coordinates: GeographicalCoordinates,
+    #[asn(tag = "[1] IMPLICIT")]
     pub uncertainty_ellipse: GA_UncertaintyEllipse,
+    #[asn(tag = "[2] IMPLICIT")]
     pub confidence: INTEGER_24,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GA_PointWithUnCertaintyEllipseIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "15")]
 pub struct GA_Polygon(pub Vec<GA_Polygon_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct GA_UncertaintyEllipse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub uncertainty_semi_major: INTEGER_25,
+    #[asn(tag = "[1] IMPLICIT")]
     pub uncertainty_semi_minor: INTEGER_26,
+    #[asn(tag = "[2] IMPLICIT")]
     pub orientation_of_major_axis: INTEGER_27,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "9")]
 pub struct GANSS_PositioningDataSet(pub Vec<GANSS_PositioningMethodAndUsage>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct GANSS_PositioningMethodAndUsage(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct GERAN_BSC_Container(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct GERAN_Cell_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lai: LAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rac: RAC,
+    #[asn(tag = "[2] IMPLICIT")]
     pub ci: CI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GERAN_Cell_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct GERAN_Classmark(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct GERAN_Iumode_RAB_Failed_RABAssgntResponse_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub geran_classmark: Option<GERAN_Classmark>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<GERAN_Iumode_RAB_Failed_RABAssgntResponse_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -965,62 +1134,73 @@ pub struct GERAN_Iumode_RAB_FailedList_RABAssgntResponse(
     pub Vec<GERAN_Iumode_RAB_FailedList_RABAssgntResponse_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct GTP_TEI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum GeographicalArea {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Point(GA_Point),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     PointWithUnCertainty(GA_PointWithUnCertainty),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Polygon(GA_Polygon),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[3] IMPLICIT")]
     PointWithUncertaintyEllipse(GA_PointWithUnCertaintyEllipse),
-    #[asn(key = 1, extended = true)]
+    #[asn(key = 1, extended = true, tag = "[4] IMPLICIT")]
     PointWithAltitude(GA_PointWithAltitude),
-    #[asn(key = 2, extended = true)]
+    #[asn(key = 2, extended = true, tag = "[5] IMPLICIT")]
     PointWithAltitudeAndUncertaintyEllipsoid(GA_PointWithAltitudeAndUncertaintyEllipsoid),
-    #[asn(key = 3, extended = true)]
+    #[asn(key = 3, extended = true, tag = "[6] IMPLICIT")]
     EllipsoidArc(GA_EllipsoidArc),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GeographicalCoordinates {
+    #[asn(tag = "[0] IMPLICIT")]
     pub latitude_sign: ENUMERATED_28,
+    #[asn(tag = "[1] IMPLICIT")]
     pub latitude: INTEGER_29,
+    #[asn(tag = "[2] IMPLICIT")]
     pub longitude: INTEGER_30,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GeographicalCoordinatesIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalCN_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cn_id: CN_ID,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GlobalRNC_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rnc_id: RNC_ID,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "16000000")]
 pub struct GuaranteedBitrate(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "7")]
 pub struct HS_DSCH_MAC_d_Flow_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct HigherBitratesThan16MbpsFlag(pub u8);
 impl HigherBitratesThan16MbpsFlag {
@@ -1028,87 +1208,110 @@ impl HigherBitratesThan16MbpsFlag {
     pub const NOT_ALLOWED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HorizontalSpeedAndBearing {
+    #[asn(tag = "[0] IMPLICIT")]
     pub bearing: INTEGER_31,
+    #[asn(tag = "[1] IMPLICIT")]
     pub horizontal_speed: INTEGER_32,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct HorizontalVelocity {
+    #[asn(tag = "[0] IMPLICIT")]
     pub horizontal_speed_and_bearing: HorizontalSpeedAndBearing,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<HorizontalVelocityIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct HorizontalVelocityWithUncertainty {
+    #[asn(tag = "[0] IMPLICIT")]
     pub horizontal_speed_and_bearing: HorizontalSpeedAndBearing,
+    #[asn(tag = "[1] IMPLICIT")]
     pub uncertainty_speed: INTEGER_33,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<HorizontalVelocityWithUncertaintyIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct HorizontalWithVerticalVelocity {
+    #[asn(tag = "[0] IMPLICIT")]
     pub horizontal_speed_and_bearing: HorizontalSpeedAndBearing,
+    #[asn(tag = "[1] IMPLICIT")]
     pub veritcal_velocity: VerticalVelocity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<HorizontalWithVerticalVelocityIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct HorizontalWithVerticalVelocityAndUncertainty {
+    #[asn(tag = "[0] IMPLICIT")]
     pub horizontal_speed_and_bearing: HorizontalSpeedAndBearing,
+    #[asn(tag = "[1] IMPLICIT")]
     pub veritcal_velocity: VerticalVelocity,
+    #[asn(tag = "[2] IMPLICIT")]
     pub horizontal_uncertainty_speed: INTEGER_34,
+    #[asn(tag = "[3] IMPLICIT")]
     pub vertical_uncertainty_speed: INTEGER_35,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<HorizontalWithVerticalVelocityAndUncertaintyIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct IMEI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct IMEIGroup {
+    #[asn(tag = "[0] IMPLICIT")]
     pub imei: IMEI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub imei_mask: BIT_STRING_36,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<IMEIGroupIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct IMEIList(pub Vec<IMEI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct IMEISV(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct IMEISVGroup {
+    #[asn(tag = "[0] IMPLICIT")]
     pub imeisv: IMEISV,
+    #[asn(tag = "[1] IMPLICIT")]
     pub imeisv_mask: BIT_STRING_37,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<IMEISVGroupIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct IMEISVList(pub Vec<IMEISV>);
 
 pub type IMSI = TBCD_STRING;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -1117,52 +1320,57 @@ pub type IMSI = TBCD_STRING;
 )]
 pub struct IPMulticastAddress(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 3)]
 pub struct IRAT_Measurement_Configuration {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub rsrp: Option<INTEGER_38>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub rsrq: Option<INTEGER_39>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub ira_tmeasurement_parameters: IRATmeasurementParameters,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<IRAT_Measurement_ConfigurationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
 pub struct IRATmeasurementParameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub measurement_duration: INTEGER_40,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub eutran_frequencies: Option<EUTRANFrequencies>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<IRATmeasurementParametersIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
-#[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct ImmediateMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub measurements_to_activate: MeasurementsToActivate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
     pub m1report: Option<M1Report>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 1)]
     pub m2report: Option<M2Report>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", extension_idx = 0)]
     pub ie_extensions: Option<ImmediateMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "0")]
 pub struct IncludeVelocity(pub u8);
 impl IncludeVelocity {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1048575")]
 pub struct InformationExchangeID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct InformationExchangeType(pub u8);
 impl InformationExchangeType {
@@ -1170,90 +1378,112 @@ impl InformationExchangeType {
     pub const REQUEST: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum InformationRequestType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     MBMSIPMulticastAddressandAPNRequest(MBMSIPMulticastAddressandAPNRequest),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] EXPLICIT")]
     PermanentNAS_UE_ID(PermanentNAS_UE_ID),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum InformationRequested {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RequestedMBMSIPMulticastAddressandAPNRequest(RequestedMBMSIPMulticastAddressandAPNRequest),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     RequestedMulticastServiceList(RequestedMulticastServiceList),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InformationTransferConfirmation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InformationTransferConfirmationProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<InformationTransferConfirmationProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InformationTransferFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InformationTransferFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<InformationTransferFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1048575")]
 pub struct InformationTransferID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InformationTransferIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InformationTransferIndicationProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<InformationTransferIndicationProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum InformationTransferType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RNCTraceInformation(RNCTraceInformation),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InitialUE_Message {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialUE_MessageProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<InitialUE_MessageProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitiatingMessage {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitiatingMessageValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15")]
 pub struct IntegrityProtectionAlgorithm(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct IntegrityProtectionInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub permitted_algorithms: PermittedIntegrityProtectionAlgorithms,
+    #[asn(tag = "[1] IMPLICIT")]
     pub key: IntegrityProtectionKey,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<IntegrityProtectionInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -1262,70 +1492,88 @@ pub struct IntegrityProtectionInformation {
 )]
 pub struct IntegrityProtectionKey(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct InterSystemInformation_TransparentContainer {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub downlink_cell_load_information: Option<CellLoadInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub uplink_cell_load_information: Option<CellLoadInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<InterSystemInformation_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum InterSystemInformationTransferType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RIM_Transfer(RIM_Transfer),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InterfacesToTraceItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub interface: ENUMERATED_41,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<InterfacesToTraceItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Iu_ReleaseCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: Iu_ReleaseCommandProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<Iu_ReleaseCommandProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Iu_ReleaseComplete {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: Iu_ReleaseCompleteProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<Iu_ReleaseCompleteProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Iu_ReleaseRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: Iu_ReleaseRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<Iu_ReleaseRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "24", sz_ub = "24")]
 pub struct IuSignallingConnectionIdentifier(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum IuTransportAssociation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GTP_TEI(GTP_TEI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     BindingID(BindingID),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1334,7 +1582,7 @@ pub enum IuTransportAssociation {
 )]
 pub struct JoinedMBMSBearerService_IEs(pub Vec<JoinedMBMSBearerService_IEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct KeyStatus(pub u8);
 impl KeyStatus {
@@ -1342,11 +1590,11 @@ impl KeyStatus {
     pub const NEW: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct L3_Information(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1355,32 +1603,37 @@ pub struct L3_Information(pub Vec<u8>);
 )]
 pub struct LA_LIST(pub Vec<LA_LIST_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LABased {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lai_list: LAI_List,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LABasedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct LAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct LAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub lac: LAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LAIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct LAI_List(pub Vec<LAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1389,7 +1642,7 @@ pub struct LAI_List(pub Vec<LAI>);
 )]
 pub struct LAListofIdleModeUEs(pub Vec<LAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -1398,26 +1651,35 @@ pub struct LAListofIdleModeUEs(pub Vec<LAI>);
 )]
 pub struct LHN_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LastKnownServiceArea {
+    #[asn(tag = "[0] IMPLICIT")]
     pub sai: SAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub age_of_sai: INTEGER_42,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LastKnownServiceAreaIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LastVisitedUTRANCell_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub utran_cell_id: UTRAN_CellID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cell_type: CellType,
+    #[asn(tag = "[2] IMPLICIT")]
     pub time_ue_stayed_in_cell: Time_UE_StayedInCell,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LastVisitedUTRANCell_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1426,7 +1688,7 @@ pub struct LastVisitedUTRANCell_Item {
 )]
 pub struct LeftMBMSBearerService_IEs(pub Vec<LeftMBMSBearerService_IEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Links_to_log(pub u8);
 impl Links_to_log {
@@ -1435,7 +1697,7 @@ impl Links_to_log {
     pub const BOTH_UPLINK_AND_DOWNLINK: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1444,39 +1706,48 @@ impl Links_to_log {
 )]
 pub struct ListOF_SNAs(pub Vec<SNAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ListOfInterfacesToTrace(pub Vec<InterfacesToTraceItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "100")]
 pub struct LoadValue(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LocationRelatedDataFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationRelatedDataFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<LocationRelatedDataFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LocationRelatedDataRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationRelatedDataRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<LocationRelatedDataRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LocationRelatedDataRequestType {
+    #[asn(tag = "[0] IMPLICIT")]
     pub requested_location_related_data_type: RequestedLocationRelatedDataType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub requested_gps_assistance_data: Option<RequestedGPSAssistanceData>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct LocationRelatedDataRequestTypeSpecificToGERANIuMode(pub u8);
 impl LocationRelatedDataRequestTypeSpecificToGERANIuMode {
@@ -1485,69 +1756,84 @@ impl LocationRelatedDataRequestTypeSpecificToGERANIuMode {
     pub const DEDICATED_MOBILE_BASED_EOTD_ASSISTANCE_DATA: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LocationRelatedDataResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationRelatedDataResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<LocationRelatedDataResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LocationReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<LocationReportProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LocationReportingControl {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportingControlProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<LocationReportingControlProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 12)]
 pub struct LocationReportingTransferInformation {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub report_change_of_sai: Option<ReportChangeOfSAI>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub periodic_reporting_indicator: Option<PeriodicReportingIndicator>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub direct_reporting_indicator: Option<DirectReportingIndicator>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub vertical_accuracy_code: Option<VerticalAccuracyCode>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub positioning_priority_change_sai: Option<PositioningPriority>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 5)]
     pub positioning_priority_direct: Option<PositioningPriority>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 6)]
     pub client_type_periodic: Option<ClientType>,
-    #[asn(optional_idx = 7)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 7)]
     pub client_type_direct: Option<ClientType>,
-    #[asn(optional_idx = 8)]
+    #[asn(tag = "[8] IMPLICIT", optional_idx = 8)]
     pub response_time: Option<ResponseTime>,
-    #[asn(optional_idx = 9)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 9)]
     pub include_velocity: Option<IncludeVelocity>,
-    #[asn(optional_idx = 10)]
+    #[asn(tag = "[10] IMPLICIT", optional_idx = 10)]
     pub periodic_location_info: Option<PeriodicLocationInfo>,
-    #[asn(optional_idx = 11)]
+    #[asn(tag = "[11] IMPLICIT", optional_idx = 11)]
     pub ie_extensions: Option<LocationReportingTransferInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LoggedMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub logging_interval: LoggingInterval,
+    #[asn(tag = "[1] IMPLICIT")]
     pub logging_duration: LoggingDuration,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LoggedMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct LoggingDuration(pub u8);
 impl LoggingDuration {
@@ -1559,7 +1845,7 @@ impl LoggingDuration {
     pub const MIN120: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct LoggingInterval(pub u8);
 impl LoggingInterval {
@@ -1573,35 +1859,42 @@ impl LoggingInterval {
     pub const S61D44: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum M1Report {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Periodic(MDT_Report_Parameters),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Event1F(Event1F_Parameters),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum M2Report {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Periodic(MDT_Report_Parameters),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Event1I(Event1I_Parameters),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct M4_Collection_Parameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m4_period: M4_Period,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub m4_threshold: Option<M4_Threshold>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<M4_Collection_ParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct M4_Period(pub u8);
 impl M4_Period {
@@ -1615,20 +1908,22 @@ impl M4_Period {
     pub const MS6000: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "31")]
 pub struct M4_Threshold(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum M4Report {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     All(NULL_43),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     M4_collection_parameters(M4_Collection_Parameters),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct M5_Period(pub u8);
 impl M5_Period {
@@ -1642,16 +1937,18 @@ impl M5_Period {
     pub const MS6000: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum M5Report {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     When_available(NULL_44),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     M5_period(M5_Period),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "12")]
 pub struct M6_Period(pub u8);
 impl M6_Period {
@@ -1670,16 +1967,20 @@ impl M6_Period {
     pub const MS64000: u8 = 12u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M6Report {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m6_period: M6_Period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m6_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M6ReportIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "12")]
 pub struct M7_Period(pub u8);
 impl M7_Period {
@@ -1698,20 +1999,24 @@ impl M7_Period {
     pub const MS64000: u8 = 12u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M7Report {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m7_period: M7_Period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m7_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M7ReportIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct MBMS_PTP_RAB_ID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MBMSBearerServiceType(pub u8);
 impl MBMSBearerServiceType {
@@ -1719,7 +2024,7 @@ impl MBMSBearerServiceType {
     pub const BROADCAST: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MBMSCNDe_Registration(pub u8);
 impl MBMSCNDe_Registration {
@@ -1727,23 +2032,29 @@ impl MBMSCNDe_Registration {
     pub const DEREGISTER: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSCNDe_RegistrationRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSCNDe_RegistrationRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSCNDe_RegistrationRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSCNDe_RegistrationResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSCNDe_RegistrationResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSCNDe_RegistrationResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MBMSCountingInformation(pub u8);
 impl MBMSCountingInformation {
@@ -1751,7 +2062,7 @@ impl MBMSCountingInformation {
     pub const NOTCOUNTING: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MBMSHCIndicator(pub u8);
 impl MBMSHCIndicator {
@@ -1759,7 +2070,7 @@ impl MBMSHCIndicator {
     pub const COMPRESSED_HEADER: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1768,72 +2079,95 @@ impl MBMSHCIndicator {
 )]
 pub struct MBMSIPMulticastAddressandAPNRequest(pub Vec<TMGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSIPMulticastAddressandAPNlist {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tmgi: TMGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ip_multicast_address: IPMulticastAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub apn: APN,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<MBMSIPMulticastAddressandAPNlistIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct MBMSLinkingInformation(pub u8);
 impl MBMSLinkingInformation {
     pub const U_E_HAS_JOINED_MULTICAST_SERVICES: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSRABEstablishmentIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSRABEstablishmentIndicationProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSRABEstablishmentIndicationProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSRABRelease {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSRABReleaseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSRABReleaseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSRABReleaseFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSRABReleaseFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSRABReleaseFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSRABReleaseRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSRABReleaseRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSRABReleaseRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSRegistrationFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSRegistrationFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSRegistrationFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSRegistrationRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSRegistrationRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSRegistrationRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MBMSRegistrationRequestType(pub u8);
 impl MBMSRegistrationRequestType {
@@ -1841,121 +2175,159 @@ impl MBMSRegistrationRequestType {
     pub const DEREGISTER: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSRegistrationResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSRegistrationResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSRegistrationResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct MBMSServiceArea(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct MBMSSessionDuration(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct MBMSSessionIdentity(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct MBMSSessionRepetitionNumber(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionStart {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionStartProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionStartProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionStartFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionStartFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionStartFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionStartResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionStartResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionStartResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionStop {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionStopProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionStopProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionStopResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionStopResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionStopResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionUpdate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionUpdateProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionUpdateProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionUpdateFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionUpdateFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionUpdateFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSessionUpdateResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSSessionUpdateResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSSessionUpdateResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSSynchronisationInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mbmshc_indicator: MBMSHCIndicator,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ip_multicast_address: IPMulticastAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub gtpdlteid: GTP_TEI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<MBMSSynchronisationInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSUELinkingRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSUELinkingRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSUELinkingRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MBMSUELinkingResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MBMSUELinkingResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<MBMSUELinkingResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct MDT_Activation(pub u8);
 impl MDT_Activation {
@@ -1964,69 +2336,82 @@ impl MDT_Activation {
     pub const IMMEDIATE_MD_TAND_TRACE: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MDT_Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mdt_activation: MDT_Activation,
+    #[asn(tag = "[1] EXPLICIT")]
     pub mdt_area_scope: MDTAreaScope,
+    #[asn(tag = "[2] EXPLICIT")]
     pub mdt_mode: MDTMode,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<MDT_ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct MDT_PLMN_List(pub Vec<PLMNidentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MDT_Report_Parameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub report_interval: ReportInterval,
+    #[asn(tag = "[1] IMPLICIT")]
     pub report_amount: ReportAmount,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = true)]
 pub enum MDTAreaScope {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Cellbased(CellBased),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Labased(LABased),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Rabased(RABased),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Plmn_area_based(NULL_45),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum MDTMode {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     ImmediateMDT(ImmediateMDT),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     LoggedMDT(LoggedMDT),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "9")]
 pub struct MSISDN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Management_Based_MDT_Allowed(pub u8);
 impl Management_Based_MDT_Allowed {
     pub const ALLOWED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "16000000")]
 pub struct MaxBitrate(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "32768")]
 pub struct MaxSDU_Size(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "5")]
 pub struct MeasBand(pub u8);
 impl MeasBand {
@@ -2038,7 +2423,7 @@ impl MeasBand {
     pub const V100: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct MeasurementQuantity(pub u8);
 impl MeasurementQuantity {
@@ -2047,11 +2432,11 @@ impl MeasurementQuantity {
     pub const PATHLOSS: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct MeasurementsToActivate(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2060,27 +2445,27 @@ pub struct MeasurementsToActivate(pub BitVec<u8, Msb0>);
 )]
 pub struct MessageStructure(pub Vec<MessageStructure_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NAS_PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct NAS_SequenceNumber(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct NAS_SynchronisationIndicator(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "3")]
 pub struct NRTLoadInformationValue(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NewBSS_To_OldBSS_Information(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2089,7 +2474,7 @@ pub struct NewBSS_To_OldBSS_Information(pub Vec<u8>);
 )]
 pub struct NewRAListofIdleModeUEs(pub Vec<RAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct NonSearchingIndication(pub u8);
 impl NonSearchingIndication {
@@ -2097,27 +2482,28 @@ impl NonSearchingIndication {
     pub const SEARCHING: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct NotEmptyRAListofIdleModeUEs {
+    #[asn(tag = "[0] IMPLICIT")]
     pub r_aof_idle_mode_u_es: RAofIdleModeUEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<NotEmptyRAListofIdleModeUEsIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "10", sz_ub = "10")]
 pub struct Null_NRI(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "2")]
 pub struct NumberOfIuInstances(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "16")]
 pub struct NumberOfSteps(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -2126,16 +2512,20 @@ pub struct NumberOfSteps(pub u8);
 )]
 pub struct OMC_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Offload_RAB_Parameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub access_point_name: Offload_RAB_Parameters_APN,
+    #[asn(tag = "[1] IMPLICIT")]
     pub charging_characteristics: Offload_RAB_Parameters_ChargingCharacteristics,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Offload_RAB_ParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -2144,43 +2534,48 @@ pub struct Offload_RAB_Parameters {
 )]
 pub struct Offload_RAB_Parameters_APN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct Offload_RAB_Parameters_ChargingCharacteristics(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OldBSS_ToNewBSS_Information(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Out_Of_UTRAN(pub u8);
 impl Out_Of_UTRAN {
     pub const CELL_RESELECTION_TO_EUTRAN: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Outcome {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: OutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Overload {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: OverloadProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<OverloadProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct P_TMSI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct PDP_Type(pub u8);
 impl PDP_Type {
@@ -2191,61 +2586,69 @@ impl PDP_Type {
     pub const IPV6: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct PDP_Type_extension(pub u8);
 impl PDP_Type_extension {
     pub const IPV4_AND_IPV6: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct PDP_TypeInformation(pub Vec<PDP_Type>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct PDP_TypeInformation_extension(pub Vec<PDP_Type_extension>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "3")]
 pub struct PDUType14FrameSequenceNumber(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PLMNBased {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_list: PLMNList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PLMNBasedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct PLMNList(pub Vec<PLMNidentity>);
 
 pub type PLMNidentity = TBCD_STRING;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct PLMNs_in_shared_network(pub Vec<PLMNs_in_shared_network_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Paging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PagingProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<PagingProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum PagingAreaID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     LAI(LAI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     RAI(RAI),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct PagingCause(pub u8);
 impl PagingCause {
@@ -2254,18 +2657,23 @@ impl PagingCause {
     pub const TERMINATING_INTERACTIVE_CALL: u8 = 2u8;
     pub const TERMINATING_BACKGROUND_CALL: u8 = 3u8;
     pub const TERMINATING_LOW_PRIORITY_SIGNALLING: u8 = 4u8;
+    pub const TERMINATING_HIGH_PRIORITY_SIGNALLING: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PeriodicLocationInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub reporting_amount: INTEGER_46,
+    #[asn(tag = "[1] IMPLICIT")]
     pub reporting_interval: INTEGER_47,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PeriodicLocationInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PeriodicReportingIndicator(pub u8);
 impl PeriodicReportingIndicator {
@@ -2273,52 +2681,57 @@ impl PeriodicReportingIndicator {
     pub const PERIODIC_GEO: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum PermanentNAS_UE_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     IMSI(IMSI),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct PermittedEncryptionAlgorithms(pub Vec<EncryptionAlgorithm>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct PermittedIntegrityProtectionAlgorithms(pub Vec<IntegrityProtectionAlgorithm>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct Port_Number(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PositionData {
+    #[asn(tag = "[0] IMPLICIT")]
     pub positioning_data_discriminator: PositioningDataDiscriminator,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub positioning_data_set: Option<PositioningDataSet>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PositionDataIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct PositionDataSpecificToGERANIuMode(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct PositioningDataDiscriminator(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "9")]
 pub struct PositioningDataSet(pub Vec<PositioningMethodAndUsage>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct PositioningMethodAndUsage(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PositioningPriority(pub u8);
 impl PositioningPriority {
@@ -2326,7 +2739,7 @@ impl PositioningPriority {
     pub const NORMAL_PRIORITY: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PowerSavingIndicator(pub u8);
 impl PowerSavingIndicator {
@@ -2334,7 +2747,7 @@ impl PowerSavingIndicator {
     pub const E_DRX_CONFIGURED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct Pre_emptionCapability(pub u8);
 impl Pre_emptionCapability {
@@ -2342,7 +2755,7 @@ impl Pre_emptionCapability {
     pub const MAY_TRIGGER_PRE_EMPTION: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct Pre_emptionVulnerability(pub u8);
 impl Pre_emptionVulnerability {
@@ -2350,7 +2763,7 @@ impl Pre_emptionVulnerability {
     pub const PRE_EMPTABLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Presence(pub u8);
 impl Presence {
@@ -2359,49 +2772,54 @@ impl Presence {
     pub const MANDATORY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct Priority_Class_Indicator(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15")]
 pub struct PriorityLevel(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum PrivateIE_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Local(INTEGER_48),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Global(OBJECT_IDENTIFIER_49),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PrivateMessage {
+    #[asn(tag = "[0] IMPLICIT")]
     pub private_i_es: PrivateMessagePrivateIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct ProcedureCode(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolExtensionID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolIE_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum ProvidedData {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Shared_network_information(Shared_Network_Information),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct QueuingAllowed(pub u8);
 impl QueuingAllowed {
@@ -2409,23 +2827,29 @@ impl QueuingAllowed {
     pub const QUEUEING_ALLOWED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_AssignmentRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RAB_AssignmentRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RAB_AssignmentRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_AssignmentResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RAB_AssignmentResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RAB_AssignmentResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct RAB_AsymmetryIndicator(pub u8);
 impl RAB_AsymmetryIndicator {
@@ -2435,7 +2859,7 @@ impl RAB_AsymmetryIndicator {
     pub const ASYMMETRIC_BIDIRECTIONAL: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2444,39 +2868,45 @@ impl RAB_AsymmetryIndicator {
 )]
 pub struct RAB_ContextFailedtoTransferList(pub Vec<RAB_ContextFailedtoTransferList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct RAB_ContextItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_gtp_pdu_sequence_number: Option<DL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ul_gtp_pdu_sequence_number: Option<UL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub dl_n_pdu_sequence_number: Option<DL_N_PDU_SequenceNumber>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ul_n_pdu_sequence_number: Option<UL_N_PDU_SequenceNumber>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<RAB_ContextItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct RAB_ContextItem_RANAP_RelocInf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_gtp_pdu_sequence_number: Option<DL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ul_gtp_pdu_sequence_number: Option<UL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub dl_n_pdu_sequence_number: Option<DL_N_PDU_SequenceNumber>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ul_n_pdu_sequence_number: Option<UL_N_PDU_SequenceNumber>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<RAB_ContextItem_RANAP_RelocInfIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2485,7 +2915,7 @@ pub struct RAB_ContextItem_RANAP_RelocInf {
 )]
 pub struct RAB_ContextList(pub Vec<RAB_ContextList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2494,25 +2924,33 @@ pub struct RAB_ContextList(pub Vec<RAB_ContextList_Entry>);
 )]
 pub struct RAB_ContextList_RANAP_RelocInf(pub Vec<RAB_ContextList_RANAP_RelocInf_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_DataForwardingItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] EXPLICIT")]
     pub iu_transport_association: IuTransportAssociation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_DataForwardingItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_DataForwardingItem_SRNS_CtxReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_DataForwardingItem_SRNS_CtxReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2521,7 +2959,7 @@ pub struct RAB_DataForwardingItem_SRNS_CtxReq {
 )]
 pub struct RAB_DataForwardingList(pub Vec<RAB_DataForwardingList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2530,17 +2968,20 @@ pub struct RAB_DataForwardingList(pub Vec<RAB_DataForwardingList_Entry>);
 )]
 pub struct RAB_DataForwardingList_SRNS_CtxReq(pub Vec<RAB_DataForwardingList_SRNS_CtxReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct RAB_DataVolumeReportItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_unsuccessfully_transmitted_data_volume: Option<DataVolumeList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RAB_DataVolumeReportItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2549,15 +2990,18 @@ pub struct RAB_DataVolumeReportItem {
 )]
 pub struct RAB_DataVolumeReportList(pub Vec<RAB_DataVolumeReportList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_DataVolumeReportRequestItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_DataVolumeReportRequestItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2566,26 +3010,35 @@ pub struct RAB_DataVolumeReportRequestItem {
 )]
 pub struct RAB_DataVolumeReportRequestList(pub Vec<RAB_DataVolumeReportRequestList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_FailedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_FailedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_FailedItem_EnhRelocInfoRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cn_domain_indicator: CN_DomainIndicator,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[2] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_FailedItem_EnhRelocInfoResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2594,7 +3047,7 @@ pub struct RAB_FailedItem_EnhRelocInfoRes {
 )]
 pub struct RAB_FailedList(pub Vec<RAB_FailedList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2603,7 +3056,7 @@ pub struct RAB_FailedList(pub Vec<RAB_FailedList_Entry>);
 )]
 pub struct RAB_FailedList_EnhRelocInfoRes(pub Vec<RAB_FailedList_EnhRelocInfoRes_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2612,20 +3065,24 @@ pub struct RAB_FailedList_EnhRelocInfoRes(pub Vec<RAB_FailedList_EnhRelocInfoRes
 )]
 pub struct RAB_FailedtoReportList(pub Vec<RAB_FailedtoReportList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct RAB_ID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_ModifyItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub requested_rab_parameter_values: Requested_RAB_Parameter_Values,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_ModifyItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2634,64 +3091,78 @@ pub struct RAB_ModifyItem {
 )]
 pub struct RAB_ModifyList(pub Vec<RAB_ModifyList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_ModifyRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RAB_ModifyRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RAB_ModifyRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct RAB_Parameter_ExtendedGuaranteedBitrateList(pub Vec<ExtendedGuaranteedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct RAB_Parameter_ExtendedMaxBitrateList(pub Vec<ExtendedMaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct RAB_Parameter_GuaranteedBitrateList(pub Vec<GuaranteedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct RAB_Parameter_MaxBitrateList(pub Vec<MaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 7)]
 pub struct RAB_Parameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub traffic_class: TrafficClass,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rab_asymmetry_indicator: RAB_AsymmetryIndicator,
+    #[asn(tag = "[2] IMPLICIT")]
     pub max_bitrate: RAB_Parameter_MaxBitrateList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub guaranteed_bit_rate: Option<RAB_Parameter_GuaranteedBitrateList>,
+    #[asn(tag = "[4] IMPLICIT")]
     pub delivery_order: DeliveryOrder,
+    #[asn(tag = "[5] IMPLICIT")]
     pub max_sdu_size: MaxSDU_Size,
+    #[asn(tag = "[6] IMPLICIT")]
     pub sdu_parameters: SDU_Parameters,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 1)]
     pub transfer_delay: Option<TransferDelay>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[8] IMPLICIT", optional_idx = 2)]
     pub traffic_handling_priority: Option<TrafficHandlingPriority>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 3)]
     pub allocation_or_retention_priority: Option<AllocationOrRetentionPriority>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[10] IMPLICIT", optional_idx = 4)]
     pub source_statistics_descriptor: Option<SourceStatisticsDescriptor>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[11] IMPLICIT", optional_idx = 5)]
     pub relocation_requirement: Option<RelocationRequirement>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[12] IMPLICIT", optional_idx = 6)]
     pub ie_extensions: Option<RAB_ParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_QueuedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_QueuedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2702,16 +3173,20 @@ pub struct RAB_QueuedList(pub Vec<RAB_QueuedList_Entry>);
 
 pub type RAB_ReleaseFailedList = RAB_FailedList;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_ReleaseItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_ReleaseItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2720,41 +3195,50 @@ pub struct RAB_ReleaseItem {
 )]
 pub struct RAB_ReleaseList(pub Vec<RAB_ReleaseList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_ReleaseRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RAB_ReleaseRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RAB_ReleaseRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct RAB_ReleasedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_data_volumes: Option<DataVolumeList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub dl_gtp_pdu_sequence_number: Option<DL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ul_gtp_pdu_sequence_number: Option<UL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<RAB_ReleasedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct RAB_ReleasedItem_IuRelComp {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_gtp_pdu_sequence_number: Option<DL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ul_gtp_pdu_sequence_number: Option<UL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<RAB_ReleasedItem_IuRelCompIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2763,7 +3247,7 @@ pub struct RAB_ReleasedItem_IuRelComp {
 )]
 pub struct RAB_ReleasedList(pub Vec<RAB_ReleasedList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2772,15 +3256,18 @@ pub struct RAB_ReleasedList(pub Vec<RAB_ReleasedList_Entry>);
 )]
 pub struct RAB_ReleasedList_IuRelComp(pub Vec<RAB_ReleasedList_IuRelComp_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_RelocationReleaseItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_RelocationReleaseItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2789,106 +3276,133 @@ pub struct RAB_RelocationReleaseItem {
 )]
 pub struct RAB_RelocationReleaseList(pub Vec<RAB_RelocationReleaseList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 7)]
 pub struct RAB_SetupItem_EnhRelocInfoReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cn_domain_indicator: CN_DomainIndicator,
+    #[asn(tag = "[2] IMPLICIT")]
     pub rab_parameters: RAB_Parameters,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub data_volume_reporting_indication: Option<DataVolumeReportingIndication>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub pdp_type_information: Option<PDP_TypeInformation>,
+    #[asn(tag = "[5] IMPLICIT")]
     pub user_plane_information: UserPlaneInformation,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 2)]
     pub data_forwarding_information: Option<TNLInformationEnhRelInfoReq>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 3)]
     pub source_side_iu_ultnl_info: Option<TNLInformationEnhRelInfoReq>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[8] IMPLICIT", optional_idx = 4)]
     pub service_handover: Option<Service_Handover>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 5)]
     pub alt_rab_parameters: Option<Alt_RAB_Parameters>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[10] IMPLICIT", optional_idx = 6)]
     pub ie_extensions: Option<RAB_SetupItem_EnhRelocInfoReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct RAB_SetupItem_EnhRelocInfoRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cn_domain_indicator: CN_DomainIndicator,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub data_forwarding_information: Option<TNLInformationEnhRelInfoRes>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ass_rab_parameters: Option<Ass_RAB_Parameters>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<RAB_SetupItem_EnhRelocInfoResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct RAB_SetupItem_EnhancedRelocCompleteReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub transport_layer_address_req1: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 1)]
     pub iu_transport_association_req1: Option<IuTransportAssociation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ass_rab_parameters: Option<Ass_RAB_Parameters>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<RAB_SetupItem_EnhancedRelocCompleteReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct RAB_SetupItem_EnhancedRelocCompleteRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub rab_parameters: Option<RAB_Parameters>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub user_plane_information: UserPlaneInformation,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub transport_layer_address_res1: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] EXPLICIT", optional_idx = 2)]
     pub iu_transport_association_res1: Option<IuTransportAssociation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 3)]
     pub rab2be_released_list: Option<RAB_ToBeReleasedList_EnhancedRelocCompleteRes>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<RAB_SetupItem_EnhancedRelocCompleteResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct RAB_SetupItem_RelocReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub nas_synchronisation_indicator: Option<NAS_SynchronisationIndicator>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub rab_parameters: RAB_Parameters,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub data_volume_reporting_indication: Option<DataVolumeReportingIndication>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub pdp_type_information: Option<PDP_TypeInformation>,
+    #[asn(tag = "[5] IMPLICIT")]
     pub user_plane_information: UserPlaneInformation,
+    #[asn(tag = "[6] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[7] EXPLICIT")]
     pub iu_transport_association: IuTransportAssociation,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[8] IMPLICIT", optional_idx = 3)]
     pub service_handover: Option<Service_Handover>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<RAB_SetupItem_RelocReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct RAB_SetupItem_RelocReqAck {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub transport_layer_address: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 1)]
     pub iu_transport_association: Option<IuTransportAssociation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<RAB_SetupItem_RelocReqAckIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2897,7 +3411,7 @@ pub struct RAB_SetupItem_RelocReqAck {
 )]
 pub struct RAB_SetupList_EnhRelocInfoReq(pub Vec<RAB_SetupList_EnhRelocInfoReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2906,7 +3420,7 @@ pub struct RAB_SetupList_EnhRelocInfoReq(pub Vec<RAB_SetupList_EnhRelocInfoReq_E
 )]
 pub struct RAB_SetupList_EnhRelocInfoRes(pub Vec<RAB_SetupList_EnhRelocInfoRes_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2917,7 +3431,7 @@ pub struct RAB_SetupList_EnhancedRelocCompleteReq(
     pub Vec<RAB_SetupList_EnhancedRelocCompleteReq_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2928,7 +3442,7 @@ pub struct RAB_SetupList_EnhancedRelocCompleteRes(
     pub Vec<RAB_SetupList_EnhancedRelocCompleteRes_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2937,7 +3451,7 @@ pub struct RAB_SetupList_EnhancedRelocCompleteRes(
 )]
 pub struct RAB_SetupList_RelocReq(pub Vec<RAB_SetupList_RelocReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2946,21 +3460,24 @@ pub struct RAB_SetupList_RelocReq(pub Vec<RAB_SetupList_RelocReq_Entry>);
 )]
 pub struct RAB_SetupList_RelocReqAck(pub Vec<RAB_SetupList_RelocReqAck_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct RAB_SetupOrModifiedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub transport_layer_address: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 1)]
     pub iu_transport_association: Option<IuTransportAssociation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub dl_data_volumes: Option<DataVolumeList>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<RAB_SetupOrModifiedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2969,44 +3486,49 @@ pub struct RAB_SetupOrModifiedItem {
 )]
 pub struct RAB_SetupOrModifiedList(pub Vec<RAB_SetupOrModifiedList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 6)]
 pub struct RAB_SetupOrModifyItemFirst {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub nas_synchronisation_indicator: Option<NAS_SynchronisationIndicator>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub rab_parameters: Option<RAB_Parameters>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub user_plane_information: Option<UserPlaneInformation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub transport_layer_information: Option<TransportLayerInformation>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub service_handover: Option<Service_Handover>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 5)]
     pub ie_extensions: Option<RAB_SetupOrModifyItemFirstIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 7)]
 pub struct RAB_SetupOrModifyItemSecond {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub pdp_type_information: Option<PDP_TypeInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub data_volume_reporting_indication: Option<DataVolumeReportingIndication>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub dl_gtp_pdu_sequence_number: Option<DL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ul_gtp_pdu_sequence_number: Option<UL_GTP_PDU_SequenceNumber>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub dl_n_pdu_sequence_number: Option<DL_N_PDU_SequenceNumber>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 5)]
     pub ul_n_pdu_sequence_number: Option<UL_N_PDU_SequenceNumber>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 6)]
     pub ie_extensions: Option<RAB_SetupOrModifyItemSecondIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3015,20 +3537,24 @@ pub struct RAB_SetupOrModifyItemSecond {
 )]
 pub struct RAB_SetupOrModifyList(pub Vec<RAB_SetupOrModifyList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "16000000")]
 pub struct RAB_SubflowCombinationBitRate(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_ToBeReleasedItem_EnhancedRelocCompleteRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_ToBeReleasedItem_EnhancedRelocCompleteResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3039,7 +3565,7 @@ pub struct RAB_ToBeReleasedList_EnhancedRelocCompleteRes(
     pub Vec<RAB_ToBeReleasedList_EnhancedRelocCompleteRes_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3048,20 +3574,24 @@ pub struct RAB_ToBeReleasedList_EnhancedRelocCompleteRes(
 )]
 pub struct RAB_TrCH_Mapping(pub Vec<RAB_TrCH_MappingItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAB_TrCH_MappingItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tr_ch_id_list: TrCH_ID_List,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAB_TrCH_MappingItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct RABDataVolumeReport(pub Vec<RABDataVolumeReport_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3070,59 +3600,76 @@ pub struct RABDataVolumeReport(pub Vec<RABDataVolumeReport_Entry>);
 )]
 pub struct RABParametersList(pub Vec<RABParametersList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RABased {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rai_list: RAI_List,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RABasedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RABs_ContextFailedtoTransferItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RABs_ContextFailedtoTransferItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RABs_failed_to_reportItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RABs_failed_to_reportItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct RAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lai: LAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rac: RAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct RAI_List(pub Vec<RAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum RAListofIdleModeUEs {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     NotEmptyRAListofIdleModeUEs(NotEmptyRAListofIdleModeUEs),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     EmptyFullRAListofIdleModeUEs(ENUMERATED_50),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3131,44 +3678,61 @@ pub enum RAListofIdleModeUEs {
 )]
 pub struct RAListwithNoIdleModeUEsAnyMore(pub Vec<RAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RANAP_EnhancedRelocationInformationRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RANAP_EnhancedRelocationInformationRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RANAP_EnhancedRelocationInformationRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RANAP_EnhancedRelocationInformationResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RANAP_EnhancedRelocationInformationResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RANAP_EnhancedRelocationInformationResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
-#[asn(type = "CHOICE", lb = "0", ub = "3", extensible = true)]
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "3",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
 pub enum RANAP_PDU {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     InitiatingMessage(InitiatingMessage),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SuccessfulOutcome(SuccessfulOutcome),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     UnsuccessfulOutcome(UnsuccessfulOutcome),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Outcome(Outcome),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RANAP_RelocationInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RANAP_RelocationInformationProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RANAP_RelocationInformationProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RAT_Type(pub u8);
 impl RAT_Type {
@@ -3176,7 +3740,7 @@ impl RAT_Type {
     pub const GERAN: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3185,104 +3749,117 @@ impl RAT_Type {
 )]
 pub struct RAofIdleModeUEs(pub Vec<RAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
 pub struct RIM_Transfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rim_information: RIMInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
     pub rim_routing_address: Option<RIMRoutingAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RIM_TransferIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct RIMInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum RIMRoutingAddress {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TargetRNC_ID(TargetRNC_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     GERAN_Cell_ID(GERAN_Cell_ID),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[2] IMPLICIT")]
     TargeteNB_ID(TargetENB_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct RNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
 pub struct RNCTraceInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub trace_reference: TraceReference,
+    #[asn(tag = "[1] IMPLICIT")]
     pub trace_activation_indicator: ENUMERATED_51,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] EXPLICIT", optional_idx = 0)]
     pub equipments_to_be_traced: Option<EquipmentsToBeTraced>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RNCTraceInformationIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct RNSAPRelocationParameters {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub rab_parmeters_list: Option<RABParametersList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub location_reporting: Option<LocationReportingTransferInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub trace_information: Option<TraceInformation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub source_sai: Option<SAI>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<RNSAPRelocationParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct RRC_Container(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "-30", ub = "46", extensible = true)]
 pub struct RSRQ_Extension(pub i8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RSRQ_Type {
+    #[asn(tag = "[0] IMPLICIT")]
     pub all_symbols: BOOLEAN_52,
+    #[asn(tag = "[1] IMPLICIT")]
     pub wide_band: BOOLEAN_53,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct RSRVCC_HO_Indication(pub u8);
 impl RSRVCC_HO_Indication {
     pub const PS_ONLY: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RSRVCC_Information {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nonce: BIT_STRING_54,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ims_information: OCTET_STRING_55,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RSRVCC_InformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct RSRVCC_Operation_Possible(pub u8);
 impl RSRVCC_Operation_Possible {
     pub const RSRVCC_POSSIBLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "100")]
 pub struct RTLoadValue(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct RateControlAllowed(pub u8);
 impl RateControlAllowed {
@@ -3290,18 +3867,18 @@ impl RateControlAllowed {
     pub const ALLOWED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct RedirectAttemptFlag;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct RedirectionCompleted(pub u8);
 impl RedirectionCompleted {
     pub const REDIRECTION_COMPLETED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3310,7 +3887,7 @@ impl RedirectionCompleted {
 )]
 pub struct RedirectionIndication(pub Vec<RedirectionIndication_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct RejectCauseValue(pub u8);
 impl RejectCauseValue {
@@ -3320,97 +3897,130 @@ impl RejectCauseValue {
     pub const NO_SUITABLE_CELL_IN_LOCATION_AREA: u8 = 3u8;
     pub const G_PRS_SERVICES_NOT_ALLOWED_IN_THIS_PLMN: u8 = 4u8;
     pub const C_S_PS_COORDINATION_REQUIRED: u8 = 5u8;
+    pub const NETWORK_FAILURE: u8 = 6u8;
+    pub const NOT_AUTHORIZED_FOR_THIS_CSG: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationCancel {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationCancelProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationCancelProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationCancelAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationCancelAcknowledgeProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationCancelAcknowledgeProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationCommandProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationCommandProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationComplete {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationCompleteProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationCompleteProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationDetect {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationDetectProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationDetectProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationPreparationFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationPreparationFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationPreparationFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationRequestAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationRequestAcknowledgeProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationRequestAcknowledgeProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RelocationRequired {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RelocationRequiredProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RelocationRequiredProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RelocationRequirement(pub u8);
 impl RelocationRequirement {
     pub const LOSSLESS: u8 = 0u8;
     pub const NONE: u8 = 1u8;
+    pub const REALTIME: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct RelocationType(pub u8);
 impl RelocationType {
@@ -3418,15 +4028,15 @@ impl RelocationType {
     pub const UE_INVOLVED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct RepetitionNumber0(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "256")]
 pub struct RepetitionNumber1(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct ReportAmount(pub u8);
 impl ReportAmount {
@@ -3440,7 +4050,7 @@ impl ReportAmount {
     pub const INFINITY: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ReportArea(pub u8);
 impl ReportArea {
@@ -3448,14 +4058,14 @@ impl ReportArea {
     pub const GEOGRAPHICAL_AREA: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ReportChangeOfSAI(pub u8);
 impl ReportChangeOfSAI {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "12")]
 pub struct ReportInterval(pub u8);
 impl ReportInterval {
@@ -3472,47 +4082,55 @@ impl ReportInterval {
     pub const MS24000: u8 = 10u8;
     pub const MS32000: u8 = 11u8;
     pub const MS64000: u8 = 12u8;
+    pub const MS8000: u8 = 13u8;
+    pub const MS28000: u8 = 14u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RequestType {
+    #[asn(tag = "[0] IMPLICIT")]
     pub event: Event,
+    #[asn(tag = "[1] IMPLICIT")]
     pub report_area: ReportArea,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub accuracy_code: Option<INTEGER_56>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Requested_RAB_Parameter_ExtendedGuaranteedBitrateList(
     pub Vec<ExtendedGuaranteedBitrate>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Requested_RAB_Parameter_ExtendedMaxBitrateList(pub Vec<ExtendedMaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Requested_RAB_Parameter_GuaranteedBitrateList(pub Vec<GuaranteedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct Requested_RAB_Parameter_MaxBitrateList(pub Vec<MaxBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct Requested_RAB_Parameter_Values {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub requested_max_bitrates: Option<Requested_RAB_Parameter_MaxBitrateList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub requested_guaranteed_bitrates: Option<Requested_RAB_Parameter_GuaranteedBitrateList>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<Requested_RAB_Parameter_ValuesIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -3521,7 +4139,7 @@ pub struct Requested_RAB_Parameter_Values {
 )]
 pub struct RequestedGANSSAssistanceData(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -3530,7 +4148,7 @@ pub struct RequestedGANSSAssistanceData(pub Vec<u8>);
 )]
 pub struct RequestedGPSAssistanceData(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct RequestedLocationRelatedDataType(pub u8);
 impl RequestedLocationRelatedDataType {
@@ -3538,9 +4156,13 @@ impl RequestedLocationRelatedDataType {
     pub const DECIPHERING_KEYS_ASSISTED_GPS: u8 = 1u8;
     pub const DEDICATED_ASSISTANCE_DATA_UE_BASED_OTDOA: u8 = 2u8;
     pub const DEDICATED_ASSISTANCE_DATA_ASSISTED_GPS: u8 = 3u8;
+    pub const DECIPHERING_KEYS_ASSISTED_GANSS: u8 = 4u8;
+    pub const DEDICATED_ASSISTANCE_DATA_ASSISTED_GANSS: u8 = 5u8;
+    pub const DECIPHERING_KEYS_ASSISTED_GP_SAND_GANSS: u8 = 6u8;
+    pub const DEDICATED_ASSISTANCE_DATA_ASSISTED_GP_SAND_GANSS: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3549,7 +4171,7 @@ impl RequestedLocationRelatedDataType {
 )]
 pub struct RequestedMBMSIPMulticastAddressandAPNRequest(pub Vec<MBMSIPMulticastAddressandAPNlist>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3558,47 +4180,62 @@ pub struct RequestedMBMSIPMulticastAddressandAPNRequest(pub Vec<MBMSIPMulticastA
 )]
 pub struct RequestedMulticastServiceList(pub Vec<TMGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RerouteNASRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RerouteNASRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<RerouteNASRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Reset {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ResetProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<ResetProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ResetAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ResetAcknowledgeProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<ResetAcknowledgeProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ResetResource {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ResetResourceProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<ResetResourceProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ResetResourceAckItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub iu_sig_con_id: IuSignallingConnectionIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ResetResourceAckItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3607,23 +4244,29 @@ pub struct ResetResourceAckItem {
 )]
 pub struct ResetResourceAckList(pub Vec<ResetResourceAckList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ResetResourceAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ResetResourceAcknowledgeProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<ResetResourceAcknowledgeProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ResetResourceItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub iu_sig_con_id: IuSignallingConnectionIdentifier,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ResetResourceItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3632,16 +4275,18 @@ pub struct ResetResourceItem {
 )]
 pub struct ResetResourceList(pub Vec<ResetResourceList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct ResidualBitErrorRatio {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mantissa: INTEGER_57,
+    #[asn(tag = "[1] IMPLICIT")]
     pub exponent: INTEGER_58,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ResidualBitErrorRatioIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ResponseTime(pub u8);
 impl ResponseTime {
@@ -3649,21 +4294,24 @@ impl ResponseTime {
     pub const DELAYTOLERANT: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct SAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct SAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub lac: LAC,
+    #[asn(tag = "[2] IMPLICIT")]
     pub sac: SAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SAIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SAPI(pub u8);
 impl SAPI {
@@ -3671,106 +4319,130 @@ impl SAPI {
     pub const SAPI_3: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct SDU_ErrorRatio {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mantissa: INTEGER_59,
+    #[asn(tag = "[1] IMPLICIT")]
     pub exponent: INTEGER_60,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SDU_ErrorRatioIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct SDU_FormatInformationParameters(pub Vec<SDU_FormatInformationParameters_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "7")]
 pub struct SDU_Parameters(pub Vec<SDU_Parameters_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct SGSN_Group_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum SGSN_Group_Identity {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Null_NRI(Null_NRI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SGSN_Group_ID(SGSN_Group_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SNA_Access_Information {
+    #[asn(tag = "[0] IMPLICIT")]
     pub authorised_plm_ns: AuthorisedPLMNs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SNA_Access_InformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct SNAC(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "32")]
 pub struct SRB_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct SRB_TrCH_Mapping(pub Vec<SRB_TrCH_MappingItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SRB_TrCH_MappingItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub srb_id: SRB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tr_ch_id: TrCH_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SRB_TrCH_MappingItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SRNS_ContextRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SRNS_ContextRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SRNS_ContextRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SRNS_ContextResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SRNS_ContextResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SRNS_ContextResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SRNS_DataForwardCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SRNS_DataForwardCommandProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SRNS_DataForwardCommandProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SRVCC_CSKeysRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SRVCC_CSKeysRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SRVCC_CSKeysRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SRVCC_CSKeysResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SRVCC_CSKeysResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SRVCC_CSKeysResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SRVCC_HO_Indication(pub u8);
 impl SRVCC_HO_Indication {
@@ -3778,53 +4450,65 @@ impl SRVCC_HO_Indication {
     pub const CS_ONLY: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SRVCC_Information {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nonce: BIT_STRING_61,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SRVCC_InformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SRVCC_Operation_Possible(pub u8);
 impl SRVCC_Operation_Possible {
     pub const SRVCC_POSSIBLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SRVCCSource(pub u8);
 impl SRVCCSource {
     pub const V5_G: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityModeCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SecurityModeCommandProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SecurityModeCommandProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityModeComplete {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SecurityModeCompleteProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SecurityModeCompleteProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityModeReject {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SecurityModeRejectProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<SecurityModeRejectProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Service_Handover(pub u8);
 impl Service_Handover {
@@ -3833,7 +4517,7 @@ impl Service_Handover {
     pub const HANDOVER_TO_GSM_SHALL_NOT_BE_PERFORMED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ServiceType(pub u8);
 impl ServiceType {
@@ -3841,96 +4525,110 @@ impl ServiceType {
     pub const Q_MC_FOR_MSTI_SERVICE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Session_Re_establishment_Indicator(pub u8);
 impl Session_Re_establishment_Indicator {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1048575")]
 pub struct SessionUpdateID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Shared_Network_Information {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_ns_in_shared_network: PLMNs_in_shared_network,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Shared_Network_InformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SignallingIndication(pub u8);
 impl SignallingIndication {
     pub const SIGNALLING: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Source_ToTarget_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct SourceBSS_ToTargetBSS_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum SourceCellID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SourceUTRANCellID(SourceUTRANCellID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SourceGERANCellID(CGI),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum SourceID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SourceRNC_ID(SourceRNC_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SAI(SAI),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct SourceRNC_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rnc_id: RNC_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SourceRNC_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 10)]
 pub struct SourceRNC_ToTargetRNC_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rrc_container: RRC_Container,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_iu_instances: NumberOfIuInstances,
+    #[asn(tag = "[2] IMPLICIT")]
     pub relocation_type: RelocationType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub chosen_integrity_protection_algorithm: Option<ChosenIntegrityProtectionAlgorithm>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub integrity_protection_key: Option<IntegrityProtectionKey>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub chosen_encryption_algorith_for_signalling: Option<ChosenEncryptionAlgorithm>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 3)]
     pub ciphering_key: Option<EncryptionKey>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 4)]
     pub chosen_encryption_algorith_for_cs: Option<ChosenEncryptionAlgorithm>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[8] IMPLICIT", optional_idx = 5)]
     pub chosen_encryption_algorith_for_ps: Option<ChosenEncryptionAlgorithm>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 6)]
     pub d_rnti: Option<D_RNTI>,
-    #[asn(optional_idx = 7)]
+    #[asn(tag = "[10] IMPLICIT", optional_idx = 7)]
     pub target_cell_id: Option<TargetCellId>,
-    #[asn(optional_idx = 8)]
+    #[asn(tag = "[11] IMPLICIT", optional_idx = 8)]
     pub rab_tr_ch_mapping: Option<RAB_TrCH_Mapping>,
-    #[asn(optional_idx = 9)]
+    #[asn(tag = "[12] IMPLICIT", optional_idx = 9)]
     pub ie_extensions: Option<SourceRNC_ToTargetRNC_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SourceStatisticsDescriptor(pub u8);
 impl SourceStatisticsDescriptor {
@@ -3938,193 +4636,225 @@ impl SourceStatisticsDescriptor {
     pub const UNKNOWN: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct SourceUTRANCellID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub utra_ncell_id: TargetCellId,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SourceUTRANCellIDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct SourceeNodeB_ToTargeteNodeB_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct SubflowSDU_Size(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "256")]
 pub struct SubscriberProfileIDforRFP(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SuccessfulOutcome {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "1000000000", extensible = true)]
 pub struct SupportedBitrate(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct SupportedRAB_ParameterBitrateList(pub Vec<SupportedBitrate>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct TAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct TAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tac: TAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TBCD_STRING(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct TMGI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub service_id: OCTET_STRING_62,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TMGIIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct TMSI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TNLInformationEnhRelInfoReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[1] EXPLICIT")]
     pub iu_transport_association: IuTransportAssociation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TNLInformationEnhRelInfoReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TNLInformationEnhRelInfoRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_forwarding_transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[1] EXPLICIT")]
     pub dl_forwarding_transport_association: IuTransportAssociation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TNLInformationEnhRelInfoResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Target_ToSource_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargetBSS_ToSourceBSS_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "268435455")]
 pub struct TargetCellId(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetENB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub enb_id: ENB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TargetENB_IDIE_Extensions>,
+    #[asn(tag = "[3] IMPLICIT")]
     pub selected_tai: TAI,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum TargetID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TargetRNC_ID(TargetRNC_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     CGI(CGI),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[2] IMPLICIT")]
     TargeteNB_ID(TargetENB_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
 pub struct TargetRNC_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lai: LAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub rac: Option<RAC>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub rnc_id: RNC_ID,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TargetRNC_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TargetRNC_ToSourceRNC_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rrc_container: RRC_Container,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub d_rnti: Option<D_RNTI>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TargetRNC_ToSourceRNC_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargeteNodeB_ToSourceeNodeB_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum TemporaryUE_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TMSI(TMSI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     P_TMSI(P_TMSI),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct Time_UE_StayedInCell(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "40950")]
 pub struct Time_UE_StayedInCell_EnhancedGranularity(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct TimeToMBMSDataTransfer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct TimingDifferenceULDL(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct TrCH_ID {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub dch_id: Option<DCH_ID>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub dsch_id: Option<DSCH_ID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub usch_id: Option<USCH_ID>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<TrCH_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "7")]
 pub struct TrCH_ID_List(pub Vec<TrCH_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct TraceDepth(pub u8);
 impl TraceDepth {
@@ -4133,50 +4863,62 @@ impl TraceDepth {
     pub const MAXIMUM: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TraceInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub trace_reference: TraceReference,
+    #[asn(tag = "[1] EXPLICIT")]
     pub ue_identity: UE_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub trace_propagation_parameters: Option<TracePropagationParameters>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TraceInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TracePropagationParameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub trace_recording_session_reference: TraceRecordingSessionReference,
+    #[asn(tag = "[1] IMPLICIT")]
     pub trace_depth: TraceDepth,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub list_of_interfaces_to_trace: Option<ListOfInterfacesToTrace>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TracePropagationParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TraceRecordingSessionInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub trace_reference: TraceReference,
+    #[asn(tag = "[1] IMPLICIT")]
     pub trace_recording_session_reference: TraceRecordingSessionReference,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TraceRecordingSessionInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct TraceRecordingSessionReference(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "3")]
 pub struct TraceReference(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct TraceType(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct TrafficClass(pub u8);
 impl TrafficClass {
@@ -4186,28 +4928,32 @@ impl TrafficClass {
     pub const BACKGROUND: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15")]
 pub struct TrafficHandlingPriority(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct TransferDelay(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "1", sz_ub = "160")]
 pub struct TransportLayerAddress(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TransportLayerInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[1] EXPLICIT")]
     pub iu_transport_association: IuTransportAssociation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TransportLayerInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -4216,7 +4962,7 @@ pub struct TransportLayerInformation {
 )]
 pub struct TriggerID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "3")]
 pub struct TriggeringMessage(pub u8);
 impl TriggeringMessage {
@@ -4226,17 +4972,20 @@ impl TriggeringMessage {
     pub const OUTCOME: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TunnelInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub udp_port_number: Option<Port_Number>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TunnelInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct TypeOfError(pub u8);
 impl TypeOfError {
@@ -4244,200 +4993,248 @@ impl TypeOfError {
     pub const MISSING: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UE_AggregateMaximumBitRate {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub ue_aggregate_maximum_bit_rate_downlink: Option<UE_AggregateMaximumBitRateDownlink>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ue_aggregate_maximum_bit_rate_uplink: Option<UE_AggregateMaximumBitRateUplink>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "1000000000")]
 pub struct UE_AggregateMaximumBitRateDownlink(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "1000000000")]
 pub struct UE_AggregateMaximumBitRateUplink(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct UE_Application_Layer_Measurement_Capability(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UE_Application_Layer_Measurement_Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub application_layer_container_for_measurement_configuration: OCTET_STRING_63,
+    #[asn(tag = "[1] EXPLICIT")]
     pub area_scope_for_ue_application_layer_measurement_configuration:
         AreaScopeForUEApplicationLayerMeasurementConfiguration,
-    pub service_type: ServiceType,
+    #[asn(tag = "[2] IMPLICIT", extension_idx = 0)]
+    pub service_type: Option<ServiceType>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct UE_Application_Layer_Measurement_Configuration_For_Relocation {
+    #[asn(tag = "[0] EXPLICIT")]
     pub area_scope_for_ue_application_layer_measurement_configuration:
         AreaScopeForUEApplicationLayerMeasurementConfiguration,
+    #[asn(tag = "[1] IMPLICIT")]
     pub trace_reference: TraceReference,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub trace_propagation_parameters: Option<TracePropagationParameters>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub trace_collection_entity_ip_address: Option<TransportLayerAddress>,
-    pub service_type: ServiceType,
+    #[asn(tag = "[4] IMPLICIT", extension_idx = 0)]
+    pub service_type: Option<ServiceType>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UE_History_Information(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum UE_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Imsi(IMSI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Imei(IMEI),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[2] IMPLICIT")]
     Imeisv(IMEISV),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UE_IsNotServed {
+    #[asn(tag = "[0] EXPLICIT")]
     pub permanent_nas_ue_id: PermanentNAS_UE_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UE_IsNotServedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UE_IsServed {
+    #[asn(tag = "[0] EXPLICIT")]
     pub permanent_nas_ue_id: PermanentNAS_UE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UE_IsServedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct UE_Usage_Type(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum UERegistrationQueryResult {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     UE_IsServed(UE_IsServed),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     UE_IsNotServed(UE_IsNotServed),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UESBI_Iu {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub uesbi_iu_a: Option<UESBI_IuA>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub uesbi_iu_b: Option<UESBI_IuB>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<UESBI_IuIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "1", sz_ub = "128")]
 pub struct UESBI_IuA(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "1", sz_ub = "128")]
 pub struct UESBI_IuB(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UESpecificInformationIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UESpecificInformationIndicationProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UESpecificInformationIndicationProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct UL_GTP_PDU_SequenceNumber(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct UL_N_PDU_SequenceNumber(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct UP_ModeVersions(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UPInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub frame_seq_no_ul: FrameSequenceNumber,
+    #[asn(tag = "[1] IMPLICIT")]
     pub frame_seq_no_dl: FrameSequenceNumber,
+    #[asn(tag = "[2] IMPLICIT")]
     pub pdu14_frame_seq_no_ul: PDUType14FrameSequenceNumber,
+    #[asn(tag = "[3] IMPLICIT")]
     pub pdu14_frame_seq_no_dl: PDUType14FrameSequenceNumber,
+    #[asn(tag = "[4] IMPLICIT")]
     pub data_pdu_type: DataPDUType,
+    #[asn(tag = "[5] IMPLICIT")]
     pub upinitialisation_frame: UPInitialisationFrame,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UPInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UPInitialisationFrame(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct USCH_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct UTRAN_CellID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cell_id: TargetCellId,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UTRAN_CellIDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct UeApplicationLayerMeasurementSupportIndication(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UeRadioCapabilityMatchRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UeRadioCapabilityMatchRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UeRadioCapabilityMatchRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UeRadioCapabilityMatchResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UeRadioCapabilityMatchResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UeRadioCapabilityMatchResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UeRegistrationQueryRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UeRegistrationQueryRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UeRegistrationQueryRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UeRegistrationQueryResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UeRegistrationQueryResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UeRegistrationQueryResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4446,53 +5243,68 @@ pub struct UeRegistrationQueryResponse {
 )]
 pub struct UnsuccessfulLinking_IEs(pub Vec<UnsuccessfulLinking_IEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UnsuccessfulOutcome {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UnsuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
 pub struct UnsuccessfullyTransmittedDataVolume(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UplinkInformationExchangeFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkInformationExchangeFailureProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UplinkInformationExchangeFailureProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UplinkInformationExchangeRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkInformationExchangeRequestProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UplinkInformationExchangeRequestProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UplinkInformationExchangeResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkInformationExchangeResponseProtocolIEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub protocol_extensions: Option<UplinkInformationExchangeResponseProtocolExtensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UserPlaneInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub user_plane_mode: UserPlaneMode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub up_mode_versions: UP_ModeVersions,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UserPlaneInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct UserPlaneMode(pub u8);
 impl UserPlaneMode {
@@ -4500,24 +5312,26 @@ impl UserPlaneMode {
     pub const SUPPORT_MODE_FOR_PREDEFINED_SDU_SIZES: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = true)]
 pub enum VelocityEstimate {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     HorizontalVelocity(HorizontalVelocity),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     HorizontalWithVerticalVelocity(HorizontalWithVerticalVelocity),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     HorizontalVelocityWithUncertainty(HorizontalVelocityWithUncertainty),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     HorizontalWithVeritcalVelocityAndUncertainty(HorizontalWithVerticalVelocityAndUncertainty),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct VerticalAccuracyCode(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct VerticalSpeedDirection(pub u8);
 impl VerticalSpeedDirection {
@@ -4525,14 +5339,16 @@ impl VerticalSpeedDirection {
     pub const DOWNWARD: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct VerticalVelocity {
+    #[asn(tag = "[0] IMPLICIT")]
     pub veritcal_speed: INTEGER_64,
+    #[asn(tag = "[1] IMPLICIT")]
     pub veritcal_speed_direction: VerticalSpeedDirection,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct VoiceSupportMatchIndicator(pub u8);
 impl VoiceSupportMatchIndicator {
@@ -4540,19 +5356,19 @@ impl VoiceSupportMatchIndicator {
     pub const NOT_SUPPORTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "10", sz_ub = "10")]
 pub struct BIT_STRING_2(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_3;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Additional_CSPS_coordination_informationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4563,11 +5379,11 @@ pub struct Additional_CSPS_coordination_informationIE_Extensions(
     pub Vec<Additional_CSPS_coordination_informationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AllocationOrRetentionPriorityIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4578,11 +5394,11 @@ pub struct AllocationOrRetentionPriorityIE_Extensions(
     pub Vec<AllocationOrRetentionPriorityIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Alt_RAB_Parameter_SupportedGuaranteedBitrateInfIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4593,11 +5409,11 @@ pub struct Alt_RAB_Parameter_SupportedGuaranteedBitrateInfIE_Extensions(
     pub Vec<Alt_RAB_Parameter_SupportedGuaranteedBitrateInfIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Alt_RAB_Parameter_SupportedMaxBitrateInfIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4608,7 +5424,7 @@ pub struct Alt_RAB_Parameter_SupportedMaxBitrateInfIE_Extensions(
     pub Vec<Alt_RAB_Parameter_SupportedMaxBitrateInfIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Alt_RAB_ParametersIE_Extensions_EntryExtensionValue {
     #[asn(key = 172)]
@@ -4627,16 +5443,18 @@ pub enum Alt_RAB_ParametersIE_Extensions_EntryExtensionValue {
     Id_AlternativeRABConfiguration(RAB_Parameters),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Alt_RAB_ParametersIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: Alt_RAB_ParametersIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4645,7 +5463,7 @@ pub struct Alt_RAB_ParametersIE_Extensions_Entry {
 )]
 pub struct Alt_RAB_ParametersIE_Extensions(pub Vec<Alt_RAB_ParametersIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Ass_RAB_ParametersIE_Extensions_EntryExtensionValue {
     #[asn(key = 174)]
@@ -4660,16 +5478,18 @@ pub enum Ass_RAB_ParametersIE_Extensions_EntryExtensionValue {
     Id_Ass_RAB_Parameter_SupportedMaxBitrateList(SupportedRAB_ParameterBitrateList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Ass_RAB_ParametersIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: Ass_RAB_ParametersIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4678,11 +5498,11 @@ pub struct Ass_RAB_ParametersIE_Extensions_Entry {
 )]
 pub struct Ass_RAB_ParametersIE_Extensions(pub Vec<Ass_RAB_ParametersIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AuthorisedPLMNs_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4691,45 +5511,50 @@ pub struct AuthorisedPLMNs_EntryIE_Extensions_Entry {}
 )]
 pub struct AuthorisedPLMNs_EntryIE_Extensions(pub Vec<AuthorisedPLMNs_EntryIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct AuthorisedPLMNs_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub authorised_sn_as_list: Option<AuthorisedSNAs>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<AuthorisedPLMNs_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct BIT_STRING_4(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "56", sz_ub = "56")]
 pub struct BIT_STRING_5(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "56", sz_ub = "56")]
 pub struct BIT_STRING_6(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CGIIE_Extensions_EntryExtensionValue {
     #[asn(key = 55)]
     Id_RAC(RAC),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CGIIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: CGIIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4738,7 +5563,7 @@ pub struct CGIIE_Extensions_Entry {
 )]
 pub struct CGIIE_Extensions(pub Vec<CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CN_DeactivateTraceProtocolIEs_EntryValue {
     #[asn(key = 65)]
@@ -4747,16 +5572,18 @@ pub enum CN_DeactivateTraceProtocolIEs_EntryValue {
     Id_TriggerID(TriggerID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CN_DeactivateTraceProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: CN_DeactivateTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4765,11 +5592,11 @@ pub struct CN_DeactivateTraceProtocolIEs_Entry {
 )]
 pub struct CN_DeactivateTraceProtocolIEs(pub Vec<CN_DeactivateTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CN_DeactivateTraceProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4780,7 +5607,7 @@ pub struct CN_DeactivateTraceProtocolExtensions(
     pub Vec<CN_DeactivateTraceProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CN_InvokeTraceProtocolIEs_EntryValue {
     #[asn(key = 19)]
@@ -4795,16 +5622,18 @@ pub enum CN_InvokeTraceProtocolIEs_EntryValue {
     Id_UE_ID(UE_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CN_InvokeTraceProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: CN_InvokeTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4813,7 +5642,7 @@ pub struct CN_InvokeTraceProtocolIEs_Entry {
 )]
 pub struct CN_InvokeTraceProtocolIEs(pub Vec<CN_InvokeTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CN_InvokeTraceProtocolExtensions_EntryExtensionValue {
     #[asn(key = 244)]
@@ -4828,16 +5657,18 @@ pub enum CN_InvokeTraceProtocolExtensions_EntryExtensionValue {
     ),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CN_InvokeTraceProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: CN_InvokeTraceProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4846,11 +5677,11 @@ pub struct CN_InvokeTraceProtocolExtensions_Entry {
 )]
 pub struct CN_InvokeTraceProtocolExtensions(pub Vec<CN_InvokeTraceProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CNMBMSLinkingInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4861,11 +5692,11 @@ pub struct CNMBMSLinkingInformationIE_Extensions(
     pub Vec<CNMBMSLinkingInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellBasedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4874,11 +5705,11 @@ pub struct CellBasedIE_Extensions_Entry {}
 )]
 pub struct CellBasedIE_Extensions(pub Vec<CellBasedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellLoadInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4887,11 +5718,11 @@ pub struct CellLoadInformationIE_Extensions_Entry {}
 )]
 pub struct CellLoadInformationIE_Extensions(pub Vec<CellLoadInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellLoadInformationGroupIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4902,23 +5733,25 @@ pub struct CellLoadInformationGroupIE_Extensions(
     pub Vec<CellLoadInformationGroupIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CommonIDProtocolIEs_EntryValue {
     #[asn(key = 23)]
     Id_PermanentNAS_UE_ID(PermanentNAS_UE_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CommonIDProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: CommonIDProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4927,7 +5760,7 @@ pub struct CommonIDProtocolIEs_Entry {
 )]
 pub struct CommonIDProtocolIEs(pub Vec<CommonIDProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CommonIDProtocolExtensions_EntryExtensionValue {
     #[asn(key = 234)]
@@ -4954,16 +5787,18 @@ pub enum CommonIDProtocolExtensions_EntryExtensionValue {
     Id_UESBI_Iu(UESBI_Iu),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CommonIDProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: CommonIDProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4972,11 +5807,11 @@ pub struct CommonIDProtocolExtensions_Entry {
 )]
 pub struct CommonIDProtocolExtensions(pub Vec<CommonIDProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4985,7 +5820,7 @@ pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 )]
 pub struct CriticalityDiagnosticsIE_Extensions(pub Vec<CriticalityDiagnosticsIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CriticalityDiagnostics_IE_List_EntryIE_Extensions_EntryExtensionValue {
     #[asn(key = 88)]
@@ -4994,16 +5829,18 @@ pub enum CriticalityDiagnostics_IE_List_EntryIE_Extensions_EntryExtensionValue {
     Id_TypeOfError(TypeOfError),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnostics_IE_List_EntryIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: CriticalityDiagnostics_IE_List_EntryIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5014,22 +5851,26 @@ pub struct CriticalityDiagnostics_IE_List_EntryIE_Extensions(
     pub Vec<CriticalityDiagnostics_IE_List_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct CriticalityDiagnostics_IE_List_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ie_criticality: Criticality,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ie_id: ProtocolIE_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub repetition_number: Option<RepetitionNumber0>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<CriticalityDiagnostics_IE_List_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataVolumeList_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5038,17 +5879,20 @@ pub struct DataVolumeList_EntryIE_Extensions_Entry {}
 )]
 pub struct DataVolumeList_EntryIE_Extensions(pub Vec<DataVolumeList_EntryIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct DataVolumeList_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_unsuccessfully_transmitted_data_volume: UnsuccessfullyTransmittedDataVolume,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub data_volume_reference: Option<DataVolumeReference>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<DataVolumeList_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DataVolumeReportProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -5059,16 +5903,18 @@ pub enum DataVolumeReportProtocolIEs_EntryValue {
     Id_RAB_FailedtoReportList(RAB_FailedtoReportList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataVolumeReportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DataVolumeReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5077,11 +5923,11 @@ pub struct DataVolumeReportProtocolIEs_Entry {
 )]
 pub struct DataVolumeReportProtocolIEs(pub Vec<DataVolumeReportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataVolumeReportProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5090,23 +5936,25 @@ pub struct DataVolumeReportProtocolExtensions_Entry {}
 )]
 pub struct DataVolumeReportProtocolExtensions(pub Vec<DataVolumeReportProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DataVolumeReportRequestProtocolIEs_EntryValue {
     #[asn(key = 33)]
     Id_RAB_DataVolumeReportRequestList(RAB_DataVolumeReportRequestList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataVolumeReportRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DataVolumeReportRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5115,11 +5963,11 @@ pub struct DataVolumeReportRequestProtocolIEs_Entry {
 )]
 pub struct DataVolumeReportRequestProtocolIEs(pub Vec<DataVolumeReportRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DataVolumeReportRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5130,7 +5978,7 @@ pub struct DataVolumeReportRequestProtocolExtensions(
     pub Vec<DataVolumeReportRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DeltaRAListofIdleModeUEsIE_Extensions_EntryExtensionValue {
     #[asn(key = 182)]
@@ -5139,16 +5987,18 @@ pub enum DeltaRAListofIdleModeUEsIE_Extensions_EntryExtensionValue {
     Id_newLAListofIdleModeUEs(LAListofIdleModeUEs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DeltaRAListofIdleModeUEsIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: DeltaRAListofIdleModeUEsIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5159,7 +6009,7 @@ pub struct DeltaRAListofIdleModeUEsIE_Extensions(
     pub Vec<DeltaRAListofIdleModeUEsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DirectInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -5172,16 +6022,18 @@ pub enum DirectInformationTransferProtocolIEs_EntryValue {
     Id_InterSystemInformationTransferType(InterSystemInformationTransferType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DirectInformationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DirectInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5192,23 +6044,25 @@ pub struct DirectInformationTransferProtocolIEs(
     pub Vec<DirectInformationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DirectInformationTransferProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DirectInformationTransferProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: DirectInformationTransferProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5219,7 +6073,7 @@ pub struct DirectInformationTransferProtocolExtensions(
     pub Vec<DirectInformationTransferProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DirectTransferProtocolIEs_EntryValue {
     #[asn(key = 15)]
@@ -5234,16 +6088,18 @@ pub enum DirectTransferProtocolIEs_EntryValue {
     Id_SAPI(SAPI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DirectTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DirectTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5252,7 +6108,7 @@ pub struct DirectTransferProtocolIEs_Entry {
 )]
 pub struct DirectTransferProtocolIEs(pub Vec<DirectTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DirectTransferProtocolExtensions_EntryExtensionValue {
     #[asn(key = 241)]
@@ -5269,16 +6125,18 @@ pub enum DirectTransferProtocolExtensions_EntryExtensionValue {
     Id_SubscriberProfileIDforRFP(SubscriberProfileIDforRFP),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DirectTransferProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: DirectTransferProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5287,11 +6145,11 @@ pub struct DirectTransferProtocolExtensions_Entry {
 )]
 pub struct DirectTransferProtocolExtensions(pub Vec<DirectTransferProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DirectTransferInformationItem_RANAP_RelocInfIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5302,23 +6160,25 @@ pub struct DirectTransferInformationItem_RANAP_RelocInfIE_Extensions(
     pub Vec<DirectTransferInformationItem_RANAP_RelocInfIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DirectTransferInformationList_RANAP_RelocInf_Entry_EntryValue {
     #[asn(key = 80)]
     Id_DirectTransferInformationItem_RANAP_RelocInf(DirectTransferInformationItem_RANAP_RelocInf),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DirectTransferInformationList_RANAP_RelocInf_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DirectTransferInformationList_RANAP_RelocInf_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5329,43 +6189,45 @@ pub struct DirectTransferInformationList_RANAP_RelocInf_Entry(
     pub Vec<DirectTransferInformationList_RANAP_RelocInf_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "20", sz_ub = "20")]
 pub struct BIT_STRING_7(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "28", sz_ub = "28")]
 pub struct BIT_STRING_8(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "18", sz_ub = "18")]
 pub struct BIT_STRING_9(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "21", sz_ub = "21")]
 pub struct BIT_STRING_10(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct INTEGER_11(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum EUTRANFrequencies_EntryIE_Extensions_EntryExtensionValue {
     #[asn(key = 271)]
     Id_EARFCN_Extended(EARFCN_Extended),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EUTRANFrequencies_EntryIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: EUTRANFrequencies_EntryIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5376,21 +6238,22 @@ pub struct EUTRANFrequencies_EntryIE_Extensions(
     pub Vec<EUTRANFrequencies_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 2)]
 pub struct EUTRANFrequencies_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub earfcn: INTEGER_11,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub meas_band: Option<MeasBand>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<EUTRANFrequencies_EntryIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EncryptionInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5399,23 +6262,25 @@ pub struct EncryptionInformationIE_Extensions_Entry {}
 )]
 pub struct EncryptionInformationIE_Extensions(pub Vec<EncryptionInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum EnhancedRelocationCompleteConfirmProtocolIEs_EntryValue {
     #[asn(key = 35)]
     Id_RAB_FailedList(RAB_FailedList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteConfirmProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: EnhancedRelocationCompleteConfirmProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5426,11 +6291,11 @@ pub struct EnhancedRelocationCompleteConfirmProtocolIEs(
     pub Vec<EnhancedRelocationCompleteConfirmProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteConfirmProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5441,7 +6306,7 @@ pub struct EnhancedRelocationCompleteConfirmProtocolExtensions(
     pub Vec<EnhancedRelocationCompleteConfirmProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum EnhancedRelocationCompleteFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -5450,16 +6315,18 @@ pub enum EnhancedRelocationCompleteFailureProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: EnhancedRelocationCompleteFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5470,11 +6337,11 @@ pub struct EnhancedRelocationCompleteFailureProtocolIEs(
     pub Vec<EnhancedRelocationCompleteFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteFailureProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5485,7 +6352,7 @@ pub struct EnhancedRelocationCompleteFailureProtocolExtensions(
     pub Vec<EnhancedRelocationCompleteFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum EnhancedRelocationCompleteRequestProtocolIEs_EntryValue {
     #[asn(key = 79)]
@@ -5504,16 +6371,18 @@ pub enum EnhancedRelocationCompleteRequestProtocolIEs_EntryValue {
     Id_Relocation_TargetRNC_ID(GlobalRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: EnhancedRelocationCompleteRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5524,7 +6393,7 @@ pub struct EnhancedRelocationCompleteRequestProtocolIEs(
     pub Vec<EnhancedRelocationCompleteRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum EnhancedRelocationCompleteRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 203)]
@@ -5543,16 +6412,18 @@ pub enum EnhancedRelocationCompleteRequestProtocolExtensions_EntryExtensionValue
     Id_Tunnel_Information_for_BBF(TunnelInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: EnhancedRelocationCompleteRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5563,7 +6434,7 @@ pub struct EnhancedRelocationCompleteRequestProtocolExtensions(
     pub Vec<EnhancedRelocationCompleteRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum EnhancedRelocationCompleteResponseProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -5574,16 +6445,18 @@ pub enum EnhancedRelocationCompleteResponseProtocolIEs_EntryValue {
     Id_RAB_ToBeReleasedList_EnhancedRelocCompleteRes(RAB_ToBeReleasedList_EnhancedRelocCompleteRes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: EnhancedRelocationCompleteResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5594,7 +6467,7 @@ pub struct EnhancedRelocationCompleteResponseProtocolIEs(
     pub Vec<EnhancedRelocationCompleteResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum EnhancedRelocationCompleteResponseProtocolExtensions_EntryExtensionValue {
     #[asn(key = 234)]
@@ -5605,16 +6478,18 @@ pub enum EnhancedRelocationCompleteResponseProtocolExtensions_EntryExtensionValu
     Id_UE_AggregateMaximumBitRate(UE_AggregateMaximumBitRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EnhancedRelocationCompleteResponseProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: EnhancedRelocationCompleteResponseProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5625,7 +6500,7 @@ pub struct EnhancedRelocationCompleteResponseProtocolExtensions(
     pub Vec<EnhancedRelocationCompleteResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ErrorIndicationProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -5638,16 +6513,18 @@ pub enum ErrorIndicationProtocolIEs_EntryValue {
     Id_GlobalRNC_ID(GlobalRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ErrorIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ErrorIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5656,7 +6533,7 @@ pub struct ErrorIndicationProtocolIEs_Entry {
 )]
 pub struct ErrorIndicationProtocolIEs(pub Vec<ErrorIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ErrorIndicationProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
@@ -5665,16 +6542,18 @@ pub enum ErrorIndicationProtocolExtensions_EntryExtensionValue {
     Id_GlobalCN_ID(GlobalCN_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ErrorIndicationProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ErrorIndicationProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5683,31 +6562,33 @@ pub struct ErrorIndicationProtocolExtensions_Entry {
 )]
 pub struct ErrorIndicationProtocolExtensions(pub Vec<ErrorIndicationProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "-120", ub = "165")]
-pub struct INTEGER_12(pub i8);
+pub struct INTEGER_12(pub i16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "-120", ub = "-25")]
 pub struct INTEGER_13(pub i8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ForwardSRNS_ContextProtocolIEs_EntryValue {
     #[asn(key = 25)]
     Id_RAB_ContextList(RAB_ContextList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ForwardSRNS_ContextProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ForwardSRNS_ContextProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5716,23 +6597,25 @@ pub struct ForwardSRNS_ContextProtocolIEs_Entry {
 )]
 pub struct ForwardSRNS_ContextProtocolIEs(pub Vec<ForwardSRNS_ContextProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ForwardSRNS_ContextProtocolExtensions_EntryExtensionValue {
     #[asn(key = 103)]
     Id_SourceRNC_PDCP_context_info(RRC_Container),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ForwardSRNS_ContextProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ForwardSRNS_ContextProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5743,7 +6626,7 @@ pub struct ForwardSRNS_ContextProtocolExtensions(
     pub Vec<ForwardSRNS_ContextProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct ENUMERATED_14(pub u8);
 impl ENUMERATED_14 {
@@ -5751,35 +6634,35 @@ impl ENUMERATED_14 {
     pub const DEPTH: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "32767")]
 pub struct INTEGER_15(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct INTEGER_16(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_17(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "179")]
 pub struct INTEGER_18(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "179")]
 pub struct INTEGER_19(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_20(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GA_EllipsoidArcIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5788,11 +6671,11 @@ pub struct GA_EllipsoidArcIE_Extensions_Entry {}
 )]
 pub struct GA_EllipsoidArcIE_Extensions(pub Vec<GA_EllipsoidArcIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GA_PointIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5801,11 +6684,11 @@ pub struct GA_PointIE_Extensions_Entry {}
 )]
 pub struct GA_PointIE_Extensions(pub Vec<GA_PointIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GA_PointWithAltitudeIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5814,19 +6697,19 @@ pub struct GA_PointWithAltitudeIE_Extensions_Entry {}
 )]
 pub struct GA_PointWithAltitudeIE_Extensions(pub Vec<GA_PointWithAltitudeIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_21(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_22(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GA_PointWithAltitudeAndUncertaintyEllipsoidIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5837,11 +6720,11 @@ pub struct GA_PointWithAltitudeAndUncertaintyEllipsoidIE_Extensions(
     pub Vec<GA_PointWithAltitudeAndUncertaintyEllipsoidIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GA_PointWithUnCertaintyIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5852,19 +6735,19 @@ pub struct GA_PointWithUnCertaintyIE_Extensions(
     pub Vec<GA_PointWithUnCertaintyIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_23(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_24(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GA_PointWithUnCertaintyEllipseIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5875,11 +6758,11 @@ pub struct GA_PointWithUnCertaintyEllipseIE_Extensions(
     pub Vec<GA_PointWithUnCertaintyEllipseIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GA_Polygon_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5888,31 +6771,34 @@ pub struct GA_Polygon_EntryIE_Extensions_Entry {}
 )]
 pub struct GA_Polygon_EntryIE_Extensions(pub Vec<GA_Polygon_EntryIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GA_Polygon_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub geographical_coordinates: GeographicalCoordinates,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GA_Polygon_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_25(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_26(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "179")]
 pub struct INTEGER_27(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GERAN_Cell_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5921,11 +6807,11 @@ pub struct GERAN_Cell_IDIE_Extensions_Entry {}
 )]
 pub struct GERAN_Cell_IDIE_Extensions(pub Vec<GERAN_Cell_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GERAN_Iumode_RAB_Failed_RABAssgntResponse_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5936,7 +6822,7 @@ pub struct GERAN_Iumode_RAB_Failed_RABAssgntResponse_ItemIE_Extensions(
     pub Vec<GERAN_Iumode_RAB_Failed_RABAssgntResponse_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum GERAN_Iumode_RAB_FailedList_RABAssgntResponse_Entry_EntryValue {
     #[asn(key = 109)]
@@ -5945,16 +6831,18 @@ pub enum GERAN_Iumode_RAB_FailedList_RABAssgntResponse_Entry_EntryValue {
     ),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GERAN_Iumode_RAB_FailedList_RABAssgntResponse_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: GERAN_Iumode_RAB_FailedList_RABAssgntResponse_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5965,7 +6853,7 @@ pub struct GERAN_Iumode_RAB_FailedList_RABAssgntResponse_Entry(
     pub Vec<GERAN_Iumode_RAB_FailedList_RABAssgntResponse_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct ENUMERATED_28(pub u8);
 impl ENUMERATED_28 {
@@ -5973,19 +6861,19 @@ impl ENUMERATED_28 {
     pub const SOUTH: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "8388607")]
 pub struct INTEGER_29(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "-8388608", ub = "8388607")]
 pub struct INTEGER_30(pub i32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GeographicalCoordinatesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5996,19 +6884,19 @@ pub struct GeographicalCoordinatesIE_Extensions(
     pub Vec<GeographicalCoordinatesIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "359")]
 pub struct INTEGER_31(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "2047")]
 pub struct INTEGER_32(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HorizontalVelocityIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6017,15 +6905,15 @@ pub struct HorizontalVelocityIE_Extensions_Entry {}
 )]
 pub struct HorizontalVelocityIE_Extensions(pub Vec<HorizontalVelocityIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct INTEGER_33(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HorizontalVelocityWithUncertaintyIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6036,11 +6924,11 @@ pub struct HorizontalVelocityWithUncertaintyIE_Extensions(
     pub Vec<HorizontalVelocityWithUncertaintyIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HorizontalWithVerticalVelocityIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6051,19 +6939,19 @@ pub struct HorizontalWithVerticalVelocityIE_Extensions(
     pub Vec<HorizontalWithVerticalVelocityIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct INTEGER_34(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct INTEGER_35(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HorizontalWithVerticalVelocityAndUncertaintyIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6074,15 +6962,15 @@ pub struct HorizontalWithVerticalVelocityAndUncertaintyIE_Extensions(
     pub Vec<HorizontalWithVerticalVelocityAndUncertaintyIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "7", sz_ub = "7")]
 pub struct BIT_STRING_36(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IMEIGroupIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6091,15 +6979,15 @@ pub struct IMEIGroupIE_Extensions_Entry {}
 )]
 pub struct IMEIGroupIE_Extensions(pub Vec<IMEIGroupIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "7", sz_ub = "7")]
 pub struct BIT_STRING_37(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IMEISVGroupIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6108,15 +6996,15 @@ pub struct IMEISVGroupIE_Extensions_Entry {}
 )]
 pub struct IMEISVGroupIE_Extensions(pub Vec<IMEISVGroupIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "97")]
 pub struct INTEGER_38(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "34")]
 pub struct INTEGER_39(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum IRAT_Measurement_ConfigurationIE_Extensions_EntryExtensionValue {
     #[asn(key = 279)]
@@ -6125,16 +7013,18 @@ pub enum IRAT_Measurement_ConfigurationIE_Extensions_EntryExtensionValue {
     Id_RSRQ_Type(RSRQ_Type),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IRAT_Measurement_ConfigurationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: IRAT_Measurement_ConfigurationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6145,15 +7035,15 @@ pub struct IRAT_Measurement_ConfigurationIE_Extensions(
     pub Vec<IRAT_Measurement_ConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "100")]
 pub struct INTEGER_40(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IRATmeasurementParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6164,7 +7054,7 @@ pub struct IRATmeasurementParametersIE_Extensions(
     pub Vec<IRATmeasurementParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ImmediateMDTIE_Extensions_EntryExtensionValue {
     #[asn(key = 265)]
@@ -6177,16 +7067,18 @@ pub enum ImmediateMDTIE_Extensions_EntryExtensionValue {
     Id_M7Report(M7Report),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ImmediateMDTIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ImmediateMDTIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6195,7 +7087,7 @@ pub struct ImmediateMDTIE_Extensions_Entry {
 )]
 pub struct ImmediateMDTIE_Extensions(pub Vec<ImmediateMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InformationTransferConfirmationProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -6208,16 +7100,18 @@ pub enum InformationTransferConfirmationProtocolIEs_EntryValue {
     Id_InformationTransferID(InformationTransferID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InformationTransferConfirmationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InformationTransferConfirmationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6228,23 +7122,25 @@ pub struct InformationTransferConfirmationProtocolIEs(
     pub Vec<InformationTransferConfirmationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InformationTransferConfirmationProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InformationTransferConfirmationProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: InformationTransferConfirmationProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6255,7 +7151,7 @@ pub struct InformationTransferConfirmationProtocolExtensions(
     pub Vec<InformationTransferConfirmationProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InformationTransferFailureProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -6270,16 +7166,18 @@ pub enum InformationTransferFailureProtocolIEs_EntryValue {
     Id_InformationTransferID(InformationTransferID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InformationTransferFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InformationTransferFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6290,23 +7188,25 @@ pub struct InformationTransferFailureProtocolIEs(
     pub Vec<InformationTransferFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InformationTransferFailureProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InformationTransferFailureProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: InformationTransferFailureProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6317,7 +7217,7 @@ pub struct InformationTransferFailureProtocolExtensions(
     pub Vec<InformationTransferFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InformationTransferIndicationProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -6330,16 +7230,18 @@ pub enum InformationTransferIndicationProtocolIEs_EntryValue {
     Id_ProvidedData(ProvidedData),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InformationTransferIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InformationTransferIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6350,11 +7252,11 @@ pub struct InformationTransferIndicationProtocolIEs(
     pub Vec<InformationTransferIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InformationTransferIndicationProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6365,7 +7267,7 @@ pub struct InformationTransferIndicationProtocolExtensions(
     pub Vec<InformationTransferIndicationProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialUE_MessageProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -6384,16 +7286,18 @@ pub enum InitialUE_MessageProtocolIEs_EntryValue {
     Id_SAI(SAI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialUE_MessageProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialUE_MessageProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6402,7 +7306,7 @@ pub struct InitialUE_MessageProtocolIEs_Entry {
 )]
 pub struct InitialUE_MessageProtocolIEs(pub Vec<InitialUE_MessageProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialUE_MessageProtocolExtensions_EntryExtensionValue {
     #[asn(key = 203)]
@@ -6441,16 +7345,18 @@ pub enum InitialUE_MessageProtocolExtensions_EntryExtensionValue {
     Id_UE_Usage_Type(UE_Usage_Type),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialUE_MessageProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: InitialUE_MessageProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6459,7 +7365,7 @@ pub struct InitialUE_MessageProtocolExtensions_Entry {
 )]
 pub struct InitialUE_MessageProtocolExtensions(pub Vec<InitialUE_MessageProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitiatingMessageValue {
     #[asn(key = 26)]
@@ -6562,11 +7468,11 @@ pub enum InitiatingMessageValue {
     Id_privateMessage(PrivateMessage),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntegrityProtectionInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6577,11 +7483,11 @@ pub struct IntegrityProtectionInformationIE_Extensions(
     pub Vec<IntegrityProtectionInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemInformation_TransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6592,7 +7498,7 @@ pub struct InterSystemInformation_TransparentContainerIE_Extensions(
     pub Vec<InterSystemInformation_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct ENUMERATED_41(pub u8);
 impl ENUMERATED_41 {
@@ -6603,11 +7509,11 @@ impl ENUMERATED_41 {
     pub const UU: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterfacesToTraceItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6616,23 +7522,25 @@ pub struct InterfacesToTraceItemIE_Extensions_Entry {}
 )]
 pub struct InterfacesToTraceItemIE_Extensions(pub Vec<InterfacesToTraceItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Iu_ReleaseCommandProtocolIEs_EntryValue {
     #[asn(key = 4)]
     Id_Cause(Cause),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Iu_ReleaseCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: Iu_ReleaseCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6641,7 +7549,7 @@ pub struct Iu_ReleaseCommandProtocolIEs_Entry {
 )]
 pub struct Iu_ReleaseCommandProtocolIEs(pub Vec<Iu_ReleaseCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Iu_ReleaseCommandProtocolExtensions_EntryExtensionValue {
     #[asn(key = 252)]
@@ -6652,16 +7560,18 @@ pub enum Iu_ReleaseCommandProtocolExtensions_EntryExtensionValue {
     Id_Out_Of_UTRAN(Out_Of_UTRAN),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Iu_ReleaseCommandProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: Iu_ReleaseCommandProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6670,7 +7580,7 @@ pub struct Iu_ReleaseCommandProtocolExtensions_Entry {
 )]
 pub struct Iu_ReleaseCommandProtocolExtensions(pub Vec<Iu_ReleaseCommandProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Iu_ReleaseCompleteProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -6681,16 +7591,18 @@ pub enum Iu_ReleaseCompleteProtocolIEs_EntryValue {
     Id_RAB_ReleasedList_IuRelComp(RAB_ReleasedList_IuRelComp),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Iu_ReleaseCompleteProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: Iu_ReleaseCompleteProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6699,11 +7611,11 @@ pub struct Iu_ReleaseCompleteProtocolIEs_Entry {
 )]
 pub struct Iu_ReleaseCompleteProtocolIEs(pub Vec<Iu_ReleaseCompleteProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Iu_ReleaseCompleteProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6714,23 +7626,25 @@ pub struct Iu_ReleaseCompleteProtocolExtensions(
     pub Vec<Iu_ReleaseCompleteProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Iu_ReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 4)]
     Id_Cause(Cause),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Iu_ReleaseRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: Iu_ReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6739,11 +7653,11 @@ pub struct Iu_ReleaseRequestProtocolIEs_Entry {
 )]
 pub struct Iu_ReleaseRequestProtocolIEs(pub Vec<Iu_ReleaseRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Iu_ReleaseRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6752,11 +7666,11 @@ pub struct Iu_ReleaseRequestProtocolExtensions_Entry {}
 )]
 pub struct Iu_ReleaseRequestProtocolExtensions(pub Vec<Iu_ReleaseRequestProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct JoinedMBMSBearerService_IEs_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6767,20 +7681,24 @@ pub struct JoinedMBMSBearerService_IEs_EntryIE_Extensions(
     pub Vec<JoinedMBMSBearerService_IEs_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct JoinedMBMSBearerService_IEs_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tmgi: TMGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub mbms_ptp_rab_id: MBMS_PTP_RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<JoinedMBMSBearerService_IEs_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LA_LIST_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6789,20 +7707,24 @@ pub struct LA_LIST_EntryIE_Extensions_Entry {}
 )]
 pub struct LA_LIST_EntryIE_Extensions(pub Vec<LA_LIST_EntryIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LA_LIST_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lac: LAC,
+    #[asn(tag = "[1] IMPLICIT")]
     pub list_of_sn_as: ListOF_SNAs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LA_LIST_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LABasedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6811,11 +7733,11 @@ pub struct LABasedIE_Extensions_Entry {}
 )]
 pub struct LABasedIE_Extensions(pub Vec<LABasedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6824,15 +7746,15 @@ pub struct LAIIE_Extensions_Entry {}
 )]
 pub struct LAIIE_Extensions(pub Vec<LAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "32767")]
 pub struct INTEGER_42(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastKnownServiceAreaIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6841,7 +7763,7 @@ pub struct LastKnownServiceAreaIE_Extensions_Entry {}
 )]
 pub struct LastKnownServiceAreaIE_Extensions(pub Vec<LastKnownServiceAreaIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LastVisitedUTRANCell_ItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 257)]
@@ -6850,16 +7772,18 @@ pub enum LastVisitedUTRANCell_ItemIE_Extensions_EntryExtensionValue {
     Id_Time_UE_StayedInCell_EnhancedGranularity(Time_UE_StayedInCell_EnhancedGranularity),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastVisitedUTRANCell_ItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LastVisitedUTRANCell_ItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6870,11 +7794,11 @@ pub struct LastVisitedUTRANCell_ItemIE_Extensions(
     pub Vec<LastVisitedUTRANCell_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LeftMBMSBearerService_IEs_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6885,31 +7809,36 @@ pub struct LeftMBMSBearerService_IEs_EntryIE_Extensions(
     pub Vec<LeftMBMSBearerService_IEs_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LeftMBMSBearerService_IEs_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tmgi: TMGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LeftMBMSBearerService_IEs_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationRelatedDataFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
     Id_Cause(Cause),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationRelatedDataFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationRelatedDataFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6920,23 +7849,25 @@ pub struct LocationRelatedDataFailureProtocolIEs(
     pub Vec<LocationRelatedDataFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationRelatedDataFailureProtocolExtensions_EntryExtensionValue {
     #[asn(key = 9)]
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationRelatedDataFailureProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LocationRelatedDataFailureProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6947,23 +7878,25 @@ pub struct LocationRelatedDataFailureProtocolExtensions(
     pub Vec<LocationRelatedDataFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationRelatedDataRequestProtocolIEs_EntryValue {
     #[asn(key = 95)]
     Id_LocationRelatedDataRequestType(LocationRelatedDataRequestType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationRelatedDataRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationRelatedDataRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6974,7 +7907,7 @@ pub struct LocationRelatedDataRequestProtocolIEs(
     pub Vec<LocationRelatedDataRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationRelatedDataRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 115)]
@@ -6985,16 +7918,18 @@ pub enum LocationRelatedDataRequestProtocolExtensions_EntryExtensionValue {
     Id_RequestedGANSSAssistanceData(RequestedGANSSAssistanceData),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationRelatedDataRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LocationRelatedDataRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7005,23 +7940,25 @@ pub struct LocationRelatedDataRequestProtocolExtensions(
     pub Vec<LocationRelatedDataRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationRelatedDataResponseProtocolIEs_EntryValue {
     #[asn(key = 94)]
     Id_BroadcastAssistanceDataDecipheringKeys(BroadcastAssistanceDataDecipheringKeys),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationRelatedDataResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationRelatedDataResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7032,7 +7969,7 @@ pub struct LocationRelatedDataResponseProtocolIEs(
     pub Vec<LocationRelatedDataResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationRelatedDataResponseProtocolExtensions_EntryExtensionValue {
     #[asn(key = 186)]
@@ -7041,16 +7978,18 @@ pub enum LocationRelatedDataResponseProtocolExtensions_EntryExtensionValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationRelatedDataResponseProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LocationRelatedDataResponseProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7061,7 +8000,7 @@ pub struct LocationRelatedDataResponseProtocolExtensions(
     pub Vec<LocationRelatedDataResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -7072,16 +8011,18 @@ pub enum LocationReportProtocolIEs_EntryValue {
     Id_RequestType(RequestType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7090,7 +8031,7 @@ pub struct LocationReportProtocolIEs_Entry {
 )]
 pub struct LocationReportProtocolIEs(pub Vec<LocationReportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportProtocolExtensions_EntryExtensionValue {
     #[asn(key = 122)]
@@ -7109,16 +8050,18 @@ pub enum LocationReportProtocolExtensions_EntryExtensionValue {
     Id_VelocityEstimate(VelocityEstimate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LocationReportProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7127,23 +8070,25 @@ pub struct LocationReportProtocolExtensions_Entry {
 )]
 pub struct LocationReportProtocolExtensions(pub Vec<LocationReportProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingControlProtocolIEs_EntryValue {
     #[asn(key = 57)]
     Id_RequestType(RequestType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingControlProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportingControlProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7152,7 +8097,7 @@ pub struct LocationReportingControlProtocolIEs_Entry {
 )]
 pub struct LocationReportingControlProtocolIEs(pub Vec<LocationReportingControlProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingControlProtocolExtensions_EntryExtensionValue {
     #[asn(key = 114)]
@@ -7169,16 +8114,18 @@ pub enum LocationReportingControlProtocolExtensions_EntryExtensionValue {
     Id_VerticalAccuracyCode(VerticalAccuracyCode),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingControlProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LocationReportingControlProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7189,11 +8136,11 @@ pub struct LocationReportingControlProtocolExtensions(
     pub Vec<LocationReportingControlProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingTransferInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7204,11 +8151,11 @@ pub struct LocationReportingTransferInformationIE_Extensions(
     pub Vec<LocationReportingTransferInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LoggedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7217,11 +8164,11 @@ pub struct LoggedMDTIE_Extensions_Entry {}
 )]
 pub struct LoggedMDTIE_Extensions(pub Vec<LoggedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M4_Collection_ParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7232,19 +8179,19 @@ pub struct M4_Collection_ParametersIE_Extensions(
     pub Vec<M4_Collection_ParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_43;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_44;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M6ReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7253,11 +8200,11 @@ pub struct M6ReportIE_Extensions_Entry {}
 )]
 pub struct M6ReportIE_Extensions(pub Vec<M6ReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M7ReportIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7266,7 +8213,7 @@ pub struct M7ReportIE_Extensions_Entry {}
 )]
 pub struct M7ReportIE_Extensions(pub Vec<M7ReportIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSCNDe_RegistrationRequestProtocolIEs_EntryValue {
     #[asn(key = 96)]
@@ -7275,16 +8222,18 @@ pub enum MBMSCNDe_RegistrationRequestProtocolIEs_EntryValue {
     Id_TMGI(TMGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSCNDe_RegistrationRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSCNDe_RegistrationRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7295,11 +8244,11 @@ pub struct MBMSCNDe_RegistrationRequestProtocolIEs(
     pub Vec<MBMSCNDe_RegistrationRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSCNDe_RegistrationRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7310,7 +8259,7 @@ pub struct MBMSCNDe_RegistrationRequestProtocolExtensions(
     pub Vec<MBMSCNDe_RegistrationRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSCNDe_RegistrationResponseProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7323,16 +8272,18 @@ pub enum MBMSCNDe_RegistrationResponseProtocolIEs_EntryValue {
     Id_TMGI(TMGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSCNDe_RegistrationResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSCNDe_RegistrationResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7343,23 +8294,25 @@ pub struct MBMSCNDe_RegistrationResponseProtocolIEs(
     pub Vec<MBMSCNDe_RegistrationResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSCNDe_RegistrationResponseProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSCNDe_RegistrationResponseProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: MBMSCNDe_RegistrationResponseProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7370,11 +8323,11 @@ pub struct MBMSCNDe_RegistrationResponseProtocolExtensions(
     pub Vec<MBMSCNDe_RegistrationResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSIPMulticastAddressandAPNlistIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7385,23 +8338,25 @@ pub struct MBMSIPMulticastAddressandAPNlistIE_Extensions(
     pub Vec<MBMSIPMulticastAddressandAPNlistIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRABEstablishmentIndicationProtocolIEs_EntryValue {
     #[asn(key = 154)]
     Id_TransportLayerInformation(TransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABEstablishmentIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSRABEstablishmentIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7412,11 +8367,11 @@ pub struct MBMSRABEstablishmentIndicationProtocolIEs(
     pub Vec<MBMSRABEstablishmentIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABEstablishmentIndicationProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7427,7 +8382,7 @@ pub struct MBMSRABEstablishmentIndicationProtocolExtensions(
     pub Vec<MBMSRABEstablishmentIndicationProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRABReleaseProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7436,16 +8391,18 @@ pub enum MBMSRABReleaseProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABReleaseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSRABReleaseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7454,11 +8411,11 @@ pub struct MBMSRABReleaseProtocolIEs_Entry {
 )]
 pub struct MBMSRABReleaseProtocolIEs(pub Vec<MBMSRABReleaseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABReleaseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7467,7 +8424,7 @@ pub struct MBMSRABReleaseProtocolExtensions_Entry {}
 )]
 pub struct MBMSRABReleaseProtocolExtensions(pub Vec<MBMSRABReleaseProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRABReleaseFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7476,16 +8433,18 @@ pub enum MBMSRABReleaseFailureProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABReleaseFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSRABReleaseFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7494,11 +8453,11 @@ pub struct MBMSRABReleaseFailureProtocolIEs_Entry {
 )]
 pub struct MBMSRABReleaseFailureProtocolIEs(pub Vec<MBMSRABReleaseFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABReleaseFailureProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7509,23 +8468,25 @@ pub struct MBMSRABReleaseFailureProtocolExtensions(
     pub Vec<MBMSRABReleaseFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRABReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 4)]
     Id_Cause(Cause),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABReleaseRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSRABReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7534,11 +8495,11 @@ pub struct MBMSRABReleaseRequestProtocolIEs_Entry {
 )]
 pub struct MBMSRABReleaseRequestProtocolIEs(pub Vec<MBMSRABReleaseRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRABReleaseRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7549,7 +8510,7 @@ pub struct MBMSRABReleaseRequestProtocolExtensions(
     pub Vec<MBMSRABReleaseRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRegistrationFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7562,16 +8523,18 @@ pub enum MBMSRegistrationFailureProtocolIEs_EntryValue {
     Id_TMGI(TMGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRegistrationFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSRegistrationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7580,11 +8543,11 @@ pub struct MBMSRegistrationFailureProtocolIEs_Entry {
 )]
 pub struct MBMSRegistrationFailureProtocolIEs(pub Vec<MBMSRegistrationFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRegistrationFailureProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7595,7 +8558,7 @@ pub struct MBMSRegistrationFailureProtocolExtensions(
     pub Vec<MBMSRegistrationFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRegistrationRequestProtocolIEs_EntryValue {
     #[asn(key = 132)]
@@ -7610,16 +8573,18 @@ pub enum MBMSRegistrationRequestProtocolIEs_EntryValue {
     Id_TMGI(TMGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRegistrationRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSRegistrationRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7628,23 +8593,25 @@ pub struct MBMSRegistrationRequestProtocolIEs_Entry {
 )]
 pub struct MBMSRegistrationRequestProtocolIEs(pub Vec<MBMSRegistrationRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRegistrationRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRegistrationRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: MBMSRegistrationRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7655,7 +8622,7 @@ pub struct MBMSRegistrationRequestProtocolExtensions(
     pub Vec<MBMSRegistrationRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSRegistrationResponseProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -7666,16 +8633,18 @@ pub enum MBMSRegistrationResponseProtocolIEs_EntryValue {
     Id_TMGI(TMGI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRegistrationResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSRegistrationResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7684,11 +8653,11 @@ pub struct MBMSRegistrationResponseProtocolIEs_Entry {
 )]
 pub struct MBMSRegistrationResponseProtocolIEs(pub Vec<MBMSRegistrationResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSRegistrationResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7699,7 +8668,7 @@ pub struct MBMSRegistrationResponseProtocolExtensions(
     pub Vec<MBMSRegistrationResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionStartProtocolIEs_EntryValue {
     #[asn(key = 135)]
@@ -7730,16 +8699,18 @@ pub enum MBMSSessionStartProtocolIEs_EntryValue {
     Id_TimeToMBMSDataTransfer(TimeToMBMSDataTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStartProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionStartProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7748,7 +8719,7 @@ pub struct MBMSSessionStartProtocolIEs_Entry {
 )]
 pub struct MBMSSessionStartProtocolIEs(pub Vec<MBMSSessionStartProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionStartProtocolExtensions_EntryExtensionValue {
     #[asn(key = 169)]
@@ -7761,16 +8732,18 @@ pub enum MBMSSessionStartProtocolExtensions_EntryExtensionValue {
     Id_Session_Re_establishment_Indicator(Session_Re_establishment_Indicator),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStartProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: MBMSSessionStartProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7779,7 +8752,7 @@ pub struct MBMSSessionStartProtocolExtensions_Entry {
 )]
 pub struct MBMSSessionStartProtocolExtensions(pub Vec<MBMSSessionStartProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionStartFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7788,16 +8761,18 @@ pub enum MBMSSessionStartFailureProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStartFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionStartFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7806,11 +8781,11 @@ pub struct MBMSSessionStartFailureProtocolIEs_Entry {
 )]
 pub struct MBMSSessionStartFailureProtocolIEs(pub Vec<MBMSSessionStartFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStartFailureProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7821,7 +8796,7 @@ pub struct MBMSSessionStartFailureProtocolExtensions(
     pub Vec<MBMSSessionStartFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionStartResponseProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7832,16 +8807,18 @@ pub enum MBMSSessionStartResponseProtocolIEs_EntryValue {
     Id_TransportLayerInformation(TransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStartResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionStartResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7850,11 +8827,11 @@ pub struct MBMSSessionStartResponseProtocolIEs_Entry {
 )]
 pub struct MBMSSessionStartResponseProtocolIEs(pub Vec<MBMSSessionStartResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStartResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7865,23 +8842,25 @@ pub struct MBMSSessionStartResponseProtocolExtensions(
     pub Vec<MBMSSessionStartResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionStopProtocolIEs_EntryValue {
     #[asn(key = 144)]
     Id_MBMSCNDe_Registration(MBMSCNDe_Registration),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStopProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionStopProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7890,11 +8869,11 @@ pub struct MBMSSessionStopProtocolIEs_Entry {
 )]
 pub struct MBMSSessionStopProtocolIEs(pub Vec<MBMSSessionStopProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStopProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7903,7 +8882,7 @@ pub struct MBMSSessionStopProtocolExtensions_Entry {}
 )]
 pub struct MBMSSessionStopProtocolExtensions(pub Vec<MBMSSessionStopProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionStopResponseProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7912,16 +8891,18 @@ pub enum MBMSSessionStopResponseProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStopResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionStopResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7930,11 +8911,11 @@ pub struct MBMSSessionStopResponseProtocolIEs_Entry {
 )]
 pub struct MBMSSessionStopResponseProtocolIEs(pub Vec<MBMSSessionStopResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionStopResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7945,7 +8926,7 @@ pub struct MBMSSessionStopResponseProtocolExtensions(
     pub Vec<MBMSSessionStopResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionUpdateProtocolIEs_EntryValue {
     #[asn(key = 134)]
@@ -7954,16 +8935,18 @@ pub enum MBMSSessionUpdateProtocolIEs_EntryValue {
     Id_SessionUpdateID(SessionUpdateID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionUpdateProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionUpdateProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7972,11 +8955,11 @@ pub struct MBMSSessionUpdateProtocolIEs_Entry {
 )]
 pub struct MBMSSessionUpdateProtocolIEs(pub Vec<MBMSSessionUpdateProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionUpdateProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7985,7 +8968,7 @@ pub struct MBMSSessionUpdateProtocolExtensions_Entry {}
 )]
 pub struct MBMSSessionUpdateProtocolExtensions(pub Vec<MBMSSessionUpdateProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionUpdateFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -7996,16 +8979,18 @@ pub enum MBMSSessionUpdateFailureProtocolIEs_EntryValue {
     Id_SessionUpdateID(SessionUpdateID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionUpdateFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionUpdateFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8014,11 +8999,11 @@ pub struct MBMSSessionUpdateFailureProtocolIEs_Entry {
 )]
 pub struct MBMSSessionUpdateFailureProtocolIEs(pub Vec<MBMSSessionUpdateFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionUpdateFailureProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8029,7 +9014,7 @@ pub struct MBMSSessionUpdateFailureProtocolExtensions(
     pub Vec<MBMSSessionUpdateFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSessionUpdateResponseProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -8042,16 +9027,18 @@ pub enum MBMSSessionUpdateResponseProtocolIEs_EntryValue {
     Id_TransportLayerInformation(TransportLayerInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionUpdateResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSSessionUpdateResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8062,11 +9049,11 @@ pub struct MBMSSessionUpdateResponseProtocolIEs(
     pub Vec<MBMSSessionUpdateResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSessionUpdateResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8077,23 +9064,25 @@ pub struct MBMSSessionUpdateResponseProtocolExtensions(
     pub Vec<MBMSSessionUpdateResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSSynchronisationInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 236)]
     Id_IP_Source_Address(IPMulticastAddress),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSSynchronisationInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: MBMSSynchronisationInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8104,7 +9093,7 @@ pub struct MBMSSynchronisationInformationIE_Extensions(
     pub Vec<MBMSSynchronisationInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSUELinkingRequestProtocolIEs_EntryValue {
     #[asn(key = 141)]
@@ -8113,16 +9102,18 @@ pub enum MBMSUELinkingRequestProtocolIEs_EntryValue {
     Id_LeftMBMSBearerServicesList(LeftMBMSBearerService_IEs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSUELinkingRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSUELinkingRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8131,11 +9122,11 @@ pub struct MBMSUELinkingRequestProtocolIEs_Entry {
 )]
 pub struct MBMSUELinkingRequestProtocolIEs(pub Vec<MBMSUELinkingRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSUELinkingRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8146,7 +9137,7 @@ pub struct MBMSUELinkingRequestProtocolExtensions(
     pub Vec<MBMSUELinkingRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MBMSUELinkingResponseProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -8155,16 +9146,18 @@ pub enum MBMSUELinkingResponseProtocolIEs_EntryValue {
     Id_UnsuccessfulLinkingList(UnsuccessfulLinking_IEs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSUELinkingResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MBMSUELinkingResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8173,11 +9166,11 @@ pub struct MBMSUELinkingResponseProtocolIEs_Entry {
 )]
 pub struct MBMSUELinkingResponseProtocolIEs(pub Vec<MBMSUELinkingResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBMSUELinkingResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8188,23 +9181,25 @@ pub struct MBMSUELinkingResponseProtocolExtensions(
     pub Vec<MBMSUELinkingResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MDT_ConfigurationIE_Extensions_EntryExtensionValue {
     #[asn(key = 264)]
     Id_SignallingBasedMDTPLMNList(MDT_PLMN_List),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_ConfigurationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: MDT_ConfigurationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8213,15 +9208,15 @@ pub struct MDT_ConfigurationIE_Extensions_Entry {
 )]
 pub struct MDT_ConfigurationIE_Extensions(pub Vec<MDT_ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_45;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MessageStructure_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8230,33 +9225,38 @@ pub struct MessageStructure_EntryIE_Extensions_Entry {}
 )]
 pub struct MessageStructure_EntryIE_Extensions(pub Vec<MessageStructure_EntryIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct MessageStructure_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ie_id: ProtocolIE_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub repetition_number: Option<RepetitionNumber1>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<MessageStructure_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NotEmptyRAListofIdleModeUEsIE_Extensions_EntryExtensionValue {
     #[asn(key = 180)]
     Id_LAofIdleModeUEs(LAListofIdleModeUEs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NotEmptyRAListofIdleModeUEsIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: NotEmptyRAListofIdleModeUEsIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8267,11 +9267,11 @@ pub struct NotEmptyRAListofIdleModeUEsIE_Extensions(
     pub Vec<NotEmptyRAListofIdleModeUEsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Offload_RAB_ParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8280,7 +9280,7 @@ pub struct Offload_RAB_ParametersIE_Extensions_Entry {}
 )]
 pub struct Offload_RAB_ParametersIE_Extensions(pub Vec<Offload_RAB_ParametersIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum OutcomeValue {
     #[asn(key = 38)]
@@ -8295,7 +9295,7 @@ pub enum OutcomeValue {
     Id_UeRegistrationQuery(UeRegistrationQueryResponse),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum OverloadProtocolIEs_EntryValue {
     #[asn(key = 86)]
@@ -8304,16 +9304,18 @@ pub enum OverloadProtocolIEs_EntryValue {
     Id_NumberOfSteps(NumberOfSteps),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: OverloadProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8322,7 +9324,7 @@ pub struct OverloadProtocolIEs_Entry {
 )]
 pub struct OverloadProtocolIEs(pub Vec<OverloadProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum OverloadProtocolExtensions_EntryExtensionValue {
     #[asn(key = 3)]
@@ -8335,16 +9337,18 @@ pub enum OverloadProtocolExtensions_EntryExtensionValue {
     Id_Priority_Class_Indicator(Priority_Class_Indicator),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: OverloadProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8353,11 +9357,11 @@ pub struct OverloadProtocolExtensions_Entry {
 )]
 pub struct OverloadProtocolExtensions(pub Vec<OverloadProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PLMNBasedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8366,11 +9370,11 @@ pub struct PLMNBasedIE_Extensions_Entry {}
 )]
 pub struct PLMNBasedIE_Extensions(pub Vec<PLMNBasedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PLMNs_in_shared_network_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8381,16 +9385,20 @@ pub struct PLMNs_in_shared_network_EntryIE_Extensions(
     pub Vec<PLMNs_in_shared_network_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PLMNs_in_shared_network_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub la_list: LA_LIST,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PLMNs_in_shared_network_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PagingProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -8409,16 +9417,18 @@ pub enum PagingProtocolIEs_EntryValue {
     Id_TemporaryUE_ID(TemporaryUE_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PagingProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8427,7 +9437,7 @@ pub struct PagingProtocolIEs_Entry {
 )]
 pub struct PagingProtocolIEs(pub Vec<PagingProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PagingProtocolExtensions_EntryExtensionValue {
     #[asn(key = 229)]
@@ -8436,16 +9446,18 @@ pub enum PagingProtocolExtensions_EntryExtensionValue {
     Id_GlobalCN_ID(GlobalCN_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PagingProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8454,19 +9466,19 @@ pub struct PagingProtocolExtensions_Entry {
 )]
 pub struct PagingProtocolExtensions(pub Vec<PagingProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "8639999", extensible = true)]
 pub struct INTEGER_46(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "8639999", extensible = true)]
 pub struct INTEGER_47(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PeriodicLocationInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8475,7 +9487,7 @@ pub struct PeriodicLocationInfoIE_Extensions_Entry {}
 )]
 pub struct PeriodicLocationInfoIE_Extensions(pub Vec<PeriodicLocationInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PositionDataIE_Extensions_EntryExtensionValue {
     #[asn(key = 284)]
@@ -8484,16 +9496,18 @@ pub enum PositionDataIE_Extensions_EntryExtensionValue {
     Id_GANSS_PositioningDataSet(GANSS_PositioningDataSet),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PositionDataIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: PositionDataIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8502,19 +9516,19 @@ pub struct PositionDataIE_Extensions_Entry {
 )]
 pub struct PositionDataIE_Extensions(pub Vec<PositionDataIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct INTEGER_48(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OBJECT-IDENTIFIER")]
 pub struct OBJECT_IDENTIFIER_49;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PrivateMessagePrivateIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8523,7 +9537,7 @@ pub struct PrivateMessagePrivateIEs_Entry {}
 )]
 pub struct PrivateMessagePrivateIEs(pub Vec<PrivateMessagePrivateIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_AssignmentRequestProtocolIEs_EntryValue {
     #[asn(key = 41)]
@@ -8532,16 +9546,18 @@ pub enum RAB_AssignmentRequestProtocolIEs_EntryValue {
     Id_RAB_SetupOrModifyList(RAB_SetupOrModifyList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_AssignmentRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_AssignmentRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8550,7 +9566,7 @@ pub struct RAB_AssignmentRequestProtocolIEs_Entry {
 )]
 pub struct RAB_AssignmentRequestProtocolIEs(pub Vec<RAB_AssignmentRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_AssignmentRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 239)]
@@ -8559,16 +9575,18 @@ pub enum RAB_AssignmentRequestProtocolExtensions_EntryExtensionValue {
     Id_UE_AggregateMaximumBitRate(UE_AggregateMaximumBitRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_AssignmentRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_AssignmentRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8579,7 +9597,7 @@ pub struct RAB_AssignmentRequestProtocolExtensions(
     pub Vec<RAB_AssignmentRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_AssignmentResponseProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -8596,16 +9614,18 @@ pub enum RAB_AssignmentResponseProtocolIEs_EntryValue {
     Id_RAB_SetupOrModifiedList(RAB_SetupOrModifiedList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_AssignmentResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_AssignmentResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8614,23 +9634,25 @@ pub struct RAB_AssignmentResponseProtocolIEs_Entry {
 )]
 pub struct RAB_AssignmentResponseProtocolIEs(pub Vec<RAB_AssignmentResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_AssignmentResponseProtocolExtensions_EntryExtensionValue {
     #[asn(key = 110)]
     Id_GERAN_Iumode_RAB_FailedList_RABAssgntResponse(GERAN_Iumode_RAB_FailedList_RABAssgntResponse),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_AssignmentResponseProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_AssignmentResponseProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8641,23 +9663,25 @@ pub struct RAB_AssignmentResponseProtocolExtensions(
     pub Vec<RAB_AssignmentResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ContextFailedtoTransferList_Entry_EntryValue {
     #[asn(key = 84)]
     Id_RAB_ContextFailedtoTransferItem(RABs_ContextFailedtoTransferItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ContextFailedtoTransferList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ContextFailedtoTransferList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8668,11 +9692,11 @@ pub struct RAB_ContextFailedtoTransferList_Entry(
     pub Vec<RAB_ContextFailedtoTransferList_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ContextItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8681,11 +9705,11 @@ pub struct RAB_ContextItemIE_Extensions_Entry {}
 )]
 pub struct RAB_ContextItemIE_Extensions(pub Vec<RAB_ContextItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ContextItem_RANAP_RelocInfIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8696,23 +9720,25 @@ pub struct RAB_ContextItem_RANAP_RelocInfIE_Extensions(
     pub Vec<RAB_ContextItem_RANAP_RelocInfIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ContextList_Entry_EntryValue {
     #[asn(key = 24)]
     Id_RAB_ContextItem(RAB_ContextItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ContextList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ContextList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8721,23 +9747,25 @@ pub struct RAB_ContextList_Entry_Entry {
 )]
 pub struct RAB_ContextList_Entry(pub Vec<RAB_ContextList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ContextList_RANAP_RelocInf_Entry_EntryValue {
     #[asn(key = 82)]
     Id_RAB_ContextItem_RANAP_RelocInf(RAB_ContextItem_RANAP_RelocInf),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ContextList_RANAP_RelocInf_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ContextList_RANAP_RelocInf_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8748,7 +9776,7 @@ pub struct RAB_ContextList_RANAP_RelocInf_Entry(
     pub Vec<RAB_ContextList_RANAP_RelocInf_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_DataForwardingItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 13)]
@@ -8757,16 +9785,18 @@ pub enum RAB_DataForwardingItemIE_Extensions_EntryExtensionValue {
     Id_TransportLayerAddress(TransportLayerAddress),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataForwardingItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_DataForwardingItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8775,11 +9805,11 @@ pub struct RAB_DataForwardingItemIE_Extensions_Entry {
 )]
 pub struct RAB_DataForwardingItemIE_Extensions(pub Vec<RAB_DataForwardingItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataForwardingItem_SRNS_CtxReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8790,23 +9820,25 @@ pub struct RAB_DataForwardingItem_SRNS_CtxReqIE_Extensions(
     pub Vec<RAB_DataForwardingItem_SRNS_CtxReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_DataForwardingList_Entry_EntryValue {
     #[asn(key = 26)]
     Id_RAB_DataForwardingItem(RAB_DataForwardingItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataForwardingList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_DataForwardingList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8815,23 +9847,25 @@ pub struct RAB_DataForwardingList_Entry_Entry {
 )]
 pub struct RAB_DataForwardingList_Entry(pub Vec<RAB_DataForwardingList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_DataForwardingList_SRNS_CtxReq_Entry_EntryValue {
     #[asn(key = 27)]
     Id_RAB_DataForwardingItem_SRNS_CtxReq(RAB_DataForwardingItem_SRNS_CtxReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataForwardingList_SRNS_CtxReq_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_DataForwardingList_SRNS_CtxReq_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8842,11 +9876,11 @@ pub struct RAB_DataForwardingList_SRNS_CtxReq_Entry(
     pub Vec<RAB_DataForwardingList_SRNS_CtxReq_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataVolumeReportItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8857,23 +9891,25 @@ pub struct RAB_DataVolumeReportItemIE_Extensions(
     pub Vec<RAB_DataVolumeReportItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_DataVolumeReportList_Entry_EntryValue {
     #[asn(key = 30)]
     Id_RAB_DataVolumeReportItem(RAB_DataVolumeReportItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataVolumeReportList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_DataVolumeReportList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8882,11 +9918,11 @@ pub struct RAB_DataVolumeReportList_Entry_Entry {
 )]
 pub struct RAB_DataVolumeReportList_Entry(pub Vec<RAB_DataVolumeReportList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataVolumeReportRequestItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8897,23 +9933,25 @@ pub struct RAB_DataVolumeReportRequestItemIE_Extensions(
     pub Vec<RAB_DataVolumeReportRequestItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_DataVolumeReportRequestList_Entry_EntryValue {
     #[asn(key = 32)]
     Id_RAB_DataVolumeReportRequestItem(RAB_DataVolumeReportRequestItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_DataVolumeReportRequestList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_DataVolumeReportRequestList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8924,11 +9962,11 @@ pub struct RAB_DataVolumeReportRequestList_Entry(
     pub Vec<RAB_DataVolumeReportRequestList_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_FailedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8937,11 +9975,11 @@ pub struct RAB_FailedItemIE_Extensions_Entry {}
 )]
 pub struct RAB_FailedItemIE_Extensions(pub Vec<RAB_FailedItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_FailedItem_EnhRelocInfoResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8952,23 +9990,25 @@ pub struct RAB_FailedItem_EnhRelocInfoResIE_Extensions(
     pub Vec<RAB_FailedItem_EnhRelocInfoResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_FailedList_Entry_EntryValue {
     #[asn(key = 34)]
     Id_RAB_FailedItem(RAB_FailedItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_FailedList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_FailedList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8977,23 +10017,25 @@ pub struct RAB_FailedList_Entry_Entry {
 )]
 pub struct RAB_FailedList_Entry(pub Vec<RAB_FailedList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_FailedList_EnhRelocInfoRes_Entry_EntryValue {
     #[asn(key = 198)]
     Id_RAB_FailedItem_EnhRelocInfoRes(RAB_FailedItem_EnhRelocInfoRes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_FailedList_EnhRelocInfoRes_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_FailedList_EnhRelocInfoRes_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9004,23 +10046,25 @@ pub struct RAB_FailedList_EnhRelocInfoRes_Entry(
     pub Vec<RAB_FailedList_EnhRelocInfoRes_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_FailedtoReportList_Entry_EntryValue {
     #[asn(key = 71)]
     Id_RAB_FailedtoReportItem(RABs_failed_to_reportItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_FailedtoReportList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_FailedtoReportList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9029,11 +10073,11 @@ pub struct RAB_FailedtoReportList_Entry_Entry {
 )]
 pub struct RAB_FailedtoReportList_Entry(pub Vec<RAB_FailedtoReportList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ModifyItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9042,23 +10086,25 @@ pub struct RAB_ModifyItemIE_Extensions_Entry {}
 )]
 pub struct RAB_ModifyItemIE_Extensions(pub Vec<RAB_ModifyItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ModifyList_Entry_EntryValue {
     #[asn(key = 92)]
     Id_RAB_ModifyItem(RAB_ModifyItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ModifyList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ModifyList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9067,23 +10113,25 @@ pub struct RAB_ModifyList_Entry_Entry {
 )]
 pub struct RAB_ModifyList_Entry(pub Vec<RAB_ModifyList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ModifyRequestProtocolIEs_EntryValue {
     #[asn(key = 91)]
     Id_RAB_ModifyList(RAB_ModifyList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ModifyRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ModifyRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9092,11 +10140,11 @@ pub struct RAB_ModifyRequestProtocolIEs_Entry {
 )]
 pub struct RAB_ModifyRequestProtocolIEs(pub Vec<RAB_ModifyRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ModifyRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9105,7 +10153,7 @@ pub struct RAB_ModifyRequestProtocolExtensions_Entry {}
 )]
 pub struct RAB_ModifyRequestProtocolExtensions(pub Vec<RAB_ModifyRequestProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ParametersIE_Extensions_EntryExtensionValue {
     #[asn(key = 176)]
@@ -9120,16 +10168,18 @@ pub enum RAB_ParametersIE_Extensions_EntryExtensionValue {
     Id_SignallingIndication(SignallingIndication),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ParametersIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_ParametersIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9138,11 +10188,11 @@ pub struct RAB_ParametersIE_Extensions_Entry {
 )]
 pub struct RAB_ParametersIE_Extensions(pub Vec<RAB_ParametersIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_QueuedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9151,23 +10201,25 @@ pub struct RAB_QueuedItemIE_Extensions_Entry {}
 )]
 pub struct RAB_QueuedItemIE_Extensions(pub Vec<RAB_QueuedItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_QueuedList_Entry_EntryValue {
     #[asn(key = 37)]
     Id_RAB_QueuedItem(RAB_QueuedItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_QueuedList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_QueuedList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9176,11 +10228,11 @@ pub struct RAB_QueuedList_Entry_Entry {
 )]
 pub struct RAB_QueuedList_Entry(pub Vec<RAB_QueuedList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleaseItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9189,23 +10241,25 @@ pub struct RAB_ReleaseItemIE_Extensions_Entry {}
 )]
 pub struct RAB_ReleaseItemIE_Extensions(pub Vec<RAB_ReleaseItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ReleaseList_Entry_EntryValue {
     #[asn(key = 40)]
     Id_RAB_ReleaseItem(RAB_ReleaseItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleaseList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ReleaseList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9214,23 +10268,25 @@ pub struct RAB_ReleaseList_Entry_Entry {
 )]
 pub struct RAB_ReleaseList_Entry(pub Vec<RAB_ReleaseList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 41)]
     Id_RAB_ReleaseList(RAB_ReleaseList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleaseRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9239,11 +10295,11 @@ pub struct RAB_ReleaseRequestProtocolIEs_Entry {
 )]
 pub struct RAB_ReleaseRequestProtocolIEs(pub Vec<RAB_ReleaseRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleaseRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9254,11 +10310,11 @@ pub struct RAB_ReleaseRequestProtocolExtensions(
     pub Vec<RAB_ReleaseRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleasedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9267,11 +10323,11 @@ pub struct RAB_ReleasedItemIE_Extensions_Entry {}
 )]
 pub struct RAB_ReleasedItemIE_Extensions(pub Vec<RAB_ReleasedItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleasedItem_IuRelCompIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9282,23 +10338,25 @@ pub struct RAB_ReleasedItem_IuRelCompIE_Extensions(
     pub Vec<RAB_ReleasedItem_IuRelCompIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ReleasedList_Entry_EntryValue {
     #[asn(key = 42)]
     Id_RAB_ReleasedItem(RAB_ReleasedItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleasedList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ReleasedList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9307,23 +10365,25 @@ pub struct RAB_ReleasedList_Entry_Entry {
 )]
 pub struct RAB_ReleasedList_Entry(pub Vec<RAB_ReleasedList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ReleasedList_IuRelComp_Entry_EntryValue {
     #[asn(key = 87)]
     Id_RAB_ReleasedItem_IuRelComp(RAB_ReleasedItem_IuRelComp),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ReleasedList_IuRelComp_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ReleasedList_IuRelComp_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9332,11 +10392,11 @@ pub struct RAB_ReleasedList_IuRelComp_Entry_Entry {
 )]
 pub struct RAB_ReleasedList_IuRelComp_Entry(pub Vec<RAB_ReleasedList_IuRelComp_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_RelocationReleaseItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9347,23 +10407,25 @@ pub struct RAB_RelocationReleaseItemIE_Extensions(
     pub Vec<RAB_RelocationReleaseItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_RelocationReleaseList_Entry_EntryValue {
     #[asn(key = 45)]
     Id_RAB_RelocationReleaseItem(RAB_RelocationReleaseItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_RelocationReleaseList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_RelocationReleaseList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9372,7 +10434,7 @@ pub struct RAB_RelocationReleaseList_Entry_Entry {
 )]
 pub struct RAB_RelocationReleaseList_Entry(pub Vec<RAB_RelocationReleaseList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupItem_EnhRelocInfoReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 231)]
@@ -9381,16 +10443,18 @@ pub enum RAB_SetupItem_EnhRelocInfoReqIE_Extensions_EntryExtensionValue {
     Id_PDP_TypeInformation_extension(PDP_TypeInformation_extension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupItem_EnhRelocInfoReqIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_SetupItem_EnhRelocInfoReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9401,11 +10465,11 @@ pub struct RAB_SetupItem_EnhRelocInfoReqIE_Extensions(
     pub Vec<RAB_SetupItem_EnhRelocInfoReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupItem_EnhRelocInfoResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9416,11 +10480,11 @@ pub struct RAB_SetupItem_EnhRelocInfoResIE_Extensions(
     pub Vec<RAB_SetupItem_EnhRelocInfoResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupItem_EnhancedRelocCompleteReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9431,23 +10495,25 @@ pub struct RAB_SetupItem_EnhancedRelocCompleteReqIE_Extensions(
     pub Vec<RAB_SetupItem_EnhancedRelocCompleteReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupItem_EnhancedRelocCompleteResIE_Extensions_EntryExtensionValue {
     #[asn(key = 240)]
     Id_Offload_RAB_Parameters(Offload_RAB_Parameters),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupItem_EnhancedRelocCompleteResIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_SetupItem_EnhancedRelocCompleteResIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9458,7 +10524,7 @@ pub struct RAB_SetupItem_EnhancedRelocCompleteResIE_Extensions(
     pub Vec<RAB_SetupItem_EnhancedRelocCompleteResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupItem_RelocReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 89)]
@@ -9473,16 +10539,18 @@ pub enum RAB_SetupItem_RelocReqIE_Extensions_EntryExtensionValue {
     Id_PDP_TypeInformation_extension(PDP_TypeInformation_extension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupItem_RelocReqIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_SetupItem_RelocReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9491,7 +10559,7 @@ pub struct RAB_SetupItem_RelocReqIE_Extensions_Entry {
 )]
 pub struct RAB_SetupItem_RelocReqIE_Extensions(pub Vec<RAB_SetupItem_RelocReqIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupItem_RelocReqAckIE_Extensions_EntryExtensionValue {
     #[asn(key = 90)]
@@ -9502,16 +10570,18 @@ pub enum RAB_SetupItem_RelocReqAckIE_Extensions_EntryExtensionValue {
     Id_TransportLayerAddress(TransportLayerAddress),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupItem_RelocReqAckIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_SetupItem_RelocReqAckIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9522,23 +10592,25 @@ pub struct RAB_SetupItem_RelocReqAckIE_Extensions(
     pub Vec<RAB_SetupItem_RelocReqAckIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupList_EnhRelocInfoReq_Entry_EntryValue {
     #[asn(key = 193)]
     Id_RAB_SetupItem_EnhRelocInfoReq(RAB_SetupItem_EnhRelocInfoReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupList_EnhRelocInfoReq_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_SetupList_EnhRelocInfoReq_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9547,23 +10619,25 @@ pub struct RAB_SetupList_EnhRelocInfoReq_Entry_Entry {
 )]
 pub struct RAB_SetupList_EnhRelocInfoReq_Entry(pub Vec<RAB_SetupList_EnhRelocInfoReq_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupList_EnhRelocInfoRes_Entry_EntryValue {
     #[asn(key = 195)]
     Id_RAB_SetupItem_EnhRelocInfoRes(RAB_SetupItem_EnhRelocInfoRes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupList_EnhRelocInfoRes_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_SetupList_EnhRelocInfoRes_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9572,23 +10646,25 @@ pub struct RAB_SetupList_EnhRelocInfoRes_Entry_Entry {
 )]
 pub struct RAB_SetupList_EnhRelocInfoRes_Entry(pub Vec<RAB_SetupList_EnhRelocInfoRes_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupList_EnhancedRelocCompleteReq_Entry_EntryValue {
     #[asn(key = 189)]
     Id_RAB_SetupItem_EnhancedRelocCompleteReq(RAB_SetupItem_EnhancedRelocCompleteReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupList_EnhancedRelocCompleteReq_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_SetupList_EnhancedRelocCompleteReq_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9599,23 +10675,25 @@ pub struct RAB_SetupList_EnhancedRelocCompleteReq_Entry(
     pub Vec<RAB_SetupList_EnhancedRelocCompleteReq_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupList_EnhancedRelocCompleteRes_Entry_EntryValue {
     #[asn(key = 191)]
     Id_RAB_SetupItem_EnhancedRelocCompleteRes(RAB_SetupItem_EnhancedRelocCompleteRes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupList_EnhancedRelocCompleteRes_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_SetupList_EnhancedRelocCompleteRes_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9626,23 +10704,25 @@ pub struct RAB_SetupList_EnhancedRelocCompleteRes_Entry(
     pub Vec<RAB_SetupList_EnhancedRelocCompleteRes_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupList_RelocReq_Entry_EntryValue {
     #[asn(key = 47)]
     Id_RAB_SetupItem_RelocReq(RAB_SetupItem_RelocReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupList_RelocReq_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_SetupList_RelocReq_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9651,23 +10731,25 @@ pub struct RAB_SetupList_RelocReq_Entry_Entry {
 )]
 pub struct RAB_SetupList_RelocReq_Entry(pub Vec<RAB_SetupList_RelocReq_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupList_RelocReqAck_Entry_EntryValue {
     #[asn(key = 48)]
     Id_RAB_SetupItem_RelocReqAck(RAB_SetupItem_RelocReqAck),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupList_RelocReqAck_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_SetupList_RelocReqAck_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9676,23 +10758,25 @@ pub struct RAB_SetupList_RelocReqAck_Entry_Entry {
 )]
 pub struct RAB_SetupList_RelocReqAck_Entry(pub Vec<RAB_SetupList_RelocReqAck_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupOrModifiedItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 90)]
     Id_Ass_RAB_Parameters(Ass_RAB_Parameters),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupOrModifiedItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_SetupOrModifiedItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9703,23 +10787,25 @@ pub struct RAB_SetupOrModifiedItemIE_Extensions(
     pub Vec<RAB_SetupOrModifiedItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupOrModifiedList_Entry_EntryValue {
     #[asn(key = 51)]
     Id_RAB_SetupOrModifiedItem(RAB_SetupOrModifiedItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupOrModifiedList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_SetupOrModifiedList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9728,7 +10814,7 @@ pub struct RAB_SetupOrModifiedList_Entry_Entry {
 )]
 pub struct RAB_SetupOrModifiedList_Entry(pub Vec<RAB_SetupOrModifiedList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupOrModifyItemFirstIE_Extensions_EntryExtensionValue {
     #[asn(key = 242)]
@@ -9739,16 +10825,18 @@ pub enum RAB_SetupOrModifyItemFirstIE_Extensions_EntryExtensionValue {
     Id_SIPTO_Correlation_ID(Correlation_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupOrModifyItemFirstIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_SetupOrModifyItemFirstIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9759,7 +10847,7 @@ pub struct RAB_SetupOrModifyItemFirstIE_Extensions(
     pub Vec<RAB_SetupOrModifyItemFirstIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupOrModifyItemSecondIE_Extensions_EntryExtensionValue {
     #[asn(key = 89)]
@@ -9772,16 +10860,18 @@ pub enum RAB_SetupOrModifyItemSecondIE_Extensions_EntryExtensionValue {
     Id_PDP_TypeInformation_extension(PDP_TypeInformation_extension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupOrModifyItemSecondIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_SetupOrModifyItemSecondIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9792,32 +10882,36 @@ pub struct RAB_SetupOrModifyItemSecondIE_Extensions(
     pub Vec<RAB_SetupOrModifyItemSecondIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupOrModifyList_Entry_EntryFirstValue {
     #[asn(key = 53)]
     Id_RAB_SetupOrModifyItem(RAB_SetupOrModifyItemFirst),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_SetupOrModifyList_Entry_EntrySecondValue {
     #[asn(key = 53)]
     Id_RAB_SetupOrModifyItem(RAB_SetupOrModifyItemSecond),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_SetupOrModifyList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub first_criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub first_value: RAB_SetupOrModifyList_Entry_EntryFirstValue,
+    #[asn(tag = "[3] IMPLICIT")]
     pub second_criticality: Criticality,
+    #[asn(tag = "[4] EXPLICIT")]
     pub second_value: RAB_SetupOrModifyList_Entry_EntrySecondValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9826,11 +10920,11 @@ pub struct RAB_SetupOrModifyList_Entry_Entry {
 )]
 pub struct RAB_SetupOrModifyList_Entry(pub Vec<RAB_SetupOrModifyList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ToBeReleasedItem_EnhancedRelocCompleteResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9841,23 +10935,25 @@ pub struct RAB_ToBeReleasedItem_EnhancedRelocCompleteResIE_Extensions(
     pub Vec<RAB_ToBeReleasedItem_EnhancedRelocCompleteResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_ToBeReleasedList_EnhancedRelocCompleteRes_Entry_EntryValue {
     #[asn(key = 209)]
     Id_RAB_ToBeReleasedItem_EnhancedRelocCompleteRes(RAB_ToBeReleasedItem_EnhancedRelocCompleteRes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_ToBeReleasedList_EnhancedRelocCompleteRes_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RAB_ToBeReleasedList_EnhancedRelocCompleteRes_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9868,23 +10964,25 @@ pub struct RAB_ToBeReleasedList_EnhancedRelocCompleteRes_Entry(
     pub Vec<RAB_ToBeReleasedList_EnhancedRelocCompleteRes_Entry_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RAB_TrCH_MappingItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 3)]
     Id_CN_DomainIndicator(CN_DomainIndicator),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAB_TrCH_MappingItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RAB_TrCH_MappingItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9893,11 +10991,11 @@ pub struct RAB_TrCH_MappingItemIE_Extensions_Entry {
 )]
 pub struct RAB_TrCH_MappingItemIE_Extensions(pub Vec<RAB_TrCH_MappingItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RABDataVolumeReport_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9908,21 +11006,24 @@ pub struct RABDataVolumeReport_EntryIE_Extensions(
     pub Vec<RABDataVolumeReport_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct RABDataVolumeReport_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_unsuccessfully_transmitted_data_volume: UnsuccessfullyTransmittedDataVolume,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub data_volume_reference: Option<DataVolumeReference>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RABDataVolumeReport_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RABParametersList_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9933,24 +11034,28 @@ pub struct RABParametersList_EntryIE_Extensions(
     pub Vec<RABParametersList_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct RABParametersList_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rab_id: RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cn_domain: CN_DomainIndicator,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub rab_data_volume_report: Option<RABDataVolumeReport>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub up_information: Option<UPInformation>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<RABParametersList_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RABasedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9959,11 +11064,11 @@ pub struct RABasedIE_Extensions_Entry {}
 )]
 pub struct RABasedIE_Extensions(pub Vec<RABasedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RABs_ContextFailedtoTransferItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9974,11 +11079,11 @@ pub struct RABs_ContextFailedtoTransferItemIE_Extensions(
     pub Vec<RABs_ContextFailedtoTransferItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RABs_failed_to_reportItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9989,11 +11094,11 @@ pub struct RABs_failed_to_reportItemIE_Extensions(
     pub Vec<RABs_failed_to_reportItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10002,7 +11107,7 @@ pub struct RAIIE_Extensions_Entry {}
 )]
 pub struct RAIIE_Extensions(pub Vec<RAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_50(pub u8);
 impl ENUMERATED_50 {
@@ -10010,7 +11115,7 @@ impl ENUMERATED_50 {
     pub const FULLLIST: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANAP_EnhancedRelocationInformationRequestProtocolIEs_EntryValue {
     #[asn(key = 133)]
@@ -10035,16 +11140,18 @@ pub enum RANAP_EnhancedRelocationInformationRequestProtocolIEs_EntryValue {
     Id_UESBI_Iu(UESBI_Iu),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANAP_EnhancedRelocationInformationRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RANAP_EnhancedRelocationInformationRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10055,7 +11162,7 @@ pub struct RANAP_EnhancedRelocationInformationRequestProtocolIEs(
     pub Vec<RANAP_EnhancedRelocationInformationRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANAP_EnhancedRelocationInformationRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 261)]
@@ -10074,17 +11181,19 @@ pub enum RANAP_EnhancedRelocationInformationRequestProtocolExtensions_EntryExten
     Id_UE_AggregateMaximumBitRate(UE_AggregateMaximumBitRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANAP_EnhancedRelocationInformationRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         RANAP_EnhancedRelocationInformationRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10095,7 +11204,7 @@ pub struct RANAP_EnhancedRelocationInformationRequestProtocolExtensions(
     pub Vec<RANAP_EnhancedRelocationInformationRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANAP_EnhancedRelocationInformationResponseProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -10108,16 +11217,18 @@ pub enum RANAP_EnhancedRelocationInformationResponseProtocolIEs_EntryValue {
     Id_Target_ToSource_TransparentContainer(TargetRNC_ToSourceRNC_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANAP_EnhancedRelocationInformationResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RANAP_EnhancedRelocationInformationResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10128,11 +11239,11 @@ pub struct RANAP_EnhancedRelocationInformationResponseProtocolIEs(
     pub Vec<RANAP_EnhancedRelocationInformationResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANAP_EnhancedRelocationInformationResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10143,7 +11254,7 @@ pub struct RANAP_EnhancedRelocationInformationResponseProtocolExtensions(
     pub Vec<RANAP_EnhancedRelocationInformationResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANAP_RelocationInformationProtocolIEs_EntryValue {
     #[asn(key = 81)]
@@ -10152,16 +11263,18 @@ pub enum RANAP_RelocationInformationProtocolIEs_EntryValue {
     Id_RAB_ContextList_RANAP_RelocInf(RAB_ContextList_RANAP_RelocInf),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANAP_RelocationInformationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RANAP_RelocationInformationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10172,7 +11285,7 @@ pub struct RANAP_RelocationInformationProtocolIEs(
     pub Vec<RANAP_RelocationInformationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RANAP_RelocationInformationProtocolExtensions_EntryExtensionValue {
     #[asn(key = 247)]
@@ -10181,16 +11294,18 @@ pub enum RANAP_RelocationInformationProtocolExtensions_EntryExtensionValue {
     Id_SourceRNC_PDCP_context_info(RRC_Container),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RANAP_RelocationInformationProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RANAP_RelocationInformationProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10201,11 +11316,11 @@ pub struct RANAP_RelocationInformationProtocolExtensions(
     pub Vec<RANAP_RelocationInformationProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RIM_TransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10214,7 +11329,7 @@ pub struct RIM_TransferIE_Extensions_Entry {}
 )]
 pub struct RIM_TransferIE_Extensions(pub Vec<RIM_TransferIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct ENUMERATED_51(pub u8);
 impl ENUMERATED_51 {
@@ -10222,7 +11337,7 @@ impl ENUMERATED_51 {
     pub const DEACTIVATED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RNCTraceInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 256)]
@@ -10235,16 +11350,18 @@ pub enum RNCTraceInformationIE_Extensions_EntryExtensionValue {
     Id_TraceRecordingSessionReference(TraceRecordingSessionReference),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RNCTraceInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RNCTraceInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10253,11 +11370,11 @@ pub struct RNCTraceInformationIE_Extensions_Entry {
 )]
 pub struct RNCTraceInformationIE_Extensions(pub Vec<RNCTraceInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RNSAPRelocationParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10268,15 +11385,15 @@ pub struct RNSAPRelocationParametersIE_Extensions(
     pub Vec<RNSAPRelocationParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BOOLEAN")]
 pub struct BOOLEAN_52(pub bool);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BOOLEAN")]
 pub struct BOOLEAN_53(pub bool);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -10285,7 +11402,7 @@ pub struct BOOLEAN_53(pub bool);
 )]
 pub struct BIT_STRING_54(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -10294,11 +11411,11 @@ pub struct BIT_STRING_54(pub BitVec<u8, Msb0>);
 )]
 pub struct OCTET_STRING_55(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RSRVCC_InformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10307,7 +11424,7 @@ pub struct RSRVCC_InformationIE_Extensions_Entry {}
 )]
 pub struct RSRVCC_InformationIE_Extensions(pub Vec<RSRVCC_InformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RedirectionIndication_EntryValue {
     #[asn(key = 280)]
@@ -10322,32 +11439,36 @@ pub enum RedirectionIndication_EntryValue {
     Id_RejectCauseValue(RejectCauseValue),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RedirectionIndication_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RedirectionIndication_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationCancelProtocolIEs_EntryValue {
     #[asn(key = 4)]
     Id_Cause(Cause),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCancelProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationCancelProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10356,11 +11477,11 @@ pub struct RelocationCancelProtocolIEs_Entry {
 )]
 pub struct RelocationCancelProtocolIEs(pub Vec<RelocationCancelProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCancelProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10369,23 +11490,25 @@ pub struct RelocationCancelProtocolExtensions_Entry {}
 )]
 pub struct RelocationCancelProtocolExtensions(pub Vec<RelocationCancelProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationCancelAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 9)]
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCancelAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationCancelAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10396,11 +11519,11 @@ pub struct RelocationCancelAcknowledgeProtocolIEs(
     pub Vec<RelocationCancelAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCancelAcknowledgeProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10411,7 +11534,7 @@ pub struct RelocationCancelAcknowledgeProtocolExtensions(
     pub Vec<RelocationCancelAcknowledgeProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationCommandProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -10426,16 +11549,18 @@ pub enum RelocationCommandProtocolIEs_EntryValue {
     Id_Target_ToSource_TransparentContainer(Target_ToSource_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10444,7 +11569,7 @@ pub struct RelocationCommandProtocolIEs_Entry {
 )]
 pub struct RelocationCommandProtocolIEs(pub Vec<RelocationCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationCommandProtocolExtensions_EntryExtensionValue {
     #[asn(key = 99)]
@@ -10457,16 +11582,18 @@ pub enum RelocationCommandProtocolExtensions_EntryExtensionValue {
     Id_TargetBSS_ToSourceBSS_TransparentContainer(TargetBSS_ToSourceBSS_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCommandProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RelocationCommandProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10475,11 +11602,11 @@ pub struct RelocationCommandProtocolExtensions_Entry {
 )]
 pub struct RelocationCommandProtocolExtensions(pub Vec<RelocationCommandProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCompleteProtocolIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10488,7 +11615,7 @@ pub struct RelocationCompleteProtocolIEs_Entry {}
 )]
 pub struct RelocationCompleteProtocolIEs(pub Vec<RelocationCompleteProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationCompleteProtocolExtensions_EntryExtensionValue {
     #[asn(key = 250)]
@@ -10499,16 +11626,18 @@ pub enum RelocationCompleteProtocolExtensions_EntryExtensionValue {
     Id_Tunnel_Information_for_BBF(TunnelInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationCompleteProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RelocationCompleteProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10519,11 +11648,11 @@ pub struct RelocationCompleteProtocolExtensions(
     pub Vec<RelocationCompleteProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationDetectProtocolIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10532,11 +11661,11 @@ pub struct RelocationDetectProtocolIEs_Entry {}
 )]
 pub struct RelocationDetectProtocolIEs(pub Vec<RelocationDetectProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationDetectProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10545,7 +11674,7 @@ pub struct RelocationDetectProtocolExtensions_Entry {}
 )]
 pub struct RelocationDetectProtocolExtensions(pub Vec<RelocationDetectProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -10554,16 +11683,18 @@ pub enum RelocationFailureProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10572,7 +11703,7 @@ pub struct RelocationFailureProtocolIEs_Entry {
 )]
 pub struct RelocationFailureProtocolIEs(pub Vec<RelocationFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationFailureProtocolExtensions_EntryExtensionValue {
     #[asn(key = 108)]
@@ -10581,16 +11712,18 @@ pub enum RelocationFailureProtocolExtensions_EntryExtensionValue {
     Id_NewBSS_To_OldBSS_Information(NewBSS_To_OldBSS_Information),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationFailureProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RelocationFailureProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10599,7 +11732,7 @@ pub struct RelocationFailureProtocolExtensions_Entry {
 )]
 pub struct RelocationFailureProtocolExtensions(pub Vec<RelocationFailureProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationPreparationFailureProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -10608,16 +11741,18 @@ pub enum RelocationPreparationFailureProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationPreparationFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationPreparationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10628,23 +11763,25 @@ pub struct RelocationPreparationFailureProtocolIEs(
     pub Vec<RelocationPreparationFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationPreparationFailureProtocolExtensions_EntryExtensionValue {
     #[asn(key = 99)]
     Id_InterSystemInformation_TransparentContainer(InterSystemInformation_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationPreparationFailureProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RelocationPreparationFailureProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10655,7 +11792,7 @@ pub struct RelocationPreparationFailureProtocolExtensions(
     pub Vec<RelocationPreparationFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationRequestProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -10676,16 +11813,18 @@ pub enum RelocationRequestProtocolIEs_EntryValue {
     Id_Source_ToTarget_TransparentContainer(SourceRNC_ToTargetRNC_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10694,7 +11833,7 @@ pub struct RelocationRequestProtocolIEs_Entry {
 )]
 pub struct RelocationRequestProtocolIEs(pub Vec<RelocationRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 261)]
@@ -10725,16 +11864,18 @@ pub enum RelocationRequestProtocolExtensions_EntryExtensionValue {
     Id_UESBI_Iu(UESBI_Iu),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RelocationRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10743,7 +11884,7 @@ pub struct RelocationRequestProtocolExtensions_Entry {
 )]
 pub struct RelocationRequestProtocolExtensions(pub Vec<RelocationRequestProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationRequestAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 5)]
@@ -10760,16 +11901,18 @@ pub enum RelocationRequestAcknowledgeProtocolIEs_EntryValue {
     Id_Target_ToSource_TransparentContainer(TargetRNC_ToSourceRNC_TransparentContainer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationRequestAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationRequestAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10780,7 +11923,7 @@ pub struct RelocationRequestAcknowledgeProtocolIEs(
     pub Vec<RelocationRequestAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationRequestAcknowledgeProtocolExtensions_EntryExtensionValue {
     #[asn(key = 203)]
@@ -10789,16 +11932,18 @@ pub enum RelocationRequestAcknowledgeProtocolExtensions_EntryExtensionValue {
     Id_NewBSS_To_OldBSS_Information(NewBSS_To_OldBSS_Information),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationRequestAcknowledgeProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RelocationRequestAcknowledgeProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10809,7 +11954,7 @@ pub struct RelocationRequestAcknowledgeProtocolExtensions(
     pub Vec<RelocationRequestAcknowledgeProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationRequiredProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -10830,16 +11975,18 @@ pub enum RelocationRequiredProtocolIEs_EntryValue {
     Id_TargetID(TargetID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationRequiredProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RelocationRequiredProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10848,7 +11995,7 @@ pub struct RelocationRequiredProtocolIEs_Entry {
 )]
 pub struct RelocationRequiredProtocolIEs(pub Vec<RelocationRequiredProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RelocationRequiredProtocolExtensions_EntryExtensionValue {
     #[asn(key = 203)]
@@ -10869,16 +12016,18 @@ pub enum RelocationRequiredProtocolExtensions_EntryExtensionValue {
     ),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RelocationRequiredProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RelocationRequiredProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10889,11 +12038,11 @@ pub struct RelocationRequiredProtocolExtensions(
     pub Vec<RelocationRequiredProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_56(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Requested_RAB_Parameter_ValuesIE_Extensions_EntryExtensionValue {
     #[asn(key = 159)]
@@ -10912,16 +12061,18 @@ pub enum Requested_RAB_Parameter_ValuesIE_Extensions_EntryExtensionValue {
     Id_Requested_RAB_Parameter_SupportedMaxBitrateList(SupportedRAB_ParameterBitrateList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Requested_RAB_Parameter_ValuesIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: Requested_RAB_Parameter_ValuesIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10932,7 +12083,7 @@ pub struct Requested_RAB_Parameter_ValuesIE_Extensions(
     pub Vec<Requested_RAB_Parameter_ValuesIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RerouteNASRequestProtocolIEs_EntryValue {
     #[asn(key = 287)]
@@ -10943,16 +12094,18 @@ pub enum RerouteNASRequestProtocolIEs_EntryValue {
     Id_UE_Usage_Type(UE_Usage_Type),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RerouteNASRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RerouteNASRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10961,11 +12114,11 @@ pub struct RerouteNASRequestProtocolIEs_Entry {
 )]
 pub struct RerouteNASRequestProtocolIEs(pub Vec<RerouteNASRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RerouteNASRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10974,7 +12127,7 @@ pub struct RerouteNASRequestProtocolExtensions_Entry {}
 )]
 pub struct RerouteNASRequestProtocolExtensions(pub Vec<RerouteNASRequestProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -10985,16 +12138,18 @@ pub enum ResetProtocolIEs_EntryValue {
     Id_GlobalRNC_ID(GlobalRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11003,7 +12158,7 @@ pub struct ResetProtocolIEs_Entry {
 )]
 pub struct ResetProtocolIEs(pub Vec<ResetProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
@@ -11012,16 +12167,18 @@ pub enum ResetProtocolExtensions_EntryExtensionValue {
     Id_GlobalCN_ID(GlobalCN_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ResetProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11030,7 +12187,7 @@ pub struct ResetProtocolExtensions_Entry {
 )]
 pub struct ResetProtocolExtensions(pub Vec<ResetProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -11041,16 +12198,18 @@ pub enum ResetAcknowledgeProtocolIEs_EntryValue {
     Id_GlobalRNC_ID(GlobalRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11059,7 +12218,7 @@ pub struct ResetAcknowledgeProtocolIEs_Entry {
 )]
 pub struct ResetAcknowledgeProtocolIEs(pub Vec<ResetAcknowledgeProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetAcknowledgeProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
@@ -11068,16 +12227,18 @@ pub enum ResetAcknowledgeProtocolExtensions_EntryExtensionValue {
     Id_GlobalCN_ID(GlobalCN_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetAcknowledgeProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ResetAcknowledgeProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11086,7 +12247,7 @@ pub struct ResetAcknowledgeProtocolExtensions_Entry {
 )]
 pub struct ResetAcknowledgeProtocolExtensions(pub Vec<ResetAcknowledgeProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -11099,16 +12260,18 @@ pub enum ResetResourceProtocolIEs_EntryValue {
     Id_IuSigConIdList(ResetResourceList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetResourceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11117,7 +12280,7 @@ pub struct ResetResourceProtocolIEs_Entry {
 )]
 pub struct ResetResourceProtocolIEs(pub Vec<ResetResourceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
@@ -11126,16 +12289,18 @@ pub enum ResetResourceProtocolExtensions_EntryExtensionValue {
     Id_GlobalCN_ID(GlobalCN_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ResetResourceProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11144,23 +12309,25 @@ pub struct ResetResourceProtocolExtensions_Entry {
 )]
 pub struct ResetResourceProtocolExtensions(pub Vec<ResetResourceProtocolExtensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceAckItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 282)]
     Id_IuSigConIdRangeEnd(IuSignallingConnectionIdentifier),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceAckItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ResetResourceAckItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11169,23 +12336,25 @@ pub struct ResetResourceAckItemIE_Extensions_Entry {
 )]
 pub struct ResetResourceAckItemIE_Extensions(pub Vec<ResetResourceAckItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceAckList_Entry_EntryValue {
     #[asn(key = 78)]
     Id_IuSigConIdItem(ResetResourceAckItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceAckList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetResourceAckList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11194,7 +12363,7 @@ pub struct ResetResourceAckList_Entry_Entry {
 )]
 pub struct ResetResourceAckList_Entry(pub Vec<ResetResourceAckList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -11207,16 +12376,18 @@ pub enum ResetResourceAcknowledgeProtocolIEs_EntryValue {
     Id_IuSigConIdList(ResetResourceAckList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetResourceAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11225,7 +12396,7 @@ pub struct ResetResourceAcknowledgeProtocolIEs_Entry {
 )]
 pub struct ResetResourceAcknowledgeProtocolIEs(pub Vec<ResetResourceAcknowledgeProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceAcknowledgeProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
@@ -11234,16 +12405,18 @@ pub enum ResetResourceAcknowledgeProtocolExtensions_EntryExtensionValue {
     Id_GlobalCN_ID(GlobalCN_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceAcknowledgeProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ResetResourceAcknowledgeProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11254,23 +12427,25 @@ pub struct ResetResourceAcknowledgeProtocolExtensions(
     pub Vec<ResetResourceAcknowledgeProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 282)]
     Id_IuSigConIdRangeEnd(IuSignallingConnectionIdentifier),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ResetResourceItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11279,23 +12454,25 @@ pub struct ResetResourceItemIE_Extensions_Entry {
 )]
 pub struct ResetResourceItemIE_Extensions(pub Vec<ResetResourceItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetResourceList_Entry_EntryValue {
     #[asn(key = 78)]
     Id_IuSigConIdItem(ResetResourceItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetResourceList_Entry_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetResourceList_Entry_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11304,19 +12481,19 @@ pub struct ResetResourceList_Entry_Entry {
 )]
 pub struct ResetResourceList_Entry(pub Vec<ResetResourceList_Entry_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "9")]
 pub struct INTEGER_57(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "8")]
 pub struct INTEGER_58(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResidualBitErrorRatioIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11325,11 +12502,11 @@ pub struct ResidualBitErrorRatioIE_Extensions_Entry {}
 )]
 pub struct ResidualBitErrorRatioIE_Extensions(pub Vec<ResidualBitErrorRatioIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11338,19 +12515,19 @@ pub struct SAIIE_Extensions_Entry {}
 )]
 pub struct SAIIE_Extensions(pub Vec<SAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "9")]
 pub struct INTEGER_59(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "6")]
 pub struct INTEGER_60(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SDU_ErrorRatioIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11359,11 +12536,11 @@ pub struct SDU_ErrorRatioIE_Extensions_Entry {}
 )]
 pub struct SDU_ErrorRatioIE_Extensions(pub Vec<SDU_ErrorRatioIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SDU_FormatInformationParameters_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11374,22 +12551,24 @@ pub struct SDU_FormatInformationParameters_EntryIE_Extensions(
     pub Vec<SDU_FormatInformationParameters_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct SDU_FormatInformationParameters_Entry {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub subflow_sdu_size: Option<SubflowSDU_Size>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub rab_subflow_combination_bit_rate: Option<RAB_SubflowCombinationBitRate>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<SDU_FormatInformationParameters_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SDU_Parameters_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11398,24 +12577,28 @@ pub struct SDU_Parameters_EntryIE_Extensions_Entry {}
 )]
 pub struct SDU_Parameters_EntryIE_Extensions(pub Vec<SDU_Parameters_EntryIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct SDU_Parameters_Entry {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub sdu_error_ratio: Option<SDU_ErrorRatio>,
+    #[asn(tag = "[1] IMPLICIT")]
     pub residual_bit_error_ratio: ResidualBitErrorRatio,
+    #[asn(tag = "[2] IMPLICIT")]
     pub delivery_of_erroneous_sdu: DeliveryOfErroneousSDU,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub sdu_format_information_parameters: Option<SDU_FormatInformationParameters>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<SDU_Parameters_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SNA_Access_InformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11424,11 +12607,11 @@ pub struct SNA_Access_InformationIE_Extensions_Entry {}
 )]
 pub struct SNA_Access_InformationIE_Extensions(pub Vec<SNA_Access_InformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRB_TrCH_MappingItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11437,23 +12620,25 @@ pub struct SRB_TrCH_MappingItemIE_Extensions_Entry {}
 )]
 pub struct SRB_TrCH_MappingItemIE_Extensions(pub Vec<SRB_TrCH_MappingItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SRNS_ContextRequestProtocolIEs_EntryValue {
     #[asn(key = 29)]
     Id_RAB_DataForwardingList_SRNS_CtxReq(RAB_DataForwardingList_SRNS_CtxReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRNS_ContextRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SRNS_ContextRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11462,23 +12647,25 @@ pub struct SRNS_ContextRequestProtocolIEs_Entry {
 )]
 pub struct SRNS_ContextRequestProtocolIEs(pub Vec<SRNS_ContextRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SRNS_ContextRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 167)]
     Id_RAT_Type(RAT_Type),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRNS_ContextRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: SRNS_ContextRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11489,7 +12676,7 @@ pub struct SRNS_ContextRequestProtocolExtensions(
     pub Vec<SRNS_ContextRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SRNS_ContextResponseProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -11500,16 +12687,18 @@ pub enum SRNS_ContextResponseProtocolIEs_EntryValue {
     Id_RAB_ContextList(RAB_ContextList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRNS_ContextResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SRNS_ContextResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11518,11 +12707,11 @@ pub struct SRNS_ContextResponseProtocolIEs_Entry {
 )]
 pub struct SRNS_ContextResponseProtocolIEs(pub Vec<SRNS_ContextResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRNS_ContextResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11533,23 +12722,25 @@ pub struct SRNS_ContextResponseProtocolExtensions(
     pub Vec<SRNS_ContextResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SRNS_DataForwardCommandProtocolIEs_EntryValue {
     #[asn(key = 28)]
     Id_RAB_DataForwardingList(RAB_DataForwardingList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRNS_DataForwardCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SRNS_DataForwardCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11558,11 +12749,11 @@ pub struct SRNS_DataForwardCommandProtocolIEs_Entry {
 )]
 pub struct SRNS_DataForwardCommandProtocolIEs(pub Vec<SRNS_DataForwardCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRNS_DataForwardCommandProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11573,11 +12764,11 @@ pub struct SRNS_DataForwardCommandProtocolExtensions(
     pub Vec<SRNS_DataForwardCommandProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRVCC_CSKeysRequestProtocolIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11586,11 +12777,11 @@ pub struct SRVCC_CSKeysRequestProtocolIEs_Entry {}
 )]
 pub struct SRVCC_CSKeysRequestProtocolIEs(pub Vec<SRVCC_CSKeysRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRVCC_CSKeysRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11601,7 +12792,7 @@ pub struct SRVCC_CSKeysRequestProtocolExtensions(
     pub Vec<SRVCC_CSKeysRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SRVCC_CSKeysResponseProtocolIEs_EntryValue {
     #[asn(key = 9)]
@@ -11614,16 +12805,18 @@ pub enum SRVCC_CSKeysResponseProtocolIEs_EntryValue {
     Id_SRVCC_Information(SRVCC_Information),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRVCC_CSKeysResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SRVCC_CSKeysResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11632,11 +12825,11 @@ pub struct SRVCC_CSKeysResponseProtocolIEs_Entry {
 )]
 pub struct SRVCC_CSKeysResponseProtocolIEs(pub Vec<SRVCC_CSKeysResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRVCC_CSKeysResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11647,7 +12840,7 @@ pub struct SRVCC_CSKeysResponseProtocolExtensions(
     pub Vec<SRVCC_CSKeysResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -11656,11 +12849,11 @@ pub struct SRVCC_CSKeysResponseProtocolExtensions(
 )]
 pub struct BIT_STRING_61(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SRVCC_InformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11669,7 +12862,7 @@ pub struct SRVCC_InformationIE_Extensions_Entry {}
 )]
 pub struct SRVCC_InformationIE_Extensions(pub Vec<SRVCC_InformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SecurityModeCommandProtocolIEs_EntryValue {
     #[asn(key = 11)]
@@ -11680,16 +12873,18 @@ pub enum SecurityModeCommandProtocolIEs_EntryValue {
     Id_KeyStatus(KeyStatus),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityModeCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SecurityModeCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11698,11 +12893,11 @@ pub struct SecurityModeCommandProtocolIEs_Entry {
 )]
 pub struct SecurityModeCommandProtocolIEs(pub Vec<SecurityModeCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityModeCommandProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11713,7 +12908,7 @@ pub struct SecurityModeCommandProtocolExtensions(
     pub Vec<SecurityModeCommandProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SecurityModeCompleteProtocolIEs_EntryValue {
     #[asn(key = 5)]
@@ -11724,16 +12919,18 @@ pub enum SecurityModeCompleteProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityModeCompleteProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SecurityModeCompleteProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11742,11 +12939,11 @@ pub struct SecurityModeCompleteProtocolIEs_Entry {
 )]
 pub struct SecurityModeCompleteProtocolIEs(pub Vec<SecurityModeCompleteProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityModeCompleteProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11757,7 +12954,7 @@ pub struct SecurityModeCompleteProtocolExtensions(
     pub Vec<SecurityModeCompleteProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SecurityModeRejectProtocolIEs_EntryValue {
     #[asn(key = 4)]
@@ -11766,16 +12963,18 @@ pub enum SecurityModeRejectProtocolIEs_EntryValue {
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityModeRejectProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SecurityModeRejectProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11784,11 +12983,11 @@ pub struct SecurityModeRejectProtocolIEs_Entry {
 )]
 pub struct SecurityModeRejectProtocolIEs(pub Vec<SecurityModeRejectProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityModeRejectProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11799,11 +12998,11 @@ pub struct SecurityModeRejectProtocolExtensions(
     pub Vec<SecurityModeRejectProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Shared_Network_InformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11814,23 +13013,25 @@ pub struct Shared_Network_InformationIE_Extensions(
     pub Vec<Shared_Network_InformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SourceRNC_IDIE_Extensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceRNC_IDIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: SourceRNC_IDIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11839,7 +13040,7 @@ pub struct SourceRNC_IDIE_Extensions_Entry {
 )]
 pub struct SourceRNC_IDIE_Extensions(pub Vec<SourceRNC_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SourceRNC_ToTargetRNC_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 237)]
@@ -11874,17 +13075,19 @@ pub enum SourceRNC_ToTargetRNC_TransparentContainerIE_Extensions_EntryExtensionV
     Id_d_RNTI_for_NoIuCSUP(D_RNTI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceRNC_ToTargetRNC_TransparentContainerIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         SourceRNC_ToTargetRNC_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11895,11 +13098,11 @@ pub struct SourceRNC_ToTargetRNC_TransparentContainerIE_Extensions(
     pub Vec<SourceRNC_ToTargetRNC_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceUTRANCellIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11908,7 +13111,7 @@ pub struct SourceUTRANCellIDIE_Extensions_Entry {}
 )]
 pub struct SourceUTRANCellIDIE_Extensions(pub Vec<SourceUTRANCellIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SuccessfulOutcomeValue {
     #[asn(key = 7)]
@@ -11953,11 +13156,11 @@ pub enum SuccessfulOutcomeValue {
     Id_enhancedRelocationComplete(EnhancedRelocationCompleteResponse),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11966,15 +13169,15 @@ pub struct TAIIE_Extensions_Entry {}
 )]
 pub struct TAIIE_Extensions(pub Vec<TAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct OCTET_STRING_62(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TMGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11983,11 +13186,11 @@ pub struct TMGIIE_Extensions_Entry {}
 )]
 pub struct TMGIIE_Extensions(pub Vec<TMGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TNLInformationEnhRelInfoReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11998,11 +13201,11 @@ pub struct TNLInformationEnhRelInfoReqIE_Extensions(
     pub Vec<TNLInformationEnhRelInfoReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TNLInformationEnhRelInfoResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12013,11 +13216,11 @@ pub struct TNLInformationEnhRelInfoResIE_Extensions(
     pub Vec<TNLInformationEnhRelInfoResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetENB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12026,23 +13229,25 @@ pub struct TargetENB_IDIE_Extensions_Entry {}
 )]
 pub struct TargetENB_IDIE_Extensions(pub Vec<TargetENB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TargetRNC_IDIE_Extensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetRNC_IDIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: TargetRNC_IDIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12051,7 +13256,7 @@ pub struct TargetRNC_IDIE_Extensions_Entry {
 )]
 pub struct TargetRNC_IDIE_Extensions(pub Vec<TargetRNC_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TargetRNC_ToSourceRNC_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 295)]
@@ -12060,17 +13265,19 @@ pub enum TargetRNC_ToSourceRNC_TransparentContainerIE_Extensions_EntryExtensionV
     ),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetRNC_ToSourceRNC_TransparentContainerIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         TargetRNC_ToSourceRNC_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12081,7 +13288,7 @@ pub struct TargetRNC_ToSourceRNC_TransparentContainerIE_Extensions(
     pub Vec<TargetRNC_ToSourceRNC_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TrCH_IDIE_Extensions_EntryExtensionValue {
     #[asn(key = 160)]
@@ -12090,16 +13297,18 @@ pub enum TrCH_IDIE_Extensions_EntryExtensionValue {
     Id_hS_DSCH_MAC_d_Flow_ID(HS_DSCH_MAC_d_Flow_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TrCH_IDIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: TrCH_IDIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12108,11 +13317,11 @@ pub struct TrCH_IDIE_Extensions_Entry {
 )]
 pub struct TrCH_IDIE_Extensions(pub Vec<TrCH_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12121,11 +13330,11 @@ pub struct TraceInformationIE_Extensions_Entry {}
 )]
 pub struct TraceInformationIE_Extensions(pub Vec<TraceInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TracePropagationParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12136,11 +13345,11 @@ pub struct TracePropagationParametersIE_Extensions(
     pub Vec<TracePropagationParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceRecordingSessionInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12151,11 +13360,11 @@ pub struct TraceRecordingSessionInformationIE_Extensions(
     pub Vec<TraceRecordingSessionInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TransportLayerInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12166,11 +13375,11 @@ pub struct TransportLayerInformationIE_Extensions(
     pub Vec<TransportLayerInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TunnelInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12179,7 +13388,7 @@ pub struct TunnelInformationIE_Extensions_Entry {}
 )]
 pub struct TunnelInformationIE_Extensions(pub Vec<TunnelInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -12188,11 +13397,11 @@ pub struct TunnelInformationIE_Extensions(pub Vec<TunnelInformationIE_Extensions
 )]
 pub struct OCTET_STRING_63(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_IsNotServedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12201,11 +13410,11 @@ pub struct UE_IsNotServedIE_Extensions_Entry {}
 )]
 pub struct UE_IsNotServedIE_Extensions(pub Vec<UE_IsNotServedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_IsServedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12214,11 +13423,11 @@ pub struct UE_IsServedIE_Extensions_Entry {}
 )]
 pub struct UE_IsServedIE_Extensions(pub Vec<UE_IsServedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UESBI_IuIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12227,23 +13436,25 @@ pub struct UESBI_IuIE_Extensions_Entry {}
 )]
 pub struct UESBI_IuIE_Extensions(pub Vec<UESBI_IuIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UESpecificInformationIndicationProtocolIEs_EntryValue {
     #[asn(key = 118)]
     Id_UESBI_Iu(UESBI_Iu),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UESpecificInformationIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UESpecificInformationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12254,11 +13465,11 @@ pub struct UESpecificInformationIndicationProtocolIEs(
     pub Vec<UESpecificInformationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UESpecificInformationIndicationProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12269,23 +13480,25 @@ pub struct UESpecificInformationIndicationProtocolExtensions(
     pub Vec<UESpecificInformationIndicationProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UPInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 269)]
     Id_TimingDifferenceULDL(TimingDifferenceULDL),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UPInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UPInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12294,11 +13507,11 @@ pub struct UPInformationIE_Extensions_Entry {
 )]
 pub struct UPInformationIE_Extensions(pub Vec<UPInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UTRAN_CellIDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12307,11 +13520,11 @@ pub struct UTRAN_CellIDIE_Extensions_Entry {}
 )]
 pub struct UTRAN_CellIDIE_Extensions(pub Vec<UTRAN_CellIDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRadioCapabilityMatchRequestProtocolIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12322,11 +13535,11 @@ pub struct UeRadioCapabilityMatchRequestProtocolIEs(
     pub Vec<UeRadioCapabilityMatchRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRadioCapabilityMatchRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12337,23 +13550,25 @@ pub struct UeRadioCapabilityMatchRequestProtocolExtensions(
     pub Vec<UeRadioCapabilityMatchRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UeRadioCapabilityMatchResponseProtocolIEs_EntryValue {
     #[asn(key = 258)]
     Id_VoiceSupportMatchIndicator(VoiceSupportMatchIndicator),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRadioCapabilityMatchResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UeRadioCapabilityMatchResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12364,11 +13579,11 @@ pub struct UeRadioCapabilityMatchResponseProtocolIEs(
     pub Vec<UeRadioCapabilityMatchResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRadioCapabilityMatchResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12379,7 +13594,7 @@ pub struct UeRadioCapabilityMatchResponseProtocolExtensions(
     pub Vec<UeRadioCapabilityMatchResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UeRegistrationQueryRequestProtocolIEs_EntryValue {
     #[asn(key = 79)]
@@ -12388,16 +13603,18 @@ pub enum UeRegistrationQueryRequestProtocolIEs_EntryValue {
     Id_PermanentNAS_UE_ID(PermanentNAS_UE_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRegistrationQueryRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UeRegistrationQueryRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12408,11 +13625,11 @@ pub struct UeRegistrationQueryRequestProtocolIEs(
     pub Vec<UeRegistrationQueryRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRegistrationQueryRequestProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12423,23 +13640,25 @@ pub struct UeRegistrationQueryRequestProtocolExtensions(
     pub Vec<UeRegistrationQueryRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UeRegistrationQueryResponseProtocolIEs_EntryValue {
     #[asn(key = 281)]
     Id_UERegistrationQueryResult(UERegistrationQueryResult),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRegistrationQueryResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UeRegistrationQueryResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12450,11 +13669,11 @@ pub struct UeRegistrationQueryResponseProtocolIEs(
     pub Vec<UeRegistrationQueryResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UeRegistrationQueryResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12465,11 +13684,11 @@ pub struct UeRegistrationQueryResponseProtocolExtensions(
     pub Vec<UeRegistrationQueryResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UnsuccessfulLinking_IEs_EntryIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12480,16 +13699,20 @@ pub struct UnsuccessfulLinking_IEs_EntryIE_Extensions(
     pub Vec<UnsuccessfulLinking_IEs_EntryIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UnsuccessfulLinking_IEs_Entry {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tmgi: TMGI,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UnsuccessfulLinking_IEs_EntryIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UnsuccessfulOutcomeValue {
     #[asn(key = 31)]
@@ -12516,7 +13739,7 @@ pub enum UnsuccessfulOutcomeValue {
     Id_enhancedRelocationComplete(EnhancedRelocationCompleteFailure),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkInformationExchangeFailureProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -12531,16 +13754,18 @@ pub enum UplinkInformationExchangeFailureProtocolIEs_EntryValue {
     Id_InformationExchangeID(InformationExchangeID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkInformationExchangeFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkInformationExchangeFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12551,11 +13776,11 @@ pub struct UplinkInformationExchangeFailureProtocolIEs(
     pub Vec<UplinkInformationExchangeFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkInformationExchangeFailureProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12566,7 +13791,7 @@ pub struct UplinkInformationExchangeFailureProtocolExtensions(
     pub Vec<UplinkInformationExchangeFailureProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkInformationExchangeRequestProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -12583,16 +13808,18 @@ pub enum UplinkInformationExchangeRequestProtocolIEs_EntryValue {
     Id_InformationTransferType(InformationTransferType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkInformationExchangeRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkInformationExchangeRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12603,23 +13830,25 @@ pub struct UplinkInformationExchangeRequestProtocolIEs(
     pub Vec<UplinkInformationExchangeRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkInformationExchangeRequestProtocolExtensions_EntryExtensionValue {
     #[asn(key = 171)]
     Id_ExtendedRNC_ID(ExtendedRNC_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkInformationExchangeRequestProtocolExtensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UplinkInformationExchangeRequestProtocolExtensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12630,7 +13859,7 @@ pub struct UplinkInformationExchangeRequestProtocolExtensions(
     pub Vec<UplinkInformationExchangeRequestProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkInformationExchangeResponseProtocolIEs_EntryValue {
     #[asn(key = 3)]
@@ -12645,16 +13874,18 @@ pub enum UplinkInformationExchangeResponseProtocolIEs_EntryValue {
     Id_InformationRequested(InformationRequested),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkInformationExchangeResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkInformationExchangeResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12665,11 +13896,11 @@ pub struct UplinkInformationExchangeResponseProtocolIEs(
     pub Vec<UplinkInformationExchangeResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkInformationExchangeResponseProtocolExtensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12680,11 +13911,11 @@ pub struct UplinkInformationExchangeResponseProtocolExtensions(
     pub Vec<UplinkInformationExchangeResponseProtocolExtensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserPlaneInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12693,7 +13924,7 @@ pub struct UserPlaneInformationIE_Extensions_Entry {}
 )]
 pub struct UserPlaneInformationIE_Extensions(pub Vec<UserPlaneInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct INTEGER_64(pub u8);
 
@@ -12710,4 +13941,7 @@ fn main() {
     let mut codec_data = AperCodecData::from_slice(&ranap_data);
     let ranap_pdu = RANAP_PDU::decode(&mut codec_data).unwrap();
     eprintln!("ranap_pdu: {:#?}", ranap_pdu);
+
+    let procedure_code = RANAP_PDU::peek_procedure_code(&ranap_data).unwrap();
+    assert_eq!(procedure_code.0, 20);
 }
@@ -1,9 +1,14 @@
 #![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::enum_variant_names,
+    clippy::upper_case_acronyms
+)]
 use asn1_codecs_derive::AperCodec;
 use bitvec::order::Msb0;
 use bitvec::vec::BitVec;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12,22 +17,29 @@ use bitvec::vec::BitVec;
 )]
 pub struct ActivatedCellsList(pub Vec<ActivatedCellsList_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ActivatedCellsList_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id: OCTET_STRING_2,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Additional_GUTI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub gummei: GUMMEI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m_tmsi: M_TMSI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Additional_GUTIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct AdditionalCSFallbackIndicator(pub u8);
 impl AdditionalCSFallbackIndicator {
@@ -35,11 +47,11 @@ impl AdditionalCSFallbackIndicator {
     pub const RESTRICTION: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "32", sz_ub = "32")]
 pub struct AdditionalRRMPriorityIndex(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct AerialUEsubscriptionInformation(pub u8);
 impl AerialUEsubscriptionInformation {
@@ -47,92 +59,113 @@ impl AerialUEsubscriptionInformation {
     pub const NOT_ALLOWED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AllocationAndRetentionPriority {
+    #[asn(tag = "[0] IMPLICIT")]
     pub priority_level: PriorityLevel,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pre_emption_capability: Pre_emptionCapability,
+    #[asn(tag = "[2] IMPLICIT")]
     pub pre_emption_vulnerability: Pre_emptionVulnerability,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AllocationAndRetentionPriorityIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum AreaScopeOfMDT {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellBased(CellBasedMDT),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TABased(TABasedMDT),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     PLMNWide(NULL_3),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[3] IMPLICIT")]
     TAIBased(TAIBasedMDT),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "3", extensible = true)]
 pub enum AreaScopeOfQMC {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellBased(CellBasedQMC),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TABased(TABasedQMC),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     TAIBased(TAIBasedQMC),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     PLMNAreaBased(PLMNAreaBasedQMC),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AssistanceDataForCECapableUEs {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_identifier_and_ce_level_for_ce_capable_u_es: CellIdentifierAndCELevelForCECapableUEs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AssistanceDataForCECapableUEsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct AssistanceDataForPaging {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub assistance_data_for_recommended_cells: Option<AssistanceDataForRecommendedCells>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub assistance_data_for_ce_capable_u_es: Option<AssistanceDataForCECapableUEs>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub paging_attempt_information: Option<PagingAttemptInformation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<AssistanceDataForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct AssistanceDataForRecommendedCells {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_cells_for_paging: RecommendedCellsForPaging,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<AssistanceDataForRecommendedCellsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "6")]
 pub struct BPLMNs(pub Vec<PLMNidentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct BearerType(pub u8);
 impl BearerType {
     pub const NON_IP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Bearers_SubjectToEarlyStatusTransfer_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub dlcount_pdcp_s_nlength: DLCOUNT_PDCP_SNlength,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Bearers_SubjectToEarlyStatusTransfer_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -143,19 +176,24 @@ pub struct Bearers_SubjectToEarlyStatusTransferList(
     pub Vec<Bearers_SubjectToEarlyStatusTransferList_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct Bearers_SubjectToStatusTransfer_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ul_coun_tvalue: COUNTvalue,
+    #[asn(tag = "[2] IMPLICIT")]
     pub dl_coun_tvalue: COUNTvalue,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub receive_statusof_ulpdcpsd_us: Option<ReceiveStatusofULPDCPSDUs>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<Bearers_SubjectToStatusTransfer_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -164,34 +202,37 @@ pub struct Bearers_SubjectToStatusTransfer_Item {
 )]
 pub struct Bearers_SubjectToStatusTransferList(pub Vec<Bearers_SubjectToStatusTransferList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "10000000000")]
 pub struct BitRate(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct BluetoothMeasConfig(pub u8);
 impl BluetoothMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct BluetoothMeasConfigNameList(pub Vec<BluetoothName>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct BluetoothMeasurementConfiguration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub bluetooth_meas_config: BluetoothMeasConfig,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub bluetooth_meas_config_name_list: Option<BluetoothMeasConfigNameList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub bt_rssi: Option<ENUMERATED_4>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<BluetoothMeasurementConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -200,29 +241,33 @@ pub struct BluetoothMeasurementConfiguration {
 )]
 pub struct BluetoothName(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum BroadcastCancelledAreaList {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellID_Cancelled(CellID_Cancelled),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TAI_Cancelled(TAI_Cancelled),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     EmergencyAreaID_Cancelled(EmergencyAreaID_Cancelled),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum BroadcastCompletedAreaList {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellID_Broadcast(CellID_Broadcast),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TAI_Broadcast(TAI_Broadcast),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     EmergencyAreaID_Broadcast(EmergencyAreaID_Broadcast),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CE_ModeBRestricted(pub u8);
 impl CE_ModeBRestricted {
@@ -230,34 +275,39 @@ impl CE_ModeBRestricted {
     pub const NOT_RESTRICTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CE_mode_B_SupportIndicator(pub u8);
 impl CE_mode_B_SupportIndicator {
     pub const SUPPORTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct CELevel(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct CGI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub lac: LAC,
+    #[asn(tag = "[2] IMPLICIT")]
     pub ci: CI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub rac: Option<RAC>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<CGIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct CI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct CNDomain(pub u8);
 impl CNDomain {
@@ -265,65 +315,83 @@ impl CNDomain {
     pub const CS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CNType(pub u8);
 impl CNType {
     pub const FIVE_GC_FORBIDDEN: u8 = 0u8;
+    pub const EPC_FORBIDDDEN: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct CNTypeRestrictions(pub Vec<CNTypeRestrictions_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CNTypeRestrictions_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cn_type: CNType,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CNTypeRestrictions_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct COUNTValueExtended {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdcp_sn_extended: PDCP_SNExtended,
+    #[asn(tag = "[1] IMPLICIT")]
     pub hfn_modified: HFNModified,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<COUNTValueExtendedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct COUNTvalue {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdcp_sn: PDCP_SN,
+    #[asn(tag = "[1] IMPLICIT")]
     pub hfn: HFN,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<COUNTvalueIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct COUNTvaluePDCP_SNlength18 {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pdcp_s_nlength18: PDCP_SNlength18,
+    #[asn(tag = "[1] IMPLICIT")]
     pub hf_nfor_pdcp_s_nlength18: HFNforPDCP_SNlength18,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<COUNTvaluePDCP_SNlength18IE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CSFallbackIndicator(pub u8);
 impl CSFallbackIndicator {
     pub const CS_FALLBACK_REQUIRED: u8 = 0u8;
+    pub const CS_FALLBACK_HIGH_PRIORITY: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "27", sz_ub = "27")]
 pub struct CSG_Id(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -332,28 +400,35 @@ pub struct CSG_Id(pub BitVec<u8, Msb0>);
 )]
 pub struct CSG_IdList(pub Vec<CSG_IdList_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CSG_IdList_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub csg_id: CSG_Id,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CSG_IdList_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct CSGMembershipInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub csg_membership_status: CSGMembershipStatus,
+    #[asn(tag = "[1] IMPLICIT")]
     pub csg_id: CSG_Id,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub cell_access_mode: Option<CellAccessMode>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub plm_nidentity: Option<PLMNidentity>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<CSGMembershipInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct CSGMembershipStatus(pub u8);
 impl CSGMembershipStatus {
@@ -361,7 +436,7 @@ impl CSGMembershipStatus {
     pub const NOT_MEMBER: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -370,16 +445,20 @@ impl CSGMembershipStatus {
 )]
 pub struct CancelledCellinEAI(pub Vec<CancelledCellinEAI_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellinEAI_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ecgi: EUTRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CancelledCellinEAI_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -388,46 +467,56 @@ pub struct CancelledCellinEAI_Item {
 )]
 pub struct CancelledCellinTAI(pub Vec<CancelledCellinTAI_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CancelledCellinTAI_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ecgi: EUTRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CancelledCellinTAI_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct CandidateCellList(pub Vec<IRAT_Cell_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct CandidatePCI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pci: INTEGER_5,
+    #[asn(tag = "[1] IMPLICIT")]
     pub earfcn: OCTET_STRING_6,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct CandidatePCIList(pub Vec<CandidatePCI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "4", extensible = true)]
 pub enum Cause {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RadioNetwork(CauseRadioNetwork),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Transport(CauseTransport),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     Nas(CauseNas),
-    #[asn(key = 3, extended = false)]
+    #[asn(key = 3, extended = false, tag = "[3] IMPLICIT")]
     Protocol(CauseProtocol),
-    #[asn(key = 4, extended = false)]
+    #[asn(key = 4, extended = false, tag = "[4] IMPLICIT")]
     Misc(CauseMisc),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct CauseMisc(pub u8);
 impl CauseMisc {
@@ -439,7 +528,7 @@ impl CauseMisc {
     pub const UNKNOWN_PLMN: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct CauseNas(pub u8);
 impl CauseNas {
@@ -447,9 +536,10 @@ impl CauseNas {
     pub const AUTHENTICATION_FAILURE: u8 = 1u8;
     pub const DETACH: u8 = 2u8;
     pub const UNSPECIFIED: u8 = 3u8;
+    pub const CSG_SUBSCRIPTION_EXPIRY: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "6")]
 pub struct CauseProtocol(pub u8);
 impl CauseProtocol {
@@ -462,7 +552,7 @@ impl CauseProtocol {
     pub const UNSPECIFIED: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "35")]
 pub struct CauseRadioNetwork(pub u8);
 impl CauseRadioNetwork {
@@ -502,9 +592,15 @@ impl CauseRadioNetwork {
     pub const S1_INTRA_SYSTEM_HANDOVER_TRIGGERED: u8 = 33u8;
     pub const S1_INTER_SYSTEM_HANDOVER_TRIGGERED: u8 = 34u8;
     pub const X2_HANDOVER_TRIGGERED: u8 = 35u8;
+    pub const REDIRECTION_TOWARDS_1X_RTT: u8 = 36u8;
+    pub const NOT_SUPPORTED_QCI_VALUE: u8 = 37u8;
+    pub const INVALID_CSG_ID: u8 = 38u8;
+    pub const RELEASE_DUE_TO_PRE_EMPTION: u8 = 39u8;
+    pub const N26_INTERFACE_NOT_AVAILABLE: u8 = 40u8;
+    pub const INSUFFICIENT_UE_CAPABILITIES: u8 = 41u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct CauseTransport(pub u8);
 impl CauseTransport {
@@ -512,14 +608,14 @@ impl CauseTransport {
     pub const UNSPECIFIED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Cdma2000HORequiredIndication(pub u8);
 impl Cdma2000HORequiredIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct Cdma2000HOStatus(pub u8);
 impl Cdma2000HOStatus {
@@ -527,37 +623,42 @@ impl Cdma2000HOStatus {
     pub const H_O_FAILURE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Cdma2000OneXMEID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Cdma2000OneXMSI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Cdma2000OneXPilot(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Cdma2000OneXRAND(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Cdma2000OneXSRVCCInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cdma2000_one_xmeid: Cdma2000OneXMEID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cdma2000_one_xmsi: Cdma2000OneXMSI,
+    #[asn(tag = "[2] IMPLICIT")]
     pub cdma2000_one_x_pilot: Cdma2000OneXPilot,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Cdma2000OneXSRVCCInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Cdma2000PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct Cdma2000RATType(pub u8);
 impl Cdma2000RATType {
@@ -565,11 +666,11 @@ impl Cdma2000RATType {
     pub const ONEX_RTT: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Cdma2000SectorID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct Cell_Size(pub u8);
 impl Cell_Size {
@@ -579,14 +680,14 @@ impl Cell_Size {
     pub const LARGE: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct CellAccessMode(pub u8);
 impl CellAccessMode {
     pub const HYBRID: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct CellActivationCause(pub u8);
 impl CellActivationCause {
@@ -595,37 +696,49 @@ impl CellActivationCause {
     pub const UNSPECIFIED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellActivationRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cells_to_activate_list: CellsToActivateList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub minimum_activation_time: Option<INTEGER_7>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct CellActivationResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub activated_cells_list: ActivatedCellsList,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellBasedMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id_listfor_mdt: CellIdListforMDT,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellBasedMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellBasedQMC {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id_listfor_qmc: CellIdListforQMC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellBasedQMCIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -634,15 +747,18 @@ pub struct CellBasedQMC {
 )]
 pub struct CellID_Broadcast(pub Vec<CellID_Broadcast_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellID_Broadcast_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ecgi: EUTRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellID_Broadcast_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -651,37 +767,45 @@ pub struct CellID_Broadcast_Item {
 )]
 pub struct CellID_Cancelled(pub Vec<CellID_Cancelled_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellID_Cancelled_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ecgi: EUTRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub number_of_broadcasts: NumberOfBroadcasts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellID_Cancelled_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CellIdListforMDT(pub Vec<EUTRAN_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct CellIdListforQMC(pub Vec<EUTRAN_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellIdentifierAndCELevelForCECapableUEs {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_cell_id: EUTRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ce_level: CELevel,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellIdentifierAndCELevelForCECapableUEsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "28", sz_ub = "28")]
 pub struct CellIdentity(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct CellLoadReportingCause(pub u8);
 impl CellLoadReportingCause {
@@ -690,26 +814,31 @@ impl CellLoadReportingCause {
     pub const UNSPECIFIED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum CellLoadReportingResponse {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     EUTRAN(EUTRANcellLoadReportingResponse),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     UTRAN(OCTET_STRING_8),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     GERAN(OCTET_STRING_9),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[3] IMPLICIT")]
     EHRPD(EHRPDSectorLoadReportingResponse),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct CellStateIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub notification_cell_list: NotificationCellList,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct CellStateIndicationCause(pub u8);
 impl CellStateIndicationCause {
@@ -718,21 +847,27 @@ impl CellStateIndicationCause {
     pub const UNSPECIFIED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct CellTrafficTrace {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: CellTrafficTraceProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CellType {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_size: Cell_Size,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CellTypeIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -741,13 +876,16 @@ pub struct CellType {
 )]
 pub struct CellsToActivateList(pub Vec<CellsToActivateList_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct CellsToActivateList_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id: OCTET_STRING_10,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -756,15 +894,18 @@ pub struct CellsToActivateList_Item {
 )]
 pub struct CompletedCellinEAI(pub Vec<CompletedCellinEAI_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellinEAI_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ecgi: EUTRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CompletedCellinEAI_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -773,35 +914,42 @@ pub struct CompletedCellinEAI_Item {
 )]
 pub struct CompletedCellinTAI(pub Vec<CompletedCellinTAI_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CompletedCellinTAI_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ecgi: EUTRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CompletedCellinTAI_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct CompositeAvailableCapacityGroup(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "0")]
 pub struct ConcurrentWarningMessageIndicator(pub u8);
 impl ConcurrentWarningMessageIndicator {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ConnectedengNBItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub en_g_nb_id: En_gNB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub supported_t_as: SupportedTAs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ConnectedengNBItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -810,33 +958,40 @@ pub struct ConnectedengNBItem {
 )]
 pub struct ConnectedengNBList(pub Vec<ConnectedengNBItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ConnectionEstablishmentIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ConnectionEstablishmentIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ContextatSource {
+    #[asn(tag = "[0] EXPLICIT")]
     pub source_ng_ran_node_id: Global_RAN_NODE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ran_ue_ngap_id: RAN_UE_NGAP_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ContextatSourceIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct Correlation_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Coverage_Level(pub u8);
 impl Coverage_Level {
     pub const EXTENDEDCOVERAGE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Criticality(pub u8);
 impl Criticality {
@@ -845,32 +1000,39 @@ impl Criticality {
     pub const NOTIFY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct CriticalityDiagnostics {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub procedure_code: Option<ProcedureCode>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub triggering_message: Option<TriggeringMessage>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub procedure_criticality: Option<Criticality>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub i_es_criticality_diagnostics: Option<CriticalityDiagnostics_IE_List>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<CriticalityDiagnosticsIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct CriticalityDiagnostics_IE_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ie_criticality: Criticality,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ie_id: ProtocolIE_ID,
+    #[asn(tag = "[2] IMPLICIT")]
     pub type_of_error: TypeOfError,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<CriticalityDiagnostics_IE_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -879,32 +1041,42 @@ pub struct CriticalityDiagnostics_IE_Item {
 )]
 pub struct CriticalityDiagnostics_IE_List(pub Vec<CriticalityDiagnostics_IE_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSRequestInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub daps_indicator: ENUMERATED_11,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DAPSRequestInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSResponseInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dapsresponseindicator: ENUMERATED_12,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DAPSResponseInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DAPSResponseInfoItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub daps_response_info: DAPSResponseInfo,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DAPSResponseInfoItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -913,122 +1085,147 @@ pub struct DAPSResponseInfoItem {
 )]
 pub struct DAPSResponseInfoList(pub Vec<DAPSResponseInfoList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct DCN_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct DL_CP_SecurityInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_nas_mac: DL_NAS_MAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<DL_CP_SecurityInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DL_Forwarding(pub u8);
 impl DL_Forwarding {
     pub const D_L_FORWARDING_PROPOSED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct DL_NAS_MAC(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum DLCOUNT_PDCP_SNlength {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     DLCOUNTValuePDCP_SNlength12(COUNTvalue),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     DLCOUNTValuePDCP_SNlength15(COUNTValueExtended),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     DLCOUNTValuePDCP_SNlength18(COUNTvaluePDCP_SNlength18),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct DLNASPDUDeliveryAckRequest(pub u8);
 impl DLNASPDUDeliveryAckRequest {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Data_Forwarding_Not_Possible(pub u8);
 impl Data_Forwarding_Not_Possible {
     pub const DATA_FORWARDING_NOT_POSSIBLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct DataCodingScheme(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "4095", extensible = true)]
 pub struct DataSize(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DeactivateTrace {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DeactivateTraceProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Direct_Forwarding_Path_Availability(pub u8);
 impl Direct_Forwarding_Path_Availability {
     pub const DIRECT_PATH_AVAILABLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkNASTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkNASTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkNonUEAssociatedLPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkNonUEAssociatedLPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkS1cdma2000tunnelling {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkS1cdma2000tunnellingProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct DownlinkUEAssociatedLPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: DownlinkUEAssociatedLPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15", extensible = true)]
 pub struct E_RAB_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct E_RABAdmittedItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub dl_transport_layer_address: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub dl_g_tp_teid: Option<GTP_TEID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub ul_transport_layer_address: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 3)]
     pub ul_gtp_teid: Option<GTP_TEID>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<E_RABAdmittedItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1037,41 +1234,52 @@ pub struct E_RABAdmittedItem {
 )]
 pub struct E_RABAdmittedList(pub Vec<E_RABAdmittedList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct E_RABDataForwardingItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_transport_layer_address: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub dl_g_tp_teid: Option<GTP_TEID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ul_transport_layer_address: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ul_gtp_teid: Option<GTP_TEID>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<E_RABDataForwardingItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABFailedToResumeItemResumeReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABFailedToResumeItemResumeReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABFailedToResumeItemResumeRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABFailedToResumeItemResumeResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1080,7 +1288,7 @@ pub struct E_RABFailedToResumeItemResumeRes {
 )]
 pub struct E_RABFailedToResumeListResumeReq(pub Vec<E_RABFailedToResumeListResumeReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1089,16 +1297,20 @@ pub struct E_RABFailedToResumeListResumeReq(pub Vec<E_RABFailedToResumeListResum
 )]
 pub struct E_RABFailedToResumeListResumeRes(pub Vec<E_RABFailedToResumeListResumeRes_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABFailedToSetupItemHOReqAck {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABFailedToSetupItemHOReqAckIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1107,7 +1319,7 @@ pub struct E_RABFailedToSetupItemHOReqAck {
 )]
 pub struct E_RABFailedtoSetupListHOReqAck(pub Vec<E_RABFailedtoSetupListHOReqAck_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1116,37 +1328,48 @@ pub struct E_RABFailedtoSetupListHOReqAck(pub Vec<E_RABFailedtoSetupListHOReqAck
 )]
 pub struct E_RABInformationList(pub Vec<E_RABInformationList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct E_RABInformationListItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub dl_forwarding: Option<DL_Forwarding>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<E_RABInformationListItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] EXPLICIT")]
     pub cause: Cause,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct E_RABLevelQoSParameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub qci: QCI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub allocation_retention_priority: AllocationAndRetentionPriority,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub gbr_qos_information: Option<GBR_QosInformation>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<E_RABLevelQoSParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1155,35 +1378,47 @@ pub struct E_RABLevelQoSParameters {
 )]
 pub struct E_RABList(pub Vec<E_RABList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABModificationConfirm {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABModificationConfirmProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABModificationIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABModificationIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABModifyItemBearerModConf {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABModifyItemBearerModConfIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABModifyItemBearerModRes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABModifyItemBearerModResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1192,7 +1427,7 @@ pub struct E_RABModifyItemBearerModRes {
 )]
 pub struct E_RABModifyListBearerModConf(pub Vec<E_RABModifyListBearerModConf_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1201,29 +1436,40 @@ pub struct E_RABModifyListBearerModConf(pub Vec<E_RABModifyListBearerModConf_Ent
 )]
 pub struct E_RABModifyListBearerModRes(pub Vec<E_RABModifyListBearerModRes_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABModifyRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABModifyRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABModifyResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABModifyResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABNotToBeModifiedItemBearerModInd {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub dl_gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABNotToBeModifiedItemBearerModIndIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1234,27 +1480,36 @@ pub struct E_RABNotToBeModifiedListBearerModInd(
     pub Vec<E_RABNotToBeModifiedListBearerModInd_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABReleaseCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABReleaseCommandProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABReleaseIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABReleaseIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABReleaseItemBearerRelComp {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABReleaseItemBearerRelCompIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1263,33 +1518,46 @@ pub struct E_RABReleaseItemBearerRelComp {
 )]
 pub struct E_RABReleaseListBearerRelComp(pub Vec<E_RABReleaseListBearerRelComp_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABReleaseResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABReleaseResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABSetupItemBearerSURes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABSetupItemBearerSUResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABSetupItemCtxtSURes {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABSetupItemCtxtSUResIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1298,7 +1566,7 @@ pub struct E_RABSetupItemCtxtSURes {
 )]
 pub struct E_RABSetupListBearerSURes(pub Vec<E_RABSetupListBearerSURes_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1307,19 +1575,25 @@ pub struct E_RABSetupListBearerSURes(pub Vec<E_RABSetupListBearerSURes_Entry>);
 )]
 pub struct E_RABSetupListCtxtSURes(pub Vec<E_RABSetupListCtxtSURes_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABSetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABSetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct E_RABSetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: E_RABSetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1328,27 +1602,37 @@ pub struct E_RABSetupResponse {
 )]
 pub struct E_RABSubjecttoDataForwardingList(pub Vec<E_RABSubjecttoDataForwardingList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABToBeModifiedItemBearerModInd {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub dl_gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABToBeModifiedItemBearerModIndIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABToBeModifiedItemBearerModReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub e_rab_level_qo_s_parameters: E_RABLevelQoSParameters,
+    #[asn(tag = "[2] IMPLICIT")]
     pub nas_pdu: NAS_PDU,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABToBeModifiedItemBearerModReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1357,7 +1641,7 @@ pub struct E_RABToBeModifiedItemBearerModReq {
 )]
 pub struct E_RABToBeModifiedListBearerModInd(pub Vec<E_RABToBeModifiedListBearerModInd_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1366,43 +1650,62 @@ pub struct E_RABToBeModifiedListBearerModInd(pub Vec<E_RABToBeModifiedListBearer
 )]
 pub struct E_RABToBeModifiedListBearerModReq(pub Vec<E_RABToBeModifiedListBearerModReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABToBeSetupItemBearerSUReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub e_ra_blevel_qo_s_parameters: E_RABLevelQoSParameters,
+    #[asn(tag = "[2] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[3] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
+    #[asn(tag = "[4] IMPLICIT")]
     pub nas_pdu: NAS_PDU,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABToBeSetupItemBearerSUReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct E_RABToBeSetupItemCtxtSUReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub e_ra_blevel_qo_s_parameters: E_RABLevelQoSParameters,
+    #[asn(tag = "[2] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[3] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub nas_pdu: Option<NAS_PDU>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<E_RABToBeSetupItemCtxtSUReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABToBeSetupItemHOReq {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
+    #[asn(tag = "[3] IMPLICIT")]
     pub e_ra_blevel_qos_parameters: E_RABLevelQoSParameters,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABToBeSetupItemHOReqIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1411,7 +1714,7 @@ pub struct E_RABToBeSetupItemHOReq {
 )]
 pub struct E_RABToBeSetupListBearerSUReq(pub Vec<E_RABToBeSetupListBearerSUReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1420,7 +1723,7 @@ pub struct E_RABToBeSetupListBearerSUReq(pub Vec<E_RABToBeSetupListBearerSUReq_E
 )]
 pub struct E_RABToBeSetupListCtxtSUReq(pub Vec<E_RABToBeSetupListCtxtSUReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1429,17 +1732,22 @@ pub struct E_RABToBeSetupListCtxtSUReq(pub Vec<E_RABToBeSetupListCtxtSUReq_Entry
 )]
 pub struct E_RABToBeSetupListHOReq(pub Vec<E_RABToBeSetupListHOReq_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABToBeSwitchedDLItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABToBeSwitchedDLItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1448,17 +1756,22 @@ pub struct E_RABToBeSwitchedDLItem {
 )]
 pub struct E_RABToBeSwitchedDLList(pub Vec<E_RABToBeSwitchedDLList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABToBeSwitchedULItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[2] IMPLICIT")]
     pub gtp_teid: GTP_TEID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABToBeSwitchedULItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1467,30 +1780,36 @@ pub struct E_RABToBeSwitchedULItem {
 )]
 pub struct E_RABToBeSwitchedULList(pub Vec<E_RABToBeSwitchedULList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct E_RABUsageReportItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub start_timestamp: OCTET_STRING_13,
+    #[asn(tag = "[1] IMPLICIT")]
     pub end_timestamp: OCTET_STRING_14,
+    #[asn(tag = "[2] IMPLICIT")]
     pub usage_count_ul: INTEGER_15,
+    #[asn(tag = "[3] IMPLICIT")]
     pub usage_count_dl: INTEGER_16,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<E_RABUsageReportItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct E_RABUsageReportList(pub Vec<E_RABUsageReportList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct E_UTRAN_Trace_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "262143", extensible = true)]
 pub struct EARFCN(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1499,7 +1818,7 @@ pub struct EARFCN(pub u32);
 )]
 pub struct ECGI_List(pub Vec<EUTRAN_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1508,7 +1827,7 @@ pub struct ECGI_List(pub Vec<EUTRAN_CGI>);
 )]
 pub struct ECGIList(pub Vec<EUTRAN_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1517,14 +1836,14 @@ pub struct ECGIList(pub Vec<EUTRAN_CGI>);
 )]
 pub struct ECGIListForRestart(pub Vec<EUTRAN_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct EDT_Session(pub u8);
 impl EDT_Session {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -1533,206 +1852,274 @@ impl EDT_Session {
 )]
 pub struct EHRPD_Sector_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "100")]
 pub struct EHRPDCapacityValue(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct EHRPDCompositeAvailableCapacity {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ehrpd_sector_capacity_class_value: EHRPDSectorCapacityClassValue,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ehrpd_capacity_value: EHRPDCapacityValue,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct EHRPDMultiSectorLoadReportingResponseItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ehrpd_sector_id: EHRPD_Sector_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ehrpd_sector_load_reporting_response: EHRPDSectorLoadReportingResponse,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "100", extensible = true)]
 pub struct EHRPDSectorCapacityClassValue(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct EHRPDSectorLoadReportingResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dl_ehrpd_composite_available_capacity: EHRPDCompositeAvailableCapacity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ul_ehrpd_composite_available_capacity: EHRPDCompositeAvailableCapacity,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct EN_DCSONConfigurationTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
     pub transfertype: EN_DCSONTransferType,
+    #[asn(tag = "[1] EXPLICIT")]
     pub son_information: SONInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub x2tnl_config_info: Option<X2TNLConfigurationInfo>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<EN_DCSONConfigurationTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum EN_DCSONTransferType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Request(EN_DCTransferTypeRequest),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Reply(EN_DCTransferTypeReply),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EN_DCSONeNBIdentification {
+    #[asn(tag = "[0] IMPLICIT")]
     pub globale_nbid: Global_ENB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EN_DCSONeNBIdentificationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EN_DCSONengNBIdentification {
+    #[asn(tag = "[0] IMPLICIT")]
     pub globaleng_nbid: Global_en_gNB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EN_DCSONengNBIdentificationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EN_DCTransferTypeReply {
+    #[asn(tag = "[0] IMPLICIT")]
     pub sourceeng_nb: EN_DCSONengNBIdentification,
+    #[asn(tag = "[1] IMPLICIT")]
     pub targete_nb: EN_DCSONeNBIdentification,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EN_DCTransferTypeReplyIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct EN_DCTransferTypeRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub sourcee_nb: EN_DCSONeNBIdentification,
+    #[asn(tag = "[1] IMPLICIT")]
     pub targeteng_nb: EN_DCSONengNBIdentification,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub targete_nb: Option<EN_DCSONeNBIdentification>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub associated_tai: Option<TAI>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub broadcast5_gstai: Option<FiveGSTAI>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<EN_DCTransferTypeRequestIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ENB_EarlyStatusTransfer_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub bearers_subject_to_early_status_transfer_list: Bearers_SubjectToEarlyStatusTransferList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ENB_EarlyStatusTransfer_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum ENB_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     MacroENB_ID(BIT_STRING_17),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     HomeENB_ID(BIT_STRING_18),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[2] IMPLICIT")]
     Short_macroENB_ID(BIT_STRING_19),
-    #[asn(key = 1, extended = true)]
+    #[asn(key = 1, extended = true, tag = "[3] IMPLICIT")]
     Long_macroENB_ID(BIT_STRING_20),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ENB_StatusTransfer_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub bearers_subject_to_status_transfer_list: Bearers_SubjectToStatusTransferList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ENB_StatusTransfer_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "16777215")]
 pub struct ENB_UE_S1AP_ID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBCPRelocationIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBCPRelocationIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBConfigurationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBConfigurationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBConfigurationUpdate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBConfigurationUpdateProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBConfigurationUpdateAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBConfigurationUpdateAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBConfigurationUpdateFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBConfigurationUpdateFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBDirectInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBDirectInformationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBEarlyStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBEarlyStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct ENBIndirectX2TransportLayerAddresses(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ENBStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ENBStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct ENBX2ExtTLA {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub i_psec_tla: Option<TransportLayerAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub gtptl_aa: Option<ENBX2GTPTLAs>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<ENBX2ExtTLAIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ENBX2ExtTLAs(pub Vec<ENBX2ExtTLA>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ENBX2GTPTLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "2")]
 pub struct ENBX2TLAs(pub Vec<TransportLayerAddress>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "PrintableString",
     sz_extensible = true,
@@ -1741,41 +2128,52 @@ pub struct ENBX2TLAs(pub Vec<TransportLayerAddress>);
 )]
 pub struct ENBname(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "15")]
 pub struct EPLMNs(pub Vec<PLMNidentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EUTRAN_CGI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cell_id: CellIdentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EUTRAN_CGIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct EUTRANResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id: OCTET_STRING_21,
+    #[asn(tag = "[1] IMPLICIT")]
     pub eutra_ncell_load_reporting_response: EUTRANcellLoadReportingResponse,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "2047")]
 pub struct EUTRANRoundTripDelayEstimationInfo(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct EUTRANcellLoadReportingResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub composite_available_capacity_group: CompositeAvailableCapacityGroup,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct EmergencyAreaID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1784,16 +2182,20 @@ pub struct EmergencyAreaID(pub Vec<u8>);
 )]
 pub struct EmergencyAreaID_Broadcast(pub Vec<EmergencyAreaID_Broadcast_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaID_Broadcast_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub emergency_area_id: EmergencyAreaID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub completed_cellin_eai: CompletedCellinEAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EmergencyAreaID_Broadcast_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1802,16 +2204,20 @@ pub struct EmergencyAreaID_Broadcast_Item {
 )]
 pub struct EmergencyAreaID_Cancelled(pub Vec<EmergencyAreaID_Cancelled_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EmergencyAreaID_Cancelled_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub emergency_area_id: EmergencyAreaID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cancelled_cellin_eai: CancelledCellinEAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<EmergencyAreaID_Cancelled_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1820,7 +2226,7 @@ pub struct EmergencyAreaID_Cancelled_Item {
 )]
 pub struct EmergencyAreaIDList(pub Vec<EmergencyAreaID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -1829,22 +2235,22 @@ pub struct EmergencyAreaIDList(pub Vec<EmergencyAreaID>);
 )]
 pub struct EmergencyAreaIDListForRestart(pub Vec<EmergencyAreaID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct EmergencyIndicator(pub u8);
 impl EmergencyIndicator {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "22", sz_ub = "32")]
 pub struct En_gNB_ID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct EncryptionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct EndIndication(pub u8);
 impl EndIndication {
@@ -1852,41 +2258,50 @@ impl EndIndication {
     pub const FURTHER_DATA_EXISTS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct EnhancedCoverageRestricted(pub u8);
 impl EnhancedCoverageRestricted {
     pub const RESTRICTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ErrorIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ErrorIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct Ethernet_Type(pub u8);
 impl Ethernet_Type {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct EventTriggeredCellLoadReportingRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub number_of_measurement_reporting_levels: NumberOfMeasurementReportingLevels,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct EventTriggeredCellLoadReportingResponse {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cell_load_reporting_response: CellLoadReportingResponse,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub overload_flag: Option<OverloadFlag>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct EventType(pub u8);
 impl EventType {
@@ -1895,11 +2310,11 @@ impl EventType {
     pub const STOP_CHANGE_OF_SERVE_CELL: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "30", extensible = true)]
 pub struct ExpectedActivityPeriod(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "6")]
 pub struct ExpectedHOInterval(pub u8);
 impl ExpectedHOInterval {
@@ -1912,39 +2327,43 @@ impl ExpectedHOInterval {
     pub const LONG_TIME: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "30", extensible = true)]
 pub struct ExpectedIdlePeriod(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct ExpectedUEActivityBehaviour {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub expected_activity_period: Option<ExpectedActivityPeriod>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub expected_idle_period: Option<ExpectedIdlePeriod>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub sourceof_ue_activity_behaviour_information: Option<SourceOfUEActivityBehaviourInformation>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<ExpectedUEActivityBehaviourIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct ExpectedUEBehaviour {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub expected_activity: Option<ExpectedUEActivityBehaviour>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub expected_ho_interval: Option<ExpectedHOInterval>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<ExpectedUEBehaviourIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "14", sz_ub = "14")]
 pub struct Extended_UEIdentityIndexValue(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "INTEGER",
     lb = "10000000001",
@@ -1953,22 +2372,24 @@ pub struct Extended_UEIdentityIndexValue(pub BitVec<u8, Msb0>);
 )]
 pub struct ExtendedBitRate(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "4096", ub = "65535")]
 pub struct ExtendedRNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "4096", ub = "131071")]
 pub struct ExtendedRepetitionPeriod(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum FailureEventReport {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TooEarlyInterRATHOReportFromEUTRAN(TooEarlyInterRATHOReportReportFromEUTRAN),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct FailureEventReportingCause(pub u8);
 impl FailureEventReportingCause {
@@ -1977,24 +2398,28 @@ impl FailureEventReportingCause {
     pub const UNSPECIFIED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct FiveGSTAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct FiveGSTAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub five_gstac: FiveGSTAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<FiveGSTAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255", extensible = true)]
 pub struct FiveQI(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct ForbiddenInterRATs(pub u8);
 impl ForbiddenInterRATs {
@@ -2002,9 +2427,11 @@ impl ForbiddenInterRATs {
     pub const GERAN: u8 = 1u8;
     pub const UTRAN: u8 = 2u8;
     pub const CDMA2000: u8 = 3u8;
+    pub const GERANANDUTRAN: u8 = 4u8;
+    pub const CDMA2000ANDUTRAN: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2013,20 +2440,24 @@ impl ForbiddenInterRATs {
 )]
 pub struct ForbiddenLACs(pub Vec<LAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ForbiddenLAs(pub Vec<ForbiddenLAs_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ForbiddenLAs_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub forbidden_la_cs: ForbiddenLACs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ForbiddenLAs_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2035,74 +2466,99 @@ pub struct ForbiddenLAs_Item {
 )]
 pub struct ForbiddenTACs(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct ForbiddenTAs(pub Vec<ForbiddenTAs_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ForbiddenTAs_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub forbidden_ta_cs: ForbiddenTACs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ForbiddenTAs_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GBR_QosInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_maximum_bitrate_dl: BitRate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub e_rab_maximum_bitrate_ul: BitRate,
+    #[asn(tag = "[2] IMPLICIT")]
     pub e_rab_guaranteed_bitrate_dl: BitRate,
+    #[asn(tag = "[3] IMPLICIT")]
     pub e_rab_guaranteed_bitrate_ul: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GBR_QosInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GERAN_Cell_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lai: LAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub rac: RAC,
+    #[asn(tag = "[2] IMPLICIT")]
     pub ci: CI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GERAN_Cell_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GNB {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_g_nb_id: Global_GNB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GNBIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "22", sz_ub = "32")]
 pub struct GNB_ID(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum GNB_Identity {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GNB_ID(GNB_ID),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct GTP_TEID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct GUMMEI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub mme_group_id: MME_Group_ID,
+    #[asn(tag = "[2] IMPLICIT")]
     pub mme_code: MME_Code,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<GUMMEIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2111,82 +2567,104 @@ pub struct GUMMEI {
 )]
 pub struct GUMMEIList(pub Vec<GUMMEI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct GUMMEIType(pub u8);
 impl GUMMEIType {
     pub const NATIVE: u8 = 0u8;
     pub const MAPPED: u8 = 1u8;
+    pub const MAPPED_FROM5_G: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct GWContextReleaseIndication(pub u8);
 impl GWContextReleaseIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Global_ENB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub enb_id: ENB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Global_ENB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Global_GNB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNidentity,
+    #[asn(tag = "[1] EXPLICIT")]
     pub gnb_id: GNB_Identity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Global_GNB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum Global_RAN_NODE_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GNB(GNB),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Ng_eNB(NG_eNB),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct Global_en_gNB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub en_g_nb_id: En_gNB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<Global_en_gNB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1048575")]
 pub struct HFN(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "131071")]
 pub struct HFNModified(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "16383")]
 pub struct HFNforPDCP_SNlength18(pub u16);
 
-#[derive(Debug, AperCodec)]
-#[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
 pub struct HOReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ho_type: HoType,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ho_report_type: HoReportType,
+    #[asn(tag = "[2] EXPLICIT")]
     pub hosource_id: IRAT_Cell_ID,
+    #[asn(tag = "[3] EXPLICIT")]
     pub ho_target_id: IRAT_Cell_ID,
+    #[asn(tag = "[4] IMPLICIT")]
     pub candidate_cell_list: CandidateCellList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[5] IMPLICIT", extension_idx = 0)]
     pub candidate_pci_list: Option<CandidatePCIList>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct HOReportingCause(pub u8);
 impl HOReportingCause {
@@ -2195,90 +2673,123 @@ impl HOReportingCause {
     pub const UNSPECIFIED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCancel {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverCancelProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCancelAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverCancelAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverCommandProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct HandoverFlag(pub u8);
 impl HandoverFlag {
     pub const HANDOVER_PREPARATION: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverNotify {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverNotifyProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverPreparationFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverPreparationFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequestAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverRequestAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverRequired {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverRequiredProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 5)]
 pub struct HandoverRestrictionList {
+    #[asn(tag = "[0] IMPLICIT")]
     pub serving_plmn: PLMNidentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub equivalent_plm_ns: Option<EPLMNs>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub forbidden_t_as: Option<ForbiddenTAs>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub forbidden_l_as: Option<ForbiddenLAs>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub forbidden_inter_ra_ts: Option<ForbiddenInterRATs>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 4)]
     pub ie_extensions: Option<HandoverRestrictionListIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct HandoverSuccess {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: HandoverSuccessProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct HandoverType(pub u8);
 impl HandoverType {
@@ -2287,16 +2798,19 @@ impl HandoverType {
     pub const LTETOGERAN: u8 = 2u8;
     pub const UTRANTOLTE: u8 = 3u8;
     pub const GERANTOLTE: u8 = 4u8;
+    pub const EPS_TO_5GS: u8 = 5u8;
+    pub const FIVEGS_TO_EPS: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct HoReportType(pub u8);
 impl HoReportType {
     pub const UNNECESSARYHOTOANOTHERRAT: u8 = 0u8;
+    pub const EARLYIRATHANDOVER: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct HoType(pub u8);
 impl HoType {
@@ -2304,7 +2818,7 @@ impl HoType {
     pub const LTETOGERAN: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct IAB_Authorized(pub u8);
 impl IAB_Authorized {
@@ -2312,211 +2826,256 @@ impl IAB_Authorized {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct IAB_Node_Indication(pub u8);
 impl IAB_Node_Indication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct IAB_Supported(pub u8);
 impl IAB_Supported {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "8")]
 pub struct IMSI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct IMSvoiceEPSfallbackfrom5G(pub u8);
 impl IMSvoiceEPSfallbackfrom5G {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum IRAT_Cell_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     EUTRAN(OCTET_STRING_22),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     UTRAN(OCTET_STRING_23),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     GERAN(OCTET_STRING_24),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[3] IMPLICIT")]
     EHRPD(EHRPD_Sector_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct ImmediateMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub measurements_to_activate: MeasurementsToActivate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m1reporting_trigger: M1ReportingTrigger,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub m1thresholdevent_a2: Option<M1ThresholdEventA2>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub m1periodic_reporting: Option<M1PeriodicReporting>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<ImmediateMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct InformationOnRecommendedCellsAndENBsForPaging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_cells_for_paging: RecommendedCellsForPaging,
+    #[asn(tag = "[1] IMPLICIT")]
     pub recommend_en_bs_for_paging: RecommendedENBsForPaging,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<InformationOnRecommendedCellsAndENBsForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialContextSetupFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialContextSetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialContextSetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialContextSetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct InitialUEMessage {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: InitialUEMessageProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitiatingMessage {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitiatingMessageValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct IntegrityProtectionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "16", extensible = true)]
 pub struct IntendedNumberOfPagingAttempts(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum Inter_SystemInformationTransferType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RIMTransfer(RIMTransfer),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 8)]
 pub struct InterSystemMeasurementItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub freq_band_indicator_nr: INTEGER_25,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ss_bfrequencies: INTEGER_26,
+    #[asn(tag = "[2] IMPLICIT")]
     pub subcarrier_spacing_ssb: ENUMERATED_27,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub max_rs_index_cell_qual: Option<INTEGER_28>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 1)]
     pub smtc: Option<OCTET_STRING_29>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub thresh_rs_index_r15: Option<OCTET_STRING_30>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 3)]
     pub ssb_to_measure: Option<OCTET_STRING_31>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[7] IMPLICIT", optional_idx = 4)]
     pub ssrssi_measurement: Option<OCTET_STRING_32>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[8] IMPLICIT", optional_idx = 5)]
     pub quantity_config_nr_r15: Option<OCTET_STRING_33>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[9] IMPLICIT", optional_idx = 6)]
     pub black_cells_to_add_mod_list: Option<OCTET_STRING_34>,
-    #[asn(optional_idx = 7)]
+    #[asn(tag = "[10] IMPLICIT", optional_idx = 7)]
     pub ie_extensions: Option<InterSystemMeasurementItemIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "64")]
 pub struct InterSystemMeasurementList(pub Vec<InterSystemMeasurementItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct InterSystemMeasurementParameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub measurement_duration: INTEGER_35,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub inter_system_measurement_list: Option<InterSystemMeasurementList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<InterSystemMeasurementParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct InterfacesToTrace(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct IntersystemMeasurementConfiguration {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub rsrp: Option<INTEGER_36>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub rsrq: Option<INTEGER_37>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub sinr: Option<INTEGER_38>,
+    #[asn(tag = "[3] IMPLICIT")]
     pub inter_system_measurement_parameters: InterSystemMeasurementParameters,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<IntersystemMeasurementConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct IntersystemSONConfigurationTransfer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "0")]
 pub struct KillAllWarningMessages(pub u8);
 impl KillAllWarningMessages {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct KillRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: KillRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct KillResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: KillResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct L3_Information(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct LAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub lac: LAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -2525,56 +3084,65 @@ pub struct LAI {
 )]
 pub struct LHN_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct LPPa_PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct LTE_M_Indication(pub u8);
 impl LTE_M_Indication {
     pub const LTE_M: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum LastVisitedCell_Item {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     E_UTRAN_Cell(LastVisitedEUTRANCellInformation),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     UTRAN_Cell(LastVisitedUTRANCellInformation),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] EXPLICIT")]
     GERAN_Cell(LastVisitedGERANCellInformation),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[3] IMPLICIT")]
     NG_RAN_Cell(LastVisitedNGRANCellInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LastVisitedEUTRANCellInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_cell_id: EUTRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cell_type: CellType,
+    #[asn(tag = "[2] IMPLICIT")]
     pub time_ue_stayed_in_cell: Time_UE_StayedInCell,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LastVisitedEUTRANCellInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum LastVisitedGERANCellInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Undefined(NULL_39),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedNGRANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct LastVisitedUTRANCellInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct Links_to_log(pub u8);
 impl Links_to_log {
@@ -2583,54 +3151,75 @@ impl Links_to_log {
     pub const BOTH_UPLINK_AND_DOWNLINK: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ListeningSubframePattern {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pattern_period: ENUMERATED_40,
+    #[asn(tag = "[1] IMPLICIT")]
     pub pattern_offset: INTEGER_41,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ListeningSubframePatternIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReportingControl {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportingControlProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct LocationReportingFailureIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: LocationReportingFailureIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct LoggedMBSFNMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub logging_interval: LoggingInterval,
+    #[asn(tag = "[1] IMPLICIT")]
     pub logging_duration: LoggingDuration,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub mbsfn_result_to_log: Option<MBSFN_ResultToLog>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<LoggedMBSFNMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct LoggedMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub logging_interval: LoggingInterval,
+    #[asn(tag = "[1] IMPLICIT")]
     pub logging_duration: LoggingDuration,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<LoggedMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "5")]
 pub struct LoggingDuration(pub u8);
 impl LoggingDuration {
@@ -2642,7 +3231,7 @@ impl LoggingDuration {
     pub const M120: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "7")]
 pub struct LoggingInterval(pub u8);
 impl LoggingInterval {
@@ -2656,62 +3245,84 @@ impl LoggingInterval {
     pub const MS6144: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct M_TMSI(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M1PeriodicReporting {
+    #[asn(tag = "[0] IMPLICIT")]
     pub report_interval: ReportIntervalMDT,
+    #[asn(tag = "[1] IMPLICIT")]
     pub report_amount: ReportAmountMDT,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M1PeriodicReportingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct M1ReportingTrigger(pub u8);
 impl M1ReportingTrigger {
     pub const PERIODIC: u8 = 0u8;
     pub const A2EVENTTRIGGERED: u8 = 1u8;
+    pub const A2EVENTTRIGGERED_PERIODIC: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M1ThresholdEventA2 {
+    #[asn(tag = "[0] EXPLICIT")]
     pub measurement_threshold: MeasurementThresholdA2,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M1ThresholdEventA2IE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M3Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m3period: M3period,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M3ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct M3period(pub u8);
 impl M3period {
     pub const MS100: u8 = 0u8;
     pub const MS1000: u8 = 1u8;
     pub const MS10000: u8 = 2u8;
+    pub const MS1024: u8 = 3u8;
+    pub const MS1280: u8 = 4u8;
+    pub const MS2048: u8 = 5u8;
+    pub const MS2560: u8 = 6u8;
+    pub const MS5120: u8 = 7u8;
+    pub const MS10240: u8 = 8u8;
+    pub const MIN1: u8 = 9u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M4Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m4period: M4period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m4_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M4ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct M4period(pub u8);
 impl M4period {
@@ -2722,16 +3333,20 @@ impl M4period {
     pub const MIN1: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M5Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m5period: M5period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m5_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M5ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct M5period(pub u8);
 impl M5period {
@@ -2742,18 +3357,22 @@ impl M5period {
     pub const MIN1: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct M6Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m6report_interval: M6report_Interval,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub m6delay_threshold: Option<M6delay_threshold>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub m6_links_to_log: Links_to_log,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<M6ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "11")]
 pub struct M6delay_threshold(pub u8);
 impl M6delay_threshold {
@@ -2771,7 +3390,7 @@ impl M6delay_threshold {
     pub const MS750: u8 = 11u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct M6report_Interval(pub u8);
 impl M6report_Interval {
@@ -2781,161 +3400,204 @@ impl M6report_Interval {
     pub const MS10240: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct M7Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub m7period: M7period,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m7_links_to_log: Links_to_log,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<M7ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "60", extensible = true)]
 pub struct M7period(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct MBSFN_ResultToLog(pub Vec<MBSFN_ResultToLogInfo>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct MBSFN_ResultToLogInfo {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub mbsfn_area_id: Option<INTEGER_42>,
+    #[asn(tag = "[1] IMPLICIT")]
     pub carrier_freq: EARFCN,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<MBSFN_ResultToLogInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct MDT_Activation(pub u8);
 impl MDT_Activation {
     pub const IMMEDIATE_MDT_ONLY: u8 = 0u8;
     pub const IMMEDIATE_MDT_AND_TRACE: u8 = 1u8;
     pub const LOGGED_MDT_ONLY: u8 = 2u8;
+    pub const LOGGED_MBSFN_MDT: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct MDT_Configuration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mdt_activation: MDT_Activation,
+    #[asn(tag = "[1] EXPLICIT")]
     pub area_scope_of_mdt: AreaScopeOfMDT,
+    #[asn(tag = "[2] EXPLICIT")]
     pub mdt_mode: MDTMode,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<MDT_ConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct MDT_ConfigurationNR(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct MDT_Location_Info(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum MDTMode {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     ImmediateMDT(ImmediateMDT),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     LoggedMDT(LoggedMDT),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[2] IMPLICIT")]
     MDTMode_Extension(MDTMode_Extension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDTMode_Extension {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MDTMode_ExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct MDTPLMNList(pub Vec<PLMNidentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct MME_Code(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct MME_Group_ID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
 pub struct MME_UE_S1AP_ID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMECPRelocationIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMECPRelocationIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMEConfigurationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMEConfigurationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMEConfigurationUpdate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMEConfigurationUpdateProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMEConfigurationUpdateAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMEConfigurationUpdateAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMEConfigurationUpdateFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMEConfigurationUpdateFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMEDirectInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMEDirectInformationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMEEarlyStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMEEarlyStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum MMEPagingTarget {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Global_ENB_ID(Global_ENB_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TAI(TAI),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct MMERelaySupportIndicator(pub u8);
 impl MMERelaySupportIndicator {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MMEStatusTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: MMEStatusTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "PrintableString",
     sz_extensible = true,
@@ -2944,53 +3606,58 @@ pub struct MMEStatusTransfer {
 )]
 pub struct MMEname(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct MSClassmark2(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct MSClassmark3(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ManagementBasedMDTAllowed(pub u8);
 impl ManagementBasedMDTAllowed {
     pub const ALLOWED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "64", sz_ub = "64")]
 pub struct Masked_IMEISV(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum MeasurementThresholdA2 {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Threshold_RSRP(Threshold_RSRP),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Threshold_RSRQ(Threshold_RSRQ),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct MeasurementsToActivate(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct MessageIdentifier(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "32", sz_ub = "32")]
 pub struct MobilityInformation(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct MultiCellLoadReportingRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub requested_cell_list: RequestedCellList,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -2999,20 +3666,22 @@ pub struct MultiCellLoadReportingRequest {
 )]
 pub struct MultiCellLoadReportingResponse(pub Vec<MultiCellLoadReportingResponse_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum MultiCellLoadReportingResponse_Item {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     EUTRANResponse(EUTRANResponse),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     UTRANResponse(OCTET_STRING_43),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     GERANResponse(OCTET_STRING_44),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[3] IMPLICIT")]
     EHRPD(EHRPDMultiSectorLoadReportingResponseItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct MutingAvailabilityIndication(pub u8);
 impl MutingAvailabilityIndication {
@@ -3020,41 +3689,50 @@ impl MutingAvailabilityIndication {
     pub const UNAVAILABLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct MutingPatternInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub muting_pattern_period: ENUMERATED_45,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub muting_pattern_offset: Option<INTEGER_46>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<MutingPatternInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NAS_PDU(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NASDeliveryIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NASDeliveryIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NASNonDeliveryIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: NASNonDeliveryIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NASSecurityParametersfromE_UTRAN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NASSecurityParameterstoE_UTRAN(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct NB_IoT_DefaultPagingDRX(pub u8);
 impl NB_IoT_DefaultPagingDRX {
@@ -3064,7 +3742,7 @@ impl NB_IoT_DefaultPagingDRX {
     pub const V1024: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct NB_IoT_Paging_eDRX_Cycle(pub u8);
 impl NB_IoT_Paging_eDRX_Cycle {
@@ -3084,17 +3762,20 @@ impl NB_IoT_Paging_eDRX_Cycle {
     pub const HF1024: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct NB_IoT_Paging_eDRXInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub nb_io_t_paging_e_drx_cycle: NB_IoT_Paging_eDRX_Cycle,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub nb_io_t_paging_time_window: Option<NB_IoT_PagingTimeWindow>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<NB_IoT_Paging_eDRXInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct NB_IoT_PagingDRX(pub u8);
 impl NB_IoT_PagingDRX {
@@ -3106,7 +3787,7 @@ impl NB_IoT_PagingDRX {
     pub const V1024: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "15")]
 pub struct NB_IoT_PagingTimeWindow(pub u8);
 impl NB_IoT_PagingTimeWindow {
@@ -3128,86 +3809,102 @@ impl NB_IoT_PagingTimeWindow {
     pub const S16: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct NB_IoT_RLF_Report_Container(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "12", sz_ub = "12")]
 pub struct NB_IoT_UEIdentityIndexValue(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NG_eNB {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_ng_e_nb_id: Global_ENB_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<NG_eNBIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NR_CGI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_identity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub nr_cell_identity: NRCellIdentity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<NR_CGIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "36", sz_ub = "36")]
 pub struct NRCellIdentity(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRUESecurityCapabilities {
+    #[asn(tag = "[0] IMPLICIT")]
     pub n_rencryption_algorithms: NRencryptionAlgorithms,
+    #[asn(tag = "[1] IMPLICIT")]
     pub n_rintegrity_protection_algorithms: NRintegrityProtectionAlgorithms,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<NRUESecurityCapabilitiesIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct NRUESidelinkAggregateMaximumBitrate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub u_eaggregate_maximum_bit_rate: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<NRUESidelinkAggregateMaximumBitrateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct NRV2XServicesAuthorized {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub vehicle_ue: Option<VehicleUE>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub pedestrian_ue: Option<PedestrianUE>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<NRV2XServicesAuthorizedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct NRencryptionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "16", sz_ub = "16")]
 pub struct NRintegrityProtectionAlgorithms(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NRrestrictionin5GS(pub u8);
 impl NRrestrictionin5GS {
     pub const N_RRESTRICTEDIN5_GS: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NRrestrictioninEPSasSecondaryRAT(pub u8);
 impl NRrestrictioninEPSasSecondaryRAT {
     pub const N_RRESTRICTEDIN_EP_SAS_SECONDARY_RAT: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct NextPagingAreaScope(pub u8);
 impl NextPagingAreaScope {
@@ -3215,7 +3912,7 @@ impl NextPagingAreaScope {
     pub const CHANGED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3224,14 +3921,18 @@ impl NextPagingAreaScope {
 )]
 pub struct NotificationCellList(pub Vec<NotificationCellList_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct NotificationCellList_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub cell_id: OCTET_STRING_47,
+    #[asn(tag = "[1] IMPLICIT")]
     pub notify_flag: NotifyFlag,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct NotifyFlag(pub u8);
 impl NotifyFlag {
@@ -3239,18 +3940,18 @@ impl NotifyFlag {
     pub const DEACTIVATED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct NotifySourceeNB(pub u8);
 impl NotifySourceeNB {
     pub const NOTIFY_SOURCE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct NumberOfBroadcasts(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct NumberOfMeasurementReportingLevels(pub u8);
 impl NumberOfMeasurementReportingLevels {
@@ -3261,71 +3962,90 @@ impl NumberOfMeasurementReportingLevels {
     pub const RL10: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct NumberofBroadcastRequest(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OldBSS_ToNewBSS_Information(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct OverloadAction(pub u8);
 impl OverloadAction {
     pub const REJECT_NON_EMERGENCY_MO_DT: u8 = 0u8;
     pub const REJECT_RRC_CR_SIGNALLING: u8 = 1u8;
     pub const PERMIT_EMERGENCY_SESSIONS_AND_MOBILE_TERMINATED_SERVICES_ONLY: u8 = 2u8;
+    pub const PERMIT_HIGH_PRIORITY_SESSIONS_AND_MOBILE_TERMINATED_SERVICES_ONLY: u8 = 3u8;
+    pub const REJECT_DELAY_TOLERANT_ACCESS: u8 = 4u8;
+    pub const PERMIT_HIGH_PRIORITY_SESSIONS_AND_EXCEPTION_REPORTING_AND_MOBILE_TERMINATED_SERVICES_ONLY : u8 = 5u8 ;
+    pub const NOT_ACCEPT_MO_DATA_OR_DELAY_TOLERANT_ACCESS_FROM_CP_C_IO_T: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct OverloadFlag(pub u8);
 impl OverloadFlag {
     pub const OVERLOAD: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum OverloadResponse {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     OverloadAction(OverloadAction),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct OverloadStart {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: OverloadStartProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct OverloadStop {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: OverloadStopProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PC5FlowBitRates {
+    #[asn(tag = "[0] IMPLICIT")]
     pub guaranteed_flow_bit_rate: BitRate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub maximum_flow_bit_rate: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PC5FlowBitRatesIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct PC5QoSFlowItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pqi: FiveQI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub pc5_flow_bit_rates: Option<PC5FlowBitRates>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub range: Option<Range>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<PC5QoSFlowItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3334,70 +4054,85 @@ pub struct PC5QoSFlowItem {
 )]
 pub struct PC5QoSFlowList(pub Vec<PC5QoSFlowItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PC5QoSParameters {
+    #[asn(tag = "[0] IMPLICIT")]
     pub pc5_qo_s_flow_list: PC5QoSFlowList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub pc5_link_aggregated_bit_rates: Option<BitRate>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PC5QoSParametersIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct PDCP_SN(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "32767")]
 pub struct PDCP_SNExtended(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "262143")]
 pub struct PDCP_SNlength18(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PLMNAreaBasedQMC {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plmn_listfor_qmc: PLMNListforQMC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PLMNAreaBasedQMCIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct PLMNListforQMC(pub Vec<PLMNidentity>);
 
 pub type PLMNidentity = TBCD_STRING;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct PS_ServiceNotAvailable(pub u8);
 impl PS_ServiceNotAvailable {
     pub const PS_SERVICE_NOT_AVAILABLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct PSCellInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ncgi: NR_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<PSCellInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSFailureIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PWSFailureIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PWSRestartIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PWSRestartIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3406,17 +4141,20 @@ pub struct PWSRestartIndication {
 )]
 pub struct PWSfailedECGIList(pub Vec<EUTRAN_CGI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "1000")]
 pub struct Packet_LossRate(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct Paging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PagingProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "13")]
 pub struct Paging_eDRX_Cycle(pub u8);
 impl Paging_eDRX_Cycle {
@@ -3436,32 +4174,39 @@ impl Paging_eDRX_Cycle {
     pub const HF256: u8 = 13u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct Paging_eDRXInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub paging_e_drx_cycle: Paging_eDRX_Cycle,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub paging_time_window: Option<PagingTimeWindow>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<Paging_eDRXInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "16", extensible = true)]
 pub struct PagingAttemptCount(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct PagingAttemptInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub paging_attempt_count: PagingAttemptCount,
+    #[asn(tag = "[1] IMPLICIT")]
     pub intended_number_of_paging_attempts: IntendedNumberOfPagingAttempts,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub next_paging_area_scope: Option<NextPagingAreaScope>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<PagingAttemptInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct PagingDRX(pub u8);
 impl PagingDRX {
@@ -3471,7 +4216,7 @@ impl PagingDRX {
     pub const V256: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "7")]
 pub struct PagingPriority(pub u8);
 impl PagingPriority {
@@ -3485,7 +4230,7 @@ impl PagingPriority {
     pub const PRIOLEVEL8: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "20")]
 pub struct PagingProbabilityInformation(pub u8);
 impl PagingProbabilityInformation {
@@ -3512,7 +4257,7 @@ impl PagingProbabilityInformation {
     pub const P100: u8 = 20u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "15")]
 pub struct PagingTimeWindow(pub u8);
 impl PagingTimeWindow {
@@ -3534,25 +4279,34 @@ impl PagingTimeWindow {
     pub const S16: u8 = 15u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PathSwitchRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequestAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PathSwitchRequestAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PathSwitchRequestFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: PathSwitchRequestFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PedestrianUE(pub u8);
 impl PedestrianUE {
@@ -3560,18 +4314,18 @@ impl PedestrianUE {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct PendingDataIndication(pub u8);
 impl PendingDataIndication {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct Port_Number(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct Pre_emptionCapability(pub u8);
 impl Pre_emptionCapability {
@@ -3579,7 +4333,7 @@ impl Pre_emptionCapability {
     pub const MAY_TRIGGER_PRE_EMPTION: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "1")]
 pub struct Pre_emptionVulnerability(pub u8);
 impl Pre_emptionVulnerability {
@@ -3587,7 +4341,7 @@ impl Pre_emptionVulnerability {
     pub const PRE_EMPTABLE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct Presence(pub u8);
 impl Presence {
@@ -3596,11 +4350,11 @@ impl Presence {
     pub const MANDATORY: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "15")]
 pub struct PriorityLevel(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct PrivacyIndicator(pub u8);
 impl PrivacyIndicator {
@@ -3608,33 +4362,38 @@ impl PrivacyIndicator {
     pub const LOGGED_MDT: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum PrivateIE_ID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     Local(INTEGER_48),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     Global(OBJECT_IDENTIFIER_49),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct PrivateMessage {
+    #[asn(tag = "[0] IMPLICIT")]
     pub private_i_es: PrivateMessagePrivateIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct ProSeAuthorized {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub pro_se_direct_discovery: Option<ProSeDirectDiscovery>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub pro_se_direct_communication: Option<ProSeDirectCommunication>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<ProSeAuthorizedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ProSeDirectCommunication(pub u8);
 impl ProSeDirectCommunication {
@@ -3642,7 +4401,7 @@ impl ProSeDirectCommunication {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ProSeDirectDiscovery(pub u8);
 impl ProSeDirectDiscovery {
@@ -3650,7 +4409,7 @@ impl ProSeDirectDiscovery {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ProSeUEtoNetworkRelaying(pub u8);
 impl ProSeUEtoNetworkRelaying {
@@ -3658,82 +4417,90 @@ impl ProSeUEtoNetworkRelaying {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct ProcedureCode(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolExtensionID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct ProtocolIE_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct QCI(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "1", sz_ub = "1")]
 pub struct RAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4294967295")]
 pub struct RAN_UE_NGAP_ID(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct RAT_Type(pub u8);
 impl RAT_Type {
     pub const NBIOT: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct RIMInformation(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum RIMRoutingAddress {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     GERAN_Cell_ID(GERAN_Cell_ID),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[1] IMPLICIT")]
     TargetRNC_ID(TargetRNC_ID),
-    #[asn(key = 1, extended = true)]
+    #[asn(key = 1, extended = true, tag = "[2] IMPLICIT")]
     EHRPD_Sector_ID(OCTET_STRING_50),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct RIMTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rim_information: RIMInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] EXPLICIT", optional_idx = 0)]
     pub rim_routing_address: Option<RIMRoutingAddress>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RIMTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct RLFReportInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ue_rlf_report_container: UE_RLF_Report_Container,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ue_rlf_report_container_for_extended_bands:
         Option<UE_RLF_Report_Container_for_extended_bands>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RLFReportInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct RNC_ID(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct RRC_Container(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct RRC_Establishment_Cause(pub u8);
 impl RRC_Establishment_Cause {
@@ -3742,9 +4509,12 @@ impl RRC_Establishment_Cause {
     pub const MT_ACCESS: u8 = 2u8;
     pub const MO_SIGNALLING: u8 = 3u8;
     pub const MO_DATA: u8 = 4u8;
+    pub const DELAY_TOLERANT_ACCESS: u8 = 5u8;
+    pub const MO_VOICE_CALL: u8 = 6u8;
+    pub const MO_EXCEPTION_DATA: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "8")]
 pub struct Range(pub u8);
 impl Range {
@@ -3759,7 +4529,7 @@ impl Range {
     pub const M1000: u8 = 8u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -3768,7 +4538,7 @@ impl Range {
 )]
 pub struct ReceiveStatusOfULPDCPSDUsExtended(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -3777,7 +4547,7 @@ pub struct ReceiveStatusOfULPDCPSDUsExtended(pub BitVec<u8, Msb0>);
 )]
 pub struct ReceiveStatusOfULPDCPSDUsPDCP_SNlength18(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -3786,64 +4556,76 @@ pub struct ReceiveStatusOfULPDCPSDUsPDCP_SNlength18(pub BitVec<u8, Msb0>);
 )]
 pub struct ReceiveStatusofULPDCPSDUs(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct RecommendedCellItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutran_cgi: EUTRAN_CGI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub time_stayed_in_cell: Option<INTEGER_51>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<RecommendedCellItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RecommendedCellList(pub Vec<RecommendedCellList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedCellsForPaging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_cell_list: RecommendedCellList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RecommendedCellsForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedENBItem {
+    #[asn(tag = "[0] EXPLICIT")]
     pub mme_paging_target: MMEPagingTarget,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RecommendedENBItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct RecommendedENBList(pub Vec<RecommendedENBList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RecommendedENBsForPaging {
+    #[asn(tag = "[0] IMPLICIT")]
     pub recommended_enb_list: RecommendedENBList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RecommendedENBsForPagingIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct RelativeMMECapacity(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct RelayNode_Indicator(pub u8);
 impl RelayNode_Indicator {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct RepetitionPeriod(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "7")]
 pub struct ReportAmountMDT(pub u8);
 impl ReportAmountMDT {
@@ -3857,14 +4639,14 @@ impl ReportAmountMDT {
     pub const RINFINITY: u8 = 7u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ReportArea(pub u8);
 impl ReportArea {
     pub const ECGI: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "12")]
 pub struct ReportIntervalMDT(pub u8);
 impl ReportIntervalMDT {
@@ -3883,7 +4665,7 @@ impl ReportIntervalMDT {
     pub const MIN60: u8 = 12u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3892,29 +4674,36 @@ impl ReportIntervalMDT {
 )]
 pub struct ReportingCellList(pub Vec<ReportingCellList_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ReportingCellList_Item {
+    #[asn(tag = "[0] EXPLICIT")]
     pub cell_id: IRAT_Cell_ID,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct RequestType {
+    #[asn(tag = "[0] IMPLICIT")]
     pub event_type: EventType,
+    #[asn(tag = "[1] IMPLICIT")]
     pub report_area: ReportArea,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<RequestTypeIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct RequestTypeAdditionalInfo(pub u8);
 impl RequestTypeAdditionalInfo {
     pub const INCLUDE_PS_CELL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -3923,206 +4712,269 @@ impl RequestTypeAdditionalInfo {
 )]
 pub struct RequestedCellList(pub Vec<IRAT_Cell_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RerouteNASRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RerouteNASRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct Reset {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ResetProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct ResetAcknowledge {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: ResetAcknowledgeProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ResetAll(pub u8);
 impl ResetAll {
     pub const RESET_ALL: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum ResetType {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     S1_Interface(ResetAll),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     PartOfS1_Interface(UE_associatedLogicalS1_ConnectionListRes),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct RetrieveUEInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: RetrieveUEInformationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct Routing_ID(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct S_TMSI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mmec: MME_Code,
+    #[asn(tag = "[1] IMPLICIT")]
     pub m_tmsi: M_TMSI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<S_TMSIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
-#[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "2",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
 pub enum S1AP_PDU {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     InitiatingMessage(InitiatingMessage),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SuccessfulOutcome(SuccessfulOutcome),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     UnsuccessfulOutcome(UnsuccessfulOutcome),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct S1SetupFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: S1SetupFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct S1SetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: S1SetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct S1SetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: S1SetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SONConfigurationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub targete_nb_id: TargeteNB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub sourcee_nb_id: SourceeNB_ID,
+    #[asn(tag = "[2] EXPLICIT")]
     pub son_information: SONInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SONConfigurationTransferIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum SONInformation {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SONInformationRequest(SONInformationRequest),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SONInformationReply(SONInformationReply),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[2] IMPLICIT")]
     SONInformation_Extension(SONInformation_Extension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONInformation_Extension {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SONInformation_ExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct SONInformationReply {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub x2tnl_configuration_info: Option<X2TNLConfigurationInfo>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<SONInformationReplyIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum SONInformationReport {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     RLFReportInformation(RLFReportInformation),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SONInformationRequest(pub u8);
 impl SONInformationRequest {
     pub const X2_TNL_CONFIGURATION_INFO: u8 = 0u8;
+    pub const TIME_SYNCHRONISATION_INFO: u8 = 1u8;
+    pub const ACTIVATE_MUTING: u8 = 2u8;
+    pub const DEACTIVATE_MUTING: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SONtransferApplicationIdentity(pub u8);
 impl SONtransferApplicationIdentity {
     pub const CELL_LOAD_REPORTING: u8 = 0u8;
+    pub const MULTI_CELL_LOAD_REPORTING: u8 = 1u8;
+    pub const EVENT_TRIGGERED_CELL_LOAD_REPORTING: u8 = 2u8;
+    pub const HO_REPORTING: u8 = 3u8;
+    pub const EUTRAN_CELL_ACTIVATION: u8 = 4u8;
+    pub const ENERGY_SAVINGS_INDICATION: u8 = 5u8;
+    pub const FAILURE_EVENT_REPORTING: u8 = 6u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum SONtransferCause {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellLoadReporting(CellLoadReportingCause),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[1] IMPLICIT")]
     MultiCellLoadReporting(CellLoadReportingCause),
-    #[asn(key = 1, extended = true)]
+    #[asn(key = 1, extended = true, tag = "[2] IMPLICIT")]
     EventTriggeredCellLoadReporting(CellLoadReportingCause),
-    #[asn(key = 2, extended = true)]
+    #[asn(key = 2, extended = true, tag = "[3] IMPLICIT")]
     HOReporting(HOReportingCause),
-    #[asn(key = 3, extended = true)]
+    #[asn(key = 3, extended = true, tag = "[4] IMPLICIT")]
     EutranCellActivation(CellActivationCause),
-    #[asn(key = 4, extended = true)]
+    #[asn(key = 4, extended = true, tag = "[5] IMPLICIT")]
     EnergySavingsIndication(CellStateIndicationCause),
-    #[asn(key = 5, extended = true)]
+    #[asn(key = 5, extended = true, tag = "[6] IMPLICIT")]
     FailureEventReporting(FailureEventReportingCause),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum SONtransferRequestContainer {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellLoadReporting(NULL_52),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[1] IMPLICIT")]
     MultiCellLoadReporting(MultiCellLoadReportingRequest),
-    #[asn(key = 1, extended = true)]
+    #[asn(key = 1, extended = true, tag = "[2] IMPLICIT")]
     EventTriggeredCellLoadReporting(EventTriggeredCellLoadReportingRequest),
-    #[asn(key = 2, extended = true)]
+    #[asn(key = 2, extended = true, tag = "[3] IMPLICIT")]
     HOReporting(HOReport),
-    #[asn(key = 3, extended = true)]
+    #[asn(key = 3, extended = true, tag = "[4] IMPLICIT")]
     EutranCellActivation(CellActivationRequest),
-    #[asn(key = 4, extended = true)]
+    #[asn(key = 4, extended = true, tag = "[5] IMPLICIT")]
     EnergySavingsIndication(CellStateIndication),
-    #[asn(key = 5, extended = true)]
+    #[asn(key = 5, extended = true, tag = "[6] EXPLICIT")]
     FailureEventReporting(FailureEventReport),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "0", extensible = true)]
 pub enum SONtransferResponseContainer {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] EXPLICIT")]
     CellLoadReporting(CellLoadReportingResponse),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[1] IMPLICIT")]
     MultiCellLoadReporting(MultiCellLoadReportingResponse),
-    #[asn(key = 1, extended = true)]
+    #[asn(key = 1, extended = true, tag = "[2] IMPLICIT")]
     EventTriggeredCellLoadReporting(EventTriggeredCellLoadReportingResponse),
-    #[asn(key = 2, extended = true)]
+    #[asn(key = 2, extended = true, tag = "[3] IMPLICIT")]
     HOReporting(NULL_53),
-    #[asn(key = 3, extended = true)]
+    #[asn(key = 3, extended = true, tag = "[4] IMPLICIT")]
     EutranCellActivation(CellActivationResponse),
-    #[asn(key = 4, extended = true)]
+    #[asn(key = 4, extended = true, tag = "[5] IMPLICIT")]
     EnergySavingsIndication(NULL_54),
-    #[asn(key = 5, extended = true)]
+    #[asn(key = 5, extended = true, tag = "[6] IMPLICIT")]
     FailureEventReporting(NULL_55),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SRVCCHOIndication(pub u8);
 impl SRVCCHOIndication {
@@ -4130,50 +4982,60 @@ impl SRVCCHOIndication {
     pub const C_SONLY: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SRVCCOperationNotPossible(pub u8);
 impl SRVCCOperationNotPossible {
     pub const NOT_POSSIBLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SRVCCOperationPossible(pub u8);
 impl SRVCCOperationPossible {
     pub const POSSIBLE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct ScheduledCommunicationTime {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub dayof_week: Option<BIT_STRING_56>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub timeof_day_start: Option<INTEGER_57>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub timeof_day_end: Option<INTEGER_58>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<ScheduledCommunicationTimeIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct SecondaryRATDataUsageReport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: SecondaryRATDataUsageReportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecondaryRATDataUsageReportItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_rab_id: E_RAB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub secondary_rat_type: SecondaryRATType,
+    #[asn(tag = "[2] IMPLICIT")]
     pub e_rab_usage_report_list: E_RABUsageReportList,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SecondaryRATDataUsageReportItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4182,30 +5044,35 @@ pub struct SecondaryRATDataUsageReportItem {
 )]
 pub struct SecondaryRATDataUsageReportList(pub Vec<SecondaryRATDataUsageReportList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SecondaryRATDataUsageRequest(pub u8);
 impl SecondaryRATDataUsageRequest {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct SecondaryRATType(pub u8);
 impl SecondaryRATType {
     pub const N_R: u8 = 0u8;
+    pub const UNLICENSED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SecurityContext {
+    #[asn(tag = "[0] IMPLICIT")]
     pub next_hop_chaining_count: INTEGER_59,
+    #[asn(tag = "[1] IMPLICIT")]
     pub next_hop_parameter: SecurityKey,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SecurityContextIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "BITSTRING",
     sz_extensible = false,
@@ -4214,38 +5081,47 @@ pub struct SecurityContext {
 )]
 pub struct SecurityKey(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct SerialNumber(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "0", sz_ub = "32")]
 pub struct ServedDCNs(pub Vec<ServedDCNsItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ServedDCNsItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub dcn_id: DCN_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub relative_dcn_capacity: RelativeMMECapacity,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ServedDCNsItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct ServedGUMMEIs(pub Vec<ServedGUMMEIsItem>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct ServedGUMMEIsItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub served_plm_ns: ServedPLMNs,
+    #[asn(tag = "[1] IMPLICIT")]
     pub served_group_i_ds: ServedGroupIDs,
+    #[asn(tag = "[2] IMPLICIT")]
     pub served_mme_cs: ServedMMECs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<ServedGUMMEIsItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4254,7 +5130,7 @@ pub struct ServedGUMMEIsItem {
 )]
 pub struct ServedGroupIDs(pub Vec<MME_Group_ID>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4263,11 +5139,11 @@ pub struct ServedGroupIDs(pub Vec<MME_Group_ID>);
 )]
 pub struct ServedMMECs(pub Vec<MME_Code>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "32")]
 pub struct ServedPLMNs(pub Vec<PLMNidentity>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ServiceType(pub u8);
 impl ServiceType {
@@ -4275,41 +5151,45 @@ impl ServiceType {
     pub const Q_MC_FOR_MTSI_SERVICE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Source_ToTarget_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct SourceBSS_ToTargetBSS_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SourceNgRanNode_ID {
+    #[asn(tag = "[0] EXPLICIT")]
     pub global_ran_node_id: Global_RAN_NODE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: FiveGSTAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SourceNgRanNode_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct SourceNgRanNode_ToTargetNgRanNode_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = false)]
 pub enum SourceNodeID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     SourceNgRanNode_ID(SourceNgRanNode_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     SourceNodeID_Extension(SourceNodeID_Extension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceNodeID_Extension {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SourceOfUEActivityBehaviourInformation(pub u8);
 impl SourceOfUEActivityBehaviourInformation {
@@ -4317,70 +5197,81 @@ impl SourceOfUEActivityBehaviourInformation {
     pub const STATISTICS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct SourceRNC_ToTargetRNC_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false, optional_fields = 1)]
 pub struct SourceeNB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_enb_id: Global_ENB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SourceeNB_IDIE_Extensions>,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct SourceeNB_ToTargeteNB_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rrc_container: RRC_Container,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub e_rab_information_list: Option<E_RABInformationList>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub target_cell_id: EUTRAN_CGI,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub subscriber_profile_i_dfor_rfp: Option<SubscriberProfileIDforRFP>,
+    #[asn(tag = "[4] IMPLICIT")]
     pub ue_history_information: UE_HistoryInformation,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<SourceeNB_ToTargeteNB_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "3", extensible = true)]
 pub struct StratumLevel(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "256")]
 pub struct SubscriberProfileIDforRFP(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 7)]
 pub struct Subscription_Based_UE_DifferentiationInfo {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub periodic_communication_indicator: Option<ENUMERATED_60>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub periodic_time: Option<INTEGER_61>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub scheduled_communication_time: Option<ScheduledCommunicationTime>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub stationary_indication: Option<ENUMERATED_62>,
-    #[asn(optional_idx = 4)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 4)]
     pub traffic_profile: Option<ENUMERATED_63>,
-    #[asn(optional_idx = 5)]
+    #[asn(tag = "[5] IMPLICIT", optional_idx = 5)]
     pub battery_indication: Option<ENUMERATED_64>,
-    #[asn(optional_idx = 6)]
+    #[asn(tag = "[6] IMPLICIT", optional_idx = 6)]
     pub ie_extensions: Option<Subscription_Based_UE_DifferentiationInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SuccessfulOutcome {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4389,29 +5280,35 @@ pub struct SuccessfulOutcome {
 )]
 pub struct SupportedTAs(pub Vec<SupportedTAs_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct SupportedTAs_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tac: TAC,
+    #[asn(tag = "[1] IMPLICIT")]
     pub broadcast_plm_ns: BPLMNs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<SupportedTAs_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct SynchronisationInformation {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub source_stratum_level: Option<StratumLevel>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub listening_subframe_pattern: Option<ListeningSubframePattern>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub aggressore_cgi_list: Option<ECGI_List>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<SynchronisationInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct SynchronisationStatus(pub u8);
 impl SynchronisationStatus {
@@ -4419,36 +5316,46 @@ impl SynchronisationStatus {
     pub const ASYNCHRONOUS: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TABasedMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ta_listfor_mdt: TAListforMDT,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TABasedMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TABasedQMC {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ta_listfor_qmc: TAListforQMC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TABasedQMCIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct TAC(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAI {
+    #[asn(tag = "[0] IMPLICIT")]
     pub plm_nidentity: PLMNidentity,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tac: TAC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4457,16 +5364,20 @@ pub struct TAI {
 )]
 pub struct TAI_Broadcast(pub Vec<TAI_Broadcast_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAI_Broadcast_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub completed_cellin_tai: CompletedCellinTAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAI_Broadcast_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4475,40 +5386,53 @@ pub struct TAI_Broadcast_Item {
 )]
 pub struct TAI_Cancelled(pub Vec<TAI_Cancelled_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAI_Cancelled_Item {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub cancelled_cellin_tai: CancelledCellinTAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAI_Cancelled_ItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBasedMDT {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai_listfor_mdt: TAIListforMDT,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIBasedMDTIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIBasedQMC {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai_listfor_qmc: TAIListforQMC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIBasedQMCIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TAIItem {
+    #[asn(tag = "[0] IMPLICIT")]
     pub tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TAIItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4517,7 +5441,7 @@ pub struct TAIItem {
 )]
 pub struct TAIList(pub Vec<TAIList_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4526,15 +5450,15 @@ pub struct TAIList(pub Vec<TAIList_Entry>);
 )]
 pub struct TAIListForRestart(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAIListforMDT(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAIListforQMC(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4543,116 +5467,137 @@ pub struct TAIListforQMC(pub Vec<TAI>);
 )]
 pub struct TAIListforWarning(pub Vec<TAI>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAListforMDT(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "8")]
 pub struct TAListforQMC(pub Vec<TAC>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
 pub struct TBCD_STRING(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct Target_ToSource_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargetBSS_ToSourceBSS_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum TargetID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     TargeteNB_ID(TargeteNB_ID),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TargetRNC_ID(TargetRNC_ID),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     CGI(CGI),
-    #[asn(key = 0, extended = true)]
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+    #[asn(key = 0, extended = true, tag = "[3] IMPLICIT")]
     TargetgNgRanNode_ID(TargetNgRanNode_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargetNgRanNode_ID {
+    #[asn(tag = "[0] EXPLICIT")]
     pub global_ran_node_id: Global_RAN_NODE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: FiveGSTAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TargetNgRanNode_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargetNgRanNode_ToSourceNgRanNode_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct TargetRNC_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub lai: LAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub rac: Option<RAC>,
+    #[asn(tag = "[2] IMPLICIT")]
     pub rnc_id: RNC_ID,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 1)]
     pub extended_rnc_id: Option<ExtendedRNC_ID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<TargetRNC_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct TargetRNC_ToSourceRNC_TransparentContainer(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargeteNB_ID {
+    #[asn(tag = "[0] IMPLICIT")]
     pub global_enb_id: Global_ENB_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub selected_tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TargeteNB_IDIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TargeteNB_ToSourceeNB_TransparentContainer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub rrc_container: RRC_Container,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TargeteNB_ToSourceeNB_TransparentContainerIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "97")]
 pub struct Threshold_RSRP(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "34")]
 pub struct Threshold_RSRQ(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct Time_UE_StayedInCell(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "40950")]
 pub struct Time_UE_StayedInCell_EnhancedGranularity(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct TimeSinceSecondaryNodeRelease(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TimeSynchronisationInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub stratum_level: StratumLevel,
+    #[asn(tag = "[1] IMPLICIT")]
     pub synchronisation_status: SynchronisationStatus,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TimeSynchronisationInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct TimeToWait(pub u8);
 impl TimeToWait {
@@ -4664,26 +5609,35 @@ impl TimeToWait {
     pub const V60S: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TooEarlyInterRATHOReportReportFromEUTRAN {
+    #[asn(tag = "[0] IMPLICIT")]
     pub uerlf_report_container: OCTET_STRING_65,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub mobility_information: Option<MobilityInformation>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct TraceActivation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub e_utran_trace_id: E_UTRAN_Trace_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub interfaces_to_trace: InterfacesToTrace,
+    #[asn(tag = "[2] IMPLICIT")]
     pub trace_depth: TraceDepth,
+    #[asn(tag = "[3] IMPLICIT")]
     pub trace_collection_entity_ip_address: TransportLayerAddress,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<TraceActivationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "5")]
 pub struct TraceDepth(pub u8);
 impl TraceDepth {
@@ -4695,34 +5649,44 @@ impl TraceDepth {
     pub const MAXIMUM_WITHOUT_VENDOR_SPECIFIC_EXTENSION: u8 = 5u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct TraceFailureIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: TraceFailureIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct TraceStart {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: TraceStartProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "99")]
 pub struct TrafficLoadReductionIndication(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct TransportInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ul_gtp_teid: GTP_TEID,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = true, sz_lb = "1", sz_ub = "160")]
 pub struct TransportLayerAddress(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", lb = "0", ub = "2")]
 pub struct TriggeringMessage(pub u8);
 impl TriggeringMessage {
@@ -4731,17 +5695,20 @@ impl TriggeringMessage {
     pub const UNSUCCESSFULL_OUTCOME: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 2)]
 pub struct TunnelInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub transport_layer_address: TransportLayerAddress,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub udp_port_number: Option<Port_Number>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub ie_extensions: Option<TunnelInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct TypeOfError(pub u8);
 impl TypeOfError {
@@ -4749,67 +5716,75 @@ impl TypeOfError {
     pub const MISSING: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "8", sz_ub = "8")]
 pub struct UE_Application_Layer_Measurement_Capability(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
 pub struct UE_HistoryInformation(pub Vec<LastVisitedCell_Item>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UE_HistoryInformationFromTheUE(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UE_RLF_Report_Container(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UE_RLF_Report_Container_for_extended_bands(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UE_RetentionInformation(pub u8);
 impl UE_RetentionInformation {
     pub const UES_RETAINED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UE_S1AP_ID_pair {
+    #[asn(tag = "[0] IMPLICIT")]
     pub mme_ue_s1ap_id: MME_UE_S1AP_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub enb_ue_s1ap_id: ENB_UE_S1AP_ID,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UE_S1AP_ID_pairIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum UE_S1AP_IDs {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     UE_S1AP_ID_pair(UE_S1AP_ID_pair),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     MME_UE_S1AP_ID(MME_UE_S1AP_ID),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct UE_Usage_Type(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct UE_associatedLogicalS1_ConnectionItem {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub mme_ue_s1ap_id: Option<MME_UE_S1AP_ID>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub enb_ue_s1ap_id: Option<ENB_UE_S1AP_ID>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<UE_associatedLogicalS1_ConnectionItemIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4820,7 +5795,7 @@ pub struct UE_associatedLogicalS1_ConnectionListRes(
     pub Vec<UE_associatedLogicalS1_ConnectionListRes_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -4831,276 +5806,374 @@ pub struct UE_associatedLogicalS1_ConnectionListResAck(
     pub Vec<UE_associatedLogicalS1_ConnectionListResAck_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UEAggregateMaximumBitrate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub u_eaggregate_maximum_bit_rate_dl: BitRate,
+    #[asn(tag = "[1] IMPLICIT")]
     pub u_eaggregate_maximum_bit_rate_ul: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UEAggregateMaximumBitrateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UEAppLayerMeasConfig {
+    #[asn(tag = "[0] IMPLICIT")]
     pub container_for_app_layer_meas_config: OCTET_STRING_66,
+    #[asn(tag = "[1] EXPLICIT")]
     pub area_scope_of_qmc: AreaScopeOfQMC,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UEAppLayerMeasConfigIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UECapabilityInfoIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UECapabilityInfoIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UECapabilityInfoRequest(pub u8);
 impl UECapabilityInfoRequest {
     pub const REQUESTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationConfirm {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationConfirmProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationIndication {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationIndicationProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextModificationResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextModificationResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseCommand {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextReleaseCommandProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseComplete {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextReleaseCompleteProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextReleaseRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextReleaseRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeFailure {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextResumeFailureProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextResumeRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextResumeResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextResumeResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextSuspendRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEContextSuspendResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEContextSuspendResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "10", sz_ub = "10")]
 pub struct UEIdentityIndexValue(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UEInformationTransfer {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UEInformationTransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
 pub enum UEPagingID {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     S_TMSI(S_TMSI),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     IMSI(IMSI),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapability(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityForPaging(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct UERadioCapabilityID(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityIDMappingRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityIDMappingRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityIDMappingResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityIDMappingResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityMatchRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityMatchRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UERadioCapabilityMatchResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UERadioCapabilityMatchResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UESecurityCapabilities {
+    #[asn(tag = "[0] IMPLICIT")]
     pub encryption_algorithms: EncryptionAlgorithms,
+    #[asn(tag = "[1] IMPLICIT")]
     pub integrity_protection_algorithms: IntegrityProtectionAlgorithms,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UESecurityCapabilitiesIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UESidelinkAggregateMaximumBitrate {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ue_sidelink_aggregate_maximum_bit_rate: BitRate,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UESidelinkAggregateMaximumBitrateIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UEUserPlaneCIoTSupportIndicator(pub u8);
 impl UEUserPlaneCIoTSupportIndicator {
     pub const SUPPORTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UL_CP_SecurityInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub ul_nas_mac: UL_NAS_MAC,
+    #[asn(tag = "[1] IMPLICIT")]
     pub ul_nas_count: UL_NAS_Count,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UL_CP_SecurityInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "5", sz_ub = "5")]
 pub struct UL_NAS_Count(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "16", sz_ub = "16")]
 pub struct UL_NAS_MAC(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "VisibleString")]
 pub struct URI_Address(pub String);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct UnlicensedSpectrumRestriction(pub u8);
 impl UnlicensedSpectrumRestriction {
     pub const UNLICENSED_RESTRICTED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UnsuccessfulOutcome {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UnsuccessfulOutcomeValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkNASTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkNASTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkNonUEAssociatedLPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkNonUEAssociatedLPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkS1cdma2000tunnelling {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkS1cdma2000tunnellingProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct UplinkUEAssociatedLPPaTransport {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: UplinkUEAssociatedLPPaTransportProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct UserLocationInformation {
+    #[asn(tag = "[0] IMPLICIT")]
     pub eutran_cgi: EUTRAN_CGI,
+    #[asn(tag = "[1] IMPLICIT")]
     pub tai: TAI,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<UserLocationInformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 3)]
 pub struct V2XServicesAuthorized {
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[0] IMPLICIT", optional_idx = 0)]
     pub vehicle_ue: Option<VehicleUE>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 1)]
     pub pedestrian_ue: Option<PedestrianUE>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 2)]
     pub ie_extensions: Option<V2XServicesAuthorizedIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct VehicleUE(pub u8);
 impl VehicleUE {
@@ -5108,7 +6181,7 @@ impl VehicleUE {
     pub const NOT_AUTHORIZED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct VoiceSupportMatchIndicator(pub u8);
 impl VoiceSupportMatchIndicator {
@@ -5116,32 +6189,35 @@ impl VoiceSupportMatchIndicator {
     pub const NOT_SUPPORTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct WLANMeasConfig(pub u8);
 impl WLANMeasConfig {
     pub const SETUP: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "4")]
 pub struct WLANMeasConfigNameList(pub Vec<WLANName>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 4)]
 pub struct WLANMeasurementConfiguration {
+    #[asn(tag = "[0] IMPLICIT")]
     pub wlan_meas_config: WLANMeasConfig,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub wlan_meas_config_name_list: Option<WLANMeasConfigNameList>,
-    #[asn(optional_idx = 1)]
+    #[asn(tag = "[2] IMPLICIT", optional_idx = 1)]
     pub wlan_rssi: Option<ENUMERATED_67>,
-    #[asn(optional_idx = 2)]
+    #[asn(tag = "[3] IMPLICIT", optional_idx = 2)]
     pub wlan_rtt: Option<ENUMERATED_68>,
-    #[asn(optional_idx = 3)]
+    #[asn(tag = "[4] IMPLICIT", optional_idx = 3)]
     pub ie_extensions: Option<WLANMeasurementConfigurationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5150,15 +6226,18 @@ pub struct WLANMeasurementConfiguration {
 )]
 pub struct WLANName(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct WUS_Assistance_Information {
+    #[asn(tag = "[0] IMPLICIT")]
     pub paging_probability_information: PagingProbabilityInformation,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<WUS_Assistance_InformationIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5167,18 +6246,20 @@ pub struct WUS_Assistance_Information {
 )]
 pub struct WarningAreaCoordinates(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "CHOICE", lb = "0", ub = "2", extensible = true)]
 pub enum WarningAreaList {
-    #[asn(key = 0, extended = false)]
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
     CellIDList(ECGIList),
-    #[asn(key = 1, extended = false)]
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
     TrackingAreaListforWarning(TAIListforWarning),
-    #[asn(key = 2, extended = false)]
+    #[asn(key = 2, extended = false, tag = "[2] IMPLICIT")]
     EmergencyAreaIDList(EmergencyAreaIDList),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5187,7 +6268,7 @@ pub enum WarningAreaList {
 )]
 pub struct WarningMessageContents(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -5196,39 +6277,48 @@ pub struct WarningMessageContents(pub Vec<u8>);
 )]
 pub struct WarningSecurityInfo(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "2", sz_ub = "2")]
 pub struct WarningType(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct WriteReplaceWarningRequest {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: WriteReplaceWarningRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true)]
 pub struct WriteReplaceWarningResponse {
+    #[asn(tag = "[0] IMPLICIT")]
     pub protocol_i_es: WriteReplaceWarningResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = true, optional_fields = 1)]
 pub struct X2TNLConfigurationInfo {
+    #[asn(tag = "[0] IMPLICIT")]
     pub enbx2_transport_layer_addresses: ENBX2TLAs,
-    #[asn(optional_idx = 0)]
+    #[asn(tag = "[1] IMPLICIT", optional_idx = 0)]
     pub ie_extensions: Option<X2TNLConfigurationInfoIE_Extensions>,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_2(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Additional_GUTIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5237,11 +6327,11 @@ pub struct Additional_GUTIIE_Extensions_Entry {}
 )]
 pub struct Additional_GUTIIE_Extensions(pub Vec<Additional_GUTIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AllocationAndRetentionPriorityIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5252,15 +6342,15 @@ pub struct AllocationAndRetentionPriorityIE_Extensions(
     pub Vec<AllocationAndRetentionPriorityIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_3;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssistanceDataForCECapableUEsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5271,11 +6361,11 @@ pub struct AssistanceDataForCECapableUEsIE_Extensions(
     pub Vec<AssistanceDataForCECapableUEsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssistanceDataForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5286,11 +6376,11 @@ pub struct AssistanceDataForPagingIE_Extensions(
     pub Vec<AssistanceDataForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct AssistanceDataForRecommendedCellsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5301,11 +6391,11 @@ pub struct AssistanceDataForRecommendedCellsIE_Extensions(
     pub Vec<AssistanceDataForRecommendedCellsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Bearers_SubjectToEarlyStatusTransfer_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5316,23 +6406,25 @@ pub struct Bearers_SubjectToEarlyStatusTransfer_ItemIE_Extensions(
     pub Vec<Bearers_SubjectToEarlyStatusTransfer_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Bearers_SubjectToEarlyStatusTransferList_EntryValue {
     #[asn(key = 322)]
     Id_Bearers_SubjectToEarlyStatusTransfer_Item(Bearers_SubjectToEarlyStatusTransfer_Item),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Bearers_SubjectToEarlyStatusTransferList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: Bearers_SubjectToEarlyStatusTransferList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Bearers_SubjectToStatusTransfer_ItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 180)]
@@ -5349,16 +6441,18 @@ pub enum Bearers_SubjectToStatusTransfer_ItemIE_Extensions_EntryExtensionValue {
     Id_ULCOUNTValuePDCP_SNlength18(COUNTvaluePDCP_SNlength18),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Bearers_SubjectToStatusTransfer_ItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: Bearers_SubjectToStatusTransfer_ItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5369,34 +6463,36 @@ pub struct Bearers_SubjectToStatusTransfer_ItemIE_Extensions(
     pub Vec<Bearers_SubjectToStatusTransfer_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum Bearers_SubjectToStatusTransferList_EntryValue {
     #[asn(key = 89)]
     Id_Bearers_SubjectToStatusTransfer_Item(Bearers_SubjectToStatusTransfer_Item),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Bearers_SubjectToStatusTransferList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: Bearers_SubjectToStatusTransferList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_4(pub u8);
 impl ENUMERATED_4 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct BluetoothMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5407,11 +6503,11 @@ pub struct BluetoothMeasurementConfigurationIE_Extensions(
     pub Vec<BluetoothMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5420,11 +6516,11 @@ pub struct CGIIE_Extensions_Entry {}
 )]
 pub struct CGIIE_Extensions(pub Vec<CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CNTypeRestrictions_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5435,11 +6531,11 @@ pub struct CNTypeRestrictions_ItemIE_Extensions(
     pub Vec<CNTypeRestrictions_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct COUNTValueExtendedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5448,11 +6544,11 @@ pub struct COUNTValueExtendedIE_Extensions_Entry {}
 )]
 pub struct COUNTValueExtendedIE_Extensions(pub Vec<COUNTValueExtendedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct COUNTvalueIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5461,11 +6557,11 @@ pub struct COUNTvalueIE_Extensions_Entry {}
 )]
 pub struct COUNTvalueIE_Extensions(pub Vec<COUNTvalueIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct COUNTvaluePDCP_SNlength18IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5476,11 +6572,11 @@ pub struct COUNTvaluePDCP_SNlength18IE_Extensions(
     pub Vec<COUNTvaluePDCP_SNlength18IE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CSG_IdList_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5489,11 +6585,11 @@ pub struct CSG_IdList_ItemIE_Extensions_Entry {}
 )]
 pub struct CSG_IdList_ItemIE_Extensions(pub Vec<CSG_IdList_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CSGMembershipInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5502,11 +6598,11 @@ pub struct CSGMembershipInfoIE_Extensions_Entry {}
 )]
 pub struct CSGMembershipInfoIE_Extensions(pub Vec<CSGMembershipInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellinEAI_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5517,11 +6613,11 @@ pub struct CancelledCellinEAI_ItemIE_Extensions(
     pub Vec<CancelledCellinEAI_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CancelledCellinTAI_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5532,19 +6628,19 @@ pub struct CancelledCellinTAI_ItemIE_Extensions(
     pub Vec<CancelledCellinTAI_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "503")]
 pub struct INTEGER_5(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_6(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Cdma2000OneXSRVCCInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5553,15 +6649,15 @@ pub struct Cdma2000OneXSRVCCInfoIE_Extensions_Entry {}
 )]
 pub struct Cdma2000OneXSRVCCInfoIE_Extensions(pub Vec<Cdma2000OneXSRVCCInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "60")]
 pub struct INTEGER_7(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellBasedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5570,11 +6666,11 @@ pub struct CellBasedMDTIE_Extensions_Entry {}
 )]
 pub struct CellBasedMDTIE_Extensions(pub Vec<CellBasedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellBasedQMCIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5583,11 +6679,11 @@ pub struct CellBasedQMCIE_Extensions_Entry {}
 )]
 pub struct CellBasedQMCIE_Extensions(pub Vec<CellBasedQMCIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellID_Broadcast_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5596,11 +6692,11 @@ pub struct CellID_Broadcast_ItemIE_Extensions_Entry {}
 )]
 pub struct CellID_Broadcast_ItemIE_Extensions(pub Vec<CellID_Broadcast_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellID_Cancelled_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5609,11 +6705,11 @@ pub struct CellID_Cancelled_ItemIE_Extensions_Entry {}
 )]
 pub struct CellID_Cancelled_ItemIE_Extensions(pub Vec<CellID_Cancelled_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellIdentifierAndCELevelForCECapableUEsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5624,15 +6720,15 @@ pub struct CellIdentifierAndCELevelForCECapableUEsIE_Extensions(
     pub Vec<CellIdentifierAndCELevelForCECapableUEsIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_8(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_9(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum CellTrafficTraceProtocolIEs_EntryValue {
     #[asn(key = 86)]
@@ -5649,16 +6745,18 @@ pub enum CellTrafficTraceProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellTrafficTraceProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: CellTrafficTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5667,11 +6765,11 @@ pub struct CellTrafficTraceProtocolIEs_Entry {
 )]
 pub struct CellTrafficTraceProtocolIEs(pub Vec<CellTrafficTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CellTypeIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5680,15 +6778,15 @@ pub struct CellTypeIE_Extensions_Entry {}
 )]
 pub struct CellTypeIE_Extensions(pub Vec<CellTypeIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_10(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellinEAI_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5699,11 +6797,11 @@ pub struct CompletedCellinEAI_ItemIE_Extensions(
     pub Vec<CompletedCellinEAI_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CompletedCellinTAI_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5714,11 +6812,11 @@ pub struct CompletedCellinTAI_ItemIE_Extensions(
     pub Vec<CompletedCellinTAI_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ConnectedengNBItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5727,7 +6825,7 @@ pub struct ConnectedengNBItemIE_Extensions_Entry {}
 )]
 pub struct ConnectedengNBItemIE_Extensions(pub Vec<ConnectedengNBItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ConnectionEstablishmentIndicationProtocolIEs_EntryValue {
     #[asn(key = 271)]
@@ -5752,16 +6850,18 @@ pub enum ConnectionEstablishmentIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ConnectionEstablishmentIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ConnectionEstablishmentIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5772,11 +6872,11 @@ pub struct ConnectionEstablishmentIndicationProtocolIEs(
     pub Vec<ConnectionEstablishmentIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ContextatSourceIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5785,11 +6885,11 @@ pub struct ContextatSourceIE_Extensions_Entry {}
 )]
 pub struct ContextatSourceIE_Extensions(pub Vec<ContextatSourceIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5798,11 +6898,11 @@ pub struct CriticalityDiagnosticsIE_Extensions_Entry {}
 )]
 pub struct CriticalityDiagnosticsIE_Extensions(pub Vec<CriticalityDiagnosticsIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct CriticalityDiagnostics_IE_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5813,18 +6913,18 @@ pub struct CriticalityDiagnostics_IE_ItemIE_Extensions(
     pub Vec<CriticalityDiagnostics_IE_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_11(pub u8);
 impl ENUMERATED_11 {
     pub const D_APS_HO_REQUIRED: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSRequestInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5833,7 +6933,7 @@ pub struct DAPSRequestInfoIE_Extensions_Entry {}
 )]
 pub struct DAPSRequestInfoIE_Extensions(pub Vec<DAPSRequestInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_12(pub u8);
 impl ENUMERATED_12 {
@@ -5841,11 +6941,11 @@ impl ENUMERATED_12 {
     pub const D_APS_HO_NOT_ACCEPTED: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSResponseInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5854,11 +6954,11 @@ pub struct DAPSResponseInfoIE_Extensions_Entry {}
 )]
 pub struct DAPSResponseInfoIE_Extensions(pub Vec<DAPSResponseInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSResponseInfoItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5867,27 +6967,29 @@ pub struct DAPSResponseInfoItemIE_Extensions_Entry {}
 )]
 pub struct DAPSResponseInfoItemIE_Extensions(pub Vec<DAPSResponseInfoItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DAPSResponseInfoList_EntryValue {
     #[asn(key = 319)]
     Id_DAPSResponseInfoItem(DAPSResponseInfoItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DAPSResponseInfoList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DAPSResponseInfoList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DL_CP_SecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5898,7 +7000,7 @@ pub struct DL_CP_SecurityInformationIE_Extensions(
     pub Vec<DL_CP_SecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DeactivateTraceProtocolIEs_EntryValue {
     #[asn(key = 86)]
@@ -5909,16 +7011,18 @@ pub enum DeactivateTraceProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DeactivateTraceProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DeactivateTraceProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5927,7 +7031,7 @@ pub struct DeactivateTraceProtocolIEs_Entry {
 )]
 pub struct DeactivateTraceProtocolIEs(pub Vec<DeactivateTraceProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkNASTransportProtocolIEs_EntryValue {
     #[asn(key = 299)]
@@ -5966,16 +7070,18 @@ pub enum DownlinkNASTransportProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkNASTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkNASTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -5984,7 +7090,7 @@ pub struct DownlinkNASTransportProtocolIEs_Entry {
 )]
 pub struct DownlinkNASTransportProtocolIEs(pub Vec<DownlinkNASTransportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkNonUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 147)]
@@ -5993,16 +7099,18 @@ pub enum DownlinkNonUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     Id_Routing_ID(Routing_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkNonUEAssociatedLPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkNonUEAssociatedLPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6013,7 +7121,7 @@ pub struct DownlinkNonUEAssociatedLPPaTransportProtocolIEs(
     pub Vec<DownlinkNonUEAssociatedLPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkS1cdma2000tunnellingProtocolIEs_EntryValue {
     #[asn(key = 12)]
@@ -6030,16 +7138,18 @@ pub enum DownlinkS1cdma2000tunnellingProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkS1cdma2000tunnellingProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkS1cdma2000tunnellingProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6050,7 +7160,7 @@ pub struct DownlinkS1cdma2000tunnellingProtocolIEs(
     pub Vec<DownlinkS1cdma2000tunnellingProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum DownlinkUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 147)]
@@ -6063,16 +7173,18 @@ pub enum DownlinkUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct DownlinkUEAssociatedLPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: DownlinkUEAssociatedLPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6083,11 +7195,11 @@ pub struct DownlinkUEAssociatedLPPaTransportProtocolIEs(
     pub Vec<DownlinkUEAssociatedLPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABAdmittedItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6096,27 +7208,29 @@ pub struct E_RABAdmittedItemIE_Extensions_Entry {}
 )]
 pub struct E_RABAdmittedItemIE_Extensions(pub Vec<E_RABAdmittedItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABAdmittedList_EntryValue {
     #[asn(key = 20)]
     Id_E_RABAdmittedItem(E_RABAdmittedItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABAdmittedList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABAdmittedList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABDataForwardingItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6127,11 +7241,11 @@ pub struct E_RABDataForwardingItemIE_Extensions(
     pub Vec<E_RABDataForwardingItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABFailedToResumeItemResumeReqIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6142,11 +7256,11 @@ pub struct E_RABFailedToResumeItemResumeReqIE_Extensions(
     pub Vec<E_RABFailedToResumeItemResumeReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABFailedToResumeItemResumeResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6157,43 +7271,47 @@ pub struct E_RABFailedToResumeItemResumeResIE_Extensions(
     pub Vec<E_RABFailedToResumeItemResumeResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABFailedToResumeListResumeReq_EntryValue {
     #[asn(key = 236)]
     Id_E_RABFailedToResumeItemResumeReq(E_RABFailedToResumeItemResumeReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABFailedToResumeListResumeReq_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABFailedToResumeListResumeReq_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABFailedToResumeListResumeRes_EntryValue {
     #[asn(key = 238)]
     Id_E_RABFailedToResumeItemResumeRes(E_RABFailedToResumeItemResumeRes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABFailedToResumeListResumeRes_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABFailedToResumeListResumeRes_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABFailedToSetupItemHOReqAckIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6204,55 +7322,61 @@ pub struct E_RABFailedToSetupItemHOReqAckIE_Extensions(
     pub Vec<E_RABFailedToSetupItemHOReqAckIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABFailedtoSetupListHOReqAck_EntryValue {
     #[asn(key = 21)]
     Id_E_RABFailedtoSetupItemHOReqAck(E_RABFailedToSetupItemHOReqAck),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABFailedtoSetupListHOReqAck_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABFailedtoSetupListHOReqAck_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABInformationList_EntryValue {
     #[asn(key = 78)]
     Id_E_RABInformationListItem(E_RABInformationListItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABInformationList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABInformationList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABInformationListItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 317)]
     Id_DAPSRequestInfo(DAPSRequestInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABInformationListItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: E_RABInformationListItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6263,11 +7387,11 @@ pub struct E_RABInformationListItemIE_Extensions(
     pub Vec<E_RABInformationListItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6276,7 +7400,7 @@ pub struct E_RABItemIE_Extensions_Entry {}
 )]
 pub struct E_RABItemIE_Extensions(pub Vec<E_RABItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABLevelQoSParametersIE_Extensions_EntryExtensionValue {
     #[asn(key = 273)]
@@ -6285,16 +7409,18 @@ pub enum E_RABLevelQoSParametersIE_Extensions_EntryExtensionValue {
     Id_UplinkPacketLossRate(Packet_LossRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABLevelQoSParametersIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: E_RABLevelQoSParametersIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6305,23 +7431,25 @@ pub struct E_RABLevelQoSParametersIE_Extensions(
     pub Vec<E_RABLevelQoSParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABList_EntryValue {
     #[asn(key = 35)]
     Id_E_RABItem(E_RABItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABModificationConfirmProtocolIEs_EntryValue {
     #[asn(key = 146)]
@@ -6340,16 +7468,18 @@ pub enum E_RABModificationConfirmProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModificationConfirmProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABModificationConfirmProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6358,7 +7488,7 @@ pub struct E_RABModificationConfirmProtocolIEs_Entry {
 )]
 pub struct E_RABModificationConfirmProtocolIEs(pub Vec<E_RABModificationConfirmProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABModificationIndicationProtocolIEs_EntryValue {
     #[asn(key = 226)]
@@ -6379,16 +7509,18 @@ pub enum E_RABModificationIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModificationIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABModificationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6399,11 +7531,11 @@ pub struct E_RABModificationIndicationProtocolIEs(
     pub Vec<E_RABModificationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModifyItemBearerModConfIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6414,11 +7546,11 @@ pub struct E_RABModifyItemBearerModConfIE_Extensions(
     pub Vec<E_RABModifyItemBearerModConfIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModifyItemBearerModResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6429,39 +7561,43 @@ pub struct E_RABModifyItemBearerModResIE_Extensions(
     pub Vec<E_RABModifyItemBearerModResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABModifyListBearerModConf_EntryValue {
     #[asn(key = 204)]
     Id_E_RABModifyItemBearerModConf(E_RABModifyItemBearerModConf),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModifyListBearerModConf_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABModifyListBearerModConf_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABModifyListBearerModRes_EntryValue {
     #[asn(key = 37)]
     Id_E_RABModifyItemBearerModRes(E_RABModifyItemBearerModRes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModifyListBearerModRes_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABModifyListBearerModRes_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABModifyRequestProtocolIEs_EntryValue {
     #[asn(key = 30)]
@@ -6476,16 +7612,18 @@ pub enum E_RABModifyRequestProtocolIEs_EntryValue {
     Id_uEaggregateMaximumBitrate(UEAggregateMaximumBitrate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModifyRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABModifyRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6494,7 +7632,7 @@ pub struct E_RABModifyRequestProtocolIEs_Entry {
 )]
 pub struct E_RABModifyRequestProtocolIEs(pub Vec<E_RABModifyRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABModifyResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -6511,16 +7649,18 @@ pub enum E_RABModifyResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABModifyResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABModifyResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6529,11 +7669,11 @@ pub struct E_RABModifyResponseProtocolIEs_Entry {
 )]
 pub struct E_RABModifyResponseProtocolIEs(pub Vec<E_RABModifyResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABNotToBeModifiedItemBearerModIndIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6544,23 +7684,25 @@ pub struct E_RABNotToBeModifiedItemBearerModIndIE_Extensions(
     pub Vec<E_RABNotToBeModifiedItemBearerModIndIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABNotToBeModifiedListBearerModInd_EntryValue {
     #[asn(key = 202)]
     Id_E_RABNotToBeModifiedItemBearerModInd(E_RABNotToBeModifiedItemBearerModInd),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABNotToBeModifiedListBearerModInd_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABNotToBeModifiedListBearerModInd_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABReleaseCommandProtocolIEs_EntryValue {
     #[asn(key = 33)]
@@ -6575,16 +7717,18 @@ pub enum E_RABReleaseCommandProtocolIEs_EntryValue {
     Id_uEaggregateMaximumBitrate(UEAggregateMaximumBitrate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABReleaseCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABReleaseCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6593,7 +7737,7 @@ pub struct E_RABReleaseCommandProtocolIEs_Entry {
 )]
 pub struct E_RABReleaseCommandProtocolIEs(pub Vec<E_RABReleaseCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABReleaseIndicationProtocolIEs_EntryValue {
     #[asn(key = 110)]
@@ -6608,16 +7752,18 @@ pub enum E_RABReleaseIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABReleaseIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABReleaseIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6626,11 +7772,11 @@ pub struct E_RABReleaseIndicationProtocolIEs_Entry {
 )]
 pub struct E_RABReleaseIndicationProtocolIEs(pub Vec<E_RABReleaseIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABReleaseItemBearerRelCompIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6641,23 +7787,25 @@ pub struct E_RABReleaseItemBearerRelCompIE_Extensions(
     pub Vec<E_RABReleaseItemBearerRelCompIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABReleaseListBearerRelComp_EntryValue {
     #[asn(key = 15)]
     Id_E_RABReleaseItemBearerRelComp(E_RABReleaseItemBearerRelComp),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABReleaseListBearerRelComp_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABReleaseListBearerRelComp_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABReleaseResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -6676,16 +7824,18 @@ pub enum E_RABReleaseResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABReleaseResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABReleaseResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6694,11 +7844,11 @@ pub struct E_RABReleaseResponseProtocolIEs_Entry {
 )]
 pub struct E_RABReleaseResponseProtocolIEs(pub Vec<E_RABReleaseResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABSetupItemBearerSUResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6709,11 +7859,11 @@ pub struct E_RABSetupItemBearerSUResIE_Extensions(
     pub Vec<E_RABSetupItemBearerSUResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABSetupItemCtxtSUResIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6724,39 +7874,43 @@ pub struct E_RABSetupItemCtxtSUResIE_Extensions(
     pub Vec<E_RABSetupItemCtxtSUResIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABSetupListBearerSURes_EntryValue {
     #[asn(key = 39)]
     Id_E_RABSetupItemBearerSURes(E_RABSetupItemBearerSURes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABSetupListBearerSURes_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABSetupListBearerSURes_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABSetupListCtxtSURes_EntryValue {
     #[asn(key = 50)]
     Id_E_RABSetupItemCtxtSURes(E_RABSetupItemCtxtSURes),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABSetupListCtxtSURes_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABSetupListCtxtSURes_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 16)]
@@ -6769,16 +7923,18 @@ pub enum E_RABSetupRequestProtocolIEs_EntryValue {
     Id_uEaggregateMaximumBitrate(UEAggregateMaximumBitrate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABSetupRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6787,7 +7943,7 @@ pub struct E_RABSetupRequestProtocolIEs_Entry {
 )]
 pub struct E_RABSetupRequestProtocolIEs(pub Vec<E_RABSetupRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -6802,16 +7958,18 @@ pub enum E_RABSetupResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABSetupResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6820,27 +7978,29 @@ pub struct E_RABSetupResponseProtocolIEs_Entry {
 )]
 pub struct E_RABSetupResponseProtocolIEs(pub Vec<E_RABSetupResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABSubjecttoDataForwardingList_EntryValue {
     #[asn(key = 14)]
     Id_E_RABDataForwardingItem(E_RABDataForwardingItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABSubjecttoDataForwardingList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABSubjecttoDataForwardingList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeModifiedItemBearerModIndIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6851,23 +8011,25 @@ pub struct E_RABToBeModifiedItemBearerModIndIE_Extensions(
     pub Vec<E_RABToBeModifiedItemBearerModIndIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeModifiedItemBearerModReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 185)]
     Id_TransportInformation(TransportInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeModifiedItemBearerModReqIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: E_RABToBeModifiedItemBearerModReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6878,39 +8040,43 @@ pub struct E_RABToBeModifiedItemBearerModReqIE_Extensions(
     pub Vec<E_RABToBeModifiedItemBearerModReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeModifiedListBearerModInd_EntryValue {
     #[asn(key = 200)]
     Id_E_RABToBeModifiedItemBearerModInd(E_RABToBeModifiedItemBearerModInd),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeModifiedListBearerModInd_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABToBeModifiedListBearerModInd_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeModifiedListBearerModReq_EntryValue {
     #[asn(key = 36)]
     Id_E_RABToBeModifiedItemBearerModReq(E_RABToBeModifiedItemBearerModReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeModifiedListBearerModReq_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABToBeModifiedListBearerModReq_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSetupItemBearerSUReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 233)]
@@ -6923,16 +8089,18 @@ pub enum E_RABToBeSetupItemBearerSUReqIE_Extensions_EntryExtensionValue {
     Id_SIPTO_Correlation_ID(Correlation_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSetupItemBearerSUReqIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: E_RABToBeSetupItemBearerSUReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6943,7 +8111,7 @@ pub struct E_RABToBeSetupItemBearerSUReqIE_Extensions(
     pub Vec<E_RABToBeSetupItemBearerSUReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSetupItemCtxtSUReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 233)]
@@ -6956,16 +8124,18 @@ pub enum E_RABToBeSetupItemCtxtSUReqIE_Extensions_EntryExtensionValue {
     Id_SIPTO_Correlation_ID(Correlation_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSetupItemCtxtSUReqIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: E_RABToBeSetupItemCtxtSUReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -6976,7 +8146,7 @@ pub struct E_RABToBeSetupItemCtxtSUReqIE_Extensions(
     pub Vec<E_RABToBeSetupItemCtxtSUReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSetupItemHOReqIE_Extensions_EntryExtensionValue {
     #[asn(key = 233)]
@@ -6987,16 +8157,18 @@ pub enum E_RABToBeSetupItemHOReqIE_Extensions_EntryExtensionValue {
     Id_Ethernet_Type(Ethernet_Type),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSetupItemHOReqIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: E_RABToBeSetupItemHOReqIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7007,59 +8179,65 @@ pub struct E_RABToBeSetupItemHOReqIE_Extensions(
     pub Vec<E_RABToBeSetupItemHOReqIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSetupListBearerSUReq_EntryValue {
     #[asn(key = 17)]
     Id_E_RABToBeSetupItemBearerSUReq(E_RABToBeSetupItemBearerSUReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSetupListBearerSUReq_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABToBeSetupListBearerSUReq_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSetupListCtxtSUReq_EntryValue {
     #[asn(key = 52)]
     Id_E_RABToBeSetupItemCtxtSUReq(E_RABToBeSetupItemCtxtSUReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSetupListCtxtSUReq_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABToBeSetupListCtxtSUReq_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSetupListHOReq_EntryValue {
     #[asn(key = 27)]
     Id_E_RABToBeSetupItemHOReq(E_RABToBeSetupItemHOReq),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSetupListHOReq_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABToBeSetupListHOReq_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSwitchedDLItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7070,27 +8248,29 @@ pub struct E_RABToBeSwitchedDLItemIE_Extensions(
     pub Vec<E_RABToBeSwitchedDLItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSwitchedDLList_EntryValue {
     #[asn(key = 23)]
     Id_E_RABToBeSwitchedDLItem(E_RABToBeSwitchedDLItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSwitchedDLList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABToBeSwitchedDLList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSwitchedULItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7101,43 +8281,45 @@ pub struct E_RABToBeSwitchedULItemIE_Extensions(
     pub Vec<E_RABToBeSwitchedULItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABToBeSwitchedULList_EntryValue {
     #[asn(key = 94)]
     Id_E_RABToBeSwitchedULItem(E_RABToBeSwitchedULItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABToBeSwitchedULList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABToBeSwitchedULList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct OCTET_STRING_13(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "4", sz_ub = "4")]
 pub struct OCTET_STRING_14(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "18446744073709551615")]
 pub struct INTEGER_15(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "18446744073709551615")]
 pub struct INTEGER_16(pub u64);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABUsageReportItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7146,27 +8328,29 @@ pub struct E_RABUsageReportItemIE_Extensions_Entry {}
 )]
 pub struct E_RABUsageReportItemIE_Extensions(pub Vec<E_RABUsageReportItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum E_RABUsageReportList_EntryValue {
     #[asn(key = 267)]
     Id_E_RABUsageReportItem(E_RABUsageReportItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct E_RABUsageReportList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: E_RABUsageReportList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EN_DCSONConfigurationTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7177,11 +8361,11 @@ pub struct EN_DCSONConfigurationTransferIE_Extensions(
     pub Vec<EN_DCSONConfigurationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EN_DCSONeNBIdentificationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7192,11 +8376,11 @@ pub struct EN_DCSONeNBIdentificationIE_Extensions(
     pub Vec<EN_DCSONeNBIdentificationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EN_DCSONengNBIdentificationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7207,11 +8391,11 @@ pub struct EN_DCSONengNBIdentificationIE_Extensions(
     pub Vec<EN_DCSONengNBIdentificationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EN_DCTransferTypeReplyIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7220,11 +8404,11 @@ pub struct EN_DCTransferTypeReplyIE_Extensions_Entry {}
 )]
 pub struct EN_DCTransferTypeReplyIE_Extensions(pub Vec<EN_DCTransferTypeReplyIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EN_DCTransferTypeRequestIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7235,11 +8419,11 @@ pub struct EN_DCTransferTypeRequestIE_Extensions(
     pub Vec<EN_DCTransferTypeRequestIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENB_EarlyStatusTransfer_TransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7250,27 +8434,27 @@ pub struct ENB_EarlyStatusTransfer_TransparentContainerIE_Extensions(
     pub Vec<ENB_EarlyStatusTransfer_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "20", sz_ub = "20")]
 pub struct BIT_STRING_17(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "28", sz_ub = "28")]
 pub struct BIT_STRING_18(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "18", sz_ub = "18")]
 pub struct BIT_STRING_19(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "21", sz_ub = "21")]
 pub struct BIT_STRING_20(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENB_StatusTransfer_TransparentContainerIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7281,7 +8465,7 @@ pub struct ENB_StatusTransfer_TransparentContainerIE_Extensions(
     pub Vec<ENB_StatusTransfer_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBCPRelocationIndicationProtocolIEs_EntryValue {
     #[asn(key = 100)]
@@ -7296,16 +8480,18 @@ pub enum ENBCPRelocationIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBCPRelocationIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBCPRelocationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7316,7 +8502,7 @@ pub struct ENBCPRelocationIndicationProtocolIEs(
     pub Vec<ENBCPRelocationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBConfigurationTransferProtocolIEs_EntryValue {
     #[asn(key = 294)]
@@ -7327,16 +8513,18 @@ pub enum ENBConfigurationTransferProtocolIEs_EntryValue {
     Id_SONConfigurationTransferECT(SONConfigurationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBConfigurationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBConfigurationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7345,7 +8533,7 @@ pub struct ENBConfigurationTransferProtocolIEs_Entry {
 )]
 pub struct ENBConfigurationTransferProtocolIEs(pub Vec<ENBConfigurationTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBConfigurationUpdateProtocolIEs_EntryValue {
     #[asn(key = 128)]
@@ -7364,16 +8552,18 @@ pub enum ENBConfigurationUpdateProtocolIEs_EntryValue {
     Id_eNBname(ENBname),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBConfigurationUpdateProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBConfigurationUpdateProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7382,23 +8572,25 @@ pub struct ENBConfigurationUpdateProtocolIEs_Entry {
 )]
 pub struct ENBConfigurationUpdateProtocolIEs(pub Vec<ENBConfigurationUpdateProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 58)]
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBConfigurationUpdateAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBConfigurationUpdateAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7409,7 +8601,7 @@ pub struct ENBConfigurationUpdateAcknowledgeProtocolIEs(
     pub Vec<ENBConfigurationUpdateAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBConfigurationUpdateFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -7420,16 +8612,18 @@ pub enum ENBConfigurationUpdateFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBConfigurationUpdateFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBConfigurationUpdateFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7440,23 +8634,25 @@ pub struct ENBConfigurationUpdateFailureProtocolIEs(
     pub Vec<ENBConfigurationUpdateFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBDirectInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 121)]
     Id_Inter_SystemInformationTransferTypeEDT(Inter_SystemInformationTransferType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBDirectInformationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBDirectInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7467,7 +8663,7 @@ pub struct ENBDirectInformationTransferProtocolIEs(
     pub Vec<ENBDirectInformationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBEarlyStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -7478,16 +8674,18 @@ pub enum ENBEarlyStatusTransferProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBEarlyStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBEarlyStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7496,7 +8694,7 @@ pub struct ENBEarlyStatusTransferProtocolIEs_Entry {
 )]
 pub struct ENBEarlyStatusTransferProtocolIEs(pub Vec<ENBEarlyStatusTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ENBStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -7507,16 +8705,18 @@ pub enum ENBStatusTransferProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ENBStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7525,11 +8725,11 @@ pub struct ENBStatusTransferProtocolIEs_Entry {
 )]
 pub struct ENBStatusTransferProtocolIEs(pub Vec<ENBStatusTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ENBX2ExtTLAIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7538,11 +8738,11 @@ pub struct ENBX2ExtTLAIE_Extensions_Entry {}
 )]
 pub struct ENBX2ExtTLAIE_Extensions(pub Vec<ENBX2ExtTLAIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EUTRAN_CGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7551,15 +8751,15 @@ pub struct EUTRAN_CGIIE_Extensions_Entry {}
 )]
 pub struct EUTRAN_CGIIE_Extensions(pub Vec<EUTRAN_CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_21(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaID_Broadcast_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7570,11 +8770,11 @@ pub struct EmergencyAreaID_Broadcast_ItemIE_Extensions(
     pub Vec<EmergencyAreaID_Broadcast_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct EmergencyAreaID_Cancelled_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7585,7 +8785,7 @@ pub struct EmergencyAreaID_Cancelled_ItemIE_Extensions(
     pub Vec<EmergencyAreaID_Cancelled_ItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ErrorIndicationProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -7600,16 +8800,18 @@ pub enum ErrorIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ErrorIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ErrorIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7618,11 +8820,11 @@ pub struct ErrorIndicationProtocolIEs_Entry {
 )]
 pub struct ErrorIndicationProtocolIEs(pub Vec<ErrorIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEActivityBehaviourIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7633,11 +8835,11 @@ pub struct ExpectedUEActivityBehaviourIE_Extensions(
     pub Vec<ExpectedUEActivityBehaviourIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ExpectedUEBehaviourIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7646,11 +8848,11 @@ pub struct ExpectedUEBehaviourIE_Extensions_Entry {}
 )]
 pub struct ExpectedUEBehaviourIE_Extensions(pub Vec<ExpectedUEBehaviourIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct FiveGSTAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7659,11 +8861,11 @@ pub struct FiveGSTAIIE_Extensions_Entry {}
 )]
 pub struct FiveGSTAIIE_Extensions(pub Vec<FiveGSTAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ForbiddenLAs_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7672,11 +8874,11 @@ pub struct ForbiddenLAs_ItemIE_Extensions_Entry {}
 )]
 pub struct ForbiddenLAs_ItemIE_Extensions(pub Vec<ForbiddenLAs_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ForbiddenTAs_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7685,7 +8887,7 @@ pub struct ForbiddenTAs_ItemIE_Extensions_Entry {}
 )]
 pub struct ForbiddenTAs_ItemIE_Extensions(pub Vec<ForbiddenTAs_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum GBR_QosInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 257)]
@@ -7698,16 +8900,18 @@ pub enum GBR_QosInformationIE_Extensions_EntryExtensionValue {
     Id_extended_e_RAB_MaximumBitrateUL(ExtendedBitRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GBR_QosInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: GBR_QosInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7716,11 +8920,11 @@ pub struct GBR_QosInformationIE_Extensions_Entry {
 )]
 pub struct GBR_QosInformationIE_Extensions(pub Vec<GBR_QosInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GERAN_Cell_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7729,11 +8933,11 @@ pub struct GERAN_Cell_IDIE_Extensions_Entry {}
 )]
 pub struct GERAN_Cell_IDIE_Extensions(pub Vec<GERAN_Cell_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GNBIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7742,11 +8946,11 @@ pub struct GNBIE_Extensions_Entry {}
 )]
 pub struct GNBIE_Extensions(pub Vec<GNBIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct GUMMEIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7755,11 +8959,11 @@ pub struct GUMMEIIE_Extensions_Entry {}
 )]
 pub struct GUMMEIIE_Extensions(pub Vec<GUMMEIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Global_ENB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7768,11 +8972,11 @@ pub struct Global_ENB_IDIE_Extensions_Entry {}
 )]
 pub struct Global_ENB_IDIE_Extensions(pub Vec<Global_ENB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Global_GNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7781,11 +8985,11 @@ pub struct Global_GNB_IDIE_Extensions_Entry {}
 )]
 pub struct Global_GNB_IDIE_Extensions(pub Vec<Global_GNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Global_en_gNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7794,7 +8998,7 @@ pub struct Global_en_gNB_IDIE_Extensions_Entry {}
 )]
 pub struct Global_en_gNB_IDIE_Extensions(pub Vec<Global_en_gNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverCancelProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -7805,16 +9009,18 @@ pub enum HandoverCancelProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCancelProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverCancelProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7823,7 +9029,7 @@ pub struct HandoverCancelProtocolIEs_Entry {
 )]
 pub struct HandoverCancelProtocolIEs(pub Vec<HandoverCancelProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverCancelAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -7834,16 +9040,18 @@ pub enum HandoverCancelAcknowledgeProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCancelAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverCancelAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7854,7 +9062,7 @@ pub struct HandoverCancelAcknowledgeProtocolIEs(
     pub Vec<HandoverCancelAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverCommandProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -7877,16 +9085,18 @@ pub enum HandoverCommandProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7895,7 +9105,7 @@ pub struct HandoverCommandProtocolIEs_Entry {
 )]
 pub struct HandoverCommandProtocolIEs(pub Vec<HandoverCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -7906,16 +9116,18 @@ pub enum HandoverFailureProtocolIEs_EntryValue {
     Id_MME_UE_S1AP_ID(MME_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7924,7 +9136,7 @@ pub struct HandoverFailureProtocolIEs_Entry {
 )]
 pub struct HandoverFailureProtocolIEs(pub Vec<HandoverFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverNotifyProtocolIEs_EntryValue {
     #[asn(key = 100)]
@@ -7945,16 +9157,18 @@ pub enum HandoverNotifyProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverNotifyProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverNotifyProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7963,7 +9177,7 @@ pub struct HandoverNotifyProtocolIEs_Entry {
 )]
 pub struct HandoverNotifyProtocolIEs(pub Vec<HandoverNotifyProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverPreparationFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -7976,16 +9190,18 @@ pub enum HandoverPreparationFailureProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverPreparationFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverPreparationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -7996,7 +9212,7 @@ pub struct HandoverPreparationFailureProtocolIEs(
     pub Vec<HandoverPreparationFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestProtocolIEs_EntryValue {
     #[asn(key = 299)]
@@ -8075,16 +9291,18 @@ pub enum HandoverRequestProtocolIEs_EntryValue {
     Id_uEaggregateMaximumBitrate(UEAggregateMaximumBitrate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8093,7 +9311,7 @@ pub struct HandoverRequestProtocolIEs_Entry {
 )]
 pub struct HandoverRequestProtocolIEs(pub Vec<HandoverRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequestAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 242)]
@@ -8116,16 +9334,18 @@ pub enum HandoverRequestAcknowledgeProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequestAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverRequestAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8136,7 +9356,7 @@ pub struct HandoverRequestAcknowledgeProtocolIEs(
     pub Vec<HandoverRequestAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRequiredProtocolIEs_EntryValue {
     #[asn(key = 127)]
@@ -8169,16 +9389,18 @@ pub enum HandoverRequiredProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRequiredProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverRequiredProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8187,7 +9409,7 @@ pub struct HandoverRequiredProtocolIEs_Entry {
 )]
 pub struct HandoverRequiredProtocolIEs(pub Vec<HandoverRequiredProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverRestrictionListIE_Extensions_EntryExtensionValue {
     #[asn(key = 282)]
@@ -8202,16 +9424,18 @@ pub enum HandoverRestrictionListIE_Extensions_EntryExtensionValue {
     Id_UnlicensedSpectrumRestriction(UnlicensedSpectrumRestriction),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverRestrictionListIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: HandoverRestrictionListIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8222,7 +9446,7 @@ pub struct HandoverRestrictionListIE_Extensions(
     pub Vec<HandoverRestrictionListIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum HandoverSuccessProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -8231,16 +9455,18 @@ pub enum HandoverSuccessProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct HandoverSuccessProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: HandoverSuccessProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8249,19 +9475,19 @@ pub struct HandoverSuccessProtocolIEs_Entry {
 )]
 pub struct HandoverSuccessProtocolIEs(pub Vec<HandoverSuccessProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_22(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_23(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_24(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ImmediateMDTIE_Extensions_EntryExtensionValue {
     #[asn(key = 284)]
@@ -8282,16 +9508,18 @@ pub enum ImmediateMDTIE_Extensions_EntryExtensionValue {
     Id_WLANMeasurementConfiguration(WLANMeasurementConfiguration),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ImmediateMDTIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ImmediateMDTIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8300,11 +9528,11 @@ pub struct ImmediateMDTIE_Extensions_Entry {
 )]
 pub struct ImmediateMDTIE_Extensions(pub Vec<ImmediateMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InformationOnRecommendedCellsAndENBsForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8315,7 +9543,7 @@ pub struct InformationOnRecommendedCellsAndENBsForPagingIE_Extensions(
     pub Vec<InformationOnRecommendedCellsAndENBsForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -8328,16 +9556,18 @@ pub enum InitialContextSetupFailureProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialContextSetupFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8348,7 +9578,7 @@ pub struct InitialContextSetupFailureProtocolIEs(
     pub Vec<InitialContextSetupFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupRequestProtocolIEs_EntryValue {
     #[asn(key = 187)]
@@ -8427,16 +9657,18 @@ pub enum InitialContextSetupRequestProtocolIEs_EntryValue {
     Id_uEaggregateMaximumBitrate(UEAggregateMaximumBitrate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialContextSetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8447,7 +9679,7 @@ pub struct InitialContextSetupRequestProtocolIEs(
     pub Vec<InitialContextSetupRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialContextSetupResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -8462,16 +9694,18 @@ pub enum InitialContextSetupResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialContextSetupResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialContextSetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8482,7 +9716,7 @@ pub struct InitialContextSetupResponseProtocolIEs(
     pub Vec<InitialContextSetupResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitialUEMessageProtocolIEs_EntryValue {
     #[asn(key = 242)]
@@ -8533,16 +9767,18 @@ pub enum InitialUEMessageProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InitialUEMessageProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: InitialUEMessageProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8551,7 +9787,7 @@ pub struct InitialUEMessageProtocolIEs_Entry {
 )]
 pub struct InitialUEMessageProtocolIEs(pub Vec<InitialUEMessageProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum InitiatingMessageValue {
     #[asn(key = 42)]
@@ -8690,15 +9926,15 @@ pub enum InitiatingMessageValue {
     Id_uplinkUEAssociatedLPPaTransport(UplinkUEAssociatedLPPaTransport),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "1024")]
 pub struct INTEGER_25(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "32")]
 pub struct INTEGER_26(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct ENUMERATED_27(pub u8);
 impl ENUMERATED_27 {
@@ -8709,39 +9945,39 @@ impl ENUMERATED_27 {
     pub const K_HZ240: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "16")]
 pub struct INTEGER_28(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_29(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_30(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_31(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_32(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_33(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_34(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemMeasurementItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8752,15 +9988,15 @@ pub struct InterSystemMeasurementItemIE_Extensions(
     pub Vec<InterSystemMeasurementItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "100")]
 pub struct INTEGER_35(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct InterSystemMeasurementParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8771,23 +10007,23 @@ pub struct InterSystemMeasurementParametersIE_Extensions(
     pub Vec<InterSystemMeasurementParametersIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_36(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_37(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "127")]
 pub struct INTEGER_38(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct IntersystemMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8798,7 +10034,7 @@ pub struct IntersystemMeasurementConfigurationIE_Extensions(
     pub Vec<IntersystemMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum KillRequestProtocolIEs_EntryValue {
     #[asn(key = 191)]
@@ -8811,16 +10047,18 @@ pub enum KillRequestProtocolIEs_EntryValue {
     Id_WarningAreaList(WarningAreaList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct KillRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: KillRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8829,7 +10067,7 @@ pub struct KillRequestProtocolIEs_Entry {
 )]
 pub struct KillRequestProtocolIEs(pub Vec<KillRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum KillResponseProtocolIEs_EntryValue {
     #[asn(key = 141)]
@@ -8842,16 +10080,18 @@ pub enum KillResponseProtocolIEs_EntryValue {
     Id_SerialNumber(SerialNumber),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct KillResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: KillResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8860,11 +10100,11 @@ pub struct KillResponseProtocolIEs_Entry {
 )]
 pub struct KillResponseProtocolIEs(pub Vec<KillResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8873,7 +10113,7 @@ pub struct LAIIE_Extensions_Entry {}
 )]
 pub struct LAIIE_Extensions(pub Vec<LAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LastVisitedEUTRANCellInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 168)]
@@ -8882,16 +10122,18 @@ pub enum LastVisitedEUTRANCellInformationIE_Extensions_EntryExtensionValue {
     Id_Time_UE_StayedInCell_EnhancedGranularity(Time_UE_StayedInCell_EnhancedGranularity),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LastVisitedEUTRANCellInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LastVisitedEUTRANCellInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8902,11 +10144,11 @@ pub struct LastVisitedEUTRANCellInformationIE_Extensions(
     pub Vec<LastVisitedEUTRANCellInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_39;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "3")]
 pub struct ENUMERATED_40(pub u8);
 impl ENUMERATED_40 {
@@ -8916,15 +10158,15 @@ impl ENUMERATED_40 {
     pub const MS10240: u8 = 3u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "10239", extensible = true)]
 pub struct INTEGER_41(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ListeningSubframePatternIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8935,7 +10177,7 @@ pub struct ListeningSubframePatternIE_Extensions(
     pub Vec<ListeningSubframePatternIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportProtocolIEs_EntryValue {
     #[asn(key = 100)]
@@ -8952,16 +10194,18 @@ pub enum LocationReportProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8970,7 +10214,7 @@ pub struct LocationReportProtocolIEs_Entry {
 )]
 pub struct LocationReportProtocolIEs(pub Vec<LocationReportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingControlProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -8981,16 +10225,18 @@ pub enum LocationReportingControlProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingControlProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportingControlProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -8999,7 +10245,7 @@ pub struct LocationReportingControlProtocolIEs_Entry {
 )]
 pub struct LocationReportingControlProtocolIEs(pub Vec<LocationReportingControlProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LocationReportingFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -9010,16 +10256,18 @@ pub enum LocationReportingFailureIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LocationReportingFailureIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: LocationReportingFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9030,11 +10278,11 @@ pub struct LocationReportingFailureIndicationProtocolIEs(
     pub Vec<LocationReportingFailureIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LoggedMBSFNMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9043,7 +10291,7 @@ pub struct LoggedMBSFNMDTIE_Extensions_Entry {}
 )]
 pub struct LoggedMBSFNMDTIE_Extensions(pub Vec<LoggedMBSFNMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum LoggedMDTIE_Extensions_EntryExtensionValue {
     #[asn(key = 284)]
@@ -9052,16 +10300,18 @@ pub enum LoggedMDTIE_Extensions_EntryExtensionValue {
     Id_WLANMeasurementConfiguration(WLANMeasurementConfiguration),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct LoggedMDTIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: LoggedMDTIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9070,11 +10320,11 @@ pub struct LoggedMDTIE_Extensions_Entry {
 )]
 pub struct LoggedMDTIE_Extensions(pub Vec<LoggedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1PeriodicReportingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9083,11 +10333,11 @@ pub struct M1PeriodicReportingIE_Extensions_Entry {}
 )]
 pub struct M1PeriodicReportingIE_Extensions(pub Vec<M1PeriodicReportingIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M1ThresholdEventA2IE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9096,11 +10346,11 @@ pub struct M1ThresholdEventA2IE_Extensions_Entry {}
 )]
 pub struct M1ThresholdEventA2IE_Extensions(pub Vec<M1ThresholdEventA2IE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M3ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9109,11 +10359,11 @@ pub struct M3ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M3ConfigurationIE_Extensions(pub Vec<M3ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M4ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9122,11 +10372,11 @@ pub struct M4ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M4ConfigurationIE_Extensions(pub Vec<M4ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M5ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9135,11 +10385,11 @@ pub struct M5ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M5ConfigurationIE_Extensions(pub Vec<M5ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M6ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9148,11 +10398,11 @@ pub struct M6ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M6ConfigurationIE_Extensions(pub Vec<M6ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct M7ConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9161,15 +10411,15 @@ pub struct M7ConfigurationIE_Extensions_Entry {}
 )]
 pub struct M7ConfigurationIE_Extensions(pub Vec<M7ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "255")]
 pub struct INTEGER_42(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MBSFN_ResultToLogInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9178,23 +10428,25 @@ pub struct MBSFN_ResultToLogInfoIE_Extensions_Entry {}
 )]
 pub struct MBSFN_ResultToLogInfoIE_Extensions(pub Vec<MBSFN_ResultToLogInfoIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MDT_ConfigurationIE_Extensions_EntryExtensionValue {
     #[asn(key = 178)]
     Id_SignallingBasedMDTPLMNList(MDTPLMNList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MDT_ConfigurationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: MDT_ConfigurationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9203,14 +10455,14 @@ pub struct MDT_ConfigurationIE_Extensions_Entry {
 )]
 pub struct MDT_ConfigurationIE_Extensions(pub Vec<MDT_ConfigurationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MDTMode_ExtensionValue {
     #[asn(key = 197)]
     Id_LoggedMBSFNMDT(LoggedMBSFNMDT),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMECPRelocationIndicationProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -9219,16 +10471,18 @@ pub enum MMECPRelocationIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMECPRelocationIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMECPRelocationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9239,7 +10493,7 @@ pub struct MMECPRelocationIndicationProtocolIEs(
     pub Vec<MMECPRelocationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMEConfigurationTransferProtocolIEs_EntryValue {
     #[asn(key = 295)]
@@ -9250,16 +10504,18 @@ pub enum MMEConfigurationTransferProtocolIEs_EntryValue {
     Id_SONConfigurationTransferMCT(SONConfigurationTransfer),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMEConfigurationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMEConfigurationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9268,7 +10524,7 @@ pub struct MMEConfigurationTransferProtocolIEs_Entry {
 )]
 pub struct MMEConfigurationTransferProtocolIEs(pub Vec<MMEConfigurationTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMEConfigurationUpdateProtocolIEs_EntryValue {
     #[asn(key = 61)]
@@ -9281,16 +10537,18 @@ pub enum MMEConfigurationUpdateProtocolIEs_EntryValue {
     Id_ServedGUMMEIs(ServedGUMMEIs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMEConfigurationUpdateProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMEConfigurationUpdateProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9299,23 +10557,25 @@ pub struct MMEConfigurationUpdateProtocolIEs_Entry {
 )]
 pub struct MMEConfigurationUpdateProtocolIEs(pub Vec<MMEConfigurationUpdateProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMEConfigurationUpdateAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 58)]
     Id_CriticalityDiagnostics(CriticalityDiagnostics),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMEConfigurationUpdateAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMEConfigurationUpdateAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9326,7 +10586,7 @@ pub struct MMEConfigurationUpdateAcknowledgeProtocolIEs(
     pub Vec<MMEConfigurationUpdateAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMEConfigurationUpdateFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -9337,16 +10597,18 @@ pub enum MMEConfigurationUpdateFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMEConfigurationUpdateFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMEConfigurationUpdateFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9357,23 +10619,25 @@ pub struct MMEConfigurationUpdateFailureProtocolIEs(
     pub Vec<MMEConfigurationUpdateFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMEDirectInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 122)]
     Id_Inter_SystemInformationTransferTypeMDT(Inter_SystemInformationTransferType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMEDirectInformationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMEDirectInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9384,7 +10648,7 @@ pub struct MMEDirectInformationTransferProtocolIEs(
     pub Vec<MMEDirectInformationTransferProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMEEarlyStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -9395,16 +10659,18 @@ pub enum MMEEarlyStatusTransferProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMEEarlyStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMEEarlyStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9413,7 +10679,7 @@ pub struct MMEEarlyStatusTransferProtocolIEs_Entry {
 )]
 pub struct MMEEarlyStatusTransferProtocolIEs(pub Vec<MMEEarlyStatusTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum MMEStatusTransferProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -9424,16 +10690,18 @@ pub enum MMEStatusTransferProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MMEStatusTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: MMEStatusTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9442,15 +10710,15 @@ pub struct MMEStatusTransferProtocolIEs_Entry {
 )]
 pub struct MMEStatusTransferProtocolIEs(pub Vec<MMEStatusTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_43(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_44(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "4")]
 pub struct ENUMERATED_45(pub u8);
 impl ENUMERATED_45 {
@@ -9461,15 +10729,15 @@ impl ENUMERATED_45 {
     pub const MS10240: u8 = 4u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "10239", extensible = true)]
 pub struct INTEGER_46(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct MutingPatternInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9480,7 +10748,7 @@ pub struct MutingPatternInformationIE_Extensions(
     pub Vec<MutingPatternInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NASDeliveryIndicationProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -9489,16 +10757,18 @@ pub enum NASDeliveryIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NASDeliveryIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NASDeliveryIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9507,7 +10777,7 @@ pub struct NASDeliveryIndicationProtocolIEs_Entry {
 )]
 pub struct NASDeliveryIndicationProtocolIEs(pub Vec<NASDeliveryIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum NASNonDeliveryIndicationProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -9520,16 +10790,18 @@ pub enum NASNonDeliveryIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NASNonDeliveryIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: NASNonDeliveryIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9538,11 +10810,11 @@ pub struct NASNonDeliveryIndicationProtocolIEs_Entry {
 )]
 pub struct NASNonDeliveryIndicationProtocolIEs(pub Vec<NASNonDeliveryIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NB_IoT_Paging_eDRXInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9553,11 +10825,11 @@ pub struct NB_IoT_Paging_eDRXInformationIE_Extensions(
     pub Vec<NB_IoT_Paging_eDRXInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NG_eNBIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9566,11 +10838,11 @@ pub struct NG_eNBIE_Extensions_Entry {}
 )]
 pub struct NG_eNBIE_Extensions(pub Vec<NG_eNBIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NR_CGIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9579,11 +10851,11 @@ pub struct NR_CGIIE_Extensions_Entry {}
 )]
 pub struct NR_CGIIE_Extensions(pub Vec<NR_CGIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRUESecurityCapabilitiesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9594,11 +10866,11 @@ pub struct NRUESecurityCapabilitiesIE_Extensions(
     pub Vec<NRUESecurityCapabilitiesIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRUESidelinkAggregateMaximumBitrateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9609,11 +10881,11 @@ pub struct NRUESidelinkAggregateMaximumBitrateIE_Extensions(
     pub Vec<NRUESidelinkAggregateMaximumBitrateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct NRV2XServicesAuthorizedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9624,11 +10896,11 @@ pub struct NRV2XServicesAuthorizedIE_Extensions(
     pub Vec<NRV2XServicesAuthorizedIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_47(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum OverloadStartProtocolIEs_EntryValue {
     #[asn(key = 154)]
@@ -9639,16 +10911,18 @@ pub enum OverloadStartProtocolIEs_EntryValue {
     Id_TrafficLoadReductionIndication(TrafficLoadReductionIndication),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStartProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: OverloadStartProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9657,23 +10931,25 @@ pub struct OverloadStartProtocolIEs_Entry {
 )]
 pub struct OverloadStartProtocolIEs(pub Vec<OverloadStartProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum OverloadStopProtocolIEs_EntryValue {
     #[asn(key = 154)]
     Id_GUMMEIList(GUMMEIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct OverloadStopProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: OverloadStopProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9682,11 +10958,11 @@ pub struct OverloadStopProtocolIEs_Entry {
 )]
 pub struct OverloadStopProtocolIEs(pub Vec<OverloadStopProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5FlowBitRatesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9695,11 +10971,11 @@ pub struct PC5FlowBitRatesIE_Extensions_Entry {}
 )]
 pub struct PC5FlowBitRatesIE_Extensions(pub Vec<PC5FlowBitRatesIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5QoSFlowItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9708,11 +10984,11 @@ pub struct PC5QoSFlowItemIE_Extensions_Entry {}
 )]
 pub struct PC5QoSFlowItemIE_Extensions(pub Vec<PC5QoSFlowItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PC5QoSParametersIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9721,11 +10997,11 @@ pub struct PC5QoSParametersIE_Extensions_Entry {}
 )]
 pub struct PC5QoSParametersIE_Extensions(pub Vec<PC5QoSParametersIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PLMNAreaBasedQMCIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9734,11 +11010,11 @@ pub struct PLMNAreaBasedQMCIE_Extensions_Entry {}
 )]
 pub struct PLMNAreaBasedQMCIE_Extensions(pub Vec<PLMNAreaBasedQMCIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PSCellInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9747,7 +11023,7 @@ pub struct PSCellInformationIE_Extensions_Entry {}
 )]
 pub struct PSCellInformationIE_Extensions(pub Vec<PSCellInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PWSFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 59)]
@@ -9756,16 +11032,18 @@ pub enum PWSFailureIndicationProtocolIEs_EntryValue {
     Id_PWSfailedECGIList(PWSfailedECGIList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSFailureIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PWSFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9774,7 +11052,7 @@ pub struct PWSFailureIndicationProtocolIEs_Entry {
 )]
 pub struct PWSFailureIndicationProtocolIEs(pub Vec<PWSFailureIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PWSRestartIndicationProtocolIEs_EntryValue {
     #[asn(key = 182)]
@@ -9787,16 +11065,18 @@ pub enum PWSRestartIndicationProtocolIEs_EntryValue {
     Id_TAIListForRestart(TAIListForRestart),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PWSRestartIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PWSRestartIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9805,7 +11085,7 @@ pub struct PWSRestartIndicationProtocolIEs_Entry {
 )]
 pub struct PWSRestartIndicationProtocolIEs(pub Vec<PWSRestartIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PagingProtocolIEs_EntryValue {
     #[asn(key = 211)]
@@ -9846,16 +11126,18 @@ pub enum PagingProtocolIEs_EntryValue {
     Id_pagingDRX(PagingDRX),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PagingProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9864,11 +11146,11 @@ pub struct PagingProtocolIEs_Entry {
 )]
 pub struct PagingProtocolIEs(pub Vec<PagingProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Paging_eDRXInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9877,11 +11159,11 @@ pub struct Paging_eDRXInformationIE_Extensions_Entry {}
 )]
 pub struct Paging_eDRXInformationIE_Extensions(pub Vec<Paging_eDRXInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PagingAttemptInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9892,7 +11174,7 @@ pub struct PagingAttemptInformationIE_Extensions(
     pub Vec<PagingAttemptInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestProtocolIEs_EntryValue {
     #[asn(key = 127)]
@@ -9927,16 +11209,18 @@ pub enum PathSwitchRequestProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PathSwitchRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -9945,7 +11229,7 @@ pub struct PathSwitchRequestProtocolIEs_Entry {
 )]
 pub struct PathSwitchRequestProtocolIEs(pub Vec<PathSwitchRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 299)]
@@ -10000,16 +11284,18 @@ pub enum PathSwitchRequestAcknowledgeProtocolIEs_EntryValue {
     Id_uEaggregateMaximumBitrate(UEAggregateMaximumBitrate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PathSwitchRequestAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10020,7 +11306,7 @@ pub struct PathSwitchRequestAcknowledgeProtocolIEs(
     pub Vec<PathSwitchRequestAcknowledgeProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum PathSwitchRequestFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -10033,16 +11319,18 @@ pub enum PathSwitchRequestFailureProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PathSwitchRequestFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: PathSwitchRequestFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10051,19 +11339,19 @@ pub struct PathSwitchRequestFailureProtocolIEs_Entry {
 )]
 pub struct PathSwitchRequestFailureProtocolIEs(pub Vec<PathSwitchRequestFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "65535")]
 pub struct INTEGER_48(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OBJECT-IDENTIFIER")]
 pub struct OBJECT_IDENTIFIER_49;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct PrivateMessagePrivateIEs_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10072,23 +11360,25 @@ pub struct PrivateMessagePrivateIEs_Entry {}
 )]
 pub struct PrivateMessagePrivateIEs(pub Vec<PrivateMessagePrivateIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ProSeAuthorizedIE_Extensions_EntryExtensionValue {
     #[asn(key = 216)]
     Id_ProSeUEtoNetworkRelaying(ProSeUEtoNetworkRelaying),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ProSeAuthorizedIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ProSeAuthorizedIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10097,7 +11387,7 @@ pub struct ProSeAuthorizedIE_Extensions_Entry {
 )]
 pub struct ProSeAuthorizedIE_Extensions(pub Vec<ProSeAuthorizedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -10106,11 +11396,11 @@ pub struct ProSeAuthorizedIE_Extensions(pub Vec<ProSeAuthorizedIE_Extensions_Ent
 )]
 pub struct OCTET_STRING_50(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RIMTransferIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10119,23 +11409,25 @@ pub struct RIMTransferIE_Extensions_Entry {}
 )]
 pub struct RIMTransferIE_Extensions(pub Vec<RIMTransferIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RLFReportInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 313)]
     Id_NB_IoT_RLF_Report_Container(NB_IoT_RLF_Report_Container),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RLFReportInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RLFReportInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10144,15 +11436,15 @@ pub struct RLFReportInformationIE_Extensions_Entry {
 )]
 pub struct RLFReportInformationIE_Extensions(pub Vec<RLFReportInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "4095")]
 pub struct INTEGER_51(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedCellItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10161,27 +11453,29 @@ pub struct RecommendedCellItemIE_Extensions_Entry {}
 )]
 pub struct RecommendedCellItemIE_Extensions(pub Vec<RecommendedCellItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RecommendedCellList_EntryValue {
     #[asn(key = 214)]
     Id_RecommendedCellItem(RecommendedCellItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedCellList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RecommendedCellList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedCellsForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10192,11 +11486,11 @@ pub struct RecommendedCellsForPagingIE_Extensions(
     pub Vec<RecommendedCellsForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedENBItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10205,27 +11499,29 @@ pub struct RecommendedENBItemIE_Extensions_Entry {}
 )]
 pub struct RecommendedENBItemIE_Extensions(pub Vec<RecommendedENBItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RecommendedENBList_EntryValue {
     #[asn(key = 215)]
     Id_RecommendedENBItem(RecommendedENBItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedENBList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RecommendedENBList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RecommendedENBsForPagingIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10236,23 +11532,25 @@ pub struct RecommendedENBsForPagingIE_Extensions(
     pub Vec<RecommendedENBsForPagingIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RequestTypeIE_Extensions_EntryExtensionValue {
     #[asn(key = 298)]
     Id_RequestTypeAdditionalInfo(RequestTypeAdditionalInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RequestTypeIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: RequestTypeIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10261,7 +11559,7 @@ pub struct RequestTypeIE_Extensions_Entry {
 )]
 pub struct RequestTypeIE_Extensions(pub Vec<RequestTypeIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RerouteNASRequestProtocolIEs_EntryValue {
     #[asn(key = 224)]
@@ -10276,16 +11574,18 @@ pub enum RerouteNASRequestProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RerouteNASRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RerouteNASRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10294,7 +11594,7 @@ pub struct RerouteNASRequestProtocolIEs_Entry {
 )]
 pub struct RerouteNASRequestProtocolIEs(pub Vec<RerouteNASRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -10303,16 +11603,18 @@ pub enum ResetProtocolIEs_EntryValue {
     Id_ResetType(ResetType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10321,7 +11623,7 @@ pub struct ResetProtocolIEs_Entry {
 )]
 pub struct ResetProtocolIEs(pub Vec<ResetProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ResetAcknowledgeProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -10330,16 +11632,18 @@ pub enum ResetAcknowledgeProtocolIEs_EntryValue {
     Id_UE_associatedLogicalS1_ConnectionListResAck(UE_associatedLogicalS1_ConnectionListResAck),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ResetAcknowledgeProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: ResetAcknowledgeProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10348,23 +11652,25 @@ pub struct ResetAcknowledgeProtocolIEs_Entry {
 )]
 pub struct ResetAcknowledgeProtocolIEs(pub Vec<ResetAcknowledgeProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum RetrieveUEInformationProtocolIEs_EntryValue {
     #[asn(key = 96)]
     Id_S_TMSI(S_TMSI),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct RetrieveUEInformationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: RetrieveUEInformationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10373,11 +11679,11 @@ pub struct RetrieveUEInformationProtocolIEs_Entry {
 )]
 pub struct RetrieveUEInformationProtocolIEs(pub Vec<RetrieveUEInformationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct S_TMSIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10386,7 +11692,7 @@ pub struct S_TMSIIE_Extensions_Entry {}
 )]
 pub struct S_TMSIIE_Extensions(pub Vec<S_TMSIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum S1SetupFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -10397,16 +11703,18 @@ pub enum S1SetupFailureProtocolIEs_EntryValue {
     Id_TimeToWait(TimeToWait),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct S1SetupFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: S1SetupFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10415,7 +11723,7 @@ pub struct S1SetupFailureProtocolIEs_Entry {
 )]
 pub struct S1SetupFailureProtocolIEs(pub Vec<S1SetupFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum S1SetupRequestProtocolIEs_EntryValue {
     #[asn(key = 128)]
@@ -10436,16 +11744,18 @@ pub enum S1SetupRequestProtocolIEs_EntryValue {
     Id_eNBname(ENBname),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct S1SetupRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: S1SetupRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10454,7 +11764,7 @@ pub struct S1SetupRequestProtocolIEs_Entry {
 )]
 pub struct S1SetupRequestProtocolIEs(pub Vec<S1SetupRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum S1SetupResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -10475,16 +11785,18 @@ pub enum S1SetupResponseProtocolIEs_EntryValue {
     Id_UE_RetentionInformation(UE_RetentionInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct S1SetupResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: S1SetupResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10493,7 +11805,7 @@ pub struct S1SetupResponseProtocolIEs_Entry {
 )]
 pub struct S1SetupResponseProtocolIEs(pub Vec<S1SetupResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SONConfigurationTransferIE_Extensions_EntryExtensionValue {
     #[asn(key = 209)]
@@ -10502,16 +11814,18 @@ pub enum SONConfigurationTransferIE_Extensions_EntryExtensionValue {
     Id_x2TNLConfigurationInfo(X2TNLConfigurationInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONConfigurationTransferIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: SONConfigurationTransferIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10522,14 +11836,14 @@ pub struct SONConfigurationTransferIE_Extensions(
     pub Vec<SONConfigurationTransferIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SONInformation_ExtensionValue {
     #[asn(key = 206)]
     Id_SON_Information_Report(SONInformationReport),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SONInformationReplyIE_Extensions_EntryExtensionValue {
     #[asn(key = 208)]
@@ -10538,16 +11852,18 @@ pub enum SONInformationReplyIE_Extensions_EntryExtensionValue {
     Id_Time_Synchronisation_Info(TimeSynchronisationInfo),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SONInformationReplyIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: SONInformationReplyIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10556,39 +11872,39 @@ pub struct SONInformationReplyIE_Extensions_Entry {
 )]
 pub struct SONInformationReplyIE_Extensions(pub Vec<SONInformationReplyIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_52;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_53;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_54;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "NULL")]
 pub struct NULL_55;
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "BITSTRING", sz_extensible = false, sz_lb = "7", sz_ub = "7")]
 pub struct BIT_STRING_56(pub BitVec<u8, Msb0>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "86399", extensible = true)]
 pub struct INTEGER_57(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "86399", extensible = true)]
 pub struct INTEGER_58(pub u32);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ScheduledCommunicationTimeIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10599,7 +11915,7 @@ pub struct ScheduledCommunicationTimeIE_Extensions(
     pub Vec<ScheduledCommunicationTimeIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SecondaryRATDataUsageReportProtocolIEs_EntryValue {
     #[asn(key = 266)]
@@ -10616,16 +11932,18 @@ pub enum SecondaryRATDataUsageReportProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATDataUsageReportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SecondaryRATDataUsageReportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10636,11 +11954,11 @@ pub struct SecondaryRATDataUsageReportProtocolIEs(
     pub Vec<SecondaryRATDataUsageReportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATDataUsageReportItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10651,31 +11969,33 @@ pub struct SecondaryRATDataUsageReportItemIE_Extensions(
     pub Vec<SecondaryRATDataUsageReportItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SecondaryRATDataUsageReportList_EntryValue {
     #[asn(key = 265)]
     Id_SecondaryRATDataUsageReportItem(SecondaryRATDataUsageReportItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecondaryRATDataUsageReportList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: SecondaryRATDataUsageReportList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "0", ub = "7")]
 pub struct INTEGER_59(pub u8);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SecurityContextIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10684,11 +12004,11 @@ pub struct SecurityContextIE_Extensions_Entry {}
 )]
 pub struct SecurityContextIE_Extensions(pub Vec<SecurityContextIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ServedDCNsItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10697,23 +12017,25 @@ pub struct ServedDCNsItemIE_Extensions_Entry {}
 )]
 pub struct ServedDCNsItemIE_Extensions(pub Vec<ServedDCNsItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum ServedGUMMEIsItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 170)]
     Id_GUMMEIType(GUMMEIType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct ServedGUMMEIsItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: ServedGUMMEIsItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10722,11 +12044,11 @@ pub struct ServedGUMMEIsItemIE_Extensions_Entry {
 )]
 pub struct ServedGUMMEIsItemIE_Extensions(pub Vec<ServedGUMMEIsItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceNgRanNode_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10735,11 +12057,11 @@ pub struct SourceNgRanNode_IDIE_Extensions_Entry {}
 )]
 pub struct SourceNgRanNode_IDIE_Extensions(pub Vec<SourceNgRanNode_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceeNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10748,7 +12070,7 @@ pub struct SourceeNB_IDIE_Extensions_Entry {}
 )]
 pub struct SourceeNB_IDIE_Extensions(pub Vec<SourceeNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SourceeNB_ToTargeteNB_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 299)]
@@ -10769,17 +12091,19 @@ pub enum SourceeNB_ToTargeteNB_TransparentContainerIE_Extensions_EntryExtensionV
     Id_uE_HistoryInformationFromTheUE(UE_HistoryInformationFromTheUE),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SourceeNB_ToTargeteNB_TransparentContainerIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         SourceeNB_ToTargeteNB_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10790,7 +12114,7 @@ pub struct SourceeNB_ToTargeteNB_TransparentContainerIE_Extensions(
     pub Vec<SourceeNB_ToTargeteNB_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_60(pub u8);
 impl ENUMERATED_60 {
@@ -10798,11 +12122,11 @@ impl ENUMERATED_60 {
     pub const ONDEMAND: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "INTEGER", lb = "1", ub = "3600", extensible = true)]
 pub struct INTEGER_61(pub u16);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
 pub struct ENUMERATED_62(pub u8);
 impl ENUMERATED_62 {
@@ -10810,7 +12134,7 @@ impl ENUMERATED_62 {
     pub const MOBILE: u8 = 1u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct ENUMERATED_63(pub u8);
 impl ENUMERATED_63 {
@@ -10819,7 +12143,7 @@ impl ENUMERATED_63 {
     pub const MULTIPLE_PACKETS: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "2")]
 pub struct ENUMERATED_64(pub u8);
 impl ENUMERATED_64 {
@@ -10828,11 +12152,11 @@ impl ENUMERATED_64 {
     pub const NOT_BATTERY_POWERED: u8 = 2u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct Subscription_Based_UE_DifferentiationInfoIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10843,7 +12167,7 @@ pub struct Subscription_Based_UE_DifferentiationInfoIE_Extensions(
     pub Vec<Subscription_Based_UE_DifferentiationInfoIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SuccessfulOutcomeValue {
     #[asn(key = 50)]
@@ -10892,23 +12216,25 @@ pub enum SuccessfulOutcomeValue {
     Id_WriteReplaceWarning(WriteReplaceWarningResponse),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum SupportedTAs_ItemIE_Extensions_EntryExtensionValue {
     #[asn(key = 232)]
     Id_RAT_Type(RAT_Type),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SupportedTAs_ItemIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: SupportedTAs_ItemIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10917,11 +12243,11 @@ pub struct SupportedTAs_ItemIE_Extensions_Entry {
 )]
 pub struct SupportedTAs_ItemIE_Extensions(pub Vec<SupportedTAs_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct SynchronisationInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10932,11 +12258,11 @@ pub struct SynchronisationInformationIE_Extensions(
     pub Vec<SynchronisationInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TABasedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10945,11 +12271,11 @@ pub struct TABasedMDTIE_Extensions_Entry {}
 )]
 pub struct TABasedMDTIE_Extensions(pub Vec<TABasedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TABasedQMCIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10958,11 +12284,11 @@ pub struct TABasedQMCIE_Extensions_Entry {}
 )]
 pub struct TABasedQMCIE_Extensions(pub Vec<TABasedQMCIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10971,11 +12297,11 @@ pub struct TAIIE_Extensions_Entry {}
 )]
 pub struct TAIIE_Extensions(pub Vec<TAIIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAI_Broadcast_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10984,11 +12310,11 @@ pub struct TAI_Broadcast_ItemIE_Extensions_Entry {}
 )]
 pub struct TAI_Broadcast_ItemIE_Extensions(pub Vec<TAI_Broadcast_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAI_Cancelled_ItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -10997,11 +12323,11 @@ pub struct TAI_Cancelled_ItemIE_Extensions_Entry {}
 )]
 pub struct TAI_Cancelled_ItemIE_Extensions(pub Vec<TAI_Cancelled_ItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBasedMDTIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11010,11 +12336,11 @@ pub struct TAIBasedMDTIE_Extensions_Entry {}
 )]
 pub struct TAIBasedMDTIE_Extensions(pub Vec<TAIBasedMDTIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIBasedQMCIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11023,11 +12349,11 @@ pub struct TAIBasedQMCIE_Extensions_Entry {}
 )]
 pub struct TAIBasedQMCIE_Extensions(pub Vec<TAIBasedQMCIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11036,27 +12362,29 @@ pub struct TAIItemIE_Extensions_Entry {}
 )]
 pub struct TAIItemIE_Extensions(pub Vec<TAIItemIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TAIList_EntryValue {
     #[asn(key = 47)]
     Id_TAIItem(TAIItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TAIList_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: TAIList_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetNgRanNode_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11065,11 +12393,11 @@ pub struct TargetNgRanNode_IDIE_Extensions_Entry {}
 )]
 pub struct TargetNgRanNode_IDIE_Extensions(pub Vec<TargetNgRanNode_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargetRNC_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11078,11 +12406,11 @@ pub struct TargetRNC_IDIE_Extensions_Entry {}
 )]
 pub struct TargetRNC_IDIE_Extensions(pub Vec<TargetRNC_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargeteNB_IDIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11091,24 +12419,26 @@ pub struct TargeteNB_IDIE_Extensions_Entry {}
 )]
 pub struct TargeteNB_IDIE_Extensions(pub Vec<TargeteNB_IDIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TargeteNB_ToSourceeNB_TransparentContainerIE_Extensions_EntryExtensionValue {
     #[asn(key = 318)]
     Id_DAPSResponseInfoList(DAPSResponseInfoList),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TargeteNB_ToSourceeNB_TransparentContainerIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value:
         TargeteNB_ToSourceeNB_TransparentContainerIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11119,23 +12449,25 @@ pub struct TargeteNB_ToSourceeNB_TransparentContainerIE_Extensions(
     pub Vec<TargeteNB_ToSourceeNB_TransparentContainerIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TimeSynchronisationInfoIE_Extensions_EntryExtensionValue {
     #[asn(key = 207)]
     Id_Muting_Availability_Indication(MutingAvailabilityIndication),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TimeSynchronisationInfoIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: TimeSynchronisationInfoIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11146,11 +12478,11 @@ pub struct TimeSynchronisationInfoIE_Extensions(
     pub Vec<TimeSynchronisationInfoIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OCTET-STRING")]
 pub struct OCTET_STRING_65(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TraceActivationIE_Extensions_EntryExtensionValue {
     #[asn(key = 162)]
@@ -11163,16 +12495,18 @@ pub enum TraceActivationIE_Extensions_EntryExtensionValue {
     Id_UEAppLayerMeasConfig(UEAppLayerMeasConfig),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceActivationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: TraceActivationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11181,7 +12515,7 @@ pub struct TraceActivationIE_Extensions_Entry {
 )]
 pub struct TraceActivationIE_Extensions(pub Vec<TraceActivationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TraceFailureIndicationProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -11194,16 +12528,18 @@ pub enum TraceFailureIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceFailureIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: TraceFailureIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11212,7 +12548,7 @@ pub struct TraceFailureIndicationProtocolIEs_Entry {
 )]
 pub struct TraceFailureIndicationProtocolIEs(pub Vec<TraceFailureIndicationProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum TraceStartProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -11223,16 +12559,18 @@ pub enum TraceStartProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TraceStartProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: TraceStartProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11241,11 +12579,11 @@ pub struct TraceStartProtocolIEs_Entry {
 )]
 pub struct TraceStartProtocolIEs(pub Vec<TraceStartProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct TunnelInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11254,11 +12592,11 @@ pub struct TunnelInformationIE_Extensions_Entry {}
 )]
 pub struct TunnelInformationIE_Extensions(pub Vec<TunnelInformationIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_S1AP_ID_pairIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11267,11 +12605,11 @@ pub struct UE_S1AP_ID_pairIE_Extensions_Entry {}
 )]
 pub struct UE_S1AP_ID_pairIE_Extensions(pub Vec<UE_S1AP_ID_pairIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_associatedLogicalS1_ConnectionItemIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11282,39 +12620,43 @@ pub struct UE_associatedLogicalS1_ConnectionItemIE_Extensions(
     pub Vec<UE_associatedLogicalS1_ConnectionItemIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UE_associatedLogicalS1_ConnectionListRes_EntryValue {
     #[asn(key = 91)]
     Id_UE_associatedLogicalS1_ConnectionItem(UE_associatedLogicalS1_ConnectionItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_associatedLogicalS1_ConnectionListRes_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UE_associatedLogicalS1_ConnectionListRes_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UE_associatedLogicalS1_ConnectionListResAck_EntryValue {
     #[asn(key = 91)]
     Id_UE_associatedLogicalS1_ConnectionItem(UE_associatedLogicalS1_ConnectionItem),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UE_associatedLogicalS1_ConnectionListResAck_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UE_associatedLogicalS1_ConnectionListResAck_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEAggregateMaximumBitrateIE_Extensions_EntryExtensionValue {
     #[asn(key = 259)]
@@ -11323,16 +12665,18 @@ pub enum UEAggregateMaximumBitrateIE_Extensions_EntryExtensionValue {
     Id_extended_uEaggregateMaximumBitRateUL(ExtendedBitRate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEAggregateMaximumBitrateIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UEAggregateMaximumBitrateIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11343,7 +12687,7 @@ pub struct UEAggregateMaximumBitrateIE_Extensions(
     pub Vec<UEAggregateMaximumBitrateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "OCTET-STRING",
     sz_extensible = false,
@@ -11352,23 +12696,25 @@ pub struct UEAggregateMaximumBitrateIE_Extensions(
 )]
 pub struct OCTET_STRING_66(pub Vec<u8>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEAppLayerMeasConfigIE_Extensions_EntryExtensionValue {
     #[asn(key = 276)]
     Id_serviceType(ServiceType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEAppLayerMeasConfigIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UEAppLayerMeasConfigIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11377,7 +12723,7 @@ pub struct UEAppLayerMeasConfigIE_Extensions_Entry {
 )]
 pub struct UEAppLayerMeasConfigIE_Extensions(pub Vec<UEAppLayerMeasConfigIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UECapabilityInfoIndicationProtocolIEs_EntryValue {
     #[asn(key = 272)]
@@ -11396,16 +12742,18 @@ pub enum UECapabilityInfoIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UECapabilityInfoIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UECapabilityInfoIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11416,7 +12764,7 @@ pub struct UECapabilityInfoIndicationProtocolIEs(
     pub Vec<UECapabilityInfoIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationConfirmProtocolIEs_EntryValue {
     #[asn(key = 146)]
@@ -11429,16 +12777,18 @@ pub enum UEContextModificationConfirmProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationConfirmProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationConfirmProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11449,7 +12799,7 @@ pub struct UEContextModificationConfirmProtocolIEs(
     pub Vec<UEContextModificationConfirmProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -11462,16 +12812,18 @@ pub enum UEContextModificationFailureProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11482,7 +12834,7 @@ pub struct UEContextModificationFailureProtocolIEs(
     pub Vec<UEContextModificationFailureProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationIndicationProtocolIEs_EntryValue {
     #[asn(key = 226)]
@@ -11493,16 +12845,18 @@ pub enum UEContextModificationIndicationProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationIndicationProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationIndicationProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11513,7 +12867,7 @@ pub struct UEContextModificationIndicationProtocolIEs(
     pub Vec<UEContextModificationIndicationProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationRequestProtocolIEs_EntryValue {
     #[asn(key = 187)]
@@ -11564,16 +12918,18 @@ pub enum UEContextModificationRequestProtocolIEs_EntryValue {
     Id_uEaggregateMaximumBitrate(UEAggregateMaximumBitrate),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11584,7 +12940,7 @@ pub struct UEContextModificationRequestProtocolIEs(
     pub Vec<UEContextModificationRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextModificationResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -11595,16 +12951,18 @@ pub enum UEContextModificationResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextModificationResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextModificationResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11615,7 +12973,7 @@ pub struct UEContextModificationResponseProtocolIEs(
     pub Vec<UEContextModificationResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseCommandProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -11624,16 +12982,18 @@ pub enum UEContextReleaseCommandProtocolIEs_EntryValue {
     Id_UE_S1AP_IDs(UE_S1AP_IDs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseCommandProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextReleaseCommandProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11642,7 +13002,7 @@ pub struct UEContextReleaseCommandProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseCommandProtocolIEs(pub Vec<UEContextReleaseCommandProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseCompleteProtocolIEs_EntryValue {
     #[asn(key = 212)]
@@ -11663,16 +13023,18 @@ pub enum UEContextReleaseCompleteProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseCompleteProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextReleaseCompleteProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11681,7 +13043,7 @@ pub struct UEContextReleaseCompleteProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseCompleteProtocolIEs(pub Vec<UEContextReleaseCompleteProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextReleaseRequestProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -11696,16 +13058,18 @@ pub enum UEContextReleaseRequestProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextReleaseRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextReleaseRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11714,7 +13078,7 @@ pub struct UEContextReleaseRequestProtocolIEs_Entry {
 )]
 pub struct UEContextReleaseRequestProtocolIEs(pub Vec<UEContextReleaseRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeFailureProtocolIEs_EntryValue {
     #[asn(key = 2)]
@@ -11727,16 +13091,18 @@ pub enum UEContextResumeFailureProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeFailureProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextResumeFailureProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11745,7 +13111,7 @@ pub struct UEContextResumeFailureProtocolIEs_Entry {
 )]
 pub struct UEContextResumeFailureProtocolIEs(pub Vec<UEContextResumeFailureProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeRequestProtocolIEs_EntryValue {
     #[asn(key = 235)]
@@ -11758,16 +13124,18 @@ pub enum UEContextResumeRequestProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextResumeRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11776,7 +13144,7 @@ pub struct UEContextResumeRequestProtocolIEs_Entry {
 )]
 pub struct UEContextResumeRequestProtocolIEs(pub Vec<UEContextResumeRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextResumeResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -11793,16 +13161,18 @@ pub enum UEContextResumeResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextResumeResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextResumeResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11811,7 +13181,7 @@ pub struct UEContextResumeResponseProtocolIEs_Entry {
 )]
 pub struct UEContextResumeResponseProtocolIEs(pub Vec<UEContextResumeResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendRequestProtocolIEs_EntryValue {
     #[asn(key = 212)]
@@ -11830,16 +13200,18 @@ pub enum UEContextSuspendRequestProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextSuspendRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11848,7 +13220,7 @@ pub struct UEContextSuspendRequestProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendRequestProtocolIEs(pub Vec<UEContextSuspendRequestProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEContextSuspendResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -11861,16 +13233,18 @@ pub enum UEContextSuspendResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEContextSuspendResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEContextSuspendResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11879,7 +13253,7 @@ pub struct UEContextSuspendResponseProtocolIEs_Entry {
 )]
 pub struct UEContextSuspendResponseProtocolIEs(pub Vec<UEContextSuspendResponseProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UEInformationTransferProtocolIEs_EntryValue {
     #[asn(key = 283)]
@@ -11894,16 +13268,18 @@ pub enum UEInformationTransferProtocolIEs_EntryValue {
     Id_UERadioCapability(UERadioCapability),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UEInformationTransferProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UEInformationTransferProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11912,23 +13288,25 @@ pub struct UEInformationTransferProtocolIEs_Entry {
 )]
 pub struct UEInformationTransferProtocolIEs(pub Vec<UEInformationTransferProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityIDMappingRequestProtocolIEs_EntryValue {
     #[asn(key = 314)]
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityIDMappingRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityIDMappingRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11939,7 +13317,7 @@ pub struct UERadioCapabilityIDMappingRequestProtocolIEs(
     pub Vec<UERadioCapabilityIDMappingRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -11950,16 +13328,18 @@ pub enum UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue {
     Id_UERadioCapabilityID(UERadioCapabilityID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityIDMappingResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityIDMappingResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -11970,7 +13350,7 @@ pub struct UERadioCapabilityIDMappingResponseProtocolIEs(
     pub Vec<UERadioCapabilityIDMappingResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityMatchRequestProtocolIEs_EntryValue {
     #[asn(key = 0)]
@@ -11983,16 +13363,18 @@ pub enum UERadioCapabilityMatchRequestProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityMatchRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityMatchRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12003,7 +13385,7 @@ pub struct UERadioCapabilityMatchRequestProtocolIEs(
     pub Vec<UERadioCapabilityMatchRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UERadioCapabilityMatchResponseProtocolIEs_EntryValue {
     #[asn(key = 58)]
@@ -12016,16 +13398,18 @@ pub enum UERadioCapabilityMatchResponseProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UERadioCapabilityMatchResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UERadioCapabilityMatchResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12036,11 +13420,11 @@ pub struct UERadioCapabilityMatchResponseProtocolIEs(
     pub Vec<UERadioCapabilityMatchResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UESecurityCapabilitiesIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12049,11 +13433,11 @@ pub struct UESecurityCapabilitiesIE_Extensions_Entry {}
 )]
 pub struct UESecurityCapabilitiesIE_Extensions(pub Vec<UESecurityCapabilitiesIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UESidelinkAggregateMaximumBitrateIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12064,11 +13448,11 @@ pub struct UESidelinkAggregateMaximumBitrateIE_Extensions(
     pub Vec<UESidelinkAggregateMaximumBitrateIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UL_CP_SecurityInformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12079,7 +13463,7 @@ pub struct UL_CP_SecurityInformationIE_Extensions(
     pub Vec<UL_CP_SecurityInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UnsuccessfulOutcomeValue {
     #[asn(key = 29)]
@@ -12102,7 +13486,7 @@ pub enum UnsuccessfulOutcomeValue {
     Id_UEContextResume(UEContextResumeFailure),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkNASTransportProtocolIEs_EntryValue {
     #[asn(key = 100)]
@@ -12125,16 +13509,18 @@ pub enum UplinkNASTransportProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkNASTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkNASTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12143,7 +13529,7 @@ pub struct UplinkNASTransportProtocolIEs_Entry {
 )]
 pub struct UplinkNASTransportProtocolIEs(pub Vec<UplinkNASTransportProtocolIEs_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkNonUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 147)]
@@ -12152,16 +13538,18 @@ pub enum UplinkNonUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     Id_Routing_ID(Routing_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkNonUEAssociatedLPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkNonUEAssociatedLPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12172,7 +13560,7 @@ pub struct UplinkNonUEAssociatedLPPaTransportProtocolIEs(
     pub Vec<UplinkNonUEAssociatedLPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkS1cdma2000tunnellingProtocolIEs_EntryValue {
     #[asn(key = 140)]
@@ -12195,16 +13583,18 @@ pub enum UplinkS1cdma2000tunnellingProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkS1cdma2000tunnellingProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkS1cdma2000tunnellingProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12215,7 +13605,7 @@ pub struct UplinkS1cdma2000tunnellingProtocolIEs(
     pub Vec<UplinkS1cdma2000tunnellingProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UplinkUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     #[asn(key = 147)]
@@ -12228,16 +13618,18 @@ pub enum UplinkUEAssociatedLPPaTransportProtocolIEs_EntryValue {
     Id_eNB_UE_S1AP_ID(ENB_UE_S1AP_ID),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UplinkUEAssociatedLPPaTransportProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: UplinkUEAssociatedLPPaTransportProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12248,23 +13640,25 @@ pub struct UplinkUEAssociatedLPPaTransportProtocolIEs(
     pub Vec<UplinkUEAssociatedLPPaTransportProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum UserLocationInformationIE_Extensions_EntryExtensionValue {
     #[asn(key = 288)]
     Id_PSCellInformation(PSCellInformation),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct UserLocationInformationIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: UserLocationInformationIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12275,11 +13669,11 @@ pub struct UserLocationInformationIE_Extensions(
     pub Vec<UserLocationInformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct V2XServicesAuthorizedIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12288,25 +13682,25 @@ pub struct V2XServicesAuthorizedIE_Extensions_Entry {}
 )]
 pub struct V2XServicesAuthorizedIE_Extensions(pub Vec<V2XServicesAuthorizedIE_Extensions_Entry>);
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_67(pub u8);
 impl ENUMERATED_67 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "0")]
 pub struct ENUMERATED_68(pub u8);
 impl ENUMERATED_68 {
     pub const TRUE: u8 = 0u8;
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WLANMeasurementConfigurationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12317,11 +13711,11 @@ pub struct WLANMeasurementConfigurationIE_Extensions(
     pub Vec<WLANMeasurementConfigurationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WUS_Assistance_InformationIE_Extensions_Entry {}
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12332,7 +13726,7 @@ pub struct WUS_Assistance_InformationIE_Extensions(
     pub Vec<WUS_Assistance_InformationIE_Extensions_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum WriteReplaceWarningRequestProtocolIEs_EntryValue {
     #[asn(key = 142)]
@@ -12361,16 +13755,18 @@ pub enum WriteReplaceWarningRequestProtocolIEs_EntryValue {
     Id_WarningType(WarningType),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WriteReplaceWarningRequestProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: WriteReplaceWarningRequestProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12381,7 +13777,7 @@ pub struct WriteReplaceWarningRequestProtocolIEs(
     pub Vec<WriteReplaceWarningRequestProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum WriteReplaceWarningResponseProtocolIEs_EntryValue {
     #[asn(key = 120)]
@@ -12394,16 +13790,18 @@ pub enum WriteReplaceWarningResponseProtocolIEs_EntryValue {
     Id_SerialNumber(SerialNumber),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct WriteReplaceWarningResponseProtocolIEs_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub value: WriteReplaceWarningResponseProtocolIEs_EntryValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
@@ -12414,7 +13812,7 @@ pub struct WriteReplaceWarningResponseProtocolIEs(
     pub Vec<WriteReplaceWarningResponseProtocolIEs_Entry>,
 );
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "OPEN")]
 pub enum X2TNLConfigurationInfoIE_Extensions_EntryExtensionValue {
     #[asn(key = 193)]
@@ -12423,16 +13821,18 @@ pub enum X2TNLConfigurationInfoIE_Extensions_EntryExtensionValue {
     Id_eNBX2ExtendedTransportLayerAddresses(ENBX2ExtTLAs),
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(type = "SEQUENCE", extensible = false)]
 pub struct X2TNLConfigurationInfoIE_Extensions_Entry {
-    #[asn(key_field = true)]
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
     pub id: ProtocolExtensionID,
+    #[asn(tag = "[1] IMPLICIT")]
     pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
     pub extension_value: X2TNLConfigurationInfoIE_Extensions_EntryExtensionValue,
 }
 
-#[derive(Debug, AperCodec)]
+#[derive(Debug, PartialEq, AperCodec)]
 #[asn(
     type = "SEQUENCE-OF",
     sz_extensible = false,
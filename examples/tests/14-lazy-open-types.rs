@@ -0,0 +1,399 @@
+#![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::enum_variant_names,
+    clippy::upper_case_acronyms
+)]
+use asn1_codecs_derive::AperCodec;
+use bitvec::order::Msb0;
+use bitvec::vec::BitVec;
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Cause {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    RadioNetwork(CauseRadioNetwork),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    Misc(CauseMisc),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct CauseMisc(pub u8);
+impl CauseMisc {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HARDWARE_FAILURE: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct CauseRadioNetwork(pub u8);
+impl CauseRadioNetwork {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HANDOVER_CANCELLED: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(pub u8);
+impl Criticality {
+    pub const REJECT: u8 = 0u8;
+    pub const IGNORE: u8 = 1u8;
+    pub const NOTIFY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct InitiatingMessage {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: asn1_codecs::aper::LazyOpenType<InitiatingMessageValue>,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ItemID(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct ItemTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
+    pub cause: Cause,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "1",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
+pub enum Mini_PDU {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    InitiatingMessage(InitiatingMessage),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    SuccessfulOutcome(SuccessfulOutcome),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "PrintableString",
+    sz_extensible = true,
+    sz_lb = "1",
+    sz_ub = "150"
+)]
+pub struct NodeName(pub String);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct PLMNIdentity(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "1", ub = "8", extensible = true)]
+pub struct PagingPriority(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING")]
+pub struct Payload(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Presence(pub u8);
+impl Presence {
+    pub const OPTIONAL: u8 = 0u8;
+    pub const CONDITIONAL: u8 = 1u8;
+    pub const MANDATORY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ProcedureCode(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(pub u16);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "BITSTRING",
+    sz_extensible = false,
+    sz_lb = "256",
+    sz_ub = "256"
+)]
+pub struct SecurityKey(pub BitVec<u8, Msb0>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: SetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: SetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SuccessfulOutcome {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: asn1_codecs::aper::LazyOpenType<SuccessfulOutcomeValue>,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct TAC(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TrackingArea {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub tac: TAC,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub plmn_identity: PLMNIdentity,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
+pub struct TrackingAreas(pub Vec<TrackingArea>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Transfer {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: TransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TransferItem {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub item_id: ItemID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub item_transfer: OCTET_STRING_2,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "1099511627775")]
+pub struct UE_ID(pub u64);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum InitiatingMessageValue {
+    #[asn(key = 1)]
+    Id_Setup(SetupRequest),
+    #[asn(key = 2)]
+    Id_Transfer(Transfer),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum SetupRequestProtocolIEs_EntryValue {
+    #[asn(key = 1)]
+    Id_NodeName(NodeName),
+    #[asn(key = 3)]
+    Id_PagingPriority(PagingPriority),
+    #[asn(key = 2)]
+    Id_TrackingAreas(TrackingAreas),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupRequestProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: asn1_codecs::aper::LazyOpenType<SetupRequestProtocolIEs_EntryValue>,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupRequestProtocolIEs(pub Vec<SetupRequestProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum SetupResponseProtocolIEs_EntryValue {
+    #[asn(key = 1)]
+    Id_NodeName(NodeName),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupResponseProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: asn1_codecs::aper::LazyOpenType<SetupResponseProtocolIEs_EntryValue>,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupResponseProtocolIEs(pub Vec<SetupResponseProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum SuccessfulOutcomeValue {
+    #[asn(key = 1)]
+    Id_Setup(SetupResponse),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum TransferProtocolIEs_EntryValue {
+    #[asn(key = 5)]
+    Id_Payload(Payload),
+    #[asn(key = 7)]
+    Id_SecurityKey(SecurityKey),
+    #[asn(key = 6)]
+    Id_TransferItem(TransferItem),
+    #[asn(key = 4)]
+    Id_UE_ID(UE_ID),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct TransferProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: asn1_codecs::aper::LazyOpenType<TransferProtocolIEs_EntryValue>,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct TransferProtocolIEs(pub Vec<TransferProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING")]
+pub struct OCTET_STRING_2(pub Vec<u8>);
+impl OCTET_STRING_2 {
+    pub fn decode_contained(&self) -> Result<ItemTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) =
+            <ItemTransfer as asn1_codecs::Decode<asn1_codecs::aper::Aper>>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(value: &ItemTransfer) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <ItemTransfer as asn1_codecs::Encode<asn1_codecs::aper::Aper>>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+fn main() {
+    use asn1_codecs::aper::*;
+    use asn1_codecs::{Decode, Encode};
+    eprintln!("Lazy Open Types");
+
+    let setup_request_str = "0001002e0000030001401006804d79206c6974746c65206e6f64650002000e1000000102f8980000000202f8980003400120";
+    let setup_request = hex::decode(setup_request_str).unwrap();
+
+    // Only the Open Type values that are accessed are decoded, the others are re-encoded as they
+    // were received.
+    let (pdu, _) = <Mini_PDU as Decode<Aper>>::from_bytes(&setup_request).unwrap();
+    assert_eq!(Encode::<Aper>::to_bytes(&pdu).unwrap(), setup_request);
+    let message = match pdu {
+        Mini_PDU::InitiatingMessage(ref message) => message,
+        _ => panic!("Expected an InitiatingMessage"),
+    };
+    assert_eq!(message.value.key(), Some(1));
+    match message.value.decode_value().unwrap() {
+        InitiatingMessageValue::Id_Setup(request) => {
+            assert_eq!(request.protocol_i_es.0.len(), 3);
+        }
+        _ => panic!("Expected a SetupRequest"),
+    }
+
+    // The UE ID of a Transfer is decoded without decoding the other IEs.
+    let ie = |id: u16, value: TransferProtocolIEs_EntryValue| TransferProtocolIEs_Entry {
+        id: ProtocolIE_ID(id),
+        criticality: Criticality(0),
+        value: LazyOpenType::new(id as i128, &value).unwrap(),
+    };
+    let transfer = Transfer {
+        protocol_i_es: TransferProtocolIEs(vec![
+            ie(
+                4,
+                TransferProtocolIEs_EntryValue::Id_UE_ID(UE_ID(0x01_0203_0405)),
+            ),
+            ie(
+                5,
+                TransferProtocolIEs_EntryValue::Id_Payload(Payload(vec![0x7e; 64])),
+            ),
+        ]),
+        unknown_extensions: UnknownExtensions::default(),
+    };
+    let pdu = Mini_PDU::InitiatingMessage(InitiatingMessage {
+        procedure_code: ProcedureCode(2),
+        criticality: Criticality(1),
+        value: LazyOpenType::new(2, &InitiatingMessageValue::Id_Transfer(transfer)).unwrap(),
+    });
+    let transfer_data = Encode::<Aper>::to_bytes(&pdu).unwrap();
+
+    let (pdu, _) = <Mini_PDU as Decode<Aper>>::from_bytes(&transfer_data).unwrap();
+    let transfer = match pdu {
+        Mini_PDU::InitiatingMessage(message) => match message.value.decode_value().unwrap() {
+            InitiatingMessageValue::Id_Transfer(transfer) => transfer,
+            _ => panic!("Expected a Transfer"),
+        },
+        _ => panic!("Expected an InitiatingMessage"),
+    };
+    let mut ue_id = None;
+    for ie in &transfer.protocol_i_es.0 {
+        match ie.id.0 {
+            4 => match ie.value.decode_value().unwrap() {
+                TransferProtocolIEs_EntryValue::Id_UE_ID(id) => ue_id = Some(id.0),
+                _ => panic!("Unexpected IE value"),
+            },
+            _ => assert_eq!(ie.value.raw_bytes().len(), 65),
+        }
+    }
+    assert_eq!(ue_id, Some(0x01_0203_0405));
+}
@@ -0,0 +1,920 @@
+#![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::enum_variant_names,
+    clippy::upper_case_acronyms
+)]
+use asn1_codecs_derive::AperCodec;
+use bitvec::order::Msb0;
+use bitvec::vec::BitVec;
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Cause {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    RadioNetwork(CauseRadioNetwork),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    Misc(CauseMisc),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct CauseMisc(pub u8);
+impl CauseMisc {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HARDWARE_FAILURE: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct CauseRadioNetwork(pub u8);
+impl CauseRadioNetwork {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HANDOVER_CANCELLED: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(pub u8);
+impl Criticality {
+    pub const REJECT: u8 = 0u8;
+    pub const IGNORE: u8 = 1u8;
+    pub const NOTIFY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct InitiatingMessage {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: InitiatingMessageValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ItemID(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct ItemTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
+    pub cause: Cause,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "1",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
+pub enum Mini_PDU {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    InitiatingMessage(InitiatingMessage),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    SuccessfulOutcome(SuccessfulOutcome),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "PrintableString",
+    sz_extensible = true,
+    sz_lb = "1",
+    sz_ub = "150"
+)]
+pub struct NodeName(pub String);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct PLMNIdentity(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "1", ub = "8", extensible = true)]
+pub struct PagingPriority(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING")]
+pub struct Payload(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Presence(pub u8);
+impl Presence {
+    pub const OPTIONAL: u8 = 0u8;
+    pub const CONDITIONAL: u8 = 1u8;
+    pub const MANDATORY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ProcedureCode(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(pub u16);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "BITSTRING",
+    sz_extensible = false,
+    sz_lb = "256",
+    sz_ub = "256"
+)]
+pub struct SecurityKey(pub BitVec<u8, Msb0>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: SetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: SetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SuccessfulOutcome {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: SuccessfulOutcomeValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct TAC(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TrackingArea {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub tac: TAC,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub plmn_identity: PLMNIdentity,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
+pub struct TrackingAreas(pub Vec<TrackingArea>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Transfer {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: TransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TransferItem {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub item_id: ItemID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub item_transfer: OCTET_STRING_2,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "INTEGER", lb = "0", ub = "1099511627775")]
+pub struct UE_ID(pub u64);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum InitiatingMessageValue {
+    #[asn(key = 1)]
+    Id_Setup(SetupRequest),
+    #[asn(key = 2)]
+    Id_Transfer(Transfer),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum SetupRequestProtocolIEs_EntryValue {
+    #[asn(key = 1)]
+    Id_NodeName(NodeName),
+    #[asn(key = 3)]
+    Id_PagingPriority(PagingPriority),
+    #[asn(key = 2)]
+    Id_TrackingAreas(TrackingAreas),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupRequestProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: SetupRequestProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupRequestProtocolIEs(pub Vec<SetupRequestProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum SetupResponseProtocolIEs_EntryValue {
+    #[asn(key = 1)]
+    Id_NodeName(NodeName),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupResponseProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: SetupResponseProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupResponseProtocolIEs(pub Vec<SetupResponseProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum SuccessfulOutcomeValue {
+    #[asn(key = 1)]
+    Id_Setup(SetupResponse),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OPEN")]
+pub enum TransferProtocolIEs_EntryValue {
+    #[asn(key = 5)]
+    Id_Payload(Payload),
+    #[asn(key = 7)]
+    Id_SecurityKey(SecurityKey),
+    #[asn(key = 6)]
+    Id_TransferItem(TransferItem),
+    #[asn(key = 4)]
+    Id_UE_ID(UE_ID),
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct TransferProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: TransferProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct TransferProtocolIEs(pub Vec<TransferProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec)]
+#[asn(type = "OCTET-STRING")]
+pub struct OCTET_STRING_2(pub Vec<u8>);
+impl OCTET_STRING_2 {
+    pub fn decode_contained(&self) -> Result<ItemTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) =
+            <ItemTransfer as asn1_codecs::Decode<asn1_codecs::aper::Aper>>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(value: &ItemTransfer) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <ItemTransfer as asn1_codecs::Encode<asn1_codecs::aper::Aper>>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+#[doc = r" Traversal of the generated Types by reference."]
+pub mod visit {
+    use super::*;
+    #[doc = r" A `visit_*` method for every generated Type."]
+    #[doc = r""]
+    #[doc = r" The default implementation of a method visits all the children of the Type"]
+    #[doc = r" using the corresponding `walk_*` function. Override the methods for the Types"]
+    #[doc = r" of interest and call the `walk_*` function to continue visiting the children."]
+    pub trait Visitor {
+        fn visit_cause(&mut self, node: &Cause) {
+            walk_cause(self, node)
+        }
+        fn visit_cause_misc(&mut self, _node: &CauseMisc) {}
+        fn visit_cause_radio_network(&mut self, _node: &CauseRadioNetwork) {}
+        fn visit_criticality(&mut self, _node: &Criticality) {}
+        fn visit_initiating_message(&mut self, node: &InitiatingMessage) {
+            walk_initiating_message(self, node)
+        }
+        fn visit_initiating_message_value(&mut self, node: &InitiatingMessageValue) {
+            walk_initiating_message_value(self, node)
+        }
+        fn visit_item_id(&mut self, _node: &ItemID) {}
+        fn visit_item_transfer(&mut self, node: &ItemTransfer) {
+            walk_item_transfer(self, node)
+        }
+        fn visit_mini_pdu(&mut self, node: &Mini_PDU) {
+            walk_mini_pdu(self, node)
+        }
+        fn visit_node_name(&mut self, _node: &NodeName) {}
+        fn visit_octet_string_2(&mut self, _node: &OCTET_STRING_2) {}
+        fn visit_plmn_identity(&mut self, _node: &PLMNIdentity) {}
+        fn visit_paging_priority(&mut self, _node: &PagingPriority) {}
+        fn visit_payload(&mut self, _node: &Payload) {}
+        fn visit_presence(&mut self, _node: &Presence) {}
+        fn visit_procedure_code(&mut self, _node: &ProcedureCode) {}
+        fn visit_protocol_ie_id(&mut self, _node: &ProtocolIE_ID) {}
+        fn visit_security_key(&mut self, _node: &SecurityKey) {}
+        fn visit_setup_request(&mut self, node: &SetupRequest) {
+            walk_setup_request(self, node)
+        }
+        fn visit_setup_request_protocol_i_es(&mut self, node: &SetupRequestProtocolIEs) {
+            walk_setup_request_protocol_i_es(self, node)
+        }
+        fn visit_setup_request_protocol_i_es_entry(
+            &mut self,
+            node: &SetupRequestProtocolIEs_Entry,
+        ) {
+            walk_setup_request_protocol_i_es_entry(self, node)
+        }
+        fn visit_setup_request_protocol_i_es_entry_value(
+            &mut self,
+            node: &SetupRequestProtocolIEs_EntryValue,
+        ) {
+            walk_setup_request_protocol_i_es_entry_value(self, node)
+        }
+        fn visit_setup_response(&mut self, node: &SetupResponse) {
+            walk_setup_response(self, node)
+        }
+        fn visit_setup_response_protocol_i_es(&mut self, node: &SetupResponseProtocolIEs) {
+            walk_setup_response_protocol_i_es(self, node)
+        }
+        fn visit_setup_response_protocol_i_es_entry(
+            &mut self,
+            node: &SetupResponseProtocolIEs_Entry,
+        ) {
+            walk_setup_response_protocol_i_es_entry(self, node)
+        }
+        fn visit_setup_response_protocol_i_es_entry_value(
+            &mut self,
+            node: &SetupResponseProtocolIEs_EntryValue,
+        ) {
+            walk_setup_response_protocol_i_es_entry_value(self, node)
+        }
+        fn visit_successful_outcome(&mut self, node: &SuccessfulOutcome) {
+            walk_successful_outcome(self, node)
+        }
+        fn visit_successful_outcome_value(&mut self, node: &SuccessfulOutcomeValue) {
+            walk_successful_outcome_value(self, node)
+        }
+        fn visit_tac(&mut self, _node: &TAC) {}
+        fn visit_tracking_area(&mut self, node: &TrackingArea) {
+            walk_tracking_area(self, node)
+        }
+        fn visit_tracking_areas(&mut self, node: &TrackingAreas) {
+            walk_tracking_areas(self, node)
+        }
+        fn visit_transfer(&mut self, node: &Transfer) {
+            walk_transfer(self, node)
+        }
+        fn visit_transfer_item(&mut self, node: &TransferItem) {
+            walk_transfer_item(self, node)
+        }
+        fn visit_transfer_protocol_i_es(&mut self, node: &TransferProtocolIEs) {
+            walk_transfer_protocol_i_es(self, node)
+        }
+        fn visit_transfer_protocol_i_es_entry(&mut self, node: &TransferProtocolIEs_Entry) {
+            walk_transfer_protocol_i_es_entry(self, node)
+        }
+        fn visit_transfer_protocol_i_es_entry_value(
+            &mut self,
+            node: &TransferProtocolIEs_EntryValue,
+        ) {
+            walk_transfer_protocol_i_es_entry_value(self, node)
+        }
+        fn visit_ue_id(&mut self, _node: &UE_ID) {}
+    }
+    pub fn walk_cause<V: Visitor + ?Sized>(visitor: &mut V, node: &Cause) {
+        match node {
+            Cause::RadioNetwork(value) => visitor.visit_cause_radio_network(value),
+            Cause::Misc(value) => visitor.visit_cause_misc(value),
+            Cause::UnknownExtension(_) => {}
+        }
+    }
+    pub fn walk_initiating_message<V: Visitor + ?Sized>(visitor: &mut V, node: &InitiatingMessage) {
+        visitor.visit_procedure_code(&node.procedure_code);
+        visitor.visit_criticality(&node.criticality);
+        visitor.visit_initiating_message_value(&node.value);
+    }
+    pub fn walk_initiating_message_value<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &InitiatingMessageValue,
+    ) {
+        match node {
+            InitiatingMessageValue::Id_Setup(value) => visitor.visit_setup_request(value),
+            InitiatingMessageValue::Id_Transfer(value) => visitor.visit_transfer(value),
+        }
+    }
+    pub fn walk_item_transfer<V: Visitor + ?Sized>(visitor: &mut V, node: &ItemTransfer) {
+        visitor.visit_cause(&node.cause);
+    }
+    pub fn walk_mini_pdu<V: Visitor + ?Sized>(visitor: &mut V, node: &Mini_PDU) {
+        match node {
+            Mini_PDU::InitiatingMessage(value) => visitor.visit_initiating_message(value),
+            Mini_PDU::SuccessfulOutcome(value) => visitor.visit_successful_outcome(value),
+            Mini_PDU::UnknownExtension(_) => {}
+        }
+    }
+    pub fn walk_setup_request<V: Visitor + ?Sized>(visitor: &mut V, node: &SetupRequest) {
+        visitor.visit_setup_request_protocol_i_es(&node.protocol_i_es);
+    }
+    pub fn walk_setup_request_protocol_i_es<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &SetupRequestProtocolIEs,
+    ) {
+        for entry in &node.0 {
+            visitor.visit_setup_request_protocol_i_es_entry(entry);
+        }
+    }
+    pub fn walk_setup_request_protocol_i_es_entry<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &SetupRequestProtocolIEs_Entry,
+    ) {
+        visitor.visit_protocol_ie_id(&node.id);
+        visitor.visit_criticality(&node.criticality);
+        visitor.visit_setup_request_protocol_i_es_entry_value(&node.value);
+    }
+    pub fn walk_setup_request_protocol_i_es_entry_value<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &SetupRequestProtocolIEs_EntryValue,
+    ) {
+        match node {
+            SetupRequestProtocolIEs_EntryValue::Id_NodeName(value) => {
+                visitor.visit_node_name(value)
+            }
+            SetupRequestProtocolIEs_EntryValue::Id_PagingPriority(value) => {
+                visitor.visit_paging_priority(value)
+            }
+            SetupRequestProtocolIEs_EntryValue::Id_TrackingAreas(value) => {
+                visitor.visit_tracking_areas(value)
+            }
+        }
+    }
+    pub fn walk_setup_response<V: Visitor + ?Sized>(visitor: &mut V, node: &SetupResponse) {
+        visitor.visit_setup_response_protocol_i_es(&node.protocol_i_es);
+    }
+    pub fn walk_setup_response_protocol_i_es<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &SetupResponseProtocolIEs,
+    ) {
+        for entry in &node.0 {
+            visitor.visit_setup_response_protocol_i_es_entry(entry);
+        }
+    }
+    pub fn walk_setup_response_protocol_i_es_entry<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &SetupResponseProtocolIEs_Entry,
+    ) {
+        visitor.visit_protocol_ie_id(&node.id);
+        visitor.visit_criticality(&node.criticality);
+        visitor.visit_setup_response_protocol_i_es_entry_value(&node.value);
+    }
+    pub fn walk_setup_response_protocol_i_es_entry_value<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &SetupResponseProtocolIEs_EntryValue,
+    ) {
+        match node {
+            SetupResponseProtocolIEs_EntryValue::Id_NodeName(value) => {
+                visitor.visit_node_name(value)
+            }
+        }
+    }
+    pub fn walk_successful_outcome<V: Visitor + ?Sized>(visitor: &mut V, node: &SuccessfulOutcome) {
+        visitor.visit_procedure_code(&node.procedure_code);
+        visitor.visit_criticality(&node.criticality);
+        visitor.visit_successful_outcome_value(&node.value);
+    }
+    pub fn walk_successful_outcome_value<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &SuccessfulOutcomeValue,
+    ) {
+        match node {
+            SuccessfulOutcomeValue::Id_Setup(value) => visitor.visit_setup_response(value),
+        }
+    }
+    pub fn walk_tracking_area<V: Visitor + ?Sized>(visitor: &mut V, node: &TrackingArea) {
+        visitor.visit_tac(&node.tac);
+        visitor.visit_plmn_identity(&node.plmn_identity);
+    }
+    pub fn walk_tracking_areas<V: Visitor + ?Sized>(visitor: &mut V, node: &TrackingAreas) {
+        for entry in &node.0 {
+            visitor.visit_tracking_area(entry);
+        }
+    }
+    pub fn walk_transfer<V: Visitor + ?Sized>(visitor: &mut V, node: &Transfer) {
+        visitor.visit_transfer_protocol_i_es(&node.protocol_i_es);
+    }
+    pub fn walk_transfer_item<V: Visitor + ?Sized>(visitor: &mut V, node: &TransferItem) {
+        visitor.visit_item_id(&node.item_id);
+        visitor.visit_octet_string_2(&node.item_transfer);
+    }
+    pub fn walk_transfer_protocol_i_es<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &TransferProtocolIEs,
+    ) {
+        for entry in &node.0 {
+            visitor.visit_transfer_protocol_i_es_entry(entry);
+        }
+    }
+    pub fn walk_transfer_protocol_i_es_entry<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &TransferProtocolIEs_Entry,
+    ) {
+        visitor.visit_protocol_ie_id(&node.id);
+        visitor.visit_criticality(&node.criticality);
+        visitor.visit_transfer_protocol_i_es_entry_value(&node.value);
+    }
+    pub fn walk_transfer_protocol_i_es_entry_value<V: Visitor + ?Sized>(
+        visitor: &mut V,
+        node: &TransferProtocolIEs_EntryValue,
+    ) {
+        match node {
+            TransferProtocolIEs_EntryValue::Id_Payload(value) => visitor.visit_payload(value),
+            TransferProtocolIEs_EntryValue::Id_SecurityKey(value) => {
+                visitor.visit_security_key(value)
+            }
+            TransferProtocolIEs_EntryValue::Id_TransferItem(value) => {
+                visitor.visit_transfer_item(value)
+            }
+            TransferProtocolIEs_EntryValue::Id_UE_ID(value) => visitor.visit_ue_id(value),
+        }
+    }
+}
+#[doc = r" Traversal of the generated Types by mutable reference."]
+pub mod visit_mut {
+    use super::*;
+    #[doc = r" A `visit_*_mut` method for every generated Type."]
+    #[doc = r""]
+    #[doc = r" The default implementation of a method visits all the children of the Type"]
+    #[doc = r" using the corresponding `walk_*_mut` function. Override the methods for the"]
+    #[doc = r" Types of interest and call the `walk_*_mut` function to continue visiting the"]
+    #[doc = r" children."]
+    pub trait VisitorMut {
+        fn visit_cause_mut(&mut self, node: &mut Cause) {
+            walk_cause_mut(self, node)
+        }
+        fn visit_cause_misc_mut(&mut self, _node: &mut CauseMisc) {}
+        fn visit_cause_radio_network_mut(&mut self, _node: &mut CauseRadioNetwork) {}
+        fn visit_criticality_mut(&mut self, _node: &mut Criticality) {}
+        fn visit_initiating_message_mut(&mut self, node: &mut InitiatingMessage) {
+            walk_initiating_message_mut(self, node)
+        }
+        fn visit_initiating_message_value_mut(&mut self, node: &mut InitiatingMessageValue) {
+            walk_initiating_message_value_mut(self, node)
+        }
+        fn visit_item_id_mut(&mut self, _node: &mut ItemID) {}
+        fn visit_item_transfer_mut(&mut self, node: &mut ItemTransfer) {
+            walk_item_transfer_mut(self, node)
+        }
+        fn visit_mini_pdu_mut(&mut self, node: &mut Mini_PDU) {
+            walk_mini_pdu_mut(self, node)
+        }
+        fn visit_node_name_mut(&mut self, _node: &mut NodeName) {}
+        fn visit_octet_string_2_mut(&mut self, _node: &mut OCTET_STRING_2) {}
+        fn visit_plmn_identity_mut(&mut self, _node: &mut PLMNIdentity) {}
+        fn visit_paging_priority_mut(&mut self, _node: &mut PagingPriority) {}
+        fn visit_payload_mut(&mut self, _node: &mut Payload) {}
+        fn visit_presence_mut(&mut self, _node: &mut Presence) {}
+        fn visit_procedure_code_mut(&mut self, _node: &mut ProcedureCode) {}
+        fn visit_protocol_ie_id_mut(&mut self, _node: &mut ProtocolIE_ID) {}
+        fn visit_security_key_mut(&mut self, _node: &mut SecurityKey) {}
+        fn visit_setup_request_mut(&mut self, node: &mut SetupRequest) {
+            walk_setup_request_mut(self, node)
+        }
+        fn visit_setup_request_protocol_i_es_mut(&mut self, node: &mut SetupRequestProtocolIEs) {
+            walk_setup_request_protocol_i_es_mut(self, node)
+        }
+        fn visit_setup_request_protocol_i_es_entry_mut(
+            &mut self,
+            node: &mut SetupRequestProtocolIEs_Entry,
+        ) {
+            walk_setup_request_protocol_i_es_entry_mut(self, node)
+        }
+        fn visit_setup_request_protocol_i_es_entry_value_mut(
+            &mut self,
+            node: &mut SetupRequestProtocolIEs_EntryValue,
+        ) {
+            walk_setup_request_protocol_i_es_entry_value_mut(self, node)
+        }
+        fn visit_setup_response_mut(&mut self, node: &mut SetupResponse) {
+            walk_setup_response_mut(self, node)
+        }
+        fn visit_setup_response_protocol_i_es_mut(&mut self, node: &mut SetupResponseProtocolIEs) {
+            walk_setup_response_protocol_i_es_mut(self, node)
+        }
+        fn visit_setup_response_protocol_i_es_entry_mut(
+            &mut self,
+            node: &mut SetupResponseProtocolIEs_Entry,
+        ) {
+            walk_setup_response_protocol_i_es_entry_mut(self, node)
+        }
+        fn visit_setup_response_protocol_i_es_entry_value_mut(
+            &mut self,
+            node: &mut SetupResponseProtocolIEs_EntryValue,
+        ) {
+            walk_setup_response_protocol_i_es_entry_value_mut(self, node)
+        }
+        fn visit_successful_outcome_mut(&mut self, node: &mut SuccessfulOutcome) {
+            walk_successful_outcome_mut(self, node)
+        }
+        fn visit_successful_outcome_value_mut(&mut self, node: &mut SuccessfulOutcomeValue) {
+            walk_successful_outcome_value_mut(self, node)
+        }
+        fn visit_tac_mut(&mut self, _node: &mut TAC) {}
+        fn visit_tracking_area_mut(&mut self, node: &mut TrackingArea) {
+            walk_tracking_area_mut(self, node)
+        }
+        fn visit_tracking_areas_mut(&mut self, node: &mut TrackingAreas) {
+            walk_tracking_areas_mut(self, node)
+        }
+        fn visit_transfer_mut(&mut self, node: &mut Transfer) {
+            walk_transfer_mut(self, node)
+        }
+        fn visit_transfer_item_mut(&mut self, node: &mut TransferItem) {
+            walk_transfer_item_mut(self, node)
+        }
+        fn visit_transfer_protocol_i_es_mut(&mut self, node: &mut TransferProtocolIEs) {
+            walk_transfer_protocol_i_es_mut(self, node)
+        }
+        fn visit_transfer_protocol_i_es_entry_mut(&mut self, node: &mut TransferProtocolIEs_Entry) {
+            walk_transfer_protocol_i_es_entry_mut(self, node)
+        }
+        fn visit_transfer_protocol_i_es_entry_value_mut(
+            &mut self,
+            node: &mut TransferProtocolIEs_EntryValue,
+        ) {
+            walk_transfer_protocol_i_es_entry_value_mut(self, node)
+        }
+        fn visit_ue_id_mut(&mut self, _node: &mut UE_ID) {}
+    }
+    pub fn walk_cause_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Cause) {
+        match node {
+            Cause::RadioNetwork(value) => visitor.visit_cause_radio_network_mut(value),
+            Cause::Misc(value) => visitor.visit_cause_misc_mut(value),
+            Cause::UnknownExtension(_) => {}
+        }
+    }
+    pub fn walk_initiating_message_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut InitiatingMessage,
+    ) {
+        visitor.visit_procedure_code_mut(&mut node.procedure_code);
+        visitor.visit_criticality_mut(&mut node.criticality);
+        visitor.visit_initiating_message_value_mut(&mut node.value);
+    }
+    pub fn walk_initiating_message_value_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut InitiatingMessageValue,
+    ) {
+        match node {
+            InitiatingMessageValue::Id_Setup(value) => visitor.visit_setup_request_mut(value),
+            InitiatingMessageValue::Id_Transfer(value) => visitor.visit_transfer_mut(value),
+        }
+    }
+    pub fn walk_item_transfer_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut ItemTransfer,
+    ) {
+        visitor.visit_cause_mut(&mut node.cause);
+    }
+    pub fn walk_mini_pdu_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Mini_PDU) {
+        match node {
+            Mini_PDU::InitiatingMessage(value) => visitor.visit_initiating_message_mut(value),
+            Mini_PDU::SuccessfulOutcome(value) => visitor.visit_successful_outcome_mut(value),
+            Mini_PDU::UnknownExtension(_) => {}
+        }
+    }
+    pub fn walk_setup_request_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupRequest,
+    ) {
+        visitor.visit_setup_request_protocol_i_es_mut(&mut node.protocol_i_es);
+    }
+    pub fn walk_setup_request_protocol_i_es_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupRequestProtocolIEs,
+    ) {
+        for entry in &mut node.0 {
+            visitor.visit_setup_request_protocol_i_es_entry_mut(entry);
+        }
+    }
+    pub fn walk_setup_request_protocol_i_es_entry_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupRequestProtocolIEs_Entry,
+    ) {
+        visitor.visit_protocol_ie_id_mut(&mut node.id);
+        visitor.visit_criticality_mut(&mut node.criticality);
+        visitor.visit_setup_request_protocol_i_es_entry_value_mut(&mut node.value);
+    }
+    pub fn walk_setup_request_protocol_i_es_entry_value_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupRequestProtocolIEs_EntryValue,
+    ) {
+        match node {
+            SetupRequestProtocolIEs_EntryValue::Id_NodeName(value) => {
+                visitor.visit_node_name_mut(value)
+            }
+            SetupRequestProtocolIEs_EntryValue::Id_PagingPriority(value) => {
+                visitor.visit_paging_priority_mut(value)
+            }
+            SetupRequestProtocolIEs_EntryValue::Id_TrackingAreas(value) => {
+                visitor.visit_tracking_areas_mut(value)
+            }
+        }
+    }
+    pub fn walk_setup_response_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupResponse,
+    ) {
+        visitor.visit_setup_response_protocol_i_es_mut(&mut node.protocol_i_es);
+    }
+    pub fn walk_setup_response_protocol_i_es_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupResponseProtocolIEs,
+    ) {
+        for entry in &mut node.0 {
+            visitor.visit_setup_response_protocol_i_es_entry_mut(entry);
+        }
+    }
+    pub fn walk_setup_response_protocol_i_es_entry_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupResponseProtocolIEs_Entry,
+    ) {
+        visitor.visit_protocol_ie_id_mut(&mut node.id);
+        visitor.visit_criticality_mut(&mut node.criticality);
+        visitor.visit_setup_response_protocol_i_es_entry_value_mut(&mut node.value);
+    }
+    pub fn walk_setup_response_protocol_i_es_entry_value_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SetupResponseProtocolIEs_EntryValue,
+    ) {
+        match node {
+            SetupResponseProtocolIEs_EntryValue::Id_NodeName(value) => {
+                visitor.visit_node_name_mut(value)
+            }
+        }
+    }
+    pub fn walk_successful_outcome_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SuccessfulOutcome,
+    ) {
+        visitor.visit_procedure_code_mut(&mut node.procedure_code);
+        visitor.visit_criticality_mut(&mut node.criticality);
+        visitor.visit_successful_outcome_value_mut(&mut node.value);
+    }
+    pub fn walk_successful_outcome_value_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut SuccessfulOutcomeValue,
+    ) {
+        match node {
+            SuccessfulOutcomeValue::Id_Setup(value) => visitor.visit_setup_response_mut(value),
+        }
+    }
+    pub fn walk_tracking_area_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut TrackingArea,
+    ) {
+        visitor.visit_tac_mut(&mut node.tac);
+        visitor.visit_plmn_identity_mut(&mut node.plmn_identity);
+    }
+    pub fn walk_tracking_areas_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut TrackingAreas,
+    ) {
+        for entry in &mut node.0 {
+            visitor.visit_tracking_area_mut(entry);
+        }
+    }
+    pub fn walk_transfer_mut<V: VisitorMut + ?Sized>(visitor: &mut V, node: &mut Transfer) {
+        visitor.visit_transfer_protocol_i_es_mut(&mut node.protocol_i_es);
+    }
+    pub fn walk_transfer_item_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut TransferItem,
+    ) {
+        visitor.visit_item_id_mut(&mut node.item_id);
+        visitor.visit_octet_string_2_mut(&mut node.item_transfer);
+    }
+    pub fn walk_transfer_protocol_i_es_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut TransferProtocolIEs,
+    ) {
+        for entry in &mut node.0 {
+            visitor.visit_transfer_protocol_i_es_entry_mut(entry);
+        }
+    }
+    pub fn walk_transfer_protocol_i_es_entry_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut TransferProtocolIEs_Entry,
+    ) {
+        visitor.visit_protocol_ie_id_mut(&mut node.id);
+        visitor.visit_criticality_mut(&mut node.criticality);
+        visitor.visit_transfer_protocol_i_es_entry_value_mut(&mut node.value);
+    }
+    pub fn walk_transfer_protocol_i_es_entry_value_mut<V: VisitorMut + ?Sized>(
+        visitor: &mut V,
+        node: &mut TransferProtocolIEs_EntryValue,
+    ) {
+        match node {
+            TransferProtocolIEs_EntryValue::Id_Payload(value) => visitor.visit_payload_mut(value),
+            TransferProtocolIEs_EntryValue::Id_SecurityKey(value) => {
+                visitor.visit_security_key_mut(value)
+            }
+            TransferProtocolIEs_EntryValue::Id_TransferItem(value) => {
+                visitor.visit_transfer_item_mut(value)
+            }
+            TransferProtocolIEs_EntryValue::Id_UE_ID(value) => visitor.visit_ue_id_mut(value),
+        }
+    }
+}
+
+fn main() {
+    use asn1_codecs::aper::*;
+    use asn1_codecs::Decode;
+    use visit::Visitor;
+    use visit_mut::VisitorMut;
+    eprintln!("Visitors");
+
+    struct TacCollector(Vec<Vec<u8>>);
+    impl Visitor for TacCollector {
+        fn visit_tac(&mut self, node: &TAC) {
+            self.0.push(node.0.clone());
+        }
+    }
+
+    struct TacRewriter(Vec<u8>);
+    impl VisitorMut for TacRewriter {
+        fn visit_tac_mut(&mut self, node: &mut TAC) {
+            node.0 = self.0.clone();
+        }
+    }
+
+    let setup_request_str = "0001002e0000030001401006804d79206c6974746c65206e6f64650002000e1000000102f8980000000202f8980003400120";
+    let setup_request = hex::decode(setup_request_str).unwrap();
+    let (mut pdu, _) = <Mini_PDU as Decode<Aper>>::from_bytes(&setup_request).unwrap();
+
+    // The visitor reaches the values inside the Open Types.
+    let mut collector = TacCollector(vec![]);
+    collector.visit_mini_pdu(&pdu);
+    assert_eq!(
+        collector.0,
+        vec![vec![0x00, 0x00, 0x01], vec![0x00, 0x00, 0x02]]
+    );
+
+    TacRewriter(vec![0x00, 0x00, 0x2a]).visit_mini_pdu_mut(&mut pdu);
+    let mut collector = TacCollector(vec![]);
+    collector.visit_mini_pdu(&pdu);
+    assert_eq!(
+        collector.0,
+        vec![vec![0x00, 0x00, 0x2a], vec![0x00, 0x00, 0x2a]]
+    );
+}
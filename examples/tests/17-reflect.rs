@@ -0,0 +1,376 @@
+#![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::enum_variant_names,
+    clippy::upper_case_acronyms
+)]
+use asn1_codecs_derive::{AperCodec, Asn1Reflect};
+use bitvec::order::Msb0;
+use bitvec::vec::BitVec;
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Cause {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    RadioNetwork(CauseRadioNetwork),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    Misc(CauseMisc),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct CauseMisc(pub u8);
+impl CauseMisc {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HARDWARE_FAILURE: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "ENUMERATED", extensible = true, lb = "0", ub = "1")]
+pub struct CauseRadioNetwork(pub u8);
+impl CauseRadioNetwork {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HANDOVER_CANCELLED: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Criticality(pub u8);
+impl Criticality {
+    pub const REJECT: u8 = 0u8;
+    pub const IGNORE: u8 = 1u8;
+    pub const NOTIFY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct InitiatingMessage {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: InitiatingMessageValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ItemID(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct ItemTransfer {
+    #[asn(tag = "[0] EXPLICIT")]
+    pub cause: Cause,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "1",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
+pub enum Mini_PDU {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT")]
+    InitiatingMessage(InitiatingMessage),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT")]
+    SuccessfulOutcome(SuccessfulOutcome),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "PrintableString",
+    sz_extensible = true,
+    sz_lb = "1",
+    sz_ub = "150"
+)]
+pub struct NodeName(pub String);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct PLMNIdentity(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "1", ub = "8", extensible = true)]
+pub struct PagingPriority(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OCTET-STRING")]
+pub struct Payload(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "ENUMERATED", lb = "0", ub = "2")]
+pub struct Presence(pub u8);
+impl Presence {
+    pub const OPTIONAL: u8 = 0u8;
+    pub const CONDITIONAL: u8 = 1u8;
+    pub const MANDATORY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ProcedureCode(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(pub u16);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "BITSTRING",
+    sz_extensible = false,
+    sz_lb = "256",
+    sz_ub = "256"
+)]
+pub struct SecurityKey(pub BitVec<u8, Msb0>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupRequest {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: SetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupResponse {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: SetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SuccessfulOutcome {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: SuccessfulOutcomeValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct TAC(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TrackingArea {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub tac: TAC,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub plmn_identity: PLMNIdentity,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
+pub struct TrackingAreas(pub Vec<TrackingArea>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Transfer {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub protocol_i_es: TransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TransferItem {
+    #[asn(tag = "[0] IMPLICIT")]
+    pub item_id: ItemID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub item_transfer: OCTET_STRING_2,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "INTEGER", lb = "0", ub = "1099511627775")]
+pub struct UE_ID(pub u64);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OPEN")]
+pub enum InitiatingMessageValue {
+    #[asn(key = 1)]
+    Id_Setup(SetupRequest),
+    #[asn(key = 2)]
+    Id_Transfer(Transfer),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OPEN")]
+pub enum SetupRequestProtocolIEs_EntryValue {
+    #[asn(key = 1)]
+    Id_NodeName(NodeName),
+    #[asn(key = 3)]
+    Id_PagingPriority(PagingPriority),
+    #[asn(key = 2)]
+    Id_TrackingAreas(TrackingAreas),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupRequestProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: SetupRequestProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupRequestProtocolIEs(pub Vec<SetupRequestProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OPEN")]
+pub enum SetupResponseProtocolIEs_EntryValue {
+    #[asn(key = 1)]
+    Id_NodeName(NodeName),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupResponseProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: SetupResponseProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupResponseProtocolIEs(pub Vec<SetupResponseProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OPEN")]
+pub enum SuccessfulOutcomeValue {
+    #[asn(key = 1)]
+    Id_Setup(SetupResponse),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OPEN")]
+pub enum TransferProtocolIEs_EntryValue {
+    #[asn(key = 5)]
+    Id_Payload(Payload),
+    #[asn(key = 7)]
+    Id_SecurityKey(SecurityKey),
+    #[asn(key = 6)]
+    Id_TransferItem(TransferItem),
+    #[asn(key = 4)]
+    Id_UE_ID(UE_ID),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct TransferProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT")]
+    pub value: TransferProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct TransferProtocolIEs(pub Vec<TransferProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1Reflect)]
+#[asn(type = "OCTET-STRING")]
+pub struct OCTET_STRING_2(pub Vec<u8>);
+impl OCTET_STRING_2 {
+    pub fn decode_contained(&self) -> Result<ItemTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) =
+            <ItemTransfer as asn1_codecs::Decode<asn1_codecs::aper::Aper>>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(value: &ItemTransfer) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <ItemTransfer as asn1_codecs::Encode<asn1_codecs::aper::Aper>>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+fn main() {
+    use asn1_codecs::aper::*;
+    use asn1_codecs::reflect::{Asn1Reflect, Asn1Value};
+    use asn1_codecs::Decode;
+    eprintln!("Reflect");
+
+    let setup_request_str = "0001002e0000030001401006804d79206c6974746c65206e6f64650002000e1000000102f8980000000202f8980003400120";
+    let setup_request = hex::decode(setup_request_str).unwrap();
+    let (pdu, _) = <Mini_PDU as Decode<Aper>>::from_bytes(&setup_request).unwrap();
+
+    // Values are selected by the names of the components and the types of the Open Type values.
+    let names = pdu.get("initiatingMessage/value/protocolIEs/*/value/NodeName");
+    assert_eq!(names.len(), 1);
+    assert_eq!(
+        names[0].value(),
+        Asn1Value::CharacterString("My little node")
+    );
+    assert!(pdu
+        .get("initiatingMessage/value/protocolIEs/*/value/UE_ID")
+        .is_empty());
+    let tacs = pdu.get("initiatingMessage/value/protocolIEs/*/value/*/*/tAC");
+    assert_eq!(tacs.len(), 2);
+    assert_eq!(tacs[1].value(), Asn1Value::OctetString(&[0x00, 0x00, 0x02]));
+
+    let redacted = format!("{:?}", pdu.redacted(&["NodeName", "PLMN*"]));
+    assert!(redacted.contains("NodeName(<redacted>)"));
+    assert!(redacted.contains("PLMNIdentity(<redacted>)"));
+    assert!(!redacted.contains("My little node"));
+
+    // Differences between two values.
+    let (mut changed_pdu, _) = <Mini_PDU as Decode<Aper>>::from_bytes(&setup_request).unwrap();
+    if let Mini_PDU::InitiatingMessage(InitiatingMessage {
+        value: InitiatingMessageValue::Id_Setup(ref mut request),
+        ..
+    }) = changed_pdu
+    {
+        request.protocol_i_es.0[0].value =
+            SetupRequestProtocolIEs_EntryValue::Id_NodeName(NodeName("My big node".to_string()));
+    }
+    let differences = asn1_codecs::reflect::diff(&pdu, &changed_pdu);
+    eprintln!("differences: {}", differences);
+    assert_eq!(differences.differences.len(), 1);
+    assert!(differences
+        .to_string()
+        .contains("\"My little node\" -> \"My big node\""));
+    assert!(asn1_codecs::reflect::diff(&pdu, &pdu).is_empty());
+}
@@ -0,0 +1,421 @@
+#![allow(dead_code, unreachable_patterns, non_camel_case_types)]
+#![allow(
+    clippy::large_enum_variant,
+    clippy::enum_variant_names,
+    clippy::upper_case_acronyms
+)]
+use asn1_codecs_derive::{AperCodec, Asn1ValueNotation};
+use bitvec::order::Msb0;
+use bitvec::vec::BitVec;
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "CHOICE", lb = "0", ub = "1", extensible = true)]
+pub enum Cause {
+    #[asn(key = 0, extended = false, tag = "[0] IMPLICIT", name = "radioNetwork")]
+    RadioNetwork(CauseRadioNetwork),
+    #[asn(key = 1, extended = false, tag = "[1] IMPLICIT", name = "misc")]
+    Misc(CauseMisc),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "ENUMERATED",
+    extensible = true,
+    lb = "0",
+    ub = "1",
+    names = "unspecified hardware-failure"
+)]
+pub struct CauseMisc(pub u8);
+impl CauseMisc {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HARDWARE_FAILURE: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "ENUMERATED",
+    extensible = true,
+    lb = "0",
+    ub = "1",
+    names = "unspecified handover-cancelled"
+)]
+pub struct CauseRadioNetwork(pub u8);
+impl CauseRadioNetwork {
+    pub const UNSPECIFIED: u8 = 0u8;
+    pub const HANDOVER_CANCELLED: u8 = 1u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "ENUMERATED",
+    lb = "0",
+    ub = "2",
+    names = "reject ignore notify"
+)]
+pub struct Criticality(pub u8);
+impl Criticality {
+    pub const REJECT: u8 = 0u8;
+    pub const IGNORE: u8 = 1u8;
+    pub const NOTIFY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct InitiatingMessage {
+    #[asn(tag = "[0] IMPLICIT", name = "procedureCode", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT", name = "criticality")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT", name = "value")]
+    pub value: InitiatingMessageValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ItemID(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct ItemTransfer {
+    #[asn(tag = "[0] EXPLICIT", name = "cause")]
+    pub cause: Cause,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "CHOICE",
+    lb = "0",
+    ub = "1",
+    extensible = true,
+    peek_key = "procedure_code"
+)]
+pub enum Mini_PDU {
+    #[asn(
+        key = 0,
+        extended = false,
+        tag = "[0] IMPLICIT",
+        name = "initiatingMessage"
+    )]
+    InitiatingMessage(InitiatingMessage),
+    #[asn(
+        key = 1,
+        extended = false,
+        tag = "[1] IMPLICIT",
+        name = "successfulOutcome"
+    )]
+    SuccessfulOutcome(SuccessfulOutcome),
+    #[asn(unknown_extensions = true)]
+    UnknownExtension(asn1_codecs::aper::UnknownExtension),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "PrintableString",
+    sz_extensible = true,
+    sz_lb = "1",
+    sz_ub = "150"
+)]
+pub struct NodeName(pub String);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct PLMNIdentity(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "INTEGER", lb = "1", ub = "8", extensible = true)]
+pub struct PagingPriority(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OCTET-STRING")]
+pub struct Payload(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "ENUMERATED",
+    lb = "0",
+    ub = "2",
+    names = "optional conditional mandatory"
+)]
+pub struct Presence(pub u8);
+impl Presence {
+    pub const OPTIONAL: u8 = 0u8;
+    pub const CONDITIONAL: u8 = 1u8;
+    pub const MANDATORY: u8 = 2u8;
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "INTEGER", lb = "0", ub = "255")]
+pub struct ProcedureCode(pub u8);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "INTEGER", lb = "0", ub = "65535")]
+pub struct ProtocolIE_ID(pub u16);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "BITSTRING",
+    sz_extensible = false,
+    sz_lb = "256",
+    sz_ub = "256"
+)]
+pub struct SecurityKey(pub BitVec<u8, Msb0>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupRequest {
+    #[asn(tag = "[0] IMPLICIT", name = "protocolIEs")]
+    pub protocol_i_es: SetupRequestProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct SetupResponse {
+    #[asn(tag = "[0] IMPLICIT", name = "protocolIEs")]
+    pub protocol_i_es: SetupResponseProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SuccessfulOutcome {
+    #[asn(tag = "[0] IMPLICIT", name = "procedureCode", key_field = true)]
+    pub procedure_code: ProcedureCode,
+    #[asn(tag = "[1] IMPLICIT", name = "criticality")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT", name = "value")]
+    pub value: SuccessfulOutcomeValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OCTET-STRING", sz_extensible = false, sz_lb = "3", sz_ub = "3")]
+pub struct TAC(pub Vec<u8>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TrackingArea {
+    #[asn(tag = "[0] IMPLICIT", name = "tAC")]
+    pub tac: TAC,
+    #[asn(tag = "[1] IMPLICIT", name = "pLMNIdentity")]
+    pub plmn_identity: PLMNIdentity,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE-OF", sz_extensible = false, sz_lb = "1", sz_ub = "16")]
+pub struct TrackingAreas(pub Vec<TrackingArea>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct Transfer {
+    #[asn(tag = "[0] IMPLICIT", name = "protocolIEs")]
+    pub protocol_i_es: TransferProtocolIEs,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = true)]
+pub struct TransferItem {
+    #[asn(tag = "[0] IMPLICIT", name = "itemID")]
+    pub item_id: ItemID,
+    #[asn(tag = "[1] IMPLICIT", name = "itemTransfer")]
+    pub item_transfer: OCTET_STRING_2,
+    #[asn(unknown_extensions = true)]
+    pub unknown_extensions: asn1_codecs::aper::UnknownExtensions,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "INTEGER", lb = "0", ub = "1099511627775")]
+pub struct UE_ID(pub u64);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OPEN")]
+pub enum InitiatingMessageValue {
+    #[asn(key = 1, name = "SetupRequest")]
+    Id_Setup(SetupRequest),
+    #[asn(key = 2, name = "Transfer")]
+    Id_Transfer(Transfer),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OPEN")]
+pub enum SetupRequestProtocolIEs_EntryValue {
+    #[asn(key = 1, name = "NodeName")]
+    Id_NodeName(NodeName),
+    #[asn(key = 3, name = "PagingPriority")]
+    Id_PagingPriority(PagingPriority),
+    #[asn(key = 2, name = "TrackingAreas")]
+    Id_TrackingAreas(TrackingAreas),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupRequestProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", name = "id", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT", name = "criticality")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT", name = "value")]
+    pub value: SetupRequestProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupRequestProtocolIEs(pub Vec<SetupRequestProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OPEN")]
+pub enum SetupResponseProtocolIEs_EntryValue {
+    #[asn(key = 1, name = "NodeName")]
+    Id_NodeName(NodeName),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct SetupResponseProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", name = "id", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT", name = "criticality")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT", name = "value")]
+    pub value: SetupResponseProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct SetupResponseProtocolIEs(pub Vec<SetupResponseProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OPEN")]
+pub enum SuccessfulOutcomeValue {
+    #[asn(key = 1, name = "SetupResponse")]
+    Id_Setup(SetupResponse),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OPEN")]
+pub enum TransferProtocolIEs_EntryValue {
+    #[asn(key = 5, name = "Payload")]
+    Id_Payload(Payload),
+    #[asn(key = 7, name = "SecurityKey")]
+    Id_SecurityKey(SecurityKey),
+    #[asn(key = 6, name = "TransferItem")]
+    Id_TransferItem(TransferItem),
+    #[asn(key = 4, name = "UE-ID")]
+    Id_UE_ID(UE_ID),
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "SEQUENCE", extensible = false)]
+pub struct TransferProtocolIEs_Entry {
+    #[asn(tag = "[0] IMPLICIT", name = "id", key_field = true)]
+    pub id: ProtocolIE_ID,
+    #[asn(tag = "[1] IMPLICIT", name = "criticality")]
+    pub criticality: Criticality,
+    #[asn(tag = "[2] EXPLICIT", name = "value")]
+    pub value: TransferProtocolIEs_EntryValue,
+}
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(
+    type = "SEQUENCE-OF",
+    sz_extensible = false,
+    sz_lb = "0",
+    sz_ub = "65535"
+)]
+pub struct TransferProtocolIEs(pub Vec<TransferProtocolIEs_Entry>);
+
+#[derive(Debug, PartialEq, AperCodec, Asn1ValueNotation)]
+#[asn(type = "OCTET-STRING")]
+pub struct OCTET_STRING_2(pub Vec<u8>);
+impl OCTET_STRING_2 {
+    pub fn decode_contained(&self) -> Result<ItemTransfer, asn1_codecs::aper::AperCodecError> {
+        let (value, _) =
+            <ItemTransfer as asn1_codecs::Decode<asn1_codecs::aper::Aper>>::from_bytes(&self.0)?;
+        Ok(value)
+    }
+    pub fn from_contained(value: &ItemTransfer) -> Result<Self, asn1_codecs::aper::AperCodecError> {
+        let bytes =
+            <ItemTransfer as asn1_codecs::Encode<asn1_codecs::aper::Aper>>::to_bytes(value)?;
+        Ok(Self(bytes))
+    }
+}
+
+fn main() {
+    use asn1_codecs::aper::*;
+    use asn1_codecs::notation::Asn1ValueNotation;
+    use asn1_codecs::{Decode, Encode};
+    eprintln!("Value Notation");
+
+    let setup_request_str = "0001002e0000030001401006804d79206c6974746c65206e6f64650002000e1000000102f8980000000202f8980003400120";
+    let setup_request = hex::decode(setup_request_str).unwrap();
+    let (pdu, _) = <Mini_PDU as Decode<Aper>>::from_bytes(&setup_request).unwrap();
+
+    // The Open Type values are written with the name of their Type.
+    let notation = pdu.to_value_notation();
+    assert_eq!(
+        notation,
+        "initiatingMessage : { procedureCode 1, criticality reject, value SetupRequest : { protocolIEs { { id 1, criticality ignore, value NodeName : \"My little node\" }, { id 2, criticality reject, value TrackingAreas : { { tAC '000001'H, pLMNIdentity '02F898'H }, { tAC '000002'H, pLMNIdentity '02F898'H } } }, { id 3, criticality ignore, value PagingPriority : 3 } } } }"
+    );
+    let parsed_pdu = Mini_PDU::from_value_notation(&notation).unwrap();
+    assert_eq!(
+        Encode::<Aper>::to_bytes(&parsed_pdu).unwrap(),
+        setup_request
+    );
+    assert!(Mini_PDU::from_value_notation("initiatingMessage : { procedureCode 1 }").is_err());
+
+    let transfer = Mini_PDU::from_value_notation(
+        r#"
+        initiatingMessage : {
+            procedureCode 2,
+            criticality ignore,
+            value Transfer : {
+                protocolIEs {
+                    -- UE-ID
+                    { id 4, criticality reject, value UE-ID : 1099511627775 },
+                    { id 5, criticality reject, value Payload : '7E7E'H }
+                }
+            }
+        }"#,
+    )
+    .unwrap();
+    let encoded = Encode::<Aper>::to_bytes(&transfer).unwrap();
+    let (decoded, _) = <Mini_PDU as Decode<Aper>>::from_bytes(&encoded).unwrap();
+    assert_eq!(
+        decoded.to_value_notation(),
+        "initiatingMessage : { procedureCode 2, criticality ignore, value Transfer : { protocolIEs { { id 4, criticality reject, value UE-ID : 1099511627775 }, { id 5, criticality reject, value Payload : '7E7E'H } } } }"
+    );
+
+    // `OCTET STRING (CONTAINING ...)` values are written as octet strings.
+    let item_transfer =
+        ItemTransfer::from_value_notation("{ cause radioNetwork : handover-cancelled }").unwrap();
+    let item_transfer_bytes = Encode::<Aper>::to_bytes(&item_transfer).unwrap();
+    let transfer_item = TransferItem::from_value_notation(&format!(
+        "{{ itemID 7, itemTransfer '{}'H }}",
+        hex::encode_upper(&item_transfer_bytes)
+    ))
+    .unwrap();
+    let contained = transfer_item.item_transfer.decode_contained().unwrap();
+    assert_eq!(
+        contained.to_value_notation(),
+        "{ cause radioNetwork : handover-cancelled }"
+    );
+}
@@ -5,4 +5,9 @@ fn tests() {
     t.pass("tests/11-ranap.rs");
     t.pass("tests/12-s1ap.rs");
     t.pass("tests/13-ngap.rs");
+    t.pass("tests/14-lazy-open-types.rs");
+    t.pass("tests/15-codec-traits.rs");
+    t.pass("tests/16-visitors.rs");
+    t.pass("tests/17-reflect.rs");
+    t.pass("tests/18-value-notation.rs");
 }